@@ -618,6 +618,33 @@ impl<T: Send + Sync, V: store::SimulatorStore + Send + Sync> RestStateReader for
     ) -> sui_types::storage::error::Result<Option<sui_types::storage::CoinInfo>> {
         todo!()
     }
+    fn account_balances_iter(
+        &self,
+        _owner: SuiAddress,
+        _cursor: Option<StructTag>,
+    ) -> sui_types::storage::error::Result<
+        Box<dyn Iterator<Item = sui_types::storage::BalanceInfo> + '_>,
+    > {
+        todo!()
+    }
+
+    fn get_balance(
+        &self,
+        _owner: SuiAddress,
+        _coin_type: &StructTag,
+    ) -> sui_types::storage::error::Result<Option<sui_types::storage::BalanceInfo>> {
+        todo!()
+    }
+
+    fn event_type_iter(
+        &self,
+        _event_type: &StructTag,
+        _cursor: Option<(CheckpointSequenceNumber, u32, u32)>,
+    ) -> sui_types::storage::error::Result<
+        Box<dyn Iterator<Item = sui_types::storage::EventInfo> + '_>,
+    > {
+        todo!()
+    }
 }
 
 impl Simulacrum {
@@ -83,6 +83,11 @@ use tracing::{error, info};
 ///│ len <uvarint> │ encoding <1 byte> │ data <bytes> │
 ///└───────────────┴───────────────────┴──────────────┘
 ///
+/// A checkpoint data archive has the same layout, but its *.chk files hold the `CheckpointData` of
+/// each checkpoint, including the objects its transactions read and wrote, instead of its
+/// `FullCheckpointContents`. Checkpoint data archives are meant for fast syncing checkpoints
+/// without executing them, and are kept in a separate store from the regular archive.
+///
 /// MANIFEST File Disk Format
 ///┌──────────────────────────────┐
 ///│        magic<4 byte>         │
@@ -93,6 +98,7 @@ use tracing::{error, info};
 ///└──────────────────────────────┘
 pub const CHECKPOINT_FILE_MAGIC: u32 = 0x0000DEAD;
 pub const SUMMARY_FILE_MAGIC: u32 = 0x0000CAFE;
+pub const CHECKPOINT_DATA_FILE_MAGIC: u32 = 0x0000DA7A;
 const MANIFEST_FILE_MAGIC: u32 = 0x00C0FFEE;
 const MAGIC_BYTES: usize = 4;
 const CHECKPOINT_FILE_SUFFIX: &str = "chk";
@@ -109,6 +115,25 @@ pub enum FileType {
     CheckpointSummary,
 }
 
+/// What the content files of an archive hold.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum ArchiveContents {
+    /// The `FullCheckpointContents` of each checkpoint.
+    #[default]
+    Checkpoints,
+    /// The `CheckpointData` of each checkpoint.
+    CheckpointData,
+}
+
+impl ArchiveContents {
+    pub fn content_file_magic(&self) -> u32 {
+        match self {
+            ArchiveContents::Checkpoints => CHECKPOINT_FILE_MAGIC,
+            ArchiveContents::CheckpointData => CHECKPOINT_DATA_FILE_MAGIC,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
 pub struct FileMetadata {
     pub file_type: FileType,
@@ -2,9 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    read_manifest, FileMetadata, FileType, Manifest, CHECKPOINT_FILE_MAGIC, SUMMARY_FILE_MAGIC,
+    read_manifest, FileMetadata, FileType, Manifest, CHECKPOINT_DATA_FILE_MAGIC,
+    CHECKPOINT_FILE_MAGIC, SUMMARY_FILE_MAGIC,
 };
-use anyhow::{anyhow, Context, Result};
+use anyhow::{anyhow, ensure, Context, Result};
 use bytes::buf::Reader;
 use bytes::{Buf, Bytes};
 use futures::{StreamExt, TryStreamExt};
@@ -21,6 +22,7 @@ use sui_storage::object_store::http::HttpDownloaderBuilder;
 use sui_storage::object_store::util::get;
 use sui_storage::object_store::ObjectStoreGetExt;
 use sui_storage::{compute_sha3_checksum_for_bytes, make_iterator, verify_checkpoint};
+use sui_types::full_checkpoint_content::CheckpointData;
 use sui_types::messages_checkpoint::{
     CertifiedCheckpointSummary, CheckpointSequenceNumber,
     FullCheckpointContents as CheckpointContents, VerifiedCheckpoint, VerifiedCheckpointContents,
@@ -515,6 +517,48 @@ impl ArchiveReader {
             .await
     }
 
+    /// Download the content file of a checkpoint data archive that holds `checkpoint`, and return
+    /// the range of checkpoints in the file along with their data. The data is not verified against
+    /// the certified checkpoints, which is left to the caller.
+    pub async fn read_checkpoint_data_file(
+        &self,
+        checkpoint: CheckpointSequenceNumber,
+    ) -> Result<(Range<CheckpointSequenceNumber>, Vec<CheckpointData>)> {
+        let manifest = self.manifest.lock().await.clone();
+        let files = self.verify_manifest(manifest).await?;
+        let index = files.partition_point(|(s, _c)| s.checkpoint_seq_range.end <= checkpoint);
+        let (_, content_metadata) = files
+            .get(index)
+            .filter(|(s, _c)| s.checkpoint_seq_range.contains(&checkpoint))
+            .with_context(|| format!("Archive does not contain checkpoint {checkpoint}"))?;
+
+        let content_data = get(&self.remote_object_store, &content_metadata.file_path()).await?;
+        ensure!(
+            compute_sha3_checksum_for_bytes(content_data.clone())? == content_metadata.sha3_digest,
+            "Content checksum doesn't match for file: {:?}",
+            content_metadata.file_path()
+        );
+        let checkpoint_data: Vec<CheckpointData> =
+            make_iterator(CHECKPOINT_DATA_FILE_MAGIC, content_data.reader())?.collect();
+
+        let num_txns = checkpoint_data
+            .iter()
+            .map(|data| data.transactions.len() as u64)
+            .sum();
+        self.archive_reader_metrics
+            .archive_txns_read
+            .with_label_values(&[&self.bucket])
+            .inc_by(num_txns);
+        self.archive_reader_metrics
+            .archive_checkpoints_read
+            .with_label_values(&[&self.bucket])
+            .inc_by(checkpoint_data.len() as u64);
+        Ok((
+            content_metadata.checkpoint_seq_range.clone(),
+            checkpoint_data,
+        ))
+    }
+
     /// Return latest available checkpoint in archive
     pub async fn latest_available_checkpoint(&self) -> Result<CheckpointSequenceNumber> {
         let manifest = self.manifest.lock().await.clone();
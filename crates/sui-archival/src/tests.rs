@@ -3,7 +3,9 @@
 
 use crate::reader::{ArchiveReader, ArchiveReaderMetrics};
 use crate::writer::ArchiveWriter;
-use crate::{read_manifest, verify_archive_with_local_store, write_manifest, Manifest};
+use crate::{
+    read_manifest, verify_archive_with_local_store, write_manifest, ArchiveContents, Manifest,
+};
 use anyhow::{anyhow, Context, Result};
 use more_asserts as ma;
 use object_store::DynObjectStore;
@@ -252,6 +254,48 @@ async fn test_archive_reader_e2e() -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_checkpoint_data_archive() -> Result<(), anyhow::Error> {
+    let test_store = SharedInMemoryStore::default();
+    let mut test_state = setup_test_state(temp_dir()).await?;
+    test_state.archive_writer = test_state
+        .archive_writer
+        .with_contents(ArchiveContents::CheckpointData);
+    let kill = test_state.archive_writer.start(test_store.clone()).await?;
+    insert_checkpoints_and_verify_manifest(&test_state, test_store.clone(), None).await?;
+    test_state.archive_reader.sync_manifest_once().await?;
+    let latest_archived_checkpoint_seq_num = test_state
+        .archive_reader
+        .latest_available_checkpoint()
+        .await?;
+    let (checkpoint_range, checkpoint_data) = test_state
+        .archive_reader
+        .read_checkpoint_data_file(latest_archived_checkpoint_seq_num)
+        .await?;
+    assert!(checkpoint_range.contains(&latest_archived_checkpoint_seq_num));
+    assert_eq!(
+        checkpoint_data.len() as u64,
+        checkpoint_range.end - checkpoint_range.start
+    );
+    for (sequence_number, data) in checkpoint_range.zip(checkpoint_data) {
+        let checkpoint = test_store
+            .get_checkpoint_by_sequence_number(sequence_number)?
+            .context("Missing checkpoint")?;
+        assert_eq!(data.checkpoint_summary.sequence_number, sequence_number);
+        assert_eq!(
+            data.checkpoint_summary.content_digest,
+            checkpoint.content_digest
+        );
+    }
+    assert!(test_state
+        .archive_reader
+        .read_checkpoint_data_file(latest_archived_checkpoint_seq_num + 1)
+        .await
+        .is_err());
+    kill.send(())?;
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_verify_archive_with_oneshot_store() -> Result<(), anyhow::Error> {
     let test_store = SharedInMemoryStore::default();
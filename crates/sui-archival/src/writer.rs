@@ -3,8 +3,8 @@
 #![allow(dead_code)]
 
 use crate::{
-    create_file_metadata, read_manifest, write_manifest, CheckpointUpdates, FileMetadata, FileType,
-    Manifest, CHECKPOINT_FILE_MAGIC, CHECKPOINT_FILE_SUFFIX, EPOCH_DIR_PREFIX, MAGIC_BYTES,
+    create_file_metadata, read_manifest, write_manifest, ArchiveContents, CheckpointUpdates,
+    FileMetadata, FileType, Manifest, CHECKPOINT_FILE_SUFFIX, EPOCH_DIR_PREFIX, MAGIC_BYTES,
     SUMMARY_FILE_MAGIC, SUMMARY_FILE_SUFFIX,
 };
 use anyhow::Result;
@@ -12,6 +12,7 @@ use anyhow::{anyhow, Context};
 use byteorder::{BigEndian, ByteOrder, WriteBytesExt};
 use object_store::DynObjectStore;
 use prometheus::{register_int_gauge_with_registry, IntGauge, Registry};
+use serde::Serialize;
 use std::fs;
 use std::fs::{File, OpenOptions};
 use std::io::{BufWriter, Seek, SeekFrom, Write};
@@ -24,8 +25,9 @@ use sui_config::object_storage_config::ObjectStoreConfig;
 use sui_storage::blob::{Blob, BlobEncoding};
 use sui_storage::object_store::util::{copy_file, path_to_filesystem};
 use sui_storage::{compress, FileCompression, StorageFormat};
+use sui_types::full_checkpoint_content::CheckpointData;
 use sui_types::messages_checkpoint::{
-    CertifiedCheckpointSummary as Checkpoint, CheckpointSequenceNumber,
+    CertifiedCheckpointSummary as Checkpoint, CheckpointContentsDigest, CheckpointSequenceNumber,
     FullCheckpointContents as CheckpointContents,
 };
 use sui_types::storage::WriteStore;
@@ -39,14 +41,20 @@ pub struct ArchiveMetrics {
 }
 
 impl ArchiveMetrics {
-    pub fn new(registry: &Registry) -> Arc<Self> {
-        let this = Self {
-            latest_checkpoint_archived: register_int_gauge_with_registry!(
+    pub fn new(registry: &Registry, contents: ArchiveContents) -> Arc<Self> {
+        let (name, help) = match contents {
+            ArchiveContents::Checkpoints => (
                 "latest_checkpoint_archived",
                 "Latest checkpoint to have archived to the remote store",
-                registry
-            )
-            .unwrap(),
+            ),
+            ArchiveContents::CheckpointData => (
+                "latest_checkpoint_data_archived",
+                "Latest checkpoint to have had its data archived to the remote store",
+            ),
+        };
+        let this = Self {
+            latest_checkpoint_archived: register_int_gauge_with_registry!(name, help, registry)
+                .unwrap(),
         };
         Arc::new(this)
     }
@@ -61,6 +69,7 @@ struct CheckpointWriter {
     summary_wbuf: BufWriter<File>,
     sender: Sender<CheckpointUpdates>,
     checkpoint_buf_offset: usize,
+    content_file_magic: u32,
     file_compression: FileCompression,
     storage_format: StorageFormat,
     manifest: Manifest,
@@ -72,6 +81,7 @@ struct CheckpointWriter {
 impl CheckpointWriter {
     fn new(
         root_dir_path: PathBuf,
+        content_file_magic: u32,
         file_compression: FileCompression,
         storage_format: StorageFormat,
         sender: Sender<CheckpointUpdates>,
@@ -90,7 +100,7 @@ impl CheckpointWriter {
             &epoch_dir,
             checkpoint_sequence_num,
             CHECKPOINT_FILE_SUFFIX,
-            CHECKPOINT_FILE_MAGIC,
+            content_file_magic,
             storage_format,
             file_compression,
         )?;
@@ -109,6 +119,7 @@ impl CheckpointWriter {
             wbuf: BufWriter::new(checkpoint_file),
             summary_wbuf: BufWriter::new(summary_file),
             checkpoint_buf_offset: 0,
+            content_file_magic,
             sender,
             file_compression,
             storage_format,
@@ -124,14 +135,28 @@ impl CheckpointWriter {
         checkpoint_contents: CheckpointContents,
         checkpoint_summary: Checkpoint,
     ) -> Result<()> {
+        let content_digest = *checkpoint_contents.checkpoint_contents().digest();
         match self.storage_format {
-            StorageFormat::Blob => self.write_as_blob(checkpoint_contents, checkpoint_summary),
+            StorageFormat::Blob => {
+                self.write_as_blob(&checkpoint_contents, content_digest, checkpoint_summary)
+            }
         }
     }
 
-    pub fn write_as_blob(
+    pub fn write_checkpoint_data(&mut self, checkpoint_data: CheckpointData) -> Result<()> {
+        let content_digest = *checkpoint_data.checkpoint_contents.digest();
+        let checkpoint_summary = checkpoint_data.checkpoint_summary.clone();
+        match self.storage_format {
+            StorageFormat::Blob => {
+                self.write_as_blob(&checkpoint_data, content_digest, checkpoint_summary)
+            }
+        }
+    }
+
+    pub fn write_as_blob<T: Serialize>(
         &mut self,
-        checkpoint_contents: CheckpointContents,
+        checkpoint_contents: &T,
+        content_digest: CheckpointContentsDigest,
         checkpoint_summary: Checkpoint,
     ) -> Result<()> {
         assert_eq!(
@@ -156,12 +181,9 @@ impl CheckpointWriter {
 
         assert_eq!(checkpoint_summary.epoch, self.epoch_num);
 
-        assert_eq!(
-            checkpoint_summary.content_digest,
-            *checkpoint_contents.checkpoint_contents().digest()
-        );
+        assert_eq!(checkpoint_summary.content_digest, content_digest);
 
-        let contents_blob = Blob::encode(&checkpoint_contents, BlobEncoding::Bcs)?;
+        let contents_blob = Blob::encode(checkpoint_contents, BlobEncoding::Bcs)?;
         let blob_size = contents_blob.size();
         let cut_new_checkpoint_file = (self.checkpoint_buf_offset + blob_size)
             > self.commit_file_size
@@ -271,7 +293,7 @@ impl CheckpointWriter {
             &self.epoch_dir(),
             self.checkpoint_range.start,
             CHECKPOINT_FILE_SUFFIX,
-            CHECKPOINT_FILE_MAGIC,
+            self.content_file_magic,
             self.storage_format,
             self.file_compression,
         )?;
@@ -312,6 +334,7 @@ impl CheckpointWriter {
 /// ArchiveWriter archives history by tailing checkpoints writing them to a local staging dir and
 /// simultaneously uploading them to a remote object store
 pub struct ArchiveWriter {
+    contents: ArchiveContents,
     file_compression: FileCompression,
     storage_format: StorageFormat,
     local_staging_dir_root: PathBuf,
@@ -319,7 +342,7 @@ pub struct ArchiveWriter {
     remote_object_store: Arc<DynObjectStore>,
     commit_duration: Duration,
     commit_file_size: usize,
-    archive_metrics: Arc<ArchiveMetrics>,
+    registry: Registry,
 }
 
 impl ArchiveWriter {
@@ -333,6 +356,7 @@ impl ArchiveWriter {
         registry: &Registry,
     ) -> Result<Self> {
         Ok(ArchiveWriter {
+            contents: ArchiveContents::default(),
             file_compression,
             storage_format,
             remote_object_store: remote_store_config.make()?,
@@ -340,10 +364,18 @@ impl ArchiveWriter {
             local_staging_dir_root: local_store_config.directory.context("Missing local dir")?,
             commit_duration,
             commit_file_size,
-            archive_metrics: ArchiveMetrics::new(registry),
+            registry: registry.clone(),
         })
     }
 
+    /// Archive the given contents of each checkpoint, instead of its full checkpoint contents.
+    /// Checkpoint data is only archived once the checkpoint has been executed, because it includes
+    /// the objects that its transactions wrote.
+    pub fn with_contents(mut self, contents: ArchiveContents) -> Self {
+        self.contents = contents;
+        self
+    }
+
     pub async fn start<S>(&self, store: S) -> Result<tokio::sync::broadcast::Sender<()>>
     where
         S: WriteStore + Send + Sync + 'static,
@@ -367,6 +399,7 @@ impl ArchiveWriter {
         let (sender, receiver) = mpsc::channel::<CheckpointUpdates>(100);
         let checkpoint_writer = CheckpointWriter::new(
             self.local_staging_dir_root.clone(),
+            self.contents.content_file_magic(),
             self.file_compression,
             self.storage_format,
             sender,
@@ -375,6 +408,7 @@ impl ArchiveWriter {
             self.commit_file_size,
         )
         .expect("Failed to create checkpoint writer");
+        let contents = self.contents;
         let (kill_sender, kill_receiver) = tokio::sync::broadcast::channel::<()>(1);
         tokio::spawn(Self::start_syncing_with_remote(
             self.remote_object_store.clone(),
@@ -382,11 +416,12 @@ impl ArchiveWriter {
             self.local_staging_dir_root.clone(),
             receiver,
             kill_sender.subscribe(),
-            self.archive_metrics.clone(),
+            ArchiveMetrics::new(&self.registry, contents),
         ));
         tokio::task::spawn_blocking(move || {
             Self::start_tailing_checkpoints(
                 start_checkpoint_sequence_number,
+                contents,
                 checkpoint_writer,
                 store,
                 kill_receiver,
@@ -397,6 +432,7 @@ impl ArchiveWriter {
 
     fn start_tailing_checkpoints<S>(
         start_checkpoint_sequence_number: CheckpointSequenceNumber,
+        contents: ArchiveContents,
         mut checkpoint_writer: CheckpointWriter,
         store: S,
         mut kill: tokio::sync::broadcast::Receiver<()>,
@@ -408,22 +444,17 @@ impl ArchiveWriter {
         info!("Starting checkpoint tailing from sequence number: {checkpoint_sequence_number}");
 
         while kill.try_recv().is_err() {
-            if let Some(checkpoint_summary) = store
-                .get_checkpoint_by_sequence_number(checkpoint_sequence_number)
-                .map_err(|_| anyhow!("Failed to read checkpoint summary from store"))?
-            {
-                if let Some(checkpoint_contents) = store
-                    .get_full_checkpoint_contents(&checkpoint_summary.content_digest)
-                    .map_err(|_| anyhow!("Failed to read checkpoint content from store"))?
-                {
-                    checkpoint_writer
-                        .write(checkpoint_contents, checkpoint_summary.into_inner())?;
-                    checkpoint_sequence_number = checkpoint_sequence_number
-                        .checked_add(1)
-                        .context("checkpoint seq number overflow")?;
-                    // There is more checkpoints to tail, so continue without sleeping
-                    continue;
-                }
+            if Self::write_checkpoint(
+                checkpoint_sequence_number,
+                contents,
+                &mut checkpoint_writer,
+                &store,
+            )? {
+                checkpoint_sequence_number = checkpoint_sequence_number
+                    .checked_add(1)
+                    .context("checkpoint seq number overflow")?;
+                // There is more checkpoints to tail, so continue without sleeping
+                continue;
             }
             // Checkpoint with `checkpoint_sequence_number` is not available to read from store yet,
             // sleep for sometime and then retry
@@ -432,6 +463,54 @@ impl ArchiveWriter {
         Ok(())
     }
 
+    /// Write the checkpoint with `checkpoint_sequence_number` to the archive, returning false if it
+    /// is not available to read from the store yet.
+    fn write_checkpoint<S>(
+        checkpoint_sequence_number: CheckpointSequenceNumber,
+        contents: ArchiveContents,
+        checkpoint_writer: &mut CheckpointWriter,
+        store: &S,
+    ) -> Result<bool>
+    where
+        S: WriteStore + Send + Sync + 'static,
+    {
+        let Some(checkpoint_summary) = store
+            .get_checkpoint_by_sequence_number(checkpoint_sequence_number)
+            .map_err(|_| anyhow!("Failed to read checkpoint summary from store"))?
+        else {
+            return Ok(false);
+        };
+        let Some(checkpoint_contents) = store
+            .get_full_checkpoint_contents(&checkpoint_summary.content_digest)
+            .map_err(|_| anyhow!("Failed to read checkpoint content from store"))?
+        else {
+            return Ok(false);
+        };
+
+        match contents {
+            ArchiveContents::Checkpoints => {
+                checkpoint_writer.write(checkpoint_contents, checkpoint_summary.into_inner())?
+            }
+            ArchiveContents::CheckpointData => {
+                // The objects written by the checkpoint's transactions are missing until the
+                // checkpoint has been executed
+                match store.get_checkpoint_data(
+                    checkpoint_summary,
+                    checkpoint_contents.into_checkpoint_contents(),
+                ) {
+                    Ok(checkpoint_data) => {
+                        checkpoint_writer.write_checkpoint_data(checkpoint_data)?
+                    }
+                    Err(e) => {
+                        debug!("Checkpoint data for {checkpoint_sequence_number} is not available yet: {e}");
+                        return Ok(false);
+                    }
+                }
+            }
+        }
+        Ok(true)
+    }
+
     async fn start_syncing_with_remote(
         remote_object_store: Arc<DynObjectStore>,
         local_object_store: Arc<DynObjectStore>,
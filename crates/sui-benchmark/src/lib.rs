@@ -361,6 +361,7 @@ impl ValidatorProxy for LocalValidatorAggregatorProxy {
                         include_input_objects: false,
                         include_output_objects: false,
                         include_auxiliary_data: false,
+                        priority: Default::default(),
                     },
                 )
                 .await?;
@@ -461,15 +461,17 @@ mod test {
         let checkpoint_budget_factor; // The checkpoint congestion control budget in respect to transaction budget.
         let txn_count_limit; // When using transaction count as congestion control mode, the limit of transactions per object per commit.
         let max_deferral_rounds;
+        let observed_cost_weight_bps; // When using observed execution cost, the weight of each new observation.
         {
             let mut rng = thread_rng();
-            mode = if rng.gen_bool(0.5) {
-                PerObjectCongestionControlMode::TotalGasBudget
-            } else {
-                PerObjectCongestionControlMode::TotalTxCount
+            mode = match rng.gen_range(0..3) {
+                0 => PerObjectCongestionControlMode::TotalGasBudget,
+                1 => PerObjectCongestionControlMode::TotalTxCount,
+                _ => PerObjectCongestionControlMode::ObservedExecutionCost,
             };
             checkpoint_budget_factor = rng.gen_range(1..20);
             txn_count_limit = rng.gen_range(1..=10);
+            observed_cost_weight_bps = rng.gen_range(1..=10_000);
             max_deferral_rounds = if rng.gen_bool(0.5) {
                 rng.gen_range(0..20) // Short deferral round (testing cancellation)
             } else {
@@ -481,8 +483,13 @@ mod test {
             "test_simulated_load_shared_object_congestion_control setup.
              mode: {:?}, checkpoint_budget_factor: {:?},
              max_deferral_rounds: {:?},
-             txn_count_limit: {:?}",
-            mode, checkpoint_budget_factor, max_deferral_rounds, txn_count_limit
+             txn_count_limit: {:?},
+             observed_cost_weight_bps: {:?}",
+            mode,
+            checkpoint_budget_factor,
+            max_deferral_rounds,
+            txn_count_limit,
+            observed_cost_weight_bps
         );
 
         let _guard = ProtocolConfig::apply_overrides_for_testing(move |_, mut config| {
@@ -496,6 +503,14 @@ mod test {
                     config.set_max_accumulated_txn_cost_per_object_in_narwhal_commit_for_testing(total_gas_limit);
                     config.set_max_accumulated_txn_cost_per_object_in_mysticeti_commit_for_testing(total_gas_limit);
                 },
+                PerObjectCongestionControlMode::ObservedExecutionCost => {
+                    let total_gas_limit = checkpoint_budget_factor
+                        * DEFAULT_VALIDATOR_GAS_PRICE
+                        * TEST_ONLY_GAS_UNIT_FOR_HEAVY_COMPUTATION_STORAGE;
+                    config.set_max_accumulated_txn_cost_per_object_in_narwhal_commit_for_testing(total_gas_limit);
+                    config.set_max_accumulated_txn_cost_per_object_in_mysticeti_commit_for_testing(total_gas_limit);
+                    config.set_observed_execution_cost_ewma_weight_bps_for_testing(observed_cost_weight_bps);
+                },
                 PerObjectCongestionControlMode::TotalTxCount => {
                     config.set_max_accumulated_txn_cost_per_object_in_narwhal_commit_for_testing(
                         txn_count_limit
@@ -1,10 +1,9 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use once_cell::sync::OnceCell;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
-use sui_types::base_types::TransactionDigest;
+use std::collections::{BTreeMap, HashSet};
+use sui_types::base_types::{EpochId, TransactionDigest};
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[serde(rename_all = "kebab-case")]
@@ -22,9 +21,34 @@ pub struct CertificateDenyConfig {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     certificate_deny_list: Vec<TransactionDigest>,
 
-    /// In-memory cache for faster lookup of the certificate deny list.
-    #[serde(skip)]
-    certificate_deny_set: OnceCell<HashSet<TransactionDigest>>,
+    /// The last epoch in which each entry of `certificate_deny_list` applies. Entries without an
+    /// expiry apply in every epoch. Certificates executed in later epochs are executed normally,
+    /// so an expiry must only be set once a fix for the issue is live on every validator.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    certificate_deny_expiry: BTreeMap<TransactionDigest, EpochId>,
+}
+
+/// A single change to a `CertificateDenyConfig`, made while the node is running.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CertificateDenyConfigUpdate {
+    /// Deny a certificate, up to and including `last_epoch` if one is given, or in every epoch
+    /// otherwise. Denying a certificate that is already denied replaces its expiry.
+    DenyCertificate {
+        digest: TransactionDigest,
+        last_epoch: Option<EpochId>,
+    },
+    AllowCertificate(TransactionDigest),
+}
+
+impl CertificateDenyConfigUpdate {
+    /// A short name for the kind of update, used as a metric label.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::DenyCertificate { .. } => "deny-certificate",
+            Self::AllowCertificate(_) => "allow-certificate",
+        }
+    }
 }
 
 impl CertificateDenyConfig {
@@ -32,13 +56,50 @@ impl CertificateDenyConfig {
         Self::default()
     }
 
-    pub fn certificate_deny_set(&self) -> &HashSet<TransactionDigest> {
-        self.certificate_deny_set.get_or_init(|| {
-            self.certificate_deny_list
-                .iter()
-                .cloned()
-                .collect::<HashSet<_>>()
-        })
+    /// The certificates denied in `epoch`, i.e. the entries that have not expired by then.
+    pub fn certificate_deny_set(&self, epoch: EpochId) -> HashSet<TransactionDigest> {
+        self.certificate_deny_list
+            .iter()
+            .filter(|digest| {
+                self.certificate_deny_expiry
+                    .get(digest)
+                    .map_or(true, |last_epoch| epoch <= *last_epoch)
+            })
+            .cloned()
+            .collect()
+    }
+
+    pub fn certificate_deny_list(&self) -> &[TransactionDigest] {
+        &self.certificate_deny_list
+    }
+
+    /// Apply `update` to this config, returning whether it changed anything. Denying a certificate
+    /// that is already denied with the same expiry, or allowing one that is not denied, are no-ops.
+    pub fn apply(&mut self, update: &CertificateDenyConfigUpdate) -> bool {
+        match update {
+            CertificateDenyConfigUpdate::DenyCertificate { digest, last_epoch } => {
+                let mut changed = false;
+                if !self.certificate_deny_list.contains(digest) {
+                    self.certificate_deny_list.push(*digest);
+                    changed = true;
+                }
+
+                changed |= match last_epoch {
+                    Some(last_epoch) => {
+                        self.certificate_deny_expiry.insert(*digest, *last_epoch)
+                            != Some(*last_epoch)
+                    }
+                    None => self.certificate_deny_expiry.remove(digest).is_some(),
+                };
+                changed
+            }
+            CertificateDenyConfigUpdate::AllowCertificate(digest) => {
+                let len = self.certificate_deny_list.len();
+                self.certificate_deny_list.retain(|d| d != digest);
+                self.certificate_deny_expiry.remove(digest);
+                self.certificate_deny_list.len() != len
+            }
+        }
     }
 }
 
@@ -60,4 +121,50 @@ impl CertificateDenyConfigBuilder {
         self.config.certificate_deny_list.push(certificate);
         self
     }
+
+    pub fn add_certificate_deny_until_epoch(
+        mut self,
+        certificate: TransactionDigest,
+        last_epoch: EpochId,
+    ) -> Self {
+        self.config.certificate_deny_list.push(certificate);
+        self.config
+            .certificate_deny_expiry
+            .insert(certificate, last_epoch);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_updates_and_expiry() {
+        let digest = TransactionDigest::random();
+        let mut config = CertificateDenyConfigBuilder::new().build();
+
+        let deny = CertificateDenyConfigUpdate::DenyCertificate {
+            digest,
+            last_epoch: Some(3),
+        };
+        assert!(config.apply(&deny));
+        assert!(!config.apply(&deny));
+        assert!(config.certificate_deny_set(3).contains(&digest));
+        assert!(config.certificate_deny_set(4).is_empty());
+
+        // Denying it again without an expiry makes it apply indefinitely.
+        let deny = CertificateDenyConfigUpdate::DenyCertificate {
+            digest,
+            last_epoch: None,
+        };
+        assert!(config.apply(&deny));
+        assert!(config.certificate_deny_set(4).contains(&digest));
+        assert_eq!(config.certificate_deny_list(), &[digest]);
+
+        let allow = CertificateDenyConfigUpdate::AllowCertificate(digest);
+        assert!(config.apply(&allow));
+        assert!(!config.apply(&allow));
+        assert!(config.certificate_deny_set(0).is_empty());
+    }
 }
@@ -7,7 +7,7 @@ use crate::p2p::P2pConfig;
 use crate::transaction_deny_config::TransactionDenyConfig;
 use crate::verifier_signing_config::VerifierSigningConfig;
 use crate::Config;
-use anyhow::Result;
+use anyhow::{ensure, Result};
 use consensus_config::Parameters as ConsensusParameters;
 use narwhal_config::Parameters as NarwhalParameters;
 use once_cell::sync::OnceCell;
@@ -15,7 +15,7 @@ use rand::rngs::OsRng;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use std::collections::{BTreeMap, BTreeSet};
-use std::net::SocketAddr;
+use std::net::{IpAddr, SocketAddr};
 use std::num::NonZeroUsize;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -28,6 +28,7 @@ use sui_types::crypto::KeypairTraits;
 use sui_types::crypto::NetworkKeyPair;
 use sui_types::crypto::SuiKeyPair;
 use sui_types::messages_checkpoint::CheckpointSequenceNumber;
+use sui_types::quorum_driver_types::TransactionPriority;
 use sui_types::supported_protocol_versions::{Chain, SupportedProtocolVersions};
 use sui_types::traffic_control::{PolicyConfig, RemoteFirewallConfig};
 
@@ -154,6 +155,11 @@ pub struct NodeConfig {
     #[serde(default)]
     pub state_archive_read_config: Vec<StateArchiveConfig>,
 
+    /// When specified, the data of every executed checkpoint, including the objects that its
+    /// transactions wrote, is archived to this store, for fullnodes to fast sync from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checkpoint_data_archive_write_config: Option<ObjectStoreConfig>,
+
     #[serde(default)]
     pub state_snapshot_write_config: StateSnapshotConfig,
 
@@ -206,6 +212,25 @@ pub struct NodeConfig {
     /// By default, write stall is enabled on validators but not on fullnodes.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub enable_db_write_stall: Option<bool>,
+
+    /// If set, a fullnode keeps an index of past object versions covering a window of recent
+    /// epochs, to serve past object reads and reads as of a checkpoint after the versions have
+    /// been pruned from the live store.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archival_index: Option<ArchivalIndexConfig>,
+
+    /// Overrides of the settings of a fullnode's transaction execution lanes, by priority.
+    /// Priorities that are not listed use `QuorumDriverLaneConfig::default_for`. The node refuses
+    /// to start unless the lanes pass `QuorumDriverLaneConfig::validate_lanes`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub quorum_driver_lanes: BTreeMap<TransactionPriority, QuorumDriverLaneConfig>,
+
+    /// Clients whose transaction execution requests to the JSON-RPC and REST APIs may set their
+    /// priority, through the `x-sui-transaction-priority` header. Requests from other clients are
+    /// executed at normal priority. Clients are identified by their socket address, or for
+    /// JSON-RPC behind a proxy, by `x-forwarded-for` if `policy-config` is set to read it.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub transaction_priority_allowlist: Vec<IpAddr>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -214,8 +239,15 @@ pub enum ExecutionCacheConfig {
     PassthroughCache,
     WritebackCache {
         /// Maximum number of entries in each cache. (There are several different caches).
-        /// If None, the default of 10000 is used.
+        /// If None, the default of 10000 is used. Ignored if `memory_budget_bytes` is set.
         max_cache_size: Option<usize>,
+
+        /// Approximate total size in bytes of the committed data held by the caches, which is
+        /// divided between them in fixed proportions. Entries are weighed by their size, so the
+        /// number of entries each cache holds depends on what it holds. Uncommitted data is not
+        /// counted, as it cannot be evicted.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        memory_budget_bytes: Option<u64>,
     },
 }
 
@@ -223,6 +255,7 @@ impl Default for ExecutionCacheConfig {
     fn default() -> Self {
         ExecutionCacheConfig::WritebackCache {
             max_cache_size: None,
+            memory_budget_bytes: None,
         }
     }
 }
@@ -412,6 +445,10 @@ impl NodeConfig {
         self.db_path.join("archive")
     }
 
+    pub fn checkpoint_data_archive_path(&self) -> PathBuf {
+        self.db_path.join("checkpoint_data_archive")
+    }
+
     pub fn snapshot_path(&self) -> PathBuf {
         self.db_path.join("snapshot")
     }
@@ -552,6 +589,46 @@ pub struct CheckpointExecutorConfig {
     /// When specified, each executed checkpoint will be saved in a local directory for post processing
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_ingestion_dir: Option<PathBuf>,
+
+    /// When specified, checkpoints up to a target are synced by applying their effects from an
+    /// archive of full checkpoint data, instead of executing their transactions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fast_sync: Option<FastSyncConfig>,
+}
+
+/// Fast sync is meant for fullnodes that have been bootstrapped from a formal snapshot, and need to
+/// catch up from the end of the snapshot's epoch. The outputs of each transaction are checked
+/// against the effects digests of the certified checkpoint, and the state accumulator root is
+/// checked at the end of each epoch, but the transactions are not executed. Fast sync is not
+/// supported on validators.
+///
+/// Fast sync refuses to start unless the node's state derives from a trusted root: genesis, within
+/// the first epoch, or otherwise the live object set committed to at the end of the last completed
+/// epoch, such as that of the formal snapshot the node was restored from. The node halts if the
+/// state at the end of a fast synced epoch does not match its certified root.
+///
+/// Regular checkpoint archives only hold checkpoint summaries and contents, not the objects that
+/// transactions wrote, so fast sync reads full checkpoint data instead.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct FastSyncConfig {
+    /// Where to read full checkpoint data from. Checkpoints that are missing from it are executed
+    /// instead.
+    pub checkpoint_data_source: CheckpointDataSource,
+
+    /// The last checkpoint to fast sync. Checkpoints after it are executed as normal.
+    pub target_checkpoint: CheckpointSequenceNumber,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CheckpointDataSource {
+    /// A store with each checkpoint in a `<sequence number>.chk` file, as written to
+    /// `data-ingestion-dir` by the data ingestion pipeline.
+    ObjectStore(ObjectStoreConfig),
+    /// A checkpoint data archive, as written by nodes with `checkpoint-data-archive-write-config`
+    /// set.
+    Archive(ObjectStoreConfig),
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -652,8 +729,121 @@ impl Default for CheckpointExecutorConfig {
             checkpoint_execution_max_concurrency: default_checkpoint_execution_max_concurrency(),
             local_execution_timeout_sec: default_local_execution_timeout_sec(),
             data_ingestion_dir: None,
+            fast_sync: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ArchivalIndexConfig {
+    /// number of epochs before the current one to keep past object versions for. The index is
+    /// pruned independently of the live store, so this may be longer than the live store's
+    /// `num-epochs-to-retain`.
+    pub num_epochs_to_retain: u64,
+    /// time interval used by the archival index pruner to check whether the retention window has
+    /// moved
+    #[serde(default = "default_archival_index_pruning_period_secs")]
+    pub pruning_period_secs: u64,
+}
+
+fn default_archival_index_pruning_period_secs() -> u64 {
+    3600
+}
+
+/// Maximum number of transactions the QuorumDriver processes at a time, shared by all of its
+/// priority lanes.
+pub const QUORUM_DRIVER_MAX_CONCURRENCY: usize = 2500;
+/// Number of the shared permits that the normal and low priority lanes together can never take,
+/// so that high priority tasks never wait behind a saturated QuorumDriver.
+pub const QUORUM_DRIVER_HIGH_PRIORITY_HEADROOM: usize = 200;
+const QUORUM_DRIVER_QUEUE_SIZE: usize = 2000;
+const QUORUM_DRIVER_MAX_RETRY_TIMES: u32 = 10;
+
+/// Queue, concurrency and retry settings of one priority lane of the QuorumDriver.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct QuorumDriverLaneConfig {
+    /// Capacity of the lane's task queue.
+    pub queue_size: usize,
+    /// Maximum number of tasks of the lane processed concurrently. Tasks of all lanes also share
+    /// the `QUORUM_DRIVER_MAX_CONCURRENCY` limit.
+    pub max_concurrency: usize,
+    /// Number of retries before a transient failure is reported to the client.
+    pub max_retry_times: u32,
+}
+
+impl QuorumDriverLaneConfig {
+    pub fn default_for(priority: TransactionPriority) -> Self {
+        match priority {
+            // High priority tasks can use the whole shared limit, and are retried for longer.
+            TransactionPriority::High => Self {
+                queue_size: QUORUM_DRIVER_QUEUE_SIZE,
+                max_concurrency: QUORUM_DRIVER_MAX_CONCURRENCY,
+                max_retry_times: 2 * QUORUM_DRIVER_MAX_RETRY_TIMES,
+            },
+            // Normal and low priority tasks together can take at most 2300 of the 2500 shared
+            // permits, leaving the headroom to high priority tasks.
+            TransactionPriority::Normal => Self {
+                queue_size: QUORUM_DRIVER_QUEUE_SIZE,
+                max_concurrency: 2000,
+                max_retry_times: QUORUM_DRIVER_MAX_RETRY_TIMES,
+            },
+            TransactionPriority::Low => Self {
+                queue_size: QUORUM_DRIVER_QUEUE_SIZE,
+                max_concurrency: 300,
+                max_retry_times: QUORUM_DRIVER_MAX_RETRY_TIMES,
+            },
         }
     }
+
+    /// Check the settings of every lane, with `overrides` applied to the defaults. Each lane must
+    /// be able to process tasks within the shared limit, and the normal and low priority lanes
+    /// together must leave `QUORUM_DRIVER_HIGH_PRIORITY_HEADROOM` of it to high priority tasks.
+    pub fn validate_lanes(overrides: &BTreeMap<TransactionPriority, Self>) -> Result<()> {
+        let lane = |priority| {
+            overrides
+                .get(&priority)
+                .copied()
+                .unwrap_or_else(|| Self::default_for(priority))
+        };
+
+        for priority in [
+            TransactionPriority::High,
+            TransactionPriority::Normal,
+            TransactionPriority::Low,
+        ] {
+            let config = lane(priority);
+            ensure!(
+                config.queue_size > 0 && config.max_concurrency > 0,
+                "{priority:?} priority lane must have a non-empty queue and concurrency limit"
+            );
+            ensure!(
+                config.max_concurrency <= QUORUM_DRIVER_MAX_CONCURRENCY,
+                "{priority:?} priority lane concurrency {} exceeds the shared limit of {}",
+                config.max_concurrency,
+                QUORUM_DRIVER_MAX_CONCURRENCY,
+            );
+        }
+
+        let high = lane(TransactionPriority::High).max_concurrency;
+        ensure!(
+            high >= QUORUM_DRIVER_HIGH_PRIORITY_HEADROOM,
+            "High priority lane concurrency {high} cannot use the headroom of {} reserved for it",
+            QUORUM_DRIVER_HIGH_PRIORITY_HEADROOM,
+        );
+
+        let others = lane(TransactionPriority::Normal).max_concurrency
+            + lane(TransactionPriority::Low).max_concurrency;
+        ensure!(
+            others <= QUORUM_DRIVER_MAX_CONCURRENCY - QUORUM_DRIVER_HIGH_PRIORITY_HEADROOM,
+            "Normal and low priority lanes together may process {others} tasks at a time, which \
+             leaves less than the {} of the shared limit of {} reserved for high priority",
+            QUORUM_DRIVER_HIGH_PRIORITY_HEADROOM,
+            QUORUM_DRIVER_MAX_CONCURRENCY,
+        );
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -1135,8 +1325,10 @@ mod tests {
     use sui_keys::keypair_file::{write_authority_keypair_to_file, write_keypair_to_file};
     use sui_types::crypto::{get_key_pair_from_rng, AuthorityKeyPair, NetworkKeyPair, SuiKeyPair};
 
-    use super::Genesis;
+    use super::{Genesis, QuorumDriverLaneConfig, QUORUM_DRIVER_MAX_CONCURRENCY};
     use crate::NodeConfig;
+    use std::collections::BTreeMap;
+    use sui_types::quorum_driver_types::TransactionPriority;
 
     #[test]
     fn serialize_genesis_from_file() {
@@ -1155,6 +1347,44 @@ mod tests {
         let _template: NodeConfig = serde_yaml::from_str(TEMPLATE).unwrap();
     }
 
+    #[test]
+    fn validate_quorum_driver_lanes() {
+        QuorumDriverLaneConfig::validate_lanes(&BTreeMap::new()).unwrap();
+
+        // Normal priority tasks may not take the headroom reserved for high priority.
+        let normal = QuorumDriverLaneConfig {
+            max_concurrency: 2300,
+            ..QuorumDriverLaneConfig::default_for(TransactionPriority::Normal)
+        };
+        let overrides = BTreeMap::from([(TransactionPriority::Normal, normal)]);
+        QuorumDriverLaneConfig::validate_lanes(&overrides).unwrap_err();
+
+        // Unless low priority tasks give up their share.
+        let low = QuorumDriverLaneConfig {
+            max_concurrency: 1,
+            ..QuorumDriverLaneConfig::default_for(TransactionPriority::Low)
+        };
+        let overrides = BTreeMap::from([
+            (
+                TransactionPriority::Normal,
+                QuorumDriverLaneConfig {
+                    max_concurrency: 2299,
+                    ..normal
+                },
+            ),
+            (TransactionPriority::Low, low),
+        ]);
+        QuorumDriverLaneConfig::validate_lanes(&overrides).unwrap();
+
+        // No lane may exceed the shared limit.
+        let high = QuorumDriverLaneConfig {
+            max_concurrency: QUORUM_DRIVER_MAX_CONCURRENCY + 1,
+            ..QuorumDriverLaneConfig::default_for(TransactionPriority::High)
+        };
+        let overrides = BTreeMap::from([(TransactionPriority::High, high)]);
+        QuorumDriverLaneConfig::validate_lanes(&overrides).unwrap_err();
+    }
+
     #[test]
     fn load_key_pairs_to_node_config() {
         let protocol_key_pair: AuthorityKeyPair =
@@ -24,11 +24,16 @@ pub struct TransactionDenyConfig {
     /// in the used package, when a package ID is denied, newer versions of that package are
     /// still allowed. If we want to deny the entire upgrade family of a package, we need to
     /// explicitly specify all the package IDs in the deny list.
-    /// TODO: We could consider making this more flexible, e.g. whether to check in type args,
-    /// whether to block entire upgrade family, whether to allow upgrade and etc.
+    /// For more control over how a package is matched, use `package_deny_rules` instead.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     package_deny_list: Vec<ObjectID>,
 
+    /// Rules for denying the use of packages that can match a whole upgrade family, uses of the
+    /// package's types as type arguments, or only specific entry points, and that can exempt
+    /// some senders. See `PackageDenyRule`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    package_deny_rules: Vec<PackageDenyRule>,
+
     /// A list of sui addresses that are not allowed to be used as the sender or sponsor.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     address_deny_list: Vec<SuiAddress>,
@@ -75,7 +80,227 @@ pub struct TransactionDenyConfig {
     // TODO: We could also consider disable more types of commands, such as transfer, split and etc.
 }
 
+/// A rule for denying transactions that use a package.
+///
+/// By default, a rule matches the package with exactly the ID in `package`, and denies calling
+/// into it, upgrading it, and depending on it (directly or transitively).
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct PackageDenyRule {
+    /// The package to deny. When `upgrade-family` is set, this should be the original ID of the
+    /// package (the ID of its first version).
+    pub package: ObjectID,
+
+    /// Whether to match every version of the package, rather than only the version with ID
+    /// `package`.
+    #[serde(default)]
+    pub upgrade_family: bool,
+
+    /// Whether to also deny transactions that use the package's types as type arguments, in Move
+    /// calls or when making Move vectors.
+    #[serde(default)]
+    pub type_arguments: bool,
+
+    /// If not empty, only deny calls to these entry points of the package, given as
+    /// `module::function`. Depending on the package, or upgrading it, is then still allowed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub functions: Vec<String>,
+
+    /// Senders that the rule does not apply to.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_senders: Vec<SuiAddress>,
+}
+
+impl PackageDenyRule {
+    pub fn new(package: ObjectID) -> Self {
+        Self {
+            package,
+            upgrade_family: false,
+            type_arguments: false,
+            functions: vec![],
+            allowed_senders: vec![],
+        }
+    }
+
+    /// Whether the rule applies to transactions sent by `sender`.
+    pub fn applies_to(&self, sender: &SuiAddress) -> bool {
+        !self.allowed_senders.contains(sender)
+    }
+
+    /// Whether the rule matches the package with ID `id`, whose original ID is `original_id`.
+    pub fn matches_package(&self, id: &ObjectID, original_id: &ObjectID) -> bool {
+        if self.upgrade_family {
+            self.package == *original_id
+        } else {
+            self.package == *id
+        }
+    }
+
+    /// Whether the rule denies any use of the package, rather than only calls to some of its
+    /// functions.
+    pub fn denies_all_uses(&self) -> bool {
+        self.functions.is_empty()
+    }
+
+    /// Whether the rule denies calling `module::function`, in a package that it matches.
+    pub fn matches_function(&self, module: &str, function: &str) -> bool {
+        self.denies_all_uses()
+            || self
+                .functions
+                .iter()
+                .any(|f| f.split_once("::") == Some((module, function)))
+    }
+}
+
+/// A single change to a `TransactionDenyConfig`, made while the node is running.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TransactionDenyConfigUpdate {
+    DenyObject(ObjectID),
+    AllowObject(ObjectID),
+    DenyPackage(ObjectID),
+    AllowPackage(ObjectID),
+    DenyAddress(SuiAddress),
+    AllowAddress(SuiAddress),
+    /// Add a package deny rule.
+    AddPackageRule(PackageDenyRule),
+    /// Remove a package deny rule. It must be equal to the rule that was added.
+    RemovePackageRule(PackageDenyRule),
+    DisableZkLoginProvider(String),
+    EnableZkLoginProvider(String),
+    /// Set whether the feature behind a kill switch is disabled.
+    SetSwitch(TransactionDenySwitch, bool),
+}
+
+/// The kill switches in a `TransactionDenyConfig`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TransactionDenySwitch {
+    PackagePublish,
+    PackageUpgrade,
+    SharedObject,
+    UserTransaction,
+    ReceivingObjects,
+    ZkloginSig,
+}
+
+impl TransactionDenyConfigUpdate {
+    /// A short name for the kind of update, used as a metric label.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::DenyObject(_) => "deny-object",
+            Self::AllowObject(_) => "allow-object",
+            Self::DenyPackage(_) => "deny-package",
+            Self::AllowPackage(_) => "allow-package",
+            Self::DenyAddress(_) => "deny-address",
+            Self::AllowAddress(_) => "allow-address",
+            Self::AddPackageRule(_) => "add-package-rule",
+            Self::RemovePackageRule(_) => "remove-package-rule",
+            Self::DisableZkLoginProvider(_) => "disable-zklogin-provider",
+            Self::EnableZkLoginProvider(_) => "enable-zklogin-provider",
+            Self::SetSwitch(..) => "set-switch",
+        }
+    }
+}
+
+impl TransactionDenySwitch {
+    pub const ALL: [TransactionDenySwitch; 6] = [
+        Self::PackagePublish,
+        Self::PackageUpgrade,
+        Self::SharedObject,
+        Self::UserTransaction,
+        Self::ReceivingObjects,
+        Self::ZkloginSig,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::PackagePublish => "package-publish",
+            Self::PackageUpgrade => "package-upgrade",
+            Self::SharedObject => "shared-object",
+            Self::UserTransaction => "user-transaction",
+            Self::ReceivingObjects => "receiving-objects",
+            Self::ZkloginSig => "zklogin-sig",
+        }
+    }
+}
+
 impl TransactionDenyConfig {
+    /// Apply `update` to this config, returning whether it changed anything. Adding an entry that
+    /// is already denied, removing one that is not, or setting a switch to its current value are
+    /// all no-ops.
+    pub fn apply(&mut self, update: &TransactionDenyConfigUpdate) -> bool {
+        use TransactionDenyConfigUpdate as U;
+
+        fn insert<T: PartialEq>(list: &mut Vec<T>, value: T) -> bool {
+            if list.contains(&value) {
+                false
+            } else {
+                list.push(value);
+                true
+            }
+        }
+
+        fn remove<T: PartialEq>(list: &mut Vec<T>, value: &T) -> bool {
+            let len = list.len();
+            list.retain(|v| v != value);
+            list.len() != len
+        }
+
+        let changed = match update {
+            U::DenyObject(id) => insert(&mut self.object_deny_list, *id),
+            U::AllowObject(id) => remove(&mut self.object_deny_list, id),
+            U::DenyPackage(id) => insert(&mut self.package_deny_list, *id),
+            U::AllowPackage(id) => remove(&mut self.package_deny_list, id),
+            U::DenyAddress(addr) => insert(&mut self.address_deny_list, *addr),
+            U::AllowAddress(addr) => remove(&mut self.address_deny_list, addr),
+            U::AddPackageRule(rule) => insert(&mut self.package_deny_rules, rule.clone()),
+            U::RemovePackageRule(rule) => remove(&mut self.package_deny_rules, rule),
+            U::DisableZkLoginProvider(p) => self.zklogin_disabled_providers.insert(p.clone()),
+            U::EnableZkLoginProvider(p) => self.zklogin_disabled_providers.remove(p),
+            U::SetSwitch(switch, disabled) => {
+                let flag = self.switch_mut(*switch);
+                let changed = *flag != *disabled;
+                *flag = *disabled;
+                changed
+            }
+        };
+
+        // The lookup sets are built lazily from the lists, so they need to be rebuilt.
+        if changed {
+            self.object_deny_set = OnceCell::new();
+            self.package_deny_set = OnceCell::new();
+            self.address_deny_set = OnceCell::new();
+        }
+
+        changed
+    }
+
+    /// Whether the feature behind `switch` is disabled.
+    pub fn switch(&self, switch: TransactionDenySwitch) -> bool {
+        use TransactionDenySwitch as S;
+        match switch {
+            S::PackagePublish => self.package_publish_disabled,
+            S::PackageUpgrade => self.package_upgrade_disabled,
+            S::SharedObject => self.shared_object_disabled,
+            S::UserTransaction => self.user_transaction_disabled,
+            S::ReceivingObjects => self.receiving_objects_disabled,
+            S::ZkloginSig => self.zklogin_sig_disabled,
+        }
+    }
+
+    fn switch_mut(&mut self, switch: TransactionDenySwitch) -> &mut bool {
+        use TransactionDenySwitch as S;
+        match switch {
+            S::PackagePublish => &mut self.package_publish_disabled,
+            S::PackageUpgrade => &mut self.package_upgrade_disabled,
+            S::SharedObject => &mut self.shared_object_disabled,
+            S::UserTransaction => &mut self.user_transaction_disabled,
+            S::ReceivingObjects => &mut self.receiving_objects_disabled,
+            S::ZkloginSig => &mut self.zklogin_sig_disabled,
+        }
+    }
+
     pub fn get_object_deny_set(&self) -> &HashSet<ObjectID> {
         self.object_deny_set
             .get_or_init(|| self.object_deny_list.iter().cloned().collect())
@@ -91,6 +316,10 @@ impl TransactionDenyConfig {
             .get_or_init(|| self.address_deny_list.iter().cloned().collect())
     }
 
+    pub fn package_deny_rules(&self) -> &[PackageDenyRule] {
+        &self.package_deny_rules
+    }
+
     pub fn package_publish_disabled(&self) -> bool {
         self.package_publish_disabled
     }
@@ -174,6 +403,11 @@ impl TransactionDenyConfigBuilder {
         self
     }
 
+    pub fn add_package_deny_rule(mut self, rule: PackageDenyRule) -> Self {
+        self.config.package_deny_rules.push(rule);
+        self
+    }
+
     pub fn disable_zklogin_sig(mut self) -> Self {
         self.config.zklogin_sig_disabled = true;
         self
@@ -184,3 +418,53 @@ impl TransactionDenyConfigBuilder {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_updates() {
+        let id = ObjectID::random();
+        let mut config = TransactionDenyConfigBuilder::new().build();
+        assert!(config.get_object_deny_set().is_empty());
+
+        assert!(config.apply(&TransactionDenyConfigUpdate::DenyObject(id)));
+        assert!(!config.apply(&TransactionDenyConfigUpdate::DenyObject(id)));
+        assert!(config.get_object_deny_set().contains(&id));
+
+        assert!(config.apply(&TransactionDenyConfigUpdate::AllowObject(id)));
+        assert!(!config.apply(&TransactionDenyConfigUpdate::AllowObject(id)));
+        assert!(config.get_object_deny_set().is_empty());
+
+        let update =
+            TransactionDenyConfigUpdate::SetSwitch(TransactionDenySwitch::PackagePublish, true);
+        assert!(config.apply(&update));
+        assert!(!config.apply(&update));
+        assert!(config.package_publish_disabled());
+    }
+
+    #[test]
+    fn package_rule_matching() {
+        let original = ObjectID::random();
+        let upgraded = ObjectID::random();
+        let sender = SuiAddress::random_for_testing_only();
+
+        let mut rule = PackageDenyRule::new(original);
+        assert!(rule.matches_package(&original, &original));
+        assert!(!rule.matches_package(&upgraded, &original));
+        assert!(rule.matches_function("m", "f"));
+
+        rule.upgrade_family = true;
+        assert!(rule.matches_package(&upgraded, &original));
+
+        rule.functions = vec!["m::f".to_string()];
+        assert!(!rule.denies_all_uses());
+        assert!(rule.matches_function("m", "f"));
+        assert!(!rule.matches_function("m", "g"));
+
+        assert!(rule.applies_to(&sender));
+        rule.allowed_senders = vec![sender];
+        assert!(!rule.applies_to(&sender));
+    }
+}
@@ -0,0 +1,149 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Compares the eviction policy of the writeback cache, W-TinyLFU, with LRU under a skewed
+//! workload, in which a small set of hot objects is read most often, interleaved with scans over
+//! cold objects that are read only once. The hit ratio of each policy is logged alongside the
+//! timings.
+
+use criterion::*;
+
+use std::sync::Arc;
+
+use moka::policy::EvictionPolicy;
+use moka::sync::Cache as MokaCache;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use sui_config::ExecutionCacheConfig;
+use sui_core::execution_cache::cache_sizing::{CacheCollection, CacheSizing, CacheWeight};
+use sui_core::execution_cache::metrics::ExecutionCacheMetrics;
+use sui_types::base_types::{ObjectID, SuiAddress};
+use sui_types::object::Object;
+use tracing::info;
+
+const MEMORY_BUDGET_BYTES: u64 = 16 << 20;
+const HOT_OBJECTS: usize = 100_000;
+const ZIPF_EXPONENT: f64 = 1.0;
+const READS: usize = 200_000;
+/// One in every `SCAN_INTERVAL` reads is of a cold object.
+const SCAN_INTERVAL: usize = 4;
+
+/// Samples indices in `0..n` with probability proportional to `1 / (i + 1)^s`.
+struct Zipf {
+    cumulative: Vec<f64>,
+}
+
+impl Zipf {
+    fn new(n: usize, s: f64) -> Self {
+        let mut total = 0.0;
+        let cumulative = (0..n)
+            .map(|i| {
+                total += 1.0 / ((i + 1) as f64).powf(s);
+                total
+            })
+            .collect();
+        Self { cumulative }
+    }
+
+    fn sample(&self, rng: &mut impl Rng) -> usize {
+        let x = rng.gen::<f64>() * self.cumulative.last().unwrap();
+        self.cumulative.partition_point(|c| *c < x)
+    }
+}
+
+fn workload() -> Vec<ObjectID> {
+    let hot: Vec<_> = (0..HOT_OBJECTS).map(|_| ObjectID::random()).collect();
+    let zipf = Zipf::new(HOT_OBJECTS, ZIPF_EXPONENT);
+    let mut rng = StdRng::seed_from_u64(0);
+
+    (0..READS)
+        .map(|i| {
+            if i % SCAN_INTERVAL == 0 {
+                ObjectID::random()
+            } else {
+                hot[zipf.sample(&mut rng)]
+            }
+        })
+        .collect()
+}
+
+#[derive(Clone, Copy)]
+enum Policy {
+    /// The object cache, as the writeback cache builds it.
+    TinyLfu,
+    /// The same cache, evicting the least recently used entry instead.
+    Lru,
+}
+
+fn build_cache(policy: Policy) -> MokaCache<ObjectID, Object> {
+    let config = ExecutionCacheConfig::WritebackCache {
+        max_cache_size: None,
+        memory_budget_bytes: Some(MEMORY_BUDGET_BYTES),
+    };
+    let sizing = CacheSizing::new(&config);
+    match policy {
+        Policy::TinyLfu => {
+            let metrics = Arc::new(ExecutionCacheMetrics::new(&prometheus::Registry::new()));
+            sizing.build(CacheCollection::Object, &metrics)
+        }
+        Policy::Lru => MokaCache::builder()
+            .eviction_policy(EvictionPolicy::lru())
+            .max_capacity(sizing.capacity_bytes(CacheCollection::Object).unwrap())
+            .weigher(|_: &ObjectID, object: &Object| {
+                let weight = std::mem::size_of::<ObjectID>() + object.cache_weight();
+                u32::try_from(weight).unwrap_or(u32::MAX)
+            })
+            .build(),
+    }
+}
+
+/// Read each object in `reads`, inserting it on a miss as the writeback cache does after reading
+/// from the db. Returns the number of hits.
+fn run(cache: &MokaCache<ObjectID, Object>, reads: &[ObjectID], owner: SuiAddress) -> usize {
+    let mut hits = 0;
+    for id in reads {
+        if cache.get(id).is_some() {
+            hits += 1;
+        } else {
+            cache.insert(*id, Object::with_id_owner_for_testing(*id, owner));
+        }
+    }
+    hits
+}
+
+fn execution_cache_bench(c: &mut Criterion) {
+    telemetry_subscribers::init_for_testing();
+    let reads = workload();
+    let owner = SuiAddress::random_for_testing_only();
+
+    let mut group = c.benchmark_group("execution-cache-eviction");
+    group.throughput(Throughput::Elements(reads.len() as u64));
+
+    for (name, policy) in [("tiny-lfu", Policy::TinyLfu), ("lru", Policy::Lru)] {
+        // Warm the cache up with the first half of the reads, and then measure the hit ratio of
+        // the second half.
+        let (warmup, measured) = reads.split_at(READS / 2);
+        let cache = build_cache(policy);
+        run(&cache, warmup, owner);
+        let hits = run(&cache, measured, owner);
+        cache.run_pending_tasks();
+        info!(
+            "{name}: hit ratio {:.3}, {} entries, {} bytes",
+            hits as f64 / measured.len() as f64,
+            cache.entry_count(),
+            cache.weighted_size(),
+        );
+
+        group.bench_function(name, |b| {
+            b.iter_batched(
+                || build_cache(policy),
+                |cache| run(&cache, &reads, owner),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, execution_cache_bench);
+criterion_main!(benches);
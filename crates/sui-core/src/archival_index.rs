@@ -0,0 +1,602 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! An index of past object versions, kept by archival fullnodes so that they can serve reads of
+//! objects at a past version, or as of a past checkpoint, after those versions have been pruned
+//! from the live store.
+//!
+//! The index covers a window of recent checkpoints. It holds every object version written within
+//! the window, along with the version each modified object had when the window started. Objects
+//! that have not been modified since the window started are not indexed at all, because their
+//! latest version in the live store is also their version at every checkpoint in the window. The
+//! window starts at the first checkpoint of an epoch, and is moved forward by the
+//! `ArchivalIndexPruner`, independently of the pruning of the live store.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use mysten_metrics::spawn_monitored_task;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use sui_config::node::ArchivalIndexConfig;
+use sui_types::base_types::{ObjectID, ObjectRef, SequenceNumber};
+use sui_types::committee::EpochId;
+use sui_types::digests::ObjectDigest;
+use sui_types::full_checkpoint_content::CheckpointData;
+use sui_types::messages_checkpoint::CheckpointSequenceNumber;
+use sui_types::object::Object;
+use sui_types::storage::error::Error as StorageError;
+use sui_types::storage::ObjectKey;
+use tokio::sync::oneshot::{self, Sender};
+use tracing::{debug, error, info};
+use typed_store::rocks::{DBMap, MetricConf};
+use typed_store::traits::Map;
+use typed_store::traits::{TableSummary, TypedStoreDebug};
+use typed_store::DBMapUtils;
+use typed_store::TypedStoreError;
+
+const CURRENT_DB_VERSION: u64 = 1;
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+struct MetadataInfo {
+    /// Version of the Database
+    version: u64,
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Debug)]
+enum Watermark {
+    /// The first checkpoint of the window covered by the index.
+    LowestAvailable,
+    /// The last checkpoint that has been indexed.
+    HighestIndexed,
+}
+
+/// An object version in the index.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Debug)]
+pub enum ArchivedObject {
+    Object(Object),
+    Deleted,
+    Wrapped,
+}
+
+impl ArchivedObject {
+    fn from_tombstone(digest: ObjectDigest) -> Self {
+        if digest.is_wrapped() {
+            ArchivedObject::Wrapped
+        } else {
+            ArchivedObject::Deleted
+        }
+    }
+}
+
+/// The state of an object as of a checkpoint, according to the index.
+#[derive(Clone, Debug)]
+pub enum ArchivedObjectRead {
+    /// The object has not been modified since the window started, so its latest version in the
+    /// live store is its version as of the checkpoint.
+    NotIndexed,
+    /// The object had not been created (or unwrapped) as of the checkpoint.
+    NotExists,
+    Exists(Object),
+    /// The object had been deleted or wrapped as of the checkpoint.
+    Removed(ObjectRef),
+}
+
+/// Tables for the archival index.
+///
+/// NOTE: Authors and Reviewers before adding any new tables ensure that they are pruned by
+/// `prune`, or are bounded by the number of epochs in the window.
+#[derive(DBMapUtils)]
+struct ArchivalIndexTables {
+    /// A singleton that store metadata information on the DB, used to determine whether the DB
+    /// was written by a different version of the index.
+    meta: DBMap<(), MetadataInfo>,
+
+    /// The first and last checkpoints of the window covered by the index.
+    watermarks: DBMap<Watermark, CheckpointSequenceNumber>,
+
+    /// The first checkpoint of each epoch in the window, used to move the start of the window to
+    /// an epoch boundary.
+    epochs: DBMap<EpochId, CheckpointSequenceNumber>,
+
+    /// Every object version written within the window, including deletions and wrapping, as well
+    /// as the versions that modified objects had when the window started.
+    objects: DBMap<ObjectKey, ArchivedObject>,
+
+    /// The version of each object as of the end of each checkpoint that modified it. Objects that
+    /// were modified after the window started also have an entry at the start of the window,
+    /// holding the version they had then.
+    ///
+    /// Allows finding the version of an object as of a checkpoint, by seeking to the entry at or
+    /// before that checkpoint.
+    checkpoint_versions: DBMap<(ObjectID, CheckpointSequenceNumber), SequenceNumber>,
+
+    /// The versions of each object written by each checkpoint, so that they can be pruned along
+    /// with the checkpoint.
+    checkpoint_objects: DBMap<(CheckpointSequenceNumber, ObjectID), Vec<SequenceNumber>>,
+}
+
+impl ArchivalIndexTables {
+    fn open<P: Into<PathBuf>>(path: P) -> Self {
+        ArchivalIndexTables::open_tables_read_write(
+            path.into(),
+            MetricConf::new("archival-index"),
+            None,
+            None,
+        )
+    }
+
+    fn needs_to_delete_old_db(&self) -> bool {
+        match self.meta.get(&()) {
+            Ok(Some(metadata)) => metadata.version != CURRENT_DB_VERSION,
+            Ok(None) => false,
+            Err(_) => true,
+        }
+    }
+
+    fn init(&self) -> Result<(), TypedStoreError> {
+        self.meta.insert(
+            &(),
+            &MetadataInfo {
+                version: CURRENT_DB_VERSION,
+            },
+        )
+    }
+
+    fn get_watermark(
+        &self,
+        watermark: Watermark,
+    ) -> Result<Option<CheckpointSequenceNumber>, TypedStoreError> {
+        self.watermarks.get(&watermark)
+    }
+
+    /// Whether `object_id` has any entries in the index.
+    fn is_indexed(&self, object_id: ObjectID) -> Result<bool, TypedStoreError> {
+        match self
+            .checkpoint_versions
+            .safe_range_iter((object_id, 0)..=(object_id, CheckpointSequenceNumber::MAX))
+            .next()
+        {
+            Some(Ok(_)) => Ok(true),
+            Some(Err(e)) => Err(e),
+            None => Ok(false),
+        }
+    }
+
+    /// The first checkpoint after `checkpoint` that modified `object_id`, if any.
+    fn next_checkpoint_version(
+        &self,
+        object_id: ObjectID,
+        checkpoint: CheckpointSequenceNumber,
+    ) -> Result<Option<CheckpointSequenceNumber>, TypedStoreError> {
+        match self
+            .checkpoint_versions
+            .safe_range_iter(
+                (object_id, checkpoint + 1)..=(object_id, CheckpointSequenceNumber::MAX),
+            )
+            .next()
+        {
+            Some(Ok(((_, next), _))) => Ok(Some(next)),
+            Some(Err(e)) => Err(e),
+            None => Ok(None),
+        }
+    }
+
+    /// Index a Checkpoint. Checkpoints must be indexed in order, with no gaps, starting from any
+    /// checkpoint.
+    fn index_checkpoint(&self, checkpoint: &CheckpointData) -> Result<(), StorageError> {
+        let summary = &checkpoint.checkpoint_summary;
+        let sequence_number = summary.sequence_number;
+
+        match self.get_watermark(Watermark::HighestIndexed)? {
+            // Checkpoints after the highest executed one are processed again after a restart, and
+            // may have been indexed before the node stopped.
+            Some(highest) if sequence_number <= highest => {
+                debug!(checkpoint = sequence_number, "checkpoint already indexed");
+                return Ok(());
+            }
+            Some(highest) if sequence_number != highest + 1 => {
+                return Err(StorageError::custom(format!(
+                    "cannot index checkpoint {sequence_number} after checkpoint {highest}, \
+                     checkpoints must be indexed in order"
+                )));
+            }
+            _ => {}
+        }
+
+        debug!(checkpoint = sequence_number, "indexing checkpoint");
+
+        let lowest_available = self
+            .get_watermark(Watermark::LowestAvailable)?
+            .unwrap_or(sequence_number);
+
+        let mut batch = self.objects.batch();
+
+        // The versions written by this checkpoint, for each object it modified.
+        let mut written: BTreeMap<ObjectID, Vec<SequenceNumber>> = BTreeMap::new();
+        let mut objects = vec![];
+        let mut window_start_versions = vec![];
+
+        for tx in &checkpoint.transactions {
+            let previous_versions = tx
+                .changed_objects()
+                .filter_map(|(_, previous)| previous)
+                .chain(tx.removed_objects_pre_version());
+
+            for object in previous_versions {
+                let id = object.id();
+                if written.contains_key(&id) || self.is_indexed(id)? {
+                    continue;
+                }
+
+                // This is the first time the object has been modified since the window started,
+                // so record the version it had until now.
+                objects.push((
+                    ObjectKey(id, object.version()),
+                    ArchivedObject::Object(object.clone()),
+                ));
+                if lowest_available < sequence_number {
+                    window_start_versions.push((id, object.version()));
+                } else {
+                    written.entry(id).or_default().push(object.version());
+                }
+            }
+
+            for object in &tx.output_objects {
+                written
+                    .entry(object.id())
+                    .or_default()
+                    .push(object.version());
+                objects.push((
+                    ObjectKey(object.id(), object.version()),
+                    ArchivedObject::Object(object.clone()),
+                ));
+            }
+
+            for (id, version, digest) in tx.removed_object_refs_post_version() {
+                written.entry(id).or_default().push(version);
+                objects.push((
+                    ObjectKey(id, version),
+                    ArchivedObject::from_tombstone(digest),
+                ));
+            }
+        }
+
+        batch.insert_batch(&self.objects, objects)?;
+        batch.insert_batch(
+            &self.checkpoint_versions,
+            window_start_versions
+                .iter()
+                .map(|(id, version)| ((*id, lowest_available), *version)),
+        )?;
+        batch.insert_batch(
+            &self.checkpoint_objects,
+            window_start_versions
+                .iter()
+                .map(|(id, version)| ((lowest_available, *id), vec![*version])),
+        )?;
+        batch.insert_batch(
+            &self.checkpoint_versions,
+            written.iter().map(|(id, versions)| {
+                let version = versions.iter().max().expect("versions are never empty");
+                ((*id, sequence_number), *version)
+            }),
+        )?;
+        batch.insert_batch(
+            &self.checkpoint_objects,
+            written
+                .into_iter()
+                .map(|(id, versions)| ((sequence_number, id), versions)),
+        )?;
+
+        if self.epochs.get(&summary.epoch)?.is_none() {
+            batch.insert_batch(&self.epochs, [(summary.epoch, sequence_number)])?;
+        }
+        if summary.end_of_epoch_data.is_some() {
+            batch.insert_batch(&self.epochs, [(summary.epoch + 1, sequence_number + 1)])?;
+        }
+
+        batch.insert_batch(
+            &self.watermarks,
+            [
+                (Watermark::LowestAvailable, lowest_available),
+                (Watermark::HighestIndexed, sequence_number),
+            ],
+        )?;
+        batch.write()?;
+
+        debug!(checkpoint = sequence_number, "finished indexing checkpoint");
+        Ok(())
+    }
+
+    /// The checkpoint to move the start of the window to, so that it starts at the first
+    /// checkpoint of the epoch `num_epochs_to_retain` epochs before the current one, along with
+    /// that epoch. Returns None if the window does not need to move.
+    fn pruning_target(
+        &self,
+        num_epochs_to_retain: u64,
+    ) -> Result<Option<(EpochId, CheckpointSequenceNumber)>, TypedStoreError> {
+        let (Some(lowest_available), Some(highest_indexed)) = (
+            self.get_watermark(Watermark::LowestAvailable)?,
+            self.get_watermark(Watermark::HighestIndexed)?,
+        ) else {
+            return Ok(None);
+        };
+
+        let Some((current_epoch, _)) = self.epochs.safe_iter().skip_to_last().next().transpose()?
+        else {
+            return Ok(None);
+        };
+
+        let first_epoch = current_epoch.saturating_sub(num_epochs_to_retain);
+        let Some(window_start) = self.epochs.get(&first_epoch)? else {
+            // The index does not go back that far yet.
+            return Ok(None);
+        };
+
+        // Always keep the last indexed checkpoint, so that the window is never empty.
+        let window_start = window_start.min(highest_indexed);
+        Ok((window_start > lowest_available).then_some((first_epoch, window_start)))
+    }
+
+    /// Remove the start of epochs before `first_epoch`, which have left the window.
+    fn prune_epochs(&self, first_epoch: EpochId) -> Result<(), TypedStoreError> {
+        let pruned_epochs = self
+            .epochs
+            .safe_range_iter(..first_epoch)
+            .map(|entry| entry.map(|(epoch, _)| epoch))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut batch = self.epochs.batch();
+        batch.delete_batch(&self.epochs, pruned_epochs)?;
+        batch.write()
+    }
+
+    /// Prune the entries for `checkpoint`, keeping the versions that objects had at
+    /// `window_start` as entries at `window_start`.
+    fn prune_checkpoint(
+        &self,
+        checkpoint: CheckpointSequenceNumber,
+        window_start: CheckpointSequenceNumber,
+    ) -> Result<(), TypedStoreError> {
+        let mut batch = self.objects.batch();
+
+        for entry in self
+            .checkpoint_objects
+            .safe_range_iter((checkpoint, ObjectID::ZERO)..=(checkpoint, ObjectID::MAX))
+        {
+            let ((_, id), mut versions) = entry?;
+            let last = *versions.iter().max().expect("versions are never empty");
+
+            match self.next_checkpoint_version(id, checkpoint)? {
+                // The object was modified again at or before the start of the window, so none of
+                // these versions are needed anymore.
+                Some(next) if next <= window_start => (),
+
+                // The object was not modified again until after the start of the window, so its
+                // last version here is its version at the start of the window.
+                Some(_) => {
+                    versions.retain(|version| *version != last);
+                    batch.insert_batch(&self.checkpoint_versions, [((id, window_start), last)])?;
+                    batch.insert_batch(
+                        &self.checkpoint_objects,
+                        [((window_start, id), vec![last])],
+                    )?;
+                }
+
+                // The object has not been modified since, so its last version is still its
+                // latest version, which the live store serves.
+                None => (),
+            }
+
+            batch.delete_batch(
+                &self.objects,
+                versions.into_iter().map(|version| ObjectKey(id, version)),
+            )?;
+            batch.delete_batch(&self.checkpoint_versions, [(id, checkpoint)])?;
+            batch.delete_batch(&self.checkpoint_objects, [(checkpoint, id)])?;
+        }
+
+        batch.insert_batch(
+            &self.watermarks,
+            [(Watermark::LowestAvailable, checkpoint + 1)],
+        )?;
+        batch.write()
+    }
+
+    fn get_object(
+        &self,
+        object_id: ObjectID,
+        version: SequenceNumber,
+    ) -> Result<Option<Object>, TypedStoreError> {
+        Ok(match self.objects.get(&ObjectKey(object_id, version))? {
+            Some(ArchivedObject::Object(object)) => Some(object),
+            Some(ArchivedObject::Deleted | ArchivedObject::Wrapped) | None => None,
+        })
+    }
+
+    fn get_object_as_of_checkpoint(
+        &self,
+        object_id: ObjectID,
+        checkpoint: CheckpointSequenceNumber,
+    ) -> Result<ArchivedObjectRead, StorageError> {
+        let entry = self
+            .checkpoint_versions
+            .safe_range_iter((object_id, 0)..=(object_id, checkpoint))
+            .skip_prior_to(&(object_id, checkpoint))?
+            .reverse()
+            .next()
+            .transpose()?;
+
+        let Some((_, version)) = entry else {
+            // Objects that existed at the start of the window have an entry there as soon as they
+            // are modified, so an object with only later entries did not exist yet.
+            return Ok(if self.is_indexed(object_id)? {
+                ArchivedObjectRead::NotExists
+            } else {
+                ArchivedObjectRead::NotIndexed
+            });
+        };
+
+        let archived = self
+            .objects
+            .get(&ObjectKey(object_id, version))?
+            .ok_or_else(|| {
+                StorageError::missing(format!(
+                    "archival index is missing version {version} of object {object_id}"
+                ))
+            })?;
+
+        Ok(match archived {
+            ArchivedObject::Object(object) => ArchivedObjectRead::Exists(object),
+            ArchivedObject::Deleted => ArchivedObjectRead::Removed((
+                object_id,
+                version,
+                ObjectDigest::OBJECT_DIGEST_DELETED,
+            )),
+            ArchivedObject::Wrapped => ArchivedObjectRead::Removed((
+                object_id,
+                version,
+                ObjectDigest::OBJECT_DIGEST_WRAPPED,
+            )),
+        })
+    }
+}
+
+pub struct ArchivalIndexStore {
+    tables: ArchivalIndexTables,
+    /// Serializes indexing with pruning, so that an object first modified while the window is
+    /// moving is recorded at the start of the window it ends up in.
+    write_lock: Mutex<()>,
+}
+
+impl ArchivalIndexStore {
+    pub fn new(path: PathBuf) -> Self {
+        let tables = ArchivalIndexTables::open(&path);
+
+        // Indexes written by a different version are rebuilt from scratch, starting from the next
+        // checkpoint to be indexed.
+        let tables = if tables.needs_to_delete_old_db() {
+            drop(tables);
+            typed_store::rocks::safe_drop_db(path.clone())
+                .expect("unable to destroy old archival-index db");
+            ArchivalIndexTables::open(path)
+        } else {
+            tables
+        };
+
+        tables.init().expect("unable to initialize archival index");
+
+        Self {
+            tables,
+            write_lock: Mutex::new(()),
+        }
+    }
+
+    pub fn index_checkpoint(&self, checkpoint: &CheckpointData) -> Result<(), StorageError> {
+        let _guard = self.write_lock.lock();
+        self.tables.index_checkpoint(checkpoint)
+    }
+
+    /// Move the start of the window forward to the first checkpoint of the epoch
+    /// `num_epochs_to_retain` epochs before the current one, pruning the checkpoints before it.
+    pub fn prune(&self, num_epochs_to_retain: u64) -> Result<(), TypedStoreError> {
+        let Some((first_epoch, window_start)) = self.tables.pruning_target(num_epochs_to_retain)?
+        else {
+            return Ok(());
+        };
+
+        let Some((lowest_available, _)) = self.available_checkpoints()? else {
+            return Ok(());
+        };
+
+        info!(
+            "Pruning archival index checkpoints {lowest_available} to {window_start}, to retain \
+             epochs from {first_epoch}"
+        );
+
+        // Checkpoints are pruned one at a time, so that indexing is not held up for long.
+        for checkpoint in lowest_available..window_start {
+            let _guard = self.write_lock.lock();
+            self.tables.prune_checkpoint(checkpoint, window_start)?;
+        }
+
+        self.tables.prune_epochs(first_epoch)
+    }
+
+    /// The first and last checkpoints that reads as of a checkpoint can be served for, if any
+    /// checkpoints have been indexed.
+    pub fn available_checkpoints(
+        &self,
+    ) -> Result<Option<(CheckpointSequenceNumber, CheckpointSequenceNumber)>, TypedStoreError> {
+        Ok(self
+            .tables
+            .get_watermark(Watermark::LowestAvailable)?
+            .zip(self.tables.get_watermark(Watermark::HighestIndexed)?))
+    }
+
+    /// The given version of an object, if it is in the index and was not a deletion or wrapping.
+    pub fn get_object(
+        &self,
+        object_id: ObjectID,
+        version: SequenceNumber,
+    ) -> Result<Option<Object>, TypedStoreError> {
+        self.tables.get_object(object_id, version)
+    }
+
+    /// The state of an object as of the end of `checkpoint`, which must be within
+    /// `available_checkpoints`.
+    pub fn get_object_as_of_checkpoint(
+        &self,
+        object_id: ObjectID,
+        checkpoint: CheckpointSequenceNumber,
+    ) -> Result<ArchivedObjectRead, StorageError> {
+        self.tables
+            .get_object_as_of_checkpoint(object_id, checkpoint)
+    }
+}
+
+/// Periodically moves the window of the archival index forward.
+pub struct ArchivalIndexPruner {
+    _cancel_handle: Sender<()>,
+}
+
+impl ArchivalIndexPruner {
+    pub fn new(index: Arc<ArchivalIndexStore>, config: &ArchivalIndexConfig) -> Self {
+        let (sender, mut recv) = oneshot::channel();
+        let num_epochs_to_retain = config.num_epochs_to_retain;
+        let mut prune_interval =
+            tokio::time::interval(Duration::from_secs(config.pruning_period_secs));
+
+        info!(
+            "Starting archival index pruning service with num_epochs_to_retain={}",
+            num_epochs_to_retain
+        );
+
+        spawn_monitored_task!(async move {
+            loop {
+                tokio::select! {
+                    _ = prune_interval.tick() => {
+                        let index = index.clone();
+                        match tokio::task::spawn_blocking(move || index.prune(num_epochs_to_retain)).await {
+                            Err(err) => error!("Failed to prune archival index: {:?}", err),
+                            Ok(Err(err)) => error!("Failed to prune archival index: {:?}", err),
+                            Ok(Ok(())) => {}
+                        }
+                    },
+                    _ = &mut recv => break,
+                }
+            }
+        });
+
+        Self {
+            _cancel_handle: sender,
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "unit_tests/archival_index_tests.rs"]
+mod archival_index_tests;
@@ -2,6 +2,7 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::archival_index::{ArchivalIndexPruner, ArchivalIndexStore, ArchivedObjectRead};
 use crate::execution_cache::ExecutionCacheTraitPointers;
 use crate::execution_cache::TransactionCacheRead;
 use crate::rest_index::RestIndexStore;
@@ -29,7 +30,7 @@ use prometheus::{
 };
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs::File;
 use std::io::Write;
 use std::path::{Path, PathBuf};
@@ -46,6 +47,7 @@ use sui_config::node::{AuthorityOverloadConfig, StateDebugDumpConfig};
 use sui_config::NodeConfig;
 use sui_types::crypto::RandomnessRound;
 use sui_types::dynamic_field::visitor as DFV;
+use sui_types::execution::DynamicallyLoadedObjectMetadata;
 use sui_types::execution_status::ExecutionStatus;
 use sui_types::inner_temporary_store::PackageStoreWithFallback;
 use sui_types::layout_resolver::into_struct_layout;
@@ -86,7 +88,7 @@ use sui_types::crypto::{default_hash, AuthoritySignInfo, Signer};
 use sui_types::deny_list_v1::check_coin_deny_list_v1;
 use sui_types::digests::ChainIdentifier;
 use sui_types::digests::TransactionEventsDigest;
-use sui_types::dynamic_field::{DynamicFieldInfo, DynamicFieldName};
+use sui_types::dynamic_field::{derive_dynamic_field_id, DynamicFieldInfo, DynamicFieldName};
 use sui_types::effects::{
     InputSharedObject, SignedTransactionEffects, TransactionEffects, TransactionEffectsAPI,
     TransactionEvents, VerifiedCertifiedTransactionEffects, VerifiedSignedTransactionEffects,
@@ -139,6 +141,7 @@ use crate::authority::authority_store_pruner::{
 };
 use crate::authority::epoch_start_configuration::EpochStartConfigTrait;
 use crate::authority::epoch_start_configuration::EpochStartConfiguration;
+use crate::certificate_deny_filter::{CertificateDenyFilter, CERTIFICATE_DENY_AUDIT_LOG};
 use crate::checkpoints::CheckpointStore;
 use crate::consensus_adapter::ConsensusAdapter;
 use crate::epoch::committee_store::CommitteeStore;
@@ -156,6 +159,7 @@ use crate::state_accumulator::{AccumulatorStore, StateAccumulator, WrappedObject
 use crate::subscription_handler::SubscriptionHandler;
 use crate::transaction_input_loader::TransactionInputLoader;
 use crate::transaction_manager::TransactionManager;
+use crate::transaction_signing_filter::{TransactionSigningFilter, TRANSACTION_DENY_AUDIT_LOG};
 
 #[cfg(msim)]
 pub use crate::checkpoints::checkpoint_executor::{
@@ -809,6 +813,7 @@ pub struct AuthorityState {
 
     pub indexes: Option<Arc<IndexStore>>,
     pub rest_index: Option<Arc<RestIndexStore>>,
+    pub archival_index: Option<Arc<ArchivalIndexStore>>,
 
     pub subscription_handler: Arc<SubscriptionHandler>,
     checkpoint_store: Arc<CheckpointStore>,
@@ -824,6 +829,7 @@ pub struct AuthorityState {
 
     pub metrics: Arc<AuthorityMetrics>,
     _pruner: AuthorityStorePruner,
+    _archival_index_pruner: Option<ArchivalIndexPruner>,
     _authority_per_epoch_pruner: AuthorityPerEpochStorePruner,
 
     /// Take db checkpoints of different dbs
@@ -831,6 +837,12 @@ pub struct AuthorityState {
 
     pub config: NodeConfig,
 
+    /// The deny config checked when signing transactions, which can be updated at runtime.
+    transaction_signing_filter: TransactionSigningFilter,
+
+    /// The deny config checked when executing certificates, which can be updated at runtime.
+    certificate_deny_filter: CertificateDenyFilter,
+
     /// Current overload status in this authority. Updated periodically.
     pub overload_info: AuthorityOverloadInfo,
 
@@ -864,6 +876,14 @@ impl AuthorityState {
         &self.config.authority_overload_config
     }
 
+    pub fn transaction_signing_filter(&self) -> &TransactionSigningFilter {
+        &self.transaction_signing_filter
+    }
+
+    pub fn certificate_deny_filter(&self) -> &CertificateDenyFilter {
+        &self.certificate_deny_filter
+    }
+
     pub fn get_epoch_state_commitments(
         &self,
         epoch: EpochId,
@@ -890,7 +910,7 @@ impl AuthorityState {
             transaction.tx_signatures(),
             &input_object_kinds,
             &receiving_objects_refs,
-            &self.config.transaction_deny_config,
+            &self.transaction_signing_filter.load(),
             self.get_backing_package_store().as_ref(),
         )?;
 
@@ -1489,6 +1509,8 @@ impl AuthorityState {
         )
         .await?;
 
+        epoch_store.record_local_execution_cost(certificate, &effects);
+
         if let TransactionKind::AuthenticatorStateUpdate(auth_state) =
             certificate.data().transaction_data().kind()
         {
@@ -1620,6 +1642,148 @@ impl AuthorityState {
         Ok(())
     }
 
+    /// Commit the outputs of a transaction from a certified checkpoint without executing it, as
+    /// part of checkpoint fast sync. `effects`, `events` and `written` must already have been
+    /// checked against the checkpoint. The transaction's inputs and the objects it modified are
+    /// read from the local store, so every transaction it depends on must already have been
+    /// committed.
+    pub(crate) async fn commit_checkpointed_outputs(
+        &self,
+        certificate: &VerifiedExecutableTransaction,
+        effects: &TransactionEffects,
+        events: TransactionEvents,
+        written: WrittenObjects,
+        epoch_store: &Arc<AuthorityPerEpochStore>,
+    ) -> SuiResult {
+        let tx_guard = epoch_store.acquire_tx_guard(certificate).await?;
+        if self
+            .get_transaction_cache_reader()
+            .is_tx_already_executed(certificate.digest())?
+        {
+            tx_guard.release();
+            return Ok(());
+        }
+
+        let execution_guard = match self
+            .execution_lock_for_executable_transaction(certificate)
+            .await
+        {
+            Ok(execution_guard) => execution_guard,
+            Err(err) => {
+                tx_guard.release();
+                return Err(err);
+            }
+        };
+        if *execution_guard != epoch_store.epoch() {
+            tx_guard.release();
+            return Err(SuiError::WrongEpoch {
+                expected_epoch: epoch_store.epoch(),
+                actual_epoch: *execution_guard,
+            });
+        }
+
+        let inner_temporary_store = match self
+            .load_checkpointed_temporary_store(certificate, effects, events, written, epoch_store)
+            .await
+        {
+            Ok(inner_temporary_store) => inner_temporary_store,
+            Err(err) => {
+                tx_guard.release();
+                return Err(err);
+            }
+        };
+
+        self.commit_certificate(
+            certificate,
+            inner_temporary_store,
+            effects,
+            tx_guard,
+            execution_guard,
+            epoch_store,
+        )
+        .await
+    }
+
+    /// Build the temporary store that executing `certificate` would have left behind, from its
+    /// checkpointed outputs. Inputs are loaded the same way as for execution, at the shared object
+    /// versions recorded in `effects`. Objects the transaction modified that were not among its
+    /// inputs were loaded at runtime, and the packages its input packages link against are
+    /// recorded as loaded from the store, so that the outputs are indexed as if executed.
+    async fn load_checkpointed_temporary_store(
+        &self,
+        certificate: &VerifiedExecutableTransaction,
+        effects: &TransactionEffects,
+        events: TransactionEvents,
+        written: WrittenObjects,
+        epoch_store: &Arc<AuthorityPerEpochStore>,
+    ) -> SuiResult<InnerTemporaryStore> {
+        if certificate.contains_shared_object() {
+            epoch_store
+                .acquire_shared_locks_from_effects(
+                    certificate,
+                    effects,
+                    self.get_object_cache_reader().as_ref(),
+                )
+                .await?;
+        }
+
+        let input_objects = self.read_objects_for_execution(certificate, epoch_store)?;
+        let mutable_inputs = input_objects.mutable_inputs();
+        let input_objects = input_objects.into_object_map();
+
+        let runtime_keys: Vec<_> = effects
+            .modified_at_versions()
+            .into_iter()
+            .filter(|(id, _)| !input_objects.contains_key(id))
+            .map(|(id, version)| ObjectKey(id, version))
+            .collect();
+        let mut loaded_runtime_objects = BTreeMap::new();
+        for (key, object) in runtime_keys.iter().zip(
+            self.get_object_cache_reader()
+                .multi_get_objects_by_key(&runtime_keys)?,
+        ) {
+            let object = object.ok_or(UserInputError::ObjectNotFound {
+                object_id: key.0,
+                version: Some(key.1),
+            })?;
+            loaded_runtime_objects.insert(
+                key.0,
+                DynamicallyLoadedObjectMetadata {
+                    version: key.1,
+                    digest: object.digest(),
+                    owner: object.owner.clone(),
+                    storage_rebate: object.storage_rebate,
+                    previous_transaction: object.previous_transaction,
+                },
+            );
+        }
+
+        let mut runtime_packages_loaded_from_db = BTreeMap::new();
+        let linked_packages: BTreeSet<_> = input_objects
+            .values()
+            .filter_map(|object| object.data.try_as_package())
+            .flat_map(|package| package.linkage_table().values())
+            .map(|upgrade| upgrade.upgraded_id)
+            .filter(|id| !input_objects.contains_key(id))
+            .collect();
+        for id in linked_packages {
+            if let Some(package) = self.get_backing_package_store().get_package_object(&id)? {
+                runtime_packages_loaded_from_db.insert(id, package);
+            }
+        }
+
+        Ok(InnerTemporaryStore {
+            input_objects,
+            mutable_inputs,
+            written,
+            loaded_runtime_objects,
+            events,
+            binary_config: to_binary_config(epoch_store.protocol_config()),
+            runtime_packages_loaded_from_db,
+            lamport_version: effects.lamport_version(),
+        })
+    }
+
     fn update_metrics(
         &self,
         certificate: &VerifiedExecutableTransaction,
@@ -1704,6 +1868,14 @@ impl AuthorityState {
         let transaction_data = &certificate.data().intent_message().value;
         let (kind, signer, gas) = transaction_data.execution_parts();
 
+        let certificate_deny_set = self
+            .certificate_deny_filter
+            .certificate_deny_set(epoch_store.epoch());
+        if certificate_deny_set.contains(&tx_digest) {
+            self.certificate_deny_filter
+                .report_denied(&tx_digest, epoch_store.epoch());
+        }
+
         #[allow(unused_mut)]
         let (inner_temp_store, _, mut effects, execution_error_opt) =
             epoch_store.executor().execute_transaction_to_effects(
@@ -1715,7 +1887,7 @@ impl AuthorityState {
                 self.config
                     .expensive_safety_check_config
                     .enable_deep_per_tx_sui_conservation_check(),
-                self.config.certificate_deny_config.certificate_deny_set(),
+                &certificate_deny_set,
                 &epoch_store.epoch_start_config().epoch_data().epoch_id(),
                 epoch_store
                     .epoch_start_config()
@@ -1826,7 +1998,7 @@ impl AuthorityState {
             &[],
             &input_object_kinds,
             &receiving_object_refs,
-            &self.config.transaction_deny_config,
+            &self.transaction_signing_filter.load(),
             self.get_backing_package_store().as_ref(),
         )?;
 
@@ -1896,7 +2068,9 @@ impl AuthorityState {
                 protocol_config,
                 self.metrics.limits_metrics.clone(),
                 expensive_checks,
-                self.config.certificate_deny_config.certificate_deny_set(),
+                &self
+                    .certificate_deny_filter
+                    .certificate_deny_set(epoch_store.epoch()),
                 &epoch_store.epoch_start_config().epoch_data().epoch_id(),
                 epoch_store
                     .epoch_start_config()
@@ -2012,7 +2186,7 @@ impl AuthorityState {
             &[],
             &input_object_kinds,
             &receiving_object_refs,
-            &self.config.transaction_deny_config,
+            &self.transaction_signing_filter.load(),
             self.get_backing_package_store().as_ref(),
         )?;
 
@@ -2082,7 +2256,9 @@ impl AuthorityState {
                 protocol_config,
                 self.metrics.limits_metrics.clone(),
                 expensive_checks,
-                self.config.certificate_deny_config.certificate_deny_set(),
+                &self
+                    .certificate_deny_filter
+                    .certificate_deny_set(epoch_store.epoch()),
                 &epoch_store.epoch_start_config().epoch_data().epoch_id(),
                 epoch_store
                     .epoch_start_config()
@@ -2174,7 +2350,7 @@ impl AuthorityState {
             &[],
             &input_object_kinds,
             &receiving_object_refs,
-            &self.config.transaction_deny_config,
+            &self.transaction_signing_filter.load(),
             self.get_backing_package_store().as_ref(),
         )?;
 
@@ -2266,7 +2442,9 @@ impl AuthorityState {
             protocol_config,
             self.metrics.limits_metrics.clone(),
             /* expensive checks */ false,
-            self.config.certificate_deny_config.certificate_deny_set(),
+            &self
+                .certificate_deny_filter
+                .certificate_deny_set(epoch_store.epoch()),
             &epoch_store.epoch_start_config().epoch_data().epoch_id(),
             epoch_store
                 .epoch_start_config()
@@ -2890,6 +3068,7 @@ impl AuthorityState {
         committee_store: Arc<CommitteeStore>,
         indexes: Option<Arc<IndexStore>>,
         rest_index: Option<Arc<RestIndexStore>>,
+        archival_index: Option<Arc<ArchivalIndexStore>>,
         checkpoint_store: Arc<CheckpointStore>,
         prometheus_registry: &Registry,
         genesis_objects: &[Object],
@@ -2930,8 +3109,26 @@ impl AuthorityState {
             indirect_objects_threshold,
             archive_readers,
         );
+        let _archival_index_pruner = archival_index
+            .clone()
+            .zip(config.archival_index.as_ref())
+            .map(|(index, archival_index_config)| {
+                ArchivalIndexPruner::new(index, archival_index_config)
+            });
         let input_loader =
             TransactionInputLoader::new(execution_cache_trait_pointers.object_cache_reader.clone());
+        let transaction_signing_filter = TransactionSigningFilter::new(
+            config.transaction_deny_config.clone(),
+            config.db_path.join(TRANSACTION_DENY_AUDIT_LOG),
+            prometheus_registry,
+        )
+        .expect("Failed to replay the transaction deny config audit log");
+        let certificate_deny_filter = CertificateDenyFilter::new(
+            config.certificate_deny_config.clone(),
+            config.db_path.join(CERTIFICATE_DENY_AUDIT_LOG),
+            prometheus_registry,
+        )
+        .expect("Failed to replay the certificate deny config audit log");
         let epoch = epoch_store.epoch();
         let state = Arc::new(AuthorityState {
             name,
@@ -2942,6 +3139,7 @@ impl AuthorityState {
             execution_cache_trait_pointers,
             indexes,
             rest_index,
+            archival_index,
             subscription_handler: Arc::new(SubscriptionHandler::new(prometheus_registry)),
             checkpoint_store,
             committee_store,
@@ -2949,9 +3147,12 @@ impl AuthorityState {
             tx_execution_shutdown: Mutex::new(Some(tx_execution_shutdown)),
             metrics,
             _pruner,
+            _archival_index_pruner,
             _authority_per_epoch_pruner,
             db_checkpoint_config: db_checkpoint_config.clone(),
             config,
+            transaction_signing_filter,
+            certificate_deny_filter,
             overload_info: AuthorityOverloadInfo::default(),
             validator_tx_finalizer,
         });
@@ -3593,16 +3794,162 @@ impl AuthorityState {
         }
     }
 
+    /// Read an object as it was at the end of `checkpoint`. This is only supported by archival
+    /// fullnodes, for checkpoints within the window covered by their archival index.
+    #[instrument(level = "trace", skip_all)]
+    pub fn get_past_object_read_as_of_checkpoint(
+        &self,
+        object_id: &ObjectID,
+        checkpoint: CheckpointSequenceNumber,
+    ) -> SuiResult<PastObjectRead> {
+        let archival_index = self.get_archival_index_for_checkpoint(checkpoint)?;
+
+        match archival_index.get_object_as_of_checkpoint(*object_id, checkpoint)? {
+            ArchivedObjectRead::NotIndexed => {
+                // The object has not been modified since the checkpoint, so its version as of the
+                // checkpoint is its latest version.
+                match self
+                    .get_object_cache_reader()
+                    .get_latest_object_ref_or_tombstone(*object_id)?
+                {
+                    Some(obj_ref) => self.get_past_object_read(object_id, obj_ref.1),
+                    None => Ok(PastObjectRead::ObjectNotExists(*object_id)),
+                }
+            }
+            ArchivedObjectRead::NotExists => Ok(PastObjectRead::ObjectNotExists(*object_id)),
+            ArchivedObjectRead::Exists(object) => {
+                let layout = self.get_object_layout(&object)?;
+                Ok(PastObjectRead::VersionFound(
+                    object.compute_object_reference(),
+                    object,
+                    layout,
+                ))
+            }
+            ArchivedObjectRead::Removed(obj_ref) => Ok(PastObjectRead::ObjectDeleted(obj_ref)),
+        }
+    }
+
+    /// Read the dynamic field of `owner` with the given name, as it was at the end of
+    /// `checkpoint`. For a dynamic object field, this reads the object that the field pointed to
+    /// at that checkpoint. This is only supported by archival fullnodes, for checkpoints within
+    /// the window covered by their archival index.
+    #[instrument(level = "trace", skip_all)]
+    pub fn get_dynamic_field_object_read_as_of_checkpoint(
+        &self,
+        owner: ObjectID,
+        name_type: TypeTag,
+        name_bcs_bytes: &[u8],
+        checkpoint: CheckpointSequenceNumber,
+    ) -> SuiResult<PastObjectRead> {
+        let derive_id = |name_type: &TypeTag| {
+            derive_dynamic_field_id(owner, name_type, name_bcs_bytes).map_err(|e| {
+                SuiError::DynamicFieldReadError(format!(
+                    "Unable to generate dynamic field id. Got error: {e:?}"
+                ))
+            })
+        };
+
+        let field_read =
+            self.get_past_object_read_as_of_checkpoint(&derive_id(&name_type)?, checkpoint)?;
+        if !matches!(field_read, PastObjectRead::ObjectNotExists(_)) {
+            return Ok(field_read);
+        }
+
+        let wrapper_type = TypeTag::Struct(Box::new(
+            DynamicFieldInfo::dynamic_object_field_wrapper(name_type),
+        ));
+        let PastObjectRead::VersionFound(_, wrapper, _) =
+            self.get_past_object_read_as_of_checkpoint(&derive_id(&wrapper_type)?, checkpoint)?
+        else {
+            return Ok(field_read);
+        };
+
+        let object_id = self.get_dynamic_object_field_value(&wrapper)?;
+        self.get_past_object_read_as_of_checkpoint(&object_id, checkpoint)
+    }
+
+    /// The ID of the object that the dynamic object field `field` points to.
+    fn get_dynamic_object_field_value(&self, field: &Object) -> SuiResult<ObjectID> {
+        let Some(move_object) = field.data.try_as_move() else {
+            return Err(SuiError::DynamicFieldReadError(format!(
+                "Dynamic field {:?} is not a Move object",
+                field.id()
+            )));
+        };
+
+        let layout = self
+            .load_epoch_store_one_call_per_task()
+            .executor()
+            .type_layout_resolver(Box::new(self.get_backing_package_store().as_ref()))
+            .get_annotated_layout(&move_object.type_().clone().into())?
+            .into_layout();
+
+        let value_metadata = DFV::FieldVisitor::deserialize(move_object.contents(), &layout)
+            .and_then(|field| field.value_metadata())
+            .map_err(|e| SuiError::ObjectDeserializationError {
+                error: e.to_string(),
+            })?;
+
+        match value_metadata {
+            DFV::ValueMetadata::DynamicObjectField(object_id) => Ok(object_id),
+            DFV::ValueMetadata::DynamicField(_) => Err(SuiError::DynamicFieldReadError(format!(
+                "Dynamic field {:?} is not a dynamic object field",
+                field.id()
+            ))),
+        }
+    }
+
+    fn get_archival_index_for_checkpoint(
+        &self,
+        checkpoint: CheckpointSequenceNumber,
+    ) -> SuiResult<&ArchivalIndexStore> {
+        let Some(archival_index) = &self.archival_index else {
+            return Err(UserInputError::Unsupported(
+                "reads as of a checkpoint are only served by archival fullnodes".to_string(),
+            )
+            .into());
+        };
+
+        let Some((lowest_available, highest_available)) = archival_index.available_checkpoints()?
+        else {
+            return Err(UserInputError::Unsupported(
+                "the archival index has not indexed any checkpoints yet".to_string(),
+            )
+            .into());
+        };
+
+        if !(lowest_available..=highest_available).contains(&checkpoint) {
+            return Err(UserInputError::CheckpointNotAvailableForHistoricalReads {
+                checkpoint,
+                lowest_available,
+                highest_available,
+            }
+            .into());
+        }
+
+        Ok(archival_index)
+    }
+
     #[instrument(level = "trace", skip_all)]
     fn read_object_at_version(
         &self,
         object_id: &ObjectID,
         version: SequenceNumber,
     ) -> SuiResult<Option<(Object, Option<MoveStructLayout>)>> {
-        let Some(object) = self
+        let object = match self
             .get_object_cache_reader()
             .get_object_by_key(object_id, version)?
-        else {
+        {
+            Some(object) => Some(object),
+            // Versions that have been pruned from the live store may still be in the archival
+            // index.
+            None => match &self.archival_index {
+                Some(archival_index) => archival_index.get_object(*object_id, version)?,
+                None => None,
+            },
+        };
+
+        let Some(object) = object else {
             return Ok(None);
         };
 
@@ -48,8 +48,13 @@ use typed_store::{
 
 use super::authority_store_tables::ENV_VAR_LOCKS_BLOCK_CACHE_SIZE;
 use super::epoch_start_configuration::EpochStartConfigTrait;
-use super::shared_object_congestion_tracker::SharedObjectCongestionTracker;
-use super::transaction_deferral::{transaction_deferral_within_limit, DeferralKey, DeferralReason};
+use super::shared_object_congestion_tracker::{
+    CommitCongestionSnapshot, SharedObjectCongestionTracker,
+};
+use super::transaction_deferral::{
+    transaction_deferral_within_limit, DeferralKey, DeferralReason, DeferredTransactions,
+    ExecutionCostEstimator, LocalExecutionCostTracker,
+};
 use crate::authority::epoch_start_configuration::{EpochFlag, EpochStartConfiguration};
 use crate::authority::AuthorityMetrics;
 use crate::authority::ResolverWrapper;
@@ -85,12 +90,13 @@ use prometheus::IntCounter;
 use std::str::FromStr;
 use sui_execution::{self, Executor};
 use sui_macros::fail_point;
-use sui_protocol_config::{Chain, ProtocolConfig, ProtocolVersion};
+use sui_protocol_config::{Chain, PerObjectCongestionControlMode, ProtocolConfig, ProtocolVersion};
 use sui_storage::mutex_table::{MutexGuard, MutexTable};
-use sui_types::effects::TransactionEffects;
+use sui_types::effects::{TransactionEffects, TransactionEffectsAPI};
 use sui_types::executable_transaction::{
     TrustedExecutableTransaction, VerifiedExecutableTransaction,
 };
+use sui_types::execution_status::{ExecutionFailureStatus, ExecutionStatus};
 use sui_types::message_envelope::TrustedEnvelope;
 use sui_types::messages_checkpoint::{
     CheckpointContents, CheckpointSequenceNumber, CheckpointSignatureMessage, CheckpointSummary,
@@ -98,7 +104,8 @@ use sui_types::messages_checkpoint::{
 use sui_types::messages_consensus::VersionedDkgConfirmation;
 use sui_types::messages_consensus::{
     check_total_jwk_size, AuthorityCapabilitiesV1, AuthorityCapabilitiesV2, ConsensusTransaction,
-    ConsensusTransactionKey, ConsensusTransactionKind,
+    ConsensusTransactionKey, ConsensusTransactionKind, ExecutionCostObservation,
+    MAX_EXECUTION_COST_OBSERVATION_OBJECTS,
 };
 use sui_types::storage::GetSharedLocks;
 use sui_types::sui_system_state::epoch_start_sui_system_state::{
@@ -341,6 +348,19 @@ pub struct AuthorityPerEpochStore {
     /// In particular, this lock is always acquired after taking read or write lock on reconfig state
     pending_consensus_certificates: RwLock<HashSet<TransactionDigest>>,
 
+    /// Shared object congestion at the end of the last consensus commit that was processed. Only
+    /// used for debugging.
+    last_commit_congestion: RwLock<Option<CommitCongestionSnapshot>>,
+
+    /// Estimated execution cost of transactions on each shared object, from the costs reported by
+    /// validators through consensus. Only used, and only updated by the consensus handler, in
+    /// `PerObjectCongestionControlMode::ObservedExecutionCost`.
+    execution_cost_estimator: Mutex<ExecutionCostEstimator>,
+
+    /// Execution cost of transactions on each shared object as measured by this validator, to be
+    /// reported through consensus.
+    local_execution_cost_tracker: Mutex<LocalExecutionCostTracker>,
+
     /// MutexTable for transaction locks (prevent concurrent execution of same transaction)
     mutex_table: MutexTable<TransactionDigest>,
 
@@ -555,6 +575,14 @@ pub struct AuthorityEpochTables {
     /// Transactions that are being deferred until some future time
     deferred_transactions: DBMap<DeferralKey, Vec<VerifiedSequencedConsensusTransaction>>,
 
+    /// The latest execution cost of transactions on each shared object reported by each
+    /// validator, used for congestion control in
+    /// `PerObjectCongestionControlMode::ObservedExecutionCost`.
+    reported_execution_costs: DBMap<(ObjectID, AuthorityName), u64>,
+
+    /// The generation of the latest `ExecutionCostObservation` processed from each validator.
+    execution_cost_observation_generations: DBMap<AuthorityName, u64>,
+
     /// This table is no longer used (can be removed when DBMap supports removing tables)
     #[allow(dead_code)]
     randomness_rounds_written: DBMap<narwhal_types::RandomnessRound, ()>,
@@ -670,6 +698,14 @@ impl AuthorityEpochTables {
             .collect()
     }
 
+    fn load_execution_cost_estimator(&self, committee: Arc<Committee>) -> ExecutionCostEstimator {
+        ExecutionCostEstimator::new(
+            committee,
+            self.reported_execution_costs.unbounded_iter(),
+            self.execution_cost_observation_generations.unbounded_iter(),
+        )
+    }
+
     pub fn reset_db_for_execution_since_genesis(&self) -> SuiResult {
         // TODO: Add new tables that get added to the db automatically
         self.executed_transactions_to_checkpoint.unsafe_clear()?;
@@ -778,6 +814,7 @@ impl AuthorityPerEpochStore {
 
         let epoch_alive_notify = NotifyOnce::new();
         let pending_consensus_transactions = tables.get_all_pending_consensus_transactions();
+        let execution_cost_estimator = tables.load_execution_cost_estimator(committee.clone());
         let pending_consensus_certificates: HashSet<_> = pending_consensus_transactions
             .iter()
             .filter_map(|transaction| {
@@ -885,6 +922,9 @@ impl AuthorityPerEpochStore {
             highest_synced_checkpoint: RwLock::new(0),
             end_of_publish: Mutex::new(end_of_publish),
             pending_consensus_certificates: RwLock::new(pending_consensus_certificates),
+            last_commit_congestion: RwLock::new(None),
+            execution_cost_estimator: Mutex::new(execution_cost_estimator),
+            local_execution_cost_tracker: Mutex::new(LocalExecutionCostTracker::default()),
             mutex_table: MutexTable::new(MUTEX_TABLE_SIZE),
             epoch_open_time: current_time,
             epoch_close_time: Default::default(),
@@ -1794,6 +1834,29 @@ impl AuthorityPerEpochStore {
             .collect::<Result<Vec<_>, _>>()?)
     }
 
+    /// Returns a summary of all deferred transactions, grouped by the key they are deferred under.
+    pub fn deferred_transactions_summary(&self) -> SuiResult<Vec<DeferredTransactions>> {
+        let mut summary = vec![];
+        for entry in self.tables()?.deferred_transactions.safe_iter() {
+            let (deferral_key, txns) = entry?;
+            summary.push(DeferredTransactions {
+                deferral_key,
+                transactions: txns
+                    .iter()
+                    .filter_map(|tx| tx.0.transaction.executable_transaction_digest())
+                    .collect(),
+                total: txns.len(),
+            });
+        }
+        Ok(summary)
+    }
+
+    /// Returns the shared object congestion at the end of the last consensus commit processed in
+    /// this epoch, if any.
+    pub fn last_commit_congestion(&self) -> Option<CommitCongestionSnapshot> {
+        self.last_commit_congestion.read().clone()
+    }
+
     fn get_max_accumulated_txn_cost_per_object_in_commit(&self) -> Option<u64> {
         self.protocol_config()
             .max_accumulated_txn_cost_per_object_in_mysticeti_commit_as_option()
@@ -2606,6 +2669,18 @@ impl AuthorityPerEpochStore {
                     return None;
                 }
             }
+            SequencedConsensusTransactionKind::External(ConsensusTransaction {
+                kind: ConsensusTransactionKind::ExecutionCostObservation(observation),
+                ..
+            }) => {
+                if transaction.sender_authority() != observation.authority {
+                    warn!(
+                        "ExecutionCostObservation authority {} does not match its author from consensus {}",
+                        observation.authority, transaction.certificate_author_index
+                    );
+                    return None;
+                }
+            }
             SequencedConsensusTransactionKind::System(_) => {}
         }
         Some(VerifiedSequencedConsensusTransaction(transaction))
@@ -3098,6 +3173,54 @@ impl AuthorityPerEpochStore {
         Ok(())
     }
 
+    /// Record the execution cost of a certificate executed by this validator, in
+    /// `PerObjectCongestionControlMode::ObservedExecutionCost`, to be reported through consensus.
+    pub fn record_local_execution_cost(
+        &self,
+        certificate: &VerifiedExecutableTransaction,
+        effects: &TransactionEffects,
+    ) {
+        if self.protocol_config().per_object_congestion_control_mode()
+            != PerObjectCongestionControlMode::ObservedExecutionCost
+            || !self.committee().authority_exists(&self.name)
+            || certificate.is_system_tx()
+        {
+            return;
+        }
+        // Cancelled transactions fail before doing any work, so their cost says nothing about the
+        // objects they use.
+        if let ExecutionStatus::Failure {
+            error:
+                ExecutionFailureStatus::ExecutionCancelledDueToSharedObjectCongestion { .. }
+                | ExecutionFailureStatus::ExecutionCancelledDueToRandomnessUnavailable,
+            ..
+        } = effects.status()
+        {
+            return;
+        }
+        let shared_objects: Vec<_> = certificate
+            .shared_input_objects()
+            .map(|object| object.id)
+            .collect();
+        if shared_objects.is_empty() {
+            return;
+        }
+        self.local_execution_cost_tracker.lock().record(
+            &shared_objects,
+            effects.gas_cost_summary().computation_cost,
+            self.protocol_config()
+                .observed_execution_cost_ewma_weight_bps(),
+        );
+    }
+
+    /// Take the execution costs measured by this validator that changed since they were last
+    /// reported, at most `MAX_EXECUTION_COST_OBSERVATION_OBJECTS` of them.
+    pub fn take_unreported_execution_costs(&self) -> Vec<(ObjectID, u64)> {
+        self.local_execution_cost_tracker
+            .lock()
+            .take_unreported(MAX_EXECUTION_COST_OBSERVATION_OBJECTS)
+    }
+
     fn process_notifications(
         &self,
         notifications: &[SequencedConsensusTransactionKey],
@@ -3154,12 +3277,17 @@ impl AuthorityPerEpochStore {
 
         // We track transaction execution cost separately for regular transactions and transactions using randomness, since
         // they will be in different checkpoints.
-        let mut shared_object_congestion_tracker = SharedObjectCongestionTracker::new(
-            self.protocol_config().per_object_congestion_control_mode(),
-        );
+        let congestion_control_mode = self.protocol_config().per_object_congestion_control_mode();
+        let object_cost_estimates = self.execution_cost_estimator.lock().estimates();
+        let mut shared_object_congestion_tracker =
+            SharedObjectCongestionTracker::new_with_cost_estimates(
+                congestion_control_mode,
+                object_cost_estimates.clone(),
+            );
         let mut shared_object_using_randomness_congestion_tracker =
-            SharedObjectCongestionTracker::new(
-                self.protocol_config().per_object_congestion_control_mode(),
+            SharedObjectCongestionTracker::new_with_cost_estimates(
+                congestion_control_mode,
+                object_cost_estimates,
             );
 
         fail_point_arg!(
@@ -3273,6 +3401,15 @@ impl AuthorityPerEpochStore {
             .consensus_handler_max_object_costs
             .with_label_values(&["randomness_commit"])
             .set(shared_object_using_randomness_congestion_tracker.max_cost() as i64);
+        *self.last_commit_congestion.write() = Some(CommitCongestionSnapshot {
+            commit_round: consensus_commit_info.round,
+            mode: shared_object_congestion_tracker.mode(),
+            max_accumulated_txn_cost_per_object_in_commit: self
+                .get_max_accumulated_txn_cost_per_object_in_commit(),
+            object_execution_cost: shared_object_congestion_tracker.object_execution_costs(),
+            randomness_object_execution_cost: shared_object_using_randomness_congestion_tracker
+                .object_execution_costs(),
+        });
 
         if randomness_state_updated {
             if let Some(randomness_manager) = randomness_manager.as_mut() {
@@ -3714,6 +3851,43 @@ impl AuthorityPerEpochStore {
                 }
                 Ok(ConsensusCertificateResult::RandomnessConsensusMessage)
             }
+            SequencedConsensusTransactionKind::External(ConsensusTransaction {
+                kind: ConsensusTransactionKind::ExecutionCostObservation(observation),
+                ..
+            }) => {
+                let authority = observation.authority;
+                if self.protocol_config().per_object_congestion_control_mode()
+                    != PerObjectCongestionControlMode::ObservedExecutionCost
+                {
+                    debug!(
+                        "Ignoring ExecutionCostObservation from {:?} because observed execution cost is not enabled",
+                        authority.concise()
+                    );
+                } else if self
+                    .get_reconfig_state_read_lock_guard()
+                    .should_accept_consensus_certs()
+                {
+                    debug!(
+                        "Received ExecutionCostObservation from {:?}",
+                        authority.concise()
+                    );
+                    // Estimates used by the current commit were taken before its transactions
+                    // were processed, so this only affects later commits.
+                    if self
+                        .execution_cost_estimator
+                        .lock()
+                        .process_observation(observation)
+                    {
+                        output.record_execution_cost_observation(observation);
+                    }
+                } else {
+                    debug!(
+                        "Ignoring ExecutionCostObservation from {:?} because of end of epoch",
+                        authority.concise()
+                    );
+                }
+                Ok(ConsensusCertificateResult::ConsensusMessage)
+            }
 
             SequencedConsensusTransactionKind::External(ConsensusTransaction {
                 kind: ConsensusTransactionKind::UserTransaction(_tx),
@@ -4071,6 +4245,9 @@ pub(crate) struct ConsensusCommitOutput {
     // deferred txns that have been loaded and can be removed
     deleted_deferred_txns: BTreeSet<DeferralKey>,
 
+    // execution cost estimation state
+    execution_cost_observations: Vec<ExecutionCostObservation>,
+
     // checkpoint state
     user_signatures_for_checkpoints: Vec<(TransactionDigest, Vec<GenericSignature>)>,
     pending_checkpoints: Vec<PendingCheckpointV2>,
@@ -4147,6 +4324,10 @@ impl ConsensusCommitOutput {
             .extend(deferral_keys.iter().cloned());
     }
 
+    fn record_execution_cost_observation(&mut self, observation: &ExecutionCostObservation) {
+        self.execution_cost_observations.push(observation.clone());
+    }
+
     fn insert_pending_checkpoint(&mut self, checkpoint: PendingCheckpointV2) {
         self.pending_checkpoints.push(checkpoint);
     }
@@ -4236,6 +4417,21 @@ impl ConsensusCommitOutput {
         batch.delete_batch(&tables.deferred_transactions, self.deleted_deferred_txns)?;
         batch.insert_batch(&tables.deferred_transactions, self.deferred_txns)?;
 
+        // Later observations overwrite the costs reported by earlier ones.
+        for observation in self.execution_cost_observations {
+            batch.insert_batch(
+                &tables.execution_cost_observation_generations,
+                [(observation.authority, observation.generation)],
+            )?;
+            batch.insert_batch(
+                &tables.reported_execution_costs,
+                observation
+                    .object_costs
+                    .into_iter()
+                    .map(|(object, cost)| ((object, observation.authority), cost)),
+            )?;
+        }
+
         batch.insert_batch(
             &tables.user_signatures_for_checkpoints,
             self.user_signatures_for_checkpoints,
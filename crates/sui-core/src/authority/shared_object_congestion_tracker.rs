@@ -3,7 +3,9 @@
 
 use crate::authority::transaction_deferral::DeferralKey;
 use narwhal_types::Round;
-use std::collections::HashMap;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
 use sui_protocol_config::PerObjectCongestionControlMode;
 use sui_types::base_types::{ObjectID, TransactionDigest};
 use sui_types::executable_transaction::VerifiedExecutableTransaction;
@@ -20,18 +22,42 @@ use sui_types::transaction::SharedInputObject;
 // objects.
 //
 // The mode field determines how the cost is calculated. The cost can be calculated based on the total gas
-// budget, or total number of transaction count.
+// budget, total number of transaction count, or the execution cost observed for recent transactions on
+// the objects.
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub struct SharedObjectCongestionTracker {
     object_execution_cost: HashMap<ObjectID, u64>,
     mode: PerObjectCongestionControlMode,
+    // Estimated execution cost of a transaction on each object, used in ObservedExecutionCost mode.
+    object_cost_estimates: Arc<HashMap<ObjectID, u64>>,
+}
+
+/// The congestion trackers at the end of the last consensus commit that was processed, kept for
+/// inspection through the admin server.
+#[derive(Clone, Debug, Serialize)]
+pub struct CommitCongestionSnapshot {
+    pub commit_round: Round,
+    pub mode: PerObjectCongestionControlMode,
+    pub max_accumulated_txn_cost_per_object_in_commit: Option<u64>,
+    /// The accumulated cost of each object, over transactions that don't use randomness.
+    pub object_execution_cost: BTreeMap<ObjectID, u64>,
+    /// The accumulated cost of each object, over transactions that use randomness.
+    pub randomness_object_execution_cost: BTreeMap<ObjectID, u64>,
 }
 
 impl SharedObjectCongestionTracker {
     pub fn new(mode: PerObjectCongestionControlMode) -> Self {
+        Self::new_with_cost_estimates(mode, Arc::default())
+    }
+
+    pub fn new_with_cost_estimates(
+        mode: PerObjectCongestionControlMode,
+        object_cost_estimates: Arc<HashMap<ObjectID, u64>>,
+    ) -> Self {
         Self {
             object_execution_cost: HashMap::new(),
             mode,
+            object_cost_estimates,
         }
     }
 
@@ -46,6 +72,7 @@ impl SharedObjectCongestionTracker {
         Self {
             object_execution_cost,
             mode,
+            object_cost_estimates: Arc::default(),
         }
     }
 
@@ -67,6 +94,22 @@ impl SharedObjectCongestionTracker {
             PerObjectCongestionControlMode::None => None,
             PerObjectCongestionControlMode::TotalGasBudget => Some(cert.gas_budget()),
             PerObjectCongestionControlMode::TotalTxCount => Some(1),
+            PerObjectCongestionControlMode::ObservedExecutionCost => {
+                // A transaction cannot cost more than its budget, and for objects that no
+                // transaction has been observed on yet, the budget is all there is to go on.
+                let gas_budget = cert.gas_budget();
+                let estimate = cert
+                    .shared_input_objects()
+                    .map(|obj| {
+                        self.object_cost_estimates
+                            .get(&obj.id)
+                            .copied()
+                            .unwrap_or(gas_budget)
+                    })
+                    .max()
+                    .unwrap_or(gas_budget);
+                Some(estimate.min(gas_budget))
+            }
         }
     }
 
@@ -146,6 +189,18 @@ impl SharedObjectCongestionTracker {
         }
     }
 
+    pub fn mode(&self) -> PerObjectCongestionControlMode {
+        self.mode
+    }
+
+    // Returns the accumulated cost of each object, ordered by object ID.
+    pub fn object_execution_costs(&self) -> BTreeMap<ObjectID, u64> {
+        self.object_execution_cost
+            .iter()
+            .map(|(id, cost)| (*id, *cost))
+            .collect()
+    }
+
     // Returns the maximum cost of all objects.
     pub fn max_cost(&self) -> u64 {
         self.object_execution_cost
@@ -261,7 +316,8 @@ mod object_cost_tests {
     fn test_should_defer_return_correct_congested_objects(
         #[values(
             PerObjectCongestionControlMode::TotalGasBudget,
-            PerObjectCongestionControlMode::TotalTxCount
+            PerObjectCongestionControlMode::TotalTxCount,
+            PerObjectCongestionControlMode::ObservedExecutionCost
         )]
         mode: PerObjectCongestionControlMode,
     ) {
@@ -274,13 +330,15 @@ mod object_cost_tests {
         // Set max_accumulated_txn_cost_per_object_in_commit to only allow 1 transaction to go through.
         let max_accumulated_txn_cost_per_object_in_commit = match mode {
             PerObjectCongestionControlMode::None => unreachable!(),
-            PerObjectCongestionControlMode::TotalGasBudget => tx_gas_budget + 1,
+            PerObjectCongestionControlMode::TotalGasBudget
+            | PerObjectCongestionControlMode::ObservedExecutionCost => tx_gas_budget + 1,
             PerObjectCongestionControlMode::TotalTxCount => 2,
         };
 
         let shared_object_congestion_tracker = match mode {
             PerObjectCongestionControlMode::None => unreachable!(),
-            PerObjectCongestionControlMode::TotalGasBudget => {
+            PerObjectCongestionControlMode::TotalGasBudget
+            | PerObjectCongestionControlMode::ObservedExecutionCost => {
                 // Construct object execution cost as following
                 //                1     10
                 // object 0:            |
@@ -361,7 +419,8 @@ mod object_cost_tests {
     fn test_should_defer_return_correct_deferral_key(
         #[values(
             PerObjectCongestionControlMode::TotalGasBudget,
-            PerObjectCongestionControlMode::TotalTxCount
+            PerObjectCongestionControlMode::TotalTxCount,
+            PerObjectCongestionControlMode::ObservedExecutionCost
         )]
         mode: PerObjectCongestionControlMode,
     ) {
@@ -460,7 +519,8 @@ mod object_cost_tests {
     fn test_bump_object_execution_cost(
         #[values(
             PerObjectCongestionControlMode::TotalGasBudget,
-            PerObjectCongestionControlMode::TotalTxCount
+            PerObjectCongestionControlMode::TotalTxCount,
+            PerObjectCongestionControlMode::ObservedExecutionCost
         )]
         mode: PerObjectCongestionControlMode,
     ) {
@@ -492,7 +552,8 @@ mod object_cost_tests {
         shared_object_congestion_tracker.bump_object_execution_cost(&cert);
         let expected_object_0_cost = match mode {
             PerObjectCongestionControlMode::None => unreachable!(),
-            PerObjectCongestionControlMode::TotalGasBudget => 20,
+            PerObjectCongestionControlMode::TotalGasBudget
+            | PerObjectCongestionControlMode::ObservedExecutionCost => 20,
             PerObjectCongestionControlMode::TotalTxCount => 11,
         };
         assert_eq!(
@@ -518,7 +579,8 @@ mod object_cost_tests {
         );
         let expected_object_cost = match mode {
             PerObjectCongestionControlMode::None => unreachable!(),
-            PerObjectCongestionControlMode::TotalGasBudget => 30,
+            PerObjectCongestionControlMode::TotalGasBudget
+            | PerObjectCongestionControlMode::ObservedExecutionCost => 30,
             PerObjectCongestionControlMode::TotalTxCount => 12,
         };
         shared_object_congestion_tracker.bump_object_execution_cost(&cert);
@@ -538,4 +600,29 @@ mod object_cost_tests {
             expected_object_cost
         );
     }
+
+    #[test]
+    fn test_observed_execution_cost() {
+        let cheap_object = ObjectID::random();
+        let unobserved_object = ObjectID::random();
+        let estimates = Arc::new(HashMap::from([(cheap_object, 10)]));
+        let tracker = SharedObjectCongestionTracker::new_with_cost_estimates(
+            PerObjectCongestionControlMode::ObservedExecutionCost,
+            estimates,
+        );
+
+        // A large budget does not make a transaction on an object observed to be cheap expensive.
+        let cert = build_transaction(&[(cheap_object, true)], 1_000_000);
+        assert_eq!(tracker.get_tx_cost(&cert), Some(10));
+
+        // But the cost is still capped by the budget.
+        let cert = build_transaction(&[(cheap_object, true)], 5);
+        assert_eq!(tracker.get_tx_cost(&cert), Some(5));
+
+        // Objects that have not been observed yet fall back to the gas budget.
+        let cert = build_transaction(&[(unobserved_object, true)], 1_000_000);
+        assert_eq!(tracker.get_tx_cost(&cert), Some(1_000_000));
+        let cert = build_transaction(&[(cheap_object, true), (unobserved_object, true)], 100);
+        assert_eq!(tracker.get_tx_cost(&cert), Some(100));
+    }
 }
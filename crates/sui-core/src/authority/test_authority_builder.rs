@@ -47,6 +47,7 @@ use super::epoch_start_configuration::EpochFlag;
 #[derive(Default, Clone)]
 pub struct TestAuthorityBuilder<'a> {
     store_base_path: Option<PathBuf>,
+    db_path: Option<PathBuf>,
     store: Option<Arc<AuthorityStore>>,
     transaction_deny_config: Option<TransactionDenyConfig>,
     certificate_deny_config: Option<CertificateDenyConfig>,
@@ -74,6 +75,13 @@ impl<'a> TestAuthorityBuilder<'a> {
         self
     }
 
+    /// The node's DB directory, for files kept alongside the store such as audit logs. Pass the
+    /// `db_path` of a previous authority to restart it with the same files.
+    pub fn with_db_path(mut self, path: PathBuf) -> Self {
+        assert!(self.db_path.replace(path).is_none());
+        self
+    }
+
     pub fn with_starting_objects(mut self, objects: &'a [Object]) -> Self {
         assert!(self.starting_objects.replace(objects).is_none());
         self
@@ -199,6 +207,9 @@ impl<'a> TestAuthorityBuilder<'a> {
             }
         };
         let mut config = local_network_config.validator_configs()[0].clone();
+        if let Some(db_path) = self.db_path {
+            config.db_path = db_path;
+        }
         if let Some(cache_config) = self.cache_config {
             config.execution_cache = cache_config;
         }
@@ -229,8 +240,12 @@ impl<'a> TestAuthorityBuilder<'a> {
         .unwrap();
         let expensive_safety_checks = self.expensive_safety_checks.unwrap_or_default();
 
-        let cache_traits =
-            build_execution_cache(&epoch_start_configuration, &registry, &authority_store);
+        let cache_traits = build_execution_cache(
+            &config.execution_cache,
+            &epoch_start_configuration,
+            &registry,
+            &authority_store,
+        );
 
         let epoch_store = AuthorityPerEpochStore::new(
             name,
@@ -311,6 +326,7 @@ impl<'a> TestAuthorityBuilder<'a> {
             committee_store,
             index_store,
             rest_index,
+            None,
             checkpoint_store,
             &registry,
             genesis.objects(),
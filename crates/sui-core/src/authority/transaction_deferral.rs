@@ -3,7 +3,11 @@
 
 use narwhal_types::Round;
 use serde::{Deserialize, Serialize};
-use sui_types::base_types::ObjectID;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::Arc;
+use sui_types::base_types::{AuthorityName, ObjectID, TransactionDigest};
+use sui_types::committee::{Committee, CommitteeTrait, StakeUnit};
+use sui_types::messages_consensus::ExecutionCostObservation;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum DeferralKey {
@@ -73,6 +77,16 @@ impl DeferralKey {
     }
 }
 
+/// The transactions deferred under one key, for debugging.
+#[derive(Debug, Serialize)]
+pub struct DeferredTransactions {
+    pub deferral_key: DeferralKey,
+    /// Digests of the deferred transactions that are executable. Other consensus messages can be
+    /// deferred too, and are only counted.
+    pub transactions: Vec<TransactionDigest>,
+    pub total: usize,
+}
+
 #[derive(Debug)]
 pub enum DeferralReason {
     RandomnessNotReady,
@@ -98,6 +112,157 @@ pub fn transaction_deferral_within_limit(
     true
 }
 
+/// Estimates of the execution cost of a transaction on each shared object, used as the transaction
+/// cost for deferral under `PerObjectCongestionControlMode::ObservedExecutionCost`.
+///
+/// Validators measure execution costs locally (see [`LocalExecutionCostTracker`]), and report them
+/// through consensus. The estimate for an object is the stake-weighted median of the costs that
+/// validators last reported for it, once validators with a quorum of stake have reported one.
+/// Estimates only change as reports are processed in consensus order, so every validator uses the
+/// same estimates for the same commit, however far it has executed.
+///
+/// Reports are kept in per-epoch tables, so estimates start out empty in each epoch, and
+/// transactions are costed at their gas budget until a quorum has reported on their objects again.
+#[derive(Debug)]
+pub struct ExecutionCostEstimator {
+    committee: Arc<Committee>,
+    estimates: Arc<HashMap<ObjectID, u64>>,
+    reported_costs: HashMap<ObjectID, BTreeMap<AuthorityName, u64>>,
+    generations: HashMap<AuthorityName, u64>,
+}
+
+impl ExecutionCostEstimator {
+    pub fn new(
+        committee: Arc<Committee>,
+        reported_costs: impl IntoIterator<Item = ((ObjectID, AuthorityName), u64)>,
+        generations: impl IntoIterator<Item = (AuthorityName, u64)>,
+    ) -> Self {
+        let mut costs_by_object: HashMap<ObjectID, BTreeMap<AuthorityName, u64>> = HashMap::new();
+        for ((object, authority), cost) in reported_costs {
+            costs_by_object
+                .entry(object)
+                .or_default()
+                .insert(authority, cost);
+        }
+        let estimates = costs_by_object
+            .iter()
+            .filter_map(|(object, costs)| {
+                stake_weighted_median(&committee, costs).map(|estimate| (*object, estimate))
+            })
+            .collect();
+        Self {
+            committee,
+            estimates: Arc::new(estimates),
+            reported_costs: costs_by_object,
+            generations: generations.into_iter().collect(),
+        }
+    }
+
+    /// The current estimates. These only change when observations are processed, so a commit
+    /// holds on to them while it is processed.
+    pub fn estimates(&self) -> Arc<HashMap<ObjectID, u64>> {
+        self.estimates.clone()
+    }
+
+    /// The generation of the last observation processed from `authority`.
+    pub fn generation(&self, authority: &AuthorityName) -> Option<u64> {
+        self.generations.get(authority).copied()
+    }
+
+    /// Apply an observation sequenced by consensus. Returns false, without changing anything, if
+    /// it is not newer than the last observation processed from the same authority.
+    pub fn process_observation(&mut self, observation: &ExecutionCostObservation) -> bool {
+        let authority = observation.authority;
+        if self
+            .generation(&authority)
+            .is_some_and(|generation| generation >= observation.generation)
+        {
+            return false;
+        }
+        self.generations.insert(authority, observation.generation);
+
+        let estimates = Arc::make_mut(&mut self.estimates);
+        for (object, cost) in &observation.object_costs {
+            let costs = self.reported_costs.entry(*object).or_default();
+            costs.insert(authority, *cost);
+            match stake_weighted_median(&self.committee, costs) {
+                Some(estimate) => estimates.insert(*object, estimate),
+                None => estimates.remove(object),
+            };
+        }
+        true
+    }
+}
+
+/// The stake-weighted median of the costs reported for an object, if authorities with a quorum of
+/// stake have reported one. Less than a third of the stake cannot move it outside the range of
+/// costs reported by honest authorities.
+fn stake_weighted_median(
+    committee: &Committee,
+    costs: &BTreeMap<AuthorityName, u64>,
+) -> Option<u64> {
+    let mut costs: Vec<_> = costs
+        .iter()
+        .map(|(authority, cost)| (*cost, committee.weight(authority)))
+        .collect();
+    let reported_stake: StakeUnit = costs.iter().map(|(_, stake)| stake).sum();
+    if reported_stake < committee.quorum_threshold() {
+        return None;
+    }
+
+    costs.sort_unstable();
+    let mut stake = 0;
+    for (cost, weight) in costs {
+        stake += weight;
+        if 2 * stake >= reported_stake {
+            return Some(cost);
+        }
+    }
+    unreachable!("Cumulative stake reaches the reported stake")
+}
+
+/// The execution cost of transactions on each shared object as measured by this validator: an
+/// exponentially weighted average of the computation cost in the effects of the transactions it
+/// executed. Costs that changed are reported through consensus in an `ExecutionCostObservation`.
+#[derive(Debug, Default)]
+pub struct LocalExecutionCostTracker {
+    costs: HashMap<ObjectID, u64>,
+    unreported: BTreeSet<ObjectID>,
+}
+
+const BASIS_POINTS: u128 = 10_000;
+
+impl LocalExecutionCostTracker {
+    /// Fold the computation cost of an executed transaction into the costs of the shared objects
+    /// it used, giving it `weight_bps` of the weight.
+    pub fn record(&mut self, shared_objects: &[ObjectID], cost: u64, weight_bps: u64) {
+        let weight = (weight_bps as u128).min(BASIS_POINTS);
+        for object in shared_objects {
+            let estimate = match self.costs.get(object) {
+                None => cost,
+                Some(previous) => {
+                    ((cost as u128 * weight + *previous as u128 * (BASIS_POINTS - weight))
+                        / BASIS_POINTS) as u64
+                }
+            };
+            self.costs.insert(*object, estimate);
+            self.unreported.insert(*object);
+        }
+    }
+
+    /// Take at most `max` of the costs that changed since they were last taken.
+    pub fn take_unreported(&mut self, max: usize) -> Vec<(ObjectID, u64)> {
+        let objects: Vec<_> = self.unreported.iter().take(max).copied().collect();
+        objects
+            .into_iter()
+            .map(|object| {
+                self.unreported.remove(&object);
+                (object, self.costs[&object])
+            })
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod object_cost_tests {
     use super::*;
@@ -147,6 +312,92 @@ mod object_cost_tests {
         }
     }
 
+    #[test]
+    fn test_execution_cost_estimator() {
+        let (committee, _) = Committee::new_simple_test_committee();
+        let authorities: Vec<_> = committee.names().copied().collect();
+        let object_0 = ObjectID::random();
+        let object_1 = ObjectID::random();
+        let observation = |authority: usize, generation: u64, object_costs: &[(ObjectID, u64)]| {
+            ExecutionCostObservation {
+                authority: authorities[authority],
+                generation,
+                object_costs: object_costs.to_vec(),
+            }
+        };
+
+        let mut estimator = ExecutionCostEstimator::new(Arc::new(committee.clone()), [], []);
+
+        // There is no estimate until a quorum has reported a cost.
+        assert!(estimator.process_observation(&observation(0, 1, &[(object_0, 100)])));
+        assert!(estimator.process_observation(&observation(1, 1, &[(object_0, 300)])));
+        assert!(estimator.estimates().is_empty());
+
+        // The estimate is the median of the reported costs, so that a single authority cannot
+        // move it arbitrarily.
+        let estimates = estimator.estimates();
+        assert!(estimator.process_observation(&observation(2, 1, &[(object_0, 1_000_000)])));
+        assert_eq!(estimator.estimates().get(&object_0), Some(&300));
+        assert!(estimator.process_observation(&observation(
+            3,
+            1,
+            &[(object_0, 200), (object_1, 10)]
+        )));
+        assert_eq!(estimator.estimates().get(&object_0), Some(&200));
+        assert_eq!(estimator.estimates().get(&object_1), None);
+
+        // Estimates handed out earlier are not affected.
+        assert!(estimates.is_empty());
+
+        // Observations that are not newer than the last one from the same authority are ignored.
+        assert!(!estimator.process_observation(&observation(3, 1, &[(object_0, 1)])));
+        assert_eq!(estimator.estimates().get(&object_0), Some(&200));
+        assert!(estimator.process_observation(&observation(2, 2, &[(object_0, 150)])));
+        assert_eq!(estimator.estimates().get(&object_0), Some(&150));
+        assert_eq!(estimator.generation(&authorities[2]), Some(2));
+
+        // The estimator is rebuilt from the reported costs alone.
+        let reported_costs = [
+            ((object_0, authorities[0]), 100),
+            ((object_0, authorities[1]), 300),
+            ((object_0, authorities[2]), 150),
+            ((object_0, authorities[3]), 200),
+            ((object_1, authorities[3]), 10),
+        ];
+        let generations = [
+            (authorities[0], 1),
+            (authorities[1], 1),
+            (authorities[2], 2),
+            (authorities[3], 1),
+        ];
+        let reloaded =
+            ExecutionCostEstimator::new(Arc::new(committee), reported_costs, generations);
+        assert_eq!(reloaded.estimates(), estimator.estimates());
+        assert_eq!(reloaded.generation(&authorities[2]), Some(2));
+    }
+
+    #[test]
+    fn test_local_execution_cost_tracker() {
+        let object_0 = ObjectID::random();
+        let object_1 = ObjectID::random();
+        let mut tracker = LocalExecutionCostTracker::default();
+
+        // The first cost of an object is taken as is, later ones are weighted against it.
+        tracker.record(&[object_0], 1000, 2500);
+        tracker.record(&[object_0, object_1], 200, 2500);
+        let mut costs = tracker.take_unreported(10);
+        costs.sort();
+        let mut expected = vec![(object_0, 800), (object_1, 200)];
+        expected.sort();
+        assert_eq!(costs, expected);
+
+        // Costs are only taken again once they change, and at most `max` at a time.
+        assert!(tracker.take_unreported(10).is_empty());
+        tracker.record(&[object_0, object_1], 200, 2500);
+        assert_eq!(tracker.take_unreported(1).len(), 1);
+        assert_eq!(tracker.take_unreported(10).len(), 1);
+    }
+
     // Tests that fetching deferred transactions up to a given consensus rounds works as expected.
     #[tokio::test]
     async fn test_fetching_deferred_txs() {
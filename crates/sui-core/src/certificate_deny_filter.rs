@@ -0,0 +1,220 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use arc_swap::ArcSwap;
+use parking_lot::Mutex;
+use prometheus::{
+    register_int_counter_vec_with_registry, register_int_counter_with_registry,
+    register_int_gauge_with_registry, IntCounter, IntCounterVec, IntGauge, Registry,
+};
+use sui_config::certificate_deny_config::{CertificateDenyConfig, CertificateDenyConfigUpdate};
+use sui_types::base_types::{EpochId, TransactionDigest};
+use tracing::{error, info, warn};
+
+use crate::authority::AuthorityState;
+use crate::transaction_signing_filter::{AuditLog, AuditRecord};
+
+/// Name of the audit log file, relative to the node's DB directory.
+pub const CERTIFICATE_DENY_AUDIT_LOG: &str = "certificate_deny_audit.log";
+
+/// The deny config that certificates are checked against when they are executed. It starts out as
+/// the `certificate-deny-config` from the node config, and can then be changed at runtime. Changes
+/// are recorded in a local audit log in the same way as for the `TransactionSigningFilter`, and the
+/// log is replayed on top of the node config when the node starts.
+///
+/// Denying a certificate changes the effects it executes to, so every validator must apply the same
+/// change before the certificate is executed, or they will fork. Replaying the log keeps a node
+/// that restarts from executing certificates differently than it did before it stopped.
+pub struct CertificateDenyFilter {
+    config: ArcSwap<CertificateDenyConfig>,
+    /// The certificates denied in the epoch that was last asked about, rebuilt whenever the config
+    /// changes or a different epoch is asked about.
+    deny_set: ArcSwap<EpochDenySet>,
+    /// Serializes rebuilds of the deny set, so that a rebuild never overwrites the set of a newer
+    /// config with an older one. Separate from the audit log lock, which is held while writing to
+    /// disk, so that executing certificates in a new epoch never waits on the audit log.
+    deny_set_rebuild: Mutex<()>,
+    /// Append-only record of changes. The lock also serializes updates.
+    audit_log: Mutex<AuditLog>,
+    metrics: CertificateDenyFilterMetrics,
+}
+
+struct EpochDenySet {
+    epoch: EpochId,
+    set: Arc<HashSet<TransactionDigest>>,
+}
+
+struct CertificateDenyFilterMetrics {
+    updates: IntCounterVec,
+    deny_list_size: IntGauge,
+    denied_certificates: IntCounter,
+    audit_log_errors: IntCounter,
+}
+
+impl CertificateDenyFilter {
+    pub fn new(
+        mut config: CertificateDenyConfig,
+        audit_log_path: PathBuf,
+        registry: &Registry,
+    ) -> anyhow::Result<Self> {
+        let updates: Vec<CertificateDenyConfigUpdate> = AuditLog::replay(&audit_log_path)?;
+        for update in &updates {
+            config.apply(update);
+        }
+
+        if !updates.is_empty() {
+            info!(
+                "Replayed {} certificate deny config updates from {}",
+                updates.len(),
+                audit_log_path.display()
+            );
+        }
+
+        let metrics = CertificateDenyFilterMetrics::new(registry);
+        metrics.observe(&config);
+
+        let deny_set = EpochDenySet {
+            epoch: 0,
+            set: Arc::new(config.certificate_deny_set(0)),
+        };
+
+        Ok(Self {
+            config: ArcSwap::new(Arc::new(config)),
+            deny_set: ArcSwap::new(Arc::new(deny_set)),
+            deny_set_rebuild: Mutex::new(()),
+            audit_log: Mutex::new(AuditLog::new(audit_log_path)),
+            metrics,
+        })
+    }
+
+    pub fn load(&self) -> Arc<CertificateDenyConfig> {
+        self.config.load_full()
+    }
+
+    /// The certificates denied when executing in `epoch`, excluding entries that have expired.
+    pub fn certificate_deny_set(&self, epoch: EpochId) -> Arc<HashSet<TransactionDigest>> {
+        let deny_set = self.deny_set.load();
+        if deny_set.epoch == epoch {
+            return deny_set.set.clone();
+        }
+
+        let _guard = self.deny_set_rebuild.lock();
+        let deny_set = self.deny_set.load();
+        if deny_set.epoch == epoch {
+            return deny_set.set.clone();
+        }
+
+        self.rebuild_deny_set(epoch)
+    }
+
+    /// Record that a certificate denied by the config is being executed, and so will fail.
+    pub fn report_denied(&self, digest: &TransactionDigest, epoch: EpochId) {
+        self.metrics.denied_certificates.inc();
+        warn!(
+            ?digest,
+            epoch, "Executing certificate denied by certificate deny config"
+        );
+    }
+
+    /// Apply `updates` to the config as a single change. The change is recorded in the audit log
+    /// before it takes effect, and if it cannot be recorded, it is not applied. Returns whether the
+    /// config changed.
+    pub fn update(&self, updates: &[CertificateDenyConfigUpdate]) -> anyhow::Result<bool> {
+        let mut audit_log = self.audit_log.lock();
+
+        let mut config = CertificateDenyConfig::clone(&self.config.load());
+        let mut changed = false;
+        for update in updates {
+            changed |= config.apply(update);
+        }
+
+        let record = AuditRecord {
+            timestamp_ms: AuthorityState::unixtime_now_ms(),
+            updates,
+            changed,
+        };
+
+        if let Err(e) = audit_log.append(&record) {
+            self.metrics.audit_log_errors.inc();
+            error!(
+                "Failed to record certificate deny config update in {}: {e}",
+                audit_log.path().display()
+            );
+            return Err(e);
+        }
+
+        for update in updates {
+            self.metrics
+                .updates
+                .with_label_values(&[update.kind()])
+                .inc();
+        }
+
+        if changed {
+            info!(?updates, "Certificate deny config updated");
+            self.metrics.observe(&config);
+            self.config.store(Arc::new(config));
+
+            let _guard = self.deny_set_rebuild.lock();
+            self.rebuild_deny_set(self.deny_set.load().epoch);
+        }
+
+        Ok(changed)
+    }
+
+    pub fn audit_log_path(&self) -> PathBuf {
+        self.audit_log.lock().path().to_path_buf()
+    }
+
+    /// Must be called with the deny set rebuild lock held.
+    fn rebuild_deny_set(&self, epoch: EpochId) -> Arc<HashSet<TransactionDigest>> {
+        let set = Arc::new(self.config.load().certificate_deny_set(epoch));
+        self.deny_set.store(Arc::new(EpochDenySet {
+            epoch,
+            set: set.clone(),
+        }));
+        set
+    }
+}
+
+impl CertificateDenyFilterMetrics {
+    fn new(registry: &Registry) -> Self {
+        Self {
+            updates: register_int_counter_vec_with_registry!(
+                "certificate_deny_config_updates",
+                "Number of runtime updates made to the certificate deny config, by kind",
+                &["kind"],
+                registry,
+            )
+            .unwrap(),
+            deny_list_size: register_int_gauge_with_registry!(
+                "certificate_deny_config_list_size",
+                "Number of entries in the certificate deny list, including expired ones",
+                registry,
+            )
+            .unwrap(),
+            denied_certificates: register_int_counter_with_registry!(
+                "certificate_deny_config_denied_certificates",
+                "Number of times a certificate denied by the certificate deny config was executed",
+                registry,
+            )
+            .unwrap(),
+            audit_log_errors: register_int_counter_with_registry!(
+                "certificate_deny_config_audit_log_errors",
+                "Number of certificate deny config updates rejected because they could not be \
+                 written to the audit log",
+                registry,
+            )
+            .unwrap(),
+        }
+    }
+
+    fn observe(&self, config: &CertificateDenyConfig) {
+        self.deny_list_size
+            .set(config.certificate_deny_list().len() as i64);
+    }
+}
@@ -0,0 +1,447 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Fast sync applies the outputs of checkpointed transactions from a store or archive of full
+//! checkpoint data, instead of executing the transactions. The data is checked against the
+//! certified checkpoint before anything is written, and checkpoints whose data is missing or does
+//! not match fall back to normal execution.
+//!
+//! Fast synced state is only as trustworthy as the state it was applied on top of, so fast sync
+//! refuses to start unless the node's state derives from a trusted root, and halts the node if the
+//! state at the end of a fast synced epoch does not match the certified root, as a fork would.
+
+use std::collections::{BTreeSet, VecDeque};
+use std::num::NonZeroUsize;
+use std::ops::Range;
+use std::sync::Arc;
+
+use anyhow::{bail, ensure};
+use fastcrypto::hash::MultisetHash;
+use object_store::path::Path;
+use object_store::DynObjectStore;
+use prometheus::Registry;
+use sui_archival::reader::{ArchiveReader, ArchiveReaderMetrics};
+use sui_config::node::{ArchiveReaderConfig, CheckpointDataSource, FastSyncConfig};
+use sui_storage::blob::Blob;
+use sui_storage::object_store::ObjectStoreGetExt;
+use sui_types::accumulator::Accumulator;
+use sui_types::effects::TransactionEffectsAPI;
+use sui_types::executable_transaction::VerifiedExecutableTransaction;
+use sui_types::full_checkpoint_content::CheckpointData;
+use sui_types::message_envelope::Message;
+use sui_types::messages_checkpoint::{
+    CheckpointCommitment, CheckpointSequenceNumber, ECMHLiveObjectSetDigest, VerifiedCheckpoint,
+};
+use sui_types::transaction::{TransactionDataAPI, VerifiedTransaction};
+use tokio::sync::{watch, Mutex};
+use tracing::{debug, info, warn};
+
+use super::metrics::CheckpointExecutorMetrics;
+use crate::authority::authority_per_epoch_store::AuthorityPerEpochStore;
+use crate::authority::AuthorityState;
+use crate::checkpoints::CheckpointStore;
+use crate::execution_cache::TransactionCacheRead;
+use crate::state_accumulator::AccumulatorStore;
+
+/// The number of checkpoint data archive files to keep after downloading them.
+const ARCHIVE_FILES_TO_KEEP: usize = 2;
+
+pub struct FastSync {
+    store: CheckpointDataStore,
+    target_checkpoint: CheckpointSequenceNumber,
+    /// The next checkpoint whose outputs may be applied. Checkpoint data is fetched concurrently,
+    /// but each checkpoint's outputs are applied only once every checkpoint before it has been
+    /// executed, because the objects it modified are read from the local store.
+    next_to_apply: watch::Sender<CheckpointSequenceNumber>,
+    metrics: Arc<CheckpointExecutorMetrics>,
+}
+
+enum CheckpointDataStore {
+    /// Each checkpoint's data is in its own `<sequence number>.chk` file.
+    ObjectStore(Arc<DynObjectStore>),
+    /// Each file of the archive holds the data of a range of checkpoints. The most recently
+    /// downloaded files are kept, since their checkpoints are fetched in order.
+    Archive {
+        reader: ArchiveReader,
+        files: Mutex<VecDeque<(Range<CheckpointSequenceNumber>, Arc<Vec<CheckpointData>>)>>,
+    },
+}
+
+impl FastSync {
+    /// Fails if the store cannot be opened, or if the node's state does not derive from a trusted
+    /// root.
+    pub fn new(
+        config: &FastSyncConfig,
+        checkpoint_store: &CheckpointStore,
+        accumulator_store: &dyn AccumulatorStore,
+        metrics: Arc<CheckpointExecutorMetrics>,
+    ) -> anyhow::Result<Self> {
+        check_trusted_root(checkpoint_store, accumulator_store)?;
+
+        let store = match &config.checkpoint_data_source {
+            CheckpointDataSource::ObjectStore(store_config) => {
+                CheckpointDataStore::ObjectStore(store_config.make()?)
+            }
+            CheckpointDataSource::Archive(store_config) => {
+                let reader_config = ArchiveReaderConfig {
+                    remote_store_config: store_config.clone(),
+                    download_concurrency: NonZeroUsize::new(1).unwrap(),
+                    use_for_pruning_watermark: false,
+                };
+                let reader_metrics = ArchiveReaderMetrics::new(&Registry::default());
+                CheckpointDataStore::Archive {
+                    reader: ArchiveReader::new(reader_config, &reader_metrics)?,
+                    files: Mutex::new(VecDeque::new()),
+                }
+            }
+        };
+
+        Ok(Self {
+            store,
+            target_checkpoint: config.target_checkpoint,
+            next_to_apply: watch::Sender::new(0),
+            metrics,
+        })
+    }
+
+    /// Whether `seq` is at or before the fast sync target.
+    pub(crate) fn covers(&self, seq: CheckpointSequenceNumber) -> bool {
+        seq <= self.target_checkpoint
+    }
+
+    /// Check the root of the live object set at the end of a fast synced epoch against the one
+    /// committed to by the epoch's last checkpoint. The caller must not continue past a mismatch,
+    /// since the node's state has diverged from the certified state.
+    pub(crate) fn check_epoch_root(
+        &self,
+        checkpoint: &VerifiedCheckpoint,
+        epoch_acc: &Accumulator,
+    ) -> anyhow::Result<()> {
+        let Some(expected) = live_object_set_commitment(checkpoint) else {
+            warn!(
+                "Checkpoint {} has no live object set commitment, so the fast synced state of epoch {} cannot be checked",
+                checkpoint.sequence_number(),
+                checkpoint.epoch(),
+            );
+            return Ok(());
+        };
+
+        let actual = ECMHLiveObjectSetDigest::from(epoch_acc.digest());
+        if expected != &actual {
+            bail!(
+                "Fast synced state of epoch {} has root {:?}, but checkpoint {} committed to {:?}",
+                checkpoint.epoch(),
+                actual.digest,
+                checkpoint.sequence_number(),
+                expected.digest,
+            );
+        }
+
+        info!(
+            "Fast synced state of epoch {} matches its live object set commitment",
+            checkpoint.epoch()
+        );
+        Ok(())
+    }
+
+    /// Set the next checkpoint to apply, when the executor (re)starts scheduling at `next`.
+    pub(crate) fn reset(&self, next: CheckpointSequenceNumber) {
+        self.next_to_apply.send_replace(next);
+    }
+
+    /// Allow the checkpoint after `seq` to be applied, once `seq` has been fully executed.
+    pub(crate) fn finish(&self, seq: CheckpointSequenceNumber) {
+        self.next_to_apply.send_if_modified(|next| {
+            if *next == seq {
+                *next = seq + 1;
+                true
+            } else {
+                false
+            }
+        });
+    }
+
+    /// Apply the outputs of every transaction in `checkpoint` except the end of epoch transaction,
+    /// which is always executed. Transactions that have already been executed are skipped. If the
+    /// checkpoint's data is unavailable or does not match, nothing is applied and the checkpoint is
+    /// left to be executed as normal.
+    ///
+    /// The caller must call `finish` once the checkpoint has been executed.
+    pub(crate) async fn sync_checkpoint(
+        &self,
+        checkpoint: &VerifiedCheckpoint,
+        state: &AuthorityState,
+        transaction_cache_reader: &dyn TransactionCacheRead,
+        epoch_store: &Arc<AuthorityPerEpochStore>,
+    ) {
+        let seq = *checkpoint.sequence_number();
+        let data = self.fetch(seq).await;
+
+        let mut next_to_apply = self.next_to_apply.subscribe();
+        // The sender lives as long as `self`, so this can only fail if the executor is dropped.
+        if next_to_apply.wait_for(|next| *next >= seq).await.is_err() {
+            return;
+        }
+
+        let Some(data) = data else {
+            self.metrics.checkpoint_fast_sync_fallbacks.inc();
+            return;
+        };
+
+        if let Err(e) = verify_checkpoint_data(checkpoint, &data) {
+            warn!("Checkpoint data for {seq} does not match the certified checkpoint, executing it instead: {e}");
+            self.metrics.checkpoint_fast_sync_fallbacks.inc();
+            return;
+        }
+
+        let mut applied = 0;
+        for tx in data.transactions {
+            if tx.transaction.transaction_data().is_end_of_epoch_tx() {
+                continue;
+            }
+
+            let digest = *tx.transaction.digest();
+            match transaction_cache_reader.is_tx_already_executed(&digest) {
+                Ok(true) => continue,
+                Ok(false) => (),
+                Err(e) => {
+                    warn!("Failed to check whether {digest:?} was executed, executing the rest of checkpoint {seq} instead: {e}");
+                    self.metrics.checkpoint_fast_sync_fallbacks.inc();
+                    return;
+                }
+            }
+
+            let certificate = VerifiedExecutableTransaction::new_from_checkpoint(
+                VerifiedTransaction::new_unchecked(tx.transaction),
+                epoch_store.epoch(),
+                seq,
+            );
+
+            let written = tx
+                .output_objects
+                .into_iter()
+                .map(|object| (object.id(), object))
+                .collect();
+
+            // Any transactions left unapplied here are executed by the normal path, which checks
+            // their effects against the checkpoint as usual.
+            if let Err(e) = state
+                .commit_checkpointed_outputs(
+                    &certificate,
+                    &tx.effects,
+                    tx.events.unwrap_or_default(),
+                    written,
+                    epoch_store,
+                )
+                .await
+            {
+                warn!("Failed to apply outputs of {digest:?}, executing the rest of checkpoint {seq} instead: {e}");
+                self.metrics.checkpoint_fast_sync_fallbacks.inc();
+                return;
+            }
+
+            applied += 1;
+        }
+
+        debug!("Applied outputs of {applied} transactions from checkpoint {seq}");
+        self.metrics.checkpoint_fast_sync_applied.inc();
+        self.metrics
+            .checkpoint_fast_sync_transactions
+            .inc_by(applied);
+
+        if seq == self.target_checkpoint {
+            info!("Fast sync reached target checkpoint {seq}");
+        }
+    }
+
+    async fn fetch(&self, seq: CheckpointSequenceNumber) -> Option<CheckpointData> {
+        match &self.store {
+            CheckpointDataStore::ObjectStore(store) => {
+                let path = Path::from(format!("{seq}.chk"));
+                let bytes = match store.get_bytes(&path).await {
+                    Ok(bytes) => bytes,
+                    Err(e) => {
+                        warn!(
+                            "Checkpoint data for {seq} is not available, executing it instead: {e}"
+                        );
+                        return None;
+                    }
+                };
+
+                Blob::from_bytes::<CheckpointData>(&bytes)
+                    .map_err(|e| {
+                        warn!(
+                            "Failed to decode checkpoint data for {seq}, executing it instead: {e}"
+                        )
+                    })
+                    .ok()
+            }
+            CheckpointDataStore::Archive { reader, files } => {
+                // Held while downloading, so that each file is only downloaded once when its
+                // checkpoints are fetched concurrently.
+                let mut files = files.lock().await;
+                if let Some((range, data)) = files.iter().find(|(range, _)| range.contains(&seq)) {
+                    return data.get((seq - range.start) as usize).cloned();
+                }
+
+                // The archive's manifest is synced periodically, so it may be behind the archive.
+                if reader
+                    .latest_available_checkpoint()
+                    .await
+                    .map_or(true, |latest| latest < seq)
+                {
+                    if let Err(e) = reader.sync_manifest_once().await {
+                        warn!("Failed to sync checkpoint data archive manifest: {e}");
+                    }
+                }
+
+                let (range, data) = match reader.read_checkpoint_data_file(seq).await {
+                    Ok(file) => file,
+                    Err(e) => {
+                        warn!(
+                            "Checkpoint data for {seq} is not available, executing it instead: {e}"
+                        );
+                        return None;
+                    }
+                };
+                let checkpoint_data = data.get((seq - range.start) as usize).cloned();
+                if files.len() == ARCHIVE_FILES_TO_KEEP {
+                    files.pop_front();
+                }
+                files.push_back((range, Arc::new(data)));
+                checkpoint_data
+            }
+        }
+    }
+}
+
+/// The root of the live object set committed to by an end of epoch checkpoint, if any.
+fn live_object_set_commitment(checkpoint: &VerifiedCheckpoint) -> Option<&ECMHLiveObjectSetDigest> {
+    match checkpoint
+        .end_of_epoch_data
+        .as_ref()?
+        .epoch_commitments
+        .first()?
+    {
+        CheckpointCommitment::ECMHLiveObjectSetDigest(digest) => Some(digest),
+    }
+}
+
+/// Check that the node's state derives from a trusted root before anything is fast synced on top
+/// of it. Within the first epoch, the state derives from genesis. After that, the root of the live
+/// object set at the end of the last completed epoch, such as the epoch of the formal snapshot the
+/// node was restored from, must match the one committed to by that epoch's last checkpoint.
+fn check_trusted_root(
+    checkpoint_store: &CheckpointStore,
+    accumulator_store: &dyn AccumulatorStore,
+) -> anyhow::Result<()> {
+    let Some(highest_executed) = checkpoint_store.get_highest_executed_checkpoint()? else {
+        info!("Fast syncing from genesis");
+        return Ok(());
+    };
+
+    let last_completed_epoch = if highest_executed.end_of_epoch_data.is_some() {
+        Some(highest_executed.epoch())
+    } else {
+        highest_executed.epoch().checked_sub(1)
+    };
+    let Some(epoch) = last_completed_epoch else {
+        info!("Fast syncing from genesis");
+        return Ok(());
+    };
+
+    let Some(last_checkpoint) = checkpoint_store.get_epoch_last_checkpoint(epoch)? else {
+        bail!("last checkpoint of epoch {epoch} is unknown, so the node's state cannot be checked against it");
+    };
+    let Some((_, root)) = accumulator_store.get_root_state_accumulator_for_epoch(epoch)? else {
+        bail!("there is no root state hash for epoch {epoch}, so the node's state cannot be checked against it");
+    };
+    let Some(expected) = live_object_set_commitment(&last_checkpoint) else {
+        bail!(
+            "checkpoint {} has no live object set commitment to check the state of epoch {epoch} against",
+            last_checkpoint.sequence_number(),
+        );
+    };
+
+    let actual = ECMHLiveObjectSetDigest::from(root.digest());
+    ensure!(
+        expected == &actual,
+        "state at the end of epoch {epoch} has root {:?}, but its last checkpoint committed to {:?}",
+        actual.digest,
+        expected.digest,
+    );
+
+    info!("Fast syncing from the certified state at the end of epoch {epoch}");
+    Ok(())
+}
+
+/// Check that `data` holds exactly the transactions of `checkpoint`, and that each transaction's
+/// effects, events and output objects are the ones committed to by the checkpoint.
+fn verify_checkpoint_data(
+    checkpoint: &VerifiedCheckpoint,
+    data: &CheckpointData,
+) -> anyhow::Result<()> {
+    ensure!(
+        data.checkpoint_summary.digest() == checkpoint.digest(),
+        "summary digest {} does not match {}",
+        data.checkpoint_summary.digest(),
+        checkpoint.digest(),
+    );
+    ensure!(
+        data.checkpoint_contents.digest() == &checkpoint.content_digest,
+        "contents digest {} does not match {}",
+        data.checkpoint_contents.digest(),
+        checkpoint.content_digest,
+    );
+    ensure!(
+        data.checkpoint_contents.size() == data.transactions.len(),
+        "expected {} transactions, found {}",
+        data.checkpoint_contents.size(),
+        data.transactions.len(),
+    );
+
+    for (digests, tx) in data.checkpoint_contents.iter().zip(&data.transactions) {
+        let tx_digest = tx.transaction.digest();
+        ensure!(
+            tx_digest == &digests.transaction,
+            "expected transaction {:?}, found {tx_digest:?}",
+            digests.transaction,
+        );
+
+        let effects_digest = tx.effects.digest();
+        ensure!(
+            effects_digest == digests.effects,
+            "effects of {tx_digest:?} have digest {effects_digest:?}, expected {:?}",
+            digests.effects,
+        );
+        ensure!(
+            tx.effects.transaction_digest() == tx_digest,
+            "effects are for {:?}, not {tx_digest:?}",
+            tx.effects.transaction_digest(),
+        );
+
+        let events_digest = tx.events.as_ref().map(|events| events.digest());
+        ensure!(
+            events_digest.as_ref() == tx.effects.events_digest(),
+            "events of {tx_digest:?} do not match their effects",
+        );
+
+        let expected: BTreeSet<_> = tx
+            .effects
+            .all_changed_objects()
+            .into_iter()
+            .map(|(object_ref, _, _)| object_ref)
+            .collect();
+        let actual: BTreeSet<_> = tx
+            .output_objects
+            .iter()
+            .map(|object| object.compute_object_reference())
+            .collect();
+        ensure!(
+            expected == actual,
+            "output objects of {tx_digest:?} do not match their effects",
+        );
+    }
+
+    Ok(())
+}
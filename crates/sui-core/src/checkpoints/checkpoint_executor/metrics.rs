@@ -24,6 +24,9 @@ pub struct CheckpointExecutorMetrics {
     pub last_executed_checkpoint_age: Histogram,
     // TODO: delete once users are migrated to non-Mysten histogram.
     pub last_executed_checkpoint_age_ms: MystenHistogram,
+    pub checkpoint_fast_sync_applied: IntCounter,
+    pub checkpoint_fast_sync_transactions: IntCounter,
+    pub checkpoint_fast_sync_fallbacks: IntCounter,
 }
 
 impl CheckpointExecutorMetrics {
@@ -110,6 +113,24 @@ impl CheckpointExecutorMetrics {
                 "Age of the last executed checkpoint",
                 registry,
             ),
+            checkpoint_fast_sync_applied: register_int_counter_with_registry!(
+                "checkpoint_fast_sync_applied",
+                "Number of checkpoints whose transaction outputs were applied by fast sync",
+                registry
+            )
+            .unwrap(),
+            checkpoint_fast_sync_transactions: register_int_counter_with_registry!(
+                "checkpoint_fast_sync_transactions",
+                "Number of transactions whose outputs were applied by fast sync",
+                registry
+            )
+            .unwrap(),
+            checkpoint_fast_sync_fallbacks: register_int_counter_with_registry!(
+                "checkpoint_fast_sync_fallbacks",
+                "Number of checkpoints covered by fast sync that were executed instead",
+                registry
+            )
+            .unwrap(),
         };
         Arc::new(this)
     }
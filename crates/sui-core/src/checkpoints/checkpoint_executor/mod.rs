@@ -46,7 +46,10 @@ use sui_types::{
 use sui_types::{error::SuiResult, transaction::TransactionDataAPI};
 use tap::{TapFallible, TapOptional};
 use tokio::{
-    sync::broadcast::{self, error::RecvError},
+    sync::{
+        broadcast::{self, error::RecvError},
+        watch,
+    },
     task::JoinHandle,
     time::timeout,
 };
@@ -67,8 +70,11 @@ use crate::{
 };
 
 mod data_ingestion_handler;
+mod fast_sync;
 pub mod metrics;
 
+pub use fast_sync::FastSync;
+
 #[cfg(test)]
 pub(crate) mod tests;
 
@@ -148,6 +154,10 @@ pub struct CheckpointExecutor {
     accumulator: Arc<StateAccumulator>,
     config: CheckpointExecutorConfig,
     metrics: Arc<CheckpointExecutorMetrics>,
+    fast_sync: Option<Arc<FastSync>>,
+    /// Notified of the sequence number of each checkpoint, once it has been executed and the
+    /// highest executed checkpoint watermark has been bumped past it.
+    executed_checkpoints: Option<Arc<watch::Sender<CheckpointSequenceNumber>>>,
 }
 
 impl CheckpointExecutor {
@@ -169,9 +179,26 @@ impl CheckpointExecutor {
             accumulator,
             config,
             metrics,
+            fast_sync: None,
+            executed_checkpoints: None,
         }
     }
 
+    /// Fast sync checkpoints up to the target of `fast_sync`, instead of executing them.
+    pub fn with_fast_sync(mut self, fast_sync: Arc<FastSync>) -> Self {
+        self.fast_sync = Some(fast_sync);
+        self
+    }
+
+    /// Publish the highest executed checkpoint to `sender` as it advances.
+    pub fn with_executed_checkpoint_notifications(
+        mut self,
+        sender: Arc<watch::Sender<CheckpointSequenceNumber>>,
+    ) -> Self {
+        self.executed_checkpoints = Some(sender);
+        self
+    }
+
     pub fn new_for_tests(
         mailbox: broadcast::Receiver<VerifiedCheckpoint>,
         checkpoint_store: Arc<CheckpointStore>,
@@ -243,6 +270,9 @@ impl CheckpointExecutor {
                 assert_eq!(epoch_store.epoch(), 0);
                 0
             });
+        if let Some(fast_sync) = &self.fast_sync {
+            fast_sync.reset(next_to_schedule);
+        }
         let mut pending: CheckpointExecutionBuffer = FuturesOrdered::new();
 
         let mut now_time = Instant::now();
@@ -411,6 +441,9 @@ impl CheckpointExecutor {
         self.checkpoint_store
             .update_highest_executed_checkpoint(checkpoint)
             .unwrap();
+        if let Some(executed_checkpoints) = &self.executed_checkpoints {
+            executed_checkpoints.send_replace(seq);
+        }
         self.metrics.last_executed_checkpoint.set(seq as i64);
 
         self.metrics
@@ -449,10 +482,57 @@ impl CheckpointExecutor {
                 .accumulate_running_root(epoch_store, checkpoint.sequence_number, checkpoint_acc)
                 .await
                 .expect("Failed to accumulate running root");
+            self.index_executed_checkpoint(epoch_store, checkpoint, all_tx_digests)
+                .expect("Indexing checkpoint cannot fail");
             self.bump_highest_executed_checkpoint(checkpoint);
         }
     }
 
+    /// Update the node's derived indexes with an executed checkpoint. This is called in checkpoint
+    /// order, once the checkpoint's outputs have been committed and before the executed watermark
+    /// is bumped, because indexes like balances and the archival index's object versions are
+    /// updated from their previous values. The indexes are served as if they were complete, and
+    /// each checkpoint must be indexed before the next one, so failing to update them halts
+    /// execution, as failing to finalize the checkpoint does, and the checkpoint is indexed again
+    /// when the node restarts.
+    fn index_executed_checkpoint(
+        &self,
+        epoch_store: &AuthorityPerEpochStore,
+        checkpoint: &VerifiedCheckpoint,
+        tx_digests: &[TransactionDigest],
+    ) -> SuiResult {
+        if self.state.rest_index.is_none() && self.state.archival_index.is_none() {
+            return Ok(());
+        }
+
+        let _scope = mysten_metrics::monitored_scope("IndexExecutedCheckpoint");
+        let checkpoint_data = load_checkpoint_data(
+            checkpoint.clone(),
+            self.object_cache_reader.as_ref(),
+            self.transaction_cache_reader.as_ref(),
+            self.checkpoint_store.clone(),
+            tx_digests,
+        )?;
+
+        if let Some(archival_index) = &self.state.archival_index {
+            archival_index.index_checkpoint(&checkpoint_data)?;
+        }
+
+        if let Some(rest_index) = &self.state.rest_index {
+            let package_store = PackageStoreWithFallback::new(
+                self.state.get_backing_package_store(),
+                &checkpoint_data,
+            );
+            let mut layout_resolver = epoch_store
+                .executor()
+                .type_layout_resolver(Box::new(package_store));
+
+            rest_index.index_checkpoint(&checkpoint_data, layout_resolver.as_mut())?;
+        }
+
+        Ok(())
+    }
+
     #[instrument(level = "debug", skip_all)]
     fn schedule_synced_checkpoints(
         &self,
@@ -531,11 +611,25 @@ impl CheckpointExecutor {
         let tx_manager = self.tx_manager.clone();
         let accumulator = self.accumulator.clone();
         let state = self.state.clone();
+        let fast_sync = self
+            .fast_sync
+            .clone()
+            .filter(|fast_sync| fast_sync.covers(*checkpoint.sequence_number()));
 
         epoch_store.notify_synced_checkpoint(*checkpoint.sequence_number());
 
         pending.push_back(spawn_monitored_task!(async move {
             let epoch_store = epoch_store.clone();
+            if let Some(fast_sync) = &fast_sync {
+                fast_sync
+                    .sync_checkpoint(
+                        &checkpoint,
+                        &state,
+                        transaction_cache_reader.as_ref(),
+                        &epoch_store,
+                    )
+                    .await;
+            }
             let (tx_digests, checkpoint_acc) = loop {
                 match execute_checkpoint(
                     checkpoint.clone(),
@@ -563,6 +657,9 @@ impl CheckpointExecutor {
                     Ok((tx_digests, checkpoint_acc)) => break (tx_digests, checkpoint_acc),
                 }
             };
+            if let Some(fast_sync) = &fast_sync {
+                fast_sync.finish(*checkpoint.sequence_number());
+            }
             (checkpoint, checkpoint_acc, tx_digests)
         }));
     }
@@ -701,11 +798,27 @@ impl CheckpointExecutor {
                         .accumulate_running_root(&epoch_store, checkpoint.sequence_number, None)
                         .await
                         .expect("Failed to accumulate running root");
-                    self.accumulator
+                    let epoch_acc = self
+                        .accumulator
                         .accumulate_epoch(epoch_store.clone(), *checkpoint.sequence_number())
                         .await
                         .expect("Accumulating epoch cannot fail");
 
+                    // Transactions that were fast synced were never executed locally, so the
+                    // live object set at the end of the epoch must be checked against the one
+                    // committed to by the certified checkpoint.
+                    if let Some(fast_sync) = self
+                        .fast_sync
+                        .as_ref()
+                        .filter(|fast_sync| fast_sync.covers(*checkpoint.sequence_number()))
+                    {
+                        fast_sync
+                            .check_epoch_root(checkpoint, &epoch_acc)
+                            .expect("Fast synced state diverged from the certified state");
+                    }
+
+                    self.index_executed_checkpoint(&epoch_store, checkpoint, &all_tx_digests)
+                        .expect("Indexing checkpoint cannot fail");
                     self.bump_highest_executed_checkpoint(checkpoint);
 
                     return true;
@@ -1323,7 +1436,7 @@ async fn finalize_checkpoint(
     let checkpoint_acc =
         accumulator.accumulate_checkpoint(effects, checkpoint.sequence_number, epoch_store)?;
 
-    if data_ingestion_dir.is_some() || state.rest_index.is_some() {
+    if let Some(path) = data_ingestion_dir {
         let checkpoint_data = load_checkpoint_data(
             checkpoint,
             object_cache_reader,
@@ -1331,20 +1444,7 @@ async fn finalize_checkpoint(
             checkpoint_store,
             tx_digests,
         )?;
-
-        // TODO(bmwill) discuss with team a better location for this indexing so that it isn't on
-        // the critical path and the writes to the DB are done in checkpoint order
-        if let Some(rest_index) = &state.rest_index {
-            let mut layout_resolver = epoch_store.executor().type_layout_resolver(Box::new(
-                PackageStoreWithFallback::new(state.get_backing_package_store(), &checkpoint_data),
-            ));
-
-            rest_index.index_checkpoint(&checkpoint_data, layout_resolver.as_mut())?;
-        }
-
-        if let Some(path) = data_ingestion_dir {
-            store_checkpoint_locally(path, &checkpoint_data)?;
-        }
+        store_checkpoint_locally(path, &checkpoint_data)?;
     }
     Ok(checkpoint_acc)
 }
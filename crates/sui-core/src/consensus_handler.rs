@@ -568,6 +568,7 @@ pub(crate) fn classify(transaction: &ConsensusTransaction) -> &'static str {
                 "owned_user_transaction"
             }
         }
+        ConsensusTransactionKind::ExecutionCostObservation(_) => "execution_cost_observation",
     }
 }
 
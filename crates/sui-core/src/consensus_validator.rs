@@ -8,9 +8,12 @@ use eyre::WrapErr;
 use fastcrypto_tbls::dkg;
 use mysten_metrics::monitored_scope;
 use prometheus::{register_int_counter_with_registry, IntCounter, Registry};
+use sui_protocol_config::PerObjectCongestionControlMode;
 use sui_types::{
     error::SuiError,
-    messages_consensus::{ConsensusTransaction, ConsensusTransactionKind},
+    messages_consensus::{
+        ConsensusTransaction, ConsensusTransactionKind, MAX_EXECUTION_COST_OBSERVATION_OBJECTS,
+    },
 };
 use tap::TapFallible;
 use tracing::{info, warn};
@@ -99,6 +102,28 @@ impl SuiTxValidator {
                     }
                     // TODO(fastpath): implement verification for uncertified user transactions.
                 }
+
+                ConsensusTransactionKind::ExecutionCostObservation(observation) => {
+                    if self
+                        .epoch_store
+                        .protocol_config()
+                        .per_object_congestion_control_mode()
+                        != PerObjectCongestionControlMode::ObservedExecutionCost
+                    {
+                        return Err(SuiError::UnexpectedMessage(
+                            "ConsensusTransactionKind::ExecutionCostObservation is unsupported"
+                                .to_string(),
+                        )
+                        .into());
+                    }
+                    if observation.object_costs.len() > MAX_EXECUTION_COST_OBSERVATION_OBJECTS {
+                        warn!("batch verification error: execution cost observation too large");
+                        return Err(SuiError::UnexpectedMessage(
+                            "ExecutionCostObservation has too many objects".to_string(),
+                        )
+                        .into());
+                    }
+                }
             }
         }
 
@@ -0,0 +1,238 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Sizing and eviction for the caches of committed data in the writeback cache.
+//!
+//! By default each cache holds a fixed number of entries. If a memory budget is configured
+//! instead, it is divided between the caches in fixed proportions, and each entry is weighed by
+//! its approximate size in bytes, so that a cache of large objects holds fewer entries than a cache
+//! of small ones.
+//!
+//! Every cache uses moka's default eviction policy, W-TinyLFU, which admits a new entry only if it
+//! is likely to be read more often than the entry it would replace. Unlike LRU, this keeps hot
+//! entries cached under skewed workloads, and is not flushed by scans over cold objects. See
+//! `benches/execution_cache_bench.rs` for a comparison of the two.
+
+use std::hash::Hash;
+use std::sync::Arc;
+
+use moka::notification::RemovalCause;
+use moka::sync::Cache as MokaCache;
+use parking_lot::{Mutex, MutexGuard};
+use sui_config::ExecutionCacheConfig;
+use sui_types::digests::TransactionEffectsDigest;
+use sui_types::effects::{TransactionEffects, TransactionEvents};
+use sui_types::object::Object;
+use sui_types::storage::PackageObject;
+use sui_types::transaction::VerifiedTransaction;
+
+use super::ExecutionCacheMetrics;
+
+const DEFAULT_MAX_CACHE_SIZE: u64 = 10000;
+
+/// The caches of committed data, named as in the `collection` label of the cache metrics.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CacheCollection {
+    Object,
+    ObjectById,
+    Marker,
+    Package,
+    Transaction,
+    TransactionEffects,
+    TransactionEvents,
+    ExecutedEffectsDigests,
+    TransactionObjects,
+}
+
+impl CacheCollection {
+    pub const ALL: [CacheCollection; 9] = [
+        CacheCollection::Object,
+        CacheCollection::ObjectById,
+        CacheCollection::Marker,
+        CacheCollection::Package,
+        CacheCollection::Transaction,
+        CacheCollection::TransactionEffects,
+        CacheCollection::TransactionEvents,
+        CacheCollection::ExecutedEffectsDigests,
+        CacheCollection::TransactionObjects,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CacheCollection::Object => "object",
+            CacheCollection::ObjectById => "object_by_id",
+            CacheCollection::Marker => "marker",
+            CacheCollection::Package => "package",
+            CacheCollection::Transaction => "transaction_block",
+            CacheCollection::TransactionEffects => "transaction_effects",
+            CacheCollection::TransactionEvents => "transaction_events",
+            CacheCollection::ExecutedEffectsDigests => "executed_effects_digests",
+            CacheCollection::TransactionObjects => "transaction_objects",
+        }
+    }
+
+    /// Percentage of the memory budget given to this cache. Objects and packages are read most
+    /// often, and are the most expensive to reload, so they get most of it.
+    fn budget_share_percent(&self) -> u64 {
+        match self {
+            CacheCollection::Object => 35,
+            CacheCollection::ObjectById => 15,
+            CacheCollection::Marker => 2,
+            CacheCollection::Package => 20,
+            CacheCollection::Transaction => 10,
+            CacheCollection::TransactionEffects => 8,
+            CacheCollection::TransactionEvents => 6,
+            CacheCollection::ExecutedEffectsDigests => 2,
+            CacheCollection::TransactionObjects => 2,
+        }
+    }
+}
+
+/// How large each cache may grow.
+#[derive(Clone, Copy, Debug)]
+pub struct CacheSizing {
+    max_entries: u64,
+    memory_budget_bytes: Option<u64>,
+}
+
+impl CacheSizing {
+    pub fn new(config: &ExecutionCacheConfig) -> Self {
+        match config {
+            ExecutionCacheConfig::WritebackCache {
+                max_cache_size,
+                memory_budget_bytes,
+            } => Self {
+                max_entries: max_cache_size
+                    .map(|size| size as u64)
+                    .unwrap_or(DEFAULT_MAX_CACHE_SIZE),
+                memory_budget_bytes: *memory_budget_bytes,
+            },
+            // The writeback cache is still built when the passthrough cache is selected, so that
+            // it can be switched to at an epoch boundary.
+            ExecutionCacheConfig::PassthroughCache => Self {
+                max_entries: DEFAULT_MAX_CACHE_SIZE,
+                memory_budget_bytes: None,
+            },
+        }
+    }
+
+    /// The number of bytes `collection` may hold, if the caches are bounded by memory.
+    pub fn capacity_bytes(&self, collection: CacheCollection) -> Option<u64> {
+        self.memory_budget_bytes
+            .map(|budget| budget / 100 * collection.budget_share_percent())
+    }
+
+    /// Build the cache for `collection`. Evictions are counted in `metrics`.
+    pub fn build<K, V>(
+        &self,
+        collection: CacheCollection,
+        metrics: &Arc<ExecutionCacheMetrics>,
+    ) -> MokaCache<K, V>
+    where
+        K: Hash + Eq + Send + Sync + 'static,
+        V: CacheWeight + Clone + Send + Sync + 'static,
+    {
+        let metrics = metrics.clone();
+        let builder = MokaCache::builder().eviction_listener(move |_, _, cause| {
+            if cause == RemovalCause::Size {
+                metrics.record_cache_eviction(collection.as_str());
+            }
+        });
+
+        match self.capacity_bytes(collection) {
+            Some(capacity) => builder
+                .max_capacity(capacity)
+                .weigher(|_: &K, value: &V| {
+                    let weight = std::mem::size_of::<K>() + value.cache_weight();
+                    u32::try_from(weight).unwrap_or(u32::MAX)
+                })
+                .build(),
+            None => builder.max_capacity(self.max_entries).build(),
+        }
+    }
+}
+
+/// The approximate number of bytes a cached value occupies, used to weigh it against the memory
+/// budget. Values are weighed once, when they are inserted. Values that are modified in place
+/// after that keep their original weight, so their weight should anticipate how large they can
+/// grow.
+pub trait CacheWeight {
+    fn cache_weight(&self) -> usize;
+}
+
+impl<T: CacheWeight> CacheWeight for Arc<T> {
+    fn cache_weight(&self) -> usize {
+        T::cache_weight(self)
+    }
+}
+
+/// A cached value that is modified in place, together with its weight. The weight is computed
+/// when the value is created, so that the cache can weigh it without taking the lock.
+pub struct WeighedMutex<T> {
+    weight: usize,
+    value: Mutex<T>,
+}
+
+impl<T: CacheWeight> WeighedMutex<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            weight: value.cache_weight(),
+            value: Mutex::new(value),
+        }
+    }
+}
+
+impl<T> WeighedMutex<T> {
+    pub fn lock(&self) -> MutexGuard<'_, T> {
+        self.value.lock()
+    }
+}
+
+impl<T> CacheWeight for WeighedMutex<T> {
+    fn cache_weight(&self) -> usize {
+        self.weight
+    }
+}
+
+impl CacheWeight for Object {
+    fn cache_weight(&self) -> usize {
+        std::mem::size_of::<Object>() + self.object_size_for_gas_metering()
+    }
+}
+
+impl CacheWeight for Vec<Object> {
+    fn cache_weight(&self) -> usize {
+        self.iter().map(Object::cache_weight).sum()
+    }
+}
+
+impl CacheWeight for PackageObject {
+    fn cache_weight(&self) -> usize {
+        self.object().cache_weight()
+    }
+}
+
+impl CacheWeight for VerifiedTransaction {
+    fn cache_weight(&self) -> usize {
+        std::mem::size_of::<VerifiedTransaction>()
+            + bcs::serialized_size(self.data()).unwrap_or_default()
+    }
+}
+
+impl CacheWeight for TransactionEffects {
+    fn cache_weight(&self) -> usize {
+        std::mem::size_of::<TransactionEffects>() + bcs::serialized_size(self).unwrap_or_default()
+    }
+}
+
+impl CacheWeight for TransactionEvents {
+    fn cache_weight(&self) -> usize {
+        std::mem::size_of::<TransactionEvents>() + bcs::serialized_size(self).unwrap_or_default()
+    }
+}
+
+impl CacheWeight for TransactionEffectsDigest {
+    fn cache_weight(&self) -> usize {
+        std::mem::size_of::<TransactionEffectsDigest>()
+    }
+}
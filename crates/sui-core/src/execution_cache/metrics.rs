@@ -15,6 +15,7 @@ pub struct ExecutionCacheMetrics {
     pub(crate) cache_negative_hits: IntCounterVec,
     pub(crate) cache_misses: IntCounterVec,
     pub(crate) cache_writes: IntCounterVec,
+    pub(crate) cache_evictions: IntCounterVec,
 }
 
 impl ExecutionCacheMetrics {
@@ -65,6 +66,14 @@ impl ExecutionCacheMetrics {
                 registry,
             )
             .unwrap(),
+            // Only evictions to make room for new entries are counted, not invalidations.
+            cache_evictions: register_int_counter_vec_with_registry!(
+                "execution_cache_evictions",
+                "Execution cache evictions",
+                &["collection"],
+                registry,
+            )
+            .unwrap(),
         }
     }
 
@@ -121,4 +130,8 @@ impl ExecutionCacheMetrics {
     pub(crate) fn record_cache_write(&self, collection: &'static str) {
         self.cache_writes.with_label_values(&[collection]).inc();
     }
+
+    pub(crate) fn record_cache_eviction(&self, collection: &'static str) {
+        self.cache_evictions.with_label_values(&[collection]).inc();
+    }
 }
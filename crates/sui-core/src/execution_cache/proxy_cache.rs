@@ -14,6 +14,7 @@ use futures::FutureExt;
 use parking_lot::RwLock;
 use std::sync::Arc;
 use std::time::Duration;
+use sui_config::ExecutionCacheConfig;
 use sui_protocol_config::ProtocolVersion;
 use sui_types::accumulator::Accumulator;
 use sui_types::base_types::VerifiedExecutionData;
@@ -57,6 +58,7 @@ pub struct ProxyCache {
 
 impl ProxyCache {
     pub fn new(
+        cache_config: &ExecutionCacheConfig,
         epoch_start_config: &EpochStartConfiguration,
         store: Arc<AuthorityStore>,
         metrics: Arc<ExecutionCacheMetrics>,
@@ -64,7 +66,7 @@ impl ProxyCache {
         let cache_type = epoch_start_config.execution_cache_type();
         tracing::info!("using cache impl {:?}", cache_type);
         let passthrough_cache = PassthroughCache::new(store.clone(), metrics.clone());
-        let writeback_cache = WritebackCache::new(store.clone(), metrics.clone());
+        let writeback_cache = WritebackCache::new(cache_config, store.clone(), metrics.clone());
 
         Self {
             passthrough_cache,
@@ -74,7 +74,11 @@ impl Scenario {
         static METRICS: once_cell::sync::Lazy<Arc<ExecutionCacheMetrics>> =
             once_cell::sync::Lazy::new(|| Arc::new(ExecutionCacheMetrics::new(default_registry())));
 
-        let cache = Arc::new(WritebackCache::new(store.clone(), (*METRICS).clone()));
+        let cache = Arc::new(WritebackCache::new(
+            &ExecutionCacheConfig::default(),
+            store.clone(),
+            (*METRICS).clone(),
+        ));
         Self {
             authority,
             store,
@@ -369,7 +373,8 @@ impl Scenario {
     }
 
     pub fn reset_cache(&mut self) {
-        self.cache = Arc::new(WritebackCache::new(
+        self.cache = Arc::new(WritebackCache::new_with_sizing(
+            self.cache.sizing,
             self.store.clone(),
             self.cache.metrics.clone(),
         ));
@@ -1227,7 +1232,11 @@ async fn latest_object_cache_race_test() {
     static METRICS: once_cell::sync::Lazy<Arc<ExecutionCacheMetrics>> =
         once_cell::sync::Lazy::new(|| Arc::new(ExecutionCacheMetrics::new(default_registry())));
 
-    let cache = Arc::new(WritebackCache::new(store.clone(), (*METRICS).clone()));
+    let cache = Arc::new(WritebackCache::new(
+        &ExecutionCacheConfig::default(),
+        store.clone(),
+        (*METRICS).clone(),
+    ));
 
     let object_id = ObjectID::random();
     let owner = SuiAddress::random_for_testing_only();
@@ -1309,3 +1318,36 @@ async fn latest_object_cache_race_test() {
     reader.join().unwrap();
     checker.join().unwrap();
 }
+
+#[test]
+fn memory_budget_test() {
+    let config = ExecutionCacheConfig::WritebackCache {
+        max_cache_size: None,
+        memory_budget_bytes: Some(1 << 20),
+    };
+    let sizing = CacheSizing::new(&config);
+    let metrics = Arc::new(ExecutionCacheMetrics::new(&Registry::new()));
+    let cache: MokaCache<ObjectID, Object> = sizing.build(CacheCollection::Object, &metrics);
+
+    let capacity = sizing.capacity_bytes(CacheCollection::Object).unwrap();
+    let owner = SuiAddress::random_for_testing_only();
+    let mut inserted = 0;
+    let mut total_weight = 0;
+    while total_weight < 4 * capacity {
+        let object = Object::with_id_owner_for_testing(ObjectID::random(), owner);
+        total_weight += object.cache_weight() as u64;
+        cache.insert(object.id(), object);
+        inserted += 1;
+    }
+    cache.run_pending_tasks();
+
+    assert!(cache.weighted_size() <= capacity);
+    assert!(cache.entry_count() < inserted);
+    assert!(
+        metrics
+            .cache_evictions
+            .with_label_values(&[CacheCollection::Object.as_str()])
+            .get()
+            > 0
+    );
+}
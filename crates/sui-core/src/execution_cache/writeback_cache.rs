@@ -52,11 +52,11 @@ use dashmap::DashMap;
 use futures::{future::BoxFuture, FutureExt};
 use moka::sync::Cache as MokaCache;
 use mysten_common::sync::notify_read::NotifyRead;
-use parking_lot::Mutex;
 use prometheus::Registry;
 use std::collections::{BTreeMap, BTreeSet};
 use std::hash::Hash;
 use std::sync::Arc;
+use sui_config::ExecutionCacheConfig;
 use sui_macros::fail_point_async;
 use sui_protocol_config::ProtocolVersion;
 use sui_types::accumulator::Accumulator;
@@ -76,6 +76,7 @@ use sui_types::transaction::{VerifiedSignedTransaction, VerifiedTransaction};
 use tap::TapOptional;
 use tracing::{debug, info, instrument, trace, warn};
 
+use super::cache_sizing::{CacheCollection, CacheSizing, CacheWeight, WeighedMutex};
 use super::ExecutionCacheAPI;
 use super::{
     cache_types::CachedVersionMap, implement_passthrough_traits, object_locks::ObjectLocks,
@@ -172,8 +173,47 @@ impl LatestObjectCacheEntry {
     }
 }
 
+impl CacheWeight for ObjectEntry {
+    fn cache_weight(&self) -> usize {
+        match self {
+            ObjectEntry::Object(object) => object.cache_weight(),
+            ObjectEntry::Deleted | ObjectEntry::Wrapped => std::mem::size_of::<ObjectEntry>(),
+        }
+    }
+}
+
+impl CacheWeight for LatestObjectCacheEntry {
+    fn cache_weight(&self) -> usize {
+        match self {
+            LatestObjectCacheEntry::Object(_, entry) => entry.cache_weight(),
+            LatestObjectCacheEntry::NonExistent => std::mem::size_of::<LatestObjectCacheEntry>(),
+        }
+    }
+}
+
+impl CacheWeight for MarkerValue {
+    fn cache_weight(&self) -> usize {
+        std::mem::size_of::<MarkerValue>()
+    }
+}
+
+/// Version maps are weighed when they are created with their first version, but grow to hold up to
+/// `MAX_VERSIONS` versions, each of which is assumed to be about the same size as the first.
+impl<V: CacheWeight> CacheWeight for CachedVersionMap<V> {
+    fn cache_weight(&self) -> usize {
+        let version_weight = self
+            .get_highest()
+            .map(|(_, value)| value.cache_weight())
+            .unwrap_or_default();
+        MAX_VERSIONS * (std::mem::size_of::<SequenceNumber>() + version_weight)
+    }
+}
+
 type MarkerKey = (EpochId, ObjectID);
 
+/// The number of committed versions of each object or marker kept in the cache.
+const MAX_VERSIONS: usize = 3;
+
 enum CacheResult<T> {
     /// Entry is in the cache
     Hit(T),
@@ -258,23 +298,20 @@ impl UncommittedData {
     }
 }
 
-// TODO: set this via the config
-static MAX_CACHE_SIZE: u64 = 10000;
-
 /// CachedData stores data that has been committed to the db, but is likely to be read soon.
 struct CachedCommittedData {
     // See module level comment for an explanation of caching strategy.
-    object_cache: MokaCache<ObjectID, Arc<Mutex<CachedVersionMap<ObjectEntry>>>>,
+    object_cache: MokaCache<ObjectID, Arc<WeighedMutex<CachedVersionMap<ObjectEntry>>>>,
 
     // We separately cache the latest version of each object. Although this seems
     // redundant, it is the only way to support populating the cache after a read.
     // We cannot simply insert objects that we read off the disk into `object_cache`,
     // since that may violate the no-missing-versions property.
     // `object_by_id_cache` is also written to on writes so that it is always coherent.
-    object_by_id_cache: MokaCache<ObjectID, Arc<Mutex<LatestObjectCacheEntry>>>,
+    object_by_id_cache: MokaCache<ObjectID, Arc<WeighedMutex<LatestObjectCacheEntry>>>,
 
     // See module level comment for an explanation of caching strategy.
-    marker_cache: MokaCache<MarkerKey, Arc<Mutex<CachedVersionMap<MarkerValue>>>>,
+    marker_cache: MokaCache<MarkerKey, Arc<WeighedMutex<CachedVersionMap<MarkerValue>>>>,
 
     transactions: MokaCache<TransactionDigest, Arc<VerifiedTransaction>>,
 
@@ -290,39 +327,16 @@ struct CachedCommittedData {
 }
 
 impl CachedCommittedData {
-    fn new() -> Self {
-        let object_cache = MokaCache::builder()
-            .max_capacity(MAX_CACHE_SIZE)
-            .max_capacity(MAX_CACHE_SIZE)
-            .build();
-        let object_by_id_cache = MokaCache::builder()
-            .max_capacity(MAX_CACHE_SIZE)
-            .max_capacity(MAX_CACHE_SIZE)
-            .build();
-        let marker_cache = MokaCache::builder()
-            .max_capacity(MAX_CACHE_SIZE)
-            .max_capacity(MAX_CACHE_SIZE)
-            .build();
-        let transactions = MokaCache::builder()
-            .max_capacity(MAX_CACHE_SIZE)
-            .max_capacity(MAX_CACHE_SIZE)
-            .build();
-        let transaction_effects = MokaCache::builder()
-            .max_capacity(MAX_CACHE_SIZE)
-            .max_capacity(MAX_CACHE_SIZE)
-            .build();
-        let transaction_events = MokaCache::builder()
-            .max_capacity(MAX_CACHE_SIZE)
-            .max_capacity(MAX_CACHE_SIZE)
-            .build();
-        let executed_effects_digests = MokaCache::builder()
-            .max_capacity(MAX_CACHE_SIZE)
-            .max_capacity(MAX_CACHE_SIZE)
-            .build();
-        let transaction_objects = MokaCache::builder()
-            .max_capacity(MAX_CACHE_SIZE)
-            .max_capacity(MAX_CACHE_SIZE)
-            .build();
+    fn new(sizing: &CacheSizing, metrics: &Arc<ExecutionCacheMetrics>) -> Self {
+        let object_cache = sizing.build(CacheCollection::Object, metrics);
+        let object_by_id_cache = sizing.build(CacheCollection::ObjectById, metrics);
+        let marker_cache = sizing.build(CacheCollection::Marker, metrics);
+        let transactions = sizing.build(CacheCollection::Transaction, metrics);
+        let transaction_effects = sizing.build(CacheCollection::TransactionEffects, metrics);
+        let transaction_events = sizing.build(CacheCollection::TransactionEvents, metrics);
+        let executed_effects_digests =
+            sizing.build(CacheCollection::ExecutedEffectsDigests, metrics);
+        let transaction_objects = sizing.build(CacheCollection::TransactionObjects, metrics);
 
         Self {
             object_cache,
@@ -388,6 +402,7 @@ pub struct WritebackCache {
     executed_effects_digests_notify_read: NotifyRead<TransactionDigest, TransactionEffectsDigest>,
     store: Arc<AuthorityStore>,
     metrics: Arc<ExecutionCacheMetrics>,
+    sizing: CacheSizing,
 }
 
 macro_rules! check_cache_entry_by_version {
@@ -428,29 +443,43 @@ macro_rules! check_cache_entry_by_latest {
 }
 
 impl WritebackCache {
-    pub fn new(store: Arc<AuthorityStore>, metrics: Arc<ExecutionCacheMetrics>) -> Self {
-        let packages = MokaCache::builder()
-            .max_capacity(MAX_CACHE_SIZE)
-            .max_capacity(MAX_CACHE_SIZE)
-            .build();
+    pub fn new(
+        config: &ExecutionCacheConfig,
+        store: Arc<AuthorityStore>,
+        metrics: Arc<ExecutionCacheMetrics>,
+    ) -> Self {
+        Self::new_with_sizing(CacheSizing::new(config), store, metrics)
+    }
+
+    fn new_with_sizing(
+        sizing: CacheSizing,
+        store: Arc<AuthorityStore>,
+        metrics: Arc<ExecutionCacheMetrics>,
+    ) -> Self {
+        let packages = sizing.build(CacheCollection::Package, &metrics);
         Self {
             dirty: UncommittedData::new(),
-            cached: CachedCommittedData::new(),
+            cached: CachedCommittedData::new(&sizing, &metrics),
             packages,
             object_locks: ObjectLocks::new(),
             executed_effects_digests_notify_read: NotifyRead::new(),
             store,
             metrics,
+            sizing,
         }
     }
 
     pub fn new_for_tests(store: Arc<AuthorityStore>, registry: &Registry) -> Self {
-        Self::new(store, ExecutionCacheMetrics::new(registry).into())
+        Self::new(
+            &ExecutionCacheConfig::default(),
+            store,
+            ExecutionCacheMetrics::new(registry).into(),
+        )
     }
 
     #[cfg(test)]
     pub fn reset_for_test(&mut self) {
-        let mut new = Self::new(self.store.clone(), self.metrics.clone());
+        let mut new = Self::new_with_sizing(self.sizing, self.store.clone(), self.metrics.clone());
         std::mem::swap(self, &mut new);
     }
 
@@ -470,7 +499,9 @@ impl WritebackCache {
             .insert(version, object.clone());
         self.cached.object_by_id_cache.insert(
             *object_id,
-            Arc::new(Mutex::new(LatestObjectCacheEntry::Object(version, object))),
+            Arc::new(WeighedMutex::new(LatestObjectCacheEntry::Object(
+                version, object,
+            ))),
         );
     }
 
@@ -500,7 +531,7 @@ impl WritebackCache {
     // creates lifetime hell.
     fn with_locked_cache_entries<K, V, R>(
         dirty_map: &DashMap<K, CachedVersionMap<V>>,
-        cached_map: &MokaCache<K, Arc<Mutex<CachedVersionMap<V>>>>,
+        cached_map: &MokaCache<K, Arc<WeighedMutex<CachedVersionMap<V>>>>,
         key: &K,
         cb: impl FnOnce(Option<&CachedVersionMap<V>>, Option<&CachedVersionMap<V>>) -> R,
     ) -> R
@@ -1029,26 +1060,31 @@ impl WritebackCache {
     // This is called after the entry is committed to the db.
     fn move_version_from_dirty_to_cache<K, V>(
         dirty: &DashMap<K, CachedVersionMap<V>>,
-        cache: &MokaCache<K, Arc<Mutex<CachedVersionMap<V>>>>,
+        cache: &MokaCache<K, Arc<WeighedMutex<CachedVersionMap<V>>>>,
         key: K,
         version: SequenceNumber,
         value: &V,
     ) where
         K: Eq + std::hash::Hash + Clone + Send + Sync + Copy + 'static,
-        V: Send + Sync + Clone + Eq + std::fmt::Debug + 'static,
+        V: Send + Sync + Clone + Eq + std::fmt::Debug + CacheWeight + 'static,
     {
-        static MAX_VERSIONS: usize = 3;
-
         // IMPORTANT: lock both the dirty set entry and the cache entry before modifying either.
         // this ensures that readers cannot see a value temporarily disappear.
         let dirty_entry = dirty.entry(key);
-        let cache_entry = cache.entry(key).or_default();
+        // A new map is created holding its first version, so that it can be weighed by size.
+        let cache_entry = cache.entry(key).or_insert_with(|| {
+            let mut map = CachedVersionMap::default();
+            map.insert(version, value.clone());
+            Arc::new(WeighedMutex::new(map))
+        });
         let mut cache_map = cache_entry.value().lock();
 
-        // insert into cache and drop old versions.
-        cache_map.insert(version, value.clone());
-        // TODO: make this automatic by giving CachedVersionMap an optional max capacity
-        cache_map.truncate_to(MAX_VERSIONS);
+        if !cache_entry.is_fresh() {
+            // insert into cache and drop old versions.
+            cache_map.insert(version, value.clone());
+            // TODO: make this automatic by giving CachedVersionMap an optional max capacity
+            cache_map.truncate_to(MAX_VERSIONS);
+        }
 
         let DashMapEntry::Occupied(mut occupied_dirty_entry) = dirty_entry else {
             panic!("dirty map must exist");
@@ -1080,7 +1116,7 @@ impl WritebackCache {
             .object_by_id_cache
             .entry(*object_id)
             // only one racing insert will call the closure
-            .or_insert_with(|| Arc::new(Mutex::new(object.clone())));
+            .or_insert_with(|| Arc::new(WeighedMutex::new(object.clone())));
 
         // We may be racing with another thread that observed an older version of the object
         if !entry.is_fresh() {
@@ -4,10 +4,12 @@
 
 extern crate core;
 
+pub mod archival_index;
 pub mod authority;
 pub mod authority_aggregator;
 pub mod authority_client;
 pub mod authority_server;
+pub mod certificate_deny_filter;
 pub mod checkpoints;
 pub mod consensus_adapter;
 pub mod consensus_handler;
@@ -78,4 +80,4 @@ mod type_param_tests;
 pub mod signature_verifier;
 
 pub mod runtime;
-mod transaction_signing_filter;
+pub mod transaction_signing_filter;
@@ -3,6 +3,7 @@
 
 use crate::authority::AuthorityState;
 use mysten_metrics::monitored_scope;
+use serde::Serialize;
 use std::cmp::{max, min};
 use std::hash::Hasher;
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
@@ -27,7 +28,21 @@ pub struct AuthorityOverloadInfo {
     pub load_shedding_percentage: AtomicU32,
 }
 
+/// The state of an `AuthorityOverloadInfo` at one point in time, for debugging.
+#[derive(Debug, Serialize)]
+pub struct AuthorityOverloadSnapshot {
+    pub is_overload: bool,
+    pub load_shedding_percentage: u32,
+}
+
 impl AuthorityOverloadInfo {
+    pub fn snapshot(&self) -> AuthorityOverloadSnapshot {
+        AuthorityOverloadSnapshot {
+            is_overload: self.is_overload.load(Ordering::Relaxed),
+            load_shedding_percentage: self.load_shedding_percentage.load(Ordering::Relaxed),
+        }
+    }
+
     pub fn set_overload(&self, load_shedding_percentage: u32) {
         self.is_overload.store(true, Ordering::Relaxed);
         self.load_shedding_percentage
@@ -321,6 +336,30 @@ mod tests {
         }
     }
 
+    #[test]
+    pub fn test_authority_overload_snapshot() {
+        let overload_info = AuthorityOverloadInfo::default();
+        overload_info.set_overload(30);
+
+        let snapshot = overload_info.snapshot();
+        assert!(snapshot.is_overload);
+        assert_eq!(snapshot.load_shedding_percentage, 30);
+
+        // This is the JSON served by the admin server.
+        assert_eq!(
+            serde_json::to_value(&snapshot).unwrap(),
+            serde_json::json!({
+                "is_overload": true,
+                "load_shedding_percentage": 30,
+            })
+        );
+
+        // The snapshot doesn't change with the overload info.
+        overload_info.clear_overload();
+        assert!(snapshot.is_overload);
+        assert!(!overload_info.snapshot().is_overload);
+    }
+
     #[test]
     pub fn test_calculate_load_shedding_ratio() {
         assert_eq!(calculate_load_shedding_percentage(95.0, 100.1), 0);
@@ -5,8 +5,8 @@
 use prometheus::{
     register_histogram_vec_with_registry, register_histogram_with_registry,
     register_int_counter_vec_with_registry, register_int_counter_with_registry,
-    register_int_gauge_with_registry, Histogram, HistogramVec, IntCounter, IntCounterVec, IntGauge,
-    Registry,
+    register_int_gauge_vec_with_registry, register_int_gauge_with_registry, Histogram,
+    HistogramVec, IntCounter, IntCounterVec, IntGauge, IntGaugeVec, Registry,
 };
 
 const FINALITY_LATENCY_SEC_BUCKETS: &[f64] = &[
@@ -37,6 +37,14 @@ pub struct QuorumDriverMetrics {
     pub(crate) current_transactions_in_retry: IntGauge,
 
     pub(crate) settlement_finality_latency: HistogramVec,
+
+    // Per priority lane metrics
+    pub(crate) total_requests_by_priority: IntCounterVec,
+    pub(crate) current_queued_by_priority: IntGaugeVec,
+    pub(crate) current_processing_by_priority: IntGaugeVec,
+    pub(crate) total_retries_by_priority: IntCounterVec,
+    pub(crate) total_retries_exhausted_by_priority: IntCounterVec,
+    pub(crate) settlement_finality_latency_by_priority: HistogramVec,
 }
 
 impl QuorumDriverMetrics {
@@ -129,6 +137,49 @@ impl QuorumDriverMetrics {
                 registry,
             )
             .unwrap(),
+            total_requests_by_priority: register_int_counter_vec_with_registry!(
+                "quorum_driver_total_requests_by_priority",
+                "Total number of requests received, grouped by priority",
+                &["priority"],
+                registry,
+            )
+            .unwrap(),
+            current_queued_by_priority: register_int_gauge_vec_with_registry!(
+                "quorum_driver_current_queued_by_priority",
+                "Current number of tasks waiting in the queue of each priority lane",
+                &["priority"],
+                registry,
+            )
+            .unwrap(),
+            current_processing_by_priority: register_int_gauge_vec_with_registry!(
+                "quorum_driver_current_processing_by_priority",
+                "Current number of tasks being processed by each priority lane",
+                &["priority"],
+                registry,
+            )
+            .unwrap(),
+            total_retries_by_priority: register_int_counter_vec_with_registry!(
+                "quorum_driver_total_retries_by_priority",
+                "Total number of retries enqueued, grouped by priority",
+                &["priority"],
+                registry,
+            )
+            .unwrap(),
+            total_retries_exhausted_by_priority: register_int_counter_vec_with_registry!(
+                "quorum_driver_total_retries_exhausted_by_priority",
+                "Total number of transactions that used up the retry budget of their lane, grouped by priority",
+                &["priority"],
+                registry,
+            )
+            .unwrap(),
+            settlement_finality_latency_by_priority: register_histogram_vec_with_registry!(
+                "quorum_driver_settlement_finality_latency_by_priority",
+                "Settlement finality latency observed from quorum driver, grouped by priority",
+                &["priority"],
+                FINALITY_LATENCY_SEC_BUCKETS.to_vec(),
+                registry,
+            )
+            .unwrap(),
         }
     }
 
@@ -4,20 +4,26 @@
 mod metrics;
 pub use metrics::*;
 
+mod pending_transactions;
+pub use pending_transactions::PendingTransactionTracker;
+
 pub mod reconfig_observer;
 
 use arc_swap::ArcSwap;
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fmt::{Debug, Formatter};
 use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::Duration;
+use strum::IntoEnumIterator;
+pub use sui_config::node::QuorumDriverLaneConfig;
+use sui_config::node::QUORUM_DRIVER_MAX_CONCURRENCY;
 use sui_types::base_types::{AuthorityName, ObjectRef, TransactionDigest};
 use sui_types::committee::{Committee, EpochId, StakeUnit};
 use sui_types::messages_grpc::HandleCertificateRequestV3;
 use sui_types::quorum_driver_types::{
-    ExecuteTransactionRequestV3, QuorumDriverEffectsQueueResult, QuorumDriverError,
-    QuorumDriverResponse, QuorumDriverResult,
+    ExecuteTransactionRequestV3, GroupedErrors, QuorumDriverEffectsQueueResult, QuorumDriverError,
+    QuorumDriverResponse, QuorumDriverResult, TransactionPriority,
 };
 use tap::TapFallible;
 use tokio::sync::Semaphore;
@@ -47,9 +53,7 @@ use self::reconfig_observer::ReconfigObserver;
 #[cfg(test)]
 mod tests;
 
-const TASK_QUEUE_SIZE: usize = 2000;
 const EFFECTS_QUEUE_SIZE: usize = 10000;
-const TX_MAX_RETRY_TIMES: u32 = 10;
 
 #[derive(Clone)]
 pub struct QuorumDriverTask {
@@ -72,34 +76,92 @@ impl Debug for QuorumDriverTask {
     }
 }
 
+pub(crate) struct QuorumDriverLane {
+    config: QuorumDriverLaneConfig,
+    task_sender: Sender<QuorumDriverTask>,
+}
+
+type LaneReceivers = Vec<(
+    TransactionPriority,
+    QuorumDriverLaneConfig,
+    Receiver<QuorumDriverTask>,
+)>;
+
+/// Creates one task queue per priority lane, returning the senders to be held by the
+/// QuorumDriver and the receivers to be drained by the lane processors.
+fn new_lanes(
+    lane_configs: &HashMap<TransactionPriority, QuorumDriverLaneConfig>,
+) -> (
+    HashMap<TransactionPriority, QuorumDriverLane>,
+    LaneReceivers,
+) {
+    let mut lanes = HashMap::new();
+    let mut receivers = Vec::new();
+    for priority in TransactionPriority::iter() {
+        let config = lane_configs
+            .get(&priority)
+            .copied()
+            .unwrap_or_else(|| QuorumDriverLaneConfig::default_for(priority));
+        let (task_sender, task_rx) = mpsc::channel::<QuorumDriverTask>(config.queue_size);
+        lanes.insert(
+            priority,
+            QuorumDriverLane {
+                config,
+                task_sender,
+            },
+        );
+        receivers.push((priority, config, task_rx));
+    }
+    (lanes, receivers)
+}
+
 pub struct QuorumDriver<A: Clone> {
     validators: ArcSwap<AuthorityAggregator<A>>,
-    task_sender: Sender<QuorumDriverTask>,
+    lanes: HashMap<TransactionPriority, QuorumDriverLane>,
+    /// Limits the number of tasks processed at a time across all lanes.
+    concurrency_limit: Arc<Semaphore>,
     effects_subscribe_sender: tokio::sync::broadcast::Sender<QuorumDriverEffectsQueueResult>,
     notifier: Arc<NotifyRead<TransactionDigest, QuorumDriverResult>>,
     metrics: Arc<QuorumDriverMetrics>,
-    max_retry_times: u32,
+    pending_transactions: Arc<PendingTransactionTracker>,
 }
 
 impl<A: Clone> QuorumDriver<A> {
     pub(crate) fn new(
         validators: ArcSwap<AuthorityAggregator<A>>,
-        task_sender: Sender<QuorumDriverTask>,
+        lanes: HashMap<TransactionPriority, QuorumDriverLane>,
         effects_subscribe_sender: tokio::sync::broadcast::Sender<QuorumDriverEffectsQueueResult>,
         notifier: Arc<NotifyRead<TransactionDigest, QuorumDriverResult>>,
         metrics: Arc<QuorumDriverMetrics>,
-        max_retry_times: u32,
     ) -> Self {
         Self {
             validators,
-            task_sender,
+            lanes,
+            concurrency_limit: Arc::new(Semaphore::new(QUORUM_DRIVER_MAX_CONCURRENCY)),
             effects_subscribe_sender,
             notifier,
             metrics,
-            max_retry_times,
+            pending_transactions: Arc::new(PendingTransactionTracker::default()),
         }
     }
 
+    pub fn pending_transactions(&self) -> &Arc<PendingTransactionTracker> {
+        &self.pending_transactions
+    }
+
+    fn lane(&self, priority: TransactionPriority) -> &QuorumDriverLane {
+        self.lanes
+            .get(&priority)
+            .expect("QuorumDriver has a lane for every priority")
+    }
+
+    pub fn lane_configs(&self) -> HashMap<TransactionPriority, QuorumDriverLaneConfig> {
+        self.lanes
+            .iter()
+            .map(|(priority, lane)| (*priority, lane.config))
+            .collect()
+    }
+
     pub fn authority_aggregator(&self) -> &ArcSwap<AuthorityAggregator<A>> {
         &self.validators
     }
@@ -113,7 +175,9 @@ impl<A: Clone> QuorumDriver<A> {
     }
 
     async fn enqueue_task(&self, task: QuorumDriverTask) -> SuiResult<()> {
-        self.task_sender
+        let priority = task.request.priority;
+        self.lane(priority)
+            .task_sender
             .send(task.clone())
             .await
             .tap_err(|e| debug!(?task, "Failed to enqueue task: {:?}", e))
@@ -121,6 +185,10 @@ impl<A: Clone> QuorumDriver<A> {
                 debug!(?task, "Enqueued task.");
                 self.metrics.current_requests_in_flight.inc();
                 self.metrics.total_enqueued.inc();
+                self.metrics
+                    .current_queued_by_priority
+                    .with_label_values(&[priority.as_ref()])
+                    .inc();
                 if task.retry_times > 0 {
                     if task.retry_times == 1 {
                         self.metrics.current_transactions_in_retry.inc();
@@ -128,6 +196,10 @@ impl<A: Clone> QuorumDriver<A> {
                     self.metrics
                         .transaction_retry_count
                         .observe(task.retry_times as f64);
+                    self.metrics
+                        .total_retries_by_priority
+                        .with_label_values(&[priority.as_ref()])
+                        .inc();
                 }
             })
             .map_err(|e| SuiError::QuorumDriverCommunicationError {
@@ -144,9 +216,14 @@ impl<A: Clone> QuorumDriver<A> {
         old_retry_times: u32,
         client_addr: Option<SocketAddr>,
     ) -> SuiResult<()> {
-        if old_retry_times >= self.max_retry_times {
+        let priority = request.priority;
+        if old_retry_times >= self.lane(priority).config.max_retry_times {
             // max out the retry times, notify failure
-            info!(tx_digest=?request.transaction.digest(), "Failed to reach finality after attempting for {} times", old_retry_times+1);
+            info!(tx_digest=?request.transaction.digest(), ?priority, "Failed to reach finality after attempting for {} times", old_retry_times+1);
+            self.metrics
+                .total_retries_exhausted_by_priority
+                .with_label_values(&[priority.as_ref()])
+                .inc();
             self.notify(
                 &request.transaction,
                 &Err(
@@ -207,6 +284,14 @@ impl<A: Clone> QuorumDriver<A> {
         let tx_digest = transaction.digest();
         let effects_queue_result = match &response {
             Ok(resp) => {
+                let committee = self.clone_committee();
+                self.pending_transactions.finalized(
+                    tx_digest,
+                    resp.effects_cert
+                        .auth_sig()
+                        .authorities(&committee)
+                        .filter_map(Result::ok),
+                );
                 self.metrics.total_ok_responses.inc();
                 self.metrics
                     .attempt_times_ok_response
@@ -214,6 +299,7 @@ impl<A: Clone> QuorumDriver<A> {
                 Ok((transaction.clone(), resp.clone()))
             }
             Err(err) => {
+                self.pending_transactions.failed(tx_digest, err.to_string());
                 self.metrics
                     .total_err_responses
                     .with_label_values(&[err.as_ref()])
@@ -244,10 +330,17 @@ where
         request: ExecuteTransactionRequestV3,
     ) -> SuiResult<Registration<TransactionDigest, QuorumDriverResult>> {
         let tx_digest = request.transaction.digest();
-        debug!(?tx_digest, "Received transaction execution request.");
+        debug!(?tx_digest, priority = ?request.priority, "Received transaction execution request.");
         self.metrics.total_requests.inc();
+        self.metrics
+            .total_requests_by_priority
+            .with_label_values(&[request.priority.as_ref()])
+            .inc();
 
         let ticket = self.notifier.register_one(tx_digest);
+        let tx_digest = *tx_digest;
+        self.pending_transactions
+            .submitted(tx_digest, request.priority);
         self.enqueue_task(QuorumDriverTask {
             request,
             tx_cert: None,
@@ -256,7 +349,8 @@ where
             client_addr: None,
             trace_span: Some(tracing::Span::current()),
         })
-        .await?;
+        .await
+        .tap_err(|_| self.pending_transactions.abandoned(&tx_digest))?;
         Ok(ticket)
     }
 
@@ -271,10 +365,18 @@ where
         let tx_digest = request.transaction.digest();
         debug!(
             ?tx_digest,
+            priority = ?request.priority,
             "Received transaction execution request, no ticket."
         );
         self.metrics.total_requests.inc();
+        self.metrics
+            .total_requests_by_priority
+            .with_label_values(&[request.priority.as_ref()])
+            .inc();
 
+        let tx_digest = *tx_digest;
+        self.pending_transactions
+            .submitted(tx_digest, request.priority);
         self.enqueue_task(QuorumDriverTask {
             request,
             tx_cert: None,
@@ -284,6 +386,7 @@ where
             trace_span: Some(tracing::Span::current()),
         })
         .await
+        .tap_err(|_| self.pending_transactions.abandoned(&tx_digest))
     }
 
     #[instrument(level = "trace", skip_all)]
@@ -296,11 +399,34 @@ where
         let _tx_guard = GaugeGuard::acquire(&auth_agg.metrics.inflight_transactions);
         let tx_digest = *transaction.digest();
         let result = auth_agg.process_transaction(transaction, client_addr).await;
+        if let Err(err) = &result {
+            if let Some(errors) = Self::grouped_errors(err) {
+                self.pending_transactions.errors_seen(&tx_digest, errors);
+            }
+        }
 
         self.process_transaction_result(result, tx_digest, client_addr)
             .await
     }
 
+    fn grouped_errors(err: &AggregatorProcessTransactionError) -> Option<&GroupedErrors> {
+        match err {
+            AggregatorProcessTransactionError::FatalTransaction { errors }
+            | AggregatorProcessTransactionError::RetryableTransaction { errors }
+            | AggregatorProcessTransactionError::FatalConflictingTransaction { errors, .. }
+            | AggregatorProcessTransactionError::RetryableConflictingTransaction {
+                errors, ..
+            }
+            | AggregatorProcessTransactionError::SystemOverload { errors, .. }
+            | AggregatorProcessTransactionError::SystemOverloadRetryAfter { errors, .. } => {
+                Some(errors)
+            }
+            AggregatorProcessTransactionError::TxAlreadyFinalizedWithDifferentUserSignatures => {
+                None
+            }
+        }
+    }
+
     #[instrument(level = "trace", skip_all)]
     async fn process_transaction_result(
         &self,
@@ -484,6 +610,14 @@ where
         let response = auth_agg
             .process_certificate(request.clone(), client_addr)
             .await
+            .tap_err(|agg_err| match agg_err {
+                AggregatorProcessCertificateError::FatalExecuteCertificate {
+                    non_retryable_errors: errors,
+                }
+                | AggregatorProcessCertificateError::RetryableExecuteCertificate {
+                    retryable_errors: errors,
+                } => self.pending_transactions.errors_seen(&tx_digest, errors),
+            })
             .map_err(|agg_err| match agg_err {
                 AggregatorProcessCertificateError::FatalExecuteCertificate {
                     non_retryable_errors,
@@ -615,7 +749,7 @@ pub struct QuorumDriverHandler<A: Clone> {
     effects_subscriber: tokio::sync::broadcast::Receiver<QuorumDriverEffectsQueueResult>,
     quorum_driver_metrics: Arc<QuorumDriverMetrics>,
     reconfig_observer: Arc<dyn ReconfigObserver<A> + Sync + Send>,
-    _processor_handle: JoinHandle<()>,
+    _processor_handles: Vec<JoinHandle<()>>,
 }
 
 impl<A> QuorumDriverHandler<A>
@@ -627,28 +761,20 @@ where
         notifier: Arc<NotifyRead<TransactionDigest, QuorumDriverResult>>,
         reconfig_observer: Arc<dyn ReconfigObserver<A> + Sync + Send>,
         metrics: Arc<QuorumDriverMetrics>,
-        max_retry_times: u32,
+        lane_configs: HashMap<TransactionPriority, QuorumDriverLaneConfig>,
     ) -> Self {
-        let (task_tx, task_rx) = mpsc::channel::<QuorumDriverTask>(TASK_QUEUE_SIZE);
+        let (lanes, lane_receivers) = new_lanes(&lane_configs);
         let (subscriber_tx, subscriber_rx) =
             tokio::sync::broadcast::channel::<_>(EFFECTS_QUEUE_SIZE);
         let quorum_driver = Arc::new(QuorumDriver::new(
             ArcSwap::new(validators),
-            task_tx,
+            lanes,
             subscriber_tx,
             notifier,
             metrics.clone(),
-            max_retry_times,
         ));
-        let metrics_clone = metrics.clone();
-        let processor_handle = {
-            let quorum_driver_clone = quorum_driver.clone();
-            spawn_monitored_task!(Self::task_queue_processor(
-                quorum_driver_clone,
-                task_rx,
-                metrics_clone
-            ))
-        };
+        let processor_handles =
+            Self::spawn_task_queue_processors(&quorum_driver, lane_receivers, &metrics);
         let reconfig_observer_clone = reconfig_observer.clone();
         {
             let quorum_driver_clone = quorum_driver.clone();
@@ -664,7 +790,7 @@ where
             effects_subscriber: subscriber_rx,
             quorum_driver_metrics: metrics,
             reconfig_observer,
-            _processor_handle: processor_handle,
+            _processor_handles: processor_handles,
         }
     }
 
@@ -692,27 +818,22 @@ where
     /// that is NOT tied to the original one. So if there are multiple QuorumDriver(Handler)
     /// then all of them need to do reconfigs on their own.
     pub fn clone_new(&self) -> Self {
-        let (task_sender, task_rx) = mpsc::channel::<QuorumDriverTask>(TASK_QUEUE_SIZE);
+        let (lanes, lane_receivers) = new_lanes(&self.quorum_driver.lane_configs());
         let (effects_subscribe_sender, subscriber_rx) =
             tokio::sync::broadcast::channel::<_>(EFFECTS_QUEUE_SIZE);
         let validators = ArcSwap::new(self.quorum_driver.authority_aggregator().load_full());
-        let quorum_driver = Arc::new(QuorumDriver {
+        let quorum_driver = Arc::new(QuorumDriver::new(
             validators,
-            task_sender,
+            lanes,
             effects_subscribe_sender,
-            notifier: Arc::new(NotifyRead::new()),
-            metrics: self.quorum_driver_metrics.clone(),
-            max_retry_times: self.quorum_driver.max_retry_times,
-        });
-        let metrics = self.quorum_driver_metrics.clone();
-        let processor_handle = {
-            let quorum_driver_copy = quorum_driver.clone();
-            spawn_monitored_task!(Self::task_queue_processor(
-                quorum_driver_copy,
-                task_rx,
-                metrics,
-            ))
-        };
+            Arc::new(NotifyRead::new()),
+            self.quorum_driver_metrics.clone(),
+        ));
+        let processor_handles = Self::spawn_task_queue_processors(
+            &quorum_driver,
+            lane_receivers,
+            &self.quorum_driver_metrics,
+        );
         {
             let quorum_driver_copy = quorum_driver.clone();
             let reconfig_observer = self.reconfig_observer.clone();
@@ -729,10 +850,29 @@ where
             effects_subscriber: subscriber_rx,
             quorum_driver_metrics: self.quorum_driver_metrics.clone(),
             reconfig_observer: self.reconfig_observer.clone(),
-            _processor_handle: processor_handle,
+            _processor_handles: processor_handles,
         }
     }
 
+    fn spawn_task_queue_processors(
+        quorum_driver: &Arc<QuorumDriver<A>>,
+        lane_receivers: LaneReceivers,
+        metrics: &Arc<QuorumDriverMetrics>,
+    ) -> Vec<JoinHandle<()>> {
+        lane_receivers
+            .into_iter()
+            .map(|(priority, config, task_rx)| {
+                spawn_monitored_task!(Self::task_queue_processor(
+                    quorum_driver.clone(),
+                    priority,
+                    config.max_concurrency,
+                    task_rx,
+                    metrics.clone(),
+                ))
+            })
+            .collect()
+    }
+
     pub fn clone_quorum_driver(&self) -> Arc<QuorumDriver<A>> {
         self.quorum_driver.clone()
     }
@@ -751,6 +891,10 @@ where
         self.quorum_driver.current_epoch()
     }
 
+    pub fn pending_transactions(&self) -> &Arc<PendingTransactionTracker> {
+        self.quorum_driver.pending_transactions()
+    }
+
     /// Process a QuorumDriverTask.
     /// The function has no return value - the corresponding actions of task result
     /// are performed in this call.
@@ -766,7 +910,13 @@ where
         } = task;
         let transaction = &request.transaction;
         let tx_digest = *transaction.digest();
+        let priority = request.priority;
         let is_single_writer_tx = !transaction.contains_shared_object();
+        quorum_driver.pending_transactions.attempt_started(
+            &tx_digest,
+            old_retry_times + 1,
+            tx_cert.is_some(),
+        );
 
         let timer = Instant::now();
         let (tx_cert, newly_formed) = match tx_cert {
@@ -779,6 +929,14 @@ where
                     newly_formed,
                 }) => {
                     debug!(?tx_digest, "Transaction processing succeeded");
+                    let committee = quorum_driver.clone_committee();
+                    quorum_driver.pending_transactions.certified(
+                        &tx_digest,
+                        certificate
+                            .auth_sig()
+                            .authorities(&committee)
+                            .filter_map(Result::ok),
+                    );
                     (certificate, newly_formed)
                 }
                 Ok(ProcessTransactionResult::Executed(effects_cert, events)) => {
@@ -855,6 +1013,11 @@ where
                     TX_TYPE_SHARED_OBJ_TX
                 }])
                 .observe(settlement_finality_latency);
+            quorum_driver
+                .metrics
+                .settlement_finality_latency_by_priority
+                .with_label_values(&[priority.as_ref()])
+                .observe(settlement_finality_latency);
             let is_out_of_expected_range =
                 settlement_finality_latency >= 8.0 || settlement_finality_latency <= 0.1;
             debug!(
@@ -914,21 +1077,38 @@ where
         }
     }
 
+    /// Drains the task queue of one priority lane, processing at most `max_concurrency` tasks
+    /// of the lane at a time, within the limit shared by all lanes.
     async fn task_queue_processor(
         quorum_driver: Arc<QuorumDriver<A>>,
+        priority: TransactionPriority,
+        max_concurrency: usize,
         mut task_receiver: Receiver<QuorumDriverTask>,
         metrics: Arc<QuorumDriverMetrics>,
     ) {
-        let limit = Arc::new(Semaphore::new(TASK_QUEUE_SIZE));
+        let limit = Arc::new(Semaphore::new(max_concurrency));
+        let queued = metrics
+            .current_queued_by_priority
+            .with_label_values(&[priority.as_ref()]);
+        let processing = metrics
+            .current_processing_by_priority
+            .with_label_values(&[priority.as_ref()]);
         while let Some(task) = task_receiver.recv().await {
+            queued.dec();
             let task_queue_span =
                 trace_span!(parent: task.trace_span.as_ref().and_then(|s| s.id()), "task_queue");
             let task_span_guard = task_queue_span.enter();
 
-            // hold semaphore permit until task completes. unwrap ok because we never close
-            // the semaphore in this context.
-            let limit = limit.clone();
-            let permit = limit.acquire_owned().await.unwrap();
+            // hold semaphore permits until task completes. unwrap ok because we never close
+            // the semaphores in this context. The lane's permit is acquired first, so a lane
+            // never waits for more of the shared permits than its own limit.
+            let permit = limit.clone().acquire_owned().await.unwrap();
+            let shared_permit = quorum_driver
+                .concurrency_limit
+                .clone()
+                .acquire_owned()
+                .await
+                .unwrap();
 
             // TODO check reconfig process here
 
@@ -943,9 +1123,14 @@ where
             }
             metrics.current_requests_in_flight.dec();
             let qd = quorum_driver.clone();
+            processing.inc();
+            let processing_guard = scopeguard::guard(processing.clone(), |processing| {
+                processing.dec();
+            });
             drop(task_span_guard);
             spawn_monitored_task!(async move {
-                let _guard = permit;
+                let _guard = (permit, shared_permit);
+                let _processing_guard = processing_guard;
                 QuorumDriverHandler::process_task(qd, task).await
             });
         }
@@ -957,7 +1142,7 @@ pub struct QuorumDriverHandlerBuilder<A: Clone> {
     metrics: Arc<QuorumDriverMetrics>,
     notifier: Option<Arc<NotifyRead<TransactionDigest, QuorumDriverResult>>>,
     reconfig_observer: Option<Arc<dyn ReconfigObserver<A> + Sync + Send>>,
-    max_retry_times: u32,
+    lane_configs: HashMap<TransactionPriority, QuorumDriverLaneConfig>,
 }
 
 impl<A> QuorumDriverHandlerBuilder<A>
@@ -970,7 +1155,9 @@ where
             metrics,
             notifier: None,
             reconfig_observer: None,
-            max_retry_times: TX_MAX_RETRY_TIMES,
+            lane_configs: TransactionPriority::iter()
+                .map(|priority| (priority, QuorumDriverLaneConfig::default_for(priority)))
+                .collect(),
         }
     }
 
@@ -990,9 +1177,29 @@ where
         self
     }
 
-    /// Used in tests when smaller number of retries is desired
+    /// Used in tests when smaller number of retries is desired. Applies to all lanes.
     pub fn with_max_retry_times(mut self, max_retry_times: u32) -> Self {
-        self.max_retry_times = max_retry_times;
+        for config in self.lane_configs.values_mut() {
+            config.max_retry_times = max_retry_times;
+        }
+        self
+    }
+
+    pub fn with_lane_config(
+        mut self,
+        priority: TransactionPriority,
+        config: QuorumDriverLaneConfig,
+    ) -> Self {
+        self.lane_configs.insert(priority, config);
+        self
+    }
+
+    /// Overrides the settings of the given lanes, e.g. from the node config.
+    pub fn with_lane_configs(
+        mut self,
+        lane_configs: &BTreeMap<TransactionPriority, QuorumDriverLaneConfig>,
+    ) -> Self {
+        self.lane_configs.extend(lane_configs);
         self
     }
 
@@ -1005,7 +1212,7 @@ where
             self.reconfig_observer
                 .expect("Reconfig observer is missing"),
             self.metrics,
-            self.max_retry_times,
+            self.lane_configs,
         )
     }
 }
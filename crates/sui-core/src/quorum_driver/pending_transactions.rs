@@ -0,0 +1,255 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use lru::LruCache;
+use parking_lot::Mutex;
+use sui_types::base_types::{AuthorityName, TransactionDigest};
+use sui_types::quorum_driver_types::{
+    GroupedErrors, PendingTransactionError, PendingTransactionInfo, PendingTransactionState,
+    TransactionPriority,
+};
+
+/// Number of finished (finalized or failed) transactions kept around for inspection.
+const FINISHED_TRANSACTIONS_CAPACITY: usize = 10_000;
+/// Number of validator errors kept per transaction. Older errors are dropped first.
+const MAX_ERRORS_PER_TRANSACTION: usize = 32;
+
+/// Keeps track of transactions submitted through the QuorumDriver, so that the progress of a
+/// submission can be inspected while clients wait for finality. Transactions stay in the
+/// tracker while they are in flight, and for a bounded while after they finish.
+pub struct PendingTransactionTracker {
+    inner: Mutex<Inner>,
+}
+
+struct Inner {
+    in_flight: HashMap<TransactionDigest, PendingTransactionInfo>,
+    finished: LruCache<TransactionDigest, PendingTransactionInfo>,
+}
+
+impl Default for PendingTransactionTracker {
+    fn default() -> Self {
+        Self::new(FINISHED_TRANSACTIONS_CAPACITY)
+    }
+}
+
+impl PendingTransactionTracker {
+    pub fn new(finished_capacity: usize) -> Self {
+        Self {
+            inner: Mutex::new(Inner {
+                in_flight: HashMap::new(),
+                finished: LruCache::new(NonZeroUsize::new(finished_capacity.max(1)).unwrap()),
+            }),
+        }
+    }
+
+    pub fn get(&self, digest: &TransactionDigest) -> Option<PendingTransactionInfo> {
+        let inner = self.inner.lock();
+        inner
+            .in_flight
+            .get(digest)
+            .or_else(|| inner.finished.peek(digest))
+            .cloned()
+    }
+
+    pub fn num_in_flight(&self) -> usize {
+        self.inner.lock().in_flight.len()
+    }
+
+    /// Start tracking a submitted transaction. A resubmission of a transaction that is already
+    /// in flight keeps its existing record; a resubmission of a finished transaction starts over.
+    pub(crate) fn submitted(&self, digest: TransactionDigest, priority: TransactionPriority) {
+        let mut inner = self.inner.lock();
+        if inner.in_flight.contains_key(&digest) {
+            return;
+        }
+        inner.finished.pop(&digest);
+        let now = now_ms();
+        inner.in_flight.insert(
+            digest,
+            PendingTransactionInfo {
+                digest,
+                priority,
+                state: PendingTransactionState::Signing,
+                attempts: 0,
+                submitted_at_ms: now,
+                last_updated_ms: now,
+                responded_validators: Default::default(),
+                errors: vec![],
+            },
+        );
+    }
+
+    /// Stop tracking a transaction that could not be handed to the QuorumDriver.
+    pub(crate) fn abandoned(&self, digest: &TransactionDigest) {
+        self.inner.lock().in_flight.remove(digest);
+    }
+
+    pub(crate) fn attempt_started(
+        &self,
+        digest: &TransactionDigest,
+        attempt: u32,
+        certified: bool,
+    ) {
+        self.update(digest, |info| {
+            info.attempts = attempt;
+            info.state = if certified {
+                PendingTransactionState::Certified
+            } else {
+                PendingTransactionState::Signing
+            };
+        });
+    }
+
+    pub(crate) fn certified<'a>(
+        &self,
+        digest: &TransactionDigest,
+        signers: impl IntoIterator<Item = &'a AuthorityName>,
+    ) {
+        self.update(digest, |info| {
+            info.state = PendingTransactionState::Certified;
+            info.responded_validators.extend(signers);
+        });
+    }
+
+    pub(crate) fn errors_seen(&self, digest: &TransactionDigest, errors: &GroupedErrors) {
+        self.update(digest, |info| {
+            let attempt = info.attempts;
+            push_errors(
+                info,
+                errors
+                    .iter()
+                    .map(|(error, _stake, validators)| PendingTransactionError {
+                        attempt,
+                        error: error.to_string(),
+                        validators: validators.iter().map(|name| (*name).into()).collect(),
+                    }),
+            );
+        });
+    }
+
+    pub(crate) fn finalized<'a>(
+        &self,
+        digest: &TransactionDigest,
+        signers: impl IntoIterator<Item = &'a AuthorityName>,
+    ) {
+        self.finish(digest, |info| {
+            info.state = PendingTransactionState::Finalized;
+            info.responded_validators.extend(signers);
+        });
+    }
+
+    pub(crate) fn failed(&self, digest: &TransactionDigest, error: String) {
+        self.finish(digest, |info| {
+            let attempt = info.attempts;
+            info.state = PendingTransactionState::Failed;
+            push_errors(
+                info,
+                [PendingTransactionError {
+                    attempt,
+                    error,
+                    validators: vec![],
+                }],
+            );
+        });
+    }
+
+    fn update(&self, digest: &TransactionDigest, f: impl FnOnce(&mut PendingTransactionInfo)) {
+        let mut inner = self.inner.lock();
+        if let Some(info) = inner.in_flight.get_mut(digest) {
+            f(info);
+            info.last_updated_ms = now_ms();
+        }
+    }
+
+    fn finish(&self, digest: &TransactionDigest, f: impl FnOnce(&mut PendingTransactionInfo)) {
+        let mut inner = self.inner.lock();
+        if let Some(mut info) = inner.in_flight.remove(digest) {
+            f(&mut info);
+            info.last_updated_ms = now_ms();
+            inner.finished.put(*digest, info);
+        }
+    }
+}
+
+fn push_errors(
+    info: &mut PendingTransactionInfo,
+    errors: impl IntoIterator<Item = PendingTransactionError>,
+) {
+    info.errors.extend(errors);
+    let excess = info.errors.len().saturating_sub(MAX_ERRORS_PER_TRANSACTION);
+    info.errors.drain(..excess);
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sui_types::base_types::ConciseableName;
+    use sui_types::crypto::{get_key_pair, AuthorityKeyPair, KeypairTraits};
+    use sui_types::error::SuiError;
+
+    #[test]
+    fn test_pending_transaction_lifecycle() {
+        let tracker = PendingTransactionTracker::new(1);
+        let digest = TransactionDigest::random();
+        let (_, key): (_, AuthorityKeyPair) = get_key_pair();
+        let name: AuthorityName = key.public().into();
+
+        assert!(tracker.get(&digest).is_none());
+        tracker.submitted(digest, TransactionPriority::High);
+        tracker.attempt_started(&digest, 1, false);
+        tracker.errors_seen(
+            &digest,
+            &vec![(
+                SuiError::TooManyTransactionsPendingExecution {
+                    queue_len: 10,
+                    threshold: 5,
+                },
+                1,
+                vec![name.concise_owned()],
+            )],
+        );
+        tracker.attempt_started(&digest, 2, false);
+        tracker.certified(&digest, [&name]);
+
+        let info = tracker.get(&digest).unwrap();
+        assert_eq!(info.state, PendingTransactionState::Certified);
+        assert_eq!(info.priority, TransactionPriority::High);
+        assert_eq!(info.attempts, 2);
+        assert_eq!(info.errors.len(), 1);
+        assert_eq!(info.errors[0].attempt, 1);
+        assert_eq!(info.errors[0].validators, vec![name]);
+        assert!(info.responded_validators.contains(&name));
+        assert_eq!(tracker.num_in_flight(), 1);
+
+        // Resubmitting an in-flight transaction keeps its progress.
+        tracker.submitted(digest, TransactionPriority::Low);
+        assert_eq!(tracker.get(&digest).unwrap().attempts, 2);
+
+        tracker.finalized(&digest, [&name]);
+        assert_eq!(tracker.num_in_flight(), 0);
+        assert_eq!(
+            tracker.get(&digest).unwrap().state,
+            PendingTransactionState::Finalized
+        );
+
+        // Finished transactions are evicted once the retention capacity is exceeded.
+        let other = TransactionDigest::random();
+        tracker.submitted(other, TransactionPriority::Normal);
+        tracker.failed(&other, "timeout".to_string());
+        assert!(tracker.get(&digest).is_none());
+        let info = tracker.get(&other).unwrap();
+        assert_eq!(info.state, PendingTransactionState::Failed);
+        assert_eq!(info.errors.last().unwrap().error, "timeout");
+    }
+}
@@ -2,7 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::quorum_driver::reconfig_observer::DummyReconfigObserver;
-use crate::quorum_driver::{AuthorityAggregator, QuorumDriverHandlerBuilder};
+use crate::quorum_driver::{
+    AuthorityAggregator, QuorumDriverHandlerBuilder, QuorumDriverLaneConfig,
+};
 use crate::test_authority_clients::LocalAuthorityClient;
 use crate::test_authority_clients::LocalAuthorityClientFaultConfig;
 use crate::test_utils::make_transfer_sui_transaction;
@@ -12,6 +14,7 @@ use std::net::SocketAddr;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
+use sui_config::node::{QUORUM_DRIVER_HIGH_PRIORITY_HEADROOM, QUORUM_DRIVER_MAX_CONCURRENCY};
 use sui_macros::{register_fail_point, sim_test};
 use sui_types::base_types::SuiAddress;
 use sui_types::base_types::TransactionDigest;
@@ -20,6 +23,7 @@ use sui_types::effects::TransactionEffectsAPI;
 use sui_types::object::{generate_test_gas_objects, Object};
 use sui_types::quorum_driver_types::{
     ExecuteTransactionRequestV3, QuorumDriverError, QuorumDriverResponse, QuorumDriverResult,
+    TransactionPriority,
 };
 use sui_types::transaction::Transaction;
 use tokio::time::timeout;
@@ -226,6 +230,91 @@ async fn test_quorum_driver_update_validators_and_max_retry_times() {
     handle.await.unwrap();
 }
 
+#[tokio::test]
+async fn test_quorum_driver_retry_budget_per_priority() {
+    telemetry_subscribers::init_for_testing();
+    let (sender, keypair): (_, AccountKeyPair) = get_key_pair();
+    let gas_object = Object::with_owner_for_testing(sender);
+    let (mut aggregator, authorities, genesis, _) =
+        init_local_authorities(4, vec![gas_object.clone()]).await;
+    let rgp = authorities
+        .first()
+        .unwrap()
+        .reference_gas_price_for_testing()
+        .unwrap();
+    let gas_object = genesis
+        .objects()
+        .iter()
+        .find(|o| o.id() == gas_object.id())
+        .unwrap();
+    let high_tx = make_tx(gas_object, sender, &keypair, rgp);
+    let low_tx = make_tx(gas_object, sender, &keypair, rgp);
+
+    // Bump the client epoch so that every attempt fails with a retryable epoch mismatch.
+    let mut committee = aggregator.clone_inner_committee_test_only();
+    committee.epoch = 10;
+    aggregator.committee = Arc::new(committee);
+
+    let quorum_driver_handler = QuorumDriverHandlerBuilder::new(
+        Arc::new(aggregator),
+        Arc::new(QuorumDriverMetrics::new_for_tests()),
+    )
+    .with_reconfig_observer(Arc::new(DummyReconfigObserver {}))
+    .with_lane_config(
+        TransactionPriority::High,
+        QuorumDriverLaneConfig {
+            max_retry_times: 1,
+            ..QuorumDriverLaneConfig::default_for(TransactionPriority::High)
+        },
+    )
+    .with_lane_config(
+        TransactionPriority::Low,
+        QuorumDriverLaneConfig {
+            max_concurrency: 1,
+            max_retry_times: 2,
+            ..QuorumDriverLaneConfig::default_for(TransactionPriority::Low)
+        },
+    )
+    .start();
+
+    let high_ticket = quorum_driver_handler
+        .submit_transaction(
+            ExecuteTransactionRequestV3::new_v2(high_tx).with_priority(TransactionPriority::High),
+        )
+        .await
+        .unwrap();
+    let low_ticket = quorum_driver_handler
+        .submit_transaction(
+            ExecuteTransactionRequestV3::new_v2(low_tx).with_priority(TransactionPriority::Low),
+        )
+        .await
+        .unwrap();
+
+    for (ticket, expected_attempts) in [(high_ticket, 2), (low_ticket, 3)] {
+        match timeout(Duration::from_secs(20), ticket).await {
+            Ok(Err(QuorumDriverError::FailedWithTransientErrorAfterMaximumAttempts {
+                total_attempts,
+            })) => assert_eq!(total_attempts, expected_attempts),
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+}
+
+#[test]
+fn test_default_lanes_leave_headroom_for_high_priority() {
+    let high = QuorumDriverLaneConfig::default_for(TransactionPriority::High);
+    let normal = QuorumDriverLaneConfig::default_for(TransactionPriority::Normal);
+    let low = QuorumDriverLaneConfig::default_for(TransactionPriority::Low);
+
+    // Normal and low priority tasks alone can never take the headroom left to high priority.
+    assert!(
+        normal.max_concurrency + low.max_concurrency
+            <= QUORUM_DRIVER_MAX_CONCURRENCY - QUORUM_DRIVER_HIGH_PRIORITY_HEADROOM
+    );
+    assert!(high.max_concurrency <= QUORUM_DRIVER_MAX_CONCURRENCY);
+    assert!(high.max_retry_times > normal.max_retry_times);
+}
+
 #[tokio::test]
 async fn test_quorum_driver_object_locked() -> Result<(), anyhow::Error> {
     let gas_objects = generate_test_gas_objects();
@@ -6,6 +6,7 @@ use crate::authority::authority_store_tables::LiveObject;
 use crate::authority::AuthorityStore;
 use crate::checkpoints::CheckpointStore;
 use move_core_types::language_storage::StructTag;
+use move_core_types::language_storage::TypeTag;
 use rayon::iter::IntoParallelIterator;
 use rayon::iter::ParallelIterator;
 use serde::Deserialize;
@@ -21,6 +22,8 @@ use sui_types::base_types::SequenceNumber;
 use sui_types::base_types::SuiAddress;
 use sui_types::digests::TransactionDigest;
 use sui_types::dynamic_field::visitor as DFV;
+use sui_types::effects::TransactionEffectsAPI;
+use sui_types::effects::TransactionEvents;
 use sui_types::full_checkpoint_content::CheckpointData;
 use sui_types::layout_resolver::LayoutResolver;
 use sui_types::messages_checkpoint::CheckpointContents;
@@ -30,14 +33,14 @@ use sui_types::storage::error::Error as StorageError;
 use sui_types::storage::BackingPackageStore;
 use sui_types::storage::DynamicFieldIndexInfo;
 use sui_types::storage::DynamicFieldKey;
-use tracing::{debug, info};
-use typed_store::rocks::{DBMap, MetricConf};
+use tracing::{debug, info, warn};
+use typed_store::rocks::{DBBatch, DBMap, MetricConf};
 use typed_store::traits::Map;
 use typed_store::traits::{TableSummary, TypedStoreDebug};
 use typed_store::DBMapUtils;
 use typed_store::TypedStoreError;
 
-const CURRENT_DB_VERSION: u64 = 0;
+const CURRENT_DB_VERSION: u64 = 2;
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 struct MetadataInfo {
@@ -100,6 +103,71 @@ impl CoinIndexInfo {
     }
 }
 
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct BalanceKey {
+    pub owner: SuiAddress,
+    pub coin_type: StructTag,
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default, Debug)]
+pub struct BalanceIndexInfo {
+    pub balance: u64,
+    pub coin_object_count: u64,
+}
+
+/// A change to an address's balance of a particular coin type, accumulated while indexing a
+/// checkpoint.
+#[derive(Clone, Copy, Default, Debug)]
+struct BalanceDelta {
+    balance: i128,
+    coin_object_count: i64,
+}
+
+impl BalanceDelta {
+    fn add(&mut self, value: u64) {
+        self.balance += value as i128;
+        self.coin_object_count += 1;
+    }
+
+    fn remove(&mut self, value: u64) {
+        self.balance -= value as i128;
+        self.coin_object_count -= 1;
+    }
+
+    /// Apply this change to `info`. A change that would take the balance or coin count out of
+    /// range means the index has diverged from the live object set. The index is derived data, so
+    /// rather than halting, the value is clamped and the divergence is logged.
+    fn apply(self, key: &BalanceKey, info: BalanceIndexInfo) -> BalanceIndexInfo {
+        let balance = info.balance as i128 + self.balance;
+        let coin_object_count = info.coin_object_count as i128 + self.coin_object_count as i128;
+
+        if balance < 0 || coin_object_count < 0 {
+            warn!(
+                owner = %key.owner,
+                coin_type = %key.coin_type,
+                balance,
+                coin_object_count,
+                "balance index out of range, clamping to zero"
+            );
+        }
+
+        BalanceIndexInfo {
+            balance: balance.clamp(0, u64::MAX as i128) as u64,
+            coin_object_count: coin_object_count.clamp(0, u64::MAX as i128) as u64,
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct EventIndexKey {
+    pub event_type: StructTag,
+    pub checkpoint: u64,
+    /// Index of the emitting transaction within its checkpoint.
+    pub transaction_index: u32,
+    /// Index of the event within the events emitted by its transaction.
+    pub event_index: u32,
+}
+
 /// RocksDB tables for the RestIndexStore
 ///
 /// Anytime a new table is added, or and existing one has it's schema changed, make sure to also
@@ -141,6 +209,32 @@ struct IndexStoreTables {
     /// Allows looking up information related to published Coins, like the ObjectID of its
     /// coorisponding CoinMetadata.
     coin: DBMap<CoinIndexKey, CoinIndexInfo>,
+
+    /// An index of the balance of each coin type owned by an address.
+    ///
+    /// Allows an efficient iterator to list all of the balances of a specific user account, or to
+    /// look up a single one, without scanning all of its coin objects.
+    balance: DBMap<BalanceKey, BalanceIndexInfo>,
+
+    /// An index of events by their type.
+    ///
+    /// Allows an efficient iterator to list all events of a particular type, in the order they
+    /// were emitted. Only contains entries for transactions which have yet to be pruned from the
+    /// main database.
+    event_type: DBMap<EventIndexKey, TransactionDigest>,
+
+    /// The keys of `event_type` that each transaction contributed to, so they can be pruned along
+    /// with the transaction.
+    ///
+    /// Only contains entries for transactions which emitted events and have yet to be pruned from
+    /// the main database.
+    transaction_events: DBMap<TransactionDigest, Vec<EventIndexKey>>,
+
+    /// A singleton holding the sequence number of the last checkpoint applied to the indexes.
+    ///
+    /// Balances are updated by reading their previous value, so a checkpoint that is executed
+    /// again after a restart must not be applied twice.
+    indexed_checkpoint: DBMap<(), u64>,
     // NOTE: Authors and Reviewers before adding any new tables ensure that they are either:
     // - bounded in size by the live object set
     // - are prune-able and have corresponding logic in the `prune` function
@@ -210,11 +304,38 @@ impl IndexStoreTables {
                     checkpoint: checkpoint.sequence_number,
                 };
 
-                self.transactions
-                    .multi_insert(contents.iter().map(|digests| (digests.transaction, info)))
-                    .map_err(StorageError::from)
+                let mut batch = self.transactions.batch();
+                batch.insert_batch(
+                    &self.transactions,
+                    contents.iter().map(|digests| (digests.transaction, info)),
+                )?;
+
+                for (transaction_index, digests) in contents.iter().enumerate() {
+                    let events = authority_store
+                        .get_executed_effects(&digests.transaction)
+                        .map_err(StorageError::custom)?
+                        .and_then(|effects| effects.events_digest().copied())
+                        .map(|digest| authority_store.get_events(&digest))
+                        .transpose()?
+                        .flatten();
+
+                    if let Some(events) = events {
+                        self.index_transaction_events(
+                            &mut batch,
+                            checkpoint.sequence_number,
+                            transaction_index,
+                            digests.transaction,
+                            &events,
+                        )?;
+                    }
+                }
+
+                batch.write().map_err(StorageError::from)
             })?;
 
+            self.indexed_checkpoint
+                .insert(&(), &highest_executed_checkpint)?;
+
             info!(
                 "Indexing checkpoints took {} seconds",
                 start_time.elapsed().as_secs()
@@ -222,6 +343,7 @@ impl IndexStoreTables {
         }
 
         let coin_index = Mutex::new(HashMap::new());
+        let balance_index = Mutex::new(HashMap::new());
 
         info!("Indexing Live Object Set");
         let start_time = Instant::now();
@@ -231,12 +353,14 @@ impl IndexStoreTables {
             for index in 0u8..(1 << BITS) {
                 let this = &self;
                 let coin_index = &coin_index;
+                let balance_index = &balance_index;
                 threads.push(s.spawn(move || {
                     this.live_object_set_index_task(
                         index,
                         BITS,
                         authority_store,
                         coin_index,
+                        balance_index,
                         epoch_store,
                         package_store,
                     )
@@ -252,6 +376,8 @@ impl IndexStoreTables {
         })?;
 
         self.coin.multi_insert(coin_index.into_inner().unwrap())?;
+        self.balance
+            .multi_insert(balance_index.into_inner().unwrap())?;
 
         info!(
             "Indexing Live Object Set took {} seconds",
@@ -276,6 +402,7 @@ impl IndexStoreTables {
         bits: u8,
         authority_store: &AuthorityStore,
         coin_index: &Mutex<HashMap<CoinIndexKey, CoinIndexInfo>>,
+        balance_index: &Mutex<HashMap<BalanceKey, BalanceIndexInfo>>,
         epoch_store: &AuthorityPerEpochStore,
         package_store: &Arc<dyn BackingPackageStore + Send + Sync>,
     ) -> Result<(), StorageError> {
@@ -293,6 +420,9 @@ impl IndexStoreTables {
             .executor()
             .type_layout_resolver(Box::new(package_store));
         let mut batch = self.owner.batch();
+        // An address's coins are spread across all tasks, so balances are accumulated locally and
+        // only merged into the shared index once this task's range has been scanned.
+        let mut balances: HashMap<BalanceKey, BalanceIndexInfo> = HashMap::new();
         let mut object_scanned: u64 = 0;
         for object in authority_store
             .perpetual_tables
@@ -312,6 +442,13 @@ impl IndexStoreTables {
                     let owner_key = OwnerIndexKey::new(owner, object.id());
                     let owner_info = OwnerIndexInfo::new(&object);
                     batch.insert_batch(&self.owner, [(owner_key, owner_info)])?;
+
+                    // Balance Index
+                    if let Some((key, value)) = try_get_balance_key(&object) {
+                        let info = balances.entry(key).or_default();
+                        info.balance += value;
+                        info.coin_object_count += 1;
+                    }
                 }
 
                 // Dynamic Field Index
@@ -353,6 +490,47 @@ impl IndexStoreTables {
         }
 
         batch.write()?;
+
+        let mut balance_index = balance_index.lock().unwrap();
+        for (key, info) in balances {
+            let total = balance_index.entry(key).or_default();
+            total.balance += info.balance;
+            total.coin_object_count += info.coin_object_count;
+        }
+
+        Ok(())
+    }
+
+    /// Add entries to the event type index for the `events` emitted by a transaction.
+    fn index_transaction_events(
+        &self,
+        batch: &mut DBBatch,
+        checkpoint: u64,
+        transaction_index: usize,
+        digest: TransactionDigest,
+        events: &TransactionEvents,
+    ) -> Result<(), TypedStoreError> {
+        if events.data.is_empty() {
+            return Ok(());
+        }
+
+        let keys: Vec<_> = events
+            .data
+            .iter()
+            .enumerate()
+            .map(|(event_index, event)| EventIndexKey {
+                event_type: event.type_.clone(),
+                checkpoint,
+                transaction_index: transaction_index as u32,
+                event_index: event_index as u32,
+            })
+            .collect();
+
+        batch.insert_batch(
+            &self.event_type,
+            keys.iter().cloned().map(|key| (key, digest)),
+        )?;
+        batch.insert_batch(&self.transaction_events, [(digest, keys)])?;
         Ok(())
     }
 
@@ -367,23 +545,42 @@ impl IndexStoreTables {
             .iter()
             .flat_map(|contents| contents.iter().map(|digests| digests.transaction));
 
-        batch.delete_batch(&self.transactions, transactions_to_prune)?;
+        for digest in transactions_to_prune {
+            if let Some(keys) = self.transaction_events.get(&digest)? {
+                batch.delete_batch(&self.event_type, keys)?;
+                batch.delete_batch(&self.transaction_events, [digest])?;
+            }
+
+            batch.delete_batch(&self.transactions, [digest])?;
+        }
 
         batch.write()
     }
 
-    /// Index a Checkpoint
+    /// Index a Checkpoint. Checkpoints must be indexed in order, and checkpoints that have already
+    /// been indexed are skipped.
     fn index_checkpoint(
         &self,
         checkpoint: &CheckpointData,
         resolver: &mut dyn LayoutResolver,
     ) -> Result<(), StorageError> {
-        debug!(
-            checkpoint = checkpoint.checkpoint_summary.sequence_number,
-            "indexing checkpoint"
-        );
+        let sequence_number = checkpoint.checkpoint_summary.sequence_number;
+        if self
+            .indexed_checkpoint
+            .get(&())?
+            .is_some_and(|indexed| sequence_number <= indexed)
+        {
+            debug!(
+                checkpoint = sequence_number,
+                "skipping already indexed checkpoint"
+            );
+            return Ok(());
+        }
+
+        debug!(checkpoint = sequence_number, "indexing checkpoint");
 
         let mut batch = self.transactions.batch();
+        batch.insert_batch(&self.indexed_checkpoint, [((), sequence_number)])?;
 
         // transactions index
         {
@@ -400,9 +597,23 @@ impl IndexStoreTables {
             )?;
         }
 
+        // event indexes
+        for (transaction_index, tx) in checkpoint.transactions.iter().enumerate() {
+            if let Some(events) = &tx.events {
+                self.index_transaction_events(
+                    &mut batch,
+                    checkpoint.checkpoint_summary.sequence_number,
+                    transaction_index,
+                    *tx.effects.transaction_digest(),
+                    events,
+                )?;
+            }
+        }
+
         // object indexes
         {
             let mut coin_index = HashMap::new();
+            let mut balance_changes: HashMap<BalanceKey, BalanceDelta> = HashMap::new();
 
             for tx in &checkpoint.transactions {
                 // determine changes from removed objects
@@ -411,6 +622,10 @@ impl IndexStoreTables {
                         Owner::AddressOwner(address) => {
                             let owner_key = OwnerIndexKey::new(*address, removed_object.id());
                             batch.delete_batch(&self.owner, [owner_key])?;
+
+                            if let Some((key, value)) = try_get_balance_key(removed_object) {
+                                balance_changes.entry(key).or_default().remove(value);
+                            }
                         }
                         Owner::ObjectOwner(object_id) => {
                             batch.delete_batch(
@@ -425,6 +640,12 @@ impl IndexStoreTables {
                 // determine changes from changed objects
                 for (object, old_object) in tx.changed_objects() {
                     if let Some(old_object) = old_object {
+                        // Coins can change value without changing owner, so balances are always
+                        // recomputed from the old and new versions of a coin.
+                        if let Some((key, value)) = try_get_balance_key(old_object) {
+                            balance_changes.entry(key).or_default().remove(value);
+                        }
+
                         if old_object.owner() != object.owner() {
                             match old_object.owner() {
                                 Owner::AddressOwner(address) => {
@@ -449,6 +670,10 @@ impl IndexStoreTables {
                             let owner_key = OwnerIndexKey::new(*owner, object.id());
                             let owner_info = OwnerIndexInfo::new(object);
                             batch.insert_batch(&self.owner, [(owner_key, owner_info)])?;
+
+                            if let Some((key, value)) = try_get_balance_key(object) {
+                                balance_changes.entry(key).or_default().add(value);
+                            }
                         }
                         Owner::ObjectOwner(parent) => {
                             if let Some(field_info) =
@@ -488,6 +713,15 @@ impl IndexStoreTables {
             }
 
             batch.insert_batch(&self.coin, coin_index)?;
+
+            for (key, delta) in balance_changes {
+                let info = delta.apply(&key, self.balance.get(&key)?.unwrap_or_default());
+                if info.coin_object_count == 0 {
+                    batch.delete_batch(&self.balance, [key])?;
+                } else {
+                    batch.insert_batch(&self.balance, [(key, info)])?;
+                }
+            }
         }
 
         batch.write()?;
@@ -552,6 +786,59 @@ impl IndexStoreTables {
         };
         self.coin.get(&key)
     }
+
+    fn balance_iter(
+        &self,
+        owner: SuiAddress,
+        cursor: Option<StructTag>,
+    ) -> Result<impl Iterator<Item = (BalanceKey, BalanceIndexInfo)> + '_, TypedStoreError> {
+        // Keys are ordered by owner, and then by coin type, so iteration starts from the smallest
+        // possible coin type (there is no coin type at address 0x0) and stops at the next owner.
+        let coin_type = cursor.unwrap_or_else(|| "0x0::A::A".parse().unwrap());
+        let lower_bound = BalanceKey { owner, coin_type };
+
+        let iter = self
+            .balance
+            .iter_with_bounds(Some(lower_bound), None)
+            .take_while(move |(key, _)| key.owner == owner);
+
+        Ok(iter)
+    }
+
+    fn get_balance(
+        &self,
+        owner: SuiAddress,
+        coin_type: &StructTag,
+    ) -> Result<Option<BalanceIndexInfo>, TypedStoreError> {
+        let key = BalanceKey {
+            owner,
+            coin_type: coin_type.to_owned(),
+        };
+        self.balance.get(&key)
+    }
+
+    fn event_type_iter(
+        &self,
+        event_type: &StructTag,
+        cursor: Option<(u64, u32, u32)>,
+    ) -> Result<impl Iterator<Item = (EventIndexKey, TransactionDigest)> + '_, TypedStoreError>
+    {
+        let key = |(checkpoint, transaction_index, event_index)| EventIndexKey {
+            event_type: event_type.to_owned(),
+            checkpoint,
+            transaction_index,
+            event_index,
+        };
+
+        let lower_bound = key(cursor.unwrap_or((0, 0, 0)));
+        let upper_bound = key((u64::MAX, u32::MAX, u32::MAX));
+
+        let iter = self
+            .event_type
+            .iter_with_bounds(Some(lower_bound), Some(upper_bound));
+
+        Ok(iter)
+    }
 }
 
 pub struct RestIndexStore {
@@ -611,6 +898,8 @@ impl RestIndexStore {
         self.tables.prune(checkpoint_contents_to_prune)
     }
 
+    /// Index an executed checkpoint. Must be called in checkpoint order, by a single writer,
+    /// because balances are updated from their previous values.
     pub fn index_checkpoint(
         &self,
         checkpoint: &CheckpointData,
@@ -649,6 +938,31 @@ impl RestIndexStore {
     ) -> Result<Option<CoinIndexInfo>, TypedStoreError> {
         self.tables.get_coin_info(coin_type)
     }
+
+    pub fn balance_iter(
+        &self,
+        owner: SuiAddress,
+        cursor: Option<StructTag>,
+    ) -> Result<impl Iterator<Item = (BalanceKey, BalanceIndexInfo)> + '_, TypedStoreError> {
+        self.tables.balance_iter(owner, cursor)
+    }
+
+    pub fn get_balance(
+        &self,
+        owner: SuiAddress,
+        coin_type: &StructTag,
+    ) -> Result<Option<BalanceIndexInfo>, TypedStoreError> {
+        self.tables.get_balance(owner, coin_type)
+    }
+
+    pub fn event_type_iter(
+        &self,
+        event_type: &StructTag,
+        cursor: Option<(u64, u32, u32)>,
+    ) -> Result<impl Iterator<Item = (EventIndexKey, TransactionDigest)> + '_, TypedStoreError>
+    {
+        self.tables.event_type_iter(event_type, cursor)
+    }
 }
 
 fn try_create_dynamic_field_info(
@@ -727,3 +1041,25 @@ fn try_create_coin_index_info(object: &Object) -> Option<(CoinIndexKey, CoinInde
                 })
         })
 }
+
+/// The key in the balance index for `object`, and its value, if it is a coin owned by an address.
+fn try_get_balance_key(object: &Object) -> Option<(BalanceKey, u64)> {
+    let Owner::AddressOwner(owner) = object.owner() else {
+        return None;
+    };
+
+    let TypeTag::Struct(coin_type) = object.coin_type_maybe()? else {
+        return None;
+    };
+
+    let key = BalanceKey {
+        owner: *owner,
+        coin_type: *coin_type,
+    };
+
+    Some((key, object.get_coin_value_unsafe()))
+}
+
+#[cfg(test)]
+#[path = "unit_tests/rest_index_tests.rs"]
+mod rest_index_tests;
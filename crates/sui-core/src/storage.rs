@@ -23,9 +23,11 @@ use sui_types::object::Object;
 use sui_types::storage::error::Error as StorageError;
 use sui_types::storage::error::Result;
 use sui_types::storage::AccountOwnedObjectInfo;
+use sui_types::storage::BalanceInfo;
 use sui_types::storage::CoinInfo;
 use sui_types::storage::DynamicFieldIndexInfo;
 use sui_types::storage::DynamicFieldKey;
+use sui_types::storage::EventInfo;
 use sui_types::storage::ObjectStore;
 use sui_types::storage::RestStateReader;
 use sui_types::storage::WriteStore;
@@ -37,7 +39,10 @@ use crate::authority::AuthorityState;
 use crate::checkpoints::CheckpointStore;
 use crate::epoch::committee_store::CommitteeStore;
 use crate::execution_cache::ExecutionCacheTraitPointers;
+use crate::rest_index::BalanceIndexInfo;
+use crate::rest_index::BalanceKey;
 use crate::rest_index::CoinIndexInfo;
+use crate::rest_index::EventIndexKey;
 use crate::rest_index::OwnerIndexInfo;
 use crate::rest_index::OwnerIndexKey;
 use crate::rest_index::RestIndexStore;
@@ -583,4 +588,70 @@ impl RestStateReader for RestReadStore {
             )
             .pipe(Ok)
     }
+
+    fn account_balances_iter(
+        &self,
+        owner: SuiAddress,
+        cursor: Option<StructTag>,
+    ) -> Result<Box<dyn Iterator<Item = BalanceInfo> + '_>> {
+        let iter = self.index()?.balance_iter(owner, cursor)?.map(
+            |(
+                BalanceKey {
+                    owner: _,
+                    coin_type,
+                },
+                BalanceIndexInfo {
+                    balance,
+                    coin_object_count,
+                },
+            )| BalanceInfo {
+                coin_type,
+                balance,
+                coin_object_count,
+            },
+        );
+
+        Ok(Box::new(iter) as _)
+    }
+
+    fn get_balance(&self, owner: SuiAddress, coin_type: &StructTag) -> Result<Option<BalanceInfo>> {
+        self.index()?
+            .get_balance(owner, coin_type)?
+            .map(
+                |BalanceIndexInfo {
+                     balance,
+                     coin_object_count,
+                 }| BalanceInfo {
+                    coin_type: coin_type.to_owned(),
+                    balance,
+                    coin_object_count,
+                },
+            )
+            .pipe(Ok)
+    }
+
+    fn event_type_iter(
+        &self,
+        event_type: &StructTag,
+        cursor: Option<(CheckpointSequenceNumber, u32, u32)>,
+    ) -> Result<Box<dyn Iterator<Item = EventInfo> + '_>> {
+        let iter = self.index()?.event_type_iter(event_type, cursor)?.map(
+            |(
+                EventIndexKey {
+                    event_type: _,
+                    checkpoint,
+                    transaction_index,
+                    event_index,
+                },
+                transaction_digest,
+            )| EventInfo {
+                checkpoint,
+                transaction_index,
+                transaction_digest,
+                event_index,
+            },
+        );
+
+        Ok(Box::new(iter) as _)
+    }
 }
@@ -11,6 +11,7 @@ use std::{
 use lru::LruCache;
 use mysten_metrics::monitored_scope;
 use parking_lot::RwLock;
+use serde::Serialize;
 use sui_types::{
     base_types::{ObjectID, SequenceNumber, TransactionDigest},
     committee::EpochId,
@@ -62,7 +63,6 @@ pub struct TransactionManager {
 #[derive(Clone, Debug)]
 pub struct PendingCertificateStats {
     // The time this certificate enters transaction manager.
-    #[allow(unused)]
     pub enqueue_time: Instant,
     // The time this certificate becomes ready for execution.
     pub ready_time: Option<Instant>,
@@ -81,6 +81,25 @@ pub struct PendingCertificate {
     pub stats: PendingCertificateStats,
 }
 
+/// The certificates held by a TransactionManager at one point in time, for debugging.
+#[derive(Debug, Serialize)]
+pub struct TransactionManagerSnapshot {
+    pub epoch: EpochId,
+    /// Certificates waiting for some of their input objects to become available.
+    pub pending: Vec<PendingCertificateSnapshot>,
+    /// Certificates whose inputs are all available, and which have not finished executing.
+    pub executing: Vec<TransactionDigest>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PendingCertificateSnapshot {
+    pub digest: TransactionDigest,
+    /// How long the certificate has been in TransactionManager.
+    pub age_ms: u64,
+    /// The input objects the certificate is still waiting for.
+    pub waiting_input_objects: Vec<InputKey>,
+}
+
 struct CacheInner {
     versioned_cache: LruCache<ObjectID, SequenceNumber>,
     // we cache packages separately, because they are more expensive to look up in the db, so we
@@ -795,6 +814,30 @@ impl TransactionManager {
             .collect()
     }
 
+    /// Returns the certificates that are waiting on input objects, oldest first, along with the
+    /// objects they are waiting on, and the certificates being executed.
+    pub fn snapshot(&self) -> TransactionManagerSnapshot {
+        let reconfig_lock = self.inner.read();
+        let inner = reconfig_lock.read();
+
+        let mut pending: Vec<_> = inner
+            .pending_certificates
+            .iter()
+            .map(|(digest, cert)| PendingCertificateSnapshot {
+                digest: *digest,
+                age_ms: cert.stats.enqueue_time.elapsed().as_millis() as u64,
+                waiting_input_objects: cert.waiting_input_objects.iter().copied().collect(),
+            })
+            .collect();
+        pending.sort_by_key(|cert| Reverse(cert.age_ms));
+
+        TransactionManagerSnapshot {
+            epoch: inner.epoch,
+            pending,
+            executing: inner.executing_certificates.iter().copied().collect(),
+        }
+    }
+
     // Returns the number of transactions pending or being executed right now.
     pub(crate) fn inflight_queue_len(&self) -> usize {
         let reconfig_lock = self.inner.read();
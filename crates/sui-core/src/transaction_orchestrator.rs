@@ -12,7 +12,9 @@ use crate::authority::AuthorityState;
 use crate::authority_aggregator::AuthorityAggregator;
 use crate::authority_client::{AuthorityAPI, NetworkAuthorityClient};
 use crate::quorum_driver::reconfig_observer::{OnsiteReconfigObserver, ReconfigObserver};
-use crate::quorum_driver::{QuorumDriverHandler, QuorumDriverHandlerBuilder, QuorumDriverMetrics};
+use crate::quorum_driver::{
+    QuorumDriverHandler, QuorumDriverHandlerBuilder, QuorumDriverLaneConfig, QuorumDriverMetrics,
+};
 use futures::future::{select, Either, Future};
 use futures::FutureExt;
 use mysten_common::sync::notify_read::NotifyRead;
@@ -24,6 +26,7 @@ use prometheus::{
     register_int_counter_with_registry, register_int_gauge_vec_with_registry,
     register_int_gauge_with_registry, Histogram, Registry,
 };
+use std::collections::BTreeMap;
 use std::net::SocketAddr;
 use std::ops::Deref;
 use std::path::Path;
@@ -34,8 +37,9 @@ use sui_types::base_types::TransactionDigest;
 use sui_types::error::{SuiError, SuiResult};
 use sui_types::quorum_driver_types::{
     ExecuteTransactionRequestType, ExecuteTransactionRequestV3, ExecuteTransactionResponseV3,
-    FinalizedEffects, IsTransactionExecutedLocally, QuorumDriverEffectsQueueResult,
-    QuorumDriverError, QuorumDriverResponse, QuorumDriverResult,
+    FinalizedEffects, IsTransactionExecutedLocally, PendingTransactionInfo,
+    PendingTransactionState, QuorumDriverEffectsQueueResult, QuorumDriverError,
+    QuorumDriverResponse, QuorumDriverResult, TransactionPriority,
 };
 use sui_types::sui_system_state::SuiSystemState;
 use sui_types::transaction::{TransactionData, VerifiedTransaction};
@@ -68,6 +72,7 @@ impl TransactiondOrchestrator<NetworkAuthorityClient> {
         reconfig_channel: Receiver<SuiSystemState>,
         parent_path: &Path,
         prometheus_registry: &Registry,
+        lane_configs: &BTreeMap<TransactionPriority, QuorumDriverLaneConfig>,
     ) -> Self {
         let observer = OnsiteReconfigObserver::new(
             reconfig_channel,
@@ -82,6 +87,7 @@ impl TransactiondOrchestrator<NetworkAuthorityClient> {
             parent_path,
             prometheus_registry,
             observer,
+            lane_configs,
         )
     }
 }
@@ -97,6 +103,7 @@ where
         parent_path: &Path,
         prometheus_registry: &Registry,
         reconfig_observer: OnsiteReconfigObserver,
+        lane_configs: &BTreeMap<TransactionPriority, QuorumDriverLaneConfig>,
     ) -> Self {
         let notifier = Arc::new(NotifyRead::new());
         let quorum_driver_handler = Arc::new(
@@ -106,6 +113,7 @@ where
             )
             .with_notifier(notifier.clone())
             .with_reconfig_observer(Arc::new(reconfig_observer))
+            .with_lane_configs(lane_configs)
             .start(),
         );
 
@@ -140,6 +148,7 @@ where
     fields(
         tx_digest = ?request.transaction.digest(),
         tx_type = ?request_type,
+        priority = ?request.priority,
     ),
     err)]
     pub async fn execute_transaction_block(
@@ -193,7 +202,7 @@ where
 
     // Utilize the handle_certificate_v3 validator api to request input/output objects
     #[instrument(name = "tx_orchestrator_execute_transaction_v3", level = "trace", skip_all,
-                 fields(tx_digest = ?request.transaction.digest()))]
+                 fields(tx_digest = ?request.transaction.digest(), priority = ?request.priority))]
     pub async fn execute_transaction_v3(
         &self,
         request: ExecuteTransactionRequestV3,
@@ -508,6 +517,7 @@ where
                             include_input_objects: false,
                             include_output_objects: false,
                             include_auxiliary_data: false,
+                            priority: TransactionPriority::default(),
                         },
                         None,
                     )
@@ -532,6 +542,27 @@ where
     pub fn load_all_pending_transactions(&self) -> Vec<VerifiedTransaction> {
         self.pending_tx_log.load_all_pending_transactions()
     }
+
+    /// Returns the submission status of a transaction recently sent through this orchestrator.
+    /// Finalized transactions are reported as executed locally once this node has executed them.
+    pub fn get_pending_transaction(
+        &self,
+        digest: &TransactionDigest,
+    ) -> Option<PendingTransactionInfo> {
+        let mut info = self
+            .quorum_driver_handler
+            .pending_transactions()
+            .get(digest)?;
+        if info.state == PendingTransactionState::Finalized
+            && self
+                .validator_state
+                .is_tx_already_executed(digest)
+                .unwrap_or(false)
+        {
+            info.state = PendingTransactionState::ExecutedLocally;
+        }
+        Some(info)
+    }
 }
 
 /// Prometheus metrics which can be displayed in Grafana, queried and alerted on
@@ -718,4 +749,11 @@ where
     ) -> Result<SimulateTransactionResult, SuiError> {
         self.validator_state.simulate_transaction(transaction)
     }
+
+    fn get_pending_transaction(
+        &self,
+        digest: &TransactionDigest,
+    ) -> Option<PendingTransactionInfo> {
+        TransactiondOrchestrator::get_pending_transaction(self, digest)
+    }
 }
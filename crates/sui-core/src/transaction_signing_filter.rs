@@ -1,6 +1,294 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use std::fs::{File, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::Context;
+use arc_swap::ArcSwap;
+use parking_lot::Mutex;
+use prometheus::{
+    register_int_counter_vec_with_registry, register_int_counter_with_registry,
+    register_int_gauge_vec_with_registry, IntCounter, IntCounterVec, IntGaugeVec, Registry,
+};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sui_config::transaction_deny_config::{
+    TransactionDenyConfig, TransactionDenyConfigUpdate, TransactionDenySwitch,
+};
+use tracing::{error, info, warn};
+
+use crate::authority::AuthorityState;
+
+/// Name of the audit log file, relative to the node's DB directory.
+pub const TRANSACTION_DENY_AUDIT_LOG: &str = "transaction_deny_audit.log";
+
+/// The deny config that transactions are checked against before they are signed. It starts out as
+/// the `transaction-deny-config` from the node config, and can then be changed at runtime. Each
+/// batch of changes is appended to a local audit log, and then swapped in as a whole, so that a
+/// transaction is always checked against exactly one version of the config. The audit log is
+/// replayed on top of the node config when the node starts, so changes survive restarts until the
+/// log is removed.
+pub struct TransactionSigningFilter {
+    config: ArcSwap<TransactionDenyConfig>,
+    /// Append-only record of changes. The lock also serializes updates, so that concurrent changes
+    /// are neither lost nor logged out of order.
+    audit_log: Mutex<AuditLog>,
+    metrics: TransactionSigningFilterMetrics,
+}
+
+/// An append-only log of runtime config changes, opened on first use.
+pub(crate) struct AuditLog {
+    path: PathBuf,
+    file: Option<File>,
+}
+
+/// A line in the audit log.
+#[derive(Serialize)]
+pub(crate) struct AuditRecord<'a, U> {
+    pub timestamp_ms: u64,
+    pub updates: &'a [U],
+    /// Whether the updates changed the config. Updates that were already in effect are still
+    /// recorded.
+    pub changed: bool,
+}
+
+/// The part of a line in the audit log that is needed to replay it.
+#[derive(Deserialize)]
+struct ReplayedRecord<U> {
+    updates: Vec<U>,
+}
+
+struct TransactionSigningFilterMetrics {
+    updates: IntCounterVec,
+    deny_list_size: IntGaugeVec,
+    switch_disabled: IntGaugeVec,
+    audit_log_errors: IntCounter,
+}
+
+impl TransactionSigningFilter {
+    /// Start from `config`, with the changes recorded in the audit log at `audit_log_path`
+    /// applied on top. Fails if the audit log exists but cannot be read back.
+    pub fn new(
+        mut config: TransactionDenyConfig,
+        audit_log_path: PathBuf,
+        registry: &Registry,
+    ) -> anyhow::Result<Self> {
+        let updates: Vec<TransactionDenyConfigUpdate> = AuditLog::replay(&audit_log_path)?;
+        for update in &updates {
+            config.apply(update);
+        }
+
+        if !updates.is_empty() {
+            info!(
+                "Replayed {} transaction deny config updates from {}",
+                updates.len(),
+                audit_log_path.display()
+            );
+        }
+
+        let metrics = TransactionSigningFilterMetrics::new(registry);
+        metrics.observe(&config);
+
+        Ok(Self {
+            config: ArcSwap::new(Arc::new(config)),
+            audit_log: Mutex::new(AuditLog::new(audit_log_path)),
+            metrics,
+        })
+    }
+
+    /// The config to check a transaction against. Hold on to the returned config for the duration
+    /// of the checks, rather than loading it again, so that the transaction sees a consistent
+    /// config even if it is updated concurrently.
+    pub fn load(&self) -> Arc<TransactionDenyConfig> {
+        self.config.load_full()
+    }
+
+    /// Apply `updates` to the config as a single change. The change is recorded in the audit log
+    /// before it takes effect, and if it cannot be recorded, it is not applied. Returns whether the
+    /// config changed.
+    pub fn update(&self, updates: &[TransactionDenyConfigUpdate]) -> anyhow::Result<bool> {
+        let mut audit_log = self.audit_log.lock();
+
+        let mut config = TransactionDenyConfig::clone(&self.config.load());
+        let mut changed = false;
+        for update in updates {
+            changed |= config.apply(update);
+        }
+
+        let record = AuditRecord {
+            timestamp_ms: AuthorityState::unixtime_now_ms(),
+            updates,
+            changed,
+        };
+
+        if let Err(e) = audit_log.append(&record) {
+            self.metrics.audit_log_errors.inc();
+            error!(
+                "Failed to record transaction deny config update in {}: {e}",
+                audit_log.path().display()
+            );
+            return Err(e);
+        }
+
+        for update in updates {
+            self.metrics
+                .updates
+                .with_label_values(&[update.kind()])
+                .inc();
+        }
+
+        if changed {
+            info!(?updates, "Transaction deny config updated");
+            self.metrics.observe(&config);
+            self.config.store(Arc::new(config));
+        }
+
+        Ok(changed)
+    }
+
+    pub fn audit_log_path(&self) -> PathBuf {
+        self.audit_log.lock().path().to_path_buf()
+    }
+}
+
+impl AuditLog {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path, file: None }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The updates recorded in the log at `path`, in the order they were made, or none if there
+    /// is no log yet. A record that was only partly written, because the node stopped while
+    /// appending it, never took effect, so it is dropped from the end of the log.
+    pub fn replay<U: DeserializeOwned>(path: &Path) -> anyhow::Result<Vec<U>> {
+        let contents = match std::fs::read(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => {
+                return Err(e).with_context(|| format!("Failed to read {}", path.display()));
+            }
+        };
+
+        let complete = contents
+            .iter()
+            .rposition(|b| *b == b'\n')
+            .map_or(0, |i| i + 1);
+
+        if complete < contents.len() {
+            warn!(
+                "Dropping partly written record from the end of {}",
+                path.display()
+            );
+            OpenOptions::new()
+                .write(true)
+                .open(path)?
+                .set_len(complete as u64)?;
+        }
+
+        let mut updates = vec![];
+        for (i, line) in contents[..complete].split(|b| *b == b'\n').enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+
+            let record: ReplayedRecord<U> = serde_json::from_slice(line).with_context(|| {
+                format!("Invalid record on line {} of {}", i + 1, path.display())
+            })?;
+            updates.extend(record.updates);
+        }
+
+        Ok(updates)
+    }
+
+    pub fn append<U: Serialize>(&mut self, record: &AuditRecord<'_, U>) -> anyhow::Result<()> {
+        if self.file.is_none() {
+            self.file = Some(open_append(&self.path)?);
+        }
+
+        let file = self.file.as_mut().expect("Audit log opened above");
+
+        let mut line = serde_json::to_vec(record)?;
+        line.push(b'\n');
+        file.write_all(&line)?;
+        file.sync_data()?;
+        Ok(())
+    }
+}
+
+fn open_append(path: &Path) -> std::io::Result<File> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+impl TransactionSigningFilterMetrics {
+    fn new(registry: &Registry) -> Self {
+        Self {
+            updates: register_int_counter_vec_with_registry!(
+                "transaction_deny_config_updates",
+                "Number of runtime updates made to the transaction deny config, by kind",
+                &["kind"],
+                registry,
+            )
+            .unwrap(),
+            deny_list_size: register_int_gauge_vec_with_registry!(
+                "transaction_deny_config_list_size",
+                "Number of entries in each of the transaction deny config's lists",
+                &["list"],
+                registry,
+            )
+            .unwrap(),
+            switch_disabled: register_int_gauge_vec_with_registry!(
+                "transaction_deny_config_switch_disabled",
+                "Whether the feature behind each transaction deny config kill switch is disabled",
+                &["switch"],
+                registry,
+            )
+            .unwrap(),
+            audit_log_errors: register_int_counter_with_registry!(
+                "transaction_deny_config_audit_log_errors",
+                "Number of transaction deny config updates rejected because they could not be \
+                 written to the audit log",
+                registry,
+            )
+            .unwrap(),
+        }
+    }
+
+    fn observe(&self, config: &TransactionDenyConfig) {
+        let lists = [
+            ("object", config.get_object_deny_set().len()),
+            ("package", config.get_package_deny_set().len()),
+            ("package-rule", config.package_deny_rules().len()),
+            ("address", config.get_address_deny_set().len()),
+            (
+                "zklogin-provider",
+                config.zklogin_disabled_providers().len(),
+            ),
+        ];
+
+        for (list, size) in lists {
+            self.deny_list_size
+                .with_label_values(&[list])
+                .set(size as i64);
+        }
+
+        for switch in TransactionDenySwitch::ALL {
+            self.switch_disabled
+                .with_label_values(&[switch.as_str()])
+                .set(config.switch(switch) as i64);
+        }
+    }
+}
+
 #[cfg(test)]
 #[path = "unit_tests/transaction_deny_tests.rs"]
 mod transaction_deny_tests;
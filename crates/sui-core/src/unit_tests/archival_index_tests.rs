@@ -0,0 +1,249 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use sui_protocol_config::{ProtocolConfig, ProtocolVersion};
+use sui_test_transaction_builder::TestTransactionBuilder;
+use sui_types::base_types::SuiAddress;
+use sui_types::committee::Committee;
+use sui_types::crypto::{get_key_pair, AccountKeyPair, AuthorityKeyPair};
+use sui_types::effects::{TestEffectsBuilder, TransactionEffectsAPI};
+use sui_types::full_checkpoint_content::CheckpointTransaction;
+use sui_types::gas::GasCostSummary;
+use sui_types::messages_checkpoint::{
+    CertifiedCheckpointSummary, CheckpointContents, CheckpointSummary, EndOfEpochData,
+};
+
+struct TestChain {
+    index: ArchivalIndexStore,
+    committee: Committee,
+    keys: Vec<AuthorityKeyPair>,
+    sender: SuiAddress,
+    sender_key: AccountKeyPair,
+    next_checkpoint: CheckpointSequenceNumber,
+    epoch: EpochId,
+}
+
+impl TestChain {
+    fn new() -> Self {
+        let (committee, keys) = Committee::new_simple_test_committee_of_size(1);
+        let (sender, sender_key) = get_key_pair();
+        Self {
+            index: ArchivalIndexStore::new(tempfile::tempdir().unwrap().into_path()),
+            committee,
+            keys,
+            sender,
+            sender_key,
+            next_checkpoint: 0,
+            epoch: 0,
+        }
+    }
+
+    fn object(&self, version: u64) -> Object {
+        Object::with_id_owner_version_for_testing(
+            ObjectID::random(),
+            SequenceNumber::from_u64(version),
+            self.sender,
+        )
+    }
+
+    /// A transaction that uses `object` as its gas, writing a new version of it.
+    fn mutate(&self, object: &Object) -> (CheckpointTransaction, Object) {
+        let transaction =
+            TestTransactionBuilder::new(self.sender, object.compute_object_reference(), 1)
+                .transfer_sui(None, self.sender)
+                .build_and_sign(&self.sender_key);
+        let effects = TestEffectsBuilder::new(transaction.data()).build();
+        let output = Object::with_id_owner_version_for_testing(
+            object.id(),
+            effects.lamport_version(),
+            self.sender,
+        );
+
+        let transaction = CheckpointTransaction {
+            transaction,
+            effects,
+            events: None,
+            input_objects: vec![object.clone()],
+            output_objects: vec![output.clone()],
+        };
+        (transaction, output)
+    }
+
+    /// Index the next checkpoint, made up of `transactions`.
+    fn index(&mut self, transactions: Vec<CheckpointTransaction>, end_of_epoch: bool) {
+        let checkpoint = self.checkpoint(transactions, end_of_epoch);
+        self.index.index_checkpoint(&checkpoint).unwrap();
+    }
+
+    /// Build the next checkpoint, made up of `transactions`, without indexing it.
+    fn checkpoint(
+        &mut self,
+        transactions: Vec<CheckpointTransaction>,
+        end_of_epoch: bool,
+    ) -> CheckpointData {
+        let end_of_epoch_data = end_of_epoch.then(|| EndOfEpochData {
+            next_epoch_committee: vec![],
+            next_epoch_protocol_version: ProtocolVersion::MAX,
+            epoch_commitments: vec![],
+        });
+        let contents = CheckpointContents::new_with_digests_only_for_tests(vec![]);
+        let summary = CheckpointSummary::new(
+            &ProtocolConfig::get_for_max_version_UNSAFE(),
+            self.epoch,
+            self.next_checkpoint,
+            0,
+            &contents,
+            None,
+            GasCostSummary::default(),
+            end_of_epoch_data,
+            0,
+            vec![],
+        );
+
+        let checkpoint = CheckpointData {
+            checkpoint_summary: CertifiedCheckpointSummary::new_from_keypairs_for_testing(
+                summary,
+                &self.keys,
+                &self.committee,
+            ),
+            checkpoint_contents: contents,
+            transactions,
+        };
+
+        self.next_checkpoint += 1;
+        if end_of_epoch {
+            self.epoch += 1;
+        }
+        checkpoint
+    }
+
+    fn version_as_of(&self, object: &Object, checkpoint: CheckpointSequenceNumber) -> Option<u64> {
+        match self
+            .index
+            .get_object_as_of_checkpoint(object.id(), checkpoint)
+            .unwrap()
+        {
+            ArchivedObjectRead::Exists(object) => Some(object.version().value()),
+            ArchivedObjectRead::NotIndexed => None,
+            read => panic!("unexpected read {read:?}"),
+        }
+    }
+}
+
+#[test]
+fn index_and_prune() {
+    let mut chain = TestChain::new();
+    let a1 = chain.object(1);
+    let b1 = chain.object(1);
+    let untouched = chain.object(1);
+
+    // Epoch 0: checkpoints 0 to 2.
+    chain.index(vec![], false);
+    let (tx, a2) = chain.mutate(&a1);
+    chain.index(vec![tx], false);
+    let (tx, a3) = chain.mutate(&a2);
+    chain.index(vec![tx], true);
+
+    // Epoch 1: checkpoints 3 and 4.
+    let (tx, b2) = chain.mutate(&b1);
+    chain.index(vec![tx], false);
+    chain.index(vec![], true);
+
+    // Epoch 2: checkpoint 5.
+    let (tx, a4) = chain.mutate(&a3);
+    chain.index(vec![tx], false);
+
+    assert_eq!(chain.index.available_checkpoints().unwrap(), Some((0, 5)));
+
+    // Objects modified within the window can be read as of any checkpoint in it, including
+    // checkpoints from before they were first modified.
+    assert_eq!(chain.version_as_of(&a1, 0), Some(1));
+    assert_eq!(chain.version_as_of(&a1, 1), Some(a2.version().value()));
+    assert_eq!(chain.version_as_of(&a1, 4), Some(a3.version().value()));
+    assert_eq!(chain.version_as_of(&a1, 5), Some(a4.version().value()));
+    assert_eq!(chain.version_as_of(&b1, 2), Some(1));
+    assert_eq!(chain.version_as_of(&b1, 3), Some(b2.version().value()));
+    assert_eq!(chain.version_as_of(&untouched, 5), None);
+    assert!(chain
+        .index
+        .get_object(a1.id(), a1.version())
+        .unwrap()
+        .is_some());
+
+    // Retaining one epoch before the current one moves the window to the start of epoch 1.
+    chain.index.prune(1).unwrap();
+    assert_eq!(chain.index.available_checkpoints().unwrap(), Some((3, 5)));
+
+    assert_eq!(chain.version_as_of(&a1, 3), Some(a3.version().value()));
+    assert_eq!(chain.version_as_of(&a1, 5), Some(a4.version().value()));
+    assert_eq!(chain.version_as_of(&b1, 3), Some(b2.version().value()));
+    assert!(chain
+        .index
+        .get_object(a1.id(), a1.version())
+        .unwrap()
+        .is_none());
+    assert!(chain
+        .index
+        .get_object(a2.id(), a2.version())
+        .unwrap()
+        .is_none());
+    assert!(chain
+        .index
+        .get_object(a3.id(), a3.version())
+        .unwrap()
+        .is_some());
+
+    // Retaining only the current epoch leaves just its first checkpoint. B has not been modified
+    // since checkpoint 3, so its latest version is served from the live store instead.
+    chain.index.prune(0).unwrap();
+    assert_eq!(chain.index.available_checkpoints().unwrap(), Some((5, 5)));
+
+    assert_eq!(chain.version_as_of(&a1, 5), Some(a4.version().value()));
+    assert_eq!(chain.version_as_of(&b1, 5), None);
+    assert!(chain
+        .index
+        .get_object(a3.id(), a3.version())
+        .unwrap()
+        .is_none());
+    assert!(chain
+        .index
+        .get_object(b2.id(), b2.version())
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn index_out_of_order() {
+    let mut chain = TestChain::new();
+    let a1 = chain.object(1);
+
+    let checkpoint_0 = chain.checkpoint(vec![], false);
+    let (tx, a2) = chain.mutate(&a1);
+    let checkpoint_1 = chain.checkpoint(vec![tx], false);
+    let (tx, a3) = chain.mutate(&a2);
+    let checkpoint_2 = chain.checkpoint(vec![tx], false);
+
+    chain.index.index_checkpoint(&checkpoint_0).unwrap();
+
+    // A checkpoint that skips ahead is rejected, rather than leaving a gap in the index.
+    chain.index.index_checkpoint(&checkpoint_2).unwrap_err();
+    assert_eq!(chain.index.available_checkpoints().unwrap(), Some((0, 0)));
+    assert!(chain
+        .index
+        .get_object(a3.id(), a3.version())
+        .unwrap()
+        .is_none());
+
+    chain.index.index_checkpoint(&checkpoint_1).unwrap();
+    chain.index.index_checkpoint(&checkpoint_2).unwrap();
+    assert_eq!(chain.index.available_checkpoints().unwrap(), Some((0, 2)));
+
+    // Indexing a checkpoint again, as after a restart, leaves the index unchanged.
+    chain.index.index_checkpoint(&checkpoint_1).unwrap();
+    assert_eq!(chain.index.available_checkpoints().unwrap(), Some((0, 2)));
+
+    assert_eq!(chain.version_as_of(&a1, 0), Some(1));
+    assert_eq!(chain.version_as_of(&a1, 1), Some(a2.version().value()));
+    assert_eq!(chain.version_as_of(&a1, 2), Some(a3.version().value()));
+}
@@ -40,7 +40,8 @@ use sui_types::execution::SharedInput;
 use sui_types::execution_status::{ExecutionFailureStatus, ExecutionStatus};
 use sui_types::gas_coin::GasCoin;
 use sui_types::messages_consensus::{
-    AuthorityCapabilitiesV2, ConsensusDeterminedVersionAssignments,
+    AuthorityCapabilitiesV2, ConsensusDeterminedVersionAssignments, ConsensusTransaction,
+    ExecutionCostObservation,
 };
 use sui_types::object::Data;
 use sui_types::programmable_transaction_builder::ProgrammableTransactionBuilder;
@@ -64,6 +65,8 @@ use crate::authority::authority_store_tables::AuthorityPerpetualTables;
 use crate::authority::move_integration_tests::build_and_publish_test_package_with_upgrade_cap;
 use crate::authority::test_authority_builder::TestAuthorityBuilder;
 use crate::authority::transaction_deferral::DeferralKey;
+use crate::checkpoints::CheckpointServiceNoop;
+use crate::consensus_handler::SequencedConsensusTransaction;
 use crate::transaction_input_loader::TransactionInputLoader;
 use crate::{
     authority_client::{AuthorityAPI, NetworkAuthorityClient},
@@ -5863,7 +5866,8 @@ async fn test_consensus_handler_per_object_congestion_control(
     let shared_objects = create_shared_objects(2);
 
     let non_congested_tx_count = match mode {
-        PerObjectCongestionControlMode::None => unreachable!(),
+        PerObjectCongestionControlMode::None
+        | PerObjectCongestionControlMode::ObservedExecutionCost => unreachable!(),
         PerObjectCongestionControlMode::TotalGasBudget => 5,
         PerObjectCongestionControlMode::TotalTxCount => 2,
     };
@@ -5876,7 +5880,8 @@ async fn test_consensus_handler_per_object_congestion_control(
     protocol_config.set_per_object_congestion_control_mode_for_testing(mode);
 
     match mode {
-        PerObjectCongestionControlMode::None => unreachable!(),
+        PerObjectCongestionControlMode::None
+        | PerObjectCongestionControlMode::ObservedExecutionCost => unreachable!(),
         PerObjectCongestionControlMode::TotalGasBudget => {
             protocol_config
                 .set_max_accumulated_txn_cost_per_object_in_narwhal_commit_for_testing(200_000_000);
@@ -6082,6 +6087,156 @@ async fn test_consensus_handler_per_object_congestion_control_using_tx_count() {
     .await;
 }
 
+// Tests that under ObservedExecutionCost congestion control, validators that are at different
+// points in execution make the same deferral decisions, as cost estimates only change when
+// execution cost observations are sequenced by consensus.
+#[sim_test]
+async fn test_consensus_handler_observed_execution_cost_independent_of_execution_lag() {
+    let (sender, keypair): (_, AccountKeyPair) = get_key_pair();
+    let shared_objects = create_shared_objects(1);
+    let gas_objects = create_gas_objects(10, sender);
+
+    // Until costs are observed, transactions are costed at their gas budget, so only 2 fit in a
+    // commit.
+    let mut protocol_config =
+        ProtocolConfig::get_for_version(ProtocolVersion::max(), Chain::Unknown);
+    protocol_config.set_per_object_congestion_control_mode_for_testing(
+        PerObjectCongestionControlMode::ObservedExecutionCost,
+    );
+    protocol_config
+        .set_max_accumulated_txn_cost_per_object_in_narwhal_commit_for_testing(200_000_000);
+    protocol_config
+        .set_max_accumulated_txn_cost_per_object_in_mysticeti_commit_for_testing(200_000_000);
+    protocol_config.set_observed_execution_cost_ewma_weight_bps_for_testing(5_000);
+    protocol_config.set_max_deferral_rounds_for_congestion_control_for_testing(1000);
+
+    let mut genesis_objects = gas_objects.clone();
+    genesis_objects.extend(shared_objects.clone());
+    let network_config =
+        sui_swarm_config::network_config_builder::ConfigBuilder::new_with_temp_dir()
+            .committee_size(4.try_into().unwrap())
+            .with_reference_gas_price(1000)
+            .with_objects(genesis_objects)
+            .build();
+    let mut authorities = vec![];
+    for idx in 0..4 {
+        authorities.push(
+            TestAuthorityBuilder::new()
+                .with_network_config(&network_config, idx)
+                .with_protocol_config(protocol_config.clone())
+                .build()
+                .await,
+        );
+    }
+    let signers: Vec<_> = authorities
+        .iter()
+        .map(|authority| authority.as_ref())
+        .collect();
+    // `validator` executes the transactions it schedules, `lagging_validator` executes none.
+    let validator = &authorities[0];
+    let lagging_validator = &authorities[1];
+
+    let mut certificates = vec![];
+    for gas_object in gas_objects.iter() {
+        let certificate = make_test_transaction(
+            &sender,
+            &keypair,
+            &[],
+            &[(shared_objects[0].id(), OBJECT_START_VERSION, true)],
+            &gas_object.compute_object_reference(),
+            &signers,
+            12345,
+            Some(1000),
+            Some(100_000_000),
+        )
+        .await;
+        certificates.push(certificate);
+    }
+    let certificate_messages = |certificates: &[VerifiedCertificate]| -> Vec<_> {
+        certificates
+            .iter()
+            .map(|cert| {
+                SequencedConsensusTransaction::new_test(
+                    ConsensusTransaction::new_certificate_message(
+                        &validator.name,
+                        cert.clone().into_inner(),
+                    ),
+                )
+            })
+            .collect()
+    };
+
+    // Has both validators process the same commit, and returns the digests they scheduled.
+    let process_commit = |transactions: Vec<SequencedConsensusTransaction>| async move {
+        let mut scheduled = vec![];
+        for authority in [validator, lagging_validator] {
+            let txns = authority
+                .epoch_store_for_testing()
+                .process_consensus_transactions_for_tests(
+                    transactions.clone(),
+                    &Arc::new(CheckpointServiceNoop {}),
+                    authority.get_object_cache_reader().as_ref(),
+                    &authority.metrics,
+                    true,
+                )
+                .await
+                .unwrap();
+            scheduled.push(txns.iter().map(|txn| *txn.digest()).collect::<Vec<_>>());
+        }
+        assert_eq!(scheduled[0], scheduled[1]);
+        scheduled.pop().unwrap()
+    };
+
+    // Commit 1: 2 of the first 5 transactions are scheduled, and executed by one validator only.
+    let scheduled = process_commit(certificate_messages(&certificates[..5])).await;
+    assert_eq!(scheduled.len(), 2);
+    for cert in certificates.iter() {
+        if scheduled.contains(cert.digest()) {
+            execute_sequenced_certificate_to_effects(validator, cert.clone())
+                .await
+                .unwrap();
+        }
+    }
+    let object_costs = validator
+        .epoch_store_for_testing()
+        .take_unreported_execution_costs();
+    assert_eq!(object_costs.len(), 1);
+    assert!(lagging_validator
+        .epoch_store_for_testing()
+        .take_unreported_execution_costs()
+        .is_empty());
+
+    // Commit 2: validators with a quorum of stake report the cost they observed. The commit is
+    // still costed with the earlier estimates, so only 2 more transactions are scheduled.
+    let mut transactions: Vec<_> = [0, 2, 3]
+        .into_iter()
+        .map(|idx| {
+            let authority = authorities[idx].name;
+            let mut transaction = SequencedConsensusTransaction::new_test(
+                ConsensusTransaction::new_execution_cost_observation(
+                    ExecutionCostObservation::new(authority, object_costs.clone()),
+                ),
+            );
+            transaction.certificate_author = authority;
+            transaction
+        })
+        .collect();
+    transactions.extend(certificate_messages(&certificates[5..]));
+    let scheduled = process_commit(transactions).await;
+    assert_eq!(scheduled.len(), 2);
+
+    // Commit 3: with the observed cost, all the remaining transactions fit, on both validators.
+    let scheduled = process_commit(vec![]).await;
+    assert_eq!(scheduled.len(), 6);
+    for authority in [validator, lagging_validator] {
+        assert!(authority
+            .epoch_store_for_testing()
+            .get_all_deferred_transactions_for_test()
+            .unwrap()
+            .is_empty());
+    }
+}
+
 // Tests congestion control triggered transaction cancellation in consensus handler:
 //   1. Consensus handler cancels transactions that are deferred for too many rounds.
 //   2. Shared locks for cancelled transaction are set correctly.
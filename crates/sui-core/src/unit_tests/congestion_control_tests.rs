@@ -3,8 +3,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::authority::shared_object_congestion_tracker::SharedObjectCongestionTracker;
+use crate::authority::transaction_deferral::DeferralKey;
 use crate::{
     authority::{
+        authority_test_utils::send_consensus_no_execution,
         authority_tests::{
             build_programmable_transaction, certify_shared_obj_transaction_no_execution,
             execute_programmable_transaction, send_and_confirm_transaction_,
@@ -16,6 +18,7 @@ use crate::{
     move_call,
 };
 use move_core_types::ident_str;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use sui_macros::{register_fail_point_arg, sim_test};
 use sui_protocol_config::{Chain, PerObjectCongestionControlMode, ProtocolConfig, ProtocolVersion};
@@ -198,9 +201,8 @@ impl TestSetup {
     }
 }
 
-// Creates a transaction that touchs the shared objects `shared_object_1` and `shared_object_2`, and `owned_object`,
-// and executes the transaction in `authority_state`. Returns the transaction and the effects of the execution.
-async fn update_objects(
+// Creates a transaction that touchs the shared objects `shared_object_1` and `shared_object_2`, and `owned_object`.
+async fn build_update_objects_transaction(
     authority_state: &AuthorityState,
     package: &ObjectRef,
     sender: &SuiAddress,
@@ -209,7 +211,7 @@ async fn update_objects(
     shared_object_1: &(ObjectID, SequenceNumber),
     shared_object_2: &(ObjectID, SequenceNumber),
     owned_object: &ObjectRef,
-) -> (Transaction, TransactionEffects) {
+) -> Transaction {
     let mut txn_builder = ProgrammableTransactionBuilder::new();
     let arg1 = txn_builder
         .obj(ObjectArg::SharedObject {
@@ -233,7 +235,7 @@ async fn update_objects(
         (package.0)::congestion_control::increment(arg1, arg2, arg3)
     };
     let pt = txn_builder.finish();
-    let transaction = build_programmable_transaction(
+    build_programmable_transaction(
         authority_state,
         gas_object_id,
         sender,
@@ -242,7 +244,32 @@ async fn update_objects(
         TEST_ONLY_GAS_UNIT,
     )
     .await
-    .unwrap();
+    .unwrap()
+}
+
+// Creates a transaction that touchs the shared objects `shared_object_1` and `shared_object_2`, and `owned_object`,
+// and executes the transaction in `authority_state`. Returns the transaction and the effects of the execution.
+async fn update_objects(
+    authority_state: &AuthorityState,
+    package: &ObjectRef,
+    sender: &SuiAddress,
+    sender_key: &AccountKeyPair,
+    gas_object_id: &ObjectID,
+    shared_object_1: &(ObjectID, SequenceNumber),
+    shared_object_2: &(ObjectID, SequenceNumber),
+    owned_object: &ObjectRef,
+) -> (Transaction, TransactionEffects) {
+    let transaction = build_update_objects_transaction(
+        authority_state,
+        package,
+        sender,
+        sender_key,
+        gas_object_id,
+        shared_object_1,
+        shared_object_2,
+        owned_object,
+    )
+    .await;
 
     let execution_effects =
         send_and_confirm_transaction_(authority_state, None, transaction.clone(), true)
@@ -367,3 +394,129 @@ async fn test_congestion_control_execution_cancellation() {
     );
     assert_eq!(&effects, effects_2.data())
 }
+
+// Tests the shared object congestion and deferred transactions that the admin server dumps, after a
+// transaction is deferred due to shared object congestion.
+#[sim_test]
+async fn test_congestion_and_deferral_snapshots() {
+    telemetry_subscribers::init_for_testing();
+
+    let test_setup = TestSetup::new().await;
+    let shared_object_1 = test_setup.create_shared_object().await;
+    let shared_object_2 = test_setup.create_shared_object().await;
+    let owned_object = test_setup.create_owned_object().await;
+
+    let genesis_objects = test_setup
+        .create_genesis_objects_for_new_authority_state(&[
+            shared_object_1.0,
+            shared_object_2.0,
+            owned_object.0,
+        ])
+        .await;
+
+    // Allow congested transactions to be deferred, instead of cancelled.
+    let mut protocol_config = test_setup.protocol_config.clone();
+    protocol_config.set_max_deferral_rounds_for_congestion_control_for_testing(10);
+    let authority_state = TestAuthorityBuilder::new()
+        .with_reference_gas_price(TEST_ONLY_GAS_PRICE)
+        .with_protocol_config(protocol_config)
+        .build()
+        .await;
+    authority_state
+        .insert_genesis_objects(&genesis_objects)
+        .await;
+
+    // Nothing has been sequenced yet.
+    let epoch_store = authority_state.epoch_store_for_testing();
+    assert!(epoch_store.last_commit_congestion().is_none());
+    assert!(epoch_store
+        .deferred_transactions_summary()
+        .unwrap()
+        .is_empty());
+
+    // Initialize shared object queue so that any transaction touches shared_object_1 should result in congestion.
+    register_fail_point_arg("initial_congestion_tracker", move || {
+        Some(
+            SharedObjectCongestionTracker::new_with_initial_value_for_test(
+                &[(shared_object_1.0, 10)],
+                PerObjectCongestionControlMode::TotalGasBudget,
+            ),
+        )
+    });
+
+    let transaction = build_update_objects_transaction(
+        &authority_state,
+        &test_setup.package,
+        &test_setup.sender,
+        &test_setup.sender_key,
+        &test_setup.gas_object_id,
+        &(shared_object_1.0, shared_object_1.1),
+        &(shared_object_2.0, shared_object_2.1),
+        &authority_state
+            .get_object(&owned_object.0)
+            .await
+            .unwrap()
+            .unwrap()
+            .compute_object_reference(),
+    )
+    .await;
+    let cert = certify_shared_obj_transaction_no_execution(&authority_state, transaction)
+        .await
+        .unwrap();
+    send_consensus_no_execution(&authority_state, &cert).await;
+
+    // The deferred transaction adds nothing to the cost of the objects it touches.
+    let congestion = epoch_store.last_commit_congestion().unwrap();
+    assert_eq!(
+        congestion.mode,
+        PerObjectCongestionControlMode::TotalGasBudget
+    );
+    assert_eq!(
+        congestion.max_accumulated_txn_cost_per_object_in_commit,
+        Some(TEST_ONLY_GAS_PRICE * TEST_ONLY_GAS_UNIT)
+    );
+    assert_eq!(
+        congestion.object_execution_cost,
+        BTreeMap::from([(shared_object_1.0, 10)])
+    );
+    assert!(congestion.randomness_object_execution_cost.is_empty());
+
+    let deferred = epoch_store.deferred_transactions_summary().unwrap();
+    assert_eq!(deferred.len(), 1);
+    let DeferralKey::ConsensusRound {
+        future_round,
+        deferred_from_round,
+    } = deferred[0].deferral_key
+    else {
+        panic!("Unexpected deferral key {:?}", deferred[0].deferral_key);
+    };
+    assert_eq!(deferred_from_round, congestion.commit_round);
+    assert!(future_round > deferred_from_round);
+    assert_eq!(deferred[0].transactions, vec![*cert.digest()]);
+    assert_eq!(deferred[0].total, 1);
+
+    // This is the JSON served by the admin server.
+    assert_eq!(
+        serde_json::to_value(&congestion).unwrap(),
+        serde_json::json!({
+            "commit_round": congestion.commit_round,
+            "mode": "TotalGasBudget",
+            "max_accumulated_txn_cost_per_object_in_commit": TEST_ONLY_GAS_PRICE * TEST_ONLY_GAS_UNIT,
+            "object_execution_cost": { (shared_object_1.0.to_string()): 10 },
+            "randomness_object_execution_cost": {},
+        })
+    );
+    assert_eq!(
+        serde_json::to_value(&deferred).unwrap(),
+        serde_json::json!([{
+            "deferral_key": {
+                "ConsensusRound": {
+                    "future_round": future_round,
+                    "deferred_from_round": deferred_from_round,
+                },
+            },
+            "transactions": [cert.digest()],
+            "total": 1,
+        }])
+    );
+}
@@ -0,0 +1,349 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use move_core_types::annotated_value::MoveDatatypeLayout;
+use sui_protocol_config::ProtocolConfig;
+use sui_test_transaction_builder::TestTransactionBuilder;
+use sui_types::committee::Committee;
+use sui_types::crypto::{get_key_pair, AccountKeyPair};
+use sui_types::effects::TestEffectsBuilder;
+use sui_types::error::SuiError;
+use sui_types::event::Event;
+use sui_types::full_checkpoint_content::CheckpointTransaction;
+use sui_types::gas::GasCostSummary;
+use sui_types::gas_coin::GAS;
+use sui_types::messages_checkpoint::{CertifiedCheckpointSummary, CheckpointSummary};
+use sui_types::object::MoveObject;
+
+/// Resolver for tests that do not index any dynamic fields.
+struct NoLayouts;
+
+impl LayoutResolver for NoLayouts {
+    fn get_annotated_layout(
+        &mut self,
+        struct_tag: &StructTag,
+    ) -> Result<MoveDatatypeLayout, SuiError> {
+        Err(SuiError::ObjectSerializationError {
+            error: format!("No layout for {struct_tag}"),
+        })
+    }
+}
+
+struct TestChain {
+    index: RestIndexStore,
+    sender: SuiAddress,
+    sender_key: AccountKeyPair,
+    next_checkpoint: u64,
+}
+
+impl TestChain {
+    fn new() -> Self {
+        let (sender, sender_key) = get_key_pair();
+        Self {
+            index: RestIndexStore::new_without_init(tempfile::tempdir().unwrap().into_path()),
+            sender,
+            sender_key,
+            next_checkpoint: 0,
+        }
+    }
+
+    /// A SUI coin owned by the sender, worth `value`, as of a transaction before the index starts.
+    /// The balance index is seeded with it, as it would have been by indexing the live object set.
+    fn coin(&self, value: u64) -> Object {
+        let coin = Object::new_move(
+            MoveObject::new_gas_coin(SequenceNumber::from_u64(1), ObjectID::random(), value),
+            Owner::AddressOwner(self.sender),
+            TransactionDigest::genesis_marker(),
+        );
+
+        let key = BalanceKey {
+            owner: self.sender,
+            coin_type: GAS::type_(),
+        };
+        let mut info = self.balance(self.sender).unwrap_or_default();
+        info.balance += value;
+        info.coin_object_count += 1;
+        self.index.tables.balance.insert(&key, &info).unwrap();
+
+        coin
+    }
+
+    /// A transaction that uses `coin` as its gas, leaving it worth `value` and owned by `owner`.
+    /// It emits events of each of `event_types`, in order.
+    fn transaction(
+        &self,
+        coin: &Object,
+        value: u64,
+        owner: SuiAddress,
+        event_types: &[&StructTag],
+    ) -> (CheckpointTransaction, Object) {
+        let transaction =
+            TestTransactionBuilder::new(self.sender, coin.compute_object_reference(), 1)
+                .transfer_sui(None, owner)
+                .build_and_sign(&self.sender_key);
+
+        let events = TransactionEvents {
+            data: event_types
+                .iter()
+                .map(|type_| Event {
+                    type_: (*type_).clone(),
+                    ..Event::random_for_testing()
+                })
+                .collect(),
+        };
+
+        let mut effects = TestEffectsBuilder::new(transaction.data());
+        if !events.data.is_empty() {
+            effects = effects.with_events_digest(events.digest());
+        }
+        let effects = effects.build();
+
+        let output = Object::new_move(
+            MoveObject::new_gas_coin(effects.lamport_version(), coin.id(), value),
+            Owner::AddressOwner(owner),
+            *effects.transaction_digest(),
+        );
+
+        let transaction = CheckpointTransaction {
+            transaction,
+            effects,
+            events: (!events.data.is_empty()).then_some(events),
+            input_objects: vec![coin.clone()],
+            output_objects: vec![output.clone()],
+        };
+        (transaction, output)
+    }
+
+    /// Index the next checkpoint, made up of `transactions`, returning its contents.
+    fn index(&mut self, transactions: Vec<CheckpointTransaction>) -> CheckpointContents {
+        let (committee, keys) = Committee::new_simple_test_committee_of_size(1);
+        let contents = CheckpointContents::new_with_digests_only_for_tests(
+            transactions.iter().map(|tx| tx.effects.execution_digests()),
+        );
+        let summary = CheckpointSummary::new(
+            &ProtocolConfig::get_for_max_version_UNSAFE(),
+            0,
+            self.next_checkpoint,
+            0,
+            &contents,
+            None,
+            GasCostSummary::default(),
+            None,
+            0,
+            vec![],
+        );
+
+        let checkpoint = CheckpointData {
+            checkpoint_summary: CertifiedCheckpointSummary::new_from_keypairs_for_testing(
+                summary, &keys, &committee,
+            ),
+            checkpoint_contents: contents.clone(),
+            transactions,
+        };
+
+        self.index
+            .index_checkpoint(&checkpoint, &mut NoLayouts)
+            .unwrap();
+        self.next_checkpoint += 1;
+        contents
+    }
+
+    fn balance(&self, owner: SuiAddress) -> Option<BalanceIndexInfo> {
+        self.index.get_balance(owner, &GAS::type_()).unwrap()
+    }
+
+    /// The positions and transactions of events of type `event_type`, starting from `cursor`.
+    fn events(
+        &self,
+        event_type: &StructTag,
+        cursor: Option<(u64, u32, u32)>,
+    ) -> Vec<((u64, u32, u32), TransactionDigest)> {
+        self.index
+            .event_type_iter(event_type, cursor)
+            .unwrap()
+            .map(|(key, digest)| {
+                (
+                    (key.checkpoint, key.transaction_index, key.event_index),
+                    digest,
+                )
+            })
+            .collect()
+    }
+}
+
+fn struct_tag(name: &str) -> StructTag {
+    format!("0x42::test::{name}").parse().unwrap()
+}
+
+#[test]
+fn balance_index() {
+    let mut chain = TestChain::new();
+    let recipient = SuiAddress::random_for_testing_only();
+
+    let a = chain.coin(100);
+    let b = chain.coin(50);
+    assert_eq!(
+        chain.balance(chain.sender),
+        Some(BalanceIndexInfo {
+            balance: 150,
+            coin_object_count: 2,
+        })
+    );
+
+    // Spending from a coin changes the balance, but not the number of coins.
+    let (tx, a) = chain.transaction(&a, 70, chain.sender, &[]);
+    chain.index(vec![tx]);
+    assert_eq!(
+        chain.balance(chain.sender),
+        Some(BalanceIndexInfo {
+            balance: 120,
+            coin_object_count: 2,
+        })
+    );
+    assert_eq!(chain.balance(recipient), None);
+
+    // Transferring a coin moves its value (and the coin) to the recipient.
+    let (tx, _) = chain.transaction(&a, 70, recipient, &[]);
+    chain.index(vec![tx]);
+    assert_eq!(
+        chain.balance(chain.sender),
+        Some(BalanceIndexInfo {
+            balance: 50,
+            coin_object_count: 1,
+        })
+    );
+    assert_eq!(
+        chain.balance(recipient),
+        Some(BalanceIndexInfo {
+            balance: 70,
+            coin_object_count: 1,
+        })
+    );
+
+    // Once an address has no coins of a type left, its entry is removed.
+    let (tx, _) = chain.transaction(&b, 50, recipient, &[]);
+    chain.index(vec![tx]);
+    assert_eq!(chain.balance(chain.sender), None);
+
+    let balances: Vec<_> = chain.index.balance_iter(recipient, None).unwrap().collect();
+    assert_eq!(
+        balances,
+        vec![(
+            BalanceKey {
+                owner: recipient,
+                coin_type: GAS::type_(),
+            },
+            BalanceIndexInfo {
+                balance: 120,
+                coin_object_count: 2,
+            }
+        )]
+    );
+
+    // Iteration stops at the owner's last balance.
+    assert_eq!(
+        chain
+            .index
+            .balance_iter(chain.sender, None)
+            .unwrap()
+            .count(),
+        0
+    );
+}
+
+#[test]
+fn event_index() {
+    let mut chain = TestChain::new();
+    let t = struct_tag("T");
+    let u = struct_tag("U");
+
+    let a = chain.coin(100);
+    let b = chain.coin(100);
+
+    let (tx_a, _) = chain.transaction(&a, 100, chain.sender, &[&t, &u, &t]);
+    let (tx_b, b) = chain.transaction(&b, 100, chain.sender, &[]);
+    let digest_a = *tx_a.effects.transaction_digest();
+    let first = chain.index(vec![tx_b, tx_a]);
+
+    let (tx_c, _) = chain.transaction(&b, 100, chain.sender, &[&t]);
+    let digest_c = *tx_c.effects.transaction_digest();
+    chain.index(vec![tx_c]);
+
+    // Events are listed in the order they were emitted, and only for the requested type.
+    assert_eq!(
+        chain.events(&t, None),
+        vec![
+            ((0, 1, 0), digest_a),
+            ((0, 1, 2), digest_a),
+            ((1, 0, 0), digest_c),
+        ]
+    );
+    assert_eq!(chain.events(&u, None), vec![((0, 1, 1), digest_a)]);
+    assert_eq!(chain.events(&struct_tag("V"), None), vec![]);
+
+    // Cursors are inclusive.
+    assert_eq!(
+        chain.events(&t, Some((0, 1, 2))),
+        vec![((0, 1, 2), digest_a), ((1, 0, 0), digest_c)]
+    );
+
+    // Pruning a checkpoint removes the events of its transactions.
+    chain.index.prune(&[first]).unwrap();
+    assert_eq!(chain.events(&t, None), vec![((1, 0, 0), digest_c)]);
+    assert_eq!(chain.events(&u, None), vec![]);
+    assert!(chain
+        .index
+        .tables
+        .transaction_events
+        .get(&digest_a)
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn reindexing_checkpoint_is_skipped() {
+    let mut chain = TestChain::new();
+    let recipient = SuiAddress::random_for_testing_only();
+
+    let a = chain.coin(100);
+    let (tx, _) = chain.transaction(&a, 100, recipient, &[]);
+    chain.index(vec![tx.clone()]);
+
+    // A checkpoint that is executed again after a restart does not move its balances twice.
+    chain.next_checkpoint -= 1;
+    chain.index(vec![tx]);
+    assert_eq!(chain.balance(chain.sender), None);
+    assert_eq!(
+        chain.balance(recipient),
+        Some(BalanceIndexInfo {
+            balance: 100,
+            coin_object_count: 1,
+        })
+    );
+}
+
+#[test]
+fn balance_underflow_is_clamped() {
+    let mut chain = TestChain::new();
+    let recipient = SuiAddress::random_for_testing_only();
+
+    // A coin the balance index was never told about.
+    let a = Object::new_move(
+        MoveObject::new_gas_coin(SequenceNumber::from_u64(1), ObjectID::random(), 100),
+        Owner::AddressOwner(chain.sender),
+        TransactionDigest::genesis_marker(),
+    );
+
+    // Spending it does not fail indexing, and the sender's balance bottoms out at zero.
+    let (tx, _) = chain.transaction(&a, 100, recipient, &[]);
+    chain.index(vec![tx]);
+    assert_eq!(chain.balance(chain.sender), None);
+    assert_eq!(
+        chain.balance(recipient),
+        Some(BalanceIndexInfo {
+            balance: 100,
+            coin_object_count: 1,
+        })
+    );
+}
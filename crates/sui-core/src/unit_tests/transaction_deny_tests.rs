@@ -12,20 +12,27 @@ use fastcrypto::traits::KeyPair;
 use move_core_types::ident_str;
 use std::path::PathBuf;
 use std::sync::Arc;
-use sui_config::certificate_deny_config::CertificateDenyConfigBuilder;
-use sui_config::transaction_deny_config::{TransactionDenyConfig, TransactionDenyConfigBuilder};
+use sui_config::certificate_deny_config::{
+    CertificateDenyConfigBuilder, CertificateDenyConfigUpdate,
+};
+use sui_config::transaction_deny_config::{
+    PackageDenyRule, TransactionDenyConfig, TransactionDenyConfigBuilder,
+    TransactionDenyConfigUpdate,
+};
 use sui_swarm_config::genesis_config::{AccountConfig, DEFAULT_GAS_AMOUNT};
 use sui_swarm_config::network_config::NetworkConfig;
 use sui_test_transaction_builder::TestTransactionBuilder;
 use sui_types::base_types::{ObjectID, ObjectRef, SuiAddress};
-use sui_types::effects::TransactionEffectsAPI;
+use sui_types::effects::{TransactionEffects, TransactionEffectsAPI};
 use sui_types::error::{SuiError, SuiResult, UserInputError};
 use sui_types::execution_status::{ExecutionFailureStatus, ExecutionStatus};
 use sui_types::messages_grpc::HandleTransactionResponse;
+use sui_types::programmable_transaction_builder::ProgrammableTransactionBuilder;
 use sui_types::transaction::{
-    CallArg, CertifiedTransaction, Transaction, TransactionData, VerifiedCertificate,
+    CallArg, CertifiedTransaction, Command, Transaction, TransactionData, VerifiedCertificate,
     VerifiedTransaction, TEST_ONLY_GAS_UNIT_FOR_TRANSFER,
 };
+use sui_types::type_input::{StructInput, TypeInput};
 use sui_types::utils::get_zklogin_user_address;
 use sui_types::utils::{
     make_zklogin_tx, to_sender_signed_transaction, to_sender_signed_transaction_with_multi_signers,
@@ -62,6 +69,7 @@ async fn reload_state_with_new_deny_config(
         .with_transaction_deny_config(config)
         .with_network_config(network_config, 0)
         .with_store(state.database_for_testing().clone())
+        .with_db_path(state.config.db_path.clone())
         .build()
         .await
 }
@@ -158,6 +166,28 @@ async fn handle_move_call_transaction(
     state.handle_transaction(&epoch_store, tx).await
 }
 
+async fn handle_make_move_vec_transaction(
+    state: &Arc<AuthorityState>,
+    type_argument: TypeInput,
+    account: &Account,
+    gas_payment_index: usize,
+) -> SuiResult<HandleTransactionResponse> {
+    let rgp = state.reference_gas_price_for_testing().unwrap();
+    let mut builder = ProgrammableTransactionBuilder::new();
+    builder.command(Command::MakeMoveVec(Some(type_argument), vec![]));
+    let data = TransactionData::new_programmable(
+        account.0,
+        vec![account.2[gas_payment_index]],
+        builder.finish(),
+        TEST_ONLY_GAS_UNIT_FOR_TRANSFER * rgp,
+        rgp,
+    );
+    let epoch_store = state.epoch_store_for_testing();
+    let tx = to_sender_signed_transaction(data, &account.1);
+    let tx = epoch_store.verify_transaction(tx).unwrap();
+    state.handle_transaction(&epoch_store, tx).await
+}
+
 fn assert_denied<T: std::fmt::Debug>(result: &SuiResult<T>) {
     assert!(matches!(
         result.as_ref().unwrap_err(),
@@ -240,6 +270,74 @@ async fn test_signer_denied() {
     assert_denied(&transfer_with_account(&accounts[2], &accounts[1], &state).await);
 }
 
+#[tokio::test]
+async fn test_runtime_update() {
+    let (network_config, state) = setup_test(TransactionDenyConfigBuilder::new().build()).await;
+    let accounts = get_accounts_and_coins(&network_config, &state);
+    let filter = state.transaction_signing_filter();
+
+    // Deny the sender without restarting the authority.
+    let deny = TransactionDenyConfigUpdate::DenyAddress(accounts[0].0);
+    assert!(filter.update(&[deny]).unwrap());
+    assert_denied(&transfer_with_account(&accounts[0], &accounts[0], &state).await);
+
+    // Allowing it again takes effect immediately too, and repeating it is a no-op.
+    let allow = TransactionDenyConfigUpdate::AllowAddress(accounts[0].0);
+    assert!(filter.update(&[allow.clone()]).unwrap());
+    assert!(!filter.update(&[allow]).unwrap());
+    transfer_with_account(&accounts[0], &accounts[0], &state)
+        .await
+        .unwrap();
+
+    // Every update is recorded, including the no-op.
+    let audit_log = std::fs::read_to_string(filter.audit_log_path()).unwrap();
+    assert_eq!(audit_log.lines().count(), 3);
+}
+
+#[tokio::test]
+async fn test_runtime_update_survives_restart() {
+    let (network_config, state) = setup_test(TransactionDenyConfigBuilder::new().build()).await;
+    let accounts = get_accounts_and_coins(&network_config, &state);
+
+    let deny = TransactionDenyConfigUpdate::DenyAddress(accounts[0].0);
+    assert!(state.transaction_signing_filter().update(&[deny]).unwrap());
+
+    // The node stopped while appending a record, so the record never took effect.
+    let audit_log_path = state.transaction_signing_filter().audit_log_path();
+    let mut audit_log = std::fs::read(&audit_log_path).unwrap();
+    let complete = audit_log.len();
+    audit_log.extend_from_slice(br#"{"timestamp_ms":0,"updates":[{"#);
+    std::fs::write(&audit_log_path, audit_log).unwrap();
+
+    // Restarting with the same node config replays the runtime update on top of it, and drops the
+    // partly written record.
+    let state = reload_state_with_new_deny_config(
+        &network_config,
+        state,
+        TransactionDenyConfigBuilder::new().build(),
+    )
+    .await;
+    assert_denied(&transfer_with_account(&accounts[0], &accounts[0], &state).await);
+    assert_eq!(
+        std::fs::metadata(&audit_log_path).unwrap().len(),
+        complete as u64
+    );
+
+    // Updates made after the restart are appended to the same log.
+    let allow = TransactionDenyConfigUpdate::AllowAddress(accounts[0].0);
+    assert!(state.transaction_signing_filter().update(&[allow]).unwrap());
+
+    let state = reload_state_with_new_deny_config(
+        &network_config,
+        state,
+        TransactionDenyConfigBuilder::new().build(),
+    )
+    .await;
+    transfer_with_account(&accounts[0], &accounts[0], &state)
+        .await
+        .unwrap();
+}
+
 #[tokio::test]
 async fn test_shared_object_transaction_disabled() {
     let (network_config, state) = setup_test(
@@ -439,6 +537,117 @@ async fn test_package_denied() {
     assert!(result.is_ok());
 }
 
+#[tokio::test]
+async fn test_package_deny_rules() {
+    let (network_config, state) = setup_test(TransactionDenyConfigBuilder::new().build()).await;
+    let accounts = get_accounts_and_coins(&network_config, &state);
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    // Publish c, and upgrade it to c'.
+    let (tx_c, (package_c, cap_c)) = publish_package_on_single_authority(
+        &path.join("src/unit_tests/data/package_deny/c"),
+        accounts[0].0,
+        &accounts[0].1,
+        accounts[0].2[0],
+        [("c", ObjectID::ZERO)],
+        vec![],
+        &state,
+    )
+    .await
+    .unwrap();
+    let (tx_c_prime, package_c_prime) = upgrade_package_on_single_authority(
+        &path.join("src/unit_tests/data/package_deny/c"),
+        accounts[0].0,
+        &accounts[0].1,
+        accounts[0].2[1],
+        package_c,
+        cap_c,
+        [("c", ObjectID::ZERO)],
+        vec![],
+        &state,
+    )
+    .await
+    .unwrap();
+
+    state
+        .get_cache_commit()
+        .commit_transaction_outputs(state.epoch_store_for_testing().epoch(), &[tx_c, tx_c_prime])
+        .await
+        .unwrap();
+
+    let filter = state.transaction_signing_filter();
+    let set_rule = |rule: PackageDenyRule| {
+        let mut updates: Vec<_> = filter
+            .load()
+            .package_deny_rules()
+            .iter()
+            .cloned()
+            .map(TransactionDenyConfigUpdate::RemovePackageRule)
+            .collect();
+        updates.push(TransactionDenyConfigUpdate::AddPackageRule(rule));
+        assert!(filter.update(&updates).unwrap());
+    };
+
+    // Denying the upgrade family of c, by its original ID, also denies c'.
+    let family = PackageDenyRule {
+        upgrade_family: true,
+        ..PackageDenyRule::new(package_c)
+    };
+    set_rule(family.clone());
+    let result =
+        handle_move_call_transaction(&state, package_c_prime, "c", "c", vec![], &accounts[0], 2)
+            .await;
+    assert_denied(&result);
+
+    // Unless the sender is exempt.
+    set_rule(PackageDenyRule {
+        allowed_senders: vec![accounts[0].0],
+        ..family.clone()
+    });
+    let result =
+        handle_move_call_transaction(&state, package_c_prime, "c", "c", vec![], &accounts[0], 3)
+            .await;
+    assert!(result.is_ok());
+    let result =
+        handle_move_call_transaction(&state, package_c_prime, "c", "c", vec![], &accounts[1], 0)
+            .await;
+    assert_denied(&result);
+
+    // Rules that only deny some entry points allow calls to the others.
+    set_rule(PackageDenyRule {
+        functions: vec!["c::other".to_string()],
+        ..family.clone()
+    });
+    let result =
+        handle_move_call_transaction(&state, package_c_prime, "c", "c", vec![], &accounts[0], 4)
+            .await;
+    assert!(result.is_ok());
+    set_rule(PackageDenyRule {
+        functions: vec!["c::c".to_string()],
+        ..family.clone()
+    });
+    let result =
+        handle_move_call_transaction(&state, package_c_prime, "c", "c", vec![], &accounts[0], 5)
+            .await;
+    assert_denied(&result);
+
+    // Type arguments are only checked when the rule asks for it.
+    let c_type = TypeInput::Struct(Box::new(StructInput {
+        address: package_c.into(),
+        module: "c".to_string(),
+        name: "C".to_string(),
+        type_params: vec![],
+    }));
+    set_rule(family.clone());
+    let result = handle_make_move_vec_transaction(&state, c_type.clone(), &accounts[0], 6).await;
+    assert!(result.is_ok());
+    set_rule(PackageDenyRule {
+        type_arguments: true,
+        ..family
+    });
+    let result = handle_make_move_vec_transaction(&state, c_type, &accounts[0], 7).await;
+    assert_denied(&result);
+}
+
 #[tokio::test]
 async fn test_certificate_deny() {
     let (network_config, state) = setup_test(TransactionDenyConfig::default()).await;
@@ -484,3 +693,162 @@ async fn test_certificate_deny() {
         }
     ));
 }
+
+#[tokio::test]
+async fn test_certificate_deny_runtime_update() {
+    let (network_config, state) = setup_test(TransactionDenyConfig::default()).await;
+    let (sender, key, gas_objects) = get_accounts_and_coins(&network_config, &state)
+        .pop()
+        .unwrap();
+    let tx = make_transfer_sui_transaction(
+        gas_objects[0],
+        sender,
+        None,
+        sender,
+        &key,
+        state.reference_gas_price_for_testing().unwrap(),
+    );
+    let digest = *tx.digest();
+    let epoch_store = state.epoch_store_for_testing();
+    let epoch = epoch_store.epoch();
+
+    // Deny the certificate for the current epoch only, without restarting the authority.
+    let filter = state.certificate_deny_filter();
+    let deny = CertificateDenyConfigUpdate::DenyCertificate {
+        digest,
+        last_epoch: Some(epoch),
+    };
+    assert!(filter.update(&[deny.clone()]).unwrap());
+    assert!(!filter.update(&[deny]).unwrap());
+    assert!(filter.certificate_deny_set(epoch).contains(&digest));
+    assert!(filter.certificate_deny_set(epoch + 1).is_empty());
+
+    let tx = epoch_store.verify_transaction(tx).unwrap();
+    let signature = state
+        .handle_transaction(&epoch_store, tx.clone())
+        .await
+        .unwrap()
+        .status
+        .into_signed_for_testing();
+    let cert = VerifiedCertificate::new_unchecked(
+        CertifiedTransaction::new(tx.into_message(), vec![signature], epoch_store.committee())
+            .unwrap(),
+    );
+    let (effects, _) = state.try_execute_for_test(&cert).await.unwrap();
+    assert!(matches!(
+        effects.status(),
+        &ExecutionStatus::Failure {
+            error: ExecutionFailureStatus::CertificateDenied,
+            ..
+        }
+    ));
+
+    // Every update is recorded, including the no-op.
+    let audit_log = std::fs::read_to_string(filter.audit_log_path()).unwrap();
+    assert_eq!(audit_log.lines().count(), 2);
+}
+
+/// Sign `tx` with the single authority in `state`'s committee, and execute the certificate.
+async fn certify_and_execute(state: &AuthorityState, tx: Transaction) -> TransactionEffects {
+    let epoch_store = state.epoch_store_for_testing();
+    let tx = epoch_store.verify_transaction(tx).unwrap();
+    let signature = state
+        .handle_transaction(&epoch_store, tx.clone())
+        .await
+        .unwrap()
+        .status
+        .into_signed_for_testing();
+    let cert = VerifiedCertificate::new_unchecked(
+        CertifiedTransaction::new(tx.into_message(), vec![signature], epoch_store.committee())
+            .unwrap(),
+    );
+    state
+        .try_execute_for_test(&cert)
+        .await
+        .unwrap()
+        .0
+        .into_message()
+}
+
+fn is_certificate_denied(effects: &TransactionEffects) -> bool {
+    matches!(
+        effects.status(),
+        &ExecutionStatus::Failure {
+            error: ExecutionFailureStatus::CertificateDenied,
+            ..
+        }
+    )
+}
+
+#[tokio::test]
+async fn test_certificate_deny_expiry() {
+    let (network_config, state) = setup_test(TransactionDenyConfig::default()).await;
+    let (sender, key, gas_objects) = get_accounts_and_coins(&network_config, &state)
+        .pop()
+        .unwrap();
+    let gas_price = state.reference_gas_price_for_testing().unwrap();
+    let expiring =
+        make_transfer_sui_transaction(gas_objects[0], sender, None, sender, &key, gas_price);
+    let permanent =
+        make_transfer_sui_transaction(gas_objects[1], sender, None, sender, &key, gas_price);
+
+    let state = TestAuthorityBuilder::new()
+        .with_network_config(&network_config, 0)
+        .with_certificate_deny_config(
+            CertificateDenyConfigBuilder::new()
+                .add_certificate_deny_until_epoch(*expiring.digest(), 0)
+                .add_certificate_deny(*permanent.digest())
+                .build(),
+        )
+        .build()
+        .await;
+    let filter = state.certificate_deny_filter();
+    assert!(filter.certificate_deny_set(0).contains(expiring.digest()));
+
+    // In the next epoch, the expired entry no longer applies, so its certificate executes normally.
+    state.reconfigure_for_testing().await;
+    assert_eq!(state.epoch_store_for_testing().epoch(), 1);
+    assert!(!filter.certificate_deny_set(1).contains(expiring.digest()));
+
+    let effects = certify_and_execute(&state, expiring).await;
+    assert!(effects.status().is_ok());
+    let effects = certify_and_execute(&state, permanent).await;
+    assert!(is_certificate_denied(&effects));
+}
+
+#[tokio::test]
+async fn test_certificate_deny_survives_restart() {
+    let (network_config, state) = setup_test(TransactionDenyConfig::default()).await;
+    let (sender, key, gas_objects) = get_accounts_and_coins(&network_config, &state)
+        .pop()
+        .unwrap();
+    let tx = make_transfer_sui_transaction(
+        gas_objects[0],
+        sender,
+        None,
+        sender,
+        &key,
+        state.reference_gas_price_for_testing().unwrap(),
+    );
+
+    let deny = CertificateDenyConfigUpdate::DenyCertificate {
+        digest: *tx.digest(),
+        last_epoch: None,
+    };
+    assert!(state.certificate_deny_filter().update(&[deny]).unwrap());
+
+    // Restarting with the same node config replays the runtime update on top of it, so the
+    // certificate is still executed as denied.
+    let state =
+        reload_state_with_new_deny_config(&network_config, state, TransactionDenyConfig::default())
+            .await;
+    assert_eq!(
+        state
+            .certificate_deny_filter()
+            .load()
+            .certificate_deny_list(),
+        &[*tx.digest()]
+    );
+    let effects = certify_and_execute(&state, tx).await;
+    assert!(is_certificate_denied(&effects));
+}
@@ -142,6 +142,35 @@ async fn transaction_manager_basics() {
 
     assert_eq!(transaction_manager.inflight_queue_len(), 1);
 
+    // The snapshot shows the transaction waiting on the gas object.
+    let snapshot = transaction_manager.snapshot();
+    assert!(snapshot.executing.is_empty());
+    assert_eq!(snapshot.pending.len(), 1);
+    assert_eq!(snapshot.pending[0].digest, *transaction.digest());
+    assert_eq!(
+        snapshot.pending[0].waiting_input_objects,
+        get_input_keys(&[gas_object_new.clone()])
+    );
+
+    // This is the JSON served by the admin server.
+    assert_eq!(
+        serde_json::to_value(&snapshot).unwrap(),
+        serde_json::json!({
+            "epoch": state.epoch_store_for_testing().epoch(),
+            "pending": [{
+                "digest": transaction.digest(),
+                "age_ms": snapshot.pending[0].age_ms,
+                "waiting_input_objects": [{
+                    "VersionedObject": {
+                        "id": gas_object_new.id(),
+                        "version": gas_object_new.version(),
+                    },
+                }],
+            }],
+            "executing": [],
+        })
+    );
+
     // Notify TM about availability of the gas object.
     transaction_manager.objects_available(
         get_input_keys(&[gas_object_new]),
@@ -158,6 +187,11 @@ async fn transaction_manager_basics() {
             >= Duration::from_secs(2)
     );
 
+    // The transaction is no longer waiting, and stays executing until it commits.
+    let snapshot = transaction_manager.snapshot();
+    assert!(snapshot.pending.is_empty());
+    assert_eq!(snapshot.executing, vec![*transaction.digest()]);
+
     // Re-enqueue the same transaction should not result in another output.
     transaction_manager.enqueue(vec![transaction.clone()], &state.epoch_store_for_testing());
     sleep(Duration::from_secs(1)).await;
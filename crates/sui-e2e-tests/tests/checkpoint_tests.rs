@@ -1,15 +1,22 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use fastcrypto::hash::MultisetHash;
+use rand::rngs::OsRng;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Mutex;
 use std::time::Duration;
+use sui_config::node::{CheckpointDataSource, FastSyncConfig};
+use sui_config::object_storage_config::{ObjectStoreConfig, ObjectStoreType};
 use sui_macros::register_fail_point;
 use sui_macros::register_fail_point_if;
 use sui_macros::sim_test;
+use sui_node::SuiNodeHandle;
 use sui_test_transaction_builder::make_transfer_sui_transaction;
+use sui_types::committee::EpochId;
+use sui_types::messages_checkpoint::{CheckpointSequenceNumber, ECMHLiveObjectSetDigest};
 use test_cluster::TestClusterBuilder;
 
 #[sim_test]
@@ -84,3 +91,106 @@ async fn test_checkpoint_split_brain() {
     let final_count = count_split_brain_nodes.lock().unwrap();
     assert!(final_count.load(Ordering::Relaxed) >= 1);
 }
+
+#[sim_test]
+async fn test_checkpoint_fast_sync() {
+    let data_ingestion_dir = tempfile::tempdir().unwrap();
+    let test_cluster = TestClusterBuilder::new()
+        .with_data_ingestion_dir(data_ingestion_dir.path().to_path_buf())
+        .build()
+        .await;
+
+    // Transactions in two epochs, so that fast sync crosses an epoch boundary.
+    for _ in 0..2 {
+        for _ in 0..3 {
+            let tx = make_transfer_sui_transaction(&test_cluster.wallet, None, None).await;
+            test_cluster.execute_transaction(tx).await;
+        }
+        test_cluster.trigger_reconfiguration().await;
+    }
+
+    let source = &test_cluster.fullnode_handle.sui_node;
+    let target = epoch_last_checkpoint(source, 1);
+
+    // Start a fullnode that fast syncs up to the end of epoch 1, from the checkpoint data written by
+    // the cluster's nodes.
+    let mut config = test_cluster
+        .fullnode_config_builder()
+        .build(&mut OsRng, test_cluster.swarm.config());
+    config.checkpoint_executor_config.data_ingestion_dir = None;
+    config.checkpoint_executor_config.fast_sync = Some(FastSyncConfig {
+        checkpoint_data_source: CheckpointDataSource::ObjectStore(ObjectStoreConfig {
+            object_store: Some(ObjectStoreType::File),
+            directory: Some(data_ingestion_dir.path().to_path_buf()),
+            ..Default::default()
+        }),
+        target_checkpoint: target,
+    });
+    let fast_synced = test_cluster.start_fullnode_from_config(config).await;
+    test_cluster
+        .wait_for_epoch_on_node(&fast_synced.sui_node, Some(2), Duration::from_secs(60))
+        .await;
+
+    // Every fast synced transaction has the effects of the normally executed one.
+    for seq in 0..=target {
+        let digests: Vec<_> = source.with(|node| {
+            let checkpoint_store = node.state().get_checkpoint_store().clone();
+            let checkpoint = checkpoint_store
+                .get_checkpoint_by_sequence_number(seq)
+                .unwrap()
+                .unwrap();
+            checkpoint_store
+                .get_checkpoint_contents(&checkpoint.content_digest)
+                .unwrap()
+                .unwrap()
+                .iter()
+                .copied()
+                .collect()
+        });
+
+        let transactions: Vec<_> = digests.iter().map(|d| d.transaction).collect();
+        let effects = fast_synced.sui_node.with(|node| {
+            node.state()
+                .get_transaction_cache_reader()
+                .multi_get_executed_effects_digests(&transactions)
+                .unwrap()
+        });
+        let expected: Vec<_> = digests.iter().map(|d| Some(d.effects)).collect();
+        assert_eq!(effects, expected, "effects of checkpoint {seq} differ");
+    }
+
+    // The live object set at the end of each fast synced epoch is the same as on the node that
+    // executed it.
+    assert_eq!(epoch_last_checkpoint(&fast_synced.sui_node, 1), target);
+    for epoch in 0..2 {
+        assert_eq!(
+            epoch_root(&fast_synced.sui_node, epoch),
+            epoch_root(source, epoch),
+            "root of epoch {epoch} differs",
+        );
+    }
+}
+
+fn epoch_last_checkpoint(node: &SuiNodeHandle, epoch: EpochId) -> CheckpointSequenceNumber {
+    node.with(|node| {
+        *node
+            .state()
+            .get_checkpoint_store()
+            .get_epoch_last_checkpoint(epoch)
+            .unwrap()
+            .unwrap()
+            .sequence_number()
+    })
+}
+
+fn epoch_root(node: &SuiNodeHandle, epoch: EpochId) -> ECMHLiveObjectSetDigest {
+    node.with(|node| {
+        let (_, root) = node
+            .state()
+            .get_accumulator_store()
+            .get_root_state_accumulator_for_epoch(epoch)
+            .unwrap()
+            .unwrap();
+        root.digest().into()
+    })
+}
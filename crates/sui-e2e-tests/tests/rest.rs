@@ -1,10 +1,12 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use futures::StreamExt;
 use shared_crypto::intent::Intent;
 use sui_keys::keystore::AccountKeystore;
 use sui_macros::sim_test;
 use sui_rest_api::client::reqwest::StatusCode;
+use sui_rest_api::client::sdk::{ListAccountBalancesQueryParameters, ListEventsQueryParameters};
 use sui_rest_api::client::BalanceChange;
 use sui_rest_api::transactions::ResolveTransactionQueryParameters;
 use sui_rest_api::Client;
@@ -20,6 +22,8 @@ use sui_sdk_types::types::UnresolvedTransaction;
 use sui_test_transaction_builder::make_transfer_sui_transaction;
 use sui_types::base_types::SuiAddress;
 use sui_types::effects::TransactionEffectsAPI;
+use sui_types::gas_coin::GAS;
+use sui_types::sui_sdk_types_conversions::struct_tag_core_to_sdk;
 use sui_types::transaction::TransactionDataAPI;
 use test_cluster::TestClusterBuilder;
 
@@ -397,6 +401,186 @@ async fn resolve_transaction_insufficient_gas() {
     );
 }
 
+#[sim_test]
+async fn stream_full_checkpoints() {
+    let test_cluster = TestClusterBuilder::new().build().await;
+    let client = Client::new(test_cluster.rpc_url());
+
+    let mut stream = client.stream_full_checkpoints(Some(0)).await.unwrap();
+
+    let txn = make_transfer_sui_transaction(&test_cluster.wallet, None, Some(1)).await;
+    let digest = test_cluster.execute_transaction(txn).await.digest;
+
+    // Checkpoints arrive in order, without gaps, and keep arriving as they are executed, until
+    // one includes the transaction executed after the stream started.
+    let mut expected = 0;
+    tokio::time::timeout(std::time::Duration::from_secs(60), async {
+        loop {
+            let checkpoint = stream.next().await.unwrap().unwrap();
+            assert_eq!(checkpoint.checkpoint_summary.sequence_number, expected);
+            expected += 1;
+
+            if checkpoint
+                .transactions
+                .iter()
+                .any(|tx| *tx.transaction.digest() == digest)
+            {
+                break;
+            }
+        }
+    })
+    .await
+    .expect("Timed out waiting for the transaction's checkpoint to be streamed");
+}
+
+#[sim_test]
+async fn list_account_balances() {
+    let test_cluster = TestClusterBuilder::new().build().await;
+    let client = Client::new(test_cluster.rpc_url());
+    let recipient = SuiAddress::random_for_testing_only();
+    let sui = struct_tag_core_to_sdk(GAS::type_()).unwrap();
+
+    for amount in [7, 11] {
+        let txn =
+            make_transfer_sui_transaction(&test_cluster.wallet, Some(recipient), Some(amount))
+                .await;
+        test_cluster.execute_transaction(txn).await;
+    }
+
+    // The index is updated as checkpoints are executed, which can lag behind the transactions.
+    let balance = tokio::time::timeout(std::time::Duration::from_secs(60), async {
+        loop {
+            if let Ok(balance) = client
+                .inner()
+                .get_account_balance(recipient.into(), &sui)
+                .await
+            {
+                if balance.inner().coin_object_count == 2 {
+                    break balance.into_inner();
+                }
+            }
+
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+    })
+    .await
+    .expect("Timed out waiting for the recipient's balance to be indexed");
+
+    assert_eq!(balance.coin_type, sui);
+    assert_eq!(balance.balance, 18);
+
+    let parameters = ListAccountBalancesQueryParameters {
+        limit: None,
+        start: None,
+    };
+
+    let balances = client
+        .inner()
+        .list_account_balances(recipient.into(), &parameters)
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(balances.len(), 1);
+    assert_eq!(balances[0].coin_type, sui);
+    assert_eq!(balances[0].balance, 18);
+    assert_eq!(balances[0].coin_object_count, 2);
+
+    // Accounts with no coins have no balances.
+    let balances = client
+        .inner()
+        .list_account_balances(SuiAddress::random_for_testing_only().into(), &parameters)
+        .await
+        .unwrap()
+        .into_inner();
+    assert!(balances.is_empty());
+
+    // ...and a zero balance of any particular coin.
+    let balance = client
+        .inner()
+        .get_account_balance(SuiAddress::random_for_testing_only().into(), &sui)
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(balance.balance, 0);
+    assert_eq!(balance.coin_object_count, 0);
+}
+
+#[sim_test]
+async fn list_events() {
+    let test_cluster = TestClusterBuilder::new().build().await;
+    let client = Client::new(test_cluster.rpc_url());
+    let epoch_info = struct_tag_core_to_sdk(
+        "0x3::sui_system_state_inner::SystemEpochInfoEvent"
+            .parse()
+            .unwrap(),
+    )
+    .unwrap();
+
+    // Each epoch change emits a `SystemEpochInfoEvent`.
+    test_cluster.trigger_reconfiguration().await;
+    test_cluster.trigger_reconfiguration().await;
+
+    let by_type = ListEventsQueryParameters {
+        transaction: None,
+        event_type: Some(epoch_info),
+        limit: Some(1),
+        start: None,
+    };
+
+    let events = tokio::time::timeout(std::time::Duration::from_secs(60), async {
+        loop {
+            let events: Vec<_> = client
+                .inner()
+                .events_stream(by_type.clone())
+                .collect::<Vec<_>>()
+                .await
+                .into_iter()
+                .collect::<Result<_, _>>()
+                .unwrap();
+
+            if events.len() >= 2 {
+                break events;
+            }
+
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+    })
+    .await
+    .expect("Timed out waiting for epoch change events to be indexed");
+
+    // Events are listed in the order they were emitted, across pages.
+    assert!(events.windows(2).all(|w| w[0].checkpoint < w[1].checkpoint));
+
+    // The same events can be found through the transactions that emitted them.
+    for event in &events {
+        let by_transaction = ListEventsQueryParameters {
+            transaction: Some(event.transaction_digest),
+            event_type: None,
+            limit: None,
+            start: None,
+        };
+
+        let emitted = client
+            .inner()
+            .list_events(&by_transaction)
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(emitted.iter().any(|e| e.event_index == event.event_index
+            && e.transaction_digest == event.transaction_digest));
+    }
+
+    // Exactly one of `transaction` or `event_type` must be provided.
+    let neither = ListEventsQueryParameters {
+        transaction: None,
+        event_type: None,
+        limit: None,
+        start: None,
+    };
+    let error = client.inner().list_events(&neither).await.unwrap_err();
+    assert_eq!(error.status(), Some(StatusCode::BAD_REQUEST));
+}
+
 fn assert_contains(haystack: &str, needle: &str) {
     if !haystack.contains(needle) {
         panic!("{haystack:?} does not contain {needle:?}");
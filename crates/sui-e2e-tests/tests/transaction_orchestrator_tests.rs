@@ -319,6 +319,7 @@ async fn execute_transaction_v3() -> Result<(), anyhow::Error> {
         include_input_objects: true,
         include_output_objects: true,
         include_auxiliary_data: false,
+        priority: Default::default(),
     };
     let response = orchestrator.execute_transaction_v3(request, None).await?;
     let fx = &response.effects.effects;
@@ -378,6 +379,7 @@ async fn execute_transaction_v3_staking_transaction() -> Result<(), anyhow::Erro
         include_input_objects: true,
         include_output_objects: true,
         include_auxiliary_data: false,
+        priority: Default::default(),
     };
     let response = orchestrator.execute_transaction_v3(request, None).await?;
     let fx = &response.effects.effects;
@@ -8,7 +8,12 @@ use fastcrypto_zkp::bn254::zk_login_api::ZkLoginEnv;
 use move_core_types::ident_str;
 use move_core_types::identifier::IdentStr;
 use serde::{Deserialize, Serialize};
-use std::{collections::BTreeSet, fmt::Display, time::Duration};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+    path::PathBuf,
+    time::Duration,
+};
 use sui_graphql_config::GraphQLConfig;
 use sui_json_rpc::name_service::NameServiceConfig;
 use sui_types::base_types::{ObjectID, SuiAddress};
@@ -81,6 +86,9 @@ pub struct ServiceConfig {
     pub background_tasks: BackgroundTasksConfig,
     pub zklogin: ZkLoginConfig,
     pub move_registry: MoveRegistryConfig,
+    pub persisted_queries: PersistedQueriesConfig,
+    pub response_cache: ResponseCacheConfig,
+    pub api_keys: ApiKeysConfig,
 }
 
 #[GraphQLConfig]
@@ -151,6 +159,47 @@ pub(crate) enum ResolutionType {
     External,
 }
 
+#[GraphQLConfig]
+#[derive(Default)]
+pub struct PersistedQueriesConfig {
+    /// Directory of `.graphql` files to register as persisted queries, one query per file. Each
+    /// query is identified by the hex-encoded SHA-256 hash of its file's contents.
+    pub directory: Option<PathBuf>,
+    /// Only accept requests for registered queries, whether they are sent by hash or in full.
+    pub strict: bool,
+}
+
+#[GraphQLConfig]
+pub struct ApiKeysConfig {
+    /// Reject requests that do not identify themselves with an API key.
+    pub required: bool,
+    /// Length of the window (in milliseconds) over which each key's budget applies.
+    pub window_ms: u64,
+    /// Recognised API keys, by the value sent in the API key header.
+    pub keys: BTreeMap<String, ApiKeyConfig>,
+}
+
+#[GraphQLConfig]
+#[derive(Default)]
+pub struct ApiKeyConfig {
+    /// Name of the key's owner, used in metrics and usage reports in place of the key itself.
+    pub name: String,
+    /// The total estimated cost (in output nodes) of the queries this key can make per window.
+    pub budget: u64,
+}
+
+#[GraphQLConfig]
+#[derive(Copy)]
+pub struct ResponseCacheConfig {
+    /// Maximum number of responses to hold in the cache.
+    pub max_entries: usize,
+    /// Maximum total size in bytes of the responses held in the cache, measured by their JSON
+    /// serialization.
+    pub max_size_bytes: usize,
+    /// Responses larger than this (in bytes) are never cached.
+    pub max_entry_size_bytes: usize,
+}
+
 /// The Version of the service. `year.month` represents the major release.
 /// New `patch` versions represent backwards compatible fixes for their major release.
 /// The `full` version is `year.month.patch-sha`.
@@ -213,6 +262,7 @@ pub struct InternalFeatureConfig {
     pub(crate) tracing: bool,
     pub(crate) apollo_tracing: bool,
     pub(crate) open_telemetry: bool,
+    pub(crate) response_cache: bool,
 }
 
 #[GraphQLConfig]
@@ -532,6 +582,27 @@ impl Default for InternalFeatureConfig {
             tracing: false,
             apollo_tracing: false,
             open_telemetry: false,
+            response_cache: false,
+        }
+    }
+}
+
+impl Default for ApiKeysConfig {
+    fn default() -> Self {
+        Self {
+            required: false,
+            window_ms: 60_000,
+            keys: BTreeMap::new(),
+        }
+    }
+}
+
+impl Default for ResponseCacheConfig {
+    fn default() -> Self {
+        Self {
+            max_entries: 10_000,
+            max_size_bytes: 256 * 1024 * 1024,
+            max_entry_size_bytes: 1024 * 1024,
         }
     }
 }
@@ -661,6 +732,38 @@ mod tests {
         assert_eq!(actual, expect)
     }
 
+    #[test]
+    fn test_read_api_keys_in_service_config() {
+        let actual = ServiceConfig::read(
+            r#" [api-keys]
+                required = true
+                window-ms = 1000
+
+                [api-keys.keys.secret]
+                name = "team"
+                budget = 42
+            "#,
+        )
+        .unwrap();
+
+        let expect = ServiceConfig {
+            api_keys: ApiKeysConfig {
+                required: true,
+                window_ms: 1000,
+                keys: BTreeMap::from([(
+                    "secret".to_string(),
+                    ApiKeyConfig {
+                        name: "team".to_string(),
+                        budget: 42,
+                    },
+                )]),
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(actual, expect)
+    }
+
     #[test]
     fn test_read_everything_in_service_config() {
         let actual = ServiceConfig::read(
@@ -684,6 +787,10 @@ mod tests {
                 max-transaction-ids = 42
                 max-scan-limit = 420
 
+                [persisted-queries]
+                directory = "/etc/graphql/queries"
+                strict = true
+
                 [experiments]
                 test-flag = true
             "#,
@@ -710,6 +817,10 @@ mod tests {
                 max_scan_limit: 420,
             },
             disabled_features: BTreeSet::from([FunctionalGroup::Analytics]),
+            persisted_queries: PersistedQueriesConfig {
+                directory: Some(PathBuf::from("/etc/graphql/queries")),
+                strict: true,
+            },
             experiments: Experiments { test_flag: true },
             ..Default::default()
         };
@@ -0,0 +1,145 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use async_graphql::{
+    extensions::{Extension, ExtensionContext, ExtensionFactory, NextParseQuery},
+    parser::types::ExecutableDocument,
+    ServerResult, Variables,
+};
+use async_trait::async_trait;
+
+use crate::{
+    config::ServiceConfig,
+    extensions::query_limits_checker::{estimate_output_nodes, is_introspection},
+    metrics::Metrics,
+    server::api_keys::{ApiKey, ApiKeyQuotas},
+};
+
+/// Extension factory for charging the estimated cost of each request to the API key it was made
+/// with. Runs independently of the `QueryLimitsChecker`, so that budgets are still enforced when
+/// limits are not.
+pub(crate) struct ApiKeyQuotaChecker;
+
+impl ExtensionFactory for ApiKeyQuotaChecker {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(ApiKeyQuotaChecker)
+    }
+}
+
+#[async_trait]
+impl Extension for ApiKeyQuotaChecker {
+    /// Charges the query's estimated output nodes to its API key, once it has been parsed (and
+    /// checked by any extensions registered after this one). Requests that fail those checks, and
+    /// pure introspection queries, are not charged.
+    async fn parse_query(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        query: &str,
+        variables: &Variables,
+        next: NextParseQuery<'_>,
+    ) -> ServerResult<ExecutableDocument> {
+        let doc = next.run(ctx, query, variables).await?;
+
+        let Some(quotas) = ctx.data_opt::<ApiKeyQuotas>() else {
+            return Ok(doc);
+        };
+
+        if is_introspection(&doc) {
+            return Ok(doc);
+        }
+
+        let cfg: &ServiceConfig = ctx.data_unchecked();
+        let metrics: &Metrics = ctx.data_unchecked();
+        let cost = estimate_output_nodes(&cfg.limits, &doc, variables);
+        quotas.charge(ctx.data_opt::<ApiKey>(), cost as u64, metrics)?;
+
+        Ok(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use async_graphql::{EmptyMutation, EmptySubscription, Object, Request, Schema};
+    use prometheus::Registry;
+
+    use crate::config::{ApiKeyConfig, ApiKeysConfig};
+
+    use super::*;
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self) -> u64 {
+            42
+        }
+    }
+
+    fn schema() -> Schema<Query, EmptyMutation, EmptySubscription> {
+        let quotas = ApiKeyQuotas::from_config(&ApiKeysConfig {
+            required: true,
+            window_ms: 1000,
+            keys: BTreeMap::from([(
+                "secret".to_string(),
+                ApiKeyConfig {
+                    name: "team".to_string(),
+                    budget: 2,
+                },
+            )]),
+        })
+        .unwrap()
+        .with_clock(|| 0);
+
+        // The query limits checker is deliberately not registered, to make sure that quotas are
+        // enforced without it.
+        Schema::build(Query, EmptyMutation, EmptySubscription)
+            .data(ServiceConfig::default())
+            .data(Metrics::new(&Registry::new()))
+            .data(quotas)
+            .extension(ApiKeyQuotaChecker)
+            .finish()
+    }
+
+    fn request(key: Option<&str>) -> Request {
+        let request = Request::new("{ a: value b: value }");
+        match key {
+            Some(key) => request.data(ApiKey(key.to_string())),
+            None => request,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_quotas_without_limits_checker() {
+        let schema = schema();
+
+        let resp = schema.execute(request(Some("secret"))).await;
+        assert!(resp.errors.is_empty(), "{:?}", resp.errors);
+
+        // Each request costs two output nodes, which exhausts the budget.
+        let resp = schema.execute(request(Some("secret"))).await;
+        assert_eq!(
+            resp.errors[0].message,
+            "API key for team has exceeded its budget of 2 output nodes per 1000 ms",
+        );
+
+        let resp = schema.execute(request(None)).await;
+        assert_eq!(
+            resp.errors[0].message,
+            "Requests must include an API key in the x-sui-rpc-api-key header",
+        );
+    }
+
+    #[tokio::test]
+    async fn test_introspection_is_free() {
+        let schema = schema();
+
+        let resp = schema
+            .execute(Request::new("{ __schema { queryType { name } } }"))
+            .await;
+        assert!(resp.errors.is_empty(), "{:?}", resp.errors);
+    }
+}
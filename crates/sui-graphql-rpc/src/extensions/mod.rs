@@ -1,8 +1,11 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+pub(crate) mod api_key_quotas;
 pub(crate) mod directive_checker;
 pub(crate) mod feature_gate;
 pub(crate) mod logger;
+pub(crate) mod persisted_queries;
 pub(crate) mod query_limits_checker;
+pub(crate) mod response_cache;
 pub(crate) mod timeout;
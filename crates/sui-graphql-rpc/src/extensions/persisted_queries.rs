@@ -0,0 +1,332 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashMap, fs, path::Path, sync::Arc};
+
+use async_graphql::{
+    extensions::{Extension, ExtensionContext, ExtensionFactory, NextPrepareRequest},
+    from_value,
+    parser::parse_query,
+    Request, ServerResult,
+};
+use async_trait::async_trait;
+use fastcrypto::hash::{HashFunction, Sha256};
+use serde::Deserialize;
+use tracing::info;
+
+use crate::{
+    config::{Limits, PersistedQueriesConfig},
+    error::{code, graphql_error, Error},
+    extensions::query_limits_checker::check_document_limits,
+};
+
+/// The key in a request's `extensions` that identifies a persisted query.
+const PERSISTED_QUERY: &str = "persistedQuery";
+
+/// The only version of the persisted query protocol that is supported.
+const PERSISTED_QUERY_VERSION: u32 = 1;
+
+/// The file extension of queries registered from the persisted queries directory.
+const QUERY_FILE_EXTENSION: &str = "graphql";
+
+/// Extension factory for supporting persisted queries: Requests can identify a registered query by
+/// its hash (using the same protocol as Apollo's Automatic Persisted Queries), rather than sending
+/// the query in full. In strict mode, only registered queries are accepted.
+///
+/// Unlike automatic persisted queries, clients cannot register new queries. The set of registered
+/// queries is fixed when the service starts.
+#[derive(Clone)]
+pub(crate) struct PersistedQueries {
+    /// Registered queries, keyed by the hex-encoded SHA-256 hash of their contents.
+    queries: Arc<HashMap<String, String>>,
+    strict: bool,
+}
+
+/// The contents of the `persistedQuery` request extension.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PersistedQueryExtension {
+    version: u32,
+    sha256_hash: String,
+}
+
+impl PersistedQueries {
+    /// Create the extension from its config, loading persisted queries from the configured
+    /// directory. Fails if any of the queries cannot be read or parsed, or if they do not fit within
+    /// the service's `limits`.
+    pub(crate) fn from_config(
+        config: &PersistedQueriesConfig,
+        limits: &Limits,
+    ) -> Result<Self, Error> {
+        let mut persisted = Self {
+            queries: Arc::new(HashMap::new()),
+            strict: config.strict,
+        };
+
+        if let Some(directory) = &config.directory {
+            persisted.queries = Arc::new(load_queries(directory, limits)?);
+        } else if config.strict {
+            return Err(Error::Internal(
+                "Persisted queries are strict, but no directory of queries is configured"
+                    .to_string(),
+            ));
+        }
+
+        Ok(persisted)
+    }
+
+    /// Whether any queries were registered, or the extension otherwise affects requests.
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.strict || !self.queries.is_empty()
+    }
+}
+
+/// Read every `.graphql` file in `directory`, and register its contents, keyed by its hash. Each
+/// query is parsed and checked against `limits` once, here, so that a query that could never be
+/// served is caught before the service starts.
+fn load_queries(directory: &Path, limits: &Limits) -> Result<HashMap<String, String>, Error> {
+    let entries = fs::read_dir(directory).map_err(|e| {
+        Error::Internal(format!(
+            "Failed to read persisted queries from {}: {e}",
+            directory.display()
+        ))
+    })?;
+
+    let mut queries = HashMap::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|e| Error::Internal(format!("Failed to read persisted query: {e}")))?
+            .path();
+
+        if path.extension().and_then(|e| e.to_str()) != Some(QUERY_FILE_EXTENSION) {
+            continue;
+        }
+
+        let query = fs::read_to_string(&path).map_err(|e| {
+            Error::Internal(format!(
+                "Failed to read persisted query {}: {e}",
+                path.display()
+            ))
+        })?;
+
+        let doc = parse_query(&query).map_err(|e| {
+            Error::Internal(format!(
+                "Failed to parse persisted query {}: {e}",
+                path.display()
+            ))
+        })?;
+
+        check_document_limits(limits, &query, &doc).map_err(|e| {
+            Error::Internal(format!(
+                "Persisted query {} exceeds limits: {}",
+                path.display(),
+                e.message
+            ))
+        })?;
+
+        queries.insert(hash(&query), query);
+    }
+
+    info!(
+        "Registered {} persisted queries from {}",
+        queries.len(),
+        directory.display()
+    );
+
+    Ok(queries)
+}
+
+/// The hex-encoded SHA-256 hash of `query`.
+fn hash(query: &str) -> String {
+    hex::encode(Sha256::digest(query.as_bytes()).digest)
+}
+
+impl ExtensionFactory for PersistedQueries {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(self.clone())
+    }
+}
+
+#[async_trait]
+impl Extension for PersistedQueries {
+    async fn prepare_request(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        mut request: Request,
+        next: NextPrepareRequest<'_>,
+    ) -> ServerResult<Request> {
+        if let Some(extension) = request.extensions.remove(PERSISTED_QUERY) {
+            let PersistedQueryExtension {
+                version,
+                sha256_hash,
+            } = from_value(extension).map_err(|_| {
+                graphql_error(code::BAD_USER_INPUT, "Invalid \"persistedQuery\" extension")
+            })?;
+
+            if version != PERSISTED_QUERY_VERSION {
+                return Err(graphql_error(
+                    code::BAD_USER_INPUT,
+                    format!(
+                        "Unsupported persisted query version {version}, only version \
+                         {PERSISTED_QUERY_VERSION} is supported"
+                    ),
+                ));
+            }
+
+            let sha256_hash = sha256_hash.to_lowercase();
+            let Some(query) = self.queries.get(&sha256_hash) else {
+                return Err(graphql_error(
+                    code::BAD_USER_INPUT,
+                    "PersistedQueryNotFound",
+                ));
+            };
+
+            if request.query.is_empty() {
+                request.query = query.clone();
+            } else if request.query != *query {
+                return Err(graphql_error(
+                    code::BAD_USER_INPUT,
+                    "Query does not match its persisted query hash",
+                ));
+            }
+        } else if self.strict && !self.queries.contains_key(&hash(&request.query)) {
+            return Err(graphql_error(
+                code::BAD_USER_INPUT,
+                "Only persisted queries are accepted by this service",
+            ));
+        }
+
+        next.run(ctx, request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_graphql::{EmptySubscription, Schema, Value};
+    use expect_test::expect;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    use crate::{config::ServiceConfig, mutation::Mutation, types::query::Query};
+
+    use super::*;
+
+    const QUERY: &str = "{ serviceConfig { maxQueryDepth } }";
+
+    /// Create a fresh directory containing the given queries, one per file.
+    fn query_dir(queries: &[&str]) -> TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        for (i, query) in queries.iter().enumerate() {
+            fs::write(dir.path().join(format!("{i}.graphql")), query).unwrap();
+        }
+        dir
+    }
+
+    fn persisted(strict: bool) -> PersistedQueries {
+        let dir = query_dir(&[QUERY]);
+        let config = PersistedQueriesConfig {
+            directory: Some(dir.path().to_path_buf()),
+            strict,
+        };
+
+        PersistedQueries::from_config(&config, &Limits::default()).unwrap()
+    }
+
+    async fn execute(persisted: PersistedQueries, request: Request) -> Vec<String> {
+        let resp = Schema::build(Query, Mutation, EmptySubscription)
+            .data(ServiceConfig::default())
+            .extension(persisted)
+            .finish()
+            .execute(request)
+            .await;
+
+        resp.errors.into_iter().map(|e| e.message).collect()
+    }
+
+    fn by_hash(query: &str, hash: &str) -> Request {
+        let mut request = Request::new(query);
+        request.extensions.insert(
+            PERSISTED_QUERY.to_string(),
+            Value::from_json(json!({ "version": 1, "sha256Hash": hash })).unwrap(),
+        );
+        request
+    }
+
+    #[tokio::test]
+    async fn test_persisted_query_by_hash() {
+        let errs = execute(persisted(false), by_hash("", &hash(QUERY))).await;
+        assert!(errs.is_empty(), "{errs:?}");
+    }
+
+    #[tokio::test]
+    async fn test_persisted_query_not_found() {
+        let errs = execute(
+            persisted(false),
+            by_hash("", &hash("{ chainIdentifier }")),
+        )
+        .await;
+
+        let expect = expect![[r#"
+            [
+                "PersistedQueryNotFound",
+            ]"#]];
+        expect.assert_eq(&format!("{errs:#?}"));
+    }
+
+    #[tokio::test]
+    async fn test_persisted_query_mismatch() {
+        let errs = execute(
+            persisted(false),
+            by_hash("{ chainIdentifier }", &hash(QUERY)),
+        )
+        .await;
+
+        let expect = expect![[r#"
+            [
+                "Query does not match its persisted query hash",
+            ]"#]];
+        expect.assert_eq(&format!("{errs:#?}"));
+    }
+
+    #[tokio::test]
+    async fn test_strict_mode() {
+        // Registered queries are accepted in full.
+        let errs = execute(persisted(true), Request::new(QUERY)).await;
+        assert!(errs.is_empty(), "{errs:?}");
+
+        let errs = execute(
+            persisted(true),
+            Request::new("{ serviceConfig { maxQueryNodes } }"),
+        )
+        .await;
+
+        let expect = expect![[r#"
+            [
+                "Only persisted queries are accepted by this service",
+            ]"#]];
+        expect.assert_eq(&format!("{errs:#?}"));
+    }
+
+    #[test]
+    fn test_limits_checked_on_load() {
+        let dir = query_dir(&[QUERY]);
+        let config = PersistedQueriesConfig {
+            directory: Some(dir.path().to_path_buf()),
+            strict: false,
+        };
+
+        let limits = Limits {
+            max_query_depth: 1,
+            ..Default::default()
+        };
+
+        let Err(Error::Internal(err)) = PersistedQueries::from_config(&config, &limits) else {
+            panic!("Expected persisted query to exceed limits");
+        };
+
+        assert!(
+            err.ends_with("exceeds limits: Query nesting is over 1"),
+            "{err}"
+        );
+    }
+}
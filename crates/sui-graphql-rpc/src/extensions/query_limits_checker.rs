@@ -670,6 +670,82 @@ impl Usage {
     }
 }
 
+/// Check that `doc` (parsed from `query`) is within `limits`, outside of the context of a request
+/// (e.g. to validate queries ahead of time). Variables are treated as unbound, so connections whose
+/// page sizes are supplied by variables are assumed to use the default page size.
+pub(crate) fn check_document_limits(
+    limits: &Limits,
+    query: &str,
+    doc: &ExecutableDocument,
+) -> ServerResult<()> {
+    let query_id = Uuid::nil();
+    let session_id = SocketAddr::from(([0, 0, 0, 0], 0));
+    let reporter = Reporter {
+        limits,
+        query_id: &query_id,
+        session_id: &session_id,
+    };
+
+    let variables = Variables::default();
+    let mut traversal = LimitsTraversal::new(
+        PayloadSize(query.len() as u64),
+        &reporter,
+        &doc.fragments,
+        &variables,
+    );
+
+    traversal.check_document(doc)
+}
+
+/// Estimate the number of output nodes `doc` could produce, in the worst case, without enforcing
+/// any limits. Documents that are too large or too deep to estimate are assumed to use the entire
+/// output node budget.
+pub(crate) fn estimate_output_nodes(
+    limits: &Limits,
+    doc: &ExecutableDocument,
+    variables: &Variables,
+) -> u32 {
+    let query_id = Uuid::nil();
+    let session_id = SocketAddr::from(([0, 0, 0, 0], 0));
+    let reporter = Reporter {
+        limits,
+        query_id: &query_id,
+        session_id: &session_id,
+    };
+
+    let mut traversal = LimitsTraversal::new(PayloadSize(0), &reporter, &doc.fragments, variables);
+
+    // Output node estimation recurses through the document, so it is only safe to run once the
+    // input limits (which bound the query's depth) have been checked.
+    for (_name, op) in doc.operations.iter() {
+        if traversal.check_input_limits(op).is_err() {
+            return limits.max_output_nodes;
+        }
+    }
+
+    for (_name, op) in doc.operations.iter() {
+        if traversal.check_output_limits(op).is_err() {
+            return limits.max_output_nodes;
+        }
+    }
+
+    limits.max_output_nodes - traversal.output_budget
+}
+
+/// Pure introspection queries are queries that only have one operation with one field and that
+/// field is a `__schema` query.
+pub(crate) fn is_introspection(doc: &ExecutableDocument) -> bool {
+    let DocumentOperations::Single(op) = &doc.operations else {
+        return false;
+    };
+
+    let [field] = &op.node.selection_set.node.items[..] else {
+        return false;
+    };
+
+    matches!(&field.node, Selection::Field(f) if f.node.name.node == "__schema")
+}
+
 impl ExtensionFactory for QueryLimitsChecker {
     fn create(&self) -> Arc<dyn Extension> {
         Arc::new(QueryLimitsCheckerExt {
@@ -700,7 +776,12 @@ impl Extension for QueryLimitsCheckerExt {
         next: NextParseQuery<'_>,
     ) -> ServerResult<ExecutableDocument> {
         let metrics: &Metrics = ctx.data_unchecked();
-        let payload_size: &PayloadSize = ctx.data_unchecked();
+        // Requests over a WebSocket (subscriptions) do not come with a `Content-Length`, so fall
+        // back to the size of the query itself.
+        let payload_size = ctx
+            .data_opt::<PayloadSize>()
+            .copied()
+            .unwrap_or(PayloadSize(query.len() as u64));
         let reporter = Reporter::new(ctx);
 
         let instant = Instant::now();
@@ -717,33 +798,25 @@ impl Extension for QueryLimitsCheckerExt {
         // Document layout of the query
         let doc = next.run(ctx, query, variables).await?;
 
-        // If the query is pure introspection, we don't need to check the limits. Pure introspection
-        // queries are queries that only have one operation with one field and that field is a
-        // `__schema` query
-        if let DocumentOperations::Single(op) = &doc.operations {
-            if let [field] = &op.node.selection_set.node.items[..] {
-                if let Selection::Field(f) = &field.node {
-                    if f.node.name.node == "__schema" {
-                        return Ok(doc);
-                    }
-                }
-            }
+        // If the query is pure introspection, we don't need to check the limits.
+        if is_introspection(&doc) {
+            return Ok(doc);
         }
 
         let mut traversal =
-            LimitsTraversal::new(*payload_size, &reporter, &doc.fragments, variables);
+            LimitsTraversal::new(payload_size, &reporter, &doc.fragments, variables);
 
         let res = traversal.check_document(&doc);
         let usage = traversal.finish(query.len() as u32);
         metrics.query_validation_latency(instant.elapsed());
         usage.report(metrics);
 
-        res.map(|()| {
-            if ctx.data_opt::<ShowUsage>().is_some() {
-                *self.usage.lock().unwrap() = Some(usage);
-            }
+        res?;
 
-            doc
-        })
+        if ctx.data_opt::<ShowUsage>().is_some() {
+            *self.usage.lock().unwrap() = Some(usage);
+        }
+
+        Ok(doc)
     }
 }
@@ -0,0 +1,596 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+
+use async_graphql::{
+    extensions::{
+        Extension, ExtensionContext, ExtensionFactory, NextExecute, NextParseQuery,
+        NextPrepareRequest, NextResolve, ResolveInfo,
+    },
+    parser::types::{ExecutableDocument, OperationType, Selection, SelectionSet},
+    Context, Request, Response, ServerResult, Value, Variables,
+};
+use async_trait::async_trait;
+use fastcrypto::hash::{HashFunction, Sha256};
+use lru::LruCache;
+
+use crate::{
+    config::ResponseCacheConfig, consistency::Checkpointed, metrics::Metrics,
+    server::watermark_task::Watermark, types::cursor::Page,
+};
+
+/// Fields whose results do not only depend on the checkpoint a request is served at (e.g. they
+/// depend on the request's API key). Requests that select any of these fields are never cached.
+const UNCACHEABLE_FIELDS: &[&str] = &["apiKeyUsage", "dryRunTransactionBlock"];
+
+/// Cache of query responses, shared between all requests.
+///
+/// Responses are keyed on the checkpoint they were resolved at, so a query (and its variables)
+/// resolved at a given checkpoint will always produce the same response, and cached responses
+/// never need to be refreshed:
+///
+/// - Most requests are resolved at the checkpoint watermark at the time they were received. Once
+///   the watermark moves past a checkpoint, no new request can be resolved at it, so the watermark
+///   task invalidates these responses to make room.
+/// - Requests that only paginate from cursors taken at an earlier checkpoint are resolved at that
+///   checkpoint, regardless of the watermark, so their responses stay valid as the watermark
+///   advances, and are only evicted to stay within the cache's size limits, or once their
+///   checkpoint has been pruned.
+#[derive(Clone)]
+pub(crate) struct ResponseCache {
+    inner: Arc<Mutex<Inner>>,
+    config: ResponseCacheConfig,
+}
+
+struct Inner {
+    responses: LruCache<CacheKey, CachedResponse>,
+    /// The checkpoints that requests are pinned to by their cursors, by request digest. A request's
+    /// cursors are part of its digest, so it is always pinned to the same checkpoint. Only contains
+    /// entries for requests whose responses are in the cache.
+    pinned: HashMap<[u8; 32], u64>,
+    /// Total size in bytes of the responses in the cache.
+    size: usize,
+    /// Responses resolved at the watermark, for checkpoints before this one, will never be read,
+    /// so are not cached.
+    watermark: u64,
+    /// Responses for checkpoints at or below this one can no longer be served consistently, because
+    /// their checkpoint has been pruned, so they are neither served nor cached.
+    pruned: Option<u64>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct CacheKey {
+    /// The checkpoint the response was resolved at.
+    checkpoint: u64,
+    /// Hash of the query, its variables, and the name of the operation being executed.
+    digest: [u8; 32],
+}
+
+struct CachedResponse {
+    data: Value,
+    extensions: BTreeMap<String, Value>,
+    /// Size of `data` and `extensions` in bytes, as measured by their JSON serialization.
+    size: usize,
+    /// Whether the request was pinned to `CacheKey::checkpoint` by its cursors, rather than
+    /// resolved at the watermark.
+    pinned: bool,
+}
+
+/// Tracks the checkpoint that a request is resolved at, as its root fields are resolved. It is
+/// added to the data of every request, for root fields to record the checkpoint they are pinned
+/// to.
+#[derive(Clone, Default)]
+pub(crate) struct ResolvedCheckpoint(Arc<Mutex<Resolution>>);
+
+#[derive(Default)]
+struct Resolution {
+    /// The number of root fields resolved so far.
+    root_fields: usize,
+    /// The checkpoint each root field that paginates from a cursor is pinned to.
+    pins: Vec<u64>,
+}
+
+/// Extension that serves queries from a `ResponseCache`. A new instance is created per request,
+/// to hold the hash of its query and variables, between parsing and execution, and to track the
+/// checkpoint it is resolved at.
+struct ResponseCacheExtension {
+    cache: ResponseCache,
+    request: Mutex<Option<Sha256>>,
+    resolved: ResolvedCheckpoint,
+}
+
+impl ResponseCache {
+    pub(crate) fn new(config: ResponseCacheConfig) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(Inner {
+                responses: LruCache::unbounded(),
+                pinned: HashMap::new(),
+                size: 0,
+                watermark: 0,
+                pruned: None,
+            })),
+            config,
+        }
+    }
+
+    /// Drop all responses resolved at the watermark, for checkpoints before `checkpoint`. Called by
+    /// the watermark task whenever the checkpoint watermark advances. Responses to requests pinned
+    /// to a checkpoint are unaffected.
+    pub(crate) fn invalidate_before(&self, checkpoint: u64) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.watermark = inner.watermark.max(checkpoint);
+
+        let stale: Vec<_> = inner
+            .responses
+            .iter()
+            .filter(|(key, cached)| !cached.pinned && key.checkpoint < checkpoint)
+            .map(|(key, _)| *key)
+            .collect();
+
+        for key in stale {
+            inner.remove(&key);
+        }
+    }
+
+    /// Drop all responses (including those pinned to a checkpoint) for checkpoints at or below
+    /// `checkpoint`, which has been pruned. Called by the watermark task whenever the lower bound
+    /// of the available range advances.
+    pub(crate) fn invalidate_pruned(&self, checkpoint: u64) {
+        let mut inner = self.inner.lock().unwrap();
+        let pruned = inner.pruned.map_or(checkpoint, |p| p.max(checkpoint));
+        inner.pruned = Some(pruned);
+
+        let stale: Vec<_> = inner
+            .responses
+            .iter()
+            .filter(|(key, _)| key.checkpoint <= pruned)
+            .map(|(key, _)| *key)
+            .collect();
+
+        for key in stale {
+            inner.remove(&key);
+        }
+    }
+
+    /// Look up the response to the request with `digest`, if it was received at `watermark`.
+    fn get(&self, digest: [u8; 32], watermark: u64) -> Option<Response> {
+        let mut inner = self.inner.lock().unwrap();
+        let checkpoint = inner.pinned.get(&digest).copied().unwrap_or(watermark);
+        if inner.is_pruned(checkpoint) {
+            return None;
+        }
+
+        let key = CacheKey { checkpoint, digest };
+        let cached = inner.responses.get(&key)?;
+
+        let mut resp = Response::new(cached.data.clone());
+        resp.extensions = cached.extensions.clone();
+        Some(resp)
+    }
+
+    fn insert(&self, key: CacheKey, pinned: bool, resp: &Response) {
+        let size = serde_json::to_vec(&resp.data).map_or(0, |bytes| bytes.len())
+            + serde_json::to_vec(&resp.extensions).map_or(0, |bytes| bytes.len());
+        if size > self.config.max_entry_size_bytes {
+            return;
+        }
+
+        let mut inner = self.inner.lock().unwrap();
+        if (!pinned && key.checkpoint < inner.watermark) || inner.is_pruned(key.checkpoint) {
+            return;
+        }
+
+        inner.remove(&key);
+        if pinned {
+            inner.pinned.insert(key.digest, key.checkpoint);
+        }
+
+        inner.size += size;
+        inner.responses.put(
+            key,
+            CachedResponse {
+                data: resp.data.clone(),
+                extensions: resp.extensions.clone(),
+                size,
+                pinned,
+            },
+        );
+
+        while inner.size > self.config.max_size_bytes
+            || inner.responses.len() > self.config.max_entries
+        {
+            let Some(key) = inner.responses.peek_lru().map(|(key, _)| *key) else {
+                break;
+            };
+
+            inner.remove(&key);
+        }
+    }
+}
+
+impl Inner {
+    fn is_pruned(&self, checkpoint: u64) -> bool {
+        self.pruned.is_some_and(|pruned| checkpoint <= pruned)
+    }
+
+    fn remove(&mut self, key: &CacheKey) {
+        let Some(removed) = self.responses.pop(key) else {
+            return;
+        };
+
+        self.size -= removed.size;
+        if removed.pinned {
+            self.pinned.remove(&key.digest);
+        }
+    }
+}
+
+impl ResolvedCheckpoint {
+    /// Record that a root field paginates through `page`. If the page has cursors, the field is
+    /// pinned to the checkpoint they were taken at (which is validated separately, when the page is
+    /// fetched). Does nothing if responses are not being cached.
+    pub(crate) fn pin_to_page<C: Checkpointed>(ctx: &Context<'_>, page: &Page<C>) {
+        let Some(resolved) = ctx.data_opt::<ResolvedCheckpoint>() else {
+            return;
+        };
+
+        if let Ok(Some(checkpoint)) = page.validate_cursor_consistency() {
+            resolved.0.lock().unwrap().pins.push(checkpoint);
+        }
+    }
+
+    /// The checkpoint the request is pinned to: If every root field was pinned to the same
+    /// checkpoint, the response does not depend on the watermark.
+    fn pinned(&self) -> Option<u64> {
+        let resolution = self.0.lock().unwrap();
+        let (&first, rest) = resolution.pins.split_first()?;
+        (resolution.root_fields == resolution.pins.len() && rest.iter().all(|cp| *cp == first))
+            .then_some(first)
+    }
+}
+
+impl ExtensionFactory for ResponseCache {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(ResponseCacheExtension {
+            cache: self.clone(),
+            request: Mutex::new(None),
+            resolved: ResolvedCheckpoint::default(),
+        })
+    }
+}
+
+#[async_trait]
+impl Extension for ResponseCacheExtension {
+    async fn prepare_request(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        request: Request,
+        next: NextPrepareRequest<'_>,
+    ) -> ServerResult<Request> {
+        next.run(ctx, request.data(self.resolved.clone())).await
+    }
+
+    async fn parse_query(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        query: &str,
+        variables: &Variables,
+        next: NextParseQuery<'_>,
+    ) -> ServerResult<ExecutableDocument> {
+        let doc = next.run(ctx, query, variables).await?;
+
+        if is_cacheable(&doc) {
+            let mut hasher = Sha256::default();
+            hash_bytes(&mut hasher, query.as_bytes());
+            // Variables are not guaranteed to serialize in a canonical order, so equivalent
+            // requests may occasionally miss the cache, but they will never collide.
+            hash_bytes(
+                &mut hasher,
+                &serde_json::to_vec(variables).unwrap_or_default(),
+            );
+            *self.request.lock().unwrap() = Some(hasher);
+        }
+
+        Ok(doc)
+    }
+
+    async fn execute(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        operation_name: Option<&str>,
+        next: NextExecute<'_>,
+    ) -> Response {
+        let request = self.request.lock().unwrap().take();
+        let (Some(mut hasher), Some(watermark)) = (request, ctx.data_opt::<Watermark>()) else {
+            return next.run(ctx, operation_name).await;
+        };
+
+        hash_bytes(&mut hasher, operation_name.unwrap_or_default().as_bytes());
+        let digest = hasher.finalize().digest;
+
+        let metrics = ctx.data_opt::<Metrics>();
+        if let Some(resp) = self.cache.get(digest, watermark.checkpoint) {
+            if let Some(metrics) = metrics {
+                metrics.request_metrics.response_cache_hits.inc();
+            }
+
+            return resp;
+        }
+
+        if let Some(metrics) = metrics {
+            metrics.request_metrics.response_cache_misses.inc();
+        }
+
+        let resp = next.run(ctx, operation_name).await;
+        if resp.errors.is_empty() {
+            let pinned = self.resolved.pinned();
+            let key = CacheKey {
+                checkpoint: pinned.unwrap_or(watermark.checkpoint),
+                digest,
+            };
+
+            self.cache.insert(key, pinned.is_some(), &resp);
+        }
+
+        resp
+    }
+
+    async fn resolve(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        info: ResolveInfo<'_>,
+        next: NextResolve<'_>,
+    ) -> ServerResult<Option<Value>> {
+        if info.path_node.parent.is_none() {
+            self.resolved.0.lock().unwrap().root_fields += 1;
+        }
+
+        next.run(ctx, info).await
+    }
+}
+
+/// Add `bytes` to the hash, prefixed by their length, so that the boundaries between consecutive
+/// inputs are unambiguous.
+fn hash_bytes(hasher: &mut Sha256, bytes: &[u8]) {
+    hasher.update((bytes.len() as u64).to_le_bytes());
+    hasher.update(bytes);
+}
+
+/// Whether responses to this document can be cached: It must only contain queries, and none of
+/// them (or their fragments) can select any of the `UNCACHEABLE_FIELDS`.
+fn is_cacheable(doc: &ExecutableDocument) -> bool {
+    doc.operations.iter().all(|(_, op)| {
+        op.node.ty == OperationType::Query && is_cacheable_selection(&op.node.selection_set.node)
+    }) && doc
+        .fragments
+        .values()
+        .all(|fragment| is_cacheable_selection(&fragment.node.selection_set.node))
+}
+
+/// Fragment spreads are not followed, because every fragment in the document is checked
+/// separately.
+fn is_cacheable_selection(selection_set: &SelectionSet) -> bool {
+    selection_set
+        .items
+        .iter()
+        .all(|selection| match &selection.node {
+            Selection::Field(field) => {
+                !UNCACHEABLE_FIELDS.contains(&field.node.name.node.as_str())
+                    && is_cacheable_selection(&field.node.selection_set.node)
+            }
+            Selection::InlineFragment(fragment) => {
+                is_cacheable_selection(&fragment.node.selection_set.node)
+            }
+            Selection::FragmentSpread(_) => true,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use async_graphql::{EmptySubscription, Request, Schema};
+
+    use crate::{config::ServiceConfig, mutation::Mutation, types::query::Query};
+
+    use super::*;
+
+    fn watermark(checkpoint: u64) -> Watermark {
+        Watermark {
+            checkpoint,
+            checkpoint_timestamp_ms: 0,
+            epoch: 0,
+        }
+    }
+
+    /// Execute `query` at `checkpoint` against a schema whose service config has the given
+    /// `max_query_depth`, so that responses from the cache can be told apart from fresh ones.
+    async fn execute(cache: &ResponseCache, checkpoint: u64, max_query_depth: u32) -> String {
+        let mut service = ServiceConfig::default();
+        service.limits.max_query_depth = max_query_depth;
+
+        let resp = Schema::build(Query, Mutation, EmptySubscription)
+            .data(service)
+            .extension(cache.clone())
+            .finish()
+            .execute(
+                Request::new("{ serviceConfig { maxQueryDepth } }").data(watermark(checkpoint)),
+            )
+            .await;
+
+        assert!(resp.errors.is_empty(), "{:?}", resp.errors);
+        serde_json::to_string(&resp.data).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_cache_hit_at_same_checkpoint() {
+        let cache = ResponseCache::new(ResponseCacheConfig::default());
+
+        let fresh = execute(&cache, 1, 10).await;
+        let cached = execute(&cache, 1, 20).await;
+        assert_eq!(fresh, cached);
+        assert_eq!(cached, r#"{"serviceConfig":{"maxQueryDepth":10}}"#);
+    }
+
+    #[tokio::test]
+    async fn test_cache_miss_at_new_checkpoint() {
+        let cache = ResponseCache::new(ResponseCacheConfig::default());
+
+        let fresh = execute(&cache, 1, 10).await;
+        let newer = execute(&cache, 2, 20).await;
+        assert_ne!(fresh, newer);
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_before() {
+        let cache = ResponseCache::new(ResponseCacheConfig::default());
+
+        execute(&cache, 1, 10).await;
+        execute(&cache, 2, 10).await;
+        cache.invalidate_before(2);
+
+        let inner = cache.inner.lock().unwrap();
+        assert_eq!(inner.responses.len(), 1);
+        assert!(inner.responses.iter().all(|(key, _)| key.checkpoint == 2));
+    }
+
+    #[tokio::test]
+    async fn test_size_limits() {
+        let cache = ResponseCache::new(ResponseCacheConfig {
+            max_entries: 2,
+            ..Default::default()
+        });
+
+        for checkpoint in 0..4 {
+            execute(&cache, checkpoint, 10).await;
+        }
+
+        let inner = cache.inner.lock().unwrap();
+        assert_eq!(inner.responses.len(), 2);
+        assert!(inner.responses.iter().all(|(key, _)| key.checkpoint >= 2));
+
+        // Entries that are too large are not cached at all.
+        let cache = ResponseCache::new(ResponseCacheConfig {
+            max_entry_size_bytes: 1,
+            ..Default::default()
+        });
+
+        execute(&cache, 1, 10).await;
+        assert_eq!(cache.inner.lock().unwrap().responses.len(), 0);
+    }
+
+    fn response(data: &str) -> Response {
+        Response::new(Value::from(data))
+    }
+
+    fn cached(cache: &ResponseCache, digest: [u8; 32], watermark: u64) -> Option<Value> {
+        cache.get(digest, watermark).map(|resp| resp.data)
+    }
+
+    #[test]
+    fn test_pinned_responses_survive_invalidation() {
+        let cache = ResponseCache::new(ResponseCacheConfig::default());
+        let (pinned, unpinned) = ([1u8; 32], [2u8; 32]);
+
+        let key = |digest| CacheKey {
+            checkpoint: 1,
+            digest,
+        };
+
+        cache.insert(key(pinned), true, &response("pinned"));
+        cache.insert(key(unpinned), false, &response("unpinned"));
+        cache.invalidate_before(5);
+
+        // The pinned response is served to requests received at any later watermark.
+        assert_eq!(cached(&cache, pinned, 5), Some(Value::from("pinned")));
+        assert_eq!(cached(&cache, pinned, 6), Some(Value::from("pinned")));
+        assert_eq!(cached(&cache, unpinned, 1), None);
+        assert_eq!(cached(&cache, unpinned, 5), None);
+
+        // Responses resolved at a watermark that has already passed are not cached.
+        cache.insert(key(unpinned), false, &response("unpinned"));
+        assert_eq!(cached(&cache, unpinned, 1), None);
+
+        // Evicting a pinned response forgets its pin.
+        let inner = &mut *cache.inner.lock().unwrap();
+        inner.remove(&key(pinned));
+        assert!(inner.pinned.is_empty());
+        assert_eq!(inner.size, 0);
+    }
+
+    #[test]
+    fn test_pruned_responses_are_evicted() {
+        let cache = ResponseCache::new(ResponseCacheConfig::default());
+        let key = |checkpoint, digest| CacheKey { checkpoint, digest };
+
+        cache.insert(key(1, [1u8; 32]), true, &response("one"));
+        cache.insert(key(2, [2u8; 32]), true, &response("two"));
+        cache.invalidate_pruned(1);
+
+        // Pinned responses at or below the pruned checkpoint are dropped, but later ones are kept.
+        assert_eq!(cached(&cache, [1u8; 32], 5), None);
+        assert_eq!(cached(&cache, [2u8; 32], 5), Some(Value::from("two")));
+
+        // Responses for pruned checkpoints are not cached again.
+        cache.insert(key(1, [1u8; 32]), true, &response("one"));
+        assert_eq!(cached(&cache, [1u8; 32], 5), None);
+
+        let inner = cache.inner.lock().unwrap();
+        assert_eq!(inner.responses.len(), 1);
+        assert_eq!(inner.pinned.len(), 1);
+    }
+
+    #[test]
+    fn test_cache_hit_keeps_extensions() {
+        let cache = ResponseCache::new(ResponseCacheConfig::default());
+        let key = CacheKey {
+            checkpoint: 1,
+            digest: [1u8; 32],
+        };
+
+        let resp = response("data").extension("usage", Value::from(42));
+        cache.insert(key, false, &resp);
+
+        let hit = cache.get([1u8; 32], 1).unwrap();
+        assert_eq!(hit.data, Value::from("data"));
+        assert_eq!(hit.extensions.get("usage"), Some(&Value::from(42)));
+    }
+
+    #[test]
+    fn test_resolved_checkpoint() {
+        let resolved = ResolvedCheckpoint::default();
+        assert_eq!(resolved.pinned(), None);
+
+        {
+            let mut resolution = resolved.0.lock().unwrap();
+            resolution.root_fields = 2;
+            resolution.pins.push(3);
+        }
+
+        // One of the root fields is resolved at the watermark.
+        assert_eq!(resolved.pinned(), None);
+
+        resolved.0.lock().unwrap().pins.push(3);
+        assert_eq!(resolved.pinned(), Some(3));
+
+        // Root fields are pinned to different checkpoints.
+        resolved.0.lock().unwrap().pins[1] = 4;
+        assert_eq!(resolved.pinned(), None);
+    }
+
+    #[test]
+    fn test_dry_run_is_not_cacheable() {
+        let doc = async_graphql::parser::parse_query(
+            "query { ...F } fragment F on Query { dryRunTransactionBlock(txBytes: \"\") { error } }",
+        )
+        .unwrap();
+
+        assert!(!is_cacheable(&doc));
+    }
+
+    #[test]
+    fn test_api_key_usage_is_not_cacheable() {
+        let doc = async_graphql::parser::parse_query("{ apiKeyUsage { cost } }").unwrap();
+        assert!(!is_cacheable(&doc));
+    }
+}
@@ -79,6 +79,16 @@ pub(crate) struct RequestMetrics {
     pub num_queries_top_level: IntCounterVec,
     /// Total inflight requests
     pub inflight_requests: Gauge,
+    /// Number of requests served from the response cache
+    pub response_cache_hits: IntCounter,
+    /// Number of cacheable requests that missed the response cache
+    pub response_cache_misses: IntCounter,
+    /// Number of requests by API key name
+    pub api_key_requests: IntCounterVec,
+    /// Total estimated cost of requests by API key name
+    pub api_key_cost: IntCounterVec,
+    /// Number of requests rejected for exceeding their API key's budget, by API key name
+    pub api_key_rejections: IntCounterVec,
 }
 
 impl Metrics {
@@ -249,6 +259,39 @@ impl RequestMetrics {
                 registry
             )
             .unwrap(),
+            response_cache_hits: register_int_counter_with_registry!(
+                "response_cache_hits",
+                "Number of requests served from the response cache",
+                registry
+            )
+            .unwrap(),
+            response_cache_misses: register_int_counter_with_registry!(
+                "response_cache_misses",
+                "Number of cacheable requests that missed the response cache",
+                registry
+            )
+            .unwrap(),
+            api_key_requests: register_int_counter_vec_with_registry!(
+                "api_key_requests",
+                "Number of requests by API key name",
+                &["key"],
+                registry
+            )
+            .unwrap(),
+            api_key_cost: register_int_counter_vec_with_registry!(
+                "api_key_cost",
+                "Total estimated cost (in output nodes) of requests by API key name",
+                &["key"],
+                registry
+            )
+            .unwrap(),
+            api_key_rejections: register_int_counter_vec_with_registry!(
+                "api_key_rejections",
+                "Number of requests rejected for exceeding their API key's budget",
+                &["key"],
+                registry
+            )
+            .unwrap(),
         }
     }
 }
@@ -0,0 +1,319 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use async_graphql::{ServerError, ServerResult};
+use axum::http::HeaderName;
+use sui_graphql_rpc_headers::API_KEY_HEADER;
+use tracing::info;
+
+use crate::{
+    config::{ApiKeyConfig, ApiKeysConfig},
+    error::{code, graphql_error},
+    metrics::Metrics,
+    types::{api_key_usage::ApiKeyUsage, date_time::DateTime},
+};
+
+/// The API key a request was made with, as it comes from the request header.
+#[derive(Clone, Debug)]
+pub(crate) struct ApiKey(pub String);
+
+/// Tracks the estimated cost of the requests made with each API key, and enforces each key's
+/// budget. Budgets apply to fixed windows of time, aligned to the UNIX epoch, and are reset at the
+/// start of each window.
+#[derive(Clone)]
+pub(crate) struct ApiKeyQuotas {
+    config: ApiKeysConfig,
+    usage: Arc<Mutex<HashMap<String, WindowUsage>>>,
+    clock: Clock,
+}
+
+/// Source of the current time, in milliseconds since the UNIX epoch.
+type Clock = Arc<dyn Fn() -> u64 + Send + Sync>;
+
+/// Usage of an API key within a single window.
+#[derive(Clone, Copy, Debug, Default)]
+struct WindowUsage {
+    window_start_ms: u64,
+    cost: u64,
+    requests: u64,
+    rejected_requests: u64,
+}
+
+impl ApiKey {
+    pub(crate) fn name() -> &'static HeaderName {
+        &API_KEY_HEADER
+    }
+}
+
+impl ApiKeyQuotas {
+    /// Create a tracker for the keys in `config`, or `None` if there are no keys to track and they
+    /// are not required (in which case API keys are ignored).
+    pub(crate) fn from_config(config: &ApiKeysConfig) -> Option<Self> {
+        if !config.required && config.keys.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            config: config.clone(),
+            usage: Arc::new(Mutex::new(HashMap::new())),
+            clock: Arc::new(|| {
+                SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |d| d.as_millis() as u64)
+            }),
+        })
+    }
+
+    /// Replace the source of the current time, so that tests control when windows roll over.
+    #[cfg(test)]
+    pub(crate) fn with_clock(mut self, clock: impl Fn() -> u64 + Send + Sync + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Charge `cost` to the budget of `key` for the current window. Fails if the key is missing
+    /// (and keys are required), if the key is not recognized, or if the charge would take the key
+    /// over its budget, in which case nothing is charged.
+    pub(crate) fn charge(
+        &self,
+        key: Option<&ApiKey>,
+        cost: u64,
+        metrics: &Metrics,
+    ) -> ServerResult<()> {
+        let Some(ApiKey(key)) = key else {
+            return if self.config.required {
+                Err(graphql_error(
+                    code::UNAUTHENTICATED,
+                    format!("Requests must include an API key in the {API_KEY_HEADER} header"),
+                ))
+            } else {
+                Ok(())
+            };
+        };
+
+        let Some(ApiKeyConfig { name, budget }) = self.config.keys.get(key) else {
+            return Err(graphql_error(code::UNAUTHENTICATED, "Unrecognized API key"));
+        };
+
+        let labels = [name.as_str()];
+        let request_metrics = &metrics.request_metrics;
+        request_metrics
+            .api_key_requests
+            .with_label_values(&labels)
+            .inc();
+
+        let mut usage = self.usage.lock().unwrap();
+        let usage = self.current(usage.entry(key.clone()).or_default());
+
+        if usage.cost.saturating_add(cost) > *budget {
+            usage.rejected_requests += 1;
+            request_metrics
+                .api_key_rejections
+                .with_label_values(&labels)
+                .inc();
+
+            info!(key = %name, cost, "API key budget exceeded");
+            return Err(self.quota_error(name, *budget));
+        }
+
+        usage.cost += cost;
+        usage.requests += 1;
+        request_metrics
+            .api_key_cost
+            .with_label_values(&labels)
+            .inc_by(cost);
+
+        Ok(())
+    }
+
+    /// Report the usage of `key` in the current window, if it is a recognized key.
+    pub(crate) fn usage(&self, ApiKey(key): &ApiKey) -> Option<ApiKeyUsage> {
+        let ApiKeyConfig { name, budget } = self.config.keys.get(key)?;
+
+        let mut usage = self.usage.lock().unwrap();
+        let usage = *self.current(usage.entry(key.clone()).or_default());
+
+        Some(ApiKeyUsage {
+            name: name.clone(),
+            window_start: DateTime::from_ms(usage.window_start_ms as i64).ok()?,
+            window_ms: self.config.window_ms.into(),
+            budget: (*budget).into(),
+            cost: usage.cost.into(),
+            requests: usage.requests.into(),
+            rejected_requests: usage.rejected_requests.into(),
+        })
+    }
+
+    /// Reset `usage` if its window has passed.
+    fn current<'u>(&self, usage: &'u mut WindowUsage) -> &'u mut WindowUsage {
+        let window_ms = self.config.window_ms.max(1);
+        let now_ms = (self.clock)();
+
+        let window_start_ms = now_ms - now_ms % window_ms;
+        if usage.window_start_ms != window_start_ms {
+            *usage = WindowUsage {
+                window_start_ms,
+                ..Default::default()
+            };
+        }
+
+        usage
+    }
+
+    fn quota_error(&self, name: &str, budget: u64) -> ServerError {
+        graphql_error(
+            code::QUOTA_EXCEEDED,
+            format!(
+                "API key for {name} has exceeded its budget of {budget} output nodes per {} ms",
+                self.config.window_ms,
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::BTreeMap,
+        sync::atomic::{AtomicU64, Ordering},
+    };
+
+    use prometheus::Registry;
+
+    use super::*;
+
+    const WINDOW_MS: u64 = 24 * 60 * 60 * 1000;
+
+    /// Quotas whose clock is stopped at the start of a window, unless it is moved with `clock`.
+    fn quotas_with_clock(required: bool, clock: Arc<AtomicU64>) -> ApiKeyQuotas {
+        ApiKeyQuotas::from_config(&ApiKeysConfig {
+            required,
+            window_ms: WINDOW_MS,
+            keys: BTreeMap::from([(
+                "secret".to_string(),
+                ApiKeyConfig {
+                    name: "team".to_string(),
+                    budget: 100,
+                },
+            )]),
+        })
+        .unwrap()
+        .with_clock(move || clock.load(Ordering::Relaxed))
+    }
+
+    fn quotas(required: bool) -> ApiKeyQuotas {
+        quotas_with_clock(required, Arc::new(AtomicU64::new(WINDOW_MS)))
+    }
+
+    fn key(key: &str) -> ApiKey {
+        ApiKey(key.to_string())
+    }
+
+    #[test]
+    fn test_disabled_without_keys() {
+        assert!(ApiKeyQuotas::from_config(&ApiKeysConfig::default()).is_none());
+    }
+
+    #[test]
+    fn test_budget() {
+        let metrics = Metrics::new(&Registry::new());
+        let quotas = quotas(false);
+
+        quotas.charge(Some(&key("secret")), 60, &metrics).unwrap();
+        quotas.charge(Some(&key("secret")), 40, &metrics).unwrap();
+
+        let err = quotas
+            .charge(Some(&key("secret")), 1, &metrics)
+            .unwrap_err();
+        assert_eq!(
+            err.message,
+            "API key for team has exceeded its budget of 100 output nodes per 86400000 ms",
+        );
+
+        let usage = quotas.usage(&key("secret")).unwrap();
+        assert_eq!(u64::from(usage.cost), 100);
+        assert_eq!(u64::from(usage.requests), 2);
+        assert_eq!(u64::from(usage.rejected_requests), 1);
+
+        let labels = ["team"];
+        let request_metrics = &metrics.request_metrics;
+        assert_eq!(
+            request_metrics
+                .api_key_requests
+                .with_label_values(&labels)
+                .get(),
+            3
+        );
+        assert_eq!(
+            request_metrics
+                .api_key_cost
+                .with_label_values(&labels)
+                .get(),
+            100
+        );
+        assert_eq!(
+            request_metrics
+                .api_key_rejections
+                .with_label_values(&labels)
+                .get(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_window_rollover() {
+        let metrics = Metrics::new(&Registry::new());
+        let clock = Arc::new(AtomicU64::new(WINDOW_MS));
+        let quotas = quotas_with_clock(false, clock.clone());
+
+        quotas.charge(Some(&key("secret")), 100, &metrics).unwrap();
+        quotas
+            .charge(Some(&key("secret")), 1, &metrics)
+            .unwrap_err();
+
+        // Moving within the window does not reset the budget.
+        clock.store(2 * WINDOW_MS - 1, Ordering::Relaxed);
+        quotas
+            .charge(Some(&key("secret")), 1, &metrics)
+            .unwrap_err();
+
+        // Moving into the next window does.
+        clock.store(2 * WINDOW_MS, Ordering::Relaxed);
+        quotas.charge(Some(&key("secret")), 30, &metrics).unwrap();
+
+        let usage = quotas.usage(&key("secret")).unwrap();
+        assert_eq!(
+            usage.window_start,
+            DateTime::from_ms(2 * WINDOW_MS as i64).unwrap()
+        );
+        assert_eq!(u64::from(usage.cost), 30);
+        assert_eq!(u64::from(usage.requests), 1);
+        assert_eq!(u64::from(usage.rejected_requests), 0);
+    }
+
+    #[test]
+    fn test_authentication() {
+        let metrics = Metrics::new(&Registry::new());
+
+        // Anonymous requests are only accepted if keys are not required.
+        quotas(false).charge(None, 1000, &metrics).unwrap();
+        let err = quotas(true).charge(None, 1, &metrics).unwrap_err();
+        assert_eq!(
+            err.message,
+            "Requests must include an API key in the x-sui-rpc-api-key header",
+        );
+
+        // Unrecognized keys are never accepted.
+        let err = quotas(false)
+            .charge(Some(&key("unknown")), 1, &metrics)
+            .unwrap_err();
+        assert_eq!(err.message, "Unrecognized API key");
+        assert!(quotas(false).usage(&key("unknown")).is_none());
+    }
+}
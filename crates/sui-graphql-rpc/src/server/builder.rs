@@ -1,6 +1,7 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use super::api_keys::{ApiKey, ApiKeyQuotas};
 use super::exchange_rates_task::TriggerExchangeRatesTask;
 use super::system_package_task::SystemPackageTask;
 use super::watermark_task::{ChainIdentifierLock, Watermark, WatermarkLock, WatermarkTask};
@@ -14,6 +15,7 @@ use crate::data::{DataLoader, Db};
 use crate::extensions::directive_checker::DirectiveChecker;
 use crate::metrics::Metrics;
 use crate::mutation::Mutation;
+use crate::subscription::{CheckpointWatch, Subscription};
 use crate::types::datatype::IMoveDatatype;
 use crate::types::move_object::IMoveObject;
 use crate::types::object::IObject;
@@ -23,9 +25,12 @@ use crate::{
     context_data::db_data_provider::PgManager,
     error::Error,
     extensions::{
+        api_key_quotas::ApiKeyQuotaChecker,
         feature_gate::FeatureGate,
         logger::Logger,
+        persisted_queries::PersistedQueries,
         query_limits_checker::{PayloadSize, QueryLimitsChecker, ShowUsage},
+        response_cache::ResponseCache,
         timeout::Timeout,
     },
     server::version::set_version_middleware,
@@ -33,10 +38,11 @@ use crate::{
 };
 use async_graphql::extensions::ApolloTracing;
 use async_graphql::extensions::Tracing;
-use async_graphql::EmptySubscription;
+use async_graphql::http::ALL_WEBSOCKET_PROTOCOLS;
 use async_graphql::{extensions::ExtensionFactory, Schema, SchemaBuilder};
-use async_graphql_axum::{GraphQLRequest, GraphQLResponse};
+use async_graphql_axum::{GraphQLProtocol, GraphQLRequest, GraphQLResponse, GraphQLWebSocket};
 use axum::body::Body;
+use axum::extract::ws::WebSocketUpgrade;
 use axum::extract::FromRef;
 use axum::extract::{ConnectInfo, Query as AxumQuery, State};
 use axum::http::{HeaderMap, StatusCode};
@@ -56,7 +62,7 @@ use std::net::TcpStream;
 use std::sync::Arc;
 use std::time::Duration;
 use std::{any::Any, net::SocketAddr, time::Instant};
-use sui_graphql_rpc_headers::LIMITS_HEADER;
+use sui_graphql_rpc_headers::{API_KEY_HEADER, LIMITS_HEADER};
 use sui_indexer::db::check_db_migration_consistency;
 use sui_package_resolver::{PackageStoreWithLruCache, Resolver};
 use sui_sdk::SuiClientBuilder;
@@ -145,10 +151,11 @@ impl Server {
 
 pub(crate) struct ServerBuilder {
     state: AppState,
-    schema: SchemaBuilder<Query, Mutation, EmptySubscription>,
+    schema: SchemaBuilder<Query, Mutation, Subscription>,
     router: Option<Router>,
     db_reader: Option<Db>,
     resolver: Option<PackageResolver>,
+    response_cache: Option<ResponseCache>,
 }
 
 #[derive(Clone)]
@@ -198,6 +205,7 @@ impl ServerBuilder {
             router: None,
             db_reader: None,
             resolver: None,
+            response_cache: None,
         }
     }
 
@@ -219,7 +227,7 @@ impl ServerBuilder {
     }
 
     #[cfg(test)]
-    fn build_schema(self) -> Schema<Query, Mutation, EmptySubscription> {
+    fn build_schema(self) -> Schema<Query, Mutation, Subscription> {
         self.schema.finish()
     }
 
@@ -229,7 +237,7 @@ impl ServerBuilder {
         self,
     ) -> (
         String,
-        Schema<Query, Mutation, EmptySubscription>,
+        Schema<Query, Mutation, Subscription>,
         Db,
         PackageResolver,
         Router,
@@ -241,6 +249,7 @@ impl ServerBuilder {
             db_reader,
             resolver,
             router,
+            response_cache: _,
         } = self;
         (
             address,
@@ -256,6 +265,8 @@ impl ServerBuilder {
             let router: Router = Router::new()
                 .route("/", post(graphql_handler))
                 .route("/graphql", post(graphql_handler))
+                .route("/subscriptions", get(subscription_handler))
+                .route("/graphql/subscriptions", get(subscription_handler))
                 .route("/health", get(health_check))
                 .route("/graphql/health", get(health_check))
                 .with_state(self.state.clone())
@@ -308,13 +319,18 @@ impl ServerBuilder {
             .allow_methods([Method::POST])
             // Allow requests from any origin
             .allow_origin(acl)
-            .allow_headers([hyper::header::CONTENT_TYPE, LIMITS_HEADER.clone()]);
+            .allow_headers([
+                hyper::header::CONTENT_TYPE,
+                LIMITS_HEADER.clone(),
+                API_KEY_HEADER.clone(),
+            ]);
         Ok(cors)
     }
 
     /// Consumes the `ServerBuilder` to create a `Server` that can be run.
     pub fn build(self) -> Result<Server, Error> {
         let state = self.state.clone();
+        let response_cache = self.response_cache.clone();
         let (address, schema, db_reader, resolver, router) = self.build_components();
 
         // Initialize the watermark background task struct.
@@ -323,7 +339,8 @@ impl ServerBuilder {
             state.metrics.clone(),
             std::time::Duration::from_millis(state.service.background_tasks.watermark_update_ms),
             state.cancellation_token.clone(),
-        );
+        )
+        .with_response_cache(response_cache);
 
         let system_package_task = SystemPackageTask::new(
             resolver,
@@ -345,6 +362,9 @@ impl ServerBuilder {
             .layer(axum::extract::Extension(schema))
             .layer(axum::extract::Extension(watermark_task.lock()))
             .layer(axum::extract::Extension(watermark_task.chain_id_lock()))
+            .layer(axum::extract::Extension(CheckpointWatch(
+                watermark_task.checkpoint_receiver(),
+            )))
             .layer(Self::cors()?);
 
         Ok(Server {
@@ -471,6 +491,18 @@ impl ServerBuilder {
             .context_data(move_registry_config.clone())
             .context_data(MoveRegistryDataLoader::new(move_registry_config));
 
+        let persisted_queries = PersistedQueries::from_config(
+            &config.service.persisted_queries,
+            &config.service.limits,
+        )?;
+        if persisted_queries.is_enabled() {
+            builder = builder.extension(persisted_queries);
+        }
+
+        if let Some(quotas) = ApiKeyQuotas::from_config(&config.service.api_keys) {
+            builder = builder.context_data(quotas).extension(ApiKeyQuotaChecker);
+        }
+
         if config.internal_features.feature_gate {
             builder = builder.extension(FeatureGate);
         }
@@ -499,6 +531,14 @@ impl ServerBuilder {
             builder = builder.extension(ApolloTracing);
         }
 
+        // The response cache is registered last, so that it wraps execution as closely as
+        // possible, and cached responses still pass through the other extensions' hooks.
+        if config.internal_features.response_cache {
+            let response_cache = ResponseCache::new(config.service.response_cache);
+            builder.response_cache = Some(response_cache.clone());
+            builder = builder.extension(response_cache);
+        }
+
         // TODO: uncomment once impl
         // if config.internal_features.open_telemetry { }
 
@@ -506,8 +546,8 @@ impl ServerBuilder {
     }
 }
 
-fn schema_builder() -> SchemaBuilder<Query, Mutation, EmptySubscription> {
-    async_graphql::Schema::build(Query, Mutation, EmptySubscription)
+fn schema_builder() -> SchemaBuilder<Query, Mutation, Subscription> {
+    async_graphql::Schema::build(Query, Mutation, Subscription)
         .register_output_type::<IMoveObject>()
         .register_output_type::<IObject>()
         .register_output_type::<IOwner>()
@@ -520,7 +560,7 @@ pub fn export_schema() -> String {
 }
 
 /// Entry point for graphql requests. Each request is stamped with a unique ID, a `ShowUsage` flag
-/// if set in the request headers, and the watermark as set by the background task.
+/// and API key if set in the request headers, and the watermark as set by the background task.
 async fn graphql_handler(
     ConnectInfo(addr): ConnectInfo<SocketAddr>,
     TypedHeader(ContentLength(content_length)): TypedHeader<ContentLength>,
@@ -538,6 +578,10 @@ async fn graphql_handler(
         req.data.insert(ShowUsage)
     }
 
+    if let Some(key) = api_key(&headers) {
+        req.data.insert(key);
+    }
+
     // Capture the IP address of the client
     // Note: if a load balancer is used it must be configured to forward the client IP address
     req.data.insert(addr);
@@ -555,6 +599,44 @@ async fn graphql_handler(
     (extensions, result.into())
 }
 
+/// Entry point for graphql subscriptions, which are served over a WebSocket. Each connection is
+/// stamped with a unique ID. Unlike queries, subscriptions outlive the watermark at the time the
+/// connection was established, so they are not given a fixed `Watermark`: They follow updates to
+/// the checkpoint watermark instead, and each result is viewed at the checkpoint it was fetched at.
+async fn subscription_handler(
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    Extension(schema): Extension<SuiGraphQLSchema>,
+    Extension(chain_identifier_lock): Extension<ChainIdentifierLock>,
+    Extension(checkpoint_watch): Extension<CheckpointWatch>,
+    headers: HeaderMap,
+    protocol: GraphQLProtocol,
+    upgrade: WebSocketUpgrade,
+) -> impl IntoResponse {
+    let mut data = async_graphql::Data::default();
+    data.insert(Uuid::new_v4());
+    data.insert(addr);
+    if let Some(key) = api_key(&headers) {
+        data.insert(key);
+    }
+
+    data.insert(chain_identifier_lock.read().await);
+    data.insert(checkpoint_watch);
+
+    upgrade
+        .protocols(ALL_WEBSOCKET_PROTOCOLS)
+        .on_upgrade(move |stream| {
+            GraphQLWebSocket::new(stream, schema, protocol)
+                .with_data(data)
+                .serve()
+        })
+}
+
+/// The API key the request was made with, if it was set in the request headers.
+fn api_key(headers: &HeaderMap) -> Option<ApiKey> {
+    let key = headers.get(ApiKey::name())?.to_str().ok()?;
+    Some(ApiKey(key.to_string()))
+}
+
 #[derive(Clone)]
 struct MetricsMakeCallbackHandler {
     metrics: Metrics,
@@ -2026,4 +2108,155 @@ pub mod tests {
              bytes or fewer."
         );
     }
+
+    /// Subscribe to `query` using a schema whose checkpoint watermark starts out at `checkpoint`,
+    /// but whose fixed `Watermark` (as used by queries) is stale. Returns the sender for updating
+    /// the checkpoint watermark, and the stream of subscription responses.
+    async fn subscribe(
+        db_url: String,
+        checkpoint: u64,
+        query: &str,
+    ) -> (
+        tokio::sync::watch::Sender<u64>,
+        impl futures::Stream<Item = Response>,
+    ) {
+        let (tx, rx) = tokio::sync::watch::channel(checkpoint);
+        let schema = prep_schema(db_url, None)
+            .await
+            .context_data(CheckpointWatch(rx))
+            .build_schema();
+
+        (tx, schema.execute_stream(query))
+    }
+
+    /// The sequence number of the checkpoint that a subscription response was found at, read from
+    /// `path` in the response data.
+    fn response_checkpoint(response: Response, path: &[&str]) -> u64 {
+        let response = response
+            .into_result()
+            .expect("Subscription should not fail");
+        let mut value = response.data.into_json().unwrap();
+        for field in path {
+            value = value[field].take();
+        }
+
+        value.as_u64().expect("Checkpoint should be a number")
+    }
+
+    #[tokio::test]
+    async fn test_subscription_follows_watermark() {
+        use futures::StreamExt;
+
+        let cluster = prep_executor_cluster().await;
+        let db_url = cluster.graphql_connection_config.db_url.clone();
+
+        // Checkpoints 1 to 3 are empty, and checkpoint 4 contains the change epoch transaction, so
+        // subscriptions started at checkpoint 3 start delivering results from checkpoint 4,
+        // once the watermark advances.
+        let (tx_checkpoints, mut transactions) = subscribe(
+            db_url.clone(),
+            3,
+            "subscription { transactions { node { effects { checkpoint { sequenceNumber } } } } }",
+        )
+        .await;
+
+        let (ev_checkpoints, mut events) = subscribe(
+            db_url.clone(),
+            3,
+            "subscription { events { node { \
+                contents { type { repr } } \
+                transactionBlock { effects { checkpoint { sequenceNumber } } } \
+            } } }",
+        )
+        .await;
+
+        // Nothing is delivered until the watermark advances.
+        assert!(
+            tokio::time::timeout(Duration::from_millis(500), transactions.next())
+                .await
+                .is_err()
+        );
+
+        tx_checkpoints.send(6).unwrap();
+        ev_checkpoints.send(6).unwrap();
+
+        let transaction = tokio::time::timeout(Duration::from_secs(10), transactions.next())
+            .await
+            .expect("Timed out waiting for transaction")
+            .expect("Subscription ended unexpectedly");
+        assert_eq!(
+            response_checkpoint(
+                transaction,
+                &[
+                    "transactions",
+                    "node",
+                    "effects",
+                    "checkpoint",
+                    "sequenceNumber"
+                ],
+            ),
+            4,
+        );
+
+        let event = tokio::time::timeout(Duration::from_secs(10), events.next())
+            .await
+            .expect("Timed out waiting for event")
+            .expect("Subscription ended unexpectedly")
+            .into_result()
+            .expect("Subscription should not fail")
+            .data
+            .into_json()
+            .unwrap();
+        let event = &event["events"]["node"];
+        assert!(event["contents"]["type"]["repr"]
+            .as_str()
+            .unwrap()
+            .ends_with("::SystemEpochInfoEvent"));
+        assert_eq!(
+            event["transactionBlock"]["effects"]["checkpoint"]["sequenceNumber"],
+            json!(4),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_subscription_ignores_stale_connection_watermark() {
+        use futures::StreamExt;
+
+        let cluster = prep_executor_cluster().await;
+        let db_url = cluster.graphql_connection_config.db_url.clone();
+
+        // The schema's fixed `Watermark` is at checkpoint 1, but the checkpoint watermark has
+        // since advanced past the change epoch transaction at checkpoint 4, so the subscription
+        // should not replay it.
+        let (checkpoints, transactions) = subscribe(
+            db_url,
+            4,
+            "subscription { transactions { node { effects { checkpoint { sequenceNumber } } } } }",
+        )
+        .await;
+
+        checkpoints.send(6).unwrap();
+
+        let responses: Vec<_> = transactions
+            .take_until(tokio::time::sleep(Duration::from_secs(2)))
+            .collect()
+            .await;
+
+        for response in responses {
+            let checkpoint = response_checkpoint(
+                response,
+                &[
+                    "transactions",
+                    "node",
+                    "effects",
+                    "checkpoint",
+                    "sequenceNumber",
+                ],
+            );
+            assert!(
+                checkpoint > 4,
+                "Replayed transaction from checkpoint {checkpoint}"
+            );
+        }
+    }
 }
@@ -3,6 +3,7 @@
 
 pub mod graphiql_server;
 
+pub(crate) mod api_keys;
 pub mod builder;
 pub(crate) mod exchange_rates_task;
 pub(crate) mod system_package_task;
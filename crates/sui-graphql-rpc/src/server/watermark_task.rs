@@ -3,7 +3,9 @@
 
 use crate::data::{Db, DbConnection, QueryExecutor};
 use crate::error::Error;
+use crate::extensions::response_cache::ResponseCache;
 use crate::metrics::Metrics;
+use crate::types::available_range::AvailableRange;
 use crate::types::chain_identifier::ChainIdentifier;
 use async_graphql::ServerError;
 use diesel::{ExpressionMethods, OptionalExtension, QueryDsl};
@@ -29,6 +31,10 @@ pub(crate) struct WatermarkTask {
     cancel: CancellationToken,
     sender: watch::Sender<u64>,
     receiver: watch::Receiver<u64>,
+    checkpoint_sender: watch::Sender<u64>,
+    checkpoint_receiver: watch::Receiver<u64>,
+    /// Cache of responses to invalidate as the checkpoint watermark advances, if enabled.
+    response_cache: Option<ResponseCache>,
 }
 
 #[derive(Clone, Default)]
@@ -57,6 +63,7 @@ impl WatermarkTask {
         cancel: CancellationToken,
     ) -> Self {
         let (sender, receiver) = watch::channel(0);
+        let (checkpoint_sender, checkpoint_receiver) = watch::channel(0);
 
         Self {
             watermark: Default::default(),
@@ -67,9 +74,17 @@ impl WatermarkTask {
             cancel,
             sender,
             receiver,
+            checkpoint_sender,
+            checkpoint_receiver,
+            response_cache: None,
         }
     }
 
+    pub(crate) fn with_response_cache(mut self, response_cache: Option<ResponseCache>) -> Self {
+        self.response_cache = response_cache;
+        self
+    }
+
     pub(crate) async fn run(&self) {
         let mut interval = tokio::time::interval(self.sleep);
         // We start the task by first finding & setting the chain identifier
@@ -94,18 +109,45 @@ impl WatermarkTask {
                     };
 
                     // Write the watermark as follows to limit how long we hold the lock
-                    let prev_epoch = {
+                    let (prev_checkpoint, prev_epoch) = {
                         let mut w = self.watermark.write().await;
-                        w.checkpoint = checkpoint;
                         w.checkpoint_timestamp_ms = checkpoint_timestamp_ms;
-                        mem::replace(&mut w.epoch, epoch)
+                        (
+                            mem::replace(&mut w.checkpoint, checkpoint),
+                            mem::replace(&mut w.epoch, epoch),
+                        )
                     };
 
                     if epoch > prev_epoch {
                         self.sender.send(epoch).unwrap();
                     }
+
+                    if checkpoint > prev_checkpoint {
+                        if let Some(response_cache) = &self.response_cache {
+                            response_cache.invalidate_before(checkpoint);
+                            self.invalidate_pruned(response_cache, checkpoint).await;
+                        }
+
+                        self.checkpoint_sender.send(checkpoint).unwrap();
+                    }
+                }
+            }
+        }
+    }
+
+    /// Drop cached responses for checkpoints that are no longer in the available range, as of
+    /// `checkpoint`.
+    async fn invalidate_pruned(&self, response_cache: &ResponseCache, checkpoint: u64) {
+        match AvailableRange::query(&self.db, checkpoint).await {
+            Ok(AvailableRange { first, .. }) => {
+                if let Some(pruned) = first.checked_sub(1) {
+                    response_cache.invalidate_pruned(pruned);
                 }
             }
+            Err(e) => {
+                error!("Failed to fetch available range: {}", e);
+                self.metrics.inc_errors(&[ServerError::new(e.to_string(), None)]);
+            }
         }
     }
 
@@ -122,6 +164,12 @@ impl WatermarkTask {
         self.receiver.clone()
     }
 
+    /// Receiver for subscribing to changes in the checkpoint high watermark. Used to drive GraphQL
+    /// subscriptions, which re-query the database each time the watermark advances.
+    pub(crate) fn checkpoint_receiver(&self) -> watch::Receiver<u64> {
+        self.checkpoint_receiver.clone()
+    }
+
     // Fetch the chain identifier (once) from the database and cache it.
     async fn get_and_cache_chain_identifier(&self, interval: &mut Interval) {
         loop {
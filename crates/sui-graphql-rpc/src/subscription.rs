@@ -0,0 +1,388 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::VecDeque;
+
+use async_graphql::connection::{CursorType, Edge};
+use async_graphql::*;
+use diesel::{ExpressionMethods, OptionalExtension, QueryDsl};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use futures::stream::{self, Stream};
+use sui_indexer::schema::checkpoints;
+use tokio::sync::watch;
+
+use crate::config::ServiceConfig;
+use crate::data::{Db, DbConnection, QueryExecutor};
+use crate::error::Error;
+use crate::functional_group::FunctionalGroup;
+use crate::types::cursor::Page;
+use crate::types::event::{self, Event, EventFilter};
+use crate::types::transaction_block::{
+    self, TransactionBlock, TransactionBlockCursor, TransactionBlockFilter,
+};
+
+pub(crate) struct Subscription;
+
+/// Receives the checkpoint high watermark every time it is updated by the watermark task. Each
+/// subscription holds on to a copy of this receiver to know when to look for new results.
+#[derive(Clone)]
+pub(crate) struct CheckpointWatch(pub watch::Receiver<u64>);
+
+/// Data that a subscription can be followed by, from a cursor onwards.
+#[async_trait::async_trait]
+trait Tailable: OutputType + Sized + Send + Sync + 'static {
+    type Cursor: CursorType + Send + Sync + 'static;
+    type Filter: Clone + Send + Sync + 'static;
+
+    /// The cursor to start following from if the subscriber did not supply one: The last element
+    /// matching `filter` as of `checkpoint`, or `None` if there is no such element.
+    async fn tip(
+        db: &Db,
+        config: &ServiceConfig,
+        filter: Self::Filter,
+        checkpoint: u64,
+    ) -> Result<Option<Self::Cursor>, Error>;
+
+    /// A copy of `cursor` that can be used to paginate at `checkpoint`.
+    fn at_checkpoint(cursor: &Self::Cursor, checkpoint: u64) -> Self::Cursor;
+
+    /// Fetch the page of elements matching `filter` after `after`, viewed at `checkpoint`.
+    async fn page(
+        db: &Db,
+        config: &ServiceConfig,
+        after: Option<Self::Cursor>,
+        filter: Self::Filter,
+        checkpoint: u64,
+    ) -> Result<TailPage<Self>, Error>;
+}
+
+/// A page of results fetched while following a subscription.
+struct TailPage<T: Tailable> {
+    edges: Vec<Edge<String, T>>,
+
+    /// The cursor to resume from when fetching the next page, if the page moved it forward.
+    end: Option<T::Cursor>,
+
+    /// Whether there are more results to fetch at the same checkpoint.
+    has_next_page: bool,
+}
+
+/// State for a single subscription: Follows the elements matching `filter` after the `after`
+/// cursor, fetching a new page each time the checkpoint watermark advances.
+struct Tail<T: Tailable> {
+    db: Db,
+    config: ServiceConfig,
+    filter: T::Filter,
+    checkpoints: watch::Receiver<u64>,
+
+    /// The checkpoint the most recent page was fetched at.
+    checkpoint: u64,
+
+    /// The cursor of the last element fetched (or the last element scanned, if the page was scan
+    /// limited).
+    after: Option<T::Cursor>,
+
+    /// Whether the most recent page indicated that there are more results at `checkpoint`.
+    has_next_page: bool,
+
+    /// Results fetched but not yet delivered to the subscriber.
+    buffer: VecDeque<Edge<String, T>>,
+}
+
+/// Subscriptions are used to observe the Sui network as it is indexed.
+#[Subscription]
+impl Subscription {
+    /// Subscribe to transaction blocks as they are indexed, optionally `filter`-ed using the same
+    /// criteria as `Query.transactionBlocks`.
+    ///
+    /// Each transaction block is delivered along with its cursor. By default, the subscription
+    /// delivers transaction blocks that are indexed after it starts, but a subscriber that
+    /// disconnects can resume from where it left off by supplying the last cursor it received as
+    /// `after`.
+    ///
+    /// Filters that would require a `scanLimit` when querying `Query.transactionBlocks` are
+    /// supported, and scan at most `ServiceConfig.maxScanLimit` transactions per page.
+    async fn transactions(
+        &self,
+        ctx: &Context<'_>,
+        after: Option<transaction_block::Cursor>,
+        filter: Option<TransactionBlockFilter>,
+    ) -> Result<impl Stream<Item = Result<Edge<String, TransactionBlock>>>> {
+        Ok(
+            Tail::<TransactionBlock>::new(ctx, after, filter.unwrap_or_default())
+                .await?
+                .stream(),
+        )
+    }
+
+    /// Subscribe to events as they are indexed, optionally `filter`-ed using the same criteria as
+    /// `Query.events`.
+    ///
+    /// Each event is delivered along with its cursor. By default, the subscription delivers events
+    /// that are indexed after it starts, but a subscriber that disconnects can resume from where
+    /// it left off by supplying the last cursor it received as `after`.
+    async fn events(
+        &self,
+        ctx: &Context<'_>,
+        after: Option<event::Cursor>,
+        filter: Option<EventFilter>,
+    ) -> Result<impl Stream<Item = Result<Edge<String, Event>>>> {
+        Ok(Tail::<Event>::new(ctx, after, filter.unwrap_or_default())
+            .await?
+            .stream())
+    }
+}
+
+impl<T: Tailable> Tail<T> {
+    async fn new(
+        ctx: &Context<'_>,
+        after: Option<T::Cursor>,
+        filter: T::Filter,
+    ) -> Result<Self, Error> {
+        let config: &ServiceConfig = ctx.data_unchecked();
+        if config
+            .disabled_features
+            .contains(&FunctionalGroup::Subscriptions)
+        {
+            return Err(Error::Client(format!(
+                "Feature {} is disabled.",
+                FunctionalGroup::Subscriptions.name(),
+            )));
+        }
+
+        let CheckpointWatch(checkpoints) = ctx.data::<CheckpointWatch>().map_err(|_| {
+            Error::Client("Subscriptions are only supported over a WebSocket.".to_string())
+        })?;
+
+        // The subscription starts from the checkpoint watermark as of when it is created, rather
+        // than when the connection it is served over was established, which may be much earlier.
+        let checkpoint = *checkpoints.borrow();
+        let db: &Db = ctx.data_unchecked();
+
+        // Subscriptions that do not supply a cursor start from the tip of the data set, rather than
+        // replaying all of history.
+        let after = match after {
+            Some(after) => Some(after),
+            None => T::tip(db, config, filter.clone(), checkpoint).await?,
+        };
+
+        Ok(Self {
+            db: db.clone(),
+            config: config.clone(),
+            filter,
+            checkpoints: checkpoints.clone(),
+            checkpoint,
+            after,
+            has_next_page: true,
+            buffer: VecDeque::new(),
+        })
+    }
+
+    /// Turn the subscription into a stream of results. The stream ends after the first error.
+    fn stream(self) -> impl Stream<Item = Result<Edge<String, T>>> {
+        stream::unfold(Some(self), |tail| async move {
+            let mut tail = tail?;
+            loop {
+                if let Some(edge) = tail.buffer.pop_front() {
+                    return Some((Ok(edge), Some(tail)));
+                }
+
+                if let Err(e) = tail.fetch().await {
+                    return Some((Err(e.extend()), None));
+                }
+            }
+        })
+    }
+
+    /// Fetch the next page of results into the buffer, waiting for the checkpoint watermark to
+    /// advance first, if all the results at the current watermark have already been fetched.
+    async fn fetch(&mut self) -> Result<(), Error> {
+        while !self.has_next_page {
+            self.checkpoints.changed().await.map_err(|_| {
+                Error::Internal("Checkpoint watermark is no longer being updated".to_string())
+            })?;
+
+            let checkpoint = *self.checkpoints.borrow_and_update();
+            if checkpoint > self.checkpoint {
+                self.checkpoint = checkpoint;
+                self.has_next_page = true;
+            }
+        }
+
+        // Cursors pin the checkpoint that a page is viewed at, so they need to be brought up to
+        // date with the watermark to see new results.
+        let after = self
+            .after
+            .as_ref()
+            .map(|after| T::at_checkpoint(after, self.checkpoint));
+
+        let TailPage {
+            edges,
+            end,
+            has_next_page,
+        } = T::page(
+            &self.db,
+            &self.config,
+            after,
+            self.filter.clone(),
+            self.checkpoint,
+        )
+        .await?;
+
+        if end.is_some() {
+            self.after = end;
+        }
+
+        self.has_next_page = has_next_page;
+        self.buffer.extend(edges);
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Tailable for TransactionBlock {
+    type Cursor = transaction_block::Cursor;
+    type Filter = TransactionBlockFilter;
+
+    async fn tip(
+        db: &Db,
+        _config: &ServiceConfig,
+        _filter: TransactionBlockFilter,
+        checkpoint: u64,
+    ) -> Result<Option<transaction_block::Cursor>, Error> {
+        use checkpoints::dsl;
+        let tx_hi: Option<i64> = db
+            .execute(move |conn| {
+                async move {
+                    conn.first(move || {
+                        dsl::checkpoints
+                            .select(dsl::network_total_transactions)
+                            .filter(dsl::sequence_number.eq(checkpoint as i64))
+                    })
+                    .await
+                    .optional()
+                }
+                .scope_boxed()
+            })
+            .await?;
+
+        // The cursor points at the last transaction at the checkpoint, which may not match the
+        // filter, so it is marked as scan limited.
+        Ok(tx_hi.filter(|hi| *hi > 0).map(|hi| {
+            transaction_block::Cursor::new(TransactionBlockCursor {
+                checkpoint_viewed_at: checkpoint,
+                tx_sequence_number: hi as u64 - 1,
+                is_scan_limited: true,
+            })
+        }))
+    }
+
+    fn at_checkpoint(
+        cursor: &transaction_block::Cursor,
+        checkpoint: u64,
+    ) -> transaction_block::Cursor {
+        transaction_block::Cursor::new(TransactionBlockCursor {
+            checkpoint_viewed_at: checkpoint,
+            tx_sequence_number: cursor.tx_sequence_number,
+            is_scan_limited: cursor.is_scan_limited,
+        })
+    }
+
+    async fn page(
+        db: &Db,
+        config: &ServiceConfig,
+        after: Option<transaction_block::Cursor>,
+        filter: TransactionBlockFilter,
+        checkpoint: u64,
+    ) -> Result<TailPage<TransactionBlock>, Error> {
+        let limits = &config.limits;
+        let scan_limit = filter
+            .requires_scan_limit()
+            .then_some(limits.max_scan_limit as u64);
+
+        let page = Page::from_params(config, Some(limits.max_page_size as u64), after, None, None)
+            .map_err(|e| Error::Internal(e.message))?;
+
+        let conn =
+            TransactionBlock::paginate_with_db(db, limits, page, filter, checkpoint, scan_limit)
+                .await?;
+
+        // If the page was scan limited, its end cursor may point past the last result, at the
+        // last transaction that was scanned.
+        let end = conn
+            .end_cursor
+            .as_ref()
+            .or_else(|| conn.edges.last().map(|edge| &edge.cursor))
+            .map(|cursor| decode_cursor(cursor))
+            .transpose()?;
+
+        Ok(TailPage {
+            edges: conn.edges,
+            end,
+            has_next_page: conn.has_next_page,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Tailable for Event {
+    type Cursor = event::Cursor;
+    type Filter = EventFilter;
+
+    async fn tip(
+        db: &Db,
+        config: &ServiceConfig,
+        filter: EventFilter,
+        checkpoint: u64,
+    ) -> Result<Option<event::Cursor>, Error> {
+        let page = Page::from_params(config, None, None, Some(1), None)
+            .map_err(|e| Error::Internal(e.message))?;
+
+        let conn = Event::paginate(db, page, filter, checkpoint).await?;
+        conn.edges
+            .last()
+            .map(|edge| decode_cursor(&edge.cursor))
+            .transpose()
+    }
+
+    fn at_checkpoint(cursor: &event::Cursor, checkpoint: u64) -> event::Cursor {
+        let mut key = (**cursor).clone();
+        key.checkpoint_viewed_at = checkpoint;
+        event::Cursor::new(key)
+    }
+
+    async fn page(
+        db: &Db,
+        config: &ServiceConfig,
+        after: Option<event::Cursor>,
+        filter: EventFilter,
+        checkpoint: u64,
+    ) -> Result<TailPage<Event>, Error> {
+        let page = Page::from_params(
+            config,
+            Some(config.limits.max_page_size as u64),
+            after,
+            None,
+            None,
+        )
+        .map_err(|e| Error::Internal(e.message))?;
+
+        let conn = Event::paginate(db, page, filter, checkpoint).await?;
+        let end = conn
+            .edges
+            .last()
+            .map(|edge| decode_cursor(&edge.cursor))
+            .transpose()?;
+
+        Ok(TailPage {
+            edges: conn.edges,
+            end,
+            has_next_page: conn.has_next_page,
+        })
+    }
+}
+
+fn decode_cursor<C: CursorType>(cursor: &str) -> Result<C, Error> {
+    C::decode_cursor(cursor)
+        .map_err(|_| Error::Internal(format!("Failed to decode cursor: {cursor}")))
+}
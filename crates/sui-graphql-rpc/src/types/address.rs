@@ -4,6 +4,7 @@
 use crate::connection::ScanConnection;
 
 use super::{
+    address_activity::{self, AddressActivity},
     balance::{self, Balance},
     coin::Coin,
     cursor::Page,
@@ -190,6 +191,29 @@ impl Address {
             .await
             .extend()
     }
+
+    /// Transactions that affected this address, each summarised from its point of view: its net
+    /// balance change per coin type, the objects it received or sent, and the other addresses
+    /// involved.
+    async fn activity(
+        &self,
+        ctx: &Context<'_>,
+        first: Option<u64>,
+        after: Option<address_activity::Cursor>,
+        last: Option<u64>,
+        before: Option<address_activity::Cursor>,
+    ) -> Result<Connection<String, AddressActivity>> {
+        let page = Page::from_params(ctx.data_unchecked(), first, after, last, before)?;
+
+        AddressActivity::paginate(
+            ctx.data_unchecked(),
+            page,
+            self.address,
+            self.checkpoint_viewed_at,
+        )
+        .await
+        .extend()
+    }
 }
 
 impl From<&Address> for OwnerImpl {
@@ -0,0 +1,324 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use async_graphql::{
+    connection::{Connection, CursorType, Edge},
+    *,
+};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use serde::{Deserialize, Serialize};
+use sui_indexer::models::transactions::StoredTransaction;
+use sui_json_rpc_types::{
+    ActivityBalanceChange as NativeBalanceChange, ActivityObjectTransfer as NativeObjectTransfer,
+    AddressActivity as NativeAddressActivity,
+    ObjectTransferDirection as NativeObjectTransferDirection,
+};
+use sui_types::TypeTag;
+
+use crate::{
+    consistency::Checkpointed,
+    data::{Db, QueryExecutor},
+    error::Error,
+    filter, query,
+    raw_query::RawQuery,
+};
+
+use super::{
+    address::Address,
+    big_int::BigInt,
+    cursor::{self, Page, RawPaginated, ScanLimited, Target},
+    date_time::DateTime,
+    move_type::MoveType,
+    object::Object,
+    sui_address::SuiAddress,
+    transaction_block::TransactionBlock,
+    uint53::UInt53,
+};
+
+/// A transaction, summarised from the point of view of one address that it affected.
+#[derive(Clone)]
+pub(crate) struct AddressActivity {
+    pub native: NativeAddressActivity,
+    /// The checkpoint sequence number at which this was viewed at.
+    pub checkpoint_viewed_at: u64,
+}
+
+/// The net change to an address's balance of one coin type, within a transaction.
+#[derive(Clone)]
+pub(crate) struct ActivityBalanceChange {
+    pub native: NativeBalanceChange,
+}
+
+/// An object that moved into or out of an address's ownership, within a transaction.
+#[derive(Clone)]
+pub(crate) struct ActivityObjectTransfer {
+    pub native: NativeObjectTransfer,
+    /// The checkpoint sequence number at which this was viewed at.
+    pub checkpoint_viewed_at: u64,
+}
+
+/// The direction an object moved in, relative to the address whose activity is being viewed.
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+pub(crate) enum ObjectTransferDirection {
+    /// The object was created for, or transferred to, the address.
+    In,
+    /// The address sent the object to another address.
+    Out,
+}
+
+pub(crate) type Cursor = cursor::BcsCursor<ActivityCursor>;
+
+/// The cursor returned for each `AddressActivity` in a connection's page of results. Activity is
+/// ordered by the sequence number of its transaction.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub(crate) struct ActivityCursor {
+    #[serde(rename = "t")]
+    pub tx_sequence_number: u64,
+    /// The checkpoint sequence number this was viewed at.
+    #[serde(rename = "c")]
+    pub checkpoint_viewed_at: u64,
+}
+
+/// A transaction, summarised from the point of view of one address that it affected.
+#[Object]
+impl AddressActivity {
+    /// The transaction block this activity was derived from.
+    async fn transaction_block(&self, ctx: &Context<'_>) -> Result<Option<TransactionBlock>> {
+        TransactionBlock::query(
+            ctx,
+            TransactionBlock::by_digest(self.native.digest.into(), self.checkpoint_viewed_at),
+        )
+        .await
+        .extend()
+    }
+
+    /// The timestamp of the checkpoint the transaction was included in.
+    async fn timestamp(&self) -> Result<DateTime> {
+        DateTime::from_ms(self.native.timestamp_ms as i64).extend()
+    }
+
+    /// The address that sent the transaction.
+    async fn sender(&self) -> Address {
+        Address {
+            address: SuiAddress::from(self.native.sender),
+            checkpoint_viewed_at: self.checkpoint_viewed_at,
+        }
+    }
+
+    /// Whether the transaction executed successfully.
+    async fn success(&self) -> bool {
+        self.native.success
+    }
+
+    /// The other addresses involved: the sender, if it was not this address, and every address
+    /// that exchanged coins or objects in the transaction.
+    async fn counterparties(&self) -> Vec<Address> {
+        self.native
+            .counterparties
+            .iter()
+            .map(|a| Address {
+                address: SuiAddress::from(*a),
+                checkpoint_viewed_at: self.checkpoint_viewed_at,
+            })
+            .collect()
+    }
+
+    /// The net change to this address's balance, per coin type.
+    async fn balance_changes(&self) -> Vec<ActivityBalanceChange> {
+        self.native
+            .balance_changes
+            .iter()
+            .map(|native| ActivityBalanceChange {
+                native: native.clone(),
+            })
+            .collect()
+    }
+
+    /// Objects that were created for this address, or transferred into or out of its ownership.
+    async fn object_transfers(&self) -> Vec<ActivityObjectTransfer> {
+        self.native
+            .object_transfers
+            .iter()
+            .map(|native| ActivityObjectTransfer {
+                native: native.clone(),
+                checkpoint_viewed_at: self.checkpoint_viewed_at,
+            })
+            .collect()
+    }
+}
+
+/// The net change to an address's balance of one coin type, within a transaction.
+#[Object]
+impl ActivityBalanceChange {
+    /// The inner type of the coin whose balance has changed (e.g. `0x2::sui::SUI`).
+    async fn coin_type(&self) -> MoveType {
+        self.native.coin_type.clone().into()
+    }
+
+    /// The signed balance change.
+    async fn amount(&self) -> BigInt {
+        BigInt::from(self.native.amount)
+    }
+}
+
+/// An object that moved into or out of an address's ownership, within a transaction.
+#[Object]
+impl ActivityObjectTransfer {
+    /// The ID of the object that moved.
+    async fn object_id(&self) -> SuiAddress {
+        SuiAddress::from(self.native.object_id)
+    }
+
+    /// The version of the object after the transaction.
+    async fn version(&self) -> UInt53 {
+        self.native.version.value().into()
+    }
+
+    /// The object's type.
+    async fn type_(&self) -> MoveType {
+        TypeTag::Struct(Box::new(self.native.object_type.clone())).into()
+    }
+
+    /// Whether the object moved into or out of the address's ownership.
+    async fn direction(&self) -> ObjectTransferDirection {
+        match self.native.direction {
+            NativeObjectTransferDirection::In => ObjectTransferDirection::In,
+            NativeObjectTransferDirection::Out => ObjectTransferDirection::Out,
+        }
+    }
+
+    /// The address the object came from or went to, if it was not the address itself.
+    async fn counterparty(&self) -> Option<Address> {
+        self.native.counterparty.map(|a| Address {
+            address: SuiAddress::from(a),
+            checkpoint_viewed_at: self.checkpoint_viewed_at,
+        })
+    }
+
+    /// The object, at the version written by the transaction.
+    async fn object(&self, ctx: &Context<'_>) -> Result<Option<Object>> {
+        Object::query(
+            ctx,
+            self.native.object_id.into(),
+            Object::at_version(self.native.version.value(), self.checkpoint_viewed_at),
+        )
+        .await
+        .extend()
+    }
+}
+
+impl AddressActivity {
+    /// Query the database for a `page` of the transactions that affected `address`, each
+    /// summarised from its point of view.
+    ///
+    /// `checkpoint_viewed_at` represents the checkpoint sequence number at which this page was
+    /// queried for. Transactions from later checkpoints are not included. The cursors in `page`
+    /// may also include checkpoint viewed at fields. If these are set, they take precedence over
+    /// the checkpoint that pagination is being conducted in.
+    pub(crate) async fn paginate(
+        db: &Db,
+        page: Page<Cursor>,
+        address: SuiAddress,
+        checkpoint_viewed_at: u64,
+    ) -> Result<Connection<String, AddressActivity>, Error> {
+        let cursor_viewed_at = page.validate_cursor_consistency()?;
+        let checkpoint_viewed_at = cursor_viewed_at.unwrap_or(checkpoint_viewed_at);
+
+        let (prev, next, results) = db
+            .execute(move |conn| {
+                async move {
+                    let mut q = query!(
+                        r#"
+                            SELECT
+                                t.*
+                            FROM
+                                tx_affected_addresses a
+                            INNER JOIN
+                                transactions t
+                            ON
+                                a.tx_sequence_number = t.tx_sequence_number
+                        "#
+                    );
+
+                    q = filter!(
+                        q,
+                        format!(
+                            "a.affected = '\\x{}'::bytea",
+                            hex::encode(address.into_vec())
+                        )
+                    );
+                    q = filter!(
+                        q,
+                        format!("t.checkpoint_sequence_number <= {checkpoint_viewed_at}")
+                    );
+
+                    page.paginate_raw_query::<StoredTransaction>(conn, checkpoint_viewed_at, q)
+                        .await
+                }
+                .scope_boxed()
+            })
+            .await?;
+
+        let mut conn = Connection::new(prev, next);
+        for stored in results {
+            let cursor =
+                <StoredTransaction as Target<Cursor>>::cursor(&stored, checkpoint_viewed_at)
+                    .encode_cursor();
+
+            let native = stored
+                .try_into_address_activity(address.into())
+                .map_err(|e| Error::Internal(format!("Failed to summarise transaction: {e}")))?;
+
+            conn.edges.push(Edge::new(
+                cursor,
+                AddressActivity {
+                    native,
+                    checkpoint_viewed_at,
+                },
+            ));
+        }
+
+        Ok(conn)
+    }
+}
+
+impl RawPaginated<Cursor> for StoredTransaction {
+    fn filter_ge(cursor: &Cursor, query: RawQuery) -> RawQuery {
+        filter!(
+            query,
+            format!("a.tx_sequence_number >= {}", cursor.tx_sequence_number)
+        )
+    }
+
+    fn filter_le(cursor: &Cursor, query: RawQuery) -> RawQuery {
+        filter!(
+            query,
+            format!("a.tx_sequence_number <= {}", cursor.tx_sequence_number)
+        )
+    }
+
+    fn order(asc: bool, query: RawQuery) -> RawQuery {
+        if asc {
+            query.order_by("a.tx_sequence_number ASC")
+        } else {
+            query.order_by("a.tx_sequence_number DESC")
+        }
+    }
+}
+
+impl Target<Cursor> for StoredTransaction {
+    fn cursor(&self, checkpoint_viewed_at: u64) -> Cursor {
+        Cursor::new(ActivityCursor {
+            tx_sequence_number: self.tx_sequence_number as u64,
+            checkpoint_viewed_at,
+        })
+    }
+}
+
+impl Checkpointed for Cursor {
+    fn checkpoint_viewed_at(&self) -> u64 {
+        self.checkpoint_viewed_at
+    }
+}
+
+impl ScanLimited for Cursor {}
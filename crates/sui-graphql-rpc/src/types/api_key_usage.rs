@@ -0,0 +1,34 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use async_graphql::*;
+
+use super::{date_time::DateTime, uint53::UInt53};
+
+/// Usage of an API key over the current window. Each API key has a budget for the total estimated
+/// cost of the requests made with it (measured in output nodes), which is reset at the start of
+/// each window.
+#[derive(SimpleObject, Clone)]
+pub(crate) struct ApiKeyUsage {
+    /// The name of the API key's owner.
+    pub name: String,
+
+    /// When the current window started.
+    pub window_start: DateTime,
+
+    /// The length of each window, in milliseconds.
+    pub window_ms: UInt53,
+
+    /// The total estimated cost of the requests that can be made with this key per window.
+    pub budget: UInt53,
+
+    /// The total estimated cost of the requests made with this key in the current window.
+    pub cost: UInt53,
+
+    /// The number of requests accepted for this key in the current window.
+    pub requests: UInt53,
+
+    /// The number of requests rejected for this key in the current window, for exceeding its
+    /// budget.
+    pub rejected_requests: UInt53,
+}
@@ -30,6 +30,17 @@ use serde::{Deserialize, Serialize};
 use sui_indexer::{models::checkpoints::StoredCheckpoint, schema::checkpoints};
 use sui_types::messages_checkpoint::CheckpointDigest;
 
+/// The number of checkpoints that transactions per second are averaged over, by default.
+const DEFAULT_TPS_WINDOW: u64 = 10;
+
+/// The number of transactions the network had executed as of some point in time, used to measure
+/// throughput.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct NetworkProgress {
+    pub network_total_transactions: u64,
+    pub timestamp_ms: u64,
+}
+
 /// Filter either by the digest, or the sequence number, or neither, to get the latest checkpoint.
 #[derive(Default, InputObject)]
 pub(crate) struct CheckpointId {
@@ -121,6 +132,32 @@ impl Checkpoint {
         Some(self.network_total_transactions_impl().into())
     }
 
+    /// The average number of transaction blocks executed per second, over a window of checkpoints
+    /// ending at this checkpoint. The window covers the last `window` checkpoints (10 by default),
+    /// or fewer if there are not that many checkpoints before this one. Returns `null` if the
+    /// start of the window is no longer available.
+    async fn transactions_per_second(
+        &self,
+        ctx: &Context<'_>,
+        window: Option<UInt53>,
+    ) -> Result<Option<f64>> {
+        let window = window.map_or(DEFAULT_TPS_WINDOW, u64::from);
+        let hi = self.sequence_number_impl();
+        let lo = hi.saturating_sub(window);
+        if hi <= lo {
+            return Ok(None);
+        }
+
+        let progress = Checkpoint::query_network_progress(ctx.data_unchecked(), vec![lo, hi])
+            .await
+            .extend()?;
+
+        Ok(progress
+            .get(&lo)
+            .zip(progress.get(&hi))
+            .and_then(|(lo, hi)| lo.transactions_per_second(hi)))
+    }
+
     /// The computation cost, storage cost, storage rebate, and non-refundable storage fee
     /// accumulated during this epoch, up to and including this checkpoint. These values increase
     /// monotonically across checkpoints in the same epoch, and reset on epoch boundaries.
@@ -301,6 +338,48 @@ impl Checkpoint {
         Ok(stored as u64)
     }
 
+    /// Query the database for the progress of the network as of the end of each of the
+    /// `checkpoints`, keyed by checkpoint sequence number. Checkpoints that are unavailable are
+    /// omitted.
+    pub(crate) async fn query_network_progress(
+        db: &Db,
+        checkpoints: Vec<u64>,
+    ) -> Result<BTreeMap<u64, NetworkProgress>, Error> {
+        use checkpoints::dsl;
+
+        let sequence_numbers: Vec<i64> = checkpoints.into_iter().map(|cp| cp as i64).collect();
+        let stored: Vec<(i64, i64, i64)> = db
+            .execute(move |conn| {
+                async move {
+                    conn.results(move || {
+                        dsl::checkpoints
+                            .select((
+                                dsl::sequence_number,
+                                dsl::network_total_transactions,
+                                dsl::timestamp_ms,
+                            ))
+                            .filter(dsl::sequence_number.eq_any(sequence_numbers.clone()))
+                    })
+                    .await
+                }
+                .scope_boxed()
+            })
+            .await
+            .map_err(|e| Error::Internal(format!("Failed to fetch checkpoints: {e}")))?;
+
+        Ok(stored
+            .into_iter()
+            .map(|(cp, tx, ts)| {
+                let progress = NetworkProgress {
+                    network_total_transactions: tx as u64,
+                    timestamp_ms: ts as u64,
+                };
+
+                (cp as u64, progress)
+            })
+            .collect())
+    }
+
     /// Query the database for a `page` of checkpoints. The Page uses the checkpoint sequence number
     /// of the stored checkpoint and the checkpoint at which this was viewed at as the cursor, and
     /// can optionally be further `filter`-ed by an epoch number (to only return checkpoints within
@@ -362,6 +441,22 @@ impl Checkpoint {
     }
 }
 
+impl NetworkProgress {
+    /// The average number of transactions executed per second between this point and a later
+    /// point, `end`. Returns `None` if no time elapsed between them.
+    pub(crate) fn transactions_per_second(&self, end: &NetworkProgress) -> Option<f64> {
+        if end.timestamp_ms <= self.timestamp_ms {
+            return None;
+        }
+
+        let transactions = end
+            .network_total_transactions
+            .saturating_sub(self.network_total_transactions);
+        let elapsed_ms = end.timestamp_ms - self.timestamp_ms;
+        Some(transactions as f64 * 1000.0 / elapsed_ms as f64)
+    }
+}
+
 impl Paginated<Cursor> for StoredCheckpoint {
     type Source = checkpoints::table;
 
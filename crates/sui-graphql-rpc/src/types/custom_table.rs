@@ -0,0 +1,279 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use async_graphql::{
+    connection::{Connection, CursorType, Edge},
+    *,
+};
+use diesel::{
+    sql_types::{BigInt as SqlBigInt, Bytea, Nullable, Text},
+    ExpressionMethods, OptionalExtension, QueryDsl, QueryableByName,
+};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use serde::{Deserialize, Serialize};
+use sui_indexer::{
+    handlers::custom_tables::SYSTEM_COLUMNS, models::custom_tables::StoredCustomTable,
+    schema::custom_tables,
+};
+
+use crate::{
+    consistency::Checkpointed,
+    data::{Db, DbConnection, QueryExecutor},
+    error::Error,
+    filter, query,
+    raw_query::RawQuery,
+};
+
+use super::{
+    cursor::{self, Page, RawPaginated, ScanLimited, Target},
+    json::Json,
+    owner::Owner,
+    sui_address::{addr, SuiAddress},
+    uint53::UInt53,
+};
+
+/// A row of a custom table, projecting the fields of an object into columns.
+#[derive(Clone)]
+pub(crate) struct CustomTableRow {
+    pub stored: StoredCustomRow,
+    /// The checkpoint sequence number at which this was viewed at.
+    pub checkpoint_viewed_at: u64,
+}
+
+/// A row read from a custom table. The table's own columns are only known at runtime, so they are
+/// read together as a JSON object.
+#[derive(QueryableByName, Clone, Debug)]
+pub(crate) struct StoredCustomRow {
+    #[diesel(sql_type = Bytea)]
+    pub object_id: Vec<u8>,
+    #[diesel(sql_type = SqlBigInt)]
+    pub object_version: i64,
+    #[diesel(sql_type = SqlBigInt)]
+    pub checkpoint_sequence_number: i64,
+    #[diesel(sql_type = Nullable<Bytea>)]
+    pub owner_id: Option<Vec<u8>>,
+    #[diesel(sql_type = Text)]
+    pub fields: String,
+}
+
+pub(crate) type Cursor = cursor::BcsCursor<CustomRowCursor>;
+
+/// The cursor returned for each `CustomTableRow` in a connection's page of results. There is at
+/// most one row per object, and rows are ordered by object ID.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub(crate) struct CustomRowCursor {
+    pub object_id: Vec<u8>,
+    pub checkpoint_viewed_at: u64,
+}
+
+/// A row of a custom table, projecting the fields of an object into columns.
+#[Object]
+impl CustomTableRow {
+    /// The ID of the object this row was projected from.
+    async fn object_id(&self) -> Result<SuiAddress> {
+        addr(&self.stored.object_id).extend()
+    }
+
+    /// The version of the object this row was projected from.
+    async fn version(&self) -> UInt53 {
+        UInt53::from(self.stored.object_version as u64)
+    }
+
+    /// The checkpoint that this version of the object was written in.
+    async fn checkpoint_sequence_number(&self) -> UInt53 {
+        UInt53::from(self.stored.checkpoint_sequence_number as u64)
+    }
+
+    /// The address or object that owned this version of the object, if it was owned.
+    async fn owner(&self) -> Result<Option<Owner>> {
+        let Some(owner_id) = &self.stored.owner_id else {
+            return Ok(None);
+        };
+
+        Ok(Some(Owner {
+            address: addr(owner_id).extend()?,
+            checkpoint_viewed_at: self.checkpoint_viewed_at,
+            root_version: None,
+        }))
+    }
+
+    /// The table's columns for this row, as a JSON object keyed by column name.
+    async fn fields(&self) -> Result<Json> {
+        let value: serde_json::Value = serde_json::from_str(&self.stored.fields)
+            .map_err(|e| Error::Internal(format!("Failed to read custom table row: {e}")))
+            .extend()?;
+
+        Ok(Json::from(
+            Value::from_json(value)
+                .map_err(|e| Error::Internal(format!("Failed to read custom table row: {e}")))
+                .extend()?,
+        ))
+    }
+}
+
+impl CustomTableRow {
+    /// Query the database for a `page` of rows from the custom table registered as `name`,
+    /// optionally limited to objects owned by `owner`. Returns `None` if there is no custom table
+    /// with that name. Each object's row is the latest one written at or before the checkpoint
+    /// being viewed, and objects that were deleted or wrapped by then are not included.
+    ///
+    /// Tables that do not keep history only hold each object's latest row, so objects that changed
+    /// after the checkpoint being viewed are missing from them until that checkpoint catches up.
+    pub(crate) async fn paginate(
+        db: &Db,
+        page: Page<Cursor>,
+        name: String,
+        owner: Option<SuiAddress>,
+        checkpoint_viewed_at: u64,
+    ) -> Result<Option<Connection<String, CustomTableRow>>, Error> {
+        let cursor_viewed_at = page.validate_cursor_consistency()?;
+        let checkpoint_viewed_at = cursor_viewed_at.unwrap_or(checkpoint_viewed_at);
+
+        let results = db
+            .execute(move |conn| {
+                async move {
+                    let table: Option<StoredCustomTable> = conn
+                        .first(move || {
+                            custom_tables::table.filter(custom_tables::name.eq(name.clone()))
+                        })
+                        .await
+                        .optional()?;
+
+                    let Some(table) = table else {
+                        return Ok::<_, diesel::result::Error>(None);
+                    };
+
+                    let query = rows_query(&table, owner, checkpoint_viewed_at);
+                    let results = page
+                        .paginate_raw_query::<StoredCustomRow>(conn, checkpoint_viewed_at, query)
+                        .await?;
+
+                    Ok(Some(results))
+                }
+                .scope_boxed()
+            })
+            .await
+            .map_err(|e| Error::Internal(format!("Failed to fetch custom table rows: {e}")))?;
+
+        let Some((prev, next, results)) = results else {
+            return Ok(None);
+        };
+
+        let mut conn = Connection::new(prev, next);
+        for stored in results {
+            let cursor = stored.cursor(checkpoint_viewed_at).encode_cursor();
+            conn.edges.push(Edge::new(
+                cursor,
+                CustomTableRow {
+                    stored,
+                    checkpoint_viewed_at,
+                },
+            ));
+        }
+
+        Ok(Some(conn))
+    }
+}
+
+/// Select the latest row of each object in `table` written at or before `checkpoint_viewed_at`,
+/// excluding objects that were deleted or wrapped by then. Rows of tables that keep history are
+/// never modified once written, so for them this is the table as of that checkpoint. The table's
+/// name is quoted and interpolated directly, which is safe because the indexer only registers
+/// tables whose names are plain identifiers.
+fn rows_query(
+    table: &StoredCustomTable,
+    owner: Option<SuiAddress>,
+    checkpoint_viewed_at: u64,
+) -> RawQuery {
+    let system_columns = SYSTEM_COLUMNS
+        .iter()
+        .map(|c| format!("'{c}'"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let columns = format!(
+        "t.object_id, t.object_version, t.checkpoint_sequence_number, t.owner_id, t.deleted, \
+         (to_jsonb(t) - ARRAY[{system_columns}])::text AS fields"
+    );
+
+    let mut latest = if table.history {
+        query!(format!(
+            "SELECT DISTINCT ON (t.object_id) {columns} FROM \"custom_{}\" t",
+            table.name,
+        ))
+        .order_by("t.object_id")
+        .order_by("t.object_version DESC")
+    } else {
+        query!(format!("SELECT {columns} FROM \"custom_{}\" t", table.name))
+    };
+
+    latest = filter!(
+        latest,
+        format!("t.checkpoint_sequence_number <= {checkpoint_viewed_at}")
+    );
+
+    // The cursor pagination logic refers to the table with the `t` alias
+    let mut query = query!(
+        "SELECT t.object_id, t.object_version, t.checkpoint_sequence_number, t.owner_id, t.fields \
+         FROM ({}) t",
+        latest
+    );
+
+    query = filter!(query, "NOT t.deleted");
+
+    if let Some(owner) = owner {
+        query = filter!(
+            query,
+            format!("t.owner_id = '\\x{}'::bytea", hex::encode(owner.into_vec()))
+        );
+    }
+
+    query
+}
+
+impl RawPaginated<Cursor> for StoredCustomRow {
+    fn filter_ge(cursor: &Cursor, query: RawQuery) -> RawQuery {
+        filter!(
+            query,
+            format!(
+                "t.object_id >= '\\x{}'::bytea",
+                hex::encode(&cursor.object_id)
+            )
+        )
+    }
+
+    fn filter_le(cursor: &Cursor, query: RawQuery) -> RawQuery {
+        filter!(
+            query,
+            format!(
+                "t.object_id <= '\\x{}'::bytea",
+                hex::encode(&cursor.object_id)
+            )
+        )
+    }
+
+    fn order(asc: bool, query: RawQuery) -> RawQuery {
+        if asc {
+            query.order_by("t.object_id ASC")
+        } else {
+            query.order_by("t.object_id DESC")
+        }
+    }
+}
+
+impl Target<Cursor> for StoredCustomRow {
+    fn cursor(&self, checkpoint_viewed_at: u64) -> Cursor {
+        Cursor::new(CustomRowCursor {
+            object_id: self.object_id.clone(),
+            checkpoint_viewed_at,
+        })
+    }
+}
+
+impl Checkpointed for Cursor {
+    fn checkpoint_viewed_at(&self) -> u64 {
+        self.checkpoint_viewed_at
+    }
+}
+
+impl ScanLimited for Cursor {}
@@ -11,10 +11,12 @@ use crate::error::Error;
 use crate::server::watermark_task::Watermark;
 
 use super::big_int::BigInt;
-use super::checkpoint::{self, Checkpoint};
+use super::checkpoint::{self, Checkpoint, NetworkProgress};
 use super::cursor::{self, Page, Paginated, ScanLimited, Target};
 use super::date_time::DateTime;
+use super::epoch_metrics::{self, PackageMetrics, SenderMetrics};
 use super::protocol_config::ProtocolConfigs;
+use super::sui_address::SuiAddress;
 use super::system_state_summary::SystemStateSummary;
 use super::transaction_block::{self, TransactionBlock, TransactionBlockFilter};
 use super::uint53::UInt53;
@@ -125,10 +127,11 @@ impl Epoch {
     async fn total_checkpoints(&self, ctx: &Context<'_>) -> Result<Option<UInt53>> {
         let last = match self.stored.last_checkpoint_id {
             Some(last) => last as u64,
-            None => {
-                let Watermark { checkpoint, .. } = *ctx.data_unchecked();
-                checkpoint
-            }
+            // Subscriptions are not served with a fixed watermark, but each result they deliver is
+            // viewed at the latest checkpoint as of when it was fetched.
+            None => ctx
+                .data_opt::<Watermark>()
+                .map_or(self.checkpoint_viewed_at, |w| w.checkpoint),
         };
 
         Ok(Some(UInt53::from(
@@ -145,6 +148,28 @@ impl Epoch {
             .map(|v| UInt53::from(v as u64)))
     }
 
+    /// The average number of transaction blocks executed per second during this epoch. For the
+    /// current epoch, this is the average up to the latest checkpoint.
+    async fn transactions_per_second(&self, ctx: &Context<'_>) -> Result<Option<f64>> {
+        let first = self.stored.first_checkpoint_id as u64;
+        let hi = self
+            .stored
+            .last_checkpoint_id
+            .map_or(self.checkpoint_viewed_at, |id| id as u64);
+
+        let checkpoints = first.checked_sub(1).into_iter().chain([hi]).collect();
+        let progress = Checkpoint::query_network_progress(ctx.data_unchecked(), checkpoints)
+            .await
+            .extend()?;
+
+        let start =
+            epoch_start_progress(first, self.stored.epoch_start_timestamp as u64, &progress);
+
+        Ok(start
+            .zip(progress.get(&hi))
+            .and_then(|(start, end)| start.transactions_per_second(end)))
+    }
+
     /// The total amount of gas fees (in MIST) that were paid in this epoch.
     async fn total_gas_fees(&self) -> Option<BigInt> {
         self.stored.total_gas_fees.map(BigInt::from)
@@ -299,6 +324,88 @@ impl Epoch {
             .await
             .extend()
     }
+
+    /// The packages called during this epoch, ordered by the number of Move calls made to them,
+    /// from most to least.
+    ///
+    /// These metrics are aggregated as checkpoints are indexed, so for the current epoch they
+    /// reflect the latest indexed checkpoint, rather than the checkpoint being viewed.
+    async fn top_packages(
+        &self,
+        ctx: &Context<'_>,
+        first: Option<u64>,
+        after: Option<epoch_metrics::PackageCursor>,
+        last: Option<u64>,
+        before: Option<epoch_metrics::PackageCursor>,
+    ) -> Result<Connection<String, PackageMetrics>> {
+        let page = Page::from_params(ctx.data_unchecked(), first, after, last, before)?;
+        PackageMetrics::paginate(
+            ctx.data_unchecked(),
+            page,
+            self.stored.epoch as u64,
+            self.checkpoint_viewed_at,
+        )
+        .await
+        .extend()
+    }
+
+    /// The addresses that sent transaction blocks during this epoch, ordered by the gas they
+    /// spent, from most to least.
+    ///
+    /// These metrics are aggregated as checkpoints are indexed, so for the current epoch they
+    /// reflect the latest indexed checkpoint, rather than the checkpoint being viewed.
+    async fn top_senders(
+        &self,
+        ctx: &Context<'_>,
+        first: Option<u64>,
+        after: Option<epoch_metrics::SenderCursor>,
+        last: Option<u64>,
+        before: Option<epoch_metrics::SenderCursor>,
+    ) -> Result<Connection<String, SenderMetrics>> {
+        let page = Page::from_params(ctx.data_unchecked(), first, after, last, before)?;
+        SenderMetrics::paginate(
+            ctx.data_unchecked(),
+            page,
+            self.stored.epoch as u64,
+            self.checkpoint_viewed_at,
+        )
+        .await
+        .extend()
+    }
+
+    /// Move call and gas metrics for `package` during this epoch, or `null` if the package was not
+    /// called during this epoch.
+    async fn package_metrics(
+        &self,
+        ctx: &Context<'_>,
+        package: SuiAddress,
+    ) -> Result<Option<PackageMetrics>> {
+        PackageMetrics::query(
+            ctx.data_unchecked(),
+            self.stored.epoch as u64,
+            package,
+            self.checkpoint_viewed_at,
+        )
+        .await
+        .extend()
+    }
+
+    /// Transaction and gas metrics for `sender` during this epoch, or `null` if the address did
+    /// not send any transaction blocks during this epoch.
+    async fn sender_metrics(
+        &self,
+        ctx: &Context<'_>,
+        sender: SuiAddress,
+    ) -> Result<Option<SenderMetrics>> {
+        SenderMetrics::query(
+            ctx.data_unchecked(),
+            self.stored.epoch as u64,
+            sender,
+            self.checkpoint_viewed_at,
+        )
+        .await
+        .extend()
+    }
 }
 
 impl Epoch {
@@ -492,3 +599,62 @@ impl Loader<EpochKey> for Db {
             .collect())
     }
 }
+
+/// The progress of the network at the start of an epoch whose first checkpoint is
+/// `first_checkpoint`, given the progress as of the end of some checkpoints. An epoch starts where
+/// the last checkpoint of the previous epoch ends, except for the first epoch, which starts at
+/// genesis, before any transactions (including those in checkpoint 0) were executed.
+fn epoch_start_progress(
+    first_checkpoint: u64,
+    epoch_start_timestamp_ms: u64,
+    progress: &BTreeMap<u64, NetworkProgress>,
+) -> Option<NetworkProgress> {
+    match first_checkpoint.checked_sub(1) {
+        Some(prev) => progress.get(&prev).copied(),
+        None => Some(NetworkProgress {
+            network_total_transactions: 0,
+            timestamp_ms: epoch_start_timestamp_ms,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn progress(network_total_transactions: u64, timestamp_ms: u64) -> NetworkProgress {
+        NetworkProgress {
+            network_total_transactions,
+            timestamp_ms,
+        }
+    }
+
+    #[test]
+    fn first_epoch_counts_genesis_checkpoint() {
+        // Checkpoint 0 contains the genesis transaction, and its timestamp is the start of epoch 0.
+        let checkpoints = BTreeMap::from([(0, progress(1, 1000)), (9, progress(101, 11000))]);
+
+        let start = epoch_start_progress(0, 1000, &checkpoints).unwrap();
+        assert_eq!(start, progress(0, 1000));
+        assert_eq!(start.transactions_per_second(&checkpoints[&9]), Some(10.1));
+    }
+
+    #[test]
+    fn later_epochs_start_after_previous_epoch() {
+        let checkpoints = BTreeMap::from([(9, progress(101, 11000)), (19, progress(301, 21000))]);
+
+        let start = epoch_start_progress(10, 11000, &checkpoints).unwrap();
+        assert_eq!(start, checkpoints[&9]);
+        assert_eq!(start.transactions_per_second(&checkpoints[&19]), Some(20.0));
+
+        // The end of the previous epoch is no longer available.
+        assert_eq!(epoch_start_progress(20, 21000, &checkpoints), None);
+    }
+
+    #[test]
+    fn no_elapsed_time() {
+        let start = progress(0, 1000);
+        assert_eq!(start.transactions_per_second(&progress(1, 1000)), None);
+        assert_eq!(start.transactions_per_second(&progress(1, 999)), None);
+    }
+}
@@ -0,0 +1,381 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use async_graphql::{
+    connection::{Connection, CursorType, Edge},
+    *,
+};
+use diesel::{BoolExpressionMethods, ExpressionMethods, OptionalExtension, QueryDsl};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use serde::{Deserialize, Serialize};
+use sui_indexer::{
+    models::epoch_metrics::{StoredEpochPackageMetrics, StoredEpochSenderMetrics},
+    schema::{epoch_package_metrics, epoch_sender_metrics},
+};
+
+use crate::{
+    consistency::Checkpointed,
+    data::{self, Db, DbConnection, QueryExecutor},
+    error::Error,
+};
+
+use super::{
+    address::Address,
+    big_int::BigInt,
+    cursor::{self, Page, Paginated, ScanLimited, Target},
+    sui_address::{addr, SuiAddress},
+    uint53::UInt53,
+};
+
+/// Aggregate Move call activity for a package over the course of an epoch.
+#[derive(Clone)]
+pub(crate) struct PackageMetrics {
+    pub stored: StoredEpochPackageMetrics,
+    /// The checkpoint sequence number at which this was viewed at.
+    pub checkpoint_viewed_at: u64,
+}
+
+/// Aggregate transaction activity for a sender over the course of an epoch.
+#[derive(Clone)]
+pub(crate) struct SenderMetrics {
+    pub stored: StoredEpochSenderMetrics,
+    /// The checkpoint sequence number at which this was viewed at.
+    pub checkpoint_viewed_at: u64,
+}
+
+pub(crate) type PackageCursor = cursor::BcsCursor<PackageMetricsCursor>;
+pub(crate) type SenderCursor = cursor::BcsCursor<SenderMetricsCursor>;
+
+type PackageQuery<ST, GB> = data::Query<ST, epoch_package_metrics::table, GB>;
+type SenderQuery<ST, GB> = data::Query<ST, epoch_sender_metrics::table, GB>;
+
+/// The cursor returned for each `PackageMetrics` in a connection's page of results. Packages are
+/// ordered by the number of Move calls made to them (descending), and then by their address.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub(crate) struct PackageMetricsCursor {
+    pub move_calls: u64,
+    pub package: Vec<u8>,
+    pub checkpoint_viewed_at: u64,
+}
+
+/// The cursor returned for each `SenderMetrics` in a connection's page of results. Senders are
+/// ordered by the gas they spent (descending), and then by their address.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub(crate) struct SenderMetricsCursor {
+    pub gas_spent: i64,
+    pub sender: Vec<u8>,
+    pub checkpoint_viewed_at: u64,
+}
+
+/// Aggregate Move call activity for a package over the course of an epoch. Gas spent by a
+/// transaction block is attributed in full to every package it calls into.
+#[Object]
+impl PackageMetrics {
+    /// The address of the package.
+    async fn address(&self) -> Result<SuiAddress> {
+        addr(&self.stored.package).extend()
+    }
+
+    /// The number of Move calls made to functions in this package.
+    async fn move_calls(&self) -> UInt53 {
+        UInt53::from(self.stored.move_calls as u64)
+    }
+
+    /// The number of transaction blocks that called at least one function in this package.
+    async fn transaction_blocks(&self) -> UInt53 {
+        UInt53::from(self.stored.transactions as u64)
+    }
+
+    /// The net gas (computation and storage costs, less storage rebates) spent by transaction
+    /// blocks that called this package, in MIST.
+    async fn gas_spent(&self) -> BigInt {
+        BigInt::from(self.stored.gas_spent)
+    }
+}
+
+/// Aggregate transaction activity for a sender over the course of an epoch.
+#[Object]
+impl SenderMetrics {
+    /// The address that sent the transaction blocks.
+    async fn sender(&self) -> Result<Address> {
+        Ok(Address {
+            address: addr(&self.stored.sender).extend()?,
+            checkpoint_viewed_at: self.checkpoint_viewed_at,
+        })
+    }
+
+    /// The number of transaction blocks sent by this address.
+    async fn transaction_blocks(&self) -> UInt53 {
+        UInt53::from(self.stored.transactions as u64)
+    }
+
+    /// The net gas (computation and storage costs, less storage rebates) spent by transaction
+    /// blocks sent by this address, in MIST.
+    async fn gas_spent(&self) -> BigInt {
+        BigInt::from(self.stored.gas_spent)
+    }
+}
+
+impl PackageMetrics {
+    /// Look up the metrics for `package` in `epoch`. Returns `None` if the package was not called
+    /// in that epoch.
+    pub(crate) async fn query(
+        db: &Db,
+        epoch: u64,
+        package: SuiAddress,
+        checkpoint_viewed_at: u64,
+    ) -> Result<Option<Self>, Error> {
+        use epoch_package_metrics::dsl;
+
+        let stored: Option<StoredEpochPackageMetrics> = db
+            .execute(move |conn| {
+                async move {
+                    conn.first(move || {
+                        dsl::epoch_package_metrics
+                            .filter(dsl::epoch.eq(epoch as i64))
+                            .filter(dsl::package.eq(package.into_vec()))
+                    })
+                    .await
+                    .optional()
+                }
+                .scope_boxed()
+            })
+            .await
+            .map_err(|e| Error::Internal(format!("Failed to fetch package metrics: {e}")))?;
+
+        Ok(stored.map(|stored| PackageMetrics {
+            stored,
+            checkpoint_viewed_at,
+        }))
+    }
+
+    /// Query the database for a `page` of the packages called in `epoch`, ordered by the number
+    /// of Move calls made to them, from most to least.
+    pub(crate) async fn paginate(
+        db: &Db,
+        page: Page<PackageCursor>,
+        epoch: u64,
+        checkpoint_viewed_at: u64,
+    ) -> Result<Connection<String, PackageMetrics>, Error> {
+        use epoch_package_metrics::dsl;
+        let cursor_viewed_at = page.validate_cursor_consistency()?;
+        let checkpoint_viewed_at = cursor_viewed_at.unwrap_or(checkpoint_viewed_at);
+
+        let (prev, next, results) = db
+            .execute(move |conn| {
+                async move {
+                    page.paginate_query::<StoredEpochPackageMetrics, _, _, _>(
+                        conn,
+                        checkpoint_viewed_at,
+                        move || {
+                            dsl::epoch_package_metrics
+                                .filter(dsl::epoch.eq(epoch as i64))
+                                .into_boxed()
+                        },
+                    )
+                    .await
+                }
+                .scope_boxed()
+            })
+            .await?;
+
+        let mut conn = Connection::new(prev, next);
+        for stored in results {
+            let cursor = stored.cursor(checkpoint_viewed_at).encode_cursor();
+            conn.edges.push(Edge::new(
+                cursor,
+                PackageMetrics {
+                    stored,
+                    checkpoint_viewed_at,
+                },
+            ));
+        }
+
+        Ok(conn)
+    }
+}
+
+impl SenderMetrics {
+    /// Look up the metrics for `sender` in `epoch`. Returns `None` if the address did not send any
+    /// transactions in that epoch.
+    pub(crate) async fn query(
+        db: &Db,
+        epoch: u64,
+        sender: SuiAddress,
+        checkpoint_viewed_at: u64,
+    ) -> Result<Option<Self>, Error> {
+        use epoch_sender_metrics::dsl;
+
+        let stored: Option<StoredEpochSenderMetrics> = db
+            .execute(move |conn| {
+                async move {
+                    conn.first(move || {
+                        dsl::epoch_sender_metrics
+                            .filter(dsl::epoch.eq(epoch as i64))
+                            .filter(dsl::sender.eq(sender.into_vec()))
+                    })
+                    .await
+                    .optional()
+                }
+                .scope_boxed()
+            })
+            .await
+            .map_err(|e| Error::Internal(format!("Failed to fetch sender metrics: {e}")))?;
+
+        Ok(stored.map(|stored| SenderMetrics {
+            stored,
+            checkpoint_viewed_at,
+        }))
+    }
+
+    /// Query the database for a `page` of the addresses that sent transactions in `epoch`, ordered
+    /// by the gas they spent, from most to least.
+    pub(crate) async fn paginate(
+        db: &Db,
+        page: Page<SenderCursor>,
+        epoch: u64,
+        checkpoint_viewed_at: u64,
+    ) -> Result<Connection<String, SenderMetrics>, Error> {
+        use epoch_sender_metrics::dsl;
+        let cursor_viewed_at = page.validate_cursor_consistency()?;
+        let checkpoint_viewed_at = cursor_viewed_at.unwrap_or(checkpoint_viewed_at);
+
+        let (prev, next, results) = db
+            .execute(move |conn| {
+                async move {
+                    page.paginate_query::<StoredEpochSenderMetrics, _, _, _>(
+                        conn,
+                        checkpoint_viewed_at,
+                        move || {
+                            dsl::epoch_sender_metrics
+                                .filter(dsl::epoch.eq(epoch as i64))
+                                .into_boxed()
+                        },
+                    )
+                    .await
+                }
+                .scope_boxed()
+            })
+            .await?;
+
+        let mut conn = Connection::new(prev, next);
+        for stored in results {
+            let cursor = stored.cursor(checkpoint_viewed_at).encode_cursor();
+            conn.edges.push(Edge::new(
+                cursor,
+                SenderMetrics {
+                    stored,
+                    checkpoint_viewed_at,
+                },
+            ));
+        }
+
+        Ok(conn)
+    }
+}
+
+impl Paginated<PackageCursor> for StoredEpochPackageMetrics {
+    type Source = epoch_package_metrics::table;
+
+    fn filter_ge<ST, GB>(
+        cursor: &PackageCursor,
+        query: PackageQuery<ST, GB>,
+    ) -> PackageQuery<ST, GB> {
+        use epoch_package_metrics::dsl;
+        let move_calls = cursor.move_calls as i64;
+        query.filter(
+            dsl::move_calls.lt(move_calls).or(dsl::move_calls
+                .eq(move_calls)
+                .and(dsl::package.ge(cursor.package.clone()))),
+        )
+    }
+
+    fn filter_le<ST, GB>(
+        cursor: &PackageCursor,
+        query: PackageQuery<ST, GB>,
+    ) -> PackageQuery<ST, GB> {
+        use epoch_package_metrics::dsl;
+        let move_calls = cursor.move_calls as i64;
+        query.filter(
+            dsl::move_calls.gt(move_calls).or(dsl::move_calls
+                .eq(move_calls)
+                .and(dsl::package.le(cursor.package.clone()))),
+        )
+    }
+
+    fn order<ST, GB>(asc: bool, query: PackageQuery<ST, GB>) -> PackageQuery<ST, GB> {
+        use epoch_package_metrics::dsl;
+        if asc {
+            query.order((dsl::move_calls.desc(), dsl::package.asc()))
+        } else {
+            query.order((dsl::move_calls.asc(), dsl::package.desc()))
+        }
+    }
+}
+
+impl Paginated<SenderCursor> for StoredEpochSenderMetrics {
+    type Source = epoch_sender_metrics::table;
+
+    fn filter_ge<ST, GB>(cursor: &SenderCursor, query: SenderQuery<ST, GB>) -> SenderQuery<ST, GB> {
+        use epoch_sender_metrics::dsl;
+        query.filter(
+            dsl::gas_spent.lt(cursor.gas_spent).or(dsl::gas_spent
+                .eq(cursor.gas_spent)
+                .and(dsl::sender.ge(cursor.sender.clone()))),
+        )
+    }
+
+    fn filter_le<ST, GB>(cursor: &SenderCursor, query: SenderQuery<ST, GB>) -> SenderQuery<ST, GB> {
+        use epoch_sender_metrics::dsl;
+        query.filter(
+            dsl::gas_spent.gt(cursor.gas_spent).or(dsl::gas_spent
+                .eq(cursor.gas_spent)
+                .and(dsl::sender.le(cursor.sender.clone()))),
+        )
+    }
+
+    fn order<ST, GB>(asc: bool, query: SenderQuery<ST, GB>) -> SenderQuery<ST, GB> {
+        use epoch_sender_metrics::dsl;
+        if asc {
+            query.order((dsl::gas_spent.desc(), dsl::sender.asc()))
+        } else {
+            query.order((dsl::gas_spent.asc(), dsl::sender.desc()))
+        }
+    }
+}
+
+impl Target<PackageCursor> for StoredEpochPackageMetrics {
+    fn cursor(&self, checkpoint_viewed_at: u64) -> PackageCursor {
+        PackageCursor::new(PackageMetricsCursor {
+            move_calls: self.move_calls as u64,
+            package: self.package.clone(),
+            checkpoint_viewed_at,
+        })
+    }
+}
+
+impl Target<SenderCursor> for StoredEpochSenderMetrics {
+    fn cursor(&self, checkpoint_viewed_at: u64) -> SenderCursor {
+        SenderCursor::new(SenderMetricsCursor {
+            gas_spent: self.gas_spent,
+            sender: self.sender.clone(),
+            checkpoint_viewed_at,
+        })
+    }
+}
+
+impl Checkpointed for PackageCursor {
+    fn checkpoint_viewed_at(&self) -> u64 {
+        self.checkpoint_viewed_at
+    }
+}
+
+impl Checkpointed for SenderCursor {
+    fn checkpoint_viewed_at(&self) -> u64 {
+        self.checkpoint_viewed_at
+    }
+}
+
+impl ScanLimited for PackageCursor {}
+
+impl ScanLimited for SenderCursor {}
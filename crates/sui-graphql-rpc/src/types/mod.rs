@@ -2,6 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub(crate) mod address;
+pub(crate) mod address_activity;
+pub(crate) mod api_key_usage;
 pub(crate) mod available_range;
 pub(crate) mod balance;
 pub(crate) mod balance_change;
@@ -12,6 +14,7 @@ pub(crate) mod checkpoint;
 pub(crate) mod coin;
 pub(crate) mod coin_metadata;
 pub(crate) mod cursor;
+pub(crate) mod custom_table;
 pub(crate) mod datatype;
 pub(crate) mod date_time;
 pub(crate) mod digest;
@@ -19,6 +22,7 @@ pub(crate) mod display;
 pub(crate) mod dry_run_result;
 pub(crate) mod dynamic_field;
 pub(crate) mod epoch;
+pub(crate) mod epoch_metrics;
 pub(crate) mod event;
 pub(crate) mod execution_result;
 pub(crate) mod gas;
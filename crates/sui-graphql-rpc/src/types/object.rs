@@ -9,7 +9,7 @@ use super::balance::{self, Balance};
 use super::big_int::BigInt;
 use super::coin::Coin;
 use super::coin_metadata::CoinMetadata;
-use super::cursor::{self, Page, RawPaginated, ScanLimited, Target};
+use super::cursor::{self, Page, Paginated, RawPaginated, ScanLimited, Target};
 use super::digest::Digest;
 use super::display::{Display, DisplayEntry};
 use super::dynamic_field::{DynamicField, DynamicFieldName};
@@ -27,7 +27,7 @@ use super::{owner::Owner, sui_address::SuiAddress, transaction_block::Transactio
 use crate::connection::ScanConnection;
 use crate::consistency::{build_objects_query, Checkpointed, View};
 use crate::data::package_resolver::PackageResolver;
-use crate::data::{DataLoader, Db, DbConnection, QueryExecutor};
+use crate::data::{DataLoader, Db, DbConnection, Query, QueryExecutor};
 use crate::error::Error;
 use crate::raw_query::RawQuery;
 use crate::types::base64::Base64;
@@ -43,7 +43,7 @@ use move_core_types::language_storage::StructTag;
 use serde::{Deserialize, Serialize};
 use sui_indexer::models::obj_indices::StoredObjectVersion;
 use sui_indexer::models::objects::{StoredFullHistoryObject, StoredHistoryObject};
-use sui_indexer::schema::{full_objects_history, objects_version};
+use sui_indexer::schema::{full_objects_history, objects_history, objects_version};
 use sui_indexer::types::ObjectStatus as NativeObjectStatus;
 use sui_indexer::types::OwnerType;
 use sui_types::object::bounded_visitor::BoundedVisitor;
@@ -133,6 +133,18 @@ pub(crate) struct ObjectFilter {
     pub object_keys: Option<Vec<ObjectKey>>,
 }
 
+/// Filter for paginating the versions of an `Object` by the checkpoint they were written in.
+#[derive(InputObject, Debug, Default, Clone, Eq, PartialEq)]
+pub(crate) struct ObjectHistoryFilter {
+    /// Fetch versions that were written strictly after this checkpoint. Omitting this fetches
+    /// versions written since genesis.
+    pub after_checkpoint: Option<UInt53>,
+
+    /// Fetch versions that were written strictly before this checkpoint. Omitting this fetches
+    /// versions written up to the latest checkpoint (inclusive).
+    pub before_checkpoint: Option<UInt53>,
+}
+
 #[derive(InputObject, Debug, Clone, Eq, PartialEq)]
 pub(crate) struct ObjectKey {
     pub object_id: SuiAddress,
@@ -217,6 +229,19 @@ pub(crate) struct HistoricalObjectCursor {
     checkpoint_viewed_at: u64,
 }
 
+pub(crate) type HistoryCursor = cursor::BcsCursor<ObjectHistoryCursor>;
+
+/// The cursor returned for each version of an object in `Object.history`. Versions are ordered by
+/// their version number.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub(crate) struct ObjectHistoryCursor {
+    #[serde(rename = "v")]
+    version: u64,
+    /// The checkpoint sequence number this was viewed at.
+    #[serde(rename = "c")]
+    checkpoint_viewed_at: u64,
+}
+
 /// Interface implemented by on-chain values that are addressable by an ID (also referred to as its
 /// address). This includes Move objects and packages.
 #[allow(clippy::duplicated_attributes)]
@@ -557,6 +582,32 @@ impl Object {
             .await
     }
 
+    /// Versions of this object, ordered by version, optionally `filter`-ed to versions written
+    /// within a range of checkpoints. Versions at which the object was wrapped or deleted are not
+    /// included, and neither are versions written after the checkpoint this object is being
+    /// viewed at.
+    async fn history(
+        &self,
+        ctx: &Context<'_>,
+        first: Option<u64>,
+        after: Option<HistoryCursor>,
+        last: Option<u64>,
+        before: Option<HistoryCursor>,
+        filter: Option<ObjectHistoryFilter>,
+    ) -> Result<Connection<String, Object>> {
+        let page = Page::from_params(ctx.data_unchecked(), first, after, last, before)?;
+
+        Object::paginate_history(
+            ctx.data_unchecked(),
+            page,
+            self.address,
+            filter,
+            self.checkpoint_viewed_at,
+        )
+        .await
+        .extend()
+    }
+
     /// Attempts to convert the object into a MoveObject
     async fn as_move_object(&self) -> Option<MoveObject> {
         MoveObject::try_from(self).ok()
@@ -883,6 +934,77 @@ impl Object {
         }
     }
 
+    /// Query the database for a `page` of the versions of the object at `address`, optionally
+    /// limited to versions written strictly within the checkpoint range given by `filter`.
+    ///
+    /// `checkpoint_viewed_at` represents the checkpoint sequence number at which this page was
+    /// queried for. Versions written after this checkpoint are not included, and each `Object`
+    /// will be viewed at this checkpoint.
+    pub(crate) async fn paginate_history(
+        db: &Db,
+        page: Page<HistoryCursor>,
+        address: SuiAddress,
+        filter: Option<ObjectHistoryFilter>,
+        checkpoint_viewed_at: u64,
+    ) -> Result<Connection<String, Object>, Error> {
+        use objects_history::dsl;
+
+        let cursor_viewed_at = page.validate_cursor_consistency()?;
+        let checkpoint_viewed_at = cursor_viewed_at.unwrap_or(checkpoint_viewed_at);
+
+        let after_checkpoint: Option<u64> = filter
+            .as_ref()
+            .and_then(|f| f.after_checkpoint)
+            .map(|v| v.into());
+
+        // Clamp the "before checkpoint" bound by "checkpoint viewed at".
+        let before_checkpoint = filter
+            .as_ref()
+            .and_then(|f| f.before_checkpoint)
+            .map(|v| v.into())
+            .unwrap_or(u64::MAX)
+            .min(checkpoint_viewed_at + 1);
+
+        let (prev, next, results) = db
+            .execute(move |conn| {
+                async move {
+                    page.paginate_query::<StoredHistoryObject, _, _, _>(
+                        conn,
+                        checkpoint_viewed_at,
+                        move || {
+                            let mut query = dsl::objects_history
+                                .filter(dsl::object_id.eq(address.into_vec()))
+                                .filter(dsl::object_status.eq(NativeObjectStatus::Active as i16))
+                                .filter(
+                                    dsl::checkpoint_sequence_number.lt(before_checkpoint as i64),
+                                )
+                                .into_boxed();
+
+                            if let Some(after) = after_checkpoint {
+                                query =
+                                    query.filter(dsl::checkpoint_sequence_number.gt(after as i64));
+                            }
+
+                            query
+                        },
+                    )
+                    .await
+                }
+                .scope_boxed()
+            })
+            .await?;
+
+        let mut conn = Connection::new(prev, next);
+        for stored in results {
+            let cursor = stored.cursor(checkpoint_viewed_at).encode_cursor();
+            let object =
+                Object::try_from_stored_history_object(stored, checkpoint_viewed_at, None)?;
+            conn.edges.push(Edge::new(cursor, object));
+        }
+
+        Ok(conn)
+    }
+
     pub(crate) async fn query(
         ctx: &Context<'_>,
         id: SuiAddress,
@@ -1193,6 +1315,50 @@ impl Checkpointed for Cursor {
 
 impl ScanLimited for Cursor {}
 
+impl Checkpointed for HistoryCursor {
+    fn checkpoint_viewed_at(&self) -> u64 {
+        self.checkpoint_viewed_at
+    }
+}
+
+impl ScanLimited for HistoryCursor {}
+
+impl Paginated<HistoryCursor> for StoredHistoryObject {
+    type Source = objects_history::table;
+
+    fn filter_ge<ST, GB>(
+        cursor: &HistoryCursor,
+        query: Query<ST, Self::Source, GB>,
+    ) -> Query<ST, Self::Source, GB> {
+        query.filter(objects_history::dsl::object_version.ge(cursor.version as i64))
+    }
+
+    fn filter_le<ST, GB>(
+        cursor: &HistoryCursor,
+        query: Query<ST, Self::Source, GB>,
+    ) -> Query<ST, Self::Source, GB> {
+        query.filter(objects_history::dsl::object_version.le(cursor.version as i64))
+    }
+
+    fn order<ST, GB>(asc: bool, query: Query<ST, Self::Source, GB>) -> Query<ST, Self::Source, GB> {
+        use objects_history::dsl;
+        if asc {
+            query.order(dsl::object_version)
+        } else {
+            query.order(dsl::object_version.desc())
+        }
+    }
+}
+
+impl Target<HistoryCursor> for StoredHistoryObject {
+    fn cursor(&self, checkpoint_viewed_at: u64) -> HistoryCursor {
+        HistoryCursor::new(ObjectHistoryCursor {
+            version: self.object_version as u64,
+            checkpoint_viewed_at,
+        })
+    }
+}
+
 impl RawPaginated<Cursor> for StoredHistoryObject {
     fn filter_ge(cursor: &Cursor, query: RawQuery) -> RawQuery {
         filter!(
@@ -21,12 +21,14 @@ use super::suins_registration::NameService;
 use super::uint53::UInt53;
 use super::{
     address::Address,
+    api_key_usage::ApiKeyUsage,
     available_range::AvailableRange,
     chain_identifier::ChainIdentifier,
     checkpoint::{self, Checkpoint, CheckpointId},
     coin::Coin,
     coin_metadata::CoinMetadata,
     cursor::Page,
+    custom_table::{self, CustomTableRow},
     digest::Digest,
     dry_run_result::DryRunResult,
     epoch::{self, Epoch},
@@ -42,15 +44,17 @@ use super::{
     type_filter::ExactTypeFilter,
 };
 use crate::connection::ScanConnection;
+use crate::extensions::response_cache::ResolvedCheckpoint;
+use crate::server::api_keys::ApiKeyQuotas;
 use crate::server::watermark_task::Watermark;
 use crate::types::base64::Base64 as GraphQLBase64;
 use crate::types::zklogin_verify_signature::verify_zklogin_signature;
 use crate::types::zklogin_verify_signature::ZkLoginIntentScope;
 use crate::types::zklogin_verify_signature::ZkLoginVerifyResult;
-use crate::{config::ServiceConfig, error::Error, mutation::Mutation};
+use crate::{config::ServiceConfig, error::Error, mutation::Mutation, subscription::Subscription};
 
 pub(crate) struct Query;
-pub(crate) type SuiGraphQLSchema = async_graphql::Schema<Query, Mutation, EmptySubscription>;
+pub(crate) type SuiGraphQLSchema = async_graphql::Schema<Query, Mutation, Subscription>;
 
 #[Object]
 impl Query {
@@ -88,6 +92,13 @@ impl Query {
             .extend()
     }
 
+    /// Usage of the API key that this request was made with, over the current window. `null` if
+    /// the request was not made with an API key, or if the service does not track API keys.
+    async fn api_key_usage(&self, ctx: &Context<'_>) -> Option<ApiKeyUsage> {
+        let quotas: &ApiKeyQuotas = ctx.data_opt()?;
+        quotas.usage(ctx.data_opt()?)
+    }
+
     /// Simulate running a transaction to inspect its effects without
     /// committing to them on-chain.
     ///
@@ -343,6 +354,7 @@ impl Query {
         let Watermark { checkpoint, .. } = *ctx.data()?;
 
         let page = Page::from_params(ctx.data_unchecked(), first, after, last, before)?;
+        ResolvedCheckpoint::pin_to_page(ctx, &page);
         let coin = type_.map_or_else(GAS::type_tag, |t| t.0);
         Coin::paginate(
             ctx.data_unchecked(),
@@ -367,6 +379,7 @@ impl Query {
         let Watermark { checkpoint, .. } = *ctx.data()?;
 
         let page = Page::from_params(ctx.data_unchecked(), first, after, last, before)?;
+        ResolvedCheckpoint::pin_to_page(ctx, &page);
         Epoch::paginate(ctx.data_unchecked(), page, checkpoint)
             .await
             .extend()
@@ -384,6 +397,7 @@ impl Query {
         let Watermark { checkpoint, .. } = *ctx.data()?;
 
         let page = Page::from_params(ctx.data_unchecked(), first, after, last, before)?;
+        ResolvedCheckpoint::pin_to_page(ctx, &page);
         Checkpoint::paginate(
             ctx.data_unchecked(),
             page,
@@ -427,6 +441,7 @@ impl Query {
         let Watermark { checkpoint, .. } = *ctx.data()?;
 
         let page = Page::from_params(ctx.data_unchecked(), first, after, last, before)?;
+        ResolvedCheckpoint::pin_to_page(ctx, &page);
 
         TransactionBlock::paginate(
             ctx,
@@ -454,6 +469,7 @@ impl Query {
         let Watermark { checkpoint, .. } = *ctx.data()?;
 
         let page = Page::from_params(ctx.data_unchecked(), first, after, last, before)?;
+        ResolvedCheckpoint::pin_to_page(ctx, &page);
         Event::paginate(
             ctx.data_unchecked(),
             page,
@@ -477,6 +493,7 @@ impl Query {
         let Watermark { checkpoint, .. } = *ctx.data()?;
 
         let page = Page::from_params(ctx.data_unchecked(), first, after, last, before)?;
+        ResolvedCheckpoint::pin_to_page(ctx, &page);
         Object::paginate(
             ctx.data_unchecked(),
             page,
@@ -487,6 +504,32 @@ impl Query {
         .extend()
     }
 
+    /// Rows of a custom table, which the indexer maintains by projecting fields of objects of a
+    /// particular type into columns, optionally limited to objects owned by `owner`. There is a row
+    /// for each object that exists as of the checkpoint being viewed, ordered by object ID. Returns
+    /// `null` if the indexer does not maintain a custom table called `name`.
+    ///
+    /// Tables that do not keep history only hold the latest version of each object, so objects
+    /// modified after the checkpoint being viewed are missing from them.
+    async fn custom_table(
+        &self,
+        ctx: &Context<'_>,
+        name: String,
+        owner: Option<SuiAddress>,
+        first: Option<u64>,
+        after: Option<custom_table::Cursor>,
+        last: Option<u64>,
+        before: Option<custom_table::Cursor>,
+    ) -> Result<Option<Connection<String, CustomTableRow>>> {
+        let Watermark { checkpoint, .. } = *ctx.data()?;
+
+        let page = Page::from_params(ctx.data_unchecked(), first, after, last, before)?;
+        ResolvedCheckpoint::pin_to_page(ctx, &page);
+        CustomTableRow::paginate(ctx.data_unchecked(), page, name, owner, checkpoint)
+            .await
+            .extend()
+    }
+
     /// The Move packages that exist in the network, optionally filtered to be strictly before
     /// `beforeCheckpoint` and/or strictly after `afterCheckpoint`.
     ///
@@ -504,6 +547,7 @@ impl Query {
         let Watermark { checkpoint, .. } = *ctx.data()?;
 
         let page = Page::from_params(ctx.data_unchecked(), first, after, last, before)?;
+        ResolvedCheckpoint::pin_to_page(ctx, &page);
         MovePackage::paginate_by_checkpoint(ctx.data_unchecked(), page, filter, checkpoint)
             .await
             .extend()
@@ -525,6 +569,7 @@ impl Query {
         let Watermark { checkpoint, .. } = *ctx.data()?;
 
         let page = Page::from_params(ctx.data_unchecked(), first, after, last, before)?;
+        ResolvedCheckpoint::pin_to_page(ctx, &page);
         MovePackage::paginate_by_version(ctx.data_unchecked(), page, address, filter, checkpoint)
             .await
             .extend()
@@ -13,7 +13,7 @@ use super::{
     transaction_block_kind::TransactionBlockKind,
 };
 use crate::{
-    config::ServiceConfig,
+    config::{Limits, ServiceConfig},
     connection::ScanConnection,
     data::{self, DataLoader, Db, DbConnection, QueryExecutor},
     error::Error,
@@ -46,7 +46,7 @@ mod cursor;
 mod filter;
 mod tx_lookups;
 
-pub(crate) use cursor::Cursor;
+pub(crate) use cursor::{Cursor, TransactionBlockCursor};
 pub(crate) use filter::TransactionBlockFilter;
 pub(crate) use tx_lookups::{subqueries, TxBounds};
 
@@ -170,8 +170,8 @@ impl TransactionBlock {
             // Non-stored transactions have a sentinel checkpoint_viewed_at value that generally
             // prevents access to further queries, but inputs should generally be available so try
             // to access them at the high watermark.
-            let Watermark { checkpoint, .. } = *ctx.data_unchecked();
-            checkpoint
+            ctx.data_opt::<Watermark>()
+                .map_or(self.checkpoint_viewed_at, |w| w.checkpoint)
         };
 
         Some(GasInput::from(
@@ -349,8 +349,28 @@ impl TransactionBlock {
         checkpoint_viewed_at: u64,
         scan_limit: Option<u64>,
     ) -> Result<ScanConnection<String, TransactionBlock>, Error> {
-        let limits = &ctx.data_unchecked::<ServiceConfig>().limits;
+        Self::paginate_with_db(
+            ctx.data_unchecked(),
+            &ctx.data_unchecked::<ServiceConfig>().limits,
+            page,
+            filter,
+            checkpoint_viewed_at,
+            scan_limit,
+        )
+        .await
+    }
 
+    /// Like `paginate`, but with the `Db` and `Limits` passed in directly rather than read from a
+    /// GraphQL `Context`, so that it can be called from outside of a resolver (e.g. from the stream
+    /// backing a subscription).
+    pub(crate) async fn paginate_with_db(
+        db: &Db,
+        limits: &Limits,
+        page: Page<Cursor>,
+        filter: TransactionBlockFilter,
+        checkpoint_viewed_at: u64,
+        scan_limit: Option<u64>,
+    ) -> Result<ScanConnection<String, TransactionBlock>, Error> {
         // If the caller has provided some arbitrary combination of `function`, `kind`,
         // `recvAddress`, `inputObject`, or `changedObject`, we require setting a `scanLimit`.
         if let Some(scan_limit) = scan_limit {
@@ -383,7 +403,6 @@ impl TransactionBlock {
 
         let cursor_viewed_at = page.validate_cursor_consistency()?;
         let checkpoint_viewed_at = cursor_viewed_at.unwrap_or(checkpoint_viewed_at);
-        let db: &Db = ctx.data_unchecked();
         let is_from_front = page.is_from_front();
 
         use transactions::dsl as tx;
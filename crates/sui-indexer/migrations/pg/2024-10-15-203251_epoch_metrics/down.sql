@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS epoch_sender_metrics;
+DROP TABLE IF EXISTS epoch_package_metrics;
@@ -0,0 +1,31 @@
+-- Per-epoch aggregates of Move call activity and gas usage by package. Rows are updated
+-- incrementally as checkpoints are committed, and are never pruned.
+CREATE TABLE epoch_package_metrics
+(
+    epoch                               BIGINT       NOT NULL,
+    package                             BYTEA        NOT NULL,
+    -- Number of Move calls to functions in this package, across all transactions in the epoch.
+    move_calls                          BIGINT       NOT NULL,
+    -- Number of transactions that called at least one function in this package.
+    transactions                        BIGINT       NOT NULL,
+    -- Net gas (computation + storage - rebate) spent by transactions calling this package.
+    gas_spent                           BIGINT       NOT NULL,
+    -- The last checkpoint whose contents have been folded into this row. Updates from checkpoints
+    -- at or below this watermark are ignored, so re-committing a checkpoint is idempotent.
+    checkpoint_sequence_number          BIGINT       NOT NULL,
+    PRIMARY KEY (epoch, package)
+);
+CREATE INDEX epoch_package_metrics_move_calls ON epoch_package_metrics (epoch, move_calls DESC, package);
+CREATE INDEX epoch_package_metrics_gas_spent ON epoch_package_metrics (epoch, gas_spent DESC, package);
+
+-- Per-epoch aggregates of transaction count and gas usage by sender.
+CREATE TABLE epoch_sender_metrics
+(
+    epoch                               BIGINT       NOT NULL,
+    sender                              BYTEA        NOT NULL,
+    transactions                        BIGINT       NOT NULL,
+    gas_spent                           BIGINT       NOT NULL,
+    checkpoint_sequence_number          BIGINT       NOT NULL,
+    PRIMARY KEY (epoch, sender)
+);
+CREATE INDEX epoch_sender_metrics_gas_spent ON epoch_sender_metrics (epoch, gas_spent DESC, sender);
@@ -0,0 +1 @@
+DROP INDEX CONCURRENTLY IF EXISTS tx_digests_digest_sequence_number;
@@ -0,0 +1,3 @@
+CREATE UNIQUE INDEX CONCURRENTLY IF NOT EXISTS
+    tx_digests_digest_sequence_number
+ON  tx_digests (tx_digest, tx_sequence_number);
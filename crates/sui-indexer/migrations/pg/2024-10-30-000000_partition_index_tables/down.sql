@@ -0,0 +1,101 @@
+-- Converts a table partitioned by `partition_by_epoch` back into an unpartitioned table. While it
+-- still has a single partition, that partition is detached and renamed in place. Once the table
+-- has been advanced past an epoch, its rows are copied into a new table with the same columns and
+-- indexes, which replaces it.
+CREATE OR REPLACE PROCEDURE unpartition_by_epoch(table_name TEXT)
+LANGUAGE plpgsql
+AS $$
+DECLARE
+    partition_count BIGINT;
+    partition_name TEXT;
+    unpartitioned_name TEXT;
+    index_name TEXT;
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_class WHERE relname = table_name AND relkind = 'p') THEN
+        RETURN;
+    END IF;
+
+    SELECT COUNT(*), MIN(child.relname) INTO partition_count, partition_name
+    FROM pg_inherits
+             JOIN pg_class parent ON pg_inherits.inhparent = parent.oid
+             JOIN pg_class child ON pg_inherits.inhrelid = child.oid
+    WHERE parent.relname = table_name;
+
+    IF partition_count = 1 THEN
+        EXECUTE format('ALTER TABLE %I DETACH PARTITION %I', table_name, partition_name);
+        EXECUTE format('DROP TABLE %I', table_name);
+        EXECUTE format('ALTER TABLE %I RENAME TO %I', partition_name, table_name);
+        RETURN;
+    END IF;
+
+    unpartitioned_name := format('%s_unpartitioned', table_name);
+    EXECUTE format('CREATE TABLE %I (LIKE %I INCLUDING ALL)', unpartitioned_name, table_name);
+    EXECUTE format('INSERT INTO %I SELECT * FROM %I', unpartitioned_name, table_name);
+    EXECUTE format('DROP TABLE %I', table_name);
+    EXECUTE format('ALTER TABLE %I RENAME TO %I', unpartitioned_name, table_name);
+
+    FOR index_name IN SELECT indexname FROM pg_indexes WHERE tablename = table_name LOOP
+        EXECUTE format('ALTER INDEX %I RENAME TO %I', index_name, replace(index_name, unpartitioned_name, table_name));
+    END LOOP;
+END;
+$$;
+
+BEGIN;
+
+DO $$
+DECLARE
+    table_name TEXT;
+BEGIN
+    FOREACH table_name IN ARRAY ARRAY[
+        'tx_senders',
+        'tx_recipients',
+        'tx_input_objects',
+        'tx_changed_objects',
+        'tx_calls_pkg',
+        'tx_calls_mod',
+        'tx_calls_fun',
+        'tx_kinds',
+        'tx_affected_addresses',
+        'tx_affected_objects',
+        'event_emit_package',
+        'event_emit_module',
+        'event_senders',
+        'event_struct_package',
+        'event_struct_module',
+        'event_struct_name',
+        'event_struct_instantiation',
+        'checkpoints',
+        'pruner_cp_watermark',
+        'tx_digests'
+    ] LOOP
+        CALL unpartition_by_epoch(table_name);
+    END LOOP;
+END;
+$$;
+
+-- A copied `tx_digests` only has the partitioned table's unique index on the digest and sequence
+-- number, so its original primary key and sequence number index are restored.
+DROP INDEX IF EXISTS tx_digests_tx_digest_tx_sequence_number_idx;
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_constraint WHERE conname = 'tx_digests_pkey') THEN
+        ALTER TABLE tx_digests ADD PRIMARY KEY (tx_digest);
+    END IF;
+END;
+$$;
+CREATE INDEX IF NOT EXISTS tx_digests_tx_sequence_number ON tx_digests (tx_sequence_number);
+
+DROP PROCEDURE IF EXISTS unpartition_by_epoch;
+DROP PROCEDURE IF EXISTS partition_by_epoch;
+
+CREATE OR REPLACE PROCEDURE advance_partition(table_name TEXT, last_epoch BIGINT, new_epoch BIGINT, last_epoch_start BIGINT, new_epoch_start BIGINT)
+LANGUAGE plpgsql
+AS $$
+BEGIN
+    EXECUTE format('ALTER TABLE %I DETACH PARTITION %I_partition_%s', table_name, table_name, last_epoch);
+    EXECUTE format('ALTER TABLE %I ATTACH PARTITION %I_partition_%s FOR VALUES FROM (%L) TO (%L)', table_name, table_name, last_epoch, last_epoch_start, new_epoch_start);
+    EXECUTE format('CREATE TABLE IF NOT EXISTS %I_partition_%s PARTITION OF %I FOR VALUES FROM (%L) TO (MAXVALUE)', table_name, new_epoch, table_name, new_epoch_start);
+END;
+$$;
+
+COMMIT;
@@ -0,0 +1,164 @@
+-- Converts the lookup tables, `checkpoints`, `pruner_cp_watermark` and `tx_digests` into tables
+-- partitioned by epoch, without copying any rows, so that the pruner can drop them an epoch at a
+-- time. Each existing table is renamed and attached as the partition for the current epoch,
+-- covering every value up to MAXVALUE. It is narrowed to its real range the next time
+-- `advance_partition` closes the epoch, and dropped by the pruner once that epoch falls out of the
+-- retention window.
+--
+-- This migration does not run in a single transaction, so that it can be applied to a live
+-- database. Each table is converted in three steps, each in its own transaction:
+--
+-- 1. A CHECK constraint implying the partition bound is added as NOT VALID, which only needs a
+--    brief lock.
+-- 2. The constraint is validated, which scans the table, but does not block reads or writes.
+-- 3. The table is swapped for its partitioned replacement. The validated constraint lets the
+--    attach skip scanning the table, so the ACCESS EXCLUSIVE locks are only held for as long as
+--    it takes to update the catalog, and `lock_timeout` stops the swap from queueing up other
+--    queries behind a long-running one.
+--
+-- Every step is idempotent, so a migration that fails part way through can be re-run.
+
+CREATE OR REPLACE PROCEDURE add_partition_check(table_name TEXT, partition_column TEXT)
+LANGUAGE plpgsql
+AS $$
+BEGIN
+    IF EXISTS (SELECT 1 FROM pg_class WHERE relname = table_name AND relkind = 'p')
+        OR EXISTS (SELECT 1 FROM pg_constraint WHERE conname = format('%s_partition_check', table_name)) THEN
+        RETURN;
+    END IF;
+
+    EXECUTE format('ALTER TABLE %I ADD CONSTRAINT %I CHECK (%I >= 0) NOT VALID', table_name, format('%s_partition_check', table_name), partition_column);
+END;
+$$;
+
+CREATE OR REPLACE PROCEDURE validate_partition_check(table_name TEXT)
+LANGUAGE plpgsql
+AS $$
+BEGIN
+    IF EXISTS (SELECT 1 FROM pg_constraint WHERE conname = format('%s_partition_check', table_name) AND NOT convalidated) THEN
+        EXECUTE format('ALTER TABLE %I VALIDATE CONSTRAINT %I', table_name, format('%s_partition_check', table_name));
+    END IF;
+END;
+$$;
+
+-- Swaps `table_name` for a table partitioned by epoch on `partition_column`, with the original
+-- table attached as the partition for the current epoch. The parent copies the original table's
+-- indexes, unless `unique_columns` is given, in which case it only gets a unique index on those
+-- columns. This is needed for tables whose primary key does not include `partition_column`, which
+-- a partitioned table cannot have. The original table must already have a matching unique index,
+-- so that the attach does not need to build one.
+CREATE OR REPLACE PROCEDURE partition_by_epoch(table_name TEXT, partition_column TEXT, unique_columns TEXT DEFAULT NULL)
+LANGUAGE plpgsql
+AS $$
+DECLARE
+    current_epoch BIGINT;
+    partition_name TEXT;
+BEGIN
+    IF EXISTS (SELECT 1 FROM pg_class WHERE relname = table_name AND relkind = 'p') THEN
+        RETURN;
+    END IF;
+
+    SELECT COALESCE(MAX(epoch), 0) INTO current_epoch FROM epochs;
+    partition_name := format('%s_partition_%s', table_name, current_epoch);
+
+    EXECUTE format('ALTER TABLE %I RENAME TO %I', table_name, partition_name);
+    IF unique_columns IS NULL THEN
+        EXECUTE format('CREATE TABLE %I (LIKE %I INCLUDING ALL EXCLUDING CONSTRAINTS) PARTITION BY RANGE (%I)', table_name, partition_name, partition_column);
+    ELSE
+        EXECUTE format('CREATE TABLE %I (LIKE %I INCLUDING ALL EXCLUDING CONSTRAINTS EXCLUDING INDEXES) PARTITION BY RANGE (%I)', table_name, partition_name, partition_column);
+        EXECUTE format('CREATE UNIQUE INDEX ON %I (%s)', table_name, unique_columns);
+    END IF;
+    EXECUTE format('ALTER TABLE %I ATTACH PARTITION %I FOR VALUES FROM (0) TO (MAXVALUE)', table_name, partition_name);
+    EXECUTE format('ALTER TABLE %I DROP CONSTRAINT IF EXISTS %I', partition_name, format('%s_partition_check', table_name));
+END;
+$$;
+
+-- A partition created by `partition_by_epoch` holds every row written before the table was
+-- partitioned, so when its epoch is closed it keeps its original lower bound instead of starting
+-- at `last_epoch_start`.
+CREATE OR REPLACE PROCEDURE advance_partition(table_name TEXT, last_epoch BIGINT, new_epoch BIGINT, last_epoch_start BIGINT, new_epoch_start BIGINT)
+LANGUAGE plpgsql
+AS $$
+DECLARE
+    lower_bound BIGINT;
+BEGIN
+    SELECT CAST(SUBSTRING(pg_get_expr(child.relpartbound, child.oid) FROM 'FROM \(''?(\d+)''?\)') AS BIGINT)
+    INTO lower_bound
+    FROM pg_class child
+    WHERE child.relname = format('%s_partition_%s', table_name, last_epoch);
+
+    lower_bound := LEAST(COALESCE(lower_bound, last_epoch_start), last_epoch_start);
+
+    EXECUTE format('ALTER TABLE %I DETACH PARTITION %I_partition_%s', table_name, table_name, last_epoch);
+    EXECUTE format('ALTER TABLE %I ATTACH PARTITION %I_partition_%s FOR VALUES FROM (%L) TO (%L)', table_name, table_name, last_epoch, lower_bound, new_epoch_start);
+    EXECUTE format('CREATE TABLE IF NOT EXISTS %I_partition_%s PARTITION OF %I FOR VALUES FROM (%L) TO (MAXVALUE)', table_name, new_epoch, table_name, new_epoch_start);
+END;
+$$;
+
+BEGIN; CALL add_partition_check('tx_senders', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL validate_partition_check('tx_senders'); COMMIT;
+BEGIN; SET LOCAL lock_timeout = '10s'; CALL partition_by_epoch('tx_senders', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL add_partition_check('tx_recipients', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL validate_partition_check('tx_recipients'); COMMIT;
+BEGIN; SET LOCAL lock_timeout = '10s'; CALL partition_by_epoch('tx_recipients', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL add_partition_check('tx_input_objects', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL validate_partition_check('tx_input_objects'); COMMIT;
+BEGIN; SET LOCAL lock_timeout = '10s'; CALL partition_by_epoch('tx_input_objects', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL add_partition_check('tx_changed_objects', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL validate_partition_check('tx_changed_objects'); COMMIT;
+BEGIN; SET LOCAL lock_timeout = '10s'; CALL partition_by_epoch('tx_changed_objects', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL add_partition_check('tx_calls_pkg', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL validate_partition_check('tx_calls_pkg'); COMMIT;
+BEGIN; SET LOCAL lock_timeout = '10s'; CALL partition_by_epoch('tx_calls_pkg', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL add_partition_check('tx_calls_mod', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL validate_partition_check('tx_calls_mod'); COMMIT;
+BEGIN; SET LOCAL lock_timeout = '10s'; CALL partition_by_epoch('tx_calls_mod', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL add_partition_check('tx_calls_fun', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL validate_partition_check('tx_calls_fun'); COMMIT;
+BEGIN; SET LOCAL lock_timeout = '10s'; CALL partition_by_epoch('tx_calls_fun', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL add_partition_check('tx_kinds', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL validate_partition_check('tx_kinds'); COMMIT;
+BEGIN; SET LOCAL lock_timeout = '10s'; CALL partition_by_epoch('tx_kinds', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL add_partition_check('tx_affected_addresses', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL validate_partition_check('tx_affected_addresses'); COMMIT;
+BEGIN; SET LOCAL lock_timeout = '10s'; CALL partition_by_epoch('tx_affected_addresses', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL add_partition_check('tx_affected_objects', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL validate_partition_check('tx_affected_objects'); COMMIT;
+BEGIN; SET LOCAL lock_timeout = '10s'; CALL partition_by_epoch('tx_affected_objects', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL add_partition_check('event_emit_package', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL validate_partition_check('event_emit_package'); COMMIT;
+BEGIN; SET LOCAL lock_timeout = '10s'; CALL partition_by_epoch('event_emit_package', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL add_partition_check('event_emit_module', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL validate_partition_check('event_emit_module'); COMMIT;
+BEGIN; SET LOCAL lock_timeout = '10s'; CALL partition_by_epoch('event_emit_module', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL add_partition_check('event_senders', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL validate_partition_check('event_senders'); COMMIT;
+BEGIN; SET LOCAL lock_timeout = '10s'; CALL partition_by_epoch('event_senders', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL add_partition_check('event_struct_package', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL validate_partition_check('event_struct_package'); COMMIT;
+BEGIN; SET LOCAL lock_timeout = '10s'; CALL partition_by_epoch('event_struct_package', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL add_partition_check('event_struct_module', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL validate_partition_check('event_struct_module'); COMMIT;
+BEGIN; SET LOCAL lock_timeout = '10s'; CALL partition_by_epoch('event_struct_module', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL add_partition_check('event_struct_name', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL validate_partition_check('event_struct_name'); COMMIT;
+BEGIN; SET LOCAL lock_timeout = '10s'; CALL partition_by_epoch('event_struct_name', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL add_partition_check('event_struct_instantiation', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL validate_partition_check('event_struct_instantiation'); COMMIT;
+BEGIN; SET LOCAL lock_timeout = '10s'; CALL partition_by_epoch('event_struct_instantiation', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL add_partition_check('checkpoints', 'sequence_number'); COMMIT;
+BEGIN; CALL validate_partition_check('checkpoints'); COMMIT;
+BEGIN; SET LOCAL lock_timeout = '10s'; CALL partition_by_epoch('checkpoints', 'sequence_number'); COMMIT;
+BEGIN; CALL add_partition_check('pruner_cp_watermark', 'checkpoint_sequence_number'); COMMIT;
+BEGIN; CALL validate_partition_check('pruner_cp_watermark'); COMMIT;
+BEGIN; SET LOCAL lock_timeout = '10s'; CALL partition_by_epoch('pruner_cp_watermark', 'checkpoint_sequence_number'); COMMIT;
+
+-- The primary key of `tx_digests` is the digest alone, so its partitioned replacement gets a unique
+-- index on the digest and sequence number instead, matching the index built concurrently by the
+-- previous migration.
+BEGIN; CALL add_partition_check('tx_digests', 'tx_sequence_number'); COMMIT;
+BEGIN; CALL validate_partition_check('tx_digests'); COMMIT;
+BEGIN; SET LOCAL lock_timeout = '10s'; CALL partition_by_epoch('tx_digests', 'tx_sequence_number', 'tx_digest, tx_sequence_number'); COMMIT;
+
+DROP PROCEDURE IF EXISTS add_partition_check;
+DROP PROCEDURE IF EXISTS validate_partition_check;
@@ -0,0 +1,437 @@
+-- The SQLite schema mirrors the Postgres schema without partitioning. Postgres array columns
+-- (`checkpoints.tx_digests`, `events.senders`, `transactions.object_changes`,
+-- `transactions.balance_changes` and `transactions.events`) are stored as BCS-encoded blobs, and
+-- `epochs.system_state_summary_json` as text.
+
+CREATE TABLE chain_identifier
+(
+    checkpoint_digest                   BLOB         NOT NULL,
+    PRIMARY KEY(checkpoint_digest)
+);
+
+CREATE TABLE checkpoints
+(
+    sequence_number                     BIGINT       PRIMARY KEY,
+    checkpoint_digest                   BLOB         NOT NULL,
+    epoch                               BIGINT       NOT NULL,
+    network_total_transactions          BIGINT       NOT NULL,
+    previous_checkpoint_digest          BLOB,
+    end_of_epoch                        BOOLEAN      NOT NULL,
+    tx_digests                          BLOB         NOT NULL,
+    timestamp_ms                        BIGINT       NOT NULL,
+    total_gas_cost                      BIGINT       NOT NULL,
+    computation_cost                    BIGINT       NOT NULL,
+    storage_cost                        BIGINT       NOT NULL,
+    storage_rebate                      BIGINT       NOT NULL,
+    non_refundable_storage_fee          BIGINT       NOT NULL,
+    checkpoint_commitments              BLOB         NOT NULL,
+    validator_signature                 BLOB         NOT NULL,
+    end_of_epoch_data                   BLOB,
+    min_tx_sequence_number              BIGINT,
+    max_tx_sequence_number              BIGINT
+);
+CREATE INDEX checkpoints_epoch ON checkpoints (epoch, sequence_number);
+CREATE INDEX checkpoints_digest ON checkpoints (checkpoint_digest);
+
+CREATE TABLE pruner_cp_watermark
+(
+    checkpoint_sequence_number          BIGINT       PRIMARY KEY,
+    min_tx_sequence_number              BIGINT       NOT NULL,
+    max_tx_sequence_number              BIGINT       NOT NULL
+);
+
+CREATE TABLE raw_checkpoints
+(
+    sequence_number                     BIGINT       PRIMARY KEY,
+    certified_checkpoint                BLOB         NOT NULL,
+    checkpoint_contents                 BLOB         NOT NULL
+);
+
+CREATE TABLE transactions
+(
+    tx_sequence_number                  BIGINT       PRIMARY KEY,
+    transaction_digest                  BLOB         NOT NULL,
+    raw_transaction                     BLOB         NOT NULL,
+    raw_effects                         BLOB         NOT NULL,
+    checkpoint_sequence_number          BIGINT       NOT NULL,
+    timestamp_ms                        BIGINT       NOT NULL,
+    object_changes                      BLOB         NOT NULL,
+    balance_changes                     BLOB         NOT NULL,
+    events                              BLOB         NOT NULL,
+    transaction_kind                    SMALLINT     NOT NULL,
+    success_command_count               SMALLINT     NOT NULL
+);
+CREATE INDEX transactions_digest ON transactions (transaction_digest);
+CREATE INDEX transactions_checkpoint_sequence_number ON transactions (checkpoint_sequence_number);
+
+CREATE TABLE events
+(
+    tx_sequence_number                  BIGINT       NOT NULL,
+    event_sequence_number               BIGINT       NOT NULL,
+    transaction_digest                  BLOB         NOT NULL,
+    senders                             BLOB         NOT NULL,
+    package                             BLOB         NOT NULL,
+    module                              TEXT         NOT NULL,
+    event_type                          TEXT         NOT NULL,
+    timestamp_ms                        BIGINT       NOT NULL,
+    bcs                                 BLOB         NOT NULL,
+    sender                              BLOB,
+    PRIMARY KEY(tx_sequence_number, event_sequence_number)
+);
+CREATE INDEX events_package ON events (package, tx_sequence_number, event_sequence_number);
+CREATE INDEX events_package_module ON events (package, module, tx_sequence_number, event_sequence_number);
+CREATE INDEX events_event_type ON events (event_type, tx_sequence_number, event_sequence_number);
+
+CREATE TABLE objects
+(
+    object_id                           BLOB         PRIMARY KEY,
+    object_version                      BIGINT       NOT NULL,
+    object_digest                       BLOB         NOT NULL,
+    owner_type                          SMALLINT     NOT NULL,
+    owner_id                            BLOB,
+    object_type                         TEXT,
+    object_type_package                 BLOB,
+    object_type_module                  TEXT,
+    object_type_name                    TEXT,
+    serialized_object                   BLOB         NOT NULL,
+    coin_type                           TEXT,
+    coin_balance                        BIGINT,
+    df_kind                             SMALLINT
+);
+CREATE INDEX objects_owner ON objects (owner_type, owner_id) WHERE owner_type BETWEEN 1 AND 2 AND owner_id IS NOT NULL;
+CREATE INDEX objects_coin ON objects (owner_id, coin_type) WHERE coin_type IS NOT NULL AND owner_type = 1;
+CREATE INDEX objects_package_module_name_full_type ON objects (object_type_package, object_type_module, object_type_name, object_type);
+CREATE INDEX objects_owner_package_module_name_full_type ON objects (owner_id, object_type_package, object_type_module, object_type_name, object_type);
+
+CREATE TABLE objects_history
+(
+    object_id                           BLOB         NOT NULL,
+    object_version                      BIGINT       NOT NULL,
+    object_status                       SMALLINT     NOT NULL,
+    object_digest                       BLOB,
+    checkpoint_sequence_number          BIGINT       NOT NULL,
+    owner_type                          SMALLINT,
+    owner_id                            BLOB,
+    object_type                         TEXT,
+    object_type_package                 BLOB,
+    object_type_module                  TEXT,
+    object_type_name                    TEXT,
+    serialized_object                   BLOB,
+    coin_type                           TEXT,
+    coin_balance                        BIGINT,
+    df_kind                             SMALLINT,
+    PRIMARY KEY(checkpoint_sequence_number, object_id, object_version)
+);
+CREATE INDEX objects_history_id_version ON objects_history (object_id, object_version, checkpoint_sequence_number);
+CREATE INDEX objects_history_owner ON objects_history (checkpoint_sequence_number, owner_type, owner_id) WHERE owner_type BETWEEN 1 AND 2 AND owner_id IS NOT NULL;
+CREATE INDEX objects_history_coin_owner ON objects_history (checkpoint_sequence_number, owner_id, coin_type, object_id) WHERE coin_type IS NOT NULL AND owner_type = 1;
+CREATE INDEX objects_history_type ON objects_history (checkpoint_sequence_number, object_type);
+
+CREATE TABLE objects_snapshot
+(
+    object_id                           BLOB         PRIMARY KEY,
+    object_version                      BIGINT       NOT NULL,
+    object_status                       SMALLINT     NOT NULL,
+    object_digest                       BLOB,
+    checkpoint_sequence_number          BIGINT       NOT NULL,
+    owner_type                          SMALLINT,
+    owner_id                            BLOB,
+    object_type                         TEXT,
+    object_type_package                 BLOB,
+    object_type_module                  TEXT,
+    object_type_name                    TEXT,
+    serialized_object                   BLOB,
+    coin_type                           TEXT,
+    coin_balance                        BIGINT,
+    df_kind                             SMALLINT
+);
+CREATE INDEX objects_snapshot_checkpoint_sequence_number ON objects_snapshot (checkpoint_sequence_number);
+CREATE INDEX objects_snapshot_owner ON objects_snapshot (owner_type, owner_id, object_id) WHERE owner_type BETWEEN 1 AND 2 AND owner_id IS NOT NULL;
+CREATE INDEX objects_snapshot_coin_owner ON objects_snapshot (owner_id, coin_type, object_id) WHERE coin_type IS NOT NULL AND owner_type = 1;
+CREATE INDEX objects_snapshot_type_id ON objects_snapshot (object_type_package, object_type_module, object_type_name, object_type, object_id);
+
+CREATE TABLE objects_version
+(
+    object_id                           BLOB         NOT NULL,
+    object_version                      BIGINT       NOT NULL,
+    cp_sequence_number                  BIGINT       NOT NULL,
+    PRIMARY KEY(object_id, object_version)
+);
+
+CREATE TABLE full_objects_history
+(
+    object_id                           BLOB         NOT NULL,
+    object_version                      BIGINT       NOT NULL,
+    serialized_object                   BLOB,
+    PRIMARY KEY(object_id, object_version)
+);
+
+CREATE TABLE packages
+(
+    package_id                          BLOB         NOT NULL,
+    original_id                         BLOB         NOT NULL,
+    package_version                     BIGINT       NOT NULL,
+    move_package                        BLOB         NOT NULL,
+    checkpoint_sequence_number          BIGINT       NOT NULL,
+    PRIMARY KEY(package_id, original_id, package_version),
+    UNIQUE(package_id)
+);
+CREATE INDEX packages_cp_id_version ON packages (checkpoint_sequence_number, original_id, package_version);
+CREATE INDEX packages_id_version_cp ON packages (original_id, package_version, checkpoint_sequence_number);
+
+CREATE TABLE display
+(
+    object_type                         TEXT         PRIMARY KEY,
+    id                                  BLOB         NOT NULL,
+    version                             SMALLINT     NOT NULL,
+    bcs                                 BLOB         NOT NULL
+);
+
+CREATE TABLE epochs
+(
+    epoch                               BIGINT       PRIMARY KEY,
+    first_checkpoint_id                 BIGINT       NOT NULL,
+    epoch_start_timestamp               BIGINT       NOT NULL,
+    reference_gas_price                 BIGINT       NOT NULL,
+    protocol_version                    BIGINT       NOT NULL,
+    total_stake                         BIGINT       NOT NULL,
+    storage_fund_balance                BIGINT       NOT NULL,
+    system_state                        BLOB,
+    epoch_total_transactions            BIGINT,
+    last_checkpoint_id                  BIGINT,
+    epoch_end_timestamp                 BIGINT,
+    storage_fund_reinvestment           BIGINT,
+    storage_charge                      BIGINT,
+    storage_rebate                      BIGINT,
+    stake_subsidy_amount                BIGINT,
+    total_gas_fees                      BIGINT,
+    total_stake_rewards_distributed     BIGINT,
+    leftover_storage_fund_inflow        BIGINT,
+    epoch_commitments                   BLOB,
+    system_state_summary_json           TEXT
+);
+
+CREATE TABLE protocol_configs
+(
+    protocol_version                    BIGINT       NOT NULL,
+    config_name                         TEXT         NOT NULL,
+    config_value                        TEXT,
+    PRIMARY KEY(protocol_version, config_name)
+);
+
+CREATE TABLE feature_flags
+(
+    protocol_version                    BIGINT       NOT NULL,
+    flag_name                           TEXT         NOT NULL,
+    flag_value                          BOOLEAN      NOT NULL,
+    PRIMARY KEY(protocol_version, flag_name)
+);
+
+CREATE TABLE epoch_package_metrics
+(
+    epoch                               BIGINT       NOT NULL,
+    package                             BLOB         NOT NULL,
+    move_calls                          BIGINT       NOT NULL,
+    transactions                        BIGINT       NOT NULL,
+    gas_spent                           BIGINT       NOT NULL,
+    checkpoint_sequence_number          BIGINT       NOT NULL,
+    PRIMARY KEY(epoch, package)
+);
+CREATE INDEX epoch_package_metrics_move_calls ON epoch_package_metrics (epoch, move_calls DESC, package);
+CREATE INDEX epoch_package_metrics_gas_spent ON epoch_package_metrics (epoch, gas_spent DESC, package);
+
+CREATE TABLE epoch_sender_metrics
+(
+    epoch                               BIGINT       NOT NULL,
+    sender                              BLOB         NOT NULL,
+    transactions                        BIGINT       NOT NULL,
+    gas_spent                           BIGINT       NOT NULL,
+    checkpoint_sequence_number          BIGINT       NOT NULL,
+    PRIMARY KEY(epoch, sender)
+);
+CREATE INDEX epoch_sender_metrics_gas_spent ON epoch_sender_metrics (epoch, gas_spent DESC, sender);
+
+CREATE TABLE watermarks
+(
+    entity                              TEXT         NOT NULL,
+    epoch_hi_inclusive                  BIGINT       NOT NULL,
+    epoch_lo                            BIGINT       NOT NULL,
+    checkpoint_hi_inclusive             BIGINT       NOT NULL,
+    tx_hi_inclusive                     BIGINT       NOT NULL,
+    reader_lo                           BIGINT       NOT NULL,
+    timestamp_ms                        BIGINT       NOT NULL,
+    pruner_lo                           BIGINT,
+    PRIMARY KEY(entity)
+);
+
+CREATE TABLE tx_digests
+(
+    tx_digest                           BLOB         PRIMARY KEY,
+    tx_sequence_number                  BIGINT       NOT NULL
+);
+CREATE INDEX tx_digests_tx_sequence_number ON tx_digests (tx_sequence_number);
+
+CREATE TABLE tx_senders
+(
+    tx_sequence_number                  BIGINT       NOT NULL,
+    sender                              BLOB         NOT NULL,
+    PRIMARY KEY(sender, tx_sequence_number)
+);
+
+CREATE TABLE tx_recipients
+(
+    tx_sequence_number                  BIGINT       NOT NULL,
+    recipient                           BLOB         NOT NULL,
+    sender                              BLOB         NOT NULL,
+    PRIMARY KEY(recipient, tx_sequence_number)
+);
+CREATE INDEX tx_recipients_sender ON tx_recipients (sender, recipient, tx_sequence_number);
+
+CREATE TABLE tx_input_objects
+(
+    tx_sequence_number                  BIGINT       NOT NULL,
+    object_id                           BLOB         NOT NULL,
+    sender                              BLOB         NOT NULL,
+    PRIMARY KEY(object_id, tx_sequence_number)
+);
+CREATE INDEX tx_input_objects_sender ON tx_input_objects (sender, object_id, tx_sequence_number);
+
+CREATE TABLE tx_changed_objects
+(
+    tx_sequence_number                  BIGINT       NOT NULL,
+    object_id                           BLOB         NOT NULL,
+    sender                              BLOB         NOT NULL,
+    PRIMARY KEY(object_id, tx_sequence_number)
+);
+CREATE INDEX tx_changed_objects_sender ON tx_changed_objects (sender, object_id, tx_sequence_number);
+
+CREATE TABLE tx_affected_addresses
+(
+    tx_sequence_number                  BIGINT       NOT NULL,
+    affected                            BLOB         NOT NULL,
+    sender                              BLOB         NOT NULL,
+    PRIMARY KEY(affected, tx_sequence_number)
+);
+CREATE INDEX tx_affected_addresses_tx_sequence_number_index ON tx_affected_addresses (tx_sequence_number);
+CREATE INDEX tx_affected_addresses_sender ON tx_affected_addresses (sender, affected, tx_sequence_number);
+
+CREATE TABLE tx_affected_objects
+(
+    tx_sequence_number                  BIGINT       NOT NULL,
+    affected                            BLOB         NOT NULL,
+    sender                              BLOB         NOT NULL,
+    PRIMARY KEY(affected, tx_sequence_number)
+);
+CREATE INDEX tx_affected_objects_tx_sequence_number_index ON tx_affected_objects (tx_sequence_number);
+CREATE INDEX tx_affected_objects_sender ON tx_affected_objects (sender, affected, tx_sequence_number);
+
+CREATE TABLE tx_calls_pkg
+(
+    tx_sequence_number                  BIGINT       NOT NULL,
+    package                             BLOB         NOT NULL,
+    sender                              BLOB         NOT NULL,
+    PRIMARY KEY(package, tx_sequence_number)
+);
+CREATE INDEX tx_calls_pkg_sender ON tx_calls_pkg (sender, package, tx_sequence_number);
+
+CREATE TABLE tx_calls_mod
+(
+    tx_sequence_number                  BIGINT       NOT NULL,
+    package                             BLOB         NOT NULL,
+    module                              TEXT         NOT NULL,
+    sender                              BLOB         NOT NULL,
+    PRIMARY KEY(package, module, tx_sequence_number)
+);
+CREATE INDEX tx_calls_mod_sender ON tx_calls_mod (sender, package, module, tx_sequence_number);
+
+CREATE TABLE tx_calls_fun
+(
+    tx_sequence_number                  BIGINT       NOT NULL,
+    package                             BLOB         NOT NULL,
+    module                              TEXT         NOT NULL,
+    func                                TEXT         NOT NULL,
+    sender                              BLOB         NOT NULL,
+    PRIMARY KEY(package, module, func, tx_sequence_number)
+);
+CREATE INDEX tx_calls_fun_sender ON tx_calls_fun (sender, package, module, func, tx_sequence_number);
+
+CREATE TABLE tx_kinds
+(
+    tx_sequence_number                  BIGINT       NOT NULL,
+    tx_kind                             SMALLINT     NOT NULL,
+    PRIMARY KEY(tx_kind, tx_sequence_number)
+);
+
+CREATE TABLE event_emit_package
+(
+    package                             BLOB         NOT NULL,
+    tx_sequence_number                  BIGINT       NOT NULL,
+    event_sequence_number               BIGINT       NOT NULL,
+    sender                              BLOB         NOT NULL,
+    PRIMARY KEY(package, tx_sequence_number, event_sequence_number)
+);
+CREATE INDEX event_emit_package_sender ON event_emit_package (sender, package, tx_sequence_number, event_sequence_number);
+
+CREATE TABLE event_emit_module
+(
+    package                             BLOB         NOT NULL,
+    module                              TEXT         NOT NULL,
+    tx_sequence_number                  BIGINT       NOT NULL,
+    event_sequence_number               BIGINT       NOT NULL,
+    sender                              BLOB         NOT NULL,
+    PRIMARY KEY(package, module, tx_sequence_number, event_sequence_number)
+);
+CREATE INDEX event_emit_module_sender ON event_emit_module (sender, package, module, tx_sequence_number, event_sequence_number);
+
+CREATE TABLE event_senders
+(
+    sender                              BLOB         NOT NULL,
+    tx_sequence_number                  BIGINT       NOT NULL,
+    event_sequence_number               BIGINT       NOT NULL,
+    PRIMARY KEY(sender, tx_sequence_number, event_sequence_number)
+);
+
+CREATE TABLE event_struct_package
+(
+    package                             BLOB         NOT NULL,
+    tx_sequence_number                  BIGINT       NOT NULL,
+    event_sequence_number               BIGINT       NOT NULL,
+    sender                              BLOB         NOT NULL,
+    PRIMARY KEY(package, tx_sequence_number, event_sequence_number)
+);
+CREATE INDEX event_struct_package_sender ON event_struct_package (sender, package, tx_sequence_number, event_sequence_number);
+
+CREATE TABLE event_struct_module
+(
+    package                             BLOB         NOT NULL,
+    module                              TEXT         NOT NULL,
+    tx_sequence_number                  BIGINT       NOT NULL,
+    event_sequence_number               BIGINT       NOT NULL,
+    sender                              BLOB         NOT NULL,
+    PRIMARY KEY(package, module, tx_sequence_number, event_sequence_number)
+);
+CREATE INDEX event_struct_module_sender ON event_struct_module (sender, package, module, tx_sequence_number, event_sequence_number);
+
+CREATE TABLE event_struct_name
+(
+    package                             BLOB         NOT NULL,
+    module                              TEXT         NOT NULL,
+    type_name                           TEXT         NOT NULL,
+    tx_sequence_number                  BIGINT       NOT NULL,
+    event_sequence_number               BIGINT       NOT NULL,
+    sender                              BLOB         NOT NULL,
+    PRIMARY KEY(package, module, type_name, tx_sequence_number, event_sequence_number)
+);
+CREATE INDEX event_struct_name_sender ON event_struct_name (sender, package, module, type_name, tx_sequence_number, event_sequence_number);
+
+CREATE TABLE event_struct_instantiation
+(
+    package                             BLOB         NOT NULL,
+    module                              TEXT         NOT NULL,
+    type_instantiation                  TEXT         NOT NULL,
+    tx_sequence_number                  BIGINT       NOT NULL,
+    event_sequence_number               BIGINT       NOT NULL,
+    sender                              BLOB         NOT NULL,
+    PRIMARY KEY(package, module, type_instantiation, tx_sequence_number, event_sequence_number)
+);
+CREATE INDEX event_struct_instantiation_sender ON event_struct_instantiation (sender, package, module, type_instantiation, tx_sequence_number, event_sequence_number);
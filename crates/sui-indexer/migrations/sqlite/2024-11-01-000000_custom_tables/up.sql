@@ -0,0 +1,11 @@
+-- Registry of the custom tables declared in the indexer's config. Each custom table is created
+-- as `custom_<name>`, with columns that follow its definition, so a table's definition cannot be
+-- changed once it has been created.
+CREATE TABLE custom_tables
+(
+    name                                TEXT         PRIMARY KEY,
+    struct_type                         TEXT         NOT NULL,
+    history                             BOOLEAN      NOT NULL,
+    -- The table's declaration from the config, as JSON.
+    definition                          TEXT         NOT NULL
+);
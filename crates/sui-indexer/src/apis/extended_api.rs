@@ -1,14 +1,24 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::errors::IndexerError;
 use crate::indexer_reader::IndexerReader;
-use jsonrpsee::{core::RpcResult, RpcModule};
+use jsonrpsee::{
+    core::{Error as RpcError, RpcResult},
+    RpcModule,
+};
+use sui_json_rpc::error::SuiRpcInputError;
 use sui_json_rpc::SuiRpcModule;
-use sui_json_rpc_api::{validate_limit, ExtendedApiServer, QUERY_MAX_RESULT_LIMIT_CHECKPOINTS};
+use sui_json_rpc_api::{
+    validate_limit, ExtendedApiServer, QUERY_MAX_RESULT_LIMIT, QUERY_MAX_RESULT_LIMIT_CHECKPOINTS,
+};
 use sui_json_rpc_types::{
-    CheckpointedObjectID, EpochInfo, EpochPage, Page, QueryObjectsPage, SuiObjectResponseQuery,
+    AddressActivityPage, CheckpointedObjectID, EpochInfo, EpochPage, ObjectHistoryPage,
+    ObjectHistoryQuery, Page, QueryObjectsPage, SuiObjectResponseQuery,
 };
 use sui_open_rpc::Module;
+use sui_types::base_types::{ObjectID, SuiAddress};
+use sui_types::digests::TransactionDigest;
 use sui_types::sui_serde::BigInt;
 
 pub(crate) struct ExtendedApi {
@@ -66,6 +76,76 @@ impl ExtendedApiServer for ExtendedApi {
         .into())
     }
 
+    async fn get_object_history(
+        &self,
+        object_id: ObjectID,
+        query: Option<ObjectHistoryQuery>,
+        cursor: Option<BigInt<u64>>,
+        limit: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> RpcResult<ObjectHistoryPage> {
+        let limit = validate_limit(limit, *QUERY_MAX_RESULT_LIMIT)?;
+        let ObjectHistoryQuery {
+            after_checkpoint,
+            before_checkpoint,
+        } = query.unwrap_or_default();
+
+        let mut versions = self
+            .inner
+            .get_object_history(
+                object_id,
+                after_checkpoint,
+                before_checkpoint,
+                cursor.map(|x| *x),
+                limit + 1,
+                descending_order.unwrap_or(false),
+            )
+            .await?;
+
+        let has_next_page = versions.len() > limit;
+        versions.truncate(limit);
+        let next_cursor = versions.last().map(|v| v.version.value());
+        Ok(Page {
+            data: versions,
+            next_cursor: next_cursor.map(|v| v.into()),
+            has_next_page,
+        })
+    }
+
+    async fn get_address_activity(
+        &self,
+        address: SuiAddress,
+        cursor: Option<TransactionDigest>,
+        limit: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> RpcResult<AddressActivityPage> {
+        let limit = validate_limit(limit, *QUERY_MAX_RESULT_LIMIT)?;
+        let mut activity = self
+            .inner
+            .get_address_activity(
+                address,
+                cursor,
+                limit + 1,
+                descending_order.unwrap_or(false),
+            )
+            .await
+            .map_err(|e| match e {
+                IndexerError::InvalidArgumentError(msg) => {
+                    SuiRpcInputError::GenericInvalid(msg).into()
+                }
+                e => RpcError::from(e),
+            })?;
+
+        let has_next_page = activity.len() > limit;
+        activity.truncate(limit);
+        let next_cursor = activity.last().map(|a| a.digest);
+        Ok(Page {
+            data: activity,
+            next_cursor,
+            has_next_page,
+        })
+    }
+
     async fn get_total_transactions(&self) -> RpcResult<BigInt<u64>> {
         let latest_checkpoint = self.inner.get_latest_checkpoint().await?;
         Ok(latest_checkpoint.network_total_transactions.into())
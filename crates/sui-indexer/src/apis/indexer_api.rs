@@ -13,8 +13,8 @@ use sui_json_rpc::SuiRpcModule;
 use sui_json_rpc_api::{cap_page_limit, IndexerApiServer};
 use sui_json_rpc_types::{
     DynamicFieldPage, EventFilter, EventPage, ObjectsPage, Page, SuiObjectResponse,
-    SuiObjectResponseQuery, SuiTransactionBlockResponseQuery, TransactionBlocksPage,
-    TransactionFilter,
+    SuiObjectResponseQuery, SuiPastObjectResponse, SuiTransactionBlockResponseQuery,
+    TransactionBlocksPage, TransactionFilter,
 };
 use sui_open_rpc::Module;
 use sui_types::base_types::{ObjectID, SuiAddress};
@@ -23,6 +23,7 @@ use sui_types::dynamic_field::{DynamicFieldName, Field};
 use sui_types::error::SuiObjectResponseError;
 use sui_types::event::EventID;
 use sui_types::object::ObjectRead;
+use sui_types::sui_serde::BigInt;
 use sui_types::TypeTag;
 
 use crate::indexer_reader::IndexerReader;
@@ -284,6 +285,18 @@ impl IndexerApiServer for IndexerApi {
         ))
     }
 
+    async fn try_get_dynamic_field_object_as_of_checkpoint(
+        &self,
+        _parent_object_id: ObjectID,
+        _name: DynamicFieldName,
+        _checkpoint: BigInt<u64>,
+    ) -> RpcResult<SuiPastObjectResponse> {
+        Err(jsonrpsee::types::error::CallError::Custom(
+            jsonrpsee::types::error::ErrorCode::MethodNotFound.into(),
+        )
+        .into())
+    }
+
     fn subscribe_event(&self, _sink: SubscriptionSink, _filter: EventFilter) -> SubscriptionResult {
         Err(SubscriptionEmptyError)
     }
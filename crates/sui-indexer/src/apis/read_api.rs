@@ -166,6 +166,18 @@ impl ReadApiServer for ReadApi {
         .into())
     }
 
+    async fn try_get_object_as_of_checkpoint(
+        &self,
+        _object_id: ObjectID,
+        _checkpoint: BigInt<u64>,
+        _options: Option<SuiObjectDataOptions>,
+    ) -> RpcResult<SuiPastObjectResponse> {
+        Err(jsonrpsee::types::error::CallError::Custom(
+            jsonrpsee::types::error::ErrorCode::MethodNotFound.into(),
+        )
+        .into())
+    }
+
     async fn get_latest_checkpoint_sequence_number(&self) -> RpcResult<BigInt<u64>> {
         let checkpoint = self.get_latest_checkpoint().await?;
         Ok(BigInt::from(checkpoint.sequence_number))
@@ -10,11 +10,11 @@ use jsonrpsee::RpcModule;
 use sui_json_rpc::SuiRpcModule;
 use sui_json_rpc_api::{WriteApiClient, WriteApiServer};
 use sui_json_rpc_types::{
-    DevInspectArgs, DevInspectResults, DryRunTransactionBlockResponse, SuiTransactionBlockResponse,
-    SuiTransactionBlockResponseOptions,
+    DevInspectArgs, DevInspectResults, DryRunTransactionBlockResponse, SuiPendingTransactionBlock,
+    SuiTransactionBlockResponse, SuiTransactionBlockResponseOptions,
 };
 use sui_open_rpc::Module;
-use sui_types::base_types::SuiAddress;
+use sui_types::base_types::{SuiAddress, TransactionDigest};
 use sui_types::quorum_driver_types::ExecuteTransactionRequestType;
 use sui_types::sui_serde::BigInt;
 
@@ -77,6 +77,13 @@ impl WriteApiServer for WriteApi {
     ) -> RpcResult<DryRunTransactionBlockResponse> {
         self.fullnode.dry_run_transaction_block(tx_bytes).await
     }
+
+    async fn get_pending_transaction_block(
+        &self,
+        digest: TransactionDigest,
+    ) -> RpcResult<Option<SuiPendingTransactionBlock>> {
+        self.fullnode.get_pending_transaction_block(digest).await
+    }
 }
 
 impl SuiRpcModule for WriteApi {
@@ -129,12 +129,28 @@ pub struct IngestionConfig {
         env = "CHECKPOINT_PROCESSING_BATCH_DATA_LIMIT",
     )]
     pub checkpoint_download_queue_size_bytes: usize,
+
+    /// Path to TOML file declaring custom tables to project Move objects into.
+    #[arg(long)]
+    pub custom_tables_config_path: Option<PathBuf>,
 }
 
 impl IngestionConfig {
     const DEFAULT_CHECKPOINT_DOWNLOAD_QUEUE_SIZE: usize = 200;
     const DEFAULT_CHECKPOINT_DOWNLOAD_QUEUE_SIZE_BYTES: usize = 20_000_000;
     const DEFAULT_CHECKPOINT_DOWNLOAD_TIMEOUT: u64 = 20;
+
+    /// Load the custom table declarations from file, if a file was provided.
+    pub fn load_custom_tables(&self) -> Option<CustomTablesConfig> {
+        let config_path = self.custom_tables_config_path.as_ref()?;
+
+        let contents = std::fs::read_to_string(config_path)
+            .expect("Failed to read custom tables config from file");
+        let custom_tables = toml::de::from_str::<CustomTablesConfig>(&contents)
+            .expect("Failed to parse into CustomTablesConfig struct");
+
+        Some(custom_tables)
+    }
 }
 
 impl Default for IngestionConfig {
@@ -145,10 +161,60 @@ impl Default for IngestionConfig {
             checkpoint_download_timeout: Self::DEFAULT_CHECKPOINT_DOWNLOAD_TIMEOUT,
             checkpoint_download_queue_size_bytes:
                 Self::DEFAULT_CHECKPOINT_DOWNLOAD_QUEUE_SIZE_BYTES,
+            custom_tables_config_path: None,
         }
     }
 }
 
+/// Tables maintained by the indexer in addition to its own, each holding a projection of the
+/// objects of one Move struct type.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CustomTablesConfig {
+    #[serde(default, rename = "table")]
+    pub tables: Vec<CustomTableConfig>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomTableConfig {
+    /// Name the table is queried by. The table itself is created as `custom_<name>`.
+    pub name: String,
+    /// The Move struct type whose objects are projected into the table, e.g.
+    /// `0x2::coin::Coin<0x2::sui::SUI>`. If the type is given without type parameters, objects of
+    /// every instantiation of the struct are included.
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// Keep a row for every version of each object, rather than only its latest version. Deleted
+    /// and wrapped objects are recorded with a row marked as deleted. Only tables that keep
+    /// history can be queried through GraphQL, which reads them as of a checkpoint.
+    #[serde(default)]
+    pub history: bool,
+    #[serde(default, rename = "column")]
+    pub columns: Vec<CustomColumnConfig>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomColumnConfig {
+    pub name: String,
+    /// Dot-separated path to the field of the object's contents to project, e.g. `balance` or
+    /// `id.id`.
+    pub field: String,
+    #[serde(rename = "type")]
+    pub type_: CustomColumnType,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomColumnType {
+    /// Any Move integer, stored as an arbitrary precision number.
+    Number,
+    Text,
+    Bool,
+    /// An address or object ID, stored in its canonical `0x`-prefixed hex form.
+    Address,
+    /// Any value, stored as JSON in the same form as JSON-RPC renders object contents.
+    Json,
+}
+
 #[derive(Args, Debug, Clone)]
 pub struct BackFillConfig {
     /// Maximum number of concurrent tasks to run.
@@ -210,6 +276,37 @@ pub enum Command {
     },
     /// Restore the database from formal snaphots.
     Restore(RestoreConfig),
+    /// Restore the database from the formal snapshot of an epoch, replay full checkpoints from a
+    /// remote checkpoint store up to a target checkpoint, then hand off to live ingestion. Progress
+    /// is recorded in the snapshot download directory, so an interrupted restore resumes where it
+    /// left off when run again.
+    IncrementalRestore {
+        #[command(flatten)]
+        restore_config: RestoreConfig,
+        #[command(flatten)]
+        replay_config: CheckpointReplayConfig,
+        #[command(flatten)]
+        ingestion_config: IngestionConfig,
+        #[command(flatten)]
+        snapshot_config: SnapshotLagConfig,
+        #[command(flatten)]
+        pruning_options: PruningOptions,
+    },
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct CheckpointReplayConfig {
+    /// Remote store of full checkpoints (`CheckpointData` blobs, as read by live ingestion from
+    /// `--remote-store-url`) to replay from, after restoring the formal snapshot. This is not a
+    /// sui-archival archive: that is only read for the epoch boundaries and checkpoint summaries.
+    #[arg(long, env = "CHECKPOINT_REMOTE_STORE_URL")]
+    pub checkpoint_remote_store_url: Url,
+    /// Checkpoint to replay up to, inclusive, before handing off to live ingestion.
+    #[arg(long)]
+    pub target_checkpoint: u64,
+    /// Stop once the target checkpoint has been indexed instead of starting live ingestion.
+    #[arg(long)]
+    pub no_live_ingestion: bool,
 }
 
 #[derive(Args, Default, Debug, Clone)]
@@ -572,4 +669,43 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn custom_tables_from_file() {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        let toml_content = r#"
+        [[table]]
+        name = "sui_coins"
+        type = "0x2::coin::Coin<0x2::sui::SUI>"
+
+        [[table.column]]
+        name = "balance"
+        field = "balance"
+        type = "number"
+
+        [[table]]
+        name = "kiosks"
+        type = "0x2::kiosk::Kiosk"
+        history = true
+        "#;
+        temp_file.write_all(toml_content.as_bytes()).unwrap();
+        let config = IngestionConfig {
+            custom_tables_config_path: Some(temp_file.path().to_path_buf()),
+            ..Default::default()
+        };
+
+        let custom_tables = config.load_custom_tables().unwrap();
+        assert_eq!(custom_tables.tables.len(), 2);
+
+        let coins = &custom_tables.tables[0];
+        assert_eq!(coins.name, "sui_coins");
+        assert!(!coins.history);
+        assert_eq!(coins.columns.len(), 1);
+        assert_eq!(coins.columns[0].field, "balance");
+        assert_eq!(coins.columns[0].type_, CustomColumnType::Number);
+
+        let kiosks = &custom_tables.tables[1];
+        assert!(kiosks.history);
+        assert!(kiosks.columns.is_empty());
+    }
 }
@@ -73,6 +73,9 @@ pub enum IndexerError {
     #[error("Indexer failed to commit changes to PostgresDB with error: `{0}`")]
     PostgresWriteError(String),
 
+    #[error("Indexer failed to open SQLite database with error: `{0}`")]
+    SqliteConnectionError(String),
+
     #[error(transparent)]
     PostgresError(#[from] diesel::result::Error),
 
@@ -97,6 +100,9 @@ pub enum IndexerError {
     #[error("GCS error: `{0}`")]
     GcsError(String),
 
+    #[error("Indexer failed to restore from formal snapshot and archives with error: `{0}`")]
+    RestoreError(String),
+
     #[error("Indexer failed to resolve object to move struct with error: `{0}`")]
     ResolveMoveStructError(String),
 
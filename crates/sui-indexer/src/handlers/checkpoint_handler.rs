@@ -6,6 +6,7 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use itertools::Itertools;
+use sui_types::base_types::{ObjectID, SuiAddress};
 use sui_types::dynamic_field::DynamicFieldInfo;
 use tokio_util::sync::CancellationToken;
 use tracing::info;
@@ -25,31 +26,48 @@ use sui_types::object::Owner;
 use sui_types::sui_system_state::{get_sui_system_state, SuiSystemStateTrait};
 use sui_types::transaction::TransactionDataAPI;
 
+use crate::config::CustomTablesConfig;
 use crate::errors::IndexerError;
 use crate::handlers::committer::start_tx_checkpoint_commit_task;
 use crate::metrics::IndexerMetrics;
 use crate::models::display::StoredDisplay;
+use crate::models::epoch_metrics::{StoredEpochPackageMetrics, StoredEpochSenderMetrics};
 use crate::models::obj_indices::StoredObjectVersion;
-use crate::store::{IndexerStore, PgIndexerStore};
+use crate::store::IndexerStore;
 use crate::types::{
     EventIndex, IndexedCheckpoint, IndexedDeletedObject, IndexedEpochInfo, IndexedEvent,
     IndexedObject, IndexedPackage, IndexedTransaction, IndexerResult, TransactionKind, TxIndex,
 };
 
+use super::custom_tables::CustomTableProjector;
 use super::tx_processor::EpochEndIndexingObjectStore;
 use super::tx_processor::TxChangesProcessor;
 use super::CheckpointDataToCommit;
+use super::EpochMetricsToCommit;
 use super::EpochToCommit;
 use super::TransactionObjectChangesToCommit;
 
 const CHECKPOINT_QUEUE_SIZE: usize = 100;
 
-pub async fn new_handlers(
-    state: PgIndexerStore,
+pub async fn new_handlers<S>(
+    state: S,
     metrics: IndexerMetrics,
     next_checkpoint_sequence_number: CheckpointSequenceNumber,
+    custom_tables: Option<CustomTablesConfig>,
     cancel: CancellationToken,
-) -> Result<CheckpointHandler, IndexerError> {
+) -> Result<CheckpointHandler<S>, IndexerError>
+where
+    S: IndexerStore,
+{
+    let custom_tables = match custom_tables {
+        Some(config) => {
+            let projector = CustomTableProjector::new(state.clone(), config, metrics.clone())?;
+            state.create_custom_tables(projector.tables()).await?;
+            Some(Arc::new(projector))
+        }
+        None => None,
+    };
+
     let checkpoint_queue_size = std::env::var("CHECKPOINT_QUEUE_SIZE")
         .unwrap_or(CHECKPOINT_QUEUE_SIZE.to_string())
         .parse::<usize>()
@@ -76,17 +94,19 @@ pub async fn new_handlers(
         state,
         metrics,
         indexed_checkpoint_sender,
+        custom_tables,
     ))
 }
 
-pub struct CheckpointHandler {
-    state: PgIndexerStore,
+pub struct CheckpointHandler<S> {
+    state: S,
     metrics: IndexerMetrics,
     indexed_checkpoint_sender: mysten_metrics::metered_channel::Sender<CheckpointDataToCommit>,
+    custom_tables: Option<Arc<CustomTableProjector<S>>>,
 }
 
 #[async_trait]
-impl Worker for CheckpointHandler {
+impl<S: IndexerStore> Worker for CheckpointHandler<S> {
     type Result = ();
     async fn process_checkpoint(&self, checkpoint: &CheckpointData) -> anyhow::Result<()> {
         let time_now_ms = chrono::Utc::now().timestamp_millis();
@@ -113,6 +133,7 @@ impl Worker for CheckpointHandler {
             checkpoint,
             Arc::new(self.metrics.clone()),
             Self::index_packages(std::slice::from_ref(checkpoint), &self.metrics),
+            self.custom_tables.as_deref(),
         )
         .await?;
         self.indexed_checkpoint_sender.send(checkpoint_data).await?;
@@ -120,21 +141,23 @@ impl Worker for CheckpointHandler {
     }
 }
 
-impl CheckpointHandler {
+impl<S: IndexerStore> CheckpointHandler<S> {
     fn new(
-        state: PgIndexerStore,
+        state: S,
         metrics: IndexerMetrics,
         indexed_checkpoint_sender: mysten_metrics::metered_channel::Sender<CheckpointDataToCommit>,
+        custom_tables: Option<Arc<CustomTableProjector<S>>>,
     ) -> Self {
         Self {
             state,
             metrics,
             indexed_checkpoint_sender,
+            custom_tables,
         }
     }
 
     async fn index_epoch(
-        state: &PgIndexerStore,
+        state: &S,
         data: &CheckpointData,
     ) -> Result<Option<EpochToCommit>, IndexerError> {
         let checkpoint_object_store = EpochEndIndexingObjectStore::new(data);
@@ -238,11 +261,30 @@ impl CheckpointHandler {
         object_versions
     }
 
+    /// Aggregates this checkpoint's Move calls, transaction counts and net gas usage by package and
+    /// by sender.
+    fn derive_epoch_metrics(
+        checkpoint: &IndexedCheckpoint,
+        transactions: &[IndexedTransaction],
+        tx_indices: &[TxIndex],
+    ) -> EpochMetricsToCommit {
+        aggregate_epoch_metrics(
+            checkpoint.epoch,
+            checkpoint.sequence_number,
+            tx_indices.iter().zip(
+                transactions
+                    .iter()
+                    .map(|tx| tx.effects.gas_cost_summary().net_gas_usage()),
+            ),
+        )
+    }
+
     async fn index_checkpoint(
-        state: &PgIndexerStore,
+        state: &S,
         data: &CheckpointData,
         metrics: Arc<IndexerMetrics>,
         packages: Vec<IndexedPackage>,
+        custom_tables: Option<&CustomTableProjector<S>>,
     ) -> Result<CheckpointDataToCommit, IndexerError> {
         let checkpoint_seq = data.checkpoint_summary.sequence_number;
         info!(checkpoint_seq, "Indexing checkpoint data blob");
@@ -256,6 +298,14 @@ impl CheckpointHandler {
         let object_history_changes: TransactionObjectChangesToCommit =
             Self::index_objects_history(data).await?;
         let object_versions = Self::derive_object_versions(&object_history_changes);
+        let custom_table_changes = match custom_tables {
+            Some(projector) => {
+                projector
+                    .project(&object_changes, &object_history_changes, &packages)
+                    .await?
+            }
+            None => vec![],
+        };
 
         let (checkpoint, db_transactions, db_events, db_tx_indices, db_event_indices, db_displays) = {
             let CheckpointData {
@@ -287,6 +337,9 @@ impl CheckpointHandler {
                 db_displays,
             )
         };
+        let epoch_metrics =
+            Self::derive_epoch_metrics(&checkpoint, &db_transactions, &db_tx_indices);
+
         let time_now_ms = chrono::Utc::now().timestamp_millis();
         metrics
             .index_lag_ms
@@ -314,6 +367,8 @@ impl CheckpointHandler {
             object_versions,
             packages,
             epoch,
+            epoch_metrics,
+            custom_table_changes,
         })
     }
 
@@ -628,3 +683,169 @@ fn try_extract_df_kind(o: &Object) -> IndexerResult<Option<DynamicFieldType>> {
         },
     ))
 }
+
+/// Aggregates the Move calls, transaction counts and net gas usage of `transactions` (paired with
+/// the net gas they used), from checkpoint `checkpoint_sequence_number` in `epoch`, by package and
+/// by sender. A transaction's gas is attributed in full to every package it calls. System
+/// transactions are not counted.
+fn aggregate_epoch_metrics<'a>(
+    epoch: u64,
+    checkpoint_sequence_number: u64,
+    transactions: impl IntoIterator<Item = (&'a TxIndex, i64)>,
+) -> EpochMetricsToCommit {
+    let mut packages: BTreeMap<ObjectID, StoredEpochPackageMetrics> = BTreeMap::new();
+    let mut senders: BTreeMap<SuiAddress, StoredEpochSenderMetrics> = BTreeMap::new();
+
+    for (tx_index, gas_spent) in transactions {
+        if matches!(tx_index.tx_kind, TransactionKind::SystemTransaction) {
+            continue;
+        }
+
+        let sender = senders
+            .entry(tx_index.sender)
+            .or_insert_with(|| StoredEpochSenderMetrics {
+                epoch: epoch as i64,
+                sender: tx_index.sender.to_vec(),
+                checkpoint_sequence_number: checkpoint_sequence_number as i64,
+                ..Default::default()
+            });
+        sender.transactions += 1;
+        sender.gas_spent += gas_spent;
+
+        let mut calls: BTreeMap<ObjectID, i64> = BTreeMap::new();
+        for (package, _, _) in &tx_index.move_calls {
+            *calls.entry(*package).or_default() += 1;
+        }
+
+        for (package, move_calls) in calls {
+            let metrics = packages
+                .entry(package)
+                .or_insert_with(|| StoredEpochPackageMetrics {
+                    epoch: epoch as i64,
+                    package: package.to_vec(),
+                    checkpoint_sequence_number: checkpoint_sequence_number as i64,
+                    ..Default::default()
+                });
+            metrics.move_calls += move_calls;
+            metrics.transactions += 1;
+            metrics.gas_spent += gas_spent;
+        }
+    }
+
+    EpochMetricsToCommit {
+        checkpoint_sequence_number,
+        package_metrics: packages.into_values().collect(),
+        sender_metrics: senders.into_values().collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sui_types::digests::TransactionDigest;
+
+    fn tx_index(
+        tx_kind: TransactionKind,
+        sender: SuiAddress,
+        move_calls: &[(ObjectID, &str)],
+    ) -> TxIndex {
+        TxIndex {
+            tx_sequence_number: 0,
+            tx_kind,
+            transaction_digest: TransactionDigest::random(),
+            checkpoint_sequence_number: 0,
+            input_objects: vec![],
+            changed_objects: vec![],
+            affected_objects: vec![],
+            payers: vec![sender],
+            sender,
+            recipients: vec![],
+            move_calls: move_calls
+                .iter()
+                .map(|(package, function)| (*package, "m".to_string(), function.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn aggregate_by_package_and_sender() {
+        let (alice, bob) = (
+            SuiAddress::random_for_testing_only(),
+            SuiAddress::random_for_testing_only(),
+        );
+        let (p, q) = (ObjectID::random(), ObjectID::random());
+
+        let txs = [
+            (
+                tx_index(
+                    TransactionKind::ProgrammableTransaction,
+                    alice,
+                    &[(p, "f"), (p, "g"), (q, "f")],
+                ),
+                100,
+            ),
+            (
+                tx_index(TransactionKind::ProgrammableTransaction, alice, &[(p, "f")]),
+                10,
+            ),
+            (
+                tx_index(TransactionKind::ProgrammableTransaction, bob, &[]),
+                -5,
+            ),
+        ];
+
+        let metrics = aggregate_epoch_metrics(3, 42, txs.iter().map(|(tx, gas)| (tx, *gas)));
+        assert_eq!(metrics.checkpoint_sequence_number, 42);
+
+        let packages: BTreeMap<_, _> = metrics
+            .package_metrics
+            .iter()
+            .map(|m| {
+                (
+                    m.package.clone(),
+                    (m.move_calls, m.transactions, m.gas_spent),
+                )
+            })
+            .collect();
+        assert_eq!(packages.len(), 2);
+        // A transaction's gas is attributed to every package it calls, but each transaction is
+        // only counted once per package.
+        assert_eq!(packages[&p.to_vec()], (3, 2, 110));
+        assert_eq!(packages[&q.to_vec()], (1, 1, 100));
+
+        let senders: BTreeMap<_, _> = metrics
+            .sender_metrics
+            .iter()
+            .map(|m| (m.sender.clone(), (m.transactions, m.gas_spent)))
+            .collect();
+        assert_eq!(senders.len(), 2);
+        assert_eq!(senders[&alice.to_vec()], (2, 110));
+        // Storage rebates can make net gas usage negative.
+        assert_eq!(senders[&bob.to_vec()], (1, -5));
+
+        for m in &metrics.package_metrics {
+            assert_eq!((m.epoch, m.checkpoint_sequence_number), (3, 42));
+        }
+        for m in &metrics.sender_metrics {
+            assert_eq!((m.epoch, m.checkpoint_sequence_number), (3, 42));
+        }
+    }
+
+    #[test]
+    fn aggregate_skips_system_transactions() {
+        let sender = SuiAddress::ZERO;
+        let package = ObjectID::random();
+        let txs = [(
+            tx_index(
+                TransactionKind::SystemTransaction,
+                sender,
+                &[(package, "advance_epoch")],
+            ),
+            1000,
+        )];
+
+        let metrics = aggregate_epoch_metrics(0, 0, txs.iter().map(|(tx, gas)| (tx, *gas)));
+        assert!(metrics.package_metrics.is_empty());
+        assert!(metrics.sender_metrics.is_empty());
+    }
+}
@@ -108,6 +108,8 @@ async fn commit_checkpoints<S>(
     let mut object_history_changes_batch = vec![];
     let mut object_versions_batch = vec![];
     let mut packages_batch = vec![];
+    let mut epoch_metrics_batch = vec![];
+    let mut custom_table_changes_batch = vec![];
 
     for indexed_checkpoint in indexed_checkpoint_batch {
         let CheckpointDataToCommit {
@@ -122,6 +124,8 @@ async fn commit_checkpoints<S>(
             object_versions,
             packages,
             epoch: _,
+            epoch_metrics,
+            custom_table_changes,
         } = indexed_checkpoint;
         checkpoint_batch.push(checkpoint);
         tx_batch.push(transactions);
@@ -133,6 +137,8 @@ async fn commit_checkpoints<S>(
         object_history_changes_batch.push(object_history_changes);
         object_versions_batch.push(object_versions);
         packages_batch.push(packages);
+        epoch_metrics_batch.push(epoch_metrics);
+        custom_table_changes_batch.extend(custom_table_changes);
     }
 
     let first_checkpoint_seq = checkpoint_batch.first().as_ref().unwrap().sequence_number;
@@ -176,6 +182,8 @@ async fn commit_checkpoints<S>(
             state.persist_full_objects_history(object_history_changes_batch.clone()),
             state.persist_objects_version(object_versions_batch.clone()),
             state.persist_raw_checkpoints(raw_checkpoints_batch),
+            state.persist_epoch_metrics(epoch_metrics_batch),
+            state.persist_custom_tables(custom_table_changes_batch),
         ];
         if let Some(epoch_data) = epoch.clone() {
             persist_tasks.push(state.persist_epoch(epoch_data));
@@ -0,0 +1,491 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Custom tables hold a projection of the objects of a single Move struct type, with one column per
+//! declared field. They are maintained from the same object changes as `objects` (or, for tables
+//! that keep history, `objects_history`), so a table declared after the indexer has started only
+//! includes objects that are created or modified from then on.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::annotated_value::MoveTypeLayout;
+use move_core_types::language_storage::{StructTag, TypeTag};
+use serde_json::Value;
+use sui_json_rpc_types::SuiMoveStruct;
+use sui_package_resolver::{
+    error::Error as PackageResolverError, Package, PackageStore, PackageStoreWithLruCache, Resolver,
+};
+use sui_types::base_types::{ObjectID, SuiAddress};
+use sui_types::object::bounded_visitor::BoundedVisitor;
+use sui_types::object::Object;
+use sui_types::parse_sui_struct_tag;
+
+use crate::config::{CustomColumnType, CustomTableConfig, CustomTablesConfig};
+use crate::errors::IndexerError;
+use crate::metrics::IndexerMetrics;
+use crate::store::IndexerStore;
+use crate::types::{owner_to_owner_info, IndexedDeletedObject, IndexedPackage};
+
+use super::TransactionObjectChangesToCommit;
+
+/// Columns every custom table has, ahead of its declared columns.
+pub const SYSTEM_COLUMNS: [&str; 6] = [
+    "object_id",
+    "object_version",
+    "checkpoint_sequence_number",
+    "owner_type",
+    "owner_id",
+    "deleted",
+];
+
+const MAX_IDENTIFIER_LENGTH: usize = 48;
+
+/// A validated custom table declaration.
+#[derive(Clone, Debug)]
+pub struct CustomTable {
+    pub name: String,
+    pub type_: StructTag,
+    pub history: bool,
+    pub columns: Vec<CustomColumn>,
+    pub config: CustomTableConfig,
+}
+
+#[derive(Clone, Debug)]
+pub struct CustomColumn {
+    pub name: String,
+    pub path: Vec<String>,
+    pub type_: CustomColumnType,
+}
+
+/// The value of a custom column, ready to be bound to a statement.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CustomValue {
+    Null,
+    /// A decimal integer, kept as a string so that values beyond 64 bits are not truncated.
+    Number(String),
+    Text(String),
+    Bool(bool),
+    Json(String),
+}
+
+#[derive(Clone, Debug)]
+pub struct CustomTableRow {
+    pub object_id: ObjectID,
+    pub object_version: u64,
+    pub checkpoint_sequence_number: u64,
+    pub owner_type: i16,
+    pub owner_id: Option<Vec<u8>>,
+    pub values: Vec<CustomValue>,
+}
+
+/// Changes to one custom table from a single checkpoint. Deleted objects are not filtered by type,
+/// as their type is no longer known, so they apply only to rows already in the table.
+#[derive(Clone, Debug)]
+pub struct CustomTableChangesToCommit {
+    pub table: Arc<CustomTable>,
+    pub changed_rows: Vec<CustomTableRow>,
+    pub deleted_objects: Vec<IndexedDeletedObject>,
+}
+
+/// Projects the objects changed in each checkpoint into the custom tables declared in the
+/// indexer's config.
+pub struct CustomTableProjector<S> {
+    tables: Vec<Arc<CustomTable>>,
+    packages: Arc<PackageStoreWithLruCache<StorePackages<S>>>,
+    metrics: IndexerMetrics,
+}
+
+/// Reads packages from the indexer's own store.
+struct StorePackages<S>(S);
+
+/// Packages published in the checkpoint being indexed, which are not yet in the store, in front
+/// of the packages that are.
+struct CheckpointPackages<S> {
+    packages: HashMap<AccountAddress, Arc<Package>>,
+    store: Arc<PackageStoreWithLruCache<StorePackages<S>>>,
+}
+
+impl CustomTable {
+    pub fn new(config: CustomTableConfig) -> Result<Self, IndexerError> {
+        validate_identifier(&config.name, "table")?;
+
+        let type_ = parse_sui_struct_tag(&config.type_).map_err(|e| {
+            IndexerError::InvalidArgumentError(format!(
+                "Invalid type for custom table {}: {e}",
+                config.name
+            ))
+        })?;
+
+        let mut columns = Vec::with_capacity(config.columns.len());
+        for column in &config.columns {
+            validate_identifier(&column.name, "column")?;
+            if SYSTEM_COLUMNS.contains(&column.name.as_str())
+                || columns.iter().any(|c: &CustomColumn| c.name == column.name)
+            {
+                return Err(IndexerError::InvalidArgumentError(format!(
+                    "Duplicate column {} in custom table {}",
+                    column.name, config.name
+                )));
+            }
+
+            let path: Vec<_> = column.field.split('.').map(str::to_owned).collect();
+            if path.iter().any(String::is_empty) {
+                return Err(IndexerError::InvalidArgumentError(format!(
+                    "Invalid field path {:?} for column {} in custom table {}",
+                    column.field, column.name, config.name
+                )));
+            }
+
+            columns.push(CustomColumn {
+                name: column.name.clone(),
+                path,
+                type_: column.type_,
+            });
+        }
+
+        Ok(Self {
+            name: config.name.clone(),
+            type_,
+            history: config.history,
+            columns,
+            config,
+        })
+    }
+
+    /// The name of the table in the database.
+    pub fn table_name(&self) -> String {
+        format!("custom_{}", self.name)
+    }
+
+    /// Whether objects of type `tag` belong in this table. A declared type without type
+    /// parameters matches every instantiation of the struct.
+    pub fn matches(&self, tag: &StructTag) -> bool {
+        self.type_.address == tag.address
+            && self.type_.module == tag.module
+            && self.type_.name == tag.name
+            && (self.type_.type_params.is_empty() || self.type_.type_params == tag.type_params)
+    }
+
+    /// Extract the declared columns from an object's contents, rendered as JSON. Fields that are
+    /// missing, or whose value does not fit the column's type, are projected as `NULL`.
+    pub fn project(&self, contents: &Value) -> Vec<CustomValue> {
+        self.columns
+            .iter()
+            .map(|column| {
+                let value = column
+                    .path
+                    .iter()
+                    .try_fold(contents, |value, step| match value {
+                        Value::Object(fields) => fields.get(step),
+                        Value::Array(elements) => elements.get(step.parse::<usize>().ok()?),
+                        _ => None,
+                    });
+
+                value.map_or(CustomValue::Null, |v| column.type_.value(v))
+            })
+            .collect()
+    }
+}
+
+impl CustomColumnType {
+    /// The SQL type of the column in Postgres.
+    pub fn pg_type(&self) -> &'static str {
+        match self {
+            CustomColumnType::Number => "NUMERIC",
+            CustomColumnType::Text | CustomColumnType::Address => "TEXT",
+            CustomColumnType::Bool => "BOOLEAN",
+            CustomColumnType::Json => "JSONB",
+        }
+    }
+
+    /// The SQL type of the column in SQLite.
+    pub fn sqlite_type(&self) -> &'static str {
+        match self {
+            CustomColumnType::Number => "NUMERIC",
+            CustomColumnType::Text | CustomColumnType::Address | CustomColumnType::Json => "TEXT",
+            CustomColumnType::Bool => "BOOLEAN",
+        }
+    }
+
+    fn value(&self, value: &Value) -> CustomValue {
+        match (self, value) {
+            (_, Value::Null) => CustomValue::Null,
+
+            (CustomColumnType::Number, Value::Number(n)) => CustomValue::Number(n.to_string()),
+            (CustomColumnType::Number, Value::String(s))
+                if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) =>
+            {
+                CustomValue::Number(s.clone())
+            }
+
+            (CustomColumnType::Text, Value::String(s)) => CustomValue::Text(s.clone()),
+            (CustomColumnType::Text, Value::Number(n)) => CustomValue::Text(n.to_string()),
+            (CustomColumnType::Text, Value::Bool(b)) => CustomValue::Text(b.to_string()),
+
+            (CustomColumnType::Bool, Value::Bool(b)) => CustomValue::Bool(*b),
+
+            (CustomColumnType::Address, Value::String(s)) => SuiAddress::from_str(s)
+                .map_or(CustomValue::Null, |a| CustomValue::Text(a.to_string())),
+
+            (CustomColumnType::Json, v) => CustomValue::Json(v.to_string()),
+
+            _ => CustomValue::Null,
+        }
+    }
+}
+
+impl<S: IndexerStore> CustomTableProjector<S> {
+    pub fn new(
+        store: S,
+        config: CustomTablesConfig,
+        metrics: IndexerMetrics,
+    ) -> Result<Self, IndexerError> {
+        let mut tables: Vec<Arc<CustomTable>> = Vec::with_capacity(config.tables.len());
+        for table in config.tables {
+            let table = CustomTable::new(table)?;
+            if tables.iter().any(|t| t.name == table.name) {
+                return Err(IndexerError::InvalidArgumentError(format!(
+                    "Duplicate custom table {}",
+                    table.name
+                )));
+            }
+            tables.push(Arc::new(table));
+        }
+
+        Ok(Self {
+            tables,
+            packages: Arc::new(PackageStoreWithLruCache::new(StorePackages(store))),
+            metrics,
+        })
+    }
+
+    pub fn tables(&self) -> &[Arc<CustomTable>] {
+        &self.tables
+    }
+
+    /// Project the objects changed by a checkpoint into every custom table. Live tables follow
+    /// `object_changes`, and tables that keep history follow `object_history_changes`.
+    pub async fn project(
+        &self,
+        object_changes: &TransactionObjectChangesToCommit,
+        object_history_changes: &TransactionObjectChangesToCommit,
+        packages: &[IndexedPackage],
+    ) -> Result<Vec<CustomTableChangesToCommit>, IndexerError> {
+        let _timer = self.metrics.indexing_custom_tables_latency.start_timer();
+
+        let resolver = Resolver::new(CheckpointPackages {
+            packages: packages
+                .iter()
+                .map(|p| {
+                    let package = Package::read_from_package(&p.move_package)?;
+                    Ok((AccountAddress::from(p.package_id), Arc::new(package)))
+                })
+                .collect::<Result<_, PackageResolverError>>()
+                .map_err(|e| IndexerError::ResolveMoveStructError(e.to_string()))?,
+            store: self.packages.clone(),
+        });
+
+        let mut changes = Vec::with_capacity(self.tables.len());
+        for table in &self.tables {
+            let source = if table.history {
+                object_history_changes
+            } else {
+                object_changes
+            };
+
+            let mut changed_rows = vec![];
+            for indexed in &source.changed_objects {
+                if let Some(row) = project_object(
+                    table,
+                    &resolver,
+                    &indexed.object,
+                    indexed.checkpoint_sequence_number,
+                )
+                .await?
+                {
+                    changed_rows.push(row);
+                }
+            }
+
+            changes.push(CustomTableChangesToCommit {
+                table: table.clone(),
+                changed_rows,
+                deleted_objects: source.deleted_objects.clone(),
+            });
+        }
+
+        Ok(changes)
+    }
+}
+
+async fn project_object<S: PackageStore>(
+    table: &CustomTable,
+    resolver: &Resolver<S>,
+    object: &Object,
+    checkpoint_sequence_number: u64,
+) -> Result<Option<CustomTableRow>, IndexerError> {
+    let Some(move_object) = object.data.try_as_move() else {
+        return Ok(None);
+    };
+
+    let tag = StructTag::from(move_object.type_().clone());
+    if !table.matches(&tag) {
+        return Ok(None);
+    }
+
+    let layout = resolver
+        .type_layout(TypeTag::Struct(Box::new(tag.clone())))
+        .await
+        .map_err(|e| {
+            IndexerError::ResolveMoveStructError(format!(
+                "Failed to resolve layout of {} for custom table {}: {e}",
+                tag.to_canonical_string(/* with_prefix */ true),
+                table.name,
+            ))
+        })?;
+
+    let MoveTypeLayout::Struct(layout) = layout else {
+        return Err(IndexerError::ResolveMoveStructError(format!(
+            "Layout of {} is not a struct",
+            tag.to_canonical_string(/* with_prefix */ true),
+        )));
+    };
+
+    let move_struct = BoundedVisitor::deserialize_struct(move_object.contents(), &layout)
+        .map_err(|e| IndexerError::SerdeError(e.to_string()))?;
+    let contents = SuiMoveStruct::from(move_struct).to_json_value();
+
+    let (owner_type, owner_id) = owner_to_owner_info(&object.owner);
+    Ok(Some(CustomTableRow {
+        object_id: object.id(),
+        object_version: object.version().value(),
+        checkpoint_sequence_number,
+        owner_type: owner_type as i16,
+        owner_id: owner_id.map(|id| id.to_vec()),
+        values: table.project(&contents),
+    }))
+}
+
+fn validate_identifier(name: &str, kind: &str) -> Result<(), IndexerError> {
+    let mut chars = name.chars();
+    let valid = name.len() <= MAX_IDENTIFIER_LENGTH
+        && chars.next().is_some_and(|c| c.is_ascii_lowercase())
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+
+    if valid {
+        Ok(())
+    } else {
+        Err(IndexerError::InvalidArgumentError(format!(
+            "Invalid custom {kind} name {name:?}: expected at most {MAX_IDENTIFIER_LENGTH} \
+             lowercase letters, digits or underscores, starting with a letter"
+        )))
+    }
+}
+
+#[async_trait]
+impl<S: IndexerStore> PackageStore for StorePackages<S> {
+    async fn fetch(&self, id: AccountAddress) -> sui_package_resolver::Result<Arc<Package>> {
+        let package = self
+            .0
+            .get_move_package(ObjectID::from(id))
+            .await
+            .map_err(|e| PackageResolverError::Store {
+                store: "IndexerStore",
+                error: e.to_string(),
+            })?
+            .ok_or(PackageResolverError::PackageNotFound(id))?;
+
+        Ok(Arc::new(Package::read_from_package(&package)?))
+    }
+}
+
+#[async_trait]
+impl<S: IndexerStore> PackageStore for CheckpointPackages<S> {
+    async fn fetch(&self, id: AccountAddress) -> sui_package_resolver::Result<Arc<Package>> {
+        if let Some(package) = self.packages.get(&id) {
+            return Ok(package.clone());
+        }
+
+        self.store.fetch(id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::config::CustomColumnConfig;
+
+    fn table(columns: &[(&str, &str, CustomColumnType)]) -> Result<CustomTable, IndexerError> {
+        CustomTable::new(CustomTableConfig {
+            name: "coins".to_string(),
+            type_: "0x2::coin::Coin".to_string(),
+            history: false,
+            columns: columns
+                .iter()
+                .map(|(name, field, type_)| CustomColumnConfig {
+                    name: name.to_string(),
+                    field: field.to_string(),
+                    type_: *type_,
+                })
+                .collect(),
+        })
+    }
+
+    #[test]
+    fn project_columns() {
+        let table = table(&[
+            ("balance", "balance", CustomColumnType::Number),
+            ("id", "id.id", CustomColumnType::Address),
+            ("first", "items.0", CustomColumnType::Text),
+            ("missing", "nope", CustomColumnType::Bool),
+            ("mismatch", "balance", CustomColumnType::Bool),
+            ("raw", "items", CustomColumnType::Json),
+        ])
+        .unwrap();
+
+        let contents = json!({
+            "id": { "id": "0x5" },
+            "balance": "18446744073709551615",
+            "items": ["a", "b"],
+        });
+
+        assert_eq!(
+            table.project(&contents),
+            vec![
+                CustomValue::Number("18446744073709551615".to_string()),
+                CustomValue::Text(SuiAddress::from_str("0x5").unwrap().to_string()),
+                CustomValue::Text("a".to_string()),
+                CustomValue::Null,
+                CustomValue::Null,
+                CustomValue::Json(r#"["a","b"]"#.to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn match_instantiations() {
+        let any = table(&[]).unwrap();
+        let sui = parse_sui_struct_tag("0x2::coin::Coin<0x2::sui::SUI>").unwrap();
+        let other = parse_sui_struct_tag("0x2::balance::Balance<0x2::sui::SUI>").unwrap();
+        assert!(any.matches(&sui));
+        assert!(!any.matches(&other));
+    }
+
+    #[test]
+    fn reject_invalid_names() {
+        assert!(table(&[("Balance", "balance", CustomColumnType::Number)]).is_err());
+        assert!(table(&[("object_id", "id.id", CustomColumnType::Address)]).is_err());
+        assert!(table(&[("balance", "balance..value", CustomColumnType::Number)]).is_err());
+        assert!(table(&[
+            ("balance", "balance", CustomColumnType::Number),
+            ("balance", "balance", CustomColumnType::Text),
+        ])
+        .is_err());
+    }
+}
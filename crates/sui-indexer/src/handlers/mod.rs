@@ -10,9 +10,14 @@ use serde::{Deserialize, Serialize};
 use sui_rest_api::CheckpointData;
 use tokio_util::sync::CancellationToken;
 
+use self::custom_tables::CustomTableChangesToCommit;
 use crate::{
     errors::IndexerError,
-    models::{display::StoredDisplay, obj_indices::StoredObjectVersion},
+    models::{
+        display::StoredDisplay,
+        epoch_metrics::{StoredEpochPackageMetrics, StoredEpochSenderMetrics},
+        obj_indices::StoredObjectVersion,
+    },
     types::{
         EventIndex, IndexedCheckpoint, IndexedDeletedObject, IndexedEpochInfo, IndexedEvent,
         IndexedObject, IndexedPackage, IndexedTransaction, IndexerResult, TxIndex,
@@ -21,6 +26,7 @@ use crate::{
 
 pub mod checkpoint_handler;
 pub mod committer;
+pub mod custom_tables;
 pub mod objects_snapshot_handler;
 pub mod pruner;
 pub mod tx_processor;
@@ -40,6 +46,8 @@ pub struct CheckpointDataToCommit {
     pub object_versions: Vec<StoredObjectVersion>,
     pub packages: Vec<IndexedPackage>,
     pub epoch: Option<EpochToCommit>,
+    pub epoch_metrics: EpochMetricsToCommit,
+    pub custom_table_changes: Vec<CustomTableChangesToCommit>,
 }
 
 #[derive(Clone, Debug)]
@@ -55,6 +63,15 @@ pub struct EpochToCommit {
     pub network_total_transactions: u64,
 }
 
+/// The contribution of a single checkpoint to the per-epoch aggregate metrics tables. Each vector
+/// contains at most one entry per package or sender.
+#[derive(Clone, Debug, Default)]
+pub struct EpochMetricsToCommit {
+    pub checkpoint_sequence_number: u64,
+    pub package_metrics: Vec<StoredEpochPackageMetrics>,
+    pub sender_metrics: Vec<StoredEpochSenderMetrics>,
+}
+
 pub struct CommonHandler<T> {
     handler: Box<dyn Handler<T>>,
 }
@@ -215,6 +232,8 @@ pub enum CommitterTables {
     // Unpruned tables
     ChainIdentifier,
     Display,
+    EpochPackageMetrics,
+    EpochSenderMetrics,
     Epochs,
     FeatureFlags,
     FullObjectsHistory,
@@ -113,7 +113,7 @@ impl Pruner {
             last_seen_max_epoch = max_epoch;
 
             // Not all partitioned tables are epoch-partitioned, so we need to filter them out.
-            let table_partitions: HashMap<_, _> = self
+            let mut table_partitions: Vec<_> = self
                 .partition_manager
                 .get_table_partitions()
                 .await?
@@ -125,6 +125,16 @@ impl Pruner {
                 })
                 .collect();
 
+            // NOTE: the order of dropping partitions is crucial. `checkpoints` is the source table
+            // of the available range, so its partitions are dropped first, to make sure that we
+            // always have full data for checkpoints within the available range. `pruner_cp_watermark`
+            // is the checkpoint pruning watermark, so its partitions are dropped last.
+            table_partitions.sort_by_key(|(table_name, _)| match table_name.as_str() {
+                "checkpoints" => 0,
+                "pruner_cp_watermark" => 2,
+                _ => 1,
+            });
+
             for (table_name, (min_partition, max_partition)) in &table_partitions {
                 if let Some(epochs_to_keep) = self.table_retention(table_name) {
                     if last_seen_max_epoch != *max_partition {
@@ -39,6 +39,24 @@ impl Indexer {
         snapshot_config: SnapshotLagConfig,
         retention_config: Option<RetentionConfig>,
         cancel: CancellationToken,
+    ) -> Result<(), IndexerError> {
+        if let Some(retention_config) = retention_config {
+            let pruner = Pruner::new(store.clone(), retention_config, metrics.clone())?;
+            let cancel_clone = cancel.clone();
+            spawn_monitored_task!(pruner.start(cancel_clone));
+        }
+
+        Self::start_writer_with_store(config, store, metrics, snapshot_config, cancel).await
+    }
+
+    /// Runs the ingestion pipelines against any `IndexerStore`. Unlike `start_writer`, this does
+    /// not start the pruner, which relies on Postgres partitions.
+    pub async fn start_writer_with_store<S: IndexerStore>(
+        config: &IngestionConfig,
+        store: S,
+        metrics: IndexerMetrics,
+        snapshot_config: SnapshotLagConfig,
+        cancel: CancellationToken,
     ) -> Result<(), IndexerError> {
         info!(
             "Sui Indexer Writer (version {:?}) started...",
@@ -69,12 +87,6 @@ impl Indexer {
         )
         .await?;
 
-        if let Some(retention_config) = retention_config {
-            let pruner = Pruner::new(store.clone(), retention_config, metrics.clone())?;
-            let cancel_clone = cancel.clone();
-            spawn_monitored_task!(pruner.start(cancel_clone));
-        }
-
         // If we already have chain identifier indexed (i.e. the first checkpoint has been indexed),
         // then we persist protocol configs for protocol versions not yet in the db.
         // Otherwise, we would do the persisting in `commit_checkpoint` while the first cp is
@@ -98,7 +110,14 @@ impl Indexer {
             2,
             DataIngestionMetrics::new(&Registry::new()),
         );
-        let worker = new_handlers(store, metrics, primary_watermark, cancel.clone()).await?;
+        let worker = new_handlers(
+            store,
+            metrics,
+            primary_watermark,
+            config.load_custom_tables(),
+            cancel.clone(),
+        )
+        .await?;
         let worker_pool = WorkerPool::new(
             worker,
             "primary".to_string(),
@@ -8,6 +8,7 @@ use diesel::{
     OptionalExtension, QueryDsl, SelectableHelper, TextExpressionMethods,
 };
 use itertools::Itertools;
+use std::collections::HashMap;
 use std::sync::Arc;
 use sui_types::dynamic_field::visitor as DFV;
 use sui_types::object::bounded_visitor::BoundedVisitor;
@@ -19,18 +20,18 @@ use fastcrypto::encoding::Hex;
 use move_core_types::annotated_value::MoveStructLayout;
 use move_core_types::language_storage::{StructTag, TypeTag};
 use sui_json_rpc_types::DisplayFieldsResponse;
-use sui_json_rpc_types::{Balance, Coin as SuiCoin, SuiCoinMetadata, SuiMoveValue};
 use sui_json_rpc_types::{
-    CheckpointId, EpochInfo, EventFilter, SuiEvent, SuiObjectDataFilter,
-    SuiTransactionBlockResponse, TransactionFilter,
+    AddressActivity, CheckpointId, EpochInfo, EventFilter, ObjectHistoryEntry, ObjectHistoryStatus,
+    SuiEvent, SuiObjectDataFilter, SuiTransactionBlockResponse, TransactionFilter,
 };
+use sui_json_rpc_types::{Balance, Coin as SuiCoin, SuiCoinMetadata, SuiMoveValue};
 use sui_package_resolver::Package;
 use sui_package_resolver::PackageStore;
 use sui_package_resolver::{PackageStoreWithLruCache, Resolver};
-use sui_types::effects::TransactionEvents;
+use sui_types::effects::{TransactionEffects, TransactionEffectsAPI, TransactionEvents};
 use sui_types::{balance::Supply, coin::TreasuryCap, dynamic_field::DynamicFieldName};
 use sui_types::{
-    base_types::{ObjectID, SuiAddress, VersionNumber},
+    base_types::{ObjectID, SequenceNumber, SuiAddress, VersionNumber},
     committee::EpochId,
     digests::TransactionDigest,
     dynamic_field::DynamicFieldInfo,
@@ -39,11 +40,11 @@ use sui_types::{
 };
 use sui_types::{coin::CoinMetadata, event::EventID};
 
-use crate::database::ConnectionPool;
+use crate::database::{Connection, ConnectionPool};
 use crate::db::ConnectionPoolConfig;
 use crate::models::transactions::{stored_events_to_events, StoredTransactionEvents};
 use crate::schema::pruner_cp_watermark;
-use crate::schema::tx_digests;
+use crate::schema::{objects_history, tx_affected_addresses, tx_affected_objects, tx_digests};
 use crate::{
     errors::IndexerError,
     models::{
@@ -51,13 +52,13 @@ use crate::{
         display::StoredDisplay,
         epoch::StoredEpochInfo,
         events::StoredEvent,
-        objects::{CoinBalance, StoredObject},
+        objects::{CoinBalance, StoredHistoryObject, StoredObject},
         transactions::{tx_events_to_sui_tx_events, StoredTransaction},
         tx_indices::TxSequenceNumber,
     },
     schema::{checkpoints, display, epochs, events, objects, transactions},
     store::package_resolver::IndexerStorePackageResolver,
-    types::{IndexerResult, OwnerType},
+    types::{IndexerResult, ObjectStatus, OwnerType},
 };
 
 pub const TX_SEQUENCE_NUMBER_STR: &str = "tx_sequence_number";
@@ -606,6 +607,223 @@ impl IndexerReader {
             .map_err(Into::into)
     }
 
+    /// Versions of `object_id` from `objects_history`, paged by version and optionally limited to
+    /// versions written strictly between `after_checkpoint` and `before_checkpoint`.
+    pub async fn get_object_history(
+        &self,
+        object_id: ObjectID,
+        after_checkpoint: Option<u64>,
+        before_checkpoint: Option<u64>,
+        cursor: Option<u64>,
+        limit: usize,
+        descending_order: bool,
+    ) -> Result<Vec<ObjectHistoryEntry>, IndexerError> {
+        use diesel_async::RunQueryDsl;
+
+        let mut connection = self.pool.get().await?;
+
+        let mut query = objects_history::table
+            .filter(objects_history::object_id.eq(object_id.to_vec()))
+            .into_boxed();
+
+        if let Some(after) = after_checkpoint {
+            query = query.filter(objects_history::checkpoint_sequence_number.gt(after as i64));
+        }
+        if let Some(before) = before_checkpoint {
+            query = query.filter(objects_history::checkpoint_sequence_number.lt(before as i64));
+        }
+
+        if descending_order {
+            if let Some(cursor) = cursor {
+                query = query.filter(objects_history::object_version.lt(cursor as i64));
+            }
+            query = query.order(objects_history::object_version.desc());
+        } else {
+            if let Some(cursor) = cursor {
+                query = query.filter(objects_history::object_version.gt(cursor as i64));
+            }
+            query = query.order(objects_history::object_version.asc());
+        }
+
+        let stored: Vec<StoredHistoryObject> = query
+            .select(StoredHistoryObject::as_select())
+            .limit(limit as i64)
+            .load(&mut connection)
+            .await?;
+
+        // Wrapped and deleted versions do not record the transaction that produced them, so they
+        // are looked up together once every entry has been read.
+        let removed: Vec<_> = stored
+            .iter()
+            .filter(|s| s.object_status == ObjectStatus::WrappedOrDeleted as i16)
+            .map(|s| s.checkpoint_sequence_number)
+            .collect();
+        let removed_by = self
+            .get_transactions_removing_object(&mut connection, object_id, removed)
+            .await?;
+
+        let mut entries = Vec::with_capacity(stored.len());
+        for stored in stored {
+            let checkpoint = stored.checkpoint_sequence_number as u64;
+            let version = SequenceNumber::from_u64(stored.object_version as u64);
+
+            let entry = match ObjectStatus::try_from(stored.object_status)? {
+                ObjectStatus::Active => {
+                    let serialized = stored.serialized_object.ok_or_else(|| {
+                        IndexerError::PersistentStorageDataCorruptionError(format!(
+                            "Active object {object_id} at version {version} has no contents"
+                        ))
+                    })?;
+                    let object: Object = bcs::from_bytes(&serialized).map_err(|e| {
+                        IndexerError::SerdeError(format!(
+                            "Failed to deserialize object: {object_id}, error: {e}"
+                        ))
+                    })?;
+                    ObjectHistoryEntry {
+                        object_id,
+                        version,
+                        digest: Some(object.digest()),
+                        checkpoint,
+                        status: ObjectHistoryStatus::Active,
+                        previous_transaction: Some(object.previous_transaction),
+                        owner: Some(object.owner.clone()),
+                        object_type: object
+                            .type_()
+                            .map(|t| t.to_canonical_string(/* with_prefix */ true)),
+                    }
+                }
+
+                ObjectStatus::WrappedOrDeleted => ObjectHistoryEntry {
+                    object_id,
+                    version,
+                    digest: None,
+                    checkpoint,
+                    status: ObjectHistoryStatus::WrappedOrDeleted,
+                    previous_transaction: removed_by.get(&version).copied(),
+                    owner: None,
+                    object_type: None,
+                },
+            };
+
+            entries.push(entry);
+        }
+
+        Ok(entries)
+    }
+
+    /// Transactions that affected `address`, each summarised from its point of view, paged by
+    /// transaction order.
+    pub async fn get_address_activity(
+        &self,
+        address: SuiAddress,
+        cursor: Option<TransactionDigest>,
+        limit: usize,
+        descending_order: bool,
+    ) -> Result<Vec<AddressActivity>, IndexerError> {
+        use diesel_async::RunQueryDsl;
+
+        let mut connection = self.pool.get().await?;
+
+        let cursor_tx_seq = if let Some(cursor) = cursor {
+            let tx_seq = tx_digests::table
+                .select(tx_digests::tx_sequence_number)
+                .filter(tx_digests::tx_digest.eq(cursor.into_inner().to_vec()))
+                .first::<i64>(&mut connection)
+                .await
+                .optional()?
+                .ok_or_else(|| {
+                    IndexerError::InvalidArgumentError(format!(
+                        "Cursor transaction {cursor} not found"
+                    ))
+                })?;
+            Some(tx_seq)
+        } else {
+            None
+        };
+
+        let mut query = tx_affected_addresses::table
+            .select(tx_affected_addresses::tx_sequence_number)
+            .filter(tx_affected_addresses::affected.eq(address.to_vec()))
+            .into_boxed();
+
+        if descending_order {
+            if let Some(cursor_tx_seq) = cursor_tx_seq {
+                query = query.filter(tx_affected_addresses::tx_sequence_number.lt(cursor_tx_seq));
+            }
+            query = query.order(tx_affected_addresses::tx_sequence_number.desc());
+        } else {
+            if let Some(cursor_tx_seq) = cursor_tx_seq {
+                query = query.filter(tx_affected_addresses::tx_sequence_number.gt(cursor_tx_seq));
+            }
+            query = query.order(tx_affected_addresses::tx_sequence_number.asc());
+        }
+
+        let tx_seqs: Vec<i64> = query.limit(limit as i64).load(&mut connection).await?;
+
+        let stored_txes: Vec<StoredTransaction> = transactions::table
+            .filter(transactions::tx_sequence_number.eq_any(tx_seqs.clone()))
+            .load(&mut connection)
+            .await?;
+
+        // Transactions come back in an arbitrary order, so restore the order of the page.
+        let mut stored_txes: HashMap<i64, StoredTransaction> = stored_txes
+            .into_iter()
+            .map(|tx| (tx.tx_sequence_number, tx))
+            .collect();
+
+        tx_seqs
+            .into_iter()
+            .filter_map(|seq| stored_txes.remove(&seq))
+            .map(|tx| tx.try_into_address_activity(address))
+            .collect()
+    }
+
+    /// The transactions that wrapped or deleted `object_id` in any of `checkpoints`, keyed by the
+    /// version they wrapped or deleted it at. That is the transaction's lamport version, which is
+    /// distinct for every transaction that touches the object, so the transaction is found among
+    /// those that affected the object in the checkpoint by its effects. Transactions that have
+    /// since been pruned are missing.
+    async fn get_transactions_removing_object(
+        &self,
+        connection: &mut Connection<'_>,
+        object_id: ObjectID,
+        checkpoints: Vec<i64>,
+    ) -> Result<HashMap<SequenceNumber, TransactionDigest>, IndexerError> {
+        use diesel_async::RunQueryDsl;
+
+        if checkpoints.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let affecting = tx_affected_objects::table
+            .select(tx_affected_objects::tx_sequence_number)
+            .filter(tx_affected_objects::affected.eq(object_id.to_vec()));
+
+        let candidates: Vec<(Vec<u8>, Vec<u8>)> = transactions::table
+            .select((transactions::transaction_digest, transactions::raw_effects))
+            .filter(transactions::checkpoint_sequence_number.eq_any(checkpoints))
+            .filter(transactions::tx_sequence_number.eq_any(affecting))
+            .load(connection)
+            .await?;
+
+        let mut removed_by = HashMap::new();
+        for (digest, raw_effects) in candidates {
+            let effects: TransactionEffects = bcs::from_bytes(&raw_effects).map_err(|e| {
+                IndexerError::SerdeError(format!("Failed to deserialize effects: {e}"))
+            })?;
+
+            let digest = TransactionDigest::try_from(digest.as_slice()).map_err(|e| {
+                IndexerError::PersistentStorageDataCorruptionError(format!(
+                    "Failed to parse transaction digest: {e}"
+                ))
+            })?;
+
+            removed_by.insert(effects.lamport_version(), digest);
+        }
+
+        Ok(removed_by)
+    }
+
     async fn query_transaction_blocks_by_checkpoint(
         &self,
         checkpoint_seq: u64,
@@ -668,7 +886,13 @@ impl IndexerReader {
                 .select(tx_digests::tx_sequence_number)
                 .filter(tx_digests::tx_digest.eq(cursor.into_inner().to_vec()))
                 .first::<i64>(&mut connection)
-                .await?;
+                .await
+                .optional()?
+                .ok_or_else(|| {
+                    IndexerError::InvalidArgumentError(format!(
+                        "Cursor transaction {cursor} not found"
+                    ))
+                })?;
             Some(tx_seq)
         } else {
             None
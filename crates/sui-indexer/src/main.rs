@@ -13,6 +13,7 @@ use sui_indexer::metrics::{
     spawn_connection_pool_metric_collector, start_prometheus_server, IndexerMetrics,
 };
 use sui_indexer::restorer::formal_snapshot::IndexerFormalSnapshotRestorer;
+use sui_indexer::restorer::incremental::IndexerIncrementalRestorer;
 use sui_indexer::store::PgIndexerStore;
 use tokio_util::sync::CancellationToken;
 use tracing::warn;
@@ -98,6 +99,30 @@ async fn main() -> anyhow::Result<()> {
                 IndexerFormalSnapshotRestorer::new(store, restore_config).await?;
             formal_restorer.restore().await?;
         }
+        Command::IncrementalRestore {
+            restore_config,
+            replay_config,
+            ingestion_config,
+            snapshot_config,
+            pruning_options,
+        } => {
+            run_migrations(pool.dedicated_connection().await?).await?;
+            let retention_config = pruning_options.load_from_file();
+            if retention_config.is_some() {
+                check_prunable_tables_valid(&mut pool.get().await?).await?;
+            }
+
+            let store =
+                PgIndexerStore::new(pool, UploadOptions::default(), indexer_metrics.clone());
+            IndexerIncrementalRestorer::new(store, indexer_metrics, restore_config, replay_config)
+                .restore(
+                    &ingestion_config,
+                    snapshot_config,
+                    retention_config,
+                    CancellationToken::new(),
+                )
+                .await?;
+        }
     }
 
     Ok(())
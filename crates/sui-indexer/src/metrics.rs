@@ -102,6 +102,7 @@ pub struct IndexerMetrics {
     pub indexing_module_resolver_in_mem_hit: IntCounter,
     pub indexing_package_resolver_in_mem_hit: IntCounter,
     pub indexing_packages_latency: Histogram,
+    pub indexing_custom_tables_latency: Histogram,
     pub checkpoint_objects_index_latency: Histogram,
     pub checkpoint_db_commit_latency: Histogram,
     pub checkpoint_db_commit_latency_step_1: Histogram,
@@ -127,6 +128,8 @@ pub struct IndexerMetrics {
     pub checkpoint_db_commit_latency_tx_indices_chunks: Histogram,
     pub checkpoint_db_commit_latency_checkpoints: Histogram,
     pub checkpoint_db_commit_latency_epoch: Histogram,
+    pub checkpoint_db_commit_latency_epoch_metrics: Histogram,
+    pub checkpoint_db_commit_latency_custom_tables: Histogram,
     pub checkpoint_db_commit_latency_watermarks: Histogram,
     pub thousand_transaction_avg_db_commit_latency: Histogram,
     pub object_db_commit_latency: Histogram,
@@ -356,6 +359,13 @@ impl IndexerMetrics {
                 registry,
             )
             .unwrap(),
+            indexing_custom_tables_latency: register_histogram_with_registry!(
+                "indexing_custom_tables_latency",
+                "Time spent in projecting objects into custom tables",
+                DATA_INGESTION_LATENCY_SEC_BUCKETS.to_vec(),
+                registry,
+            )
+            .unwrap(),
             indexing_get_object_in_mem_hit: register_int_counter_with_registry!(
                 "indexing_get_object_in_mem_hit",
                 "Total number get object hit in mem",
@@ -551,6 +561,20 @@ impl IndexerMetrics {
                 registry,
             )
             .unwrap(),
+            checkpoint_db_commit_latency_epoch_metrics: register_histogram_with_registry!(
+                "checkpoint_db_commit_latency_epoch_metrics",
+                "Time spent committing per-epoch package and sender metrics",
+                DATA_INGESTION_LATENCY_SEC_BUCKETS.to_vec(),
+                registry,
+            )
+            .unwrap(),
+            checkpoint_db_commit_latency_custom_tables: register_histogram_with_registry!(
+                "checkpoint_db_commit_latency_custom_tables",
+                "Time spent committing custom table rows",
+                DATA_INGESTION_LATENCY_SEC_BUCKETS.to_vec(),
+                registry,
+            )
+            .unwrap(),
             checkpoint_db_commit_latency_watermarks: register_histogram_with_registry!(
                 "checkpoint_db_commit_latency_watermarks",
                 "Time spent committing watermarks",
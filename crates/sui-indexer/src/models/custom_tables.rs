@@ -0,0 +1,36 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use diesel::prelude::*;
+
+use crate::errors::IndexerError;
+use crate::handlers::custom_tables::CustomTable;
+use crate::schema::custom_tables;
+
+/// Represents a row in the `custom_tables` registry, describing one custom table.
+#[derive(Queryable, Insertable, Selectable, Clone, Debug, PartialEq, Eq)]
+#[diesel(table_name = custom_tables, primary_key(name))]
+pub struct StoredCustomTable {
+    /// The name the table is queried by. The table itself is `custom_<name>`.
+    pub name: String,
+    /// The Move struct type whose objects are projected into the table.
+    pub struct_type: String,
+    /// Whether the table keeps a row per object version.
+    pub history: bool,
+    /// The table's declaration from the indexer's config, as JSON.
+    pub definition: String,
+}
+
+impl TryFrom<&CustomTable> for StoredCustomTable {
+    type Error = IndexerError;
+
+    fn try_from(table: &CustomTable) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: table.name.clone(),
+            struct_type: table.type_.to_canonical_string(/* with_prefix */ true),
+            history: table.history,
+            definition: serde_json::to_string(&table.config)
+                .map_err(|e| IndexerError::SerdeError(e.to_string()))?,
+        })
+    }
+}
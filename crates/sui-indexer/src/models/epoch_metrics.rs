@@ -0,0 +1,37 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::schema::{epoch_package_metrics, epoch_sender_metrics};
+use diesel::prelude::*;
+
+/// Represents a row in the `epoch_package_metrics` table, or the contribution of a single
+/// checkpoint to such a row, before it is folded into the running total.
+#[derive(Queryable, Insertable, Selectable, Debug, Clone, Default)]
+#[diesel(table_name = epoch_package_metrics)]
+pub struct StoredEpochPackageMetrics {
+    pub epoch: i64,
+    pub package: Vec<u8>,
+    /// Number of Move calls to functions in this package.
+    pub move_calls: i64,
+    /// Number of transactions that called at least one function in this package.
+    pub transactions: i64,
+    /// Net gas spent by transactions that called this package.
+    pub gas_spent: i64,
+    /// The last checkpoint folded into this row.
+    pub checkpoint_sequence_number: i64,
+}
+
+/// Represents a row in the `epoch_sender_metrics` table, or the contribution of a single
+/// checkpoint to such a row, before it is folded into the running total.
+#[derive(Queryable, Insertable, Selectable, Debug, Clone, Default)]
+#[diesel(table_name = epoch_sender_metrics)]
+pub struct StoredEpochSenderMetrics {
+    pub epoch: i64,
+    pub sender: Vec<u8>,
+    /// Number of transactions sent by this address.
+    pub transactions: i64,
+    /// Net gas spent by transactions sent by this address.
+    pub gas_spent: i64,
+    /// The last checkpoint folded into this row.
+    pub checkpoint_sequence_number: i64,
+}
@@ -2,8 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod checkpoints;
+pub mod custom_tables;
 pub mod display;
 pub mod epoch;
+pub mod epoch_metrics;
 pub mod event_indices;
 pub mod events;
 pub mod obj_indices;
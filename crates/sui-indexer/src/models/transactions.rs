@@ -1,6 +1,7 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::{BTreeMap, BTreeSet};
 use std::sync::Arc;
 
 use diesel::prelude::*;
@@ -8,15 +9,20 @@ use diesel::prelude::*;
 use move_core_types::annotated_value::{MoveDatatypeLayout, MoveTypeLayout};
 use move_core_types::language_storage::TypeTag;
 use sui_json_rpc_types::{
-    BalanceChange, ObjectChange, SuiEvent, SuiTransactionBlock, SuiTransactionBlockEffects,
+    ActivityBalanceChange, ActivityObjectTransfer, AddressActivity, BalanceChange, ObjectChange,
+    ObjectTransferDirection, SuiEvent, SuiTransactionBlock, SuiTransactionBlockEffects,
     SuiTransactionBlockEvents, SuiTransactionBlockResponse, SuiTransactionBlockResponseOptions,
 };
 use sui_package_resolver::{PackageStore, Resolver};
+use sui_types::base_types::SuiAddress;
 use sui_types::digests::TransactionDigest;
 use sui_types::effects::TransactionEffects;
+use sui_types::effects::TransactionEffectsAPI;
 use sui_types::effects::TransactionEvents;
 use sui_types::event::Event;
+use sui_types::object::Owner;
 use sui_types::transaction::SenderSignedData;
+use sui_types::transaction::TransactionDataAPI;
 
 use crate::errors::IndexerError;
 use crate::schema::transactions;
@@ -252,6 +258,138 @@ impl StoredTransaction {
             raw_effects: self.raw_effects,
         })
     }
+
+    /// Summarise this transaction from the point of view of `address`: its net balance change per
+    /// coin type, the objects that were created for or transferred into or out of its ownership,
+    /// and the other addresses that took part.
+    pub fn try_into_address_activity(&self, address: SuiAddress) -> IndexerResult<AddressActivity> {
+        let digest =
+            TransactionDigest::try_from(self.transaction_digest.as_slice()).map_err(|e| {
+                IndexerError::PersistentStorageDataCorruptionError(format!(
+                    "Can't convert {:?} as tx_digest. Error: {e}",
+                    self.transaction_digest
+                ))
+            })?;
+
+        let sender = self
+            .try_into_sender_signed_data()?
+            .transaction_data()
+            .sender();
+
+        let effects: TransactionEffects = bcs::from_bytes(&self.raw_effects).map_err(|e| {
+            IndexerError::PersistentStorageDataCorruptionError(format!(
+                "Can't convert raw_effects of {} into TransactionEffects. Error: {e}",
+                self.tx_sequence_number
+            ))
+        })?;
+
+        let mut counterparties = BTreeSet::new();
+        if sender != address {
+            counterparties.insert(sender);
+        }
+
+        let mut balances: BTreeMap<TypeTag, i128> = BTreeMap::new();
+        for balance_change in &self.balance_changes {
+            let Some(balance_change) = balance_change else {
+                return Err(IndexerError::PersistentStorageDataCorruptionError(format!(
+                    "balance_change should not be null, tx_digest={:?}",
+                    digest
+                )));
+            };
+
+            let balance_change: BalanceChange = bcs::from_bytes(balance_change).map_err(|e| {
+                IndexerError::PersistentStorageDataCorruptionError(format!(
+                    "Can't convert balance_change bytes into BalanceChange. tx_digest={:?} Error: {e}",
+                    digest
+                ))
+            })?;
+
+            match balance_change.owner {
+                Owner::AddressOwner(owner) if owner == address => {
+                    *balances.entry(balance_change.coin_type).or_default() += balance_change.amount;
+                }
+                Owner::AddressOwner(owner) => {
+                    counterparties.insert(owner);
+                }
+                _ => {}
+            }
+        }
+
+        let mut object_transfers = vec![];
+        for object_change in &self.object_changes {
+            let Some(object_change) = object_change else {
+                return Err(IndexerError::PersistentStorageDataCorruptionError(format!(
+                    "object_change should not be null, tx_digest={:?}",
+                    digest
+                )));
+            };
+
+            let object_change: IndexedObjectChange = bcs::from_bytes(object_change).map_err(|e| {
+                IndexerError::PersistentStorageDataCorruptionError(format!(
+                    "Can't convert object_change bytes into IndexedObjectChange. tx_digest={:?} Error: {e}",
+                    digest
+                ))
+            })?;
+
+            let (owner, object_type, object_id, version) = match object_change {
+                IndexedObjectChange::Created {
+                    owner,
+                    object_type,
+                    object_id,
+                    version,
+                    ..
+                }
+                | IndexedObjectChange::Transferred {
+                    recipient: owner,
+                    object_type,
+                    object_id,
+                    version,
+                    ..
+                } => (owner, object_type, object_id, version),
+                _ => continue,
+            };
+
+            let Owner::AddressOwner(owner) = owner else {
+                continue;
+            };
+
+            let (direction, counterparty) = if owner == address {
+                (
+                    ObjectTransferDirection::In,
+                    (sender != address).then_some(sender),
+                )
+            } else {
+                counterparties.insert(owner);
+                if sender != address {
+                    continue;
+                }
+                (ObjectTransferDirection::Out, Some(owner))
+            };
+
+            object_transfers.push(ActivityObjectTransfer {
+                object_id,
+                version,
+                object_type,
+                direction,
+                counterparty,
+            });
+        }
+
+        Ok(AddressActivity {
+            digest,
+            checkpoint: self.checkpoint_sequence_number as u64,
+            timestamp_ms: self.timestamp_ms as u64,
+            sender,
+            success: effects.status().is_ok(),
+            counterparties: counterparties.into_iter().collect(),
+            balance_changes: balances
+                .into_iter()
+                .map(|(coin_type, amount)| ActivityBalanceChange { coin_type, amount })
+                .collect(),
+            object_transfers,
+        })
+    }
+
     fn try_into_sender_signed_data(&self) -> IndexerResult<SenderSignedData> {
         let sender_signed_data: SenderSignedData =
             bcs::from_bytes(&self.raw_transaction).map_err(|e| {
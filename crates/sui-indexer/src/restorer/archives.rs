@@ -5,6 +5,9 @@ use std::num::NonZeroUsize;
 
 use prometheus::Registry;
 use sui_types::digests::CheckpointDigest;
+use sui_types::messages_checkpoint::{
+    CertifiedCheckpointSummary, CheckpointCommitment, ECMHLiveObjectSetDigest,
+};
 use tracing::info;
 
 use sui_archival::reader::{ArchiveReader, ArchiveReaderMetrics};
@@ -18,6 +21,28 @@ use crate::types::IndexerResult;
 pub struct RestoreCheckpointInfo {
     pub next_checkpoint_after_epoch: u64,
     pub chain_identifier: CheckpointDigest,
+    /// The last checkpoint of the restored epoch, which commits to the epoch's live object set.
+    pub last_checkpoint: CertifiedCheckpointSummary,
+}
+
+impl RestoreCheckpointInfo {
+    /// The root digest of the live object set at the end of the restored epoch.
+    pub fn live_object_set_digest(&self) -> IndexerResult<ECMHLiveObjectSetDigest> {
+        self.last_checkpoint
+            .end_of_epoch_data
+            .iter()
+            .flat_map(|data| &data.epoch_commitments)
+            .map(|commitment| match commitment {
+                CheckpointCommitment::ECMHLiveObjectSetDigest(digest) => digest.clone(),
+            })
+            .next()
+            .ok_or_else(|| {
+                IndexerError::ArchiveReaderError(format!(
+                    "Checkpoint {} has no live object set commitment",
+                    self.last_checkpoint.sequence_number
+                ))
+            })
+    }
 }
 
 pub async fn read_restore_checkpoint_info(
@@ -45,16 +70,30 @@ pub async fn read_restore_checkpoint_info(
         "Read from archives: next checkpoint sequence after epoch {} is: {}",
         epoch, next_checkpoint_after_epoch
     );
+    assert!(next_checkpoint_after_epoch > 0);
+    let last_checkpoint_of_epoch = next_checkpoint_after_epoch - 1;
     let cp_summaries = archive_reader
-        .get_summaries_for_list_no_verify(vec![0])
+        .get_summaries_for_list_no_verify(vec![0, last_checkpoint_of_epoch])
         .await
         .map_err(|e| IndexerError::ArchiveReaderError(format!("Failed to get summaries: {}", e)))?;
     let first_cp = cp_summaries
-        .first()
+        .iter()
+        .find(|cp| cp.sequence_number == 0)
         .ok_or_else(|| IndexerError::ArchiveReaderError("No checkpoint found".to_string()))?;
     let chain_identifier = *first_cp.digest();
+    let last_checkpoint = cp_summaries
+        .iter()
+        .find(|cp| cp.sequence_number == last_checkpoint_of_epoch)
+        .cloned()
+        .ok_or_else(|| {
+            IndexerError::ArchiveReaderError(format!(
+                "Last checkpoint {} of epoch {} not found",
+                last_checkpoint_of_epoch, epoch
+            ))
+        })?;
     Ok(RestoreCheckpointInfo {
         next_checkpoint_after_epoch,
         chain_identifier,
+        last_checkpoint,
     })
 }
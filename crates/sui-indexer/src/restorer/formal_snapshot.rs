@@ -7,6 +7,8 @@ use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use std::sync::Arc;
 
+use fastcrypto::hash::MultisetHash;
+
 use futures::future::{AbortHandle, AbortRegistration, Abortable};
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 use object_store::path::Path;
@@ -16,16 +18,22 @@ use tracing::info;
 
 use sui_config::object_storage_config::{ObjectStoreConfig, ObjectStoreType};
 use sui_core::authority::authority_store_tables::LiveObject;
+use sui_core::state_accumulator::StateAccumulator;
 use sui_snapshot::reader::{download_bytes, LiveObjectIter, StateSnapshotReaderV1};
 use sui_snapshot::FileMetadata;
 use sui_storage::object_store::util::get;
 use sui_storage::object_store::ObjectStoreGetExt;
 use sui_types::accumulator::Accumulator;
+use sui_types::messages_checkpoint::ECMHLiveObjectSetDigest;
 
 use crate::config::RestoreConfig;
 use crate::errors::IndexerError;
-use crate::handlers::TransactionObjectChangesToCommit;
+use crate::handlers::{
+    CommitterTables, CommitterWatermark, ObjectsSnapshotHandlerTables,
+    TransactionObjectChangesToCommit,
+};
 use crate::restorer::archives::{read_restore_checkpoint_info, RestoreCheckpointInfo};
+use crate::restorer::progress::{RestoreProgress, RestoreStage};
 use crate::store::{indexer_store::IndexerStore, PgIndexerStore};
 use crate::types::{IndexedCheckpoint, IndexedObject};
 
@@ -33,10 +41,23 @@ pub type DigestByBucketAndPartition = BTreeMap<u32, BTreeMap<u32, [u8; 32]>>;
 pub type SnapshotChecksums = (DigestByBucketAndPartition, Accumulator);
 pub type Sha3DigestType = Arc<Mutex<BTreeMap<u32, BTreeMap<u32, [u8; 32]>>>>;
 
+/// The objects read from one object file of the formal snapshot.
+struct RestoredObjectFile {
+    /// The file's live objects that are persisted, i.e. all of them except wrapped objects.
+    move_objects: Vec<IndexedObject>,
+    /// Accumulation of every live object in the file, wrapped objects included, hashed the same way
+    /// as the live object set root.
+    accumulator: Accumulator,
+    /// Number of live objects in the file, wrapped objects included.
+    live_objects: u64,
+}
+
 pub struct IndexerFormalSnapshotRestorer {
     store: PgIndexerStore,
     reader: StateSnapshotReaderV1,
     restore_config: RestoreConfig,
+    progress_dir: PathBuf,
+    progress: Arc<Mutex<RestoreProgress>>,
 }
 
 impl IndexerFormalSnapshotRestorer {
@@ -54,6 +75,7 @@ impl IndexerFormalSnapshotRestorer {
         };
 
         let base_path = PathBuf::from(restore_config.snapshot_download_dir.clone());
+        let progress = RestoreProgress::load(&base_path, restore_config.start_epoch)?;
         let snapshot_dir = base_path.join("snapshot");
         if snapshot_dir.exists() {
             fs::remove_dir_all(snapshot_dir.clone()).unwrap();
@@ -92,32 +114,98 @@ impl IndexerFormalSnapshotRestorer {
             store,
             reader,
             restore_config: restore_config.clone(),
+            progress_dir: base_path,
+            progress: Arc::new(Mutex::new(progress)),
         })
     }
 
+    async fn stage(&self) -> RestoreStage {
+        self.progress.lock().await.stage
+    }
+
+    /// Records that the restore has reached `stage` and persists the progress.
+    async fn set_stage(&self, stage: RestoreStage) -> Result<(), IndexerError> {
+        let mut progress = self.progress.lock().await;
+        progress.stage = stage;
+        progress.save(&self.progress_dir)
+    }
+
+    /// Restores the live object set, display table and watermarks of the epoch from its formal
+    /// snapshot. Stages already completed by an interrupted restore, and object files already
+    /// persisted, are skipped.
     pub async fn restore(&mut self) -> Result<(), IndexerError> {
-        let (sha3_digests, num_part_files) = self.reader.compute_checksum().await?;
-        let (_abort_handle, abort_registration) = AbortHandle::new_pair();
-        let (input_files, epoch_dir, remote_object_store, _concurrency) =
-            self.reader.export_metadata().await?;
-        let owned_input_files: Vec<(u32, (u32, FileMetadata))> = input_files
-            .into_iter()
-            .map(|(bucket, (part_num, metadata))| (*bucket, (part_num, metadata.clone())))
-            .collect();
-        self.restore_move_objects(
-            abort_registration,
-            owned_input_files,
-            epoch_dir,
-            remote_object_store,
-            sha3_digests,
-            num_part_files,
+        let restore_checkpoint_info = read_restore_checkpoint_info(
+            Some(self.restore_config.gcs_archive_bucket.clone()),
+            self.restore_config.start_epoch,
         )
         .await?;
-        info!("Finished restoring move objects");
-        self.restore_display_table().await?;
-        info!("Finished restoring display table");
-        self.restore_cp_watermark_and_chain_id().await?;
-        info!("Finished restoring checkpoint info");
+
+        if self.stage().await <= RestoreStage::SnapshotObjects {
+            let (sha3_digests, num_part_files) = self.reader.compute_checksum().await?;
+            let (_abort_handle, abort_registration) = AbortHandle::new_pair();
+            let (input_files, epoch_dir, remote_object_store, _concurrency) =
+                self.reader.export_metadata().await?;
+            let restored_files = self.progress.lock().await.restored_files.clone();
+            let owned_input_files: Vec<(u32, (u32, FileMetadata))> = input_files
+                .into_iter()
+                .filter(|(bucket, (part_num, _))| !restored_files.contains(&(**bucket, *part_num)))
+                .map(|(bucket, (part_num, metadata))| (*bucket, (part_num, metadata.clone())))
+                .collect();
+            info!(
+                "Restoring {} move object files, {} already restored",
+                owned_input_files.len(),
+                restored_files.len()
+            );
+            self.restore_move_objects(
+                abort_registration,
+                owned_input_files,
+                epoch_dir,
+                remote_object_store,
+                sha3_digests,
+                num_part_files,
+            )
+            .await?;
+            info!("Finished restoring move objects");
+            self.verify_live_objects(&restore_checkpoint_info).await?;
+            info!("Verified restored move objects against the live object set root");
+            self.set_stage(RestoreStage::Display).await?;
+        }
+
+        if self.stage().await <= RestoreStage::Display {
+            self.restore_display_table().await?;
+            info!("Finished restoring display table");
+            self.set_stage(RestoreStage::Watermarks).await?;
+        }
+
+        if self.stage().await <= RestoreStage::Watermarks {
+            self.restore_cp_watermark_and_chain_id(&restore_checkpoint_info)
+                .await?;
+            info!("Finished restoring checkpoint info");
+            self.set_stage(RestoreStage::CheckpointReplay).await?;
+        }
+        Ok(())
+    }
+
+    /// Checks the restored objects against the root of the live object set committed to by the
+    /// last checkpoint of the epoch, and the number of rows in the `objects` table against the
+    /// number of objects restored.
+    async fn verify_live_objects(
+        &self,
+        restore_checkpoint_info: &RestoreCheckpointInfo,
+    ) -> Result<(), IndexerError> {
+        let progress = self.progress.lock().await.clone();
+        check_live_object_set_root(
+            &restore_checkpoint_info.live_object_set_digest()?,
+            &progress,
+        )?;
+
+        let objects_count = self.store.get_objects_count().await?;
+        if objects_count != progress.restored_objects {
+            return Err(IndexerError::RestoreError(format!(
+                "Objects table has {} rows, expected {} restored objects",
+                objects_count, progress.restored_objects
+            )));
+        }
         Ok(())
     }
 
@@ -154,6 +242,8 @@ impl IndexerFormalSnapshotRestorer {
                     let store_clone = self.store.clone();
                     let bar_clone = move_object_progress_bar.clone();
                     let restore_config = self.restore_config.clone();
+                    let progress = self.progress.clone();
+                    let progress_dir = self.progress_dir.clone();
 
                     let restore_task = task::spawn(async move {
                         let _permit = sema_limit_clone.acquire().await.unwrap();
@@ -172,22 +262,14 @@ impl IndexerFormalSnapshotRestorer {
                             "Finished downloading move object file {:?}",
                             object_file_path
                         );
-                        let mut move_objects = vec![];
-                        let _result: Result<(), anyhow::Error> =
-                            LiveObjectIter::new(&file_metadata, bytes.clone()).map(|obj_iter| {
-                                for object in obj_iter {
-                                    match object {
-                                        LiveObject::Normal(obj) => {
-                                            // TODO: placeholder values for df_info and checkpoint_seq_num,
-                                            // will clean it up when the column cleanup is done.
-                                            let indexed_object =
-                                                IndexedObject::from_object(0, obj, None);
-                                            move_objects.push(indexed_object);
-                                        }
-                                        LiveObject::Wrapped(_) => {}
-                                    }
-                                }
-                            });
+                        let RestoredObjectFile {
+                            move_objects,
+                            accumulator,
+                            live_objects,
+                        } = RestoredObjectFile::new(LiveObjectIter::new(
+                            &file_metadata,
+                            bytes.clone(),
+                        )?);
 
                         let live_obj_cnt = move_objects.len();
                         let object_changes = TransactionObjectChangesToCommit {
@@ -216,6 +298,18 @@ impl IndexerFormalSnapshotRestorer {
                             .await
                             .expect("Failed to persist objects snapshot");
 
+                        {
+                            let mut progress = progress.lock().await;
+                            progress.record_file(
+                                bucket,
+                                part_num,
+                                &accumulator,
+                                live_objects,
+                                live_obj_cnt as u64,
+                            );
+                            progress.save(&progress_dir)?;
+                        }
+
                         bar_clone.inc(1);
                         bar_clone.set_message(format!(
                             "Restored {} live move objects from {}",
@@ -257,27 +351,144 @@ impl IndexerFormalSnapshotRestorer {
         Ok(())
     }
 
-    async fn restore_cp_watermark_and_chain_id(&self) -> Result<(), IndexerError> {
-        let restore_checkpoint_info = read_restore_checkpoint_info(
-            Some(self.restore_config.gcs_archive_bucket.clone()),
-            self.restore_config.start_epoch,
-        )
-        .await?;
+    async fn restore_cp_watermark_and_chain_id(
+        &self,
+        restore_checkpoint_info: &RestoreCheckpointInfo,
+    ) -> Result<(), IndexerError> {
         let RestoreCheckpointInfo {
             next_checkpoint_after_epoch,
             chain_identifier,
+            last_checkpoint,
         } = restore_checkpoint_info;
         self.store
             .persist_chain_identifier(chain_identifier.into_inner().to_vec())
             .await?;
-        assert!(next_checkpoint_after_epoch > 0);
+        assert!(*next_checkpoint_after_epoch > 0);
         // FIXME: This is a temporary hack to add a checkpoint watermark.
         // Once we have proper watermark tables, we should remove the following code.
         let last_cp = IndexedCheckpoint {
             sequence_number: next_checkpoint_after_epoch - 1,
+            checkpoint_digest: *last_checkpoint.digest(),
+            epoch: last_checkpoint.epoch,
+            network_total_transactions: last_checkpoint.network_total_transactions,
+            timestamp_ms: last_checkpoint.timestamp_ms,
             ..Default::default()
         };
+        let watermark = CommitterWatermark::from(&last_cp);
         self.store.persist_checkpoints(vec![last_cp]).await?;
+
+        // Both the primary and the objects snapshot pipelines resume from the end of the epoch, as
+        // `objects` and `objects_snapshot` both hold the epoch's live object set.
+        self.store
+            .update_watermarks_upper_bound::<CommitterTables>(watermark)
+            .await?;
+        self.store
+            .update_watermarks_upper_bound::<ObjectsSnapshotHandlerTables>(watermark)
+            .await?;
         Ok(())
     }
 }
+
+impl RestoredObjectFile {
+    fn new(objects: impl IntoIterator<Item = LiveObject>) -> Self {
+        let mut move_objects = vec![];
+        let mut accumulator = Accumulator::default();
+        let mut live_objects = 0u64;
+        for object in objects {
+            StateAccumulator::accumulate_live_object(&mut accumulator, &object);
+            live_objects += 1;
+            match object {
+                LiveObject::Normal(obj) => {
+                    // TODO: placeholder values for df_info and checkpoint_seq_num,
+                    // will clean it up when the column cleanup is done.
+                    let indexed_object = IndexedObject::from_object(0, obj, None);
+                    move_objects.push(indexed_object);
+                }
+                LiveObject::Wrapped(_) => {}
+            }
+        }
+
+        Self {
+            move_objects,
+            accumulator,
+            live_objects,
+        }
+    }
+}
+
+/// Checks the accumulation of every restored live object against the live object set root
+/// committed to by the last checkpoint of the epoch.
+fn check_live_object_set_root(
+    expected_digest: &ECMHLiveObjectSetDigest,
+    progress: &RestoreProgress,
+) -> Result<(), IndexerError> {
+    let restored_digest = ECMHLiveObjectSetDigest::from(progress.accumulator.digest());
+    if *expected_digest != restored_digest {
+        return Err(IndexerError::RestoreError(format!(
+            "Live object set root {} of epoch {} does not match root {} of {} restored objects",
+            expected_digest.digest, progress.epoch, restored_digest.digest, progress.live_objects,
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use sui_core::state_accumulator::WrappedObject;
+    use sui_types::base_types::{ObjectID, SequenceNumber, SuiAddress};
+    use sui_types::object::Object;
+    use sui_types::storage::ObjectKey;
+
+    use super::*;
+
+    fn coin(value: u64) -> Object {
+        Object::with_id_owner_gas_for_testing(
+            ObjectID::random(),
+            SuiAddress::random_for_testing_only(),
+            value,
+        )
+    }
+
+    #[test]
+    fn restored_files_match_live_object_set_root() {
+        let (a, b) = (coin(1), coin(2));
+        let wrapped = ObjectKey(ObjectID::random(), SequenceNumber::from_u64(7));
+
+        // The root as computed by a fullnode: object digests, and the BCS of `WrappedObject` for
+        // wrapped objects.
+        let mut expected = Accumulator::default();
+        expected.insert(a.compute_object_reference().2);
+        expected.insert(b.compute_object_reference().2);
+        expected.insert(bcs::to_bytes(&WrappedObject::new(wrapped.0, wrapped.1)).unwrap());
+        let expected = ECMHLiveObjectSetDigest::from(expected.digest());
+
+        // The objects are spread over two object files, one of which is restored twice, as it
+        // would be if the restore was interrupted and resumed.
+        let first = RestoredObjectFile::new([LiveObject::Normal(a), LiveObject::Wrapped(wrapped)]);
+        let second = RestoredObjectFile::new([LiveObject::Normal(b)]);
+
+        // Wrapped objects count towards the root, but are not persisted.
+        assert_eq!(first.live_objects, 2);
+        assert_eq!(first.move_objects.len(), 1);
+
+        let mut progress = RestoreProgress::default();
+        for (part, file) in [(0, &first), (0, &first), (1, &second)] {
+            progress.record_file(
+                0,
+                part,
+                &file.accumulator,
+                file.live_objects,
+                file.move_objects.len() as u64,
+            );
+        }
+
+        assert_eq!(progress.live_objects, 3);
+        assert_eq!(progress.restored_objects, 2);
+        check_live_object_set_root(&expected, &progress).unwrap();
+
+        // A missing object file is caught by the root check.
+        let mut partial = RestoreProgress::default();
+        partial.record_file(0, 0, &first.accumulator, first.live_objects, 1);
+        check_live_object_set_root(&expected, &partial).unwrap_err();
+    }
+}
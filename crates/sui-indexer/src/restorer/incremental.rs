@@ -0,0 +1,187 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use tokio_util::sync::CancellationToken;
+use tracing::info;
+
+use crate::config::{
+    CheckpointReplayConfig, IngestionConfig, IngestionSources, RestoreConfig, RetentionConfig,
+    SnapshotLagConfig,
+};
+use crate::errors::IndexerError;
+use crate::indexer::Indexer;
+use crate::metrics::IndexerMetrics;
+use crate::restorer::formal_snapshot::IndexerFormalSnapshotRestorer;
+use crate::restorer::progress::{RestoreProgress, RestoreStage};
+use crate::store::{IndexerStore, PgIndexerStore};
+
+const REPLAY_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Restores the database end to end: the live object set from the formal snapshot of an epoch,
+/// then every checkpoint from the end of that epoch up to a target from a remote store of full
+/// checkpoints, before handing off to live ingestion.
+pub struct IndexerIncrementalRestorer {
+    store: PgIndexerStore,
+    metrics: IndexerMetrics,
+    restore_config: RestoreConfig,
+    replay_config: CheckpointReplayConfig,
+}
+
+impl IndexerIncrementalRestorer {
+    pub fn new(
+        store: PgIndexerStore,
+        metrics: IndexerMetrics,
+        restore_config: RestoreConfig,
+        replay_config: CheckpointReplayConfig,
+    ) -> Self {
+        Self {
+            store,
+            metrics,
+            restore_config,
+            replay_config,
+        }
+    }
+
+    pub async fn restore(
+        &self,
+        ingestion_config: &IngestionConfig,
+        snapshot_config: SnapshotLagConfig,
+        retention_config: Option<RetentionConfig>,
+        cancel: CancellationToken,
+    ) -> Result<(), IndexerError> {
+        let progress_dir = PathBuf::from(&self.restore_config.snapshot_download_dir);
+        let epoch = self.restore_config.start_epoch;
+
+        if RestoreProgress::load(&progress_dir, epoch)?.stage < RestoreStage::CheckpointReplay {
+            let mut formal_restorer =
+                IndexerFormalSnapshotRestorer::new(self.store.clone(), self.restore_config.clone())
+                    .await?;
+            formal_restorer.restore().await?;
+        }
+
+        let mut progress = RestoreProgress::load(&progress_dir, epoch)?;
+        if progress.stage == RestoreStage::CheckpointReplay {
+            self.replay_checkpoints(ingestion_config, snapshot_config.clone())
+                .await?;
+            progress.stage = RestoreStage::Done;
+            progress.save(&progress_dir)?;
+        }
+        self.check_watermarks().await?;
+
+        if self.replay_config.no_live_ingestion {
+            info!("Restore complete, not starting live ingestion");
+            return Ok(());
+        }
+
+        info!("Restore complete, handing off to live ingestion");
+        Indexer::start_writer(
+            ingestion_config,
+            self.store.clone(),
+            self.metrics.clone(),
+            snapshot_config,
+            retention_config,
+            cancel,
+        )
+        .await
+    }
+
+    /// Indexes checkpoints from the remote checkpoint store, starting after the restored epoch or wherever an
+    /// interrupted replay stopped, until the target checkpoint has been committed. The writer is
+    /// stopped once the target is reached, so a few checkpoints past it may also be committed.
+    async fn replay_checkpoints(
+        &self,
+        ingestion_config: &IngestionConfig,
+        snapshot_config: SnapshotLagConfig,
+    ) -> Result<(), IndexerError> {
+        let target = self.replay_config.target_checkpoint;
+        if self.latest_checkpoint().await? >= Some(target) {
+            return Ok(());
+        }
+
+        // Custom tables are kept up to date during the replay, so that they are complete when live
+        // ingestion takes over.
+        let ingestion_config = IngestionConfig {
+            sources: IngestionSources {
+                remote_store_url: Some(self.replay_config.checkpoint_remote_store_url.clone()),
+                ..Default::default()
+            },
+            custom_tables_config_path: ingestion_config.custom_tables_config_path.clone(),
+            ..Default::default()
+        };
+
+        info!("Replaying checkpoints up to {}", target);
+        let cancel = CancellationToken::new();
+        let writer = {
+            let store = self.store.clone();
+            let metrics = self.metrics.clone();
+            let cancel = cancel.clone();
+            tokio::spawn(async move {
+                Indexer::start_writer(
+                    &ingestion_config,
+                    store,
+                    metrics,
+                    snapshot_config,
+                    None,
+                    cancel,
+                )
+                .await
+            })
+        };
+
+        loop {
+            tokio::time::sleep(REPLAY_POLL_INTERVAL).await;
+            let latest = self.latest_checkpoint().await?;
+            if latest >= Some(target) {
+                info!("Replayed checkpoints up to {:?}", latest);
+                break;
+            }
+            if writer.is_finished() {
+                break;
+            }
+        }
+
+        cancel.cancel();
+        writer.await.map_err(|e| {
+            IndexerError::RestoreError(format!("Checkpoint replay panicked: {e}"))
+        })??;
+
+        let latest = self.latest_checkpoint().await?;
+        if latest < Some(target) {
+            return Err(IndexerError::RestoreError(format!(
+                "Checkpoint replay stopped at checkpoint {:?} before target {}",
+                latest, target
+            )));
+        }
+        Ok(())
+    }
+
+    /// Live ingestion resumes each pipeline from its own watermark. The objects snapshot may lag
+    /// the primary pipeline, but can never be ahead of it.
+    async fn check_watermarks(&self) -> Result<(), IndexerError> {
+        let primary = self.latest_checkpoint().await?;
+        let objects_snapshot = self
+            .store
+            .get_latest_object_snapshot_checkpoint_sequence_number()
+            .await?;
+
+        if objects_snapshot > primary {
+            return Err(IndexerError::RestoreError(format!(
+                "Objects snapshot watermark {:?} is ahead of checkpoint watermark {:?}",
+                objects_snapshot, primary
+            )));
+        }
+
+        info!(
+            "Restored checkpoint watermark {:?}, objects snapshot watermark {:?}",
+            primary, objects_snapshot
+        );
+        Ok(())
+    }
+
+    async fn latest_checkpoint(&self) -> Result<Option<u64>, IndexerError> {
+        IndexerStore::get_latest_checkpoint_sequence_number(&self.store).await
+    }
+}
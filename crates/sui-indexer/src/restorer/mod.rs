@@ -3,3 +3,5 @@
 
 pub mod archives;
 pub mod formal_snapshot;
+pub mod incremental;
+pub mod progress;
@@ -0,0 +1,152 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use fastcrypto::hash::MultisetHash;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use sui_types::accumulator::Accumulator;
+
+use crate::errors::IndexerError;
+
+const RESTORE_PROGRESS_FILE: &str = "restore_progress.json";
+
+/// The stages of a restore, in the order they run. A restore that is interrupted picks up from
+/// the stage recorded in its progress file.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+pub enum RestoreStage {
+    #[default]
+    SnapshotObjects,
+    Display,
+    Watermarks,
+    CheckpointReplay,
+    Done,
+}
+
+/// Progress of a restore, persisted to `restore_progress.json` under the snapshot download
+/// directory after every unit of work so that the restore can resume where it left off.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct RestoreProgress {
+    pub epoch: u64,
+    pub stage: RestoreStage,
+    /// The `(bucket, part)` object files whose objects have been persisted.
+    pub restored_files: BTreeSet<(u32, u32)>,
+    /// Accumulation of the digests of every live object in `restored_files`, to be checked against
+    /// the epoch's live object set root.
+    pub accumulator: Accumulator,
+    /// Number of live objects in `restored_files`, wrapped objects included.
+    pub live_objects: u64,
+    /// Number of live objects in `restored_files` that were persisted to the `objects` table.
+    pub restored_objects: u64,
+}
+
+impl RestoreProgress {
+    /// Loads the progress of a restore of `epoch` from `dir`. A missing progress file, or one left
+    /// behind by a restore of a different epoch, starts the restore from the beginning.
+    pub fn load(dir: &Path, epoch: u64) -> Result<Self, IndexerError> {
+        let path = Self::path(dir);
+        let fresh = Self {
+            epoch,
+            ..Default::default()
+        };
+
+        if !path.exists() {
+            return Ok(fresh);
+        }
+
+        let contents = fs::read(&path).map_err(|e| {
+            IndexerError::RestoreError(format!("Failed to read {}: {e}", path.display()))
+        })?;
+        let progress: Self = serde_json::from_slice(&contents).map_err(|e| {
+            IndexerError::RestoreError(format!("Failed to parse {}: {e}", path.display()))
+        })?;
+
+        if progress.epoch != epoch {
+            info!(
+                "Discarding restore progress for epoch {}, restoring epoch {}",
+                progress.epoch, epoch
+            );
+            return Ok(fresh);
+        }
+
+        info!(
+            "Resuming restore of epoch {} at stage {:?}, {} object files already restored",
+            epoch,
+            progress.stage,
+            progress.restored_files.len()
+        );
+        Ok(progress)
+    }
+
+    /// Atomically replaces the progress file in `dir`.
+    pub fn save(&self, dir: &Path) -> Result<(), IndexerError> {
+        let path = Self::path(dir);
+        let tmp = path.with_extension("json.tmp");
+        let contents = serde_json::to_vec(self).map_err(|e| {
+            IndexerError::RestoreError(format!("Failed to serialize restore progress: {e}"))
+        })?;
+        fs::write(&tmp, contents)
+            .and_then(|_| fs::rename(&tmp, &path))
+            .map_err(|e| {
+                IndexerError::RestoreError(format!("Failed to write {}: {e}", path.display()))
+            })
+    }
+
+    /// Records that the objects of the object file `(bucket, part)` have been persisted.
+    pub fn record_file(
+        &mut self,
+        bucket: u32,
+        part: u32,
+        accumulator: &Accumulator,
+        live_objects: u64,
+        restored_objects: u64,
+    ) {
+        if self.restored_files.insert((bucket, part)) {
+            self.accumulator.union(accumulator);
+            self.live_objects += live_objects;
+            self.restored_objects += restored_objects;
+        }
+    }
+
+    fn path(dir: &Path) -> PathBuf {
+        dir.join(RESTORE_PROGRESS_FILE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sui_types::base_types::ObjectDigest;
+
+    #[test]
+    fn progress_roundtrip_and_resume() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let mut accumulator = Accumulator::default();
+        accumulator.insert(ObjectDigest::random());
+
+        let mut progress = RestoreProgress::load(dir.path(), 7).unwrap();
+        assert_eq!(progress.stage, RestoreStage::SnapshotObjects);
+        progress.record_file(0, 1, &accumulator, 3, 2);
+        // Recording the same file twice must not double count it.
+        progress.record_file(0, 1, &accumulator, 3, 2);
+        progress.stage = RestoreStage::Display;
+        progress.save(dir.path()).unwrap();
+
+        let resumed = RestoreProgress::load(dir.path(), 7).unwrap();
+        assert_eq!(resumed.stage, RestoreStage::Display);
+        assert_eq!(resumed.restored_files, BTreeSet::from([(0, 1)]));
+        assert_eq!(resumed.accumulator, accumulator);
+        assert_eq!(resumed.live_objects, 3);
+        assert_eq!(resumed.restored_objects, 2);
+
+        // Progress of another epoch is discarded.
+        let other = RestoreProgress::load(dir.path(), 8).unwrap();
+        assert_eq!(other.stage, RestoreStage::SnapshotObjects);
+        assert!(other.restored_files.is_empty());
+    }
+}
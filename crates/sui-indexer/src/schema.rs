@@ -31,6 +31,15 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    custom_tables (name) {
+        name -> Text,
+        struct_type -> Text,
+        history -> Bool,
+        definition -> Text,
+    }
+}
+
 diesel::table! {
     display (object_type) {
         object_type -> Text,
@@ -40,6 +49,27 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    epoch_package_metrics (epoch, package) {
+        epoch -> Int8,
+        package -> Bytea,
+        move_calls -> Int8,
+        transactions -> Int8,
+        gas_spent -> Int8,
+        checkpoint_sequence_number -> Int8,
+    }
+}
+
+diesel::table! {
+    epoch_sender_metrics (epoch, sender) {
+        epoch -> Int8,
+        sender -> Bytea,
+        transactions -> Int8,
+        gas_spent -> Int8,
+        checkpoint_sequence_number -> Int8,
+    }
+}
+
 diesel::table! {
     epochs (epoch) {
         epoch -> Int8,
@@ -384,7 +414,10 @@ diesel::table! {
 diesel::allow_tables_to_appear_in_same_query!(
     chain_identifier,
     checkpoints,
+    custom_tables,
     display,
+    epoch_package_metrics,
+    epoch_sender_metrics,
     epochs,
     event_emit_module,
     event_emit_package,
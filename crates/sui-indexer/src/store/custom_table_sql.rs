@@ -0,0 +1,576 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! SQL for creating and writing to custom tables. Their columns are only known at runtime, so
+//! statements are built as text, with values bound positionally, and shared between the Postgres
+//! and SQLite stores, which differ only in placeholder syntax and column types. Table and column
+//! names are always quoted, so that names which are reserved words in either database are safe.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use diesel::backend::Backend;
+use diesel::query_builder::{BoxedSqlQuery, SqlQuery};
+use diesel::serialize::ToSql;
+use diesel::sql_types::{BigInt, Binary, HasSqlType, Nullable, SmallInt, Text};
+use itertools::Itertools;
+
+use crate::config::CustomColumnType;
+use crate::errors::IndexerError;
+use crate::handlers::custom_tables::{
+    CustomTable, CustomTableChangesToCommit, CustomTableRow, CustomValue,
+};
+use crate::models::custom_tables::StoredCustomTable;
+use crate::types::IndexedDeletedObject;
+
+/// The most values bound to a single statement. SQLite's default limit is the lower of the two
+/// databases', at 32766.
+const MAX_BINDS_PER_STATEMENT: usize = 32_000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum Dialect {
+    Pg,
+    Sqlite,
+}
+
+/// A statement and the values to bind to its placeholders, in order.
+#[derive(Clone)]
+pub(super) struct Statement {
+    pub sql: String,
+    binds: Vec<Bind>,
+}
+
+#[derive(Clone)]
+enum Bind {
+    BigInt(i64),
+    Binary(Vec<u8>),
+    NullableSmallInt(Option<i16>),
+    NullableBinary(Option<Vec<u8>>),
+    NullableText(Option<String>),
+}
+
+impl Dialect {
+    fn placeholder(&self, index: usize) -> String {
+        match self {
+            Dialect::Pg => format!("${index}"),
+            Dialect::Sqlite => format!("?{index}"),
+        }
+    }
+
+    fn column_type(&self, type_: CustomColumnType) -> &'static str {
+        match self {
+            Dialect::Pg => type_.pg_type(),
+            Dialect::Sqlite => type_.sqlite_type(),
+        }
+    }
+
+    fn binary_type(&self) -> &'static str {
+        match self {
+            Dialect::Pg => "BYTEA",
+            Dialect::Sqlite => "BLOB",
+        }
+    }
+
+    /// Custom values are bound as text, and Postgres needs to be told to convert them to the
+    /// column's type, while SQLite converts them according to the column's affinity.
+    fn value_placeholder(&self, index: usize, type_: CustomColumnType) -> String {
+        let placeholder = self.placeholder(index);
+        match (self, type_) {
+            (Dialect::Pg, CustomColumnType::Text | CustomColumnType::Address) => placeholder,
+            (Dialect::Pg, _) => format!("CAST({placeholder} AS {})", type_.pg_type()),
+            (Dialect::Sqlite, _) => placeholder,
+        }
+    }
+}
+
+impl Statement {
+    /// Box the statement's SQL with its values bound, ready to execute.
+    pub fn into_query<DB>(self) -> BoxedSqlQuery<'static, DB, SqlQuery>
+    where
+        DB: Backend
+            + HasSqlType<BigInt>
+            + HasSqlType<Binary>
+            + HasSqlType<SmallInt>
+            + HasSqlType<Text>,
+        i64: ToSql<BigInt, DB>,
+        Vec<u8>: ToSql<Binary, DB>,
+        Option<i16>: ToSql<Nullable<SmallInt>, DB>,
+        Option<Vec<u8>>: ToSql<Nullable<Binary>, DB>,
+        Option<String>: ToSql<Nullable<Text>, DB>,
+    {
+        let mut query = diesel::sql_query(self.sql).into_boxed::<DB>();
+        for bind in self.binds {
+            query = match bind {
+                Bind::BigInt(v) => query.bind::<BigInt, _>(v),
+                Bind::Binary(v) => query.bind::<Binary, _>(v),
+                Bind::NullableSmallInt(v) => query.bind::<Nullable<SmallInt>, _>(v),
+                Bind::NullableBinary(v) => query.bind::<Nullable<Binary>, _>(v),
+                Bind::NullableText(v) => query.bind::<Nullable<Text>, _>(v),
+            };
+        }
+        query
+    }
+}
+
+impl CustomValue {
+    fn into_text(self) -> Option<String> {
+        match self {
+            CustomValue::Null => None,
+            CustomValue::Number(s) | CustomValue::Text(s) | CustomValue::Json(s) => Some(s),
+            // Both Postgres and SQLite read these as booleans.
+            CustomValue::Bool(b) => Some(if b { "1" } else { "0" }.to_string()),
+        }
+    }
+}
+
+/// The statements to create each of `tables` and its indices, and each table's registry entry.
+/// Fails if a table is already registered with a different definition, as its columns cannot be
+/// changed in place.
+pub(super) fn tables_to_create(
+    dialect: Dialect,
+    tables: &[Arc<CustomTable>],
+    registered: Vec<StoredCustomTable>,
+) -> Result<Vec<(Vec<String>, StoredCustomTable)>, IndexerError> {
+    tables
+        .iter()
+        .map(|table| {
+            let stored = StoredCustomTable::try_from(table.as_ref())?;
+            if let Some(existing) = registered.iter().find(|r| r.name == stored.name) {
+                if existing != &stored {
+                    return Err(IndexerError::InvalidArgumentError(format!(
+                        "Custom table {} is already registered with definition {}, which differs \
+                         from its definition in the config. Drop {} and its entry in \
+                         custom_tables to redefine it.",
+                        stored.name,
+                        existing.definition,
+                        table.table_name(),
+                    )));
+                }
+            }
+
+            Ok((create_table(dialect, table), stored))
+        })
+        .collect()
+}
+
+/// The statements to apply `changes`. Rows and deletions are written in batches, one table at a
+/// time. Tables that do not keep history only need each object's latest change, so earlier
+/// changes to an object that changed in more than one of the checkpoints being committed are
+/// dropped before they are written.
+pub(super) fn write_changes(
+    dialect: Dialect,
+    changes: Vec<CustomTableChangesToCommit>,
+) -> Vec<Statement> {
+    let mut tables: BTreeMap<String, CustomTableChangesToCommit> = BTreeMap::new();
+    for change in changes {
+        match tables.get_mut(&change.table.name) {
+            Some(merged) => {
+                merged.changed_rows.extend(change.changed_rows);
+                merged.deleted_objects.extend(change.deleted_objects);
+            }
+            None => {
+                tables.insert(change.table.name.clone(), change);
+            }
+        }
+    }
+
+    let mut statements = vec![];
+    for CustomTableChangesToCommit {
+        table,
+        mut changed_rows,
+        mut deleted_objects,
+    } in tables.into_values()
+    {
+        if !table.history {
+            (changed_rows, deleted_objects) = latest_changes(changed_rows, deleted_objects);
+        }
+
+        // Each row binds the five system columns, and a value for each declared column.
+        let rows_per_statement = MAX_BINDS_PER_STATEMENT / (5 + table.columns.len());
+        for rows in changed_rows.chunks(rows_per_statement.max(1)) {
+            statements.push(write_rows(dialect, &table, rows));
+        }
+
+        for deleted in deleted_objects.chunks(MAX_BINDS_PER_STATEMENT / 3) {
+            statements.push(delete_objects(dialect, &table, deleted));
+        }
+    }
+
+    statements
+}
+
+/// Reduce changes to a table that does not keep history to the latest change to each object:
+/// either its row at its latest version, or its deletion, if that came later.
+fn latest_changes(
+    changed_rows: Vec<CustomTableRow>,
+    deleted_objects: Vec<IndexedDeletedObject>,
+) -> (Vec<CustomTableRow>, Vec<IndexedDeletedObject>) {
+    let mut rows: BTreeMap<_, CustomTableRow> = BTreeMap::new();
+    for row in changed_rows {
+        match rows.get(&row.object_id) {
+            Some(latest) if latest.object_version >= row.object_version => {}
+            _ => {
+                rows.insert(row.object_id, row);
+            }
+        }
+    }
+
+    let mut deleted: BTreeMap<_, IndexedDeletedObject> = BTreeMap::new();
+    for object in deleted_objects {
+        if rows
+            .get(&object.object_id)
+            .is_some_and(|row| row.object_version > object.object_version)
+        {
+            continue;
+        }
+
+        rows.remove(&object.object_id);
+        match deleted.get(&object.object_id) {
+            Some(latest) if latest.object_version >= object.object_version => {}
+            _ => {
+                deleted.insert(object.object_id, object);
+            }
+        }
+    }
+
+    (
+        rows.into_values().collect(),
+        deleted.into_values().collect(),
+    )
+}
+
+/// Statements that create `table` and its indices, if they do not already exist.
+fn create_table(dialect: Dialect, table: &CustomTable) -> Vec<String> {
+    let name = table.table_name();
+    let table_name = quote(&name);
+    let binary = dialect.binary_type();
+    let columns = table
+        .columns
+        .iter()
+        .map(|c| format!("    {} {},\n", quote(&c.name), dialect.column_type(c.type_)))
+        .join("");
+
+    let primary_key = if table.history {
+        "object_id, object_version"
+    } else {
+        "object_id"
+    };
+
+    let mut statements = vec![
+        format!(
+            "CREATE TABLE IF NOT EXISTS {table_name}\n(\n    \
+             object_id BLOB_TYPE NOT NULL,\n    \
+             object_version BIGINT NOT NULL,\n    \
+             checkpoint_sequence_number BIGINT NOT NULL,\n    \
+             owner_type SMALLINT,\n    \
+             owner_id BLOB_TYPE,\n    \
+             deleted BOOLEAN NOT NULL DEFAULT FALSE,\n\
+             {columns}    \
+             PRIMARY KEY ({primary_key})\n)"
+        )
+        .replace("BLOB_TYPE", binary),
+        format!(
+            "CREATE INDEX IF NOT EXISTS {} ON {table_name} (owner_id, object_id)",
+            quote(&format!("{name}_owner")),
+        ),
+    ];
+
+    if table.history {
+        statements.push(format!(
+            "CREATE INDEX IF NOT EXISTS {} ON {table_name} (checkpoint_sequence_number)",
+            quote(&format!("{name}_checkpoint")),
+        ));
+    }
+
+    statements
+}
+
+/// A statement that writes `rows` to `table`. Tables that keep history gain a row per object
+/// version, while other tables keep one row per object, which is only replaced by a row for a
+/// later version of the object. Rows for tables that do not keep history must be for distinct
+/// objects, as a statement cannot update the same row twice.
+fn write_rows(dialect: Dialect, table: &CustomTable, rows: &[CustomTableRow]) -> Statement {
+    let name = quote(&table.table_name());
+    let columns = table.columns.iter().map(|c| quote(&c.name)).join(", ");
+    let separator = if table.columns.is_empty() { "" } else { ", " };
+
+    let width = 5 + table.columns.len();
+    let mut binds = Vec::with_capacity(rows.len() * width);
+    let mut values = Vec::with_capacity(rows.len());
+    for (i, row) in rows.iter().enumerate() {
+        let offset = i * width;
+        let placeholders = (1..=5).map(|j| dialect.placeholder(offset + j)).join(", ");
+        let custom = table
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(j, c)| dialect.value_placeholder(offset + 6 + j, c.type_))
+            .join(", ");
+        values.push(format!("({placeholders}, FALSE{separator}{custom})"));
+
+        binds.extend([
+            Bind::Binary(row.object_id.to_vec()),
+            Bind::BigInt(row.object_version as i64),
+            Bind::BigInt(row.checkpoint_sequence_number as i64),
+            Bind::NullableSmallInt(Some(row.owner_type)),
+            Bind::NullableBinary(row.owner_id.clone()),
+        ]);
+        binds.extend(
+            row.values
+                .iter()
+                .cloned()
+                .map(|v| Bind::NullableText(v.into_text())),
+        );
+    }
+
+    let mut sql = format!(
+        "INSERT INTO {name} AS t \
+         (object_id, object_version, checkpoint_sequence_number, owner_type, owner_id, deleted\
+         {separator}{columns}) \
+         VALUES {} ",
+        values.join(", "),
+    );
+
+    if table.history {
+        sql.push_str("ON CONFLICT DO NOTHING");
+    } else {
+        let updates = [
+            "checkpoint_sequence_number".to_string(),
+            "owner_type".to_string(),
+            "owner_id".to_string(),
+            "object_version".to_string(),
+        ]
+        .into_iter()
+        .chain(table.columns.iter().map(|c| quote(&c.name)))
+        .map(|c| format!("{c} = excluded.{c}"))
+        .join(", ");
+
+        sql.push_str(&format!(
+            "ON CONFLICT (object_id) DO UPDATE SET {updates} \
+             WHERE t.object_version < excluded.object_version"
+        ));
+    }
+
+    Statement { sql, binds }
+}
+
+/// A statement that records the deletion (or wrapping) of `deleted` objects in `table`. Tables
+/// that keep history gain a tombstone row for each object they hold any earlier version of,
+/// while other tables drop the objects' rows.
+fn delete_objects(
+    dialect: Dialect,
+    table: &CustomTable,
+    deleted: &[IndexedDeletedObject],
+) -> Statement {
+    let name = quote(&table.table_name());
+
+    if table.history {
+        let mut binds = Vec::with_capacity(deleted.len() * 3);
+        let mut values = Vec::with_capacity(deleted.len());
+        for (i, object) in deleted.iter().enumerate() {
+            let placeholders = (1..=3).map(|j| dialect.placeholder(i * 3 + j)).join(", ");
+            values.push(format!("({placeholders})"));
+            binds.extend([
+                Bind::Binary(object.object_id.to_vec()),
+                Bind::BigInt(object.object_version as i64),
+                Bind::BigInt(object.checkpoint_sequence_number as i64),
+            ]);
+        }
+
+        let sql = format!(
+            "WITH d (object_id, object_version, checkpoint_sequence_number) AS (VALUES {}) \
+             INSERT INTO {name} \
+             (object_id, object_version, checkpoint_sequence_number, deleted) \
+             SELECT d.object_id, d.object_version, d.checkpoint_sequence_number, TRUE FROM d \
+             WHERE EXISTS (SELECT 1 FROM {name} t WHERE t.object_id = d.object_id) \
+             ON CONFLICT DO NOTHING",
+            values.join(", "),
+        );
+
+        Statement { sql, binds }
+    } else {
+        let mut binds = Vec::with_capacity(deleted.len() * 2);
+        let mut values = Vec::with_capacity(deleted.len());
+        for (i, object) in deleted.iter().enumerate() {
+            let placeholders = (1..=2).map(|j| dialect.placeholder(i * 2 + j)).join(", ");
+            values.push(format!("({placeholders})"));
+            binds.extend([
+                Bind::Binary(object.object_id.to_vec()),
+                Bind::BigInt(object.object_version as i64),
+            ]);
+        }
+
+        let sql = format!(
+            "WITH d (object_id, object_version) AS (VALUES {}) \
+             DELETE FROM {name} \
+             WHERE EXISTS (\
+                 SELECT 1 FROM d \
+                 WHERE d.object_id = {name}.object_id \
+                 AND {name}.object_version < d.object_version\
+             )",
+            values.join(", "),
+        );
+
+        Statement { sql, binds }
+    }
+}
+
+/// Quote `name` as an identifier. Custom table and column names are validated to contain only
+/// lowercase letters, digits and underscores, so they never need escaping.
+fn quote(name: &str) -> String {
+    format!("\"{name}\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use sui_types::base_types::ObjectID;
+
+    use super::*;
+    use crate::config::{CustomColumnConfig, CustomTableConfig};
+
+    fn table(history: bool) -> CustomTable {
+        CustomTable::new(CustomTableConfig {
+            name: "coins".to_string(),
+            type_: "0x2::coin::Coin".to_string(),
+            history,
+            columns: vec![
+                CustomColumnConfig {
+                    name: "balance".to_string(),
+                    field: "balance".to_string(),
+                    type_: CustomColumnType::Number,
+                },
+                CustomColumnConfig {
+                    name: "label".to_string(),
+                    field: "label".to_string(),
+                    type_: CustomColumnType::Text,
+                },
+            ],
+        })
+        .unwrap()
+    }
+
+    fn row(object_id: ObjectID, object_version: u64) -> CustomTableRow {
+        CustomTableRow {
+            object_id,
+            object_version,
+            checkpoint_sequence_number: object_version + 1,
+            owner_type: 1,
+            owner_id: None,
+            values: vec![CustomValue::Number("3".to_string()), CustomValue::Null],
+        }
+    }
+
+    fn deleted(object_id: ObjectID, object_version: u64) -> IndexedDeletedObject {
+        IndexedDeletedObject {
+            object_id,
+            object_version,
+            checkpoint_sequence_number: object_version + 1,
+        }
+    }
+
+    #[test]
+    fn write_live_rows() {
+        let rows = [
+            row(ObjectID::ZERO, 1),
+            row(ObjectID::from_single_byte(1), 2),
+        ];
+        let statement = write_rows(Dialect::Pg, &table(false), &rows);
+        assert_eq!(
+            statement.sql,
+            "INSERT INTO \"custom_coins\" AS t (object_id, object_version, \
+             checkpoint_sequence_number, owner_type, owner_id, deleted, \"balance\", \"label\") \
+             VALUES ($1, $2, $3, $4, $5, FALSE, CAST($6 AS NUMERIC), $7), \
+             ($8, $9, $10, $11, $12, FALSE, CAST($13 AS NUMERIC), $14) \
+             ON CONFLICT (object_id) DO UPDATE SET \
+             checkpoint_sequence_number = excluded.checkpoint_sequence_number, \
+             owner_type = excluded.owner_type, owner_id = excluded.owner_id, \
+             object_version = excluded.object_version, \"balance\" = excluded.\"balance\", \
+             \"label\" = excluded.\"label\" \
+             WHERE t.object_version < excluded.object_version"
+        );
+        assert_eq!(statement.binds.len(), 14);
+    }
+
+    #[test]
+    fn delete_from_history() {
+        let objects = [
+            deleted(ObjectID::ZERO, 4),
+            deleted(ObjectID::from_single_byte(1), 6),
+        ];
+        let statement = delete_objects(Dialect::Sqlite, &table(true), &objects);
+        assert_eq!(
+            statement.sql,
+            "WITH d (object_id, object_version, checkpoint_sequence_number) \
+             AS (VALUES (?1, ?2, ?3), (?4, ?5, ?6)) \
+             INSERT INTO \"custom_coins\" \
+             (object_id, object_version, checkpoint_sequence_number, deleted) \
+             SELECT d.object_id, d.object_version, d.checkpoint_sequence_number, TRUE FROM d \
+             WHERE EXISTS (SELECT 1 FROM \"custom_coins\" t WHERE t.object_id = d.object_id) \
+             ON CONFLICT DO NOTHING"
+        );
+        assert_eq!(statement.binds.len(), 6);
+    }
+
+    #[test]
+    fn quote_reserved_words() {
+        let table = CustomTable::new(CustomTableConfig {
+            name: "user".to_string(),
+            type_: "0x2::coin::Coin".to_string(),
+            history: false,
+            columns: vec![CustomColumnConfig {
+                name: "order".to_string(),
+                field: "balance".to_string(),
+                type_: CustomColumnType::Number,
+            }],
+        })
+        .unwrap();
+
+        let statements = create_table(Dialect::Pg, &table);
+        assert!(statements[0].starts_with("CREATE TABLE IF NOT EXISTS \"custom_user\"\n"));
+        assert!(statements[0].contains("    \"order\" NUMERIC,\n"));
+        assert_eq!(
+            statements[1],
+            "CREATE INDEX IF NOT EXISTS \"custom_user_owner\" ON \"custom_user\" \
+             (owner_id, object_id)"
+        );
+    }
+
+    #[test]
+    fn keep_latest_live_changes() {
+        let a = ObjectID::from_single_byte(0xa);
+        let b = ObjectID::from_single_byte(0xb);
+        let c = ObjectID::from_single_byte(0xc);
+
+        // `a` is modified twice, `b` is deleted after it is modified, and `c` is unwrapped after
+        // it is wrapped.
+        let (rows, deletions) = latest_changes(
+            vec![row(a, 3), row(a, 2), row(b, 1), row(c, 5)],
+            vec![deleted(b, 2), deleted(c, 4)],
+        );
+
+        let rows: Vec<_> = rows
+            .iter()
+            .map(|r| (r.object_id, r.object_version))
+            .collect();
+        let deletions: Vec<_> = deletions
+            .iter()
+            .map(|d| (d.object_id, d.object_version))
+            .collect();
+        assert_eq!(rows, vec![(a, 3), (c, 5)]);
+        assert_eq!(deletions, vec![(b, 2)]);
+    }
+
+    #[test]
+    fn batch_changes_per_table() {
+        let changes = |object_version| CustomTableChangesToCommit {
+            table: Arc::new(table(false)),
+            changed_rows: vec![row(ObjectID::ZERO, object_version)],
+            deleted_objects: vec![],
+        };
+
+        // Two checkpoints changing the same object are written as a single row.
+        let statements = write_changes(Dialect::Pg, vec![changes(1), changes(2)]);
+        assert_eq!(statements.len(), 1);
+        assert_eq!(statements[0].binds.len(), 7);
+    }
+}
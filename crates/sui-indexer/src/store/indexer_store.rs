@@ -2,12 +2,19 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::collections::BTreeMap;
+use std::sync::Arc;
 
 use async_trait::async_trait;
 use strum::IntoEnumIterator;
 
+use sui_types::base_types::ObjectID;
+use sui_types::move_package::MovePackage;
+
 use crate::errors::IndexerError;
-use crate::handlers::{CommitterWatermark, EpochToCommit, TransactionObjectChangesToCommit};
+use crate::handlers::custom_tables::{CustomTable, CustomTableChangesToCommit};
+use crate::handlers::{
+    CommitterWatermark, EpochMetricsToCommit, EpochToCommit, TransactionObjectChangesToCommit,
+};
 use crate::models::display::StoredDisplay;
 use crate::models::obj_indices::StoredObjectVersion;
 use crate::models::objects::{StoredDeletedObject, StoredObject};
@@ -96,8 +103,27 @@ pub trait IndexerStore: Clone + Sync + Send + 'static {
 
     async fn persist_packages(&self, packages: Vec<IndexedPackage>) -> Result<(), IndexerError>;
 
+    async fn get_move_package(&self, id: ObjectID) -> Result<Option<MovePackage>, IndexerError>;
+
+    /// Create the custom tables that do not exist yet, and register them. Fails if a table has
+    /// already been registered with a different definition.
+    async fn create_custom_tables(&self, tables: &[Arc<CustomTable>]) -> Result<(), IndexerError>;
+
+    async fn persist_custom_tables(
+        &self,
+        changes: Vec<CustomTableChangesToCommit>,
+    ) -> Result<(), IndexerError>;
+
     async fn persist_epoch(&self, epoch: EpochToCommit) -> Result<(), IndexerError>;
 
+    /// Fold each checkpoint's contribution into the per-epoch aggregate metrics tables.
+    /// Contributions must be supplied in checkpoint order, and those from checkpoints that have
+    /// already been folded in are ignored.
+    async fn persist_epoch_metrics(
+        &self,
+        epoch_metrics: Vec<EpochMetricsToCommit>,
+    ) -> Result<(), IndexerError>;
+
     async fn advance_epoch(&self, epoch: EpochToCommit) -> Result<(), IndexerError>;
 
     async fn prune_epoch(&self, epoch: u64) -> Result<(), IndexerError>;
@@ -3,12 +3,14 @@
 
 use std::collections::{BTreeMap, HashMap};
 use std::io::Cursor;
+use std::sync::Arc;
 use std::time::Duration;
 
 use async_trait::async_trait;
 use core::result::Result::Ok;
 use csv::{ReaderBuilder, Writer};
 use diesel::dsl::{max, min};
+use diesel::pg::Pg;
 use diesel::ExpressionMethods;
 use diesel::OptionalExtension;
 use diesel::QueryDsl;
@@ -18,6 +20,7 @@ use itertools::Itertools;
 use object_store::path::Path;
 use strum::IntoEnumIterator;
 use sui_types::base_types::ObjectID;
+use sui_types::move_package::MovePackage;
 use tap::TapFallible;
 use tracing::{info, warn};
 
@@ -28,12 +31,14 @@ use sui_storage::object_store::util::put;
 use crate::config::UploadOptions;
 use crate::database::ConnectionPool;
 use crate::errors::{Context, IndexerError};
+use crate::handlers::custom_tables::{CustomTable, CustomTableChangesToCommit};
 use crate::handlers::TransactionObjectChangesToCommit;
-use crate::handlers::{CommitterWatermark, EpochToCommit};
+use crate::handlers::{CommitterWatermark, EpochMetricsToCommit, EpochToCommit};
 use crate::metrics::IndexerMetrics;
 use crate::models::checkpoints::StoredChainIdentifier;
 use crate::models::checkpoints::StoredCheckpoint;
 use crate::models::checkpoints::StoredCpTx;
+use crate::models::custom_tables::StoredCustomTable;
 use crate::models::display::StoredDisplay;
 use crate::models::epoch::StoredEpochInfo;
 use crate::models::epoch::{StoredFeatureFlag, StoredProtocolConfig};
@@ -47,18 +52,20 @@ use crate::models::packages::StoredPackage;
 use crate::models::transactions::StoredTransaction;
 use crate::models::watermarks::StoredWatermark;
 use crate::schema::{
-    chain_identifier, checkpoints, display, epochs, event_emit_module, event_emit_package,
-    event_senders, event_struct_instantiation, event_struct_module, event_struct_name,
-    event_struct_package, events, feature_flags, full_objects_history, objects, objects_history,
-    objects_snapshot, objects_version, packages, protocol_configs, pruner_cp_watermark,
-    raw_checkpoints, transactions, tx_affected_addresses, tx_affected_objects, tx_calls_fun,
-    tx_calls_mod, tx_calls_pkg, tx_changed_objects, tx_digests, tx_input_objects, tx_kinds,
-    tx_recipients, tx_senders, watermarks,
+    chain_identifier, checkpoints, custom_tables, display, epoch_package_metrics,
+    epoch_sender_metrics, epochs, event_emit_module, event_emit_package, event_senders,
+    event_struct_instantiation, event_struct_module, event_struct_name, event_struct_package,
+    events, feature_flags, full_objects_history, objects, objects_history, objects_snapshot,
+    objects_version, packages, protocol_configs, pruner_cp_watermark, raw_checkpoints,
+    transactions, tx_affected_addresses, tx_affected_objects, tx_calls_fun, tx_calls_mod,
+    tx_calls_pkg, tx_changed_objects, tx_digests, tx_input_objects, tx_kinds, tx_recipients,
+    tx_senders, watermarks,
 };
 use crate::store::transaction_with_retry;
 use crate::types::{EventIndex, IndexedDeletedObject, IndexedObject};
 use crate::types::{IndexedCheckpoint, IndexedEvent, IndexedPackage, IndexedTransaction, TxIndex};
 
+use super::custom_table_sql::{self, Dialect};
 use super::pg_partition_manager::{EpochPartitionData, PgPartitionManager};
 use super::IndexerStore;
 
@@ -237,20 +244,6 @@ impl PgIndexerStore {
             .context("Failed reading min and max epoch numbers from PostgresDB")
     }
 
-    async fn get_min_prunable_checkpoint(&self) -> Result<u64, IndexerError> {
-        use diesel_async::RunQueryDsl;
-
-        let mut connection = self.pool.get().await?;
-
-        pruner_cp_watermark::table
-            .select(min(pruner_cp_watermark::checkpoint_sequence_number))
-            .first::<Option<i64>>(&mut connection)
-            .await
-            .map_err(Into::into)
-            .map(|v| v.unwrap_or_default() as u64)
-            .context("Failed reading min prunable checkpoint sequence number from PostgresDB")
-    }
-
     async fn get_checkpoint_range_for_epoch(
         &self,
         epoch: u64,
@@ -269,27 +262,6 @@ impl PgIndexerStore {
             .context("Failed reading checkpoint range from PostgresDB")
     }
 
-    async fn get_transaction_range_for_checkpoint(
-        &self,
-        checkpoint: u64,
-    ) -> Result<(u64, u64), IndexerError> {
-        use diesel_async::RunQueryDsl;
-
-        let mut connection = self.pool.get().await?;
-
-        pruner_cp_watermark::table
-            .select((
-                pruner_cp_watermark::min_tx_sequence_number,
-                pruner_cp_watermark::max_tx_sequence_number,
-            ))
-            .filter(pruner_cp_watermark::checkpoint_sequence_number.eq(checkpoint as i64))
-            .first::<(i64, i64)>(&mut connection)
-            .await
-            .map_err(Into::into)
-            .map(|(min, max)| (min as u64, max as u64))
-            .context("Failed reading transaction range from PostgresDB")
-    }
-
     pub async fn get_latest_object_snapshot_checkpoint_sequence_number(
         &self,
     ) -> Result<Option<u64>, IndexerError> {
@@ -311,6 +283,21 @@ impl PgIndexerStore {
             )
     }
 
+    /// Number of rows in the `objects` table.
+    pub async fn get_objects_count(&self) -> Result<u64, IndexerError> {
+        use diesel_async::RunQueryDsl;
+
+        let mut connection = self.pool.get().await?;
+
+        objects::table
+            .count()
+            .get_result::<i64>(&mut connection)
+            .await
+            .map_err(Into::into)
+            .map(|count| count as u64)
+            .context("Failed reading objects count from PostgresDB")
+    }
+
     async fn persist_display_updates(
         &self,
         display_updates: Vec<StoredDisplay>,
@@ -920,6 +907,187 @@ impl PgIndexerStore {
         })
     }
 
+    async fn get_move_package(&self, id: ObjectID) -> Result<Option<MovePackage>, IndexerError> {
+        use diesel_async::RunQueryDsl;
+
+        let mut connection = self.pool.get().await?;
+        let Some(move_package) = packages::table
+            .select(packages::move_package)
+            .filter(packages::package_id.eq(id.to_vec()))
+            .first::<Vec<u8>>(&mut connection)
+            .await
+            .optional()
+            .map_err(Into::into)
+            .context("Failed reading package from PostgresDB")?
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(bcs::from_bytes(&move_package)?))
+    }
+
+    async fn create_custom_tables(&self, tables: &[Arc<CustomTable>]) -> Result<(), IndexerError> {
+        use diesel_async::RunQueryDsl;
+
+        let registered = {
+            let mut connection = self.pool.get().await?;
+            custom_tables::table
+                .load::<StoredCustomTable>(&mut connection)
+                .await
+                .map_err(Into::into)
+                .context("Failed reading custom_tables from PostgresDB")?
+        };
+
+        let tables = custom_table_sql::tables_to_create(Dialect::Pg, tables, registered)?;
+        transaction_with_retry(&self.pool, PG_DB_COMMIT_SLEEP_DURATION, |conn| {
+            async {
+                for (statements, stored) in &tables {
+                    for statement in statements {
+                        diesel::sql_query(statement)
+                            .execute(conn)
+                            .await
+                            .map_err(IndexerError::from)
+                            .context("Failed to create custom table")?;
+                    }
+
+                    diesel::insert_into(custom_tables::table)
+                        .values(stored)
+                        .on_conflict_do_nothing()
+                        .execute(conn)
+                        .await
+                        .map_err(IndexerError::from)
+                        .context("Failed to write to custom_tables table")?;
+                }
+                Ok::<(), IndexerError>(())
+            }
+            .scope_boxed()
+        })
+        .await
+        .tap_ok(|_| info!("Created {} custom tables", tables.len()))
+    }
+
+    async fn persist_custom_tables(
+        &self,
+        changes: Vec<CustomTableChangesToCommit>,
+    ) -> Result<(), IndexerError> {
+        use diesel_async::RunQueryDsl;
+        let guard = self
+            .metrics
+            .checkpoint_db_commit_latency_custom_tables
+            .start_timer();
+        let statements = custom_table_sql::write_changes(Dialect::Pg, changes);
+
+        transaction_with_retry(&self.pool, PG_DB_COMMIT_SLEEP_DURATION, |conn| {
+            async {
+                for statement in &statements {
+                    statement
+                        .clone()
+                        .into_query::<Pg>()
+                        .execute(conn)
+                        .await
+                        .map_err(IndexerError::from)
+                        .context("Failed to write to custom table")?;
+                }
+                Ok::<(), IndexerError>(())
+            }
+            .scope_boxed()
+        })
+        .await
+        .tap_ok(|_| {
+            let elapsed = guard.stop_and_record();
+            info!(
+                elapsed,
+                "Persisted custom tables in {} statements",
+                statements.len()
+            );
+        })
+        .tap_err(|e| {
+            tracing::error!("Failed to persist custom tables with error: {}", e);
+        })
+    }
+
+    async fn persist_epoch_metrics(
+        &self,
+        epoch_metrics: Vec<EpochMetricsToCommit>,
+    ) -> Result<(), IndexerError> {
+        use diesel_async::RunQueryDsl;
+        let guard = self
+            .metrics
+            .checkpoint_db_commit_latency_epoch_metrics
+            .start_timer();
+        let len = epoch_metrics.len();
+
+        transaction_with_retry(&self.pool, PG_DB_COMMIT_SLEEP_DURATION, |conn| {
+            async {
+                // Each checkpoint's contribution is applied in a separate statement, in order, so
+                // that the checkpoint watermark on each row can be used to skip contributions that
+                // have already been applied, e.g. when a batch is re-committed after a restart.
+                for metrics in &epoch_metrics {
+                    for chunk in metrics
+                        .package_metrics
+                        .chunks(PG_COMMIT_CHUNK_SIZE_INTRA_DB_TX)
+                    {
+                        use epoch_package_metrics as p;
+                        diesel::insert_into(p::table)
+                            .values(chunk)
+                            .on_conflict((p::epoch, p::package))
+                            .do_update()
+                            .set((
+                                p::move_calls.eq(p::move_calls + excluded(p::move_calls)),
+                                p::transactions.eq(p::transactions + excluded(p::transactions)),
+                                p::gas_spent.eq(p::gas_spent + excluded(p::gas_spent)),
+                                p::checkpoint_sequence_number
+                                    .eq(excluded(p::checkpoint_sequence_number)),
+                            ))
+                            .filter(
+                                p::checkpoint_sequence_number
+                                    .lt(excluded(p::checkpoint_sequence_number)),
+                            )
+                            .execute(conn)
+                            .await
+                            .map_err(IndexerError::from)
+                            .context("Failed to write to epoch_package_metrics table")?;
+                    }
+
+                    for chunk in metrics
+                        .sender_metrics
+                        .chunks(PG_COMMIT_CHUNK_SIZE_INTRA_DB_TX)
+                    {
+                        use epoch_sender_metrics as s;
+                        diesel::insert_into(s::table)
+                            .values(chunk)
+                            .on_conflict((s::epoch, s::sender))
+                            .do_update()
+                            .set((
+                                s::transactions.eq(s::transactions + excluded(s::transactions)),
+                                s::gas_spent.eq(s::gas_spent + excluded(s::gas_spent)),
+                                s::checkpoint_sequence_number
+                                    .eq(excluded(s::checkpoint_sequence_number)),
+                            ))
+                            .filter(
+                                s::checkpoint_sequence_number
+                                    .lt(excluded(s::checkpoint_sequence_number)),
+                            )
+                            .execute(conn)
+                            .await
+                            .map_err(IndexerError::from)
+                            .context("Failed to write to epoch_sender_metrics table")?;
+                    }
+                }
+                Ok::<(), IndexerError>(())
+            }
+            .scope_boxed()
+        })
+        .await
+        .tap_ok(|_| {
+            let elapsed = guard.stop_and_record();
+            info!(elapsed, "Persisted epoch metrics for {len} checkpoints");
+        })
+        .tap_err(|e| {
+            tracing::error!("Failed to persist epoch metrics with error: {}", e);
+        })
+    }
+
     async fn persist_event_indices_chunk(
         &self,
         indices: Vec<EventIndex>,
@@ -1377,193 +1545,6 @@ impl PgIndexerStore {
         Ok(())
     }
 
-    async fn prune_checkpoints_table(&self, cp: u64) -> Result<(), IndexerError> {
-        use diesel_async::RunQueryDsl;
-        transaction_with_retry(&self.pool, PG_DB_COMMIT_SLEEP_DURATION, |conn| {
-            async {
-                diesel::delete(
-                    checkpoints::table.filter(checkpoints::sequence_number.eq(cp as i64)),
-                )
-                .execute(conn)
-                .await
-                .map_err(IndexerError::from)
-                .context("Failed to prune checkpoints table")?;
-
-                Ok::<(), IndexerError>(())
-            }
-            .scope_boxed()
-        })
-        .await
-    }
-
-    async fn prune_event_indices_table(
-        &self,
-        min_tx: u64,
-        max_tx: u64,
-    ) -> Result<(), IndexerError> {
-        use diesel_async::RunQueryDsl;
-
-        let (min_tx, max_tx) = (min_tx as i64, max_tx as i64);
-        transaction_with_retry(&self.pool, PG_DB_COMMIT_SLEEP_DURATION, |conn| {
-            async {
-                diesel::delete(
-                    event_emit_module::table
-                        .filter(event_emit_module::tx_sequence_number.between(min_tx, max_tx)),
-                )
-                .execute(conn)
-                .await?;
-
-                diesel::delete(
-                    event_emit_package::table
-                        .filter(event_emit_package::tx_sequence_number.between(min_tx, max_tx)),
-                )
-                .execute(conn)
-                .await?;
-
-                diesel::delete(
-                    event_senders::table
-                        .filter(event_senders::tx_sequence_number.between(min_tx, max_tx)),
-                )
-                .execute(conn)
-                .await?;
-
-                diesel::delete(event_struct_instantiation::table.filter(
-                    event_struct_instantiation::tx_sequence_number.between(min_tx, max_tx),
-                ))
-                .execute(conn)
-                .await?;
-
-                diesel::delete(
-                    event_struct_module::table
-                        .filter(event_struct_module::tx_sequence_number.between(min_tx, max_tx)),
-                )
-                .execute(conn)
-                .await?;
-
-                diesel::delete(
-                    event_struct_name::table
-                        .filter(event_struct_name::tx_sequence_number.between(min_tx, max_tx)),
-                )
-                .execute(conn)
-                .await?;
-
-                diesel::delete(
-                    event_struct_package::table
-                        .filter(event_struct_package::tx_sequence_number.between(min_tx, max_tx)),
-                )
-                .execute(conn)
-                .await?;
-
-                Ok(())
-            }
-            .scope_boxed()
-        })
-        .await
-    }
-
-    async fn prune_tx_indices_table(&self, min_tx: u64, max_tx: u64) -> Result<(), IndexerError> {
-        use diesel_async::RunQueryDsl;
-
-        let (min_tx, max_tx) = (min_tx as i64, max_tx as i64);
-        transaction_with_retry(&self.pool, PG_DB_COMMIT_SLEEP_DURATION, |conn| {
-            async {
-                diesel::delete(
-                    tx_affected_addresses::table
-                        .filter(tx_affected_addresses::tx_sequence_number.between(min_tx, max_tx)),
-                )
-                .execute(conn)
-                .await?;
-
-                diesel::delete(
-                    tx_affected_objects::table
-                        .filter(tx_affected_objects::tx_sequence_number.between(min_tx, max_tx)),
-                )
-                .execute(conn)
-                .await?;
-
-                diesel::delete(
-                    tx_senders::table
-                        .filter(tx_senders::tx_sequence_number.between(min_tx, max_tx)),
-                )
-                .execute(conn)
-                .await?;
-
-                diesel::delete(
-                    tx_recipients::table
-                        .filter(tx_recipients::tx_sequence_number.between(min_tx, max_tx)),
-                )
-                .execute(conn)
-                .await?;
-
-                diesel::delete(
-                    tx_input_objects::table
-                        .filter(tx_input_objects::tx_sequence_number.between(min_tx, max_tx)),
-                )
-                .execute(conn)
-                .await?;
-
-                diesel::delete(
-                    tx_changed_objects::table
-                        .filter(tx_changed_objects::tx_sequence_number.between(min_tx, max_tx)),
-                )
-                .execute(conn)
-                .await?;
-
-                diesel::delete(
-                    tx_calls_pkg::table
-                        .filter(tx_calls_pkg::tx_sequence_number.between(min_tx, max_tx)),
-                )
-                .execute(conn)
-                .await?;
-
-                diesel::delete(
-                    tx_calls_mod::table
-                        .filter(tx_calls_mod::tx_sequence_number.between(min_tx, max_tx)),
-                )
-                .execute(conn)
-                .await?;
-
-                diesel::delete(
-                    tx_calls_fun::table
-                        .filter(tx_calls_fun::tx_sequence_number.between(min_tx, max_tx)),
-                )
-                .execute(conn)
-                .await?;
-
-                diesel::delete(
-                    tx_digests::table
-                        .filter(tx_digests::tx_sequence_number.between(min_tx, max_tx)),
-                )
-                .execute(conn)
-                .await?;
-
-                Ok(())
-            }
-            .scope_boxed()
-        })
-        .await
-    }
-
-    async fn prune_cp_tx_table(&self, cp: u64) -> Result<(), IndexerError> {
-        use diesel_async::RunQueryDsl;
-
-        transaction_with_retry(&self.pool, PG_DB_COMMIT_SLEEP_DURATION, |conn| {
-            async {
-                diesel::delete(
-                    pruner_cp_watermark::table
-                        .filter(pruner_cp_watermark::checkpoint_sequence_number.eq(cp as i64)),
-                )
-                .execute(conn)
-                .await
-                .map_err(IndexerError::from)
-                .context("Failed to prune pruner_cp_watermark table")?;
-                Ok(())
-            }
-            .scope_boxed()
-        })
-        .await
-    }
-
     async fn get_network_total_transactions_by_end_of_epoch(
         &self,
         epoch: u64,
@@ -2006,6 +1987,27 @@ impl IndexerStore for PgIndexerStore {
         self.persist_packages(packages).await
     }
 
+    async fn get_move_package(&self, id: ObjectID) -> Result<Option<MovePackage>, IndexerError> {
+        self.get_move_package(id).await
+    }
+
+    async fn create_custom_tables(&self, tables: &[Arc<CustomTable>]) -> Result<(), IndexerError> {
+        if tables.is_empty() {
+            return Ok(());
+        }
+        self.create_custom_tables(tables).await
+    }
+
+    async fn persist_custom_tables(
+        &self,
+        changes: Vec<CustomTableChangesToCommit>,
+    ) -> Result<(), IndexerError> {
+        if changes.is_empty() {
+            return Ok(());
+        }
+        self.persist_custom_tables(changes).await
+    }
+
     async fn persist_event_indices(&self, indices: Vec<EventIndex>) -> Result<(), IndexerError> {
         if indices.is_empty() {
             return Ok(());
@@ -2076,12 +2078,25 @@ impl IndexerStore for PgIndexerStore {
         self.persist_epoch(epoch).await
     }
 
+    async fn persist_epoch_metrics(
+        &self,
+        epoch_metrics: Vec<EpochMetricsToCommit>,
+    ) -> Result<(), IndexerError> {
+        if epoch_metrics.is_empty() {
+            return Ok(());
+        }
+        self.persist_epoch_metrics(epoch_metrics).await
+    }
+
     async fn advance_epoch(&self, epoch: EpochToCommit) -> Result<(), IndexerError> {
         self.advance_epoch(epoch).await
     }
 
+    /// An epoch's checkpoints, transactions and lookup rows, including `checkpoints`, `tx_digests`
+    /// and `pruner_cp_watermark`, are partitioned by epoch and dropped by the pruner a partition at
+    /// a time, so there is nothing left to delete here row by row.
     async fn prune_epoch(&self, epoch: u64) -> Result<(), IndexerError> {
-        let (mut min_cp, max_cp) = match self.get_checkpoint_range_for_epoch(epoch).await? {
+        let (min_cp, max_cp) = match self.get_checkpoint_range_for_epoch(epoch).await? {
             (min_cp, Some(max_cp)) => Ok((min_cp, max_cp)),
             _ => Err(IndexerError::PostgresReadError(format!(
                 "Failed to get checkpoint range for epoch {}",
@@ -2089,44 +2104,11 @@ impl IndexerStore for PgIndexerStore {
             ))),
         }?;
 
-        // NOTE: for disaster recovery, min_cp is the min cp of the current epoch, which is likely
-        // partially pruned already. min_prunable_cp is the min cp to be pruned.
-        // By std::cmp::max, we will resume the pruning process from the next checkpoint, instead of
-        // the first cp of the current epoch.
-        let min_prunable_cp = self.get_min_prunable_checkpoint().await?;
-        min_cp = std::cmp::max(min_cp, min_prunable_cp);
-        for cp in min_cp..=max_cp {
-            // NOTE: the order of pruning tables is crucial:
-            // 1. prune checkpoints table, checkpoints table is the source table of available range,
-            // we prune it first to make sure that we always have full data for checkpoints within the available range;
-            // 2. then prune tx_* tables;
-            // 3. then prune pruner_cp_watermark table, which is the checkpoint pruning watermark table and also tx seq source
-            // of a checkpoint to prune tx_* tables;
-            // 4. lastly we prune epochs table when all checkpoints of the epoch have been pruned.
-            info!(
-                "Pruning checkpoint {} of epoch {} (min_prunable_cp: {})",
-                cp, epoch, min_prunable_cp
-            );
-            self.prune_checkpoints_table(cp).await?;
-
-            let (min_tx, max_tx) = self.get_transaction_range_for_checkpoint(cp).await?;
-            self.prune_tx_indices_table(min_tx, max_tx).await?;
-            info!(
-                "Pruned transactions for checkpoint {} from tx {} to tx {}",
-                cp, min_tx, max_tx
-            );
-            self.prune_event_indices_table(min_tx, max_tx).await?;
-            info!(
-                "Pruned events of transactions for checkpoint {} from tx {} to tx {}",
-                cp, min_tx, max_tx
-            );
-            self.metrics.last_pruned_transaction.set(max_tx as i64);
-
-            self.prune_cp_tx_table(cp).await?;
-            info!("Pruned checkpoint {} of epoch {}", cp, epoch);
-            self.metrics.last_pruned_checkpoint.set(cp as i64);
-        }
-
+        self.metrics.last_pruned_checkpoint.set(max_cp as i64);
+        info!(
+            "Pruned checkpoints {} to {} of epoch {}",
+            min_cp, max_cp, epoch
+        );
         Ok(())
     }
 
@@ -2215,52 +2197,9 @@ impl IndexerStore for PgIndexerStore {
     ) -> Result<(), IndexerError> {
         use diesel_async::RunQueryDsl;
 
-        let chain_id = ChainIdentifier::from(
-            CheckpointDigest::try_from(chain_id).expect("Unable to convert chain id"),
-        );
-
-        let mut all_configs = vec![];
-        let mut all_flags = vec![];
-
         let (start_version, end_version) = self.get_protocol_version_index_range().await?;
-        info!(
-            "Persisting protocol configs with start_version: {}, end_version: {}",
-            start_version, end_version
-        );
-
-        // Gather all protocol configs and feature flags for all versions between start and end.
-        for version in start_version..=end_version {
-            let protocol_configs = ProtocolConfig::get_for_version_if_supported(
-                (version as u64).into(),
-                chain_id.chain(),
-            )
-            .ok_or(IndexerError::GenericError(format!(
-                "Unable to fetch protocol version {} and chain {:?}",
-                version,
-                chain_id.chain()
-            )))?;
-            let configs_vec = protocol_configs
-                .attr_map()
-                .into_iter()
-                .map(|(k, v)| StoredProtocolConfig {
-                    protocol_version: version,
-                    config_name: k,
-                    config_value: v.map(|v| v.to_string()),
-                })
-                .collect::<Vec<_>>();
-            all_configs.extend(configs_vec);
-
-            let feature_flags = protocol_configs
-                .feature_map()
-                .into_iter()
-                .map(|(k, v)| StoredFeatureFlag {
-                    protocol_version: version,
-                    flag_name: k,
-                    flag_value: v,
-                })
-                .collect::<Vec<_>>();
-            all_flags.extend(feature_flags);
-        }
+        let (all_configs, all_flags) =
+            protocol_configs_and_feature_flags(chain_id, start_version, end_version)?;
 
         // Now insert all of them into the db.
         // TODO: right now the size of these updates is manageable but later we may consider batching.
@@ -2332,7 +2271,60 @@ impl IndexerStore for PgIndexerStore {
     }
 }
 
-fn make_objects_history_to_commit(
+/// Gather the protocol configs and feature flags of every protocol version between `start_version`
+/// and `end_version`, inclusive.
+pub(super) fn protocol_configs_and_feature_flags(
+    chain_id: Vec<u8>,
+    start_version: i64,
+    end_version: i64,
+) -> Result<(Vec<StoredProtocolConfig>, Vec<StoredFeatureFlag>), IndexerError> {
+    let chain_id = ChainIdentifier::from(
+        CheckpointDigest::try_from(chain_id).expect("Unable to convert chain id"),
+    );
+
+    let mut all_configs = vec![];
+    let mut all_flags = vec![];
+
+    info!(
+        "Persisting protocol configs with start_version: {}, end_version: {}",
+        start_version, end_version
+    );
+
+    // Gather all protocol configs and feature flags for all versions between start and end.
+    for version in start_version..=end_version {
+        let protocol_configs =
+            ProtocolConfig::get_for_version_if_supported((version as u64).into(), chain_id.chain())
+                .ok_or(IndexerError::GenericError(format!(
+                    "Unable to fetch protocol version {} and chain {:?}",
+                    version,
+                    chain_id.chain()
+                )))?;
+        let configs_vec = protocol_configs
+            .attr_map()
+            .into_iter()
+            .map(|(k, v)| StoredProtocolConfig {
+                protocol_version: version,
+                config_name: k,
+                config_value: v.map(|v| v.to_string()),
+            })
+            .collect::<Vec<_>>();
+        all_configs.extend(configs_vec);
+
+        let feature_flags = protocol_configs
+            .feature_map()
+            .into_iter()
+            .map(|(k, v)| StoredFeatureFlag {
+                protocol_version: version,
+                flag_name: k,
+                flag_value: v,
+            })
+            .collect::<Vec<_>>();
+        all_flags.extend(feature_flags);
+    }
+    Ok((all_configs, all_flags))
+}
+
+pub(super) fn make_objects_history_to_commit(
     tx_object_changes: Vec<TransactionObjectChangesToCommit>,
 ) -> Vec<StoredHistoryObject> {
     let deleted_objects: Vec<StoredHistoryObject> = tx_object_changes
@@ -2353,7 +2345,7 @@ fn make_objects_history_to_commit(
 // within partition of mutations or deletions, retain the latest with highest version;
 // For overlappings of mutations and deletions, only keep one with higher version.
 // This is necessary b/c after this step, DB commit will be done in parallel and not in order.
-fn retain_latest_indexed_objects(
+pub(super) fn retain_latest_indexed_objects(
     tx_object_changes: Vec<TransactionObjectChangesToCommit>,
 ) -> (Vec<IndexedObject>, Vec<IndexedDeletedObject>) {
     // Only the last deleted / mutated object will be in the map,
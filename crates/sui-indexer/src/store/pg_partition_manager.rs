@@ -27,6 +27,31 @@ WHERE parent.relkind = 'p'
 GROUP BY table_name;
 ";
 
+/// Lookup tables keyed on `tx_sequence_number` that are partitioned by epoch, so that the pruner
+/// can drop an epoch's rows as a whole partition instead of deleting them one checkpoint at a time.
+/// `checkpoints` and `pruner_cp_watermark` are partitioned the same way, on their checkpoint
+/// sequence numbers, which is the default strategy.
+const TX_SEQUENCE_NUMBER_INDEX_TABLES: [&str; 18] = [
+    "tx_digests",
+    "tx_senders",
+    "tx_recipients",
+    "tx_input_objects",
+    "tx_changed_objects",
+    "tx_calls_pkg",
+    "tx_calls_mod",
+    "tx_calls_fun",
+    "tx_kinds",
+    "tx_affected_addresses",
+    "tx_affected_objects",
+    "event_emit_package",
+    "event_emit_module",
+    "event_senders",
+    "event_struct_package",
+    "event_struct_module",
+    "event_struct_name",
+    "event_struct_instantiation",
+];
+
 #[derive(Clone)]
 pub struct PgPartitionManager {
     pool: ConnectionPool,
@@ -91,6 +116,9 @@ impl PgPartitionManager {
         partition_strategies.insert("events", PgPartitionStrategy::TxSequenceNumber);
         partition_strategies.insert("transactions", PgPartitionStrategy::TxSequenceNumber);
         partition_strategies.insert("objects_version", PgPartitionStrategy::ObjectId);
+        for table in TX_SEQUENCE_NUMBER_INDEX_TABLES {
+            partition_strategies.insert(table, PgPartitionStrategy::TxSequenceNumber);
+        }
         let manager = Self {
             pool,
             partition_strategies,
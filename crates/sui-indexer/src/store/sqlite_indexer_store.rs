@@ -0,0 +1,1439 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use csv::ReaderBuilder;
+use diesel::dsl::{max, min};
+use diesel::sqlite::{Sqlite, SqliteConnection};
+use diesel::upsert::excluded;
+use diesel::{ExpressionMethods, OptionalExtension, QueryDsl};
+use diesel_async::scoped_futures::{ScopedBoxFuture, ScopedFutureExt};
+use diesel_async::sync_connection_wrapper::SyncConnectionWrapper;
+use diesel_async::{AsyncConnection, RunQueryDsl, SimpleAsyncConnection};
+use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+use strum::IntoEnumIterator;
+use sui_types::base_types::ObjectID;
+use sui_types::move_package::MovePackage;
+use tap::TapFallible;
+use tokio::sync::Mutex;
+use tracing::{info, warn};
+
+use crate::errors::{Context, IndexerError};
+use crate::handlers::custom_tables::{CustomTable, CustomTableChangesToCommit};
+use crate::handlers::{
+    CommitterWatermark, EpochMetricsToCommit, EpochToCommit, TransactionObjectChangesToCommit,
+};
+use crate::metrics::IndexerMetrics;
+use crate::models::checkpoints::{StoredChainIdentifier, StoredCheckpoint, StoredCpTx};
+use crate::models::custom_tables::StoredCustomTable;
+use crate::models::display::StoredDisplay;
+use crate::models::epoch::StoredEpochInfo;
+use crate::models::events::StoredEvent;
+use crate::models::obj_indices::StoredObjectVersion;
+use crate::models::objects::{
+    StoredDeletedObject, StoredFullHistoryObject, StoredHistoryObject, StoredObject,
+    StoredObjectSnapshot,
+};
+use crate::models::packages::StoredPackage;
+use crate::models::raw_checkpoints::StoredRawCheckpoint;
+use crate::models::transactions::StoredTransaction;
+use crate::models::watermarks::StoredWatermark;
+use crate::schema::{
+    chain_identifier, custom_tables, display, epoch_package_metrics, epoch_sender_metrics,
+    event_emit_module, event_emit_package, event_senders, event_struct_instantiation,
+    event_struct_module, event_struct_name, event_struct_package, feature_flags,
+    full_objects_history, objects, objects_history, objects_snapshot, objects_version, packages,
+    protocol_configs, pruner_cp_watermark, raw_checkpoints, tx_affected_addresses,
+    tx_affected_objects, tx_calls_fun, tx_calls_mod, tx_calls_pkg, tx_changed_objects, tx_digests,
+    tx_input_objects, tx_kinds, tx_recipients, tx_senders, watermarks,
+};
+use crate::types::{
+    EventIndex, IndexedCheckpoint, IndexedEvent, IndexedPackage, IndexedTransaction, TxIndex,
+};
+
+use super::custom_table_sql::{self, Dialect};
+use super::pg_indexer_store::{
+    make_objects_history_to_commit, protocol_configs_and_feature_flags,
+    retain_latest_indexed_objects,
+};
+use super::sqlite_schema::{
+    checkpoints, epochs, events, transactions, SqliteCheckpoint, SqliteEpochInfo, SqliteEvent,
+    SqliteTransaction,
+};
+use super::IndexerStore;
+
+const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations/sqlite");
+
+// SQLite allows a single writer at a time, so writers wait on each other rather than failing.
+const SQLITE_BUSY_TIMEOUT_MS: u64 = 5_000;
+
+type SqliteAsyncConnection = SyncConnectionWrapper<SqliteConnection>;
+
+/// Rows are written one statement at a time: statements are cheap within a SQLite transaction, and
+/// this keeps large batches clear of SQLite's limit on bound parameters per statement.
+macro_rules! insert_or_ignore {
+    ($conn:expr, $table:expr, $rows:expr, $context:expr) => {
+        for row in $rows {
+            diesel::insert_or_ignore_into($table)
+                .values(row)
+                .execute($conn)
+                .await
+                .map_err(IndexerError::from)
+                .context($context)?;
+        }
+    };
+}
+
+/// Like `insert_or_ignore!`, but replaces the existing row with the same key.
+macro_rules! replace {
+    ($conn:expr, $table:expr, $rows:expr, $context:expr) => {
+        for row in $rows {
+            diesel::replace_into($table)
+                .values(row)
+                .execute($conn)
+                .await
+                .map_err(IndexerError::from)
+                .context($context)?;
+        }
+    };
+}
+
+/// Deletes the rows of each table whose `tx_sequence_number` is in `[$lo, $hi]`.
+macro_rules! delete_tx_range {
+    ($conn:expr, $lo:expr, $hi:expr, $($table:ident),+ $(,)?) => {
+        $(
+            diesel::delete($table::table.filter($table::tx_sequence_number.between($lo, $hi)))
+                .execute($conn)
+                .await
+                .map_err(IndexerError::from)
+                .context(concat!("Failed to prune ", stringify!($table), " table"))?;
+        )+
+    };
+}
+
+/// An `IndexerStore` backed by a single SQLite database file, for running the indexer locally
+/// without a Postgres instance. Tables are not partitioned, and all reads and writes share one
+/// connection.
+#[derive(Clone)]
+pub struct SqliteIndexerStore {
+    connection: Arc<Mutex<SqliteAsyncConnection>>,
+    metrics: IndexerMetrics,
+}
+
+impl SqliteIndexerStore {
+    /// Opens the SQLite database at `database_url` (a file path, created if it does not exist)
+    /// and brings its schema up to date.
+    pub async fn new(database_url: &str, metrics: IndexerMetrics) -> Result<Self, IndexerError> {
+        let url = database_url.to_owned();
+        tokio::task::spawn_blocking(move || {
+            let mut connection = <SqliteConnection as diesel::Connection>::establish(&url)
+                .map_err(|e| IndexerError::SqliteConnectionError(e.to_string()))?;
+            connection
+                .run_pending_migrations(MIGRATIONS)
+                .map(|_| ())
+                .map_err(|e| IndexerError::SqliteConnectionError(e.to_string()))
+        })
+        .await
+        .map_err(|e| IndexerError::SqliteConnectionError(e.to_string()))??;
+
+        let mut connection = SqliteAsyncConnection::establish(database_url)
+            .await
+            .map_err(|e| IndexerError::SqliteConnectionError(e.to_string()))?;
+        connection
+            .batch_execute(&format!(
+                "PRAGMA journal_mode = WAL; PRAGMA busy_timeout = {SQLITE_BUSY_TIMEOUT_MS};"
+            ))
+            .await
+            .map_err(|e| IndexerError::SqliteConnectionError(e.to_string()))?;
+
+        Ok(Self {
+            connection: Arc::new(Mutex::new(connection)),
+            metrics,
+        })
+    }
+
+    async fn transaction<'a, F, T>(&self, query: F) -> Result<T, IndexerError>
+    where
+        F: for<'r> FnOnce(
+                &'r mut SqliteAsyncConnection,
+            ) -> ScopedBoxFuture<'a, 'r, Result<T, IndexerError>>
+            + Send
+            + 'a,
+        T: Send + 'a,
+    {
+        let mut connection = self.connection.lock().await;
+        connection.transaction(query).await
+    }
+
+    async fn get_protocol_version_index_range(&self) -> Result<(i64, i64), IndexerError> {
+        let mut connection = self.connection.lock().await;
+        let start = protocol_configs::table
+            .select(max(protocol_configs::protocol_version))
+            .first::<Option<i64>>(&mut *connection)
+            .await
+            .map_err(Into::into)
+            .context("Failed reading latest protocol version from SQLite")?
+            .map_or(1, |v| v + 1);
+
+        let end = epochs::table
+            .select(max(epochs::protocol_version))
+            .first::<Option<i64>>(&mut *connection)
+            .await
+            .map_err(Into::into)
+            .context("Failed reading latest epoch protocol version from SQLite")?
+            .unwrap_or(1);
+        Ok((start, end))
+    }
+
+    async fn get_checkpoint_range_for_epoch(
+        &self,
+        epoch: u64,
+    ) -> Result<(u64, Option<u64>), IndexerError> {
+        let mut connection = self.connection.lock().await;
+        epochs::table
+            .select((epochs::first_checkpoint_id, epochs::last_checkpoint_id))
+            .filter(epochs::epoch.eq(epoch as i64))
+            .first::<(i64, Option<i64>)>(&mut *connection)
+            .await
+            .map_err(Into::into)
+            .map(|(min, max)| (min as u64, max.map(|v| v as u64)))
+            .context("Failed reading checkpoint range from SQLite")
+    }
+
+    async fn persist_display_updates(
+        &self,
+        display_updates: Vec<StoredDisplay>,
+    ) -> Result<(), IndexerError> {
+        self.transaction(move |conn| {
+            async move {
+                replace!(
+                    conn,
+                    display::table,
+                    &display_updates,
+                    "Failed to write to display table"
+                );
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    // Reads, for local tooling and tests. Rows are returned as the shared `Stored*` models, so
+    // they convert to RPC and GraphQL types exactly as rows read from Postgres do.
+
+    pub async fn get_checkpoint(
+        &self,
+        sequence_number: u64,
+    ) -> Result<Option<StoredCheckpoint>, IndexerError> {
+        let mut connection = self.connection.lock().await;
+        checkpoints::table
+            .filter(checkpoints::sequence_number.eq(sequence_number as i64))
+            .first::<SqliteCheckpoint>(&mut *connection)
+            .await
+            .optional()
+            .map_err(Into::into)
+            .context("Failed reading checkpoint from SQLite")?
+            .map(StoredCheckpoint::try_from)
+            .transpose()
+    }
+
+    pub async fn get_latest_checkpoint(&self) -> Result<Option<StoredCheckpoint>, IndexerError> {
+        let mut connection = self.connection.lock().await;
+        checkpoints::table
+            .order(checkpoints::sequence_number.desc())
+            .first::<SqliteCheckpoint>(&mut *connection)
+            .await
+            .optional()
+            .map_err(Into::into)
+            .context("Failed reading latest checkpoint from SQLite")?
+            .map(StoredCheckpoint::try_from)
+            .transpose()
+    }
+
+    pub async fn get_transaction(
+        &self,
+        digest: &[u8],
+    ) -> Result<Option<StoredTransaction>, IndexerError> {
+        let mut connection = self.connection.lock().await;
+        transactions::table
+            .filter(transactions::transaction_digest.eq(digest))
+            .first::<SqliteTransaction>(&mut *connection)
+            .await
+            .optional()
+            .map_err(Into::into)
+            .context("Failed reading transaction from SQLite")?
+            .map(StoredTransaction::try_from)
+            .transpose()
+    }
+
+    pub async fn get_events(
+        &self,
+        tx_sequence_number: u64,
+    ) -> Result<Vec<StoredEvent>, IndexerError> {
+        let mut connection = self.connection.lock().await;
+        events::table
+            .filter(events::tx_sequence_number.eq(tx_sequence_number as i64))
+            .order(events::event_sequence_number.asc())
+            .load::<SqliteEvent>(&mut *connection)
+            .await
+            .map_err(Into::into)
+            .context("Failed reading events from SQLite")?
+            .into_iter()
+            .map(StoredEvent::try_from)
+            .collect()
+    }
+
+    pub async fn get_object(&self, object_id: &[u8]) -> Result<Option<StoredObject>, IndexerError> {
+        let mut connection = self.connection.lock().await;
+        objects::table
+            .filter(objects::object_id.eq(object_id))
+            .first::<StoredObject>(&mut *connection)
+            .await
+            .optional()
+            .map_err(Into::into)
+            .context("Failed reading object from SQLite")
+    }
+
+    pub async fn get_object_snapshot(
+        &self,
+        object_id: &[u8],
+        checkpoint_sequence_number: u64,
+    ) -> Result<Option<StoredObjectSnapshot>, IndexerError> {
+        let mut connection = self.connection.lock().await;
+        objects_snapshot::table
+            .filter(objects_snapshot::object_id.eq(object_id))
+            .filter(
+                objects_snapshot::checkpoint_sequence_number.eq(checkpoint_sequence_number as i64),
+            )
+            .first::<StoredObjectSnapshot>(&mut *connection)
+            .await
+            .optional()
+            .map_err(Into::into)
+            .context("Failed reading object snapshot from SQLite")
+    }
+
+    pub async fn get_max_object_snapshot_checkpoint(&self) -> Result<Option<u64>, IndexerError> {
+        let mut connection = self.connection.lock().await;
+        objects_snapshot::table
+            .select(max(objects_snapshot::checkpoint_sequence_number))
+            .first::<Option<i64>>(&mut *connection)
+            .await
+            .map_err(Into::into)
+            .map(|v| v.map(|v| v as u64))
+            .context("Failed reading max objects snapshot checkpoint from SQLite")
+    }
+
+    pub async fn get_object_history(
+        &self,
+        object_id: &[u8],
+        object_version: u64,
+    ) -> Result<Option<StoredHistoryObject>, IndexerError> {
+        let mut connection = self.connection.lock().await;
+        objects_history::table
+            .filter(objects_history::object_id.eq(object_id))
+            .filter(objects_history::object_version.eq(object_version as i64))
+            .first::<StoredHistoryObject>(&mut *connection)
+            .await
+            .optional()
+            .map_err(Into::into)
+            .context("Failed reading object history from SQLite")
+    }
+
+    pub async fn get_package(
+        &self,
+        package_id: &[u8],
+    ) -> Result<Option<StoredPackage>, IndexerError> {
+        let mut connection = self.connection.lock().await;
+        packages::table
+            .filter(packages::package_id.eq(package_id))
+            .first::<StoredPackage>(&mut *connection)
+            .await
+            .optional()
+            .map_err(Into::into)
+            .context("Failed reading package from SQLite")
+    }
+
+    pub async fn get_epoch(&self, epoch: u64) -> Result<Option<StoredEpochInfo>, IndexerError> {
+        let mut connection = self.connection.lock().await;
+        epochs::table
+            .filter(epochs::epoch.eq(epoch as i64))
+            .first::<SqliteEpochInfo>(&mut *connection)
+            .await
+            .optional()
+            .map_err(Into::into)
+            .context("Failed reading epoch from SQLite")?
+            .map(StoredEpochInfo::try_from)
+            .transpose()
+    }
+}
+
+#[async_trait]
+impl IndexerStore for SqliteIndexerStore {
+    async fn get_latest_checkpoint_sequence_number(&self) -> Result<Option<u64>, IndexerError> {
+        let mut connection = self.connection.lock().await;
+        checkpoints::table
+            .select(max(checkpoints::sequence_number))
+            .first::<Option<i64>>(&mut *connection)
+            .await
+            .map_err(Into::into)
+            .map(|v| v.map(|v| v as u64))
+            .context("Failed reading latest checkpoint sequence number from SQLite")
+    }
+
+    async fn get_available_epoch_range(&self) -> Result<(u64, u64), IndexerError> {
+        let mut connection = self.connection.lock().await;
+        epochs::table
+            .select((min(epochs::epoch), max(epochs::epoch)))
+            .first::<(Option<i64>, Option<i64>)>(&mut *connection)
+            .await
+            .map_err(Into::into)
+            .map(|(min, max)| {
+                (
+                    min.unwrap_or_default() as u64,
+                    max.unwrap_or_default() as u64,
+                )
+            })
+            .context("Failed reading min and max epoch numbers from SQLite")
+    }
+
+    async fn get_available_checkpoint_range(&self) -> Result<(u64, u64), IndexerError> {
+        let mut connection = self.connection.lock().await;
+        checkpoints::table
+            .select((
+                min(checkpoints::sequence_number),
+                max(checkpoints::sequence_number),
+            ))
+            .first::<(Option<i64>, Option<i64>)>(&mut *connection)
+            .await
+            .map_err(Into::into)
+            .map(|(min, max)| {
+                (
+                    min.unwrap_or_default() as u64,
+                    max.unwrap_or_default() as u64,
+                )
+            })
+            .context("Failed reading min and max checkpoint sequence numbers from SQLite")
+    }
+
+    async fn get_latest_object_snapshot_checkpoint_sequence_number(
+        &self,
+    ) -> Result<Option<u64>, IndexerError> {
+        let mut connection = self.connection.lock().await;
+        watermarks::table
+            .select(watermarks::checkpoint_hi_inclusive)
+            .filter(watermarks::entity.eq("objects_snapshot"))
+            .first::<i64>(&mut *connection)
+            .await
+            // Handle case where the watermark is not set yet
+            .optional()
+            .map_err(Into::into)
+            .map(|v| v.map(|v| v as u64))
+            .context("Failed reading latest object snapshot checkpoint sequence number from SQLite")
+    }
+
+    async fn get_chain_identifier(&self) -> Result<Option<Vec<u8>>, IndexerError> {
+        let mut connection = self.connection.lock().await;
+        chain_identifier::table
+            .select(chain_identifier::checkpoint_digest)
+            .first::<Vec<u8>>(&mut *connection)
+            .await
+            .optional()
+            .map_err(Into::into)
+            .context("Failed reading chain id from SQLite")
+    }
+
+    async fn persist_protocol_configs_and_feature_flags(
+        &self,
+        chain_id: Vec<u8>,
+    ) -> Result<(), IndexerError> {
+        let (start_version, end_version) = self.get_protocol_version_index_range().await?;
+        let (all_configs, all_flags) =
+            protocol_configs_and_feature_flags(chain_id, start_version, end_version)?;
+
+        self.transaction(move |conn| {
+            async move {
+                insert_or_ignore!(
+                    conn,
+                    protocol_configs::table,
+                    &all_configs,
+                    "Failed to write to protocol_configs table"
+                );
+                insert_or_ignore!(
+                    conn,
+                    feature_flags::table,
+                    &all_flags,
+                    "Failed to write to feature_flags table"
+                );
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    async fn persist_objects(
+        &self,
+        object_changes: Vec<TransactionObjectChangesToCommit>,
+    ) -> Result<(), IndexerError> {
+        if object_changes.is_empty() {
+            return Ok(());
+        }
+        let guard = self
+            .metrics
+            .checkpoint_db_commit_latency_objects
+            .start_timer();
+        let (indexed_mutations, indexed_deletions) = retain_latest_indexed_objects(object_changes);
+        let mutations = indexed_mutations
+            .into_iter()
+            .map(StoredObject::from)
+            .collect::<Vec<_>>();
+        let deletions = indexed_deletions
+            .into_iter()
+            .map(StoredDeletedObject::from)
+            .collect::<Vec<_>>();
+        let (mutation_len, deletion_len) = (mutations.len(), deletions.len());
+
+        self.transaction(move |conn| {
+            async move {
+                replace!(
+                    conn,
+                    objects::table,
+                    &mutations,
+                    "Failed to write to objects table"
+                );
+                for deleted in &deletions {
+                    diesel::delete(
+                        objects::table.filter(objects::object_id.eq(&deleted.object_id)),
+                    )
+                    .execute(conn)
+                    .await
+                    .map_err(IndexerError::from)
+                    .context("Failed to write object deletion to SQLite")?;
+                }
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+        .tap_ok(|_| {
+            let elapsed = guard.stop_and_record();
+            info!(
+                elapsed,
+                "Persisted {} objects mutations and {} deletions", mutation_len, deletion_len
+            );
+        })
+        .tap_err(|e| tracing::error!("Failed to persist objects with error: {}", e))
+    }
+
+    async fn persist_object_history(
+        &self,
+        object_changes: Vec<TransactionObjectChangesToCommit>,
+    ) -> Result<(), IndexerError> {
+        if object_changes.is_empty() {
+            return Ok(());
+        }
+        let guard = self
+            .metrics
+            .checkpoint_db_commit_latency_objects_history
+            .start_timer();
+        let objects = make_objects_history_to_commit(object_changes);
+        let len = objects.len();
+
+        self.transaction(move |conn| {
+            async move {
+                insert_or_ignore!(
+                    conn,
+                    objects_history::table,
+                    &objects,
+                    "Failed to write to objects_history table"
+                );
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await?;
+
+        let elapsed = guard.stop_and_record();
+        info!(elapsed, "Persisted {} objects history", len);
+        Ok(())
+    }
+
+    async fn persist_full_objects_history(
+        &self,
+        object_changes: Vec<TransactionObjectChangesToCommit>,
+    ) -> Result<(), IndexerError> {
+        if object_changes.is_empty() {
+            return Ok(());
+        }
+        let guard = self
+            .metrics
+            .checkpoint_db_commit_latency_full_objects_history
+            .start_timer();
+        let objects: Vec<StoredFullHistoryObject> = object_changes
+            .into_iter()
+            .flat_map(|c| {
+                let TransactionObjectChangesToCommit {
+                    changed_objects,
+                    deleted_objects,
+                } = c;
+                changed_objects
+                    .into_iter()
+                    .map(|o| o.into())
+                    .chain(deleted_objects.into_iter().map(|o| o.into()))
+            })
+            .collect();
+        let len = objects.len();
+
+        self.transaction(move |conn| {
+            async move {
+                insert_or_ignore!(
+                    conn,
+                    full_objects_history::table,
+                    &objects,
+                    "Failed to write to full_objects_history table"
+                );
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await?;
+
+        let elapsed = guard.stop_and_record();
+        info!(elapsed, "Persisted {} full objects history", len);
+        Ok(())
+    }
+
+    async fn persist_objects_version(
+        &self,
+        object_versions: Vec<StoredObjectVersion>,
+    ) -> Result<(), IndexerError> {
+        if object_versions.is_empty() {
+            return Ok(());
+        }
+        let guard = self
+            .metrics
+            .checkpoint_db_commit_latency_objects_version
+            .start_timer();
+        let len = object_versions.len();
+
+        self.transaction(move |conn| {
+            async move {
+                insert_or_ignore!(
+                    conn,
+                    objects_version::table,
+                    &object_versions,
+                    "Failed to write to objects_version table"
+                );
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await?;
+
+        let elapsed = guard.stop_and_record();
+        info!(elapsed, "Persisted {} object versions", len);
+        Ok(())
+    }
+
+    async fn persist_objects_snapshot(
+        &self,
+        object_changes: Vec<TransactionObjectChangesToCommit>,
+    ) -> Result<(), IndexerError> {
+        if object_changes.is_empty() {
+            return Ok(());
+        }
+        let guard = self
+            .metrics
+            .checkpoint_db_commit_latency_objects_snapshot
+            .start_timer();
+        let (indexed_mutations, indexed_deletions) = retain_latest_indexed_objects(object_changes);
+        let mutations = indexed_mutations
+            .into_iter()
+            .map(StoredObjectSnapshot::from)
+            .collect::<Vec<_>>();
+        let deletions = indexed_deletions
+            .into_iter()
+            .map(StoredObjectSnapshot::from)
+            .collect::<Vec<_>>();
+        let (mutation_len, deletion_len) = (mutations.len(), deletions.len());
+
+        self.transaction(move |conn| {
+            async move {
+                replace!(
+                    conn,
+                    objects_snapshot::table,
+                    &mutations,
+                    "Failed to write to objects_snapshot table"
+                );
+                for deleted in &deletions {
+                    diesel::delete(
+                        objects_snapshot::table
+                            .filter(objects_snapshot::object_id.eq(&deleted.object_id)),
+                    )
+                    .execute(conn)
+                    .await
+                    .map_err(IndexerError::from)
+                    .context("Failed to write object snapshot deletion to SQLite")?;
+                }
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+        .tap_ok(|_| {
+            let elapsed = guard.stop_and_record();
+            info!(
+                elapsed,
+                "Persisted {} objects snapshot mutations and {} deletions",
+                mutation_len,
+                deletion_len
+            );
+        })
+        .tap_err(|e| tracing::error!("Failed to persist object snapshot with error: {}", e))
+    }
+
+    async fn persist_checkpoints(
+        &self,
+        checkpoints: Vec<IndexedCheckpoint>,
+    ) -> Result<(), IndexerError> {
+        let Some(first_checkpoint) = checkpoints.as_slice().first() else {
+            return Ok(());
+        };
+
+        // If the first checkpoint has sequence number 0, we need to persist the digest as
+        // chain identifier.
+        if first_checkpoint.sequence_number == 0 {
+            let checkpoint_digest = first_checkpoint.checkpoint_digest.into_inner().to_vec();
+            self.persist_protocol_configs_and_feature_flags(checkpoint_digest.clone())
+                .await?;
+            self.persist_chain_identifier(checkpoint_digest).await?;
+        }
+
+        let guard = self
+            .metrics
+            .checkpoint_db_commit_latency_checkpoints
+            .start_timer();
+        let stored_cp_txs = checkpoints.iter().map(StoredCpTx::from).collect::<Vec<_>>();
+        let stored_checkpoints = checkpoints
+            .iter()
+            .map(StoredCheckpoint::from)
+            .collect::<Vec<_>>();
+        let rows = stored_checkpoints
+            .iter()
+            .cloned()
+            .map(SqliteCheckpoint::from)
+            .collect::<Vec<_>>();
+
+        self.transaction(move |conn| {
+            async move {
+                insert_or_ignore!(
+                    conn,
+                    pruner_cp_watermark::table,
+                    &stored_cp_txs,
+                    "Failed to write to pruner_cp_watermark table"
+                );
+                insert_or_ignore!(
+                    conn,
+                    checkpoints::table,
+                    &rows,
+                    "Failed to write to checkpoints table"
+                );
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+        .tap_err(|e| tracing::error!("Failed to persist checkpoints with error: {}", e))?;
+
+        let time_now_ms = chrono::Utc::now().timestamp_millis();
+        for stored_checkpoint in &stored_checkpoints {
+            self.metrics
+                .db_commit_lag_ms
+                .set(time_now_ms - stored_checkpoint.timestamp_ms);
+            self.metrics
+                .max_committed_checkpoint_sequence_number
+                .set(stored_checkpoint.sequence_number);
+            self.metrics
+                .committed_checkpoint_timestamp_ms
+                .set(stored_checkpoint.timestamp_ms);
+        }
+
+        let elapsed = guard.stop_and_record();
+        info!(
+            elapsed,
+            "Persisted {} checkpoints",
+            stored_checkpoints.len()
+        );
+        Ok(())
+    }
+
+    async fn persist_chain_identifier(
+        &self,
+        checkpoint_digest: Vec<u8>,
+    ) -> Result<(), IndexerError> {
+        self.transaction(move |conn| {
+            async move {
+                diesel::insert_or_ignore_into(chain_identifier::table)
+                    .values(StoredChainIdentifier { checkpoint_digest })
+                    .execute(conn)
+                    .await
+                    .map_err(IndexerError::from)
+                    .context("failed to write to chain_identifier table")?;
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    async fn persist_transactions(
+        &self,
+        transactions: Vec<IndexedTransaction>,
+    ) -> Result<(), IndexerError> {
+        let guard = self
+            .metrics
+            .checkpoint_db_commit_latency_transactions
+            .start_timer();
+        let len = transactions.len();
+        let rows = transactions
+            .iter()
+            .map(|t| SqliteTransaction::from(StoredTransaction::from(t)))
+            .collect::<Vec<_>>();
+
+        self.transaction(move |conn| {
+            async move {
+                insert_or_ignore!(
+                    conn,
+                    transactions::table,
+                    &rows,
+                    "Failed to write to transactions table"
+                );
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await?;
+
+        let elapsed = guard.stop_and_record();
+        info!(elapsed, "Persisted {} transactions", len);
+        Ok(())
+    }
+
+    async fn persist_tx_indices(&self, indices: Vec<TxIndex>) -> Result<(), IndexerError> {
+        if indices.is_empty() {
+            return Ok(());
+        }
+        let guard = self
+            .metrics
+            .checkpoint_db_commit_latency_tx_indices
+            .start_timer();
+        let len = indices.len();
+
+        self.transaction(move |conn| {
+            async move {
+                for index in indices {
+                    let (
+                        affected_addresses,
+                        affected_objects,
+                        senders,
+                        recipients,
+                        input_objects,
+                        changed_objects,
+                        pkgs,
+                        mods,
+                        funs,
+                        digests,
+                        kinds,
+                    ) = index.split();
+                    let context = "Failed to write to tx indices tables";
+                    insert_or_ignore!(
+                        conn,
+                        tx_affected_addresses::table,
+                        &affected_addresses,
+                        context
+                    );
+                    insert_or_ignore!(conn, tx_affected_objects::table, &affected_objects, context);
+                    insert_or_ignore!(conn, tx_senders::table, &senders, context);
+                    insert_or_ignore!(conn, tx_recipients::table, &recipients, context);
+                    insert_or_ignore!(conn, tx_input_objects::table, &input_objects, context);
+                    insert_or_ignore!(conn, tx_changed_objects::table, &changed_objects, context);
+                    insert_or_ignore!(conn, tx_calls_pkg::table, &pkgs, context);
+                    insert_or_ignore!(conn, tx_calls_mod::table, &mods, context);
+                    insert_or_ignore!(conn, tx_calls_fun::table, &funs, context);
+                    insert_or_ignore!(conn, tx_digests::table, &digests, context);
+                    insert_or_ignore!(conn, tx_kinds::table, &kinds, context);
+                }
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await?;
+
+        let elapsed = guard.stop_and_record();
+        info!(elapsed, "Persisted {} tx_indices", len);
+        Ok(())
+    }
+
+    async fn persist_events(&self, events: Vec<IndexedEvent>) -> Result<(), IndexerError> {
+        if events.is_empty() {
+            return Ok(());
+        }
+        let guard = self
+            .metrics
+            .checkpoint_db_commit_latency_events
+            .start_timer();
+        let len = events.len();
+        let rows = events
+            .into_iter()
+            .map(|e| SqliteEvent::from(StoredEvent::from(e)))
+            .collect::<Vec<_>>();
+
+        self.transaction(move |conn| {
+            async move {
+                insert_or_ignore!(
+                    conn,
+                    events::table,
+                    &rows,
+                    "Failed to write to events table"
+                );
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await?;
+
+        let elapsed = guard.stop_and_record();
+        info!(elapsed, "Persisted {} events", len);
+        Ok(())
+    }
+
+    async fn persist_event_indices(
+        &self,
+        event_indices: Vec<EventIndex>,
+    ) -> Result<(), IndexerError> {
+        if event_indices.is_empty() {
+            return Ok(());
+        }
+        let guard = self
+            .metrics
+            .checkpoint_db_commit_latency_event_indices
+            .start_timer();
+        let len = event_indices.len();
+
+        self.transaction(move |conn| {
+            async move {
+                for index in event_indices {
+                    let (
+                        emit_package,
+                        emit_module,
+                        sender,
+                        struct_package,
+                        struct_module,
+                        struct_name,
+                        struct_instantiation,
+                    ) = index.split();
+                    let context = "Failed to write to event indices tables";
+                    insert_or_ignore!(conn, event_emit_package::table, [emit_package], context);
+                    insert_or_ignore!(conn, event_emit_module::table, [emit_module], context);
+                    insert_or_ignore!(conn, event_senders::table, [sender], context);
+                    insert_or_ignore!(conn, event_struct_package::table, [struct_package], context);
+                    insert_or_ignore!(conn, event_struct_module::table, [struct_module], context);
+                    insert_or_ignore!(conn, event_struct_name::table, [struct_name], context);
+                    insert_or_ignore!(
+                        conn,
+                        event_struct_instantiation::table,
+                        [struct_instantiation],
+                        context
+                    );
+                }
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await?;
+
+        let elapsed = guard.stop_and_record();
+        info!(elapsed, "Persisted {} event_indices", len);
+        Ok(())
+    }
+
+    async fn persist_displays(
+        &self,
+        display_updates: BTreeMap<String, StoredDisplay>,
+    ) -> Result<(), IndexerError> {
+        if display_updates.is_empty() {
+            return Ok(());
+        }
+        self.persist_display_updates(display_updates.into_values().collect())
+            .await
+    }
+
+    async fn persist_packages(&self, packages: Vec<IndexedPackage>) -> Result<(), IndexerError> {
+        if packages.is_empty() {
+            return Ok(());
+        }
+        let guard = self
+            .metrics
+            .checkpoint_db_commit_latency_packages
+            .start_timer();
+        let packages = packages
+            .into_iter()
+            .map(StoredPackage::from)
+            .collect::<Vec<_>>();
+        let len = packages.len();
+
+        self.transaction(move |conn| {
+            async move {
+                replace!(
+                    conn,
+                    packages::table,
+                    &packages,
+                    "Failed to write to packages table"
+                );
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await?;
+
+        let elapsed = guard.stop_and_record();
+        info!(elapsed, "Persisted {} packages", len);
+        Ok(())
+    }
+
+    async fn get_move_package(&self, id: ObjectID) -> Result<Option<MovePackage>, IndexerError> {
+        let Some(package) = self.get_package(&id.to_vec()).await? else {
+            return Ok(None);
+        };
+
+        Ok(Some(bcs::from_bytes(&package.move_package)?))
+    }
+
+    async fn create_custom_tables(&self, tables: &[Arc<CustomTable>]) -> Result<(), IndexerError> {
+        if tables.is_empty() {
+            return Ok(());
+        }
+
+        let registered = {
+            let mut connection = self.connection.lock().await;
+            custom_tables::table
+                .load::<StoredCustomTable>(&mut *connection)
+                .await
+                .map_err(Into::into)
+                .context("Failed reading custom_tables from SQLite")?
+        };
+
+        let tables = custom_table_sql::tables_to_create(Dialect::Sqlite, tables, registered)?;
+        let len = tables.len();
+        self.transaction(move |conn| {
+            async move {
+                for (statements, _) in &tables {
+                    for statement in statements {
+                        diesel::sql_query(statement)
+                            .execute(conn)
+                            .await
+                            .map_err(IndexerError::from)
+                            .context("Failed to create custom table")?;
+                    }
+                }
+
+                insert_or_ignore!(
+                    conn,
+                    custom_tables::table,
+                    tables.iter().map(|(_, stored)| stored),
+                    "Failed to write to custom_tables table"
+                );
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await?;
+
+        info!("Created {} custom tables", len);
+        Ok(())
+    }
+
+    async fn persist_custom_tables(
+        &self,
+        changes: Vec<CustomTableChangesToCommit>,
+    ) -> Result<(), IndexerError> {
+        if changes.is_empty() {
+            return Ok(());
+        }
+        let guard = self
+            .metrics
+            .checkpoint_db_commit_latency_custom_tables
+            .start_timer();
+        let statements = custom_table_sql::write_changes(Dialect::Sqlite, changes);
+        let len = statements.len();
+
+        self.transaction(move |conn| {
+            async move {
+                for statement in statements {
+                    statement
+                        .into_query::<Sqlite>()
+                        .execute(conn)
+                        .await
+                        .map_err(IndexerError::from)
+                        .context("Failed to write to custom table")?;
+                }
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await?;
+
+        let elapsed = guard.stop_and_record();
+        info!(elapsed, "Persisted custom tables in {} statements", len);
+        Ok(())
+    }
+
+    async fn persist_epoch(&self, epoch: EpochToCommit) -> Result<(), IndexerError> {
+        let guard = self
+            .metrics
+            .checkpoint_db_commit_latency_epoch
+            .start_timer();
+        let epoch_id = epoch.new_epoch.epoch;
+
+        self.transaction(move |conn| {
+            async move {
+                if let Some(last_epoch) = &epoch.last_epoch {
+                    // See `PgIndexerStore::persist_epoch`: the epoch's transaction count is only
+                    // known once the checkpoints of the epoch before it have been persisted.
+                    let previous_epoch_network_total_transactions = match epoch_id {
+                        0 | 1 => 0,
+                        _ => checkpoints::table
+                            .filter(checkpoints::epoch.eq((epoch_id - 2) as i64))
+                            .select(max(checkpoints::network_total_transactions))
+                            .first::<Option<i64>>(conn)
+                            .await?
+                            .unwrap_or(0) as u64,
+                    };
+
+                    let mut last_epoch = StoredEpochInfo::from_epoch_end_info(last_epoch);
+                    last_epoch.epoch_total_transactions = Some(
+                        (epoch.network_total_transactions
+                            - previous_epoch_network_total_transactions)
+                            as i64,
+                    );
+                    diesel::insert_into(epochs::table)
+                        .values(SqliteEpochInfo::from(last_epoch))
+                        .on_conflict(epochs::epoch)
+                        .do_update()
+                        .set((
+                            epochs::system_state.eq(excluded(epochs::system_state)),
+                            epochs::epoch_total_transactions
+                                .eq(excluded(epochs::epoch_total_transactions)),
+                            epochs::last_checkpoint_id.eq(excluded(epochs::last_checkpoint_id)),
+                            epochs::epoch_end_timestamp.eq(excluded(epochs::epoch_end_timestamp)),
+                            epochs::storage_fund_reinvestment
+                                .eq(excluded(epochs::storage_fund_reinvestment)),
+                            epochs::storage_charge.eq(excluded(epochs::storage_charge)),
+                            epochs::storage_rebate.eq(excluded(epochs::storage_rebate)),
+                            epochs::stake_subsidy_amount.eq(excluded(epochs::stake_subsidy_amount)),
+                            epochs::total_gas_fees.eq(excluded(epochs::total_gas_fees)),
+                            epochs::total_stake_rewards_distributed
+                                .eq(excluded(epochs::total_stake_rewards_distributed)),
+                            epochs::leftover_storage_fund_inflow
+                                .eq(excluded(epochs::leftover_storage_fund_inflow)),
+                            epochs::epoch_commitments.eq(excluded(epochs::epoch_commitments)),
+                        ))
+                        .execute(conn)
+                        .await
+                        .map_err(IndexerError::from)
+                        .context("Failed to write epoch end data to epochs table")?;
+                }
+
+                let new_epoch = StoredEpochInfo::from_epoch_beginning_info(&epoch.new_epoch);
+                diesel::insert_or_ignore_into(epochs::table)
+                    .values(SqliteEpochInfo::from(new_epoch))
+                    .execute(conn)
+                    .await
+                    .map_err(IndexerError::from)
+                    .context("Failed to write to epochs table")?;
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+        .tap_ok(|_| {
+            let elapsed = guard.stop_and_record();
+            info!(elapsed, epoch_id, "Persisted epoch beginning info");
+        })
+        .tap_err(|e| tracing::error!("Failed to persist epoch with error: {}", e))
+    }
+
+    async fn persist_epoch_metrics(
+        &self,
+        epoch_metrics: Vec<EpochMetricsToCommit>,
+    ) -> Result<(), IndexerError> {
+        if epoch_metrics.is_empty() {
+            return Ok(());
+        }
+        let guard = self
+            .metrics
+            .checkpoint_db_commit_latency_epoch_metrics
+            .start_timer();
+        let len = epoch_metrics.len();
+
+        self.transaction(move |conn| {
+            async move {
+                // As in Postgres, the checkpoint watermark on each row skips contributions that
+                // have already been folded in.
+                for metrics in &epoch_metrics {
+                    for row in &metrics.package_metrics {
+                        use epoch_package_metrics as p;
+                        diesel::insert_into(p::table)
+                            .values(row)
+                            .on_conflict((p::epoch, p::package))
+                            .do_update()
+                            .set((
+                                p::move_calls.eq(p::move_calls + excluded(p::move_calls)),
+                                p::transactions.eq(p::transactions + excluded(p::transactions)),
+                                p::gas_spent.eq(p::gas_spent + excluded(p::gas_spent)),
+                                p::checkpoint_sequence_number
+                                    .eq(excluded(p::checkpoint_sequence_number)),
+                            ))
+                            .filter(
+                                p::checkpoint_sequence_number
+                                    .lt(excluded(p::checkpoint_sequence_number)),
+                            )
+                            .execute(conn)
+                            .await
+                            .map_err(IndexerError::from)
+                            .context("Failed to write to epoch_package_metrics table")?;
+                    }
+
+                    for row in &metrics.sender_metrics {
+                        use epoch_sender_metrics as s;
+                        diesel::insert_into(s::table)
+                            .values(row)
+                            .on_conflict((s::epoch, s::sender))
+                            .do_update()
+                            .set((
+                                s::transactions.eq(s::transactions + excluded(s::transactions)),
+                                s::gas_spent.eq(s::gas_spent + excluded(s::gas_spent)),
+                                s::checkpoint_sequence_number
+                                    .eq(excluded(s::checkpoint_sequence_number)),
+                            ))
+                            .filter(
+                                s::checkpoint_sequence_number
+                                    .lt(excluded(s::checkpoint_sequence_number)),
+                            )
+                            .execute(conn)
+                            .await
+                            .map_err(IndexerError::from)
+                            .context("Failed to write to epoch_sender_metrics table")?;
+                    }
+                }
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await?;
+
+        let elapsed = guard.stop_and_record();
+        info!(elapsed, "Persisted epoch metrics for {len} checkpoints");
+        Ok(())
+    }
+
+    /// SQLite tables are not partitioned, so there is nothing to do at an epoch boundary.
+    async fn advance_epoch(&self, _epoch: EpochToCommit) -> Result<(), IndexerError> {
+        Ok(())
+    }
+
+    /// Deletes the epoch's checkpoints, and the transactions, events and lookup rows in them,
+    /// row by row.
+    async fn prune_epoch(&self, epoch: u64) -> Result<(), IndexerError> {
+        let (min_cp, max_cp) = match self.get_checkpoint_range_for_epoch(epoch).await? {
+            (min_cp, Some(max_cp)) => (min_cp as i64, max_cp as i64),
+            _ => {
+                return Err(IndexerError::GenericError(format!(
+                    "Failed to get checkpoint range for epoch {}",
+                    epoch
+                )))
+            }
+        };
+
+        self.transaction(move |conn| {
+            async move {
+                let (min_tx, max_tx) = pruner_cp_watermark::table
+                    .filter(pruner_cp_watermark::checkpoint_sequence_number.between(min_cp, max_cp))
+                    .select((
+                        min(pruner_cp_watermark::min_tx_sequence_number),
+                        max(pruner_cp_watermark::max_tx_sequence_number),
+                    ))
+                    .first::<(Option<i64>, Option<i64>)>(conn)
+                    .await?;
+
+                // Checkpoints go first, as they define the available range.
+                diesel::delete(
+                    checkpoints::table.filter(checkpoints::sequence_number.between(min_cp, max_cp)),
+                )
+                .execute(conn)
+                .await
+                .map_err(IndexerError::from)
+                .context("Failed to prune checkpoints table")?;
+
+                if let (Some(min_tx), Some(max_tx)) = (min_tx, max_tx) {
+                    delete_tx_range!(
+                        conn,
+                        min_tx,
+                        max_tx,
+                        transactions,
+                        events,
+                        tx_digests,
+                        tx_senders,
+                        tx_recipients,
+                        tx_input_objects,
+                        tx_changed_objects,
+                        tx_calls_pkg,
+                        tx_calls_mod,
+                        tx_calls_fun,
+                        tx_kinds,
+                        tx_affected_addresses,
+                        tx_affected_objects,
+                        event_emit_package,
+                        event_emit_module,
+                        event_senders,
+                        event_struct_package,
+                        event_struct_module,
+                        event_struct_name,
+                        event_struct_instantiation,
+                    );
+                }
+
+                diesel::delete(
+                    objects_history::table.filter(
+                        objects_history::checkpoint_sequence_number.between(min_cp, max_cp),
+                    ),
+                )
+                .execute(conn)
+                .await
+                .map_err(IndexerError::from)
+                .context("Failed to prune objects_history table")?;
+
+                diesel::delete(pruner_cp_watermark::table.filter(
+                    pruner_cp_watermark::checkpoint_sequence_number.between(min_cp, max_cp),
+                ))
+                .execute(conn)
+                .await
+                .map_err(IndexerError::from)
+                .context("Failed to prune pruner_cp_watermark table")?;
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await?;
+
+        self.metrics.last_pruned_checkpoint.set(max_cp);
+        info!(
+            "Pruned checkpoints {} to {} of epoch {}",
+            min_cp, max_cp, epoch
+        );
+        Ok(())
+    }
+
+    async fn get_network_total_transactions_by_end_of_epoch(
+        &self,
+        epoch: u64,
+    ) -> Result<u64, IndexerError> {
+        let mut connection = self.connection.lock().await;
+        checkpoints::table
+            .filter(checkpoints::epoch.eq(epoch as i64))
+            .select(checkpoints::network_total_transactions)
+            .order_by(checkpoints::sequence_number.desc())
+            .first::<i64>(&mut *connection)
+            .await
+            .map_err(Into::into)
+            .context("Failed to get network total transactions in epoch")
+            .map(|v| v as u64)
+    }
+
+    async fn upload_display(&self, _epoch: u64) -> Result<(), IndexerError> {
+        warn!("Display upload is not supported by the SQLite store, skipping display upload.");
+        Ok(())
+    }
+
+    async fn restore_display(&self, bytes: bytes::Bytes) -> Result<(), IndexerError> {
+        let cursor = Cursor::new(bytes);
+        let mut csv_reader = ReaderBuilder::new().has_headers(true).from_reader(cursor);
+        let displays = csv_reader
+            .deserialize()
+            .collect::<Result<Vec<StoredDisplay>, csv::Error>>()
+            .map_err(|e| {
+                IndexerError::GcsError(format!("Failed to deserialize display records: {}", e))
+            })?;
+        self.persist_display_updates(displays).await
+    }
+
+    async fn persist_raw_checkpoints(
+        &self,
+        checkpoints: Vec<StoredRawCheckpoint>,
+    ) -> Result<(), IndexerError> {
+        self.transaction(move |conn| {
+            async move {
+                insert_or_ignore!(
+                    conn,
+                    raw_checkpoints::table,
+                    &checkpoints,
+                    "Failed to write to raw_checkpoints table"
+                );
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    async fn update_watermarks_upper_bound<E: IntoEnumIterator>(
+        &self,
+        watermark: CommitterWatermark,
+    ) -> Result<(), IndexerError>
+    where
+        E::Iterator: Iterator<Item: AsRef<str>>,
+    {
+        let guard = self
+            .metrics
+            .checkpoint_db_commit_latency_watermarks
+            .start_timer();
+        let upper_bound_updates = E::iter()
+            .map(|table| StoredWatermark::from_upper_bound_update(table.as_ref(), watermark))
+            .collect::<Vec<_>>();
+
+        self.transaction(move |conn| {
+            async move {
+                for update in &upper_bound_updates {
+                    diesel::insert_into(watermarks::table)
+                        .values(update)
+                        .on_conflict(watermarks::entity)
+                        .do_update()
+                        .set((
+                            watermarks::epoch_hi_inclusive
+                                .eq(excluded(watermarks::epoch_hi_inclusive)),
+                            watermarks::checkpoint_hi_inclusive
+                                .eq(excluded(watermarks::checkpoint_hi_inclusive)),
+                            watermarks::tx_hi_inclusive.eq(excluded(watermarks::tx_hi_inclusive)),
+                        ))
+                        .execute(conn)
+                        .await
+                        .map_err(IndexerError::from)
+                        .context("Failed to update watermarks upper bound")?;
+                }
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+        .tap_ok(|_| {
+            let elapsed = guard.stop_and_record();
+            info!(elapsed, "Persisted watermarks");
+        })
+        .tap_err(|e| tracing::error!("Failed to persist watermarks with error: {}", e))
+    }
+}
@@ -0,0 +1,396 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! SQLite declarations of the tables whose Postgres columns have no SQLite equivalent. Array
+//! columns are stored as BCS-encoded blobs and JSON columns as text, and rows are converted to and
+//! from the shared `Stored*` models at the store boundary. Every other table is shared with
+//! Postgres through `crate::schema`.
+
+use diesel::prelude::*;
+
+use crate::errors::IndexerError;
+use crate::models::checkpoints::StoredCheckpoint;
+use crate::models::epoch::StoredEpochInfo;
+use crate::models::events::StoredEvent;
+use crate::models::transactions::StoredTransaction;
+
+diesel::table! {
+    checkpoints (sequence_number) {
+        sequence_number -> BigInt,
+        checkpoint_digest -> Binary,
+        epoch -> BigInt,
+        network_total_transactions -> BigInt,
+        previous_checkpoint_digest -> Nullable<Binary>,
+        end_of_epoch -> Bool,
+        tx_digests -> Binary,
+        timestamp_ms -> BigInt,
+        total_gas_cost -> BigInt,
+        computation_cost -> BigInt,
+        storage_cost -> BigInt,
+        storage_rebate -> BigInt,
+        non_refundable_storage_fee -> BigInt,
+        checkpoint_commitments -> Binary,
+        validator_signature -> Binary,
+        end_of_epoch_data -> Nullable<Binary>,
+        min_tx_sequence_number -> Nullable<BigInt>,
+        max_tx_sequence_number -> Nullable<BigInt>,
+    }
+}
+
+diesel::table! {
+    epochs (epoch) {
+        epoch -> BigInt,
+        first_checkpoint_id -> BigInt,
+        epoch_start_timestamp -> BigInt,
+        reference_gas_price -> BigInt,
+        protocol_version -> BigInt,
+        total_stake -> BigInt,
+        storage_fund_balance -> BigInt,
+        system_state -> Nullable<Binary>,
+        epoch_total_transactions -> Nullable<BigInt>,
+        last_checkpoint_id -> Nullable<BigInt>,
+        epoch_end_timestamp -> Nullable<BigInt>,
+        storage_fund_reinvestment -> Nullable<BigInt>,
+        storage_charge -> Nullable<BigInt>,
+        storage_rebate -> Nullable<BigInt>,
+        stake_subsidy_amount -> Nullable<BigInt>,
+        total_gas_fees -> Nullable<BigInt>,
+        total_stake_rewards_distributed -> Nullable<BigInt>,
+        leftover_storage_fund_inflow -> Nullable<BigInt>,
+        epoch_commitments -> Nullable<Binary>,
+        system_state_summary_json -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    events (tx_sequence_number, event_sequence_number) {
+        tx_sequence_number -> BigInt,
+        event_sequence_number -> BigInt,
+        transaction_digest -> Binary,
+        senders -> Binary,
+        package -> Binary,
+        module -> Text,
+        event_type -> Text,
+        timestamp_ms -> BigInt,
+        bcs -> Binary,
+        sender -> Nullable<Binary>,
+    }
+}
+
+diesel::table! {
+    transactions (tx_sequence_number) {
+        tx_sequence_number -> BigInt,
+        transaction_digest -> Binary,
+        raw_transaction -> Binary,
+        raw_effects -> Binary,
+        checkpoint_sequence_number -> BigInt,
+        timestamp_ms -> BigInt,
+        object_changes -> Binary,
+        balance_changes -> Binary,
+        events -> Binary,
+        transaction_kind -> SmallInt,
+        success_command_count -> SmallInt,
+    }
+}
+
+#[derive(Queryable, Insertable, Selectable, Debug, Clone)]
+#[diesel(table_name = checkpoints)]
+pub(crate) struct SqliteCheckpoint {
+    pub sequence_number: i64,
+    pub checkpoint_digest: Vec<u8>,
+    pub epoch: i64,
+    pub network_total_transactions: i64,
+    pub previous_checkpoint_digest: Option<Vec<u8>>,
+    pub end_of_epoch: bool,
+    pub tx_digests: Vec<u8>,
+    pub timestamp_ms: i64,
+    pub total_gas_cost: i64,
+    pub computation_cost: i64,
+    pub storage_cost: i64,
+    pub storage_rebate: i64,
+    pub non_refundable_storage_fee: i64,
+    pub checkpoint_commitments: Vec<u8>,
+    pub validator_signature: Vec<u8>,
+    pub end_of_epoch_data: Option<Vec<u8>>,
+    pub min_tx_sequence_number: Option<i64>,
+    pub max_tx_sequence_number: Option<i64>,
+}
+
+#[derive(Queryable, Insertable, Selectable, Debug, Clone)]
+#[diesel(table_name = epochs)]
+pub(crate) struct SqliteEpochInfo {
+    pub epoch: i64,
+    pub first_checkpoint_id: i64,
+    pub epoch_start_timestamp: i64,
+    pub reference_gas_price: i64,
+    pub protocol_version: i64,
+    pub total_stake: i64,
+    pub storage_fund_balance: i64,
+    pub system_state: Option<Vec<u8>>,
+    pub epoch_total_transactions: Option<i64>,
+    pub last_checkpoint_id: Option<i64>,
+    pub epoch_end_timestamp: Option<i64>,
+    pub storage_fund_reinvestment: Option<i64>,
+    pub storage_charge: Option<i64>,
+    pub storage_rebate: Option<i64>,
+    pub stake_subsidy_amount: Option<i64>,
+    pub total_gas_fees: Option<i64>,
+    pub total_stake_rewards_distributed: Option<i64>,
+    pub leftover_storage_fund_inflow: Option<i64>,
+    pub epoch_commitments: Option<Vec<u8>>,
+    pub system_state_summary_json: Option<String>,
+}
+
+#[derive(Queryable, Insertable, Selectable, Debug, Clone)]
+#[diesel(table_name = events)]
+pub(crate) struct SqliteEvent {
+    pub tx_sequence_number: i64,
+    pub event_sequence_number: i64,
+    pub transaction_digest: Vec<u8>,
+    pub senders: Vec<u8>,
+    pub package: Vec<u8>,
+    pub module: String,
+    pub event_type: String,
+    pub timestamp_ms: i64,
+    pub bcs: Vec<u8>,
+    pub sender: Option<Vec<u8>>,
+}
+
+#[derive(Queryable, Insertable, Selectable, Debug, Clone)]
+#[diesel(table_name = transactions)]
+pub(crate) struct SqliteTransaction {
+    pub tx_sequence_number: i64,
+    pub transaction_digest: Vec<u8>,
+    pub raw_transaction: Vec<u8>,
+    pub raw_effects: Vec<u8>,
+    pub checkpoint_sequence_number: i64,
+    pub timestamp_ms: i64,
+    pub object_changes: Vec<u8>,
+    pub balance_changes: Vec<u8>,
+    pub events: Vec<u8>,
+    pub transaction_kind: i16,
+    pub success_command_count: i16,
+}
+
+fn encode_array(array: &[Option<Vec<u8>>]) -> Vec<u8> {
+    bcs::to_bytes(array).expect("BCS serialization of a byte array list cannot fail")
+}
+
+fn decode_array(bytes: &[u8], column: &str) -> Result<Vec<Option<Vec<u8>>>, IndexerError> {
+    bcs::from_bytes(bytes).map_err(|e| {
+        IndexerError::PersistentStorageDataCorruptionError(format!(
+            "Can't decode {column} from SQLite: {e}"
+        ))
+    })
+}
+
+impl From<StoredCheckpoint> for SqliteCheckpoint {
+    fn from(c: StoredCheckpoint) -> Self {
+        Self {
+            sequence_number: c.sequence_number,
+            checkpoint_digest: c.checkpoint_digest,
+            epoch: c.epoch,
+            network_total_transactions: c.network_total_transactions,
+            previous_checkpoint_digest: c.previous_checkpoint_digest,
+            end_of_epoch: c.end_of_epoch,
+            tx_digests: encode_array(&c.tx_digests),
+            timestamp_ms: c.timestamp_ms,
+            total_gas_cost: c.total_gas_cost,
+            computation_cost: c.computation_cost,
+            storage_cost: c.storage_cost,
+            storage_rebate: c.storage_rebate,
+            non_refundable_storage_fee: c.non_refundable_storage_fee,
+            checkpoint_commitments: c.checkpoint_commitments,
+            validator_signature: c.validator_signature,
+            end_of_epoch_data: c.end_of_epoch_data,
+            min_tx_sequence_number: c.min_tx_sequence_number,
+            max_tx_sequence_number: c.max_tx_sequence_number,
+        }
+    }
+}
+
+impl TryFrom<SqliteCheckpoint> for StoredCheckpoint {
+    type Error = IndexerError;
+
+    fn try_from(c: SqliteCheckpoint) -> Result<Self, Self::Error> {
+        Ok(Self {
+            sequence_number: c.sequence_number,
+            checkpoint_digest: c.checkpoint_digest,
+            epoch: c.epoch,
+            network_total_transactions: c.network_total_transactions,
+            previous_checkpoint_digest: c.previous_checkpoint_digest,
+            end_of_epoch: c.end_of_epoch,
+            tx_digests: decode_array(&c.tx_digests, "checkpoints.tx_digests")?,
+            timestamp_ms: c.timestamp_ms,
+            total_gas_cost: c.total_gas_cost,
+            computation_cost: c.computation_cost,
+            storage_cost: c.storage_cost,
+            storage_rebate: c.storage_rebate,
+            non_refundable_storage_fee: c.non_refundable_storage_fee,
+            checkpoint_commitments: c.checkpoint_commitments,
+            validator_signature: c.validator_signature,
+            end_of_epoch_data: c.end_of_epoch_data,
+            min_tx_sequence_number: c.min_tx_sequence_number,
+            max_tx_sequence_number: c.max_tx_sequence_number,
+        })
+    }
+}
+
+impl From<StoredEpochInfo> for SqliteEpochInfo {
+    fn from(e: StoredEpochInfo) -> Self {
+        Self {
+            epoch: e.epoch,
+            first_checkpoint_id: e.first_checkpoint_id,
+            epoch_start_timestamp: e.epoch_start_timestamp,
+            reference_gas_price: e.reference_gas_price,
+            protocol_version: e.protocol_version,
+            total_stake: e.total_stake,
+            storage_fund_balance: e.storage_fund_balance,
+            system_state: e.system_state,
+            epoch_total_transactions: e.epoch_total_transactions,
+            last_checkpoint_id: e.last_checkpoint_id,
+            epoch_end_timestamp: e.epoch_end_timestamp,
+            storage_fund_reinvestment: e.storage_fund_reinvestment,
+            storage_charge: e.storage_charge,
+            storage_rebate: e.storage_rebate,
+            stake_subsidy_amount: e.stake_subsidy_amount,
+            total_gas_fees: e.total_gas_fees,
+            total_stake_rewards_distributed: e.total_stake_rewards_distributed,
+            leftover_storage_fund_inflow: e.leftover_storage_fund_inflow,
+            epoch_commitments: e.epoch_commitments,
+            system_state_summary_json: e.system_state_summary_json.map(|v| v.to_string()),
+        }
+    }
+}
+
+impl TryFrom<SqliteEpochInfo> for StoredEpochInfo {
+    type Error = IndexerError;
+
+    fn try_from(e: SqliteEpochInfo) -> Result<Self, Self::Error> {
+        let system_state_summary_json = e
+            .system_state_summary_json
+            .map(|json| serde_json::from_str(&json))
+            .transpose()
+            .map_err(|e| {
+                IndexerError::PersistentStorageDataCorruptionError(format!(
+                    "Can't decode epochs.system_state_summary_json from SQLite: {e}"
+                ))
+            })?;
+
+        Ok(Self {
+            epoch: e.epoch,
+            first_checkpoint_id: e.first_checkpoint_id,
+            epoch_start_timestamp: e.epoch_start_timestamp,
+            reference_gas_price: e.reference_gas_price,
+            protocol_version: e.protocol_version,
+            total_stake: e.total_stake,
+            storage_fund_balance: e.storage_fund_balance,
+            system_state: e.system_state,
+            epoch_total_transactions: e.epoch_total_transactions,
+            last_checkpoint_id: e.last_checkpoint_id,
+            epoch_end_timestamp: e.epoch_end_timestamp,
+            storage_fund_reinvestment: e.storage_fund_reinvestment,
+            storage_charge: e.storage_charge,
+            storage_rebate: e.storage_rebate,
+            stake_subsidy_amount: e.stake_subsidy_amount,
+            total_gas_fees: e.total_gas_fees,
+            total_stake_rewards_distributed: e.total_stake_rewards_distributed,
+            leftover_storage_fund_inflow: e.leftover_storage_fund_inflow,
+            epoch_commitments: e.epoch_commitments,
+            system_state_summary_json,
+        })
+    }
+}
+
+impl From<StoredEvent> for SqliteEvent {
+    fn from(e: StoredEvent) -> Self {
+        Self {
+            tx_sequence_number: e.tx_sequence_number,
+            event_sequence_number: e.event_sequence_number,
+            transaction_digest: e.transaction_digest,
+            senders: encode_array(&e.senders),
+            package: e.package,
+            module: e.module,
+            event_type: e.event_type,
+            timestamp_ms: e.timestamp_ms,
+            bcs: e.bcs,
+            sender: e.sender,
+        }
+    }
+}
+
+impl TryFrom<SqliteEvent> for StoredEvent {
+    type Error = IndexerError;
+
+    fn try_from(e: SqliteEvent) -> Result<Self, Self::Error> {
+        Ok(Self {
+            tx_sequence_number: e.tx_sequence_number,
+            event_sequence_number: e.event_sequence_number,
+            transaction_digest: e.transaction_digest,
+            senders: decode_array(&e.senders, "events.senders")?,
+            package: e.package,
+            module: e.module,
+            event_type: e.event_type,
+            timestamp_ms: e.timestamp_ms,
+            bcs: e.bcs,
+            sender: e.sender,
+        })
+    }
+}
+
+impl From<StoredTransaction> for SqliteTransaction {
+    fn from(t: StoredTransaction) -> Self {
+        Self {
+            tx_sequence_number: t.tx_sequence_number,
+            transaction_digest: t.transaction_digest,
+            raw_transaction: t.raw_transaction,
+            raw_effects: t.raw_effects,
+            checkpoint_sequence_number: t.checkpoint_sequence_number,
+            timestamp_ms: t.timestamp_ms,
+            object_changes: encode_array(&t.object_changes),
+            balance_changes: encode_array(&t.balance_changes),
+            events: encode_array(&t.events),
+            transaction_kind: t.transaction_kind,
+            success_command_count: t.success_command_count,
+        }
+    }
+}
+
+impl TryFrom<SqliteTransaction> for StoredTransaction {
+    type Error = IndexerError;
+
+    fn try_from(t: SqliteTransaction) -> Result<Self, Self::Error> {
+        Ok(Self {
+            tx_sequence_number: t.tx_sequence_number,
+            transaction_digest: t.transaction_digest,
+            raw_transaction: t.raw_transaction,
+            raw_effects: t.raw_effects,
+            checkpoint_sequence_number: t.checkpoint_sequence_number,
+            timestamp_ms: t.timestamp_ms,
+            object_changes: decode_array(&t.object_changes, "transactions.object_changes")?,
+            balance_changes: decode_array(&t.balance_changes, "transactions.balance_changes")?,
+            events: decode_array(&t.events, "transactions.events")?,
+            transaction_kind: t.transaction_kind,
+            success_command_count: t.success_command_count,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn array_columns_roundtrip() {
+        let digests = vec![Some(vec![1u8; 32]), None, Some(vec![])];
+        let checkpoint = StoredCheckpoint {
+            tx_digests: digests.clone(),
+            ..Default::default()
+        };
+        let row = SqliteCheckpoint::from(checkpoint);
+        let checkpoint = StoredCheckpoint::try_from(row).unwrap();
+        assert_eq!(checkpoint.tx_digests, digests);
+
+        assert!(decode_array(&[0xff], "checkpoints.tx_digests").is_err());
+    }
+}
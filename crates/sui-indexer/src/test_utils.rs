@@ -18,7 +18,10 @@ use crate::database::ConnectionPool;
 use crate::db::ConnectionPoolConfig;
 use crate::errors::IndexerError;
 use crate::indexer::Indexer;
+use crate::store::IndexerStore;
 use crate::store::PgIndexerStore;
+#[cfg(feature = "sqlite")]
+use crate::store::SqliteIndexerStore;
 use crate::tempdb::get_available_port;
 use crate::tempdb::TempDb;
 use crate::IndexerMetrics;
@@ -234,15 +237,7 @@ pub async fn set_up(
     TempDb,
 ) {
     let database = TempDb::new().unwrap();
-    let server_url: SocketAddr = format!("127.0.0.1:{}", get_available_port())
-        .parse()
-        .unwrap();
-
-    let server_handle = tokio::spawn(async move {
-        sui_rest_api::RestService::new_without_version(sim)
-            .start_service(server_url)
-            .await;
-    });
+    let server_handle = start_rest_service_for_testing(sim);
     // Starts indexer
     let (pg_store, pg_handle, _) = start_indexer_writer_for_testing(
         database.database().url().as_str().to_owned(),
@@ -255,17 +250,70 @@ pub async fn set_up(
     (server_handle, pg_store, pg_handle, database)
 }
 
+/// Like `set_up`, but runs the test indexer against a SQLite database in a temporary directory.
+#[cfg(feature = "sqlite")]
+pub async fn set_up_sqlite(
+    sim: Arc<Simulacrum>,
+    data_ingestion_path: PathBuf,
+) -> (
+    JoinHandle<()>,
+    SqliteIndexerStore,
+    JoinHandle<Result<(), IndexerError>>,
+    tempfile::TempDir,
+) {
+    let database = tempfile::tempdir().unwrap();
+    let db_path = database.path().join("indexer.db");
+    let server_handle = start_rest_service_for_testing(sim);
+
+    let registry = prometheus::Registry::default();
+    init_metrics(&registry);
+    let indexer_metrics = IndexerMetrics::new(&registry);
+
+    let store = SqliteIndexerStore::new(db_path.to_str().unwrap(), indexer_metrics.clone())
+        .await
+        .unwrap();
+
+    let store_clone = store.clone();
+    let mut ingestion_config = IngestionConfig::default();
+    ingestion_config.sources.data_ingestion_path = Some(data_ingestion_path);
+    let snapshot_config = SnapshotLagConfig {
+        snapshot_min_lag: 5,
+        sleep_duration: 0,
+    };
+
+    let handle = tokio::spawn(async move {
+        Indexer::start_writer_with_store(
+            &ingestion_config,
+            store_clone,
+            indexer_metrics,
+            snapshot_config,
+            CancellationToken::new(),
+        )
+        .await
+    });
+    (server_handle, store, handle, database)
+}
+
+fn start_rest_service_for_testing(sim: Arc<Simulacrum>) -> JoinHandle<()> {
+    let server_url: SocketAddr = format!("127.0.0.1:{}", get_available_port())
+        .parse()
+        .unwrap();
+
+    tokio::spawn(async move {
+        sui_rest_api::RestService::new_without_version(sim)
+            .start_service(server_url)
+            .await;
+    })
+}
+
 /// Wait for the indexer to catch up to the given checkpoint sequence number.
 pub async fn wait_for_checkpoint(
-    pg_store: &PgIndexerStore,
+    store: &impl IndexerStore,
     checkpoint_sequence_number: u64,
 ) -> Result<(), IndexerError> {
     tokio::time::timeout(Duration::from_secs(30), async {
         while {
-            let cp_opt = pg_store
-                .get_latest_checkpoint_sequence_number()
-                .await
-                .unwrap();
+            let cp_opt = store.get_latest_checkpoint_sequence_number().await.unwrap();
             cp_opt.is_none() || (cp_opt.unwrap() < checkpoint_sequence_number)
         } {
             tokio::time::sleep(Duration::from_millis(100)).await;
@@ -278,12 +326,12 @@ pub async fn wait_for_checkpoint(
 
 /// Wait for the indexer to catch up to the given checkpoint sequence number for objects snapshot.
 pub async fn wait_for_objects_snapshot(
-    pg_store: &PgIndexerStore,
+    store: &impl IndexerStore,
     checkpoint_sequence_number: u64,
 ) -> Result<(), IndexerError> {
     tokio::time::timeout(Duration::from_secs(30), async {
         while {
-            let cp_opt = pg_store
+            let cp_opt = store
                 .get_latest_object_snapshot_checkpoint_sequence_number()
                 .await
                 .unwrap();
@@ -0,0 +1,219 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use simulacrum::Simulacrum;
+use sui_indexer::errors::IndexerError;
+use sui_indexer::indexer_reader::IndexerReader;
+use sui_indexer::test_utils::{set_up, wait_for_checkpoint};
+use sui_json_rpc_types::{ObjectHistoryStatus, ObjectTransferDirection};
+use sui_types::base_types::{ObjectRef, SuiAddress};
+use sui_types::digests::TransactionDigest;
+use sui_types::effects::{TransactionEffects, TransactionEffectsAPI};
+use sui_types::gas_coin::GAS;
+use sui_types::programmable_transaction_builder::ProgrammableTransactionBuilder;
+use sui_types::transaction::{
+    Argument, Command, GasData, ObjectArg, Transaction, TransactionData, TransactionKind,
+};
+use tempfile::tempdir;
+
+/// Sign and execute a programmable transaction from the simulator's first account, paying for gas
+/// with `gas`.
+fn execute(
+    sim: &mut Simulacrum,
+    gas: ObjectRef,
+    build: impl FnOnce(&mut ProgrammableTransactionBuilder),
+) -> TransactionEffects {
+    let (sender, key) = sim.keystore().accounts().next().unwrap();
+    let sender = *sender;
+
+    let mut builder = ProgrammableTransactionBuilder::new();
+    build(&mut builder);
+
+    let kind = TransactionKind::ProgrammableTransaction(builder.finish());
+    let gas_data = GasData {
+        payment: vec![gas],
+        owner: sender,
+        price: sim.reference_gas_price(),
+        budget: 1_000_000_000,
+    };
+    let tx_data = TransactionData::new_with_gas_data(kind, sender, gas_data);
+    let tx = Transaction::from_data_and_signer(tx_data, vec![key]);
+
+    let (effects, err) = sim.execute_transaction(tx).unwrap();
+    assert!(err.is_none());
+    effects
+}
+
+#[tokio::test]
+async fn test_object_history_of_deleted_object() {
+    let tempdir = tempdir().unwrap();
+    let mut sim = Simulacrum::new();
+    let data_ingestion_path = tempdir.path().to_path_buf();
+    sim.set_data_ingestion_path(data_ingestion_path.clone());
+
+    let sender = *sim.keystore().accounts().next().unwrap().0;
+
+    // Split a coin off the sender's gas coin, and then merge it back in, in the same checkpoint.
+    let (transaction, _) = sim.transfer_txn(sender);
+    let (split, err) = sim.execute_transaction(transaction).unwrap();
+    assert!(err.is_none());
+    let coin = split.created()[0].0;
+
+    let merge = execute(&mut sim, split.gas_object().0, |builder| {
+        let coin = builder.obj(ObjectArg::ImmOrOwnedObject(coin)).unwrap();
+        builder.command(Command::MergeCoins(Argument::GasCoin, vec![coin]));
+    });
+
+    sim.create_checkpoint();
+
+    let (_, pg_store, _, _database) = set_up(Arc::new(sim), data_ingestion_path).await;
+    wait_for_checkpoint(&pg_store, 1).await.unwrap();
+
+    let reader = IndexerReader::new(pg_store.pool());
+    let history = reader
+        .get_object_history(coin.0, None, None, None, 10, false)
+        .await
+        .unwrap();
+
+    assert_eq!(history.len(), 2);
+
+    assert_eq!(history[0].version, coin.1);
+    assert_eq!(history[0].status, ObjectHistoryStatus::Active);
+    assert_eq!(history[0].digest, Some(coin.2));
+    assert_eq!(
+        history[0].previous_transaction,
+        Some(*split.transaction_digest())
+    );
+
+    assert_eq!(history[1].version, merge.lamport_version());
+    assert_eq!(history[1].status, ObjectHistoryStatus::WrappedOrDeleted);
+    assert_eq!(history[1].digest, None);
+    assert_eq!(history[1].checkpoint, 1);
+    assert_eq!(
+        history[1].previous_transaction,
+        Some(*merge.transaction_digest())
+    );
+
+    // Paging backwards from the deleted version returns the version before it.
+    let history = reader
+        .get_object_history(
+            coin.0,
+            None,
+            None,
+            Some(merge.lamport_version().value()),
+            10,
+            true,
+        )
+        .await
+        .unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].version, coin.1);
+}
+
+#[tokio::test]
+async fn test_address_activity() {
+    let tempdir = tempdir().unwrap();
+    let mut sim = Simulacrum::new();
+    let data_ingestion_path = tempdir.path().to_path_buf();
+    sim.set_data_ingestion_path(data_ingestion_path.clone());
+
+    let sender = *sim.keystore().accounts().next().unwrap().0;
+    let recipient = SuiAddress::random_for_testing_only();
+
+    // Three transfers from the sender to the recipient, each of a new coin split off the sender's
+    // gas coin.
+    let mut transfers = vec![];
+    for _ in 0..3 {
+        let (transaction, amount) = sim.transfer_txn(recipient);
+        let (effects, err) = sim.execute_transaction(transaction).unwrap();
+        assert!(err.is_none());
+        transfers.push((effects, amount));
+    }
+    sim.create_checkpoint();
+
+    let (_, pg_store, _, _database) = set_up(Arc::new(sim), data_ingestion_path).await;
+    wait_for_checkpoint(&pg_store, 1).await.unwrap();
+    let reader = IndexerReader::new(pg_store.pool());
+
+    // The recipient was only affected by the transfers.
+    let received = reader
+        .get_address_activity(recipient, None, 10, false)
+        .await
+        .unwrap();
+    assert_eq!(received.len(), 3);
+
+    for (activity, (effects, amount)) in received.iter().zip(&transfers) {
+        let coin = effects.created()[0].0;
+        assert_eq!(activity.digest, *effects.transaction_digest());
+        assert_eq!(activity.sender, sender);
+        assert!(activity.success);
+        assert_eq!(activity.counterparties, vec![sender]);
+
+        assert_eq!(activity.balance_changes.len(), 1);
+        assert_eq!(activity.balance_changes[0].coin_type, GAS::type_tag());
+        assert_eq!(activity.balance_changes[0].amount, *amount as i128);
+
+        assert_eq!(activity.object_transfers.len(), 1);
+        let transfer = &activity.object_transfers[0];
+        assert_eq!(transfer.object_id, coin.0);
+        assert_eq!(transfer.version, coin.1);
+        assert_eq!(transfer.direction, ObjectTransferDirection::In);
+        assert_eq!(transfer.counterparty, Some(sender));
+    }
+
+    // The sender's balance changes by the amount it sent and the gas it paid, which are folded
+    // into a single change per coin type.
+    let sent = reader
+        .get_address_activity(sender, None, 10, true)
+        .await
+        .unwrap();
+    let (effects, amount) = transfers.last().unwrap();
+    let activity = &sent[0];
+    let gas = effects.gas_cost_summary().net_gas_usage() as i128;
+    assert_eq!(activity.digest, *effects.transaction_digest());
+    assert_eq!(activity.counterparties, vec![recipient]);
+    assert_eq!(activity.balance_changes.len(), 1);
+    assert_eq!(activity.balance_changes[0].amount, -(*amount as i128) - gas);
+
+    assert_eq!(activity.object_transfers.len(), 1);
+    let transfer = &activity.object_transfers[0];
+    assert_eq!(transfer.object_id, effects.created()[0].0 .0);
+    assert_eq!(transfer.direction, ObjectTransferDirection::Out);
+    assert_eq!(transfer.counterparty, Some(recipient));
+
+    // Paging one transaction at a time visits the same transactions, in either order.
+    for descending in [false, true] {
+        let mut expected: Vec<_> = transfers
+            .iter()
+            .map(|(effects, _)| *effects.transaction_digest())
+            .collect();
+        if descending {
+            expected.reverse();
+        }
+
+        let mut paged = vec![];
+        let mut cursor = None;
+        loop {
+            let page = reader
+                .get_address_activity(recipient, cursor, 1, descending)
+                .await
+                .unwrap();
+            let Some(activity) = page.first() else {
+                break;
+            };
+            paged.push(activity.digest);
+            cursor = Some(activity.digest);
+        }
+
+        assert_eq!(paged, expected);
+    }
+
+    // A cursor that is not a known transaction is rejected as an invalid argument.
+    let err = reader
+        .get_address_activity(recipient, Some(TransactionDigest::random()), 10, false)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, IndexerError::InvalidArgumentError(_)));
+}
@@ -0,0 +1,173 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use diesel::dsl::count_star;
+use diesel::sql_types::BigInt;
+use diesel::{ExpressionMethods, QueryDsl, QueryableByName};
+use diesel_async::RunQueryDsl;
+use simulacrum::Simulacrum;
+use sui_indexer::errors::IndexerError;
+use sui_indexer::schema::{checkpoints, pruner_cp_watermark, tx_digests, tx_senders};
+use sui_indexer::store::pg_partition_manager::PgPartitionManager;
+use sui_indexer::test_utils::{read_transaction, set_up, wait_for_checkpoint};
+use sui_types::base_types::SuiAddress;
+use sui_types::effects::TransactionEffectsAPI;
+use tempfile::tempdir;
+
+/// Tables that are converted to epoch partitions by the `partition_index_tables` migration.
+const PARTITIONED_TABLES: [&str; 4] = [
+    "checkpoints",
+    "pruner_cp_watermark",
+    "tx_digests",
+    "tx_senders",
+];
+
+#[tokio::test]
+pub async fn test_partitions_advance_and_drop_by_epoch() -> Result<(), IndexerError> {
+    let tempdir = tempdir().unwrap();
+    let mut sim = Simulacrum::new();
+    let data_ingestion_path = tempdir.path().to_path_buf();
+    sim.set_data_ingestion_path(data_ingestion_path.clone());
+
+    let transfer_recipient = SuiAddress::random_for_testing_only();
+    let (transaction, _) = sim.transfer_txn(transfer_recipient);
+    let (first, err) = sim.execute_transaction(transaction).unwrap();
+    assert!(err.is_none());
+
+    sim.create_checkpoint(); // checkpoint 1
+    sim.advance_epoch(true); // checkpoint 2 and epoch 1
+
+    let (transaction, _) = sim.transfer_txn(transfer_recipient);
+    let (second, err) = sim.execute_transaction(transaction).unwrap();
+    assert!(err.is_none());
+    sim.create_checkpoint(); // checkpoint 3
+
+    let (_, pg_store, _, _database) = set_up(Arc::new(sim), data_ingestion_path).await;
+    wait_for_checkpoint(&pg_store, 3).await?;
+
+    // Each table has been split into a partition per epoch.
+    let partition_manager = PgPartitionManager::new(pg_store.pool())?;
+    let partitions = partition_manager.get_table_partitions().await?;
+    for table in PARTITIONED_TABLES {
+        assert_eq!(partitions.get(table), Some(&(0, 1)), "{table}");
+    }
+
+    let mut connection = pg_store.pool().dedicated_connection().await.unwrap();
+    let checkpoint_count: i64 = checkpoints::table
+        .select(count_star())
+        .first(&mut connection)
+        .await?;
+    assert_eq!(checkpoint_count, 4);
+
+    // Dropping epoch 0 removes its rows from every table, and nothing from epoch 1.
+    for table in PARTITIONED_TABLES {
+        partition_manager
+            .drop_table_partition(table.to_string(), 0)
+            .await?;
+    }
+
+    let partitions = partition_manager.get_table_partitions().await?;
+    for table in PARTITIONED_TABLES {
+        assert_eq!(partitions.get(table), Some(&(1, 1)), "{table}");
+    }
+
+    let checkpoints: Vec<i64> = checkpoints::table
+        .select(checkpoints::sequence_number)
+        .order(checkpoints::sequence_number)
+        .load(&mut connection)
+        .await?;
+    assert_eq!(checkpoints, vec![3]);
+
+    let watermarks: Vec<i64> = pruner_cp_watermark::table
+        .select(pruner_cp_watermark::checkpoint_sequence_number)
+        .load(&mut connection)
+        .await?;
+    assert_eq!(watermarks, vec![3]);
+
+    let first_digest = first.transaction_digest().inner().to_vec();
+    let second_digest = second.transaction_digest().inner().to_vec();
+    let digests: Vec<Vec<u8>> = tx_digests::table
+        .select(tx_digests::tx_digest)
+        .filter(tx_digests::tx_digest.eq_any(vec![first_digest, second_digest.clone()]))
+        .load(&mut connection)
+        .await?;
+    assert_eq!(digests, vec![second_digest.clone()]);
+
+    let second_sequence_number = read_transaction(&pg_store, &second_digest)
+        .await
+        .expect("Transaction should have been indexed")
+        .tx_sequence_number;
+    let senders: Vec<i64> = tx_senders::table
+        .select(tx_senders::tx_sequence_number)
+        .load(&mut connection)
+        .await?;
+    assert_eq!(senders, vec![second_sequence_number]);
+
+    Ok(())
+}
+
+#[tokio::test]
+pub async fn test_partition_by_epoch_keeps_rows() -> Result<(), IndexerError> {
+    #[derive(QueryableByName)]
+    struct Row {
+        #[diesel(sql_type = BigInt)]
+        value: i64,
+    }
+
+    let tempdir = tempdir().unwrap();
+    let mut sim = Simulacrum::new();
+    let data_ingestion_path = tempdir.path().to_path_buf();
+    sim.set_data_ingestion_path(data_ingestion_path.clone());
+    sim.create_checkpoint();
+
+    let (_, pg_store, _, _database) = set_up(Arc::new(sim), data_ingestion_path).await;
+    wait_for_checkpoint(&pg_store, 1).await?;
+
+    let mut connection = pg_store.pool().dedicated_connection().await.unwrap();
+    diesel::sql_query("CREATE TABLE scratch (value BIGINT PRIMARY KEY)")
+        .execute(&mut connection)
+        .await?;
+    diesel::sql_query("INSERT INTO scratch VALUES (1), (2), (3)")
+        .execute(&mut connection)
+        .await?;
+
+    // The existing table becomes the partition for the current epoch, without a scan or a copy,
+    // and the constraint used to skip the scan is not left behind.
+    diesel::sql_query(
+        "ALTER TABLE scratch ADD CONSTRAINT scratch_partition_check CHECK (value >= 0)",
+    )
+    .execute(&mut connection)
+    .await?;
+    diesel::sql_query("CALL partition_by_epoch('scratch', 'value')")
+        .execute(&mut connection)
+        .await?;
+
+    let partitions = PgPartitionManager::new(pg_store.pool())?
+        .get_table_partitions()
+        .await?;
+    assert_eq!(partitions.get("scratch"), Some(&(0, 0)));
+
+    let rows: Vec<Row> = diesel::sql_query("SELECT value FROM scratch ORDER BY value")
+        .load(&mut connection)
+        .await?;
+    assert_eq!(
+        rows.into_iter().map(|r| r.value).collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+
+    let constraints: Vec<Row> = diesel::sql_query(
+        "SELECT COUNT(*) AS value FROM pg_constraint WHERE conname = 'scratch_partition_check'",
+    )
+    .load(&mut connection)
+    .await?;
+    assert_eq!(constraints[0].value, 0);
+
+    // Converting an already partitioned table is a no-op.
+    diesel::sql_query("CALL partition_by_epoch('scratch', 'value')")
+        .execute(&mut connection)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,281 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// The ingestion tests, run against the SQLite store instead of Postgres.
+#![cfg(feature = "sqlite")]
+
+use std::sync::Arc;
+
+use simulacrum::Simulacrum;
+use sui_indexer::errors::IndexerError;
+use sui_indexer::handlers::TransactionObjectChangesToCommit;
+use sui_indexer::store::indexer_store::IndexerStore;
+use sui_indexer::test_utils::{set_up_sqlite, wait_for_checkpoint, wait_for_objects_snapshot};
+use sui_indexer::types::EventIndex;
+use sui_indexer::types::IndexedDeletedObject;
+use sui_indexer::types::IndexedObject;
+use sui_indexer::types::TxIndex;
+use sui_types::base_types::SuiAddress;
+use sui_types::effects::TransactionEffectsAPI;
+use sui_types::gas_coin::GasCoin;
+use sui_types::SUI_FRAMEWORK_PACKAGE_ID;
+use tempfile::tempdir;
+
+#[tokio::test]
+pub async fn test_transaction_table() -> Result<(), IndexerError> {
+    let tempdir = tempdir().unwrap();
+    let mut sim = Simulacrum::new();
+    let data_ingestion_path = tempdir.path().to_path_buf();
+    sim.set_data_ingestion_path(data_ingestion_path.clone());
+
+    // Execute a simple transaction.
+    let transfer_recipient = SuiAddress::random_for_testing_only();
+    let (transaction, _) = sim.transfer_txn(transfer_recipient);
+    let (effects, err) = sim.execute_transaction(transaction.clone()).unwrap();
+    assert!(err.is_none());
+
+    // Create a checkpoint which should include the transaction we executed.
+    let checkpoint = sim.create_checkpoint();
+
+    let (_, store, _, _database) = set_up_sqlite(Arc::new(sim), data_ingestion_path).await;
+
+    // Wait for the indexer to catch up to the checkpoint.
+    wait_for_checkpoint(&store, 1).await?;
+
+    let digest = effects.transaction_digest();
+
+    // Read the transaction back, and check that its events were stored alongside it.
+    let db_txn = store
+        .get_transaction(digest.inner())
+        .await?
+        .expect("Failed reading transaction from SQLite");
+    let db_events = store.get_events(db_txn.tx_sequence_number as u64).await?;
+
+    // Check that the transaction was stored correctly.
+    assert_eq!(db_txn.tx_sequence_number, 1);
+    assert_eq!(db_txn.transaction_digest, digest.inner().to_vec());
+    assert_eq!(
+        db_txn.raw_transaction,
+        bcs::to_bytes(&transaction.data()).unwrap()
+    );
+    assert_eq!(db_txn.raw_effects, bcs::to_bytes(&effects).unwrap());
+    assert_eq!(db_txn.timestamp_ms, checkpoint.timestamp_ms as i64);
+    assert_eq!(db_txn.checkpoint_sequence_number, 1);
+    assert_eq!(db_txn.transaction_kind, 1);
+    assert_eq!(db_txn.success_command_count, 2); // split coin + transfer
+    assert_eq!(db_events.len(), db_txn.events.len());
+
+    let db_checkpoint = store
+        .get_checkpoint(1)
+        .await?
+        .expect("Failed reading checkpoint from SQLite");
+    assert_eq!(db_checkpoint.timestamp_ms, checkpoint.timestamp_ms as i64);
+    assert!(db_checkpoint
+        .tx_digests
+        .contains(&Some(digest.inner().to_vec())));
+    Ok(())
+}
+
+#[tokio::test]
+pub async fn test_object_type() -> Result<(), IndexerError> {
+    let tempdir = tempdir().unwrap();
+    let mut sim = Simulacrum::new();
+    let data_ingestion_path = tempdir.path().to_path_buf();
+    sim.set_data_ingestion_path(data_ingestion_path.clone());
+
+    // Execute a simple transaction.
+    let transfer_recipient = SuiAddress::random_for_testing_only();
+    let (transaction, _) = sim.transfer_txn(transfer_recipient);
+    let (_, err) = sim.execute_transaction(transaction.clone()).unwrap();
+    assert!(err.is_none());
+
+    // Create a checkpoint which should include the transaction we executed.
+    let _ = sim.create_checkpoint();
+
+    let (_, store, _, _database) = set_up_sqlite(Arc::new(sim), data_ingestion_path).await;
+
+    // Wait for the indexer to catch up to the checkpoint.
+    wait_for_checkpoint(&store, 1).await?;
+
+    let obj_id = transaction.gas()[0].0;
+
+    let db_object = store
+        .get_object(obj_id.as_ref())
+        .await?
+        .expect("Failed reading object from SQLite");
+
+    let obj_type_tag = GasCoin::type_();
+
+    // Check that the different components of the event type were stored correctly.
+    assert_eq!(
+        db_object.object_type,
+        Some(obj_type_tag.to_canonical_string(true))
+    );
+    assert_eq!(
+        db_object.object_type_package,
+        Some(SUI_FRAMEWORK_PACKAGE_ID.to_vec())
+    );
+    assert_eq!(db_object.object_type_module, Some("coin".to_string()));
+    assert_eq!(db_object.object_type_name, Some("Coin".to_string()));
+
+    // The same version is also recorded in the object's history.
+    let db_history = store
+        .get_object_history(obj_id.as_ref(), db_object.object_version as u64)
+        .await?
+        .expect("Failed reading object history from SQLite");
+    assert_eq!(db_history.object_digest, Some(db_object.object_digest));
+    Ok(())
+}
+
+#[tokio::test]
+pub async fn test_objects_snapshot() -> Result<(), IndexerError> {
+    let tempdir = tempdir().unwrap();
+    let mut sim = Simulacrum::new();
+    let data_ingestion_path = tempdir.path().to_path_buf();
+    sim.set_data_ingestion_path(data_ingestion_path.clone());
+
+    // Run 10 transfer transactions and create 10 checkpoints
+    let mut last_transaction = None;
+    let total_checkpoint_sequence_number = 7usize;
+    for _ in 0..total_checkpoint_sequence_number {
+        let transfer_recipient = SuiAddress::random_for_testing_only();
+        let (transaction, _) = sim.transfer_txn(transfer_recipient);
+        let (_, err) = sim.execute_transaction(transaction.clone()).unwrap();
+        assert!(err.is_none());
+        last_transaction = Some(transaction);
+        let _ = sim.create_checkpoint();
+    }
+
+    let (_, store, _, _database) = set_up_sqlite(Arc::new(sim), data_ingestion_path).await;
+
+    // Wait for objects snapshot at checkpoint max_expected_checkpoint_sequence_number
+    let max_expected_checkpoint_sequence_number = total_checkpoint_sequence_number - 5;
+    wait_for_objects_snapshot(&store, max_expected_checkpoint_sequence_number as u64).await?;
+
+    // Get max checkpoint_sequence_number from objects_snapshot table and assert it's expected
+    let max_checkpoint_sequence_number = store
+        .get_max_object_snapshot_checkpoint()
+        .await?
+        .expect("Failed to read max checkpoint_sequence_number from objects_snapshot");
+    assert_eq!(
+        max_checkpoint_sequence_number,
+        max_expected_checkpoint_sequence_number as u64
+    );
+
+    // Get the object state at max_expected_checkpoint_sequence_number and assert.
+    let last_tx = last_transaction.unwrap();
+    let obj_id = last_tx.gas()[0].0;
+    let gas_owner_id = last_tx.sender_address();
+
+    let snapshot_object = store
+        .get_object_snapshot(
+            obj_id.as_ref(),
+            max_expected_checkpoint_sequence_number as u64,
+        )
+        .await?
+        .expect("Failed reading object from objects_snapshot");
+    // Assert that the object state is as expected at checkpoint max_expected_checkpoint_sequence_number
+    assert_eq!(snapshot_object.object_id, obj_id.to_vec());
+    assert_eq!(
+        snapshot_object.checkpoint_sequence_number,
+        max_expected_checkpoint_sequence_number as i64
+    );
+    assert_eq!(snapshot_object.owner_type, Some(1));
+    assert_eq!(snapshot_object.owner_id, Some(gas_owner_id.to_vec()));
+    Ok(())
+}
+
+#[tokio::test]
+pub async fn test_objects_ingestion() -> Result<(), IndexerError> {
+    let tempdir = tempdir().unwrap();
+    let mut sim = Simulacrum::new();
+    let data_ingestion_path = tempdir.path().to_path_buf();
+    sim.set_data_ingestion_path(data_ingestion_path.clone());
+
+    let (_, store, _, _database) = set_up_sqlite(Arc::new(sim), data_ingestion_path).await;
+
+    let mut objects = Vec::new();
+    for _ in 0..1000 {
+        objects.push(TransactionObjectChangesToCommit {
+            changed_objects: vec![IndexedObject::random()],
+            deleted_objects: vec![IndexedDeletedObject::random()],
+        });
+    }
+    store.persist_objects(objects).await?;
+    Ok(())
+}
+
+// test insert large batch of tx_indices
+#[tokio::test]
+pub async fn test_insert_large_batch_tx_indices() -> Result<(), IndexerError> {
+    let tempdir = tempdir().unwrap();
+    let mut sim = Simulacrum::new();
+    let data_ingestion_path = tempdir.path().to_path_buf();
+    sim.set_data_ingestion_path(data_ingestion_path.clone());
+
+    let (_, store, _, _database) = set_up_sqlite(Arc::new(sim), data_ingestion_path).await;
+
+    let mut v = Vec::new();
+    for _ in 0..1000 {
+        v.push(TxIndex::random());
+    }
+    store.persist_tx_indices(v).await?;
+    Ok(())
+}
+
+// test insert large batch of event_indices
+#[tokio::test]
+pub async fn test_insert_large_batch_event_indices() -> Result<(), IndexerError> {
+    let tempdir = tempdir().unwrap();
+    let mut sim = Simulacrum::new();
+    let data_ingestion_path = tempdir.path().to_path_buf();
+    sim.set_data_ingestion_path(data_ingestion_path.clone());
+
+    let (_, store, _, _database) = set_up_sqlite(Arc::new(sim), data_ingestion_path).await;
+
+    let mut v = Vec::new();
+    for _ in 0..1000 {
+        v.push(EventIndex::random());
+    }
+    store.persist_event_indices(v).await?;
+    Ok(())
+}
+
+#[tokio::test]
+pub async fn test_epoch_boundary() -> Result<(), IndexerError> {
+    let tempdir = tempdir().unwrap();
+    let mut sim = Simulacrum::new();
+    let data_ingestion_path = tempdir.path().to_path_buf();
+    sim.set_data_ingestion_path(data_ingestion_path.clone());
+
+    let transfer_recipient = SuiAddress::random_for_testing_only();
+    let (transaction, _) = sim.transfer_txn(transfer_recipient);
+    let (_, err) = sim.execute_transaction(transaction.clone()).unwrap();
+    assert!(err.is_none());
+
+    sim.create_checkpoint(); // checkpoint 1
+    sim.advance_epoch(true); // checkpoint 2 and epoch 1
+
+    let (transaction, _) = sim.transfer_txn(transfer_recipient);
+    let (_, err) = sim.execute_transaction(transaction.clone()).unwrap();
+    sim.create_checkpoint(); // checkpoint 3
+    assert!(err.is_none());
+
+    let (_, store, _, _database) = set_up_sqlite(Arc::new(sim), data_ingestion_path).await;
+    wait_for_checkpoint(&store, 3).await?;
+    let db_checkpoint = store
+        .get_latest_checkpoint()
+        .await?
+        .expect("Failed reading checkpoint from SQLite");
+    assert_eq!(db_checkpoint.sequence_number, 3);
+    assert_eq!(db_checkpoint.epoch, 1);
+
+    // Both epochs are recorded, and the first one is closed at the epoch boundary.
+    let db_epoch = store
+        .get_epoch(0)
+        .await?
+        .expect("Failed reading epoch from SQLite");
+    assert_eq!(db_epoch.last_checkpoint_id, Some(2));
+    assert!(store.get_epoch(1).await?.is_some());
+    Ok(())
+}
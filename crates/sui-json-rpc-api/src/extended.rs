@@ -5,9 +5,12 @@ use jsonrpsee::core::RpcResult;
 use jsonrpsee::proc_macros::rpc;
 
 use sui_json_rpc_types::{
-    CheckpointedObjectID, EpochInfo, EpochPage, QueryObjectsPage, SuiObjectResponseQuery,
+    AddressActivityPage, CheckpointedObjectID, EpochInfo, EpochPage, ObjectHistoryPage,
+    ObjectHistoryQuery, QueryObjectsPage, SuiObjectResponseQuery,
 };
 use sui_open_rpc_macros::open_rpc;
+use sui_types::base_types::{ObjectID, SuiAddress};
+use sui_types::digests::TransactionDigest;
 use sui_types::sui_serde::BigInt;
 
 #[open_rpc(namespace = "suix", tag = "Extended API")]
@@ -41,6 +44,39 @@ pub trait ExtendedApi {
         limit: Option<usize>,
     ) -> RpcResult<QueryObjectsPage>;
 
+    /// Return the versions of an object, together with the transaction that produced each one.
+    /// Versions are paged by version number, and can be limited to a range of checkpoints.
+    #[method(name = "getObjectHistory")]
+    async fn get_object_history(
+        &self,
+        /// the ID of the object
+        object_id: ObjectID,
+        /// optional range of checkpoints to limit the versions returned to
+        query: Option<ObjectHistoryQuery>,
+        /// optional paging cursor, the version to continue after
+        cursor: Option<BigInt<u64>>,
+        /// maximum number of items per page
+        limit: Option<usize>,
+        /// flag to return results in descending order
+        descending_order: Option<bool>,
+    ) -> RpcResult<ObjectHistoryPage>;
+
+    /// Return the transactions that affected an address, each summarised from that address's
+    /// point of view: its balance changes per coin type, the objects it received or sent, and the
+    /// other addresses involved.
+    #[method(name = "getAddressActivity")]
+    async fn get_address_activity(
+        &self,
+        /// the address to return activity for
+        address: SuiAddress,
+        /// optional paging cursor, the digest of the transaction to continue after
+        cursor: Option<TransactionDigest>,
+        /// maximum number of items per page
+        limit: Option<usize>,
+        /// flag to return results in descending order
+        descending_order: Option<bool>,
+    ) -> RpcResult<AddressActivityPage>;
+
     #[method(name = "getTotalTransactions")]
     async fn get_total_transactions(&self) -> RpcResult<BigInt<u64>>;
 }
@@ -7,14 +7,15 @@ use jsonrpsee::proc_macros::rpc;
 use sui_json_rpc_types::SuiTransactionBlockEffects;
 use sui_json_rpc_types::{
     DynamicFieldPage, EventFilter, EventPage, ObjectsPage, Page, SuiEvent, SuiObjectResponse,
-    SuiObjectResponseQuery, SuiTransactionBlockResponseQuery, TransactionBlocksPage,
-    TransactionFilter,
+    SuiObjectResponseQuery, SuiPastObjectResponse, SuiTransactionBlockResponseQuery,
+    TransactionBlocksPage, TransactionFilter,
 };
 use sui_open_rpc_macros::open_rpc;
 use sui_types::base_types::{ObjectID, SuiAddress};
 use sui_types::digests::TransactionDigest;
 use sui_types::dynamic_field::DynamicFieldName;
 use sui_types::event::EventID;
+use sui_types::sui_serde::BigInt;
 
 #[open_rpc(namespace = "suix", tag = "Extended API")]
 #[rpc(server, client, namespace = "suix")]
@@ -99,6 +100,20 @@ pub trait IndexerApi {
         name: DynamicFieldName,
     ) -> RpcResult<SuiObjectResponse>;
 
+    /// Return the dynamic field object information for a specified object, as it was at the end
+    /// of the specified checkpoint. This is only served by archival fullnodes, for checkpoints
+    /// within the window of recent epochs that they retain.
+    #[method(name = "tryGetDynamicFieldObjectAsOfCheckpoint")]
+    async fn try_get_dynamic_field_object_as_of_checkpoint(
+        &self,
+        /// The ID of the queried parent object
+        parent_object_id: ObjectID,
+        /// The Name of the dynamic field
+        name: DynamicFieldName,
+        /// The sequence number of the checkpoint to read the dynamic field as of
+        checkpoint: BigInt<u64>,
+    ) -> RpcResult<SuiPastObjectResponse>;
+
     /// Return the resolved address given resolver and name
     #[method(name = "resolveNameServiceAddress")]
     async fn resolve_name_service_address(
@@ -100,6 +100,20 @@ pub trait ReadApi {
         options: Option<SuiObjectDataOptions>,
     ) -> RpcResult<Vec<SuiPastObjectResponse>>;
 
+    /// Return the object information as it was at the end of the specified checkpoint.
+    /// This is only served by archival fullnodes, for checkpoints within the window of
+    /// recent epochs that they retain.
+    #[method(name = "tryGetObjectAsOfCheckpoint")]
+    async fn try_get_object_as_of_checkpoint(
+        &self,
+        /// the ID of the queried object
+        object_id: ObjectID,
+        /// the sequence number of the checkpoint to read the object as of
+        checkpoint: BigInt<u64>,
+        /// options for specifying the content to be returned
+        options: Option<SuiObjectDataOptions>,
+    ) -> RpcResult<SuiPastObjectResponse>;
+
     /// Return a checkpoint
     #[method(name = "getCheckpoint")]
     async fn get_checkpoint(
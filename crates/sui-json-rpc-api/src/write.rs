@@ -6,11 +6,11 @@ use jsonrpsee::core::RpcResult;
 use jsonrpsee::proc_macros::rpc;
 
 use sui_json_rpc_types::{
-    DevInspectArgs, DevInspectResults, DryRunTransactionBlockResponse, SuiTransactionBlockResponse,
-    SuiTransactionBlockResponseOptions,
+    DevInspectArgs, DevInspectResults, DryRunTransactionBlockResponse, SuiPendingTransactionBlock,
+    SuiTransactionBlockResponse, SuiTransactionBlockResponseOptions,
 };
 use sui_open_rpc_macros::open_rpc;
-use sui_types::base_types::SuiAddress;
+use sui_types::base_types::{SuiAddress, TransactionDigest};
 use sui_types::quorum_driver_types::ExecuteTransactionRequestType;
 use sui_types::sui_serde::BigInt;
 
@@ -64,4 +64,14 @@ pub trait WriteApi {
         &self,
         tx_bytes: Base64,
     ) -> RpcResult<DryRunTransactionBlockResponse>;
+
+    /// Return the submission progress of a transaction recently sent for execution through this
+    /// node: its state, the validators that responded and the errors seen so far. Returns null if
+    /// the node has no record of the transaction.
+    #[method(name = "getPendingTransactionBlock")]
+    async fn get_pending_transaction_block(
+        &self,
+        /// the digest of the submitted transaction
+        digest: TransactionDigest,
+    ) -> RpcResult<Option<SuiPendingTransactionBlock>>;
 }
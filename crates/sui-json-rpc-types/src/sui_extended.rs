@@ -5,6 +5,7 @@ use std::collections::BTreeMap;
 
 use fastcrypto::traits::ToFromBytes;
 use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::{StructTag, TypeTag};
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
@@ -12,15 +13,21 @@ use serde_with::serde_as;
 use serde_with::DisplayFromStr;
 
 use sui_types::base_types::AuthorityName;
-use sui_types::base_types::{EpochId, ObjectID};
+use sui_types::base_types::{EpochId, ObjectDigest, ObjectID, SequenceNumber, SuiAddress};
 use sui_types::committee::Committee;
+use sui_types::digests::TransactionDigest;
 use sui_types::messages_checkpoint::CheckpointSequenceNumber;
+use sui_types::object::Owner;
 use sui_types::sui_serde::BigInt;
+use sui_types::sui_serde::SequenceNumber as AsSequenceNumber;
+use sui_types::sui_serde::{SuiStructTag, SuiTypeTag};
 use sui_types::sui_system_state::sui_system_state_summary::SuiValidatorSummary;
 
 use crate::Page;
 
 pub type EpochPage = Page<EpochInfo, BigInt<u64>>;
+pub type ObjectHistoryPage = Page<ObjectHistoryEntry, BigInt<u64>>;
+pub type AddressActivityPage = Page<AddressActivity, TransactionDigest>;
 
 #[serde_as]
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
@@ -116,3 +123,113 @@ pub struct MoveFunctionName {
     #[serde_as(as = "DisplayFromStr")]
     pub function: Identifier,
 }
+
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectHistoryEntry {
+    pub object_id: ObjectID,
+    /// version of the object after the transaction that produced this entry
+    #[schemars(with = "AsSequenceNumber")]
+    #[serde_as(as = "AsSequenceNumber")]
+    pub version: SequenceNumber,
+    /// digest of this version, absent if the object was wrapped or deleted at this version
+    pub digest: Option<ObjectDigest>,
+    /// checkpoint that this version was written in
+    #[schemars(with = "BigInt<u64>")]
+    #[serde_as(as = "BigInt<u64>")]
+    pub checkpoint: CheckpointSequenceNumber,
+    pub status: ObjectHistoryStatus,
+    /// transaction that produced this version, if it is still available
+    pub previous_transaction: Option<TransactionDigest>,
+    /// owner of this version, absent if the object was wrapped or deleted at this version
+    pub owner: Option<Owner>,
+    /// type of this version, absent if the object was wrapped or deleted at this version
+    pub object_type: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, JsonSchema)]
+pub enum ObjectHistoryStatus {
+    Active,
+    WrappedOrDeleted,
+}
+
+/// Restricts the versions returned by `suix_getObjectHistory` to those written within a range of
+/// checkpoints. Both bounds are exclusive.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectHistoryQuery {
+    /// only include versions written after this checkpoint
+    #[schemars(with = "Option<BigInt<u64>>")]
+    #[serde_as(as = "Option<BigInt<u64>>")]
+    #[serde(default)]
+    pub after_checkpoint: Option<CheckpointSequenceNumber>,
+    /// only include versions written before this checkpoint
+    #[schemars(with = "Option<BigInt<u64>>")]
+    #[serde_as(as = "Option<BigInt<u64>>")]
+    #[serde(default)]
+    pub before_checkpoint: Option<CheckpointSequenceNumber>,
+}
+
+/// A transaction, summarised from the point of view of one address that it affected.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressActivity {
+    pub digest: TransactionDigest,
+    /// checkpoint the transaction was included in
+    #[schemars(with = "BigInt<u64>")]
+    #[serde_as(as = "BigInt<u64>")]
+    pub checkpoint: CheckpointSequenceNumber,
+    #[schemars(with = "BigInt<u64>")]
+    #[serde_as(as = "BigInt<u64>")]
+    pub timestamp_ms: u64,
+    pub sender: SuiAddress,
+    /// whether the transaction executed successfully
+    pub success: bool,
+    /// other addresses involved: the sender, if it was not this address, and every address that
+    /// exchanged coins or objects in the transaction
+    pub counterparties: Vec<SuiAddress>,
+    /// net change to this address's balance, per coin type
+    pub balance_changes: Vec<ActivityBalanceChange>,
+    /// objects that moved into or out of this address's ownership
+    pub object_transfers: Vec<ActivityObjectTransfer>,
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityBalanceChange {
+    #[schemars(with = "String")]
+    #[serde_as(as = "SuiTypeTag")]
+    pub coin_type: TypeTag,
+    /// negative amount means spending coin value and positive means receiving coin value
+    #[schemars(with = "String")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub amount: i128,
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityObjectTransfer {
+    pub object_id: ObjectID,
+    #[schemars(with = "AsSequenceNumber")]
+    #[serde_as(as = "AsSequenceNumber")]
+    pub version: SequenceNumber,
+    #[schemars(with = "String")]
+    #[serde_as(as = "SuiStructTag")]
+    pub object_type: StructTag,
+    pub direction: ObjectTransferDirection,
+    /// address the object came from or went to, if it was not this address
+    pub counterparty: Option<SuiAddress>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, JsonSchema)]
+pub enum ObjectTransferDirection {
+    /// the object was created for, or transferred to, this address
+    In,
+    /// this address sent the object to another address
+    Out,
+}
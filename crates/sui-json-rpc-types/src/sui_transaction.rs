@@ -24,7 +24,7 @@ use mysten_metrics::monitored_scope;
 use sui_json::{primitive_type, SuiJsonValue};
 use sui_types::authenticator_state::ActiveJwk;
 use sui_types::base_types::{
-    EpochId, ObjectID, ObjectRef, SequenceNumber, SuiAddress, TransactionDigest,
+    AuthorityName, EpochId, ObjectID, ObjectRef, SequenceNumber, SuiAddress, TransactionDigest,
 };
 use sui_types::crypto::SuiSignature;
 use sui_types::digests::{
@@ -39,7 +39,10 @@ use sui_types::messages_checkpoint::CheckpointSequenceNumber;
 use sui_types::messages_consensus::ConsensusDeterminedVersionAssignments;
 use sui_types::object::Owner;
 use sui_types::parse_sui_type_tag;
-use sui_types::quorum_driver_types::ExecuteTransactionRequestType;
+use sui_types::quorum_driver_types::{
+    ExecuteTransactionRequestType, PendingTransactionError, PendingTransactionInfo,
+    PendingTransactionState, TransactionPriority,
+};
 use sui_types::signature::GenericSignature;
 use sui_types::storage::{DeleteKind, WriteKind};
 use sui_types::sui_serde::Readable;
@@ -1105,6 +1108,89 @@ pub struct DryRunTransactionBlockResponse {
     pub input: SuiTransactionBlockData,
 }
 
+#[derive(Eq, PartialEq, Clone, Copy, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename = "PendingTransactionBlockState", rename_all = "camelCase")]
+pub enum SuiPendingTransactionBlockState {
+    /// Collecting validator signatures to form a transaction certificate.
+    Signing,
+    /// A certificate was formed and is being submitted to validators for execution.
+    Certified,
+    /// A quorum of validators signed the effects of the transaction.
+    Finalized,
+    /// The transaction is finalized and was also executed by the node.
+    ExecutedLocally,
+    /// Submission stopped with a non-retryable error, or ran out of retries.
+    Failed,
+}
+
+impl From<PendingTransactionState> for SuiPendingTransactionBlockState {
+    fn from(state: PendingTransactionState) -> Self {
+        match state {
+            PendingTransactionState::Signing => Self::Signing,
+            PendingTransactionState::Certified => Self::Certified,
+            PendingTransactionState::Finalized => Self::Finalized,
+            PendingTransactionState::ExecutedLocally => Self::ExecutedLocally,
+            PendingTransactionState::Failed => Self::Failed,
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename = "PendingTransactionBlockError", rename_all = "camelCase")]
+pub struct SuiPendingTransactionBlockError {
+    /// The submission attempt, starting from 1, during which the error was seen.
+    pub attempt: u32,
+    pub error: String,
+    /// Validators that returned the error.
+    pub validators: Vec<AuthorityName>,
+}
+
+impl From<PendingTransactionError> for SuiPendingTransactionBlockError {
+    fn from(error: PendingTransactionError) -> Self {
+        Self {
+            attempt: error.attempt,
+            error: error.error,
+            validators: error.validators,
+        }
+    }
+}
+
+#[serde_as]
+#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename = "PendingTransactionBlock", rename_all = "camelCase")]
+pub struct SuiPendingTransactionBlock {
+    pub digest: TransactionDigest,
+    pub priority: TransactionPriority,
+    pub state: SuiPendingTransactionBlockState,
+    /// Number of submission attempts started so far.
+    pub attempts: u32,
+    #[schemars(with = "BigInt<u64>")]
+    #[serde_as(as = "BigInt<u64>")]
+    pub submitted_at_ms: u64,
+    #[schemars(with = "BigInt<u64>")]
+    #[serde_as(as = "BigInt<u64>")]
+    pub last_updated_ms: u64,
+    /// Validators whose signatures were collected on the transaction or its effects.
+    pub responded_validators: Vec<AuthorityName>,
+    /// Most recent errors returned by validators, oldest first.
+    pub errors: Vec<SuiPendingTransactionBlockError>,
+}
+
+impl From<PendingTransactionInfo> for SuiPendingTransactionBlock {
+    fn from(info: PendingTransactionInfo) -> Self {
+        Self {
+            digest: info.digest,
+            priority: info.priority,
+            state: info.state.into(),
+            attempts: info.attempts,
+            submitted_at_ms: info.submitted_at_ms,
+            last_updated_ms: info.last_updated_ms,
+            responded_validators: info.responded_validators.into_iter().collect(),
+            errors: info.errors.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
 #[derive(Eq, PartialEq, Clone, Debug, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(rename = "TransactionBlockEvents", transparent)]
 pub struct SuiTransactionBlockEvents {
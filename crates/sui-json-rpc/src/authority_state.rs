@@ -77,6 +77,12 @@ pub trait StateRead: Send + Sync {
         version: SequenceNumber,
     ) -> StateReadResult<PastObjectRead>;
 
+    fn get_past_object_read_as_of_checkpoint(
+        &self,
+        object_id: &ObjectID,
+        checkpoint: CheckpointSequenceNumber,
+    ) -> StateReadResult<PastObjectRead>;
+
     async fn get_object(&self, object_id: &ObjectID) -> StateReadResult<Option<Object>>;
 
     fn load_epoch_store_one_call_per_task(&self) -> Guard<Arc<AuthorityPerEpochStore>>;
@@ -163,6 +169,14 @@ pub trait StateRead: Send + Sync {
         name_bcs_bytes: &[u8],
     ) -> StateReadResult<Option<ObjectID>>;
 
+    fn get_dynamic_field_object_read_as_of_checkpoint(
+        &self,
+        owner: ObjectID,
+        name_type: TypeTag,
+        name_bcs_bytes: &[u8],
+        checkpoint: CheckpointSequenceNumber,
+    ) -> StateReadResult<PastObjectRead>;
+
     // governance_api
     async fn get_staked_sui(&self, owner: SuiAddress) -> StateReadResult<Vec<StakedSui>>;
     fn get_system_state(&self) -> StateReadResult<SuiSystemState>;
@@ -292,6 +306,14 @@ impl StateRead for AuthorityState {
         Ok(self.get_past_object_read(object_id, version)?)
     }
 
+    fn get_past_object_read_as_of_checkpoint(
+        &self,
+        object_id: &ObjectID,
+        checkpoint: CheckpointSequenceNumber,
+    ) -> StateReadResult<PastObjectRead> {
+        Ok(self.get_past_object_read_as_of_checkpoint(object_id, checkpoint)?)
+    }
+
     fn load_epoch_store_one_call_per_task(&self) -> Guard<Arc<AuthorityPerEpochStore>> {
         self.load_epoch_store_one_call_per_task()
     }
@@ -420,6 +442,21 @@ impl StateRead for AuthorityState {
         Ok(self.get_dynamic_field_object_id(owner, name_type, name_bcs_bytes)?)
     }
 
+    fn get_dynamic_field_object_read_as_of_checkpoint(
+        &self,
+        owner: ObjectID,
+        name_type: TypeTag,
+        name_bcs_bytes: &[u8],
+        checkpoint: CheckpointSequenceNumber,
+    ) -> StateReadResult<PastObjectRead> {
+        Ok(self.get_dynamic_field_object_read_as_of_checkpoint(
+            owner,
+            name_type,
+            name_bcs_bytes,
+            checkpoint,
+        )?)
+    }
+
     async fn get_staked_sui(&self, owner: SuiAddress) -> StateReadResult<Vec<StakedSui>> {
         Ok(self
             .get_move_objects(owner, MoveObjectType::staked_sui())
@@ -30,7 +30,11 @@ use sui_types::traffic_control::{PolicyConfig, Weight};
 use tracing::error;
 
 use crate::routing_layer::RpcRouter;
+use crate::transaction_execution_api::{EXECUTE_TRANSACTION_BLOCK_METHOD, REQUEST_PRIORITY};
 use sui_json_rpc_api::CLIENT_TARGET_API_VERSION_HEADER;
+use sui_types::quorum_driver_types::{
+    TransactionPriority, TransactionPriorityAllowlist, TRANSACTION_PRIORITY_HEADER,
+};
 
 pub const MAX_RESPONSE_SIZE: u32 = 2 << 30;
 const TOO_MANY_REQUESTS_MSG: &str = "Too many requests";
@@ -46,6 +50,7 @@ pub struct JsonRpcService<L> {
     rpc_router: RpcRouter,
     traffic_controller: Option<Arc<TrafficController>>,
     client_id_source: Option<ClientIdSource>,
+    transaction_priority_allowlist: TransactionPriorityAllowlist,
 }
 
 impl<L> JsonRpcService<L> {
@@ -56,6 +61,7 @@ impl<L> JsonRpcService<L> {
         remote_fw_config: Option<RemoteFirewallConfig>,
         policy_config: Option<PolicyConfig>,
         traffic_controller_metrics: TrafficControllerMetrics,
+        transaction_priority_allowlist: TransactionPriorityAllowlist,
     ) -> Self {
         Self {
             methods,
@@ -70,6 +76,7 @@ impl<L> JsonRpcService<L> {
                 ))
             }),
             client_id_source: policy_config.map(|policy| policy.client_id_source),
+            transaction_priority_allowlist,
         }
     }
 }
@@ -211,8 +218,19 @@ async fn process_raw_request<L: Logger>(
             }
         }
 
+        let priority = match transaction_priority(service, &request, &headers, client_addr, client)
+        {
+            Ok(priority) => priority,
+            Err(err_obj) => return MethodResponse::error(request.id, err_obj),
+        };
+
         // handle response tallying
-        let response = process_request(request, api_version, service.call_data()).await;
+        let response = REQUEST_PRIORITY
+            .scope(
+                priority,
+                process_request(request, api_version, service.call_data()),
+            )
+            .await;
         if let Some(traffic_controller) = &service.traffic_controller {
             handle_traffic_resp(traffic_controller.clone(), client, &response);
         }
@@ -229,6 +247,40 @@ async fn process_raw_request<L: Logger>(
     }
 }
 
+/// The priority to execute the transaction of `request` at. The `x-sui-transaction-priority`
+/// header is only parsed for transaction execution requests from clients on the allowlist, and is
+/// ignored otherwise.
+fn transaction_priority<L>(
+    service: &JsonRpcService<L>,
+    request: &Request,
+    headers: &HeaderMap,
+    client_addr: SocketAddr,
+    client: Option<IpAddr>,
+) -> Result<TransactionPriority, ErrorObject<'static>> {
+    if request.method != EXECUTE_TRANSACTION_BLOCK_METHOD {
+        return Ok(TransactionPriority::default());
+    }
+
+    // Behind a proxy, the socket address is the proxy's rather than the client's.
+    let client = match service.client_id_source {
+        Some(ClientIdSource::XForwardedFor(_)) => client,
+        _ => Some(client_addr.ip()),
+    };
+    let header = headers
+        .get(TRANSACTION_PRIORITY_HEADER)
+        .map(|h| h.to_str().unwrap_or_default());
+    service
+        .transaction_priority_allowlist
+        .priority(client, header)
+        .map_err(|_| {
+            ErrorObject::owned(
+                ErrorCode::InvalidParams.code(),
+                format!("Invalid {TRANSACTION_PRIORITY_HEADER} header"),
+                None::<()>,
+            )
+        })
+}
+
 async fn handle_traffic_req(
     traffic_controller: Arc<TrafficController>,
     client: &Option<IpAddr>,
@@ -23,7 +23,7 @@ use sui_json_rpc_api::{
 };
 use sui_json_rpc_types::{
     DynamicFieldPage, EventFilter, EventPage, ObjectsPage, Page, SuiObjectDataOptions,
-    SuiObjectResponse, SuiObjectResponseQuery, SuiTransactionBlockResponse,
+    SuiObjectResponse, SuiObjectResponseQuery, SuiPastObjectResponse, SuiTransactionBlockResponse,
     SuiTransactionBlockResponseQuery, TransactionBlocksPage, TransactionFilter,
 };
 use sui_open_rpc::Module;
@@ -34,6 +34,8 @@ use sui_types::{
     dynamic_field::{DynamicFieldName, Field},
     error::SuiObjectResponseError,
     event::EventID,
+    object::PastObjectRead,
+    sui_serde::BigInt,
 };
 use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 use tracing::{debug, instrument, warn};
@@ -382,6 +384,58 @@ impl<R: ReadApiServer> IndexerApiServer for IndexerApi<R> {
         })
     }
 
+    #[instrument(skip(self))]
+    async fn try_get_dynamic_field_object_as_of_checkpoint(
+        &self,
+        parent_object_id: ObjectID,
+        name: DynamicFieldName,
+        checkpoint: BigInt<u64>,
+    ) -> RpcResult<SuiPastObjectResponse> {
+        with_tracing!(async move {
+            let (name_type, name_bcs_value) = self.extract_values_from_dynamic_field_name(name)?;
+
+            let past_read = self
+                .state
+                .get_dynamic_field_object_read_as_of_checkpoint(
+                    parent_object_id,
+                    name_type,
+                    &name_bcs_value,
+                    *checkpoint,
+                )
+                .map_err(Error::from)?;
+
+            match past_read {
+                PastObjectRead::VersionFound((object_id, version, _), _, _) => self
+                    .read_api
+                    .try_get_past_object(
+                        object_id,
+                        version,
+                        Some(SuiObjectDataOptions::full_content()),
+                    )
+                    .await
+                    .map_err(Error::from),
+                PastObjectRead::ObjectNotExists(id) => {
+                    Ok(SuiPastObjectResponse::ObjectNotExists(id))
+                }
+                PastObjectRead::ObjectDeleted(oref) => {
+                    Ok(SuiPastObjectResponse::ObjectDeleted(oref.into()))
+                }
+                PastObjectRead::VersionNotFound(id, version) => {
+                    Ok(SuiPastObjectResponse::VersionNotFound(id, version))
+                }
+                PastObjectRead::VersionTooHigh {
+                    object_id,
+                    asked_version,
+                    latest_version,
+                } => Ok(SuiPastObjectResponse::VersionTooHigh {
+                    object_id,
+                    asked_version,
+                    latest_version,
+                }),
+            }
+        })
+    }
+
     #[instrument(skip(self))]
     async fn resolve_name_service_address(&self, name: String) -> RpcResult<Option<SuiAddress>> {
         with_tracing!(async move {
@@ -13,6 +13,7 @@ use hyper::Request;
 use jsonrpsee::RpcModule;
 use prometheus::Registry;
 use sui_core::traffic_controller::metrics::TrafficControllerMetrics;
+use sui_types::quorum_driver_types::{TransactionPriorityAllowlist, TRANSACTION_PRIORITY_HEADER};
 use sui_types::traffic_control::PolicyConfig;
 use sui_types::traffic_control::RemoteFirewallConfig;
 use tokio::runtime::Handle;
@@ -61,6 +62,7 @@ pub struct JsonRpcServerBuilder {
     registry: Registry,
     policy_config: Option<PolicyConfig>,
     firewall_config: Option<RemoteFirewallConfig>,
+    transaction_priority_allowlist: TransactionPriorityAllowlist,
 }
 
 pub fn sui_rpc_doc(version: &str) -> Project {
@@ -89,9 +91,20 @@ impl JsonRpcServerBuilder {
             registry: prometheus_registry.clone(),
             policy_config,
             firewall_config,
+            transaction_priority_allowlist: TransactionPriorityAllowlist::default(),
         }
     }
 
+    /// Honour the `x-sui-transaction-priority` header of transaction execution requests from the
+    /// clients on `allowlist`.
+    pub fn with_transaction_priority_allowlist(
+        mut self,
+        allowlist: TransactionPriorityAllowlist,
+    ) -> Self {
+        self.transaction_priority_allowlist = allowlist;
+        self
+    }
+
     pub fn register_module<T: SuiRpcModule>(&mut self, module: T) -> Result<(), Error> {
         self.rpc_doc.add_module(T::rpc_doc_module());
         Ok(self.module.merge(module.rpc())?)
@@ -121,6 +134,7 @@ impl JsonRpcServerBuilder {
                 HeaderName::from_static(CLIENT_SDK_VERSION_HEADER),
                 HeaderName::from_static(CLIENT_TARGET_API_VERSION_HEADER),
                 HeaderName::from_static(APP_NAME_HEADER),
+                HeaderName::from_static(TRANSACTION_PRIORITY_HEADER),
             ]);
         Ok(cors)
     }
@@ -197,6 +211,7 @@ impl JsonRpcServerBuilder {
             self.firewall_config.clone(),
             self.policy_config.clone(),
             traffic_controller_metrics,
+            self.transaction_priority_allowlist.clone(),
         );
 
         let mut router = axum::Router::new();
@@ -198,6 +198,52 @@ impl ReadApi {
         Ok(checkpoints)
     }
 
+    /// Render the result of reading an object at a past version, or as of a past checkpoint.
+    async fn past_object_response(
+        &self,
+        past_read: PastObjectRead,
+        options: SuiObjectDataOptions,
+    ) -> Result<SuiPastObjectResponse, Error> {
+        match past_read {
+            PastObjectRead::ObjectNotExists(id) => Ok(SuiPastObjectResponse::ObjectNotExists(id)),
+            PastObjectRead::VersionFound(object_ref, o, layout) => {
+                let display_fields = if options.show_display {
+                    // TODO (jian): api breaking change to also modify past objects.
+                    Some(
+                        get_display_fields(self, &self.transaction_kv_store, &o, &layout)
+                            .await
+                            .map_err(|e| {
+                                Error::UnexpectedError(format!(
+                                    "Unable to render object at version {}: {e}",
+                                    object_ref.1
+                                ))
+                            })?,
+                    )
+                } else {
+                    None
+                };
+                Ok(SuiPastObjectResponse::VersionFound(
+                    (object_ref, o, layout, options, display_fields).try_into()?,
+                ))
+            }
+            PastObjectRead::ObjectDeleted(oref) => {
+                Ok(SuiPastObjectResponse::ObjectDeleted(oref.into()))
+            }
+            PastObjectRead::VersionNotFound(id, seq_num) => {
+                Ok(SuiPastObjectResponse::VersionNotFound(id, seq_num))
+            }
+            PastObjectRead::VersionTooHigh {
+                object_id,
+                asked_version,
+                latest_version,
+            } => Ok(SuiPastObjectResponse::VersionTooHigh {
+                object_id,
+                asked_version,
+                latest_version,
+            }),
+        }
+    }
+
     #[instrument(skip_all)]
     async fn multi_get_transaction_blocks_internal(
         &self,
@@ -642,46 +688,8 @@ impl ReadApiServer for ReadApi {
                 error!("Failed to call try_get_past_object for object: {object_id:?} version: {version:?} with error: {e:?}");
                 Error::from(e)
             })}).await.map_err(Error::from)??;
-            let options = options.unwrap_or_default();
-            match past_read {
-                PastObjectRead::ObjectNotExists(id) => {
-                    Ok(SuiPastObjectResponse::ObjectNotExists(id))
-                }
-                PastObjectRead::VersionFound(object_ref, o, layout) => {
-                    let display_fields = if options.show_display {
-                        // TODO (jian): api breaking change to also modify past objects.
-                        Some(
-                            get_display_fields(self, &self.transaction_kv_store, &o, &layout)
-                                .await
-                                .map_err(|e| {
-                                    Error::UnexpectedError(format!(
-                                        "Unable to render object at version {version}: {e}"
-                                    ))
-                                })?,
-                        )
-                    } else {
-                        None
-                    };
-                    Ok(SuiPastObjectResponse::VersionFound(
-                        (object_ref, o, layout, options, display_fields).try_into()?,
-                    ))
-                }
-                PastObjectRead::ObjectDeleted(oref) => {
-                    Ok(SuiPastObjectResponse::ObjectDeleted(oref.into()))
-                }
-                PastObjectRead::VersionNotFound(id, seq_num) => {
-                    Ok(SuiPastObjectResponse::VersionNotFound(id, seq_num))
-                }
-                PastObjectRead::VersionTooHigh {
-                    object_id,
-                    asked_version,
-                    latest_version,
-                } => Ok(SuiPastObjectResponse::VersionTooHigh {
-                    object_id,
-                    asked_version,
-                    latest_version,
-                }),
-            }
+            self.past_object_response(past_read, options.unwrap_or_default())
+                .await
         })
     }
 
@@ -745,6 +753,28 @@ impl ReadApiServer for ReadApi {
         })
     }
 
+    #[instrument(skip(self))]
+    async fn try_get_object_as_of_checkpoint(
+        &self,
+        object_id: ObjectID,
+        checkpoint: BigInt<u64>,
+        options: Option<SuiObjectDataOptions>,
+    ) -> RpcResult<SuiPastObjectResponse> {
+        with_tracing!(async move {
+            let state = self.state.clone();
+            let checkpoint = *checkpoint;
+            let past_read = spawn_monitored_task!(async move {
+                state
+                    .get_past_object_read_as_of_checkpoint(&object_id, checkpoint)
+                    .map_err(Error::from)
+            })
+            .await
+            .map_err(Error::from)??;
+            self.past_object_response(past_read, options.unwrap_or_default())
+                .await
+        })
+    }
+
     #[instrument(skip(self))]
     async fn get_total_transaction_blocks(&self) -> RpcResult<BigInt<u64>> {
         with_tracing!(async move {
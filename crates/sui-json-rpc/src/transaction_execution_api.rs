@@ -23,8 +23,9 @@ use sui_core::authority_client::NetworkAuthorityClient;
 use sui_core::transaction_orchestrator::TransactiondOrchestrator;
 use sui_json_rpc_api::{JsonRpcMetrics, WriteApiOpenRpc, WriteApiServer};
 use sui_json_rpc_types::{
-    DevInspectArgs, DevInspectResults, DryRunTransactionBlockResponse, SuiTransactionBlock,
-    SuiTransactionBlockEvents, SuiTransactionBlockResponse, SuiTransactionBlockResponseOptions,
+    DevInspectArgs, DevInspectResults, DryRunTransactionBlockResponse, SuiPendingTransactionBlock,
+    SuiTransactionBlock, SuiTransactionBlockEvents, SuiTransactionBlockResponse,
+    SuiTransactionBlockResponseOptions,
 };
 use sui_open_rpc::Module;
 use sui_types::base_types::SuiAddress;
@@ -33,6 +34,7 @@ use sui_types::digests::TransactionDigest;
 use sui_types::effects::TransactionEffectsAPI;
 use sui_types::quorum_driver_types::{
     ExecuteTransactionRequestType, ExecuteTransactionRequestV3, ExecuteTransactionResponseV3,
+    TransactionPriority,
 };
 use sui_types::signature::GenericSignature;
 use sui_types::storage::PostExecutionPackageResolver;
@@ -42,6 +44,16 @@ use sui_types::transaction::{
 };
 use tracing::instrument;
 
+/// The only method whose priority can be set through the `x-sui-transaction-priority` header.
+pub(crate) const EXECUTE_TRANSACTION_BLOCK_METHOD: &str = "sui_executeTransactionBlock";
+
+tokio::task_local! {
+    /// Priority requested through the `x-sui-transaction-priority` header of the HTTP request
+    /// being served, if its client is allowed to. Set by the axum router before dispatching the
+    /// call.
+    pub(crate) static REQUEST_PRIORITY: TransactionPriority;
+}
+
 pub struct TransactionExecutionApi {
     state: Arc<dyn StateRead>,
     transaction_orchestrator: Arc<TransactiondOrchestrator<NetworkAuthorityClient>>,
@@ -122,6 +134,7 @@ impl TransactionExecutionApi {
                 // In order to resolve events, we may need access to the newly published packages.
                 || opts.show_events,
             include_auxiliary_data: false,
+            priority: REQUEST_PRIORITY.try_with(|p| *p).unwrap_or_default(),
         };
 
         Ok((
@@ -378,6 +391,19 @@ impl WriteApiServer for TransactionExecutionApi {
     ) -> RpcResult<DryRunTransactionBlockResponse> {
         with_tracing!(async move { self.dry_run_transaction_block(tx_bytes).await })
     }
+
+    #[instrument(skip(self))]
+    async fn get_pending_transaction_block(
+        &self,
+        digest: TransactionDigest,
+    ) -> RpcResult<Option<SuiPendingTransactionBlock>> {
+        with_tracing!(async move {
+            Ok(self
+                .transaction_orchestrator
+                .get_pending_transaction(&digest)
+                .map(SuiPendingTransactionBlock::from))
+        })
+    }
 }
 
 impl SuiRpcModule for TransactionExecutionApi {
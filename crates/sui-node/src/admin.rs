@@ -10,14 +10,16 @@ use axum::{
 };
 use base64::Engine;
 use humantime::parse_duration;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use std::{
     net::{IpAddr, Ipv4Addr, SocketAddr},
     str::FromStr,
 };
+use sui_config::certificate_deny_config::CertificateDenyConfigUpdate;
+use sui_config::transaction_deny_config::{TransactionDenyConfigUpdate, TransactionDenySwitch};
 use sui_types::{
-    base_types::AuthorityName,
+    base_types::{AuthorityName, EpochId, ObjectID, SuiAddress, TransactionDigest},
     crypto::{RandomnessPartialSignature, RandomnessRound, RandomnessSignature},
     error::SuiError,
 };
@@ -68,6 +70,54 @@ use tracing::info;
 // Inject a full signature from another node, bypassing validity checks.
 //
 //  $ curl 'http://127.0.0.1:1337/randomness-inject-full-sig?round=123&sigs=base64encodedsig'
+//
+// View the transaction deny config that transactions are currently checked against when signing:
+//
+//  $ curl 'http://127.0.0.1:1337/transaction-deny-config'
+//
+// Deny an object, package, address or zkLogin provider (and allow it again). Changes take effect
+// immediately and are recorded in the audit log in the node's DB directory. They are not written
+// to the node config, but the audit log is replayed on top of it when the node restarts, so remove
+// the log to go back to the node config:
+//
+//  $ curl -X POST 'http://127.0.0.1:1337/transaction-deny-config/deny?list=address&entry=0x1234'
+//  $ curl -X POST 'http://127.0.0.1:1337/transaction-deny-config/allow?list=address&entry=0x1234'
+//
+// Flip a kill switch (one of package-publish, package-upgrade, shared-object, user-transaction,
+// receiving-objects or zklogin-sig):
+//
+//  $ curl -X POST 'http://127.0.0.1:1337/transaction-deny-config/switch?switch=package-publish&disabled=true'
+//
+// View the certificate deny config that certificates are currently checked against when executing:
+//
+//  $ curl 'http://127.0.0.1:1337/certificate-deny-config'
+//
+// Deny a certificate, optionally only up to and including a given epoch, and allow it again.
+// Denied certificates fail execution, so the same change must be made on every validator before
+// the certificate executes. Changes are recorded in the audit log in the node's DB directory, which
+// is replayed on top of the node config when the node restarts, so that the node keeps executing
+// certificates the same way. Only remove the log once the certificates it denies can no longer be
+// executed differently:
+//
+//  $ curl -X POST 'http://127.0.0.1:1337/certificate-deny-config/deny?digest=base58digest&last_epoch=123'
+//  $ curl -X POST 'http://127.0.0.1:1337/certificate-deny-config/allow?digest=base58digest'
+//
+// Dump the certificates pending in TransactionManager, with the input objects each one is waiting
+// on, and the certificates currently executing:
+//
+//  $ curl 'http://127.0.0.1:1337/transaction-manager'
+//
+// Dump the shared object congestion at the end of the last consensus commit:
+//
+//  $ curl 'http://127.0.0.1:1337/congestion'
+//
+// Dump the transactions deferred by consensus, grouped by deferral key:
+//
+//  $ curl 'http://127.0.0.1:1337/deferred-transactions'
+//
+// View whether the node considers itself overloaded, and how much load it is shedding:
+//
+//  $ curl 'http://127.0.0.1:1337/overload-info'
 
 const LOGGING_ROUTE: &str = "/logging";
 const TRACING_ROUTE: &str = "/enable-tracing";
@@ -80,6 +130,17 @@ const NODE_CONFIG: &str = "/node-config";
 const RANDOMNESS_PARTIAL_SIGS_ROUTE: &str = "/randomness-partial-sigs";
 const RANDOMNESS_INJECT_PARTIAL_SIGS_ROUTE: &str = "/randomness-inject-partial-sigs";
 const RANDOMNESS_INJECT_FULL_SIG_ROUTE: &str = "/randomness-inject-full-sig";
+const TRANSACTION_DENY_CONFIG_ROUTE: &str = "/transaction-deny-config";
+const TRANSACTION_DENY_ROUTE: &str = "/transaction-deny-config/deny";
+const TRANSACTION_ALLOW_ROUTE: &str = "/transaction-deny-config/allow";
+const TRANSACTION_DENY_SWITCH_ROUTE: &str = "/transaction-deny-config/switch";
+const CERTIFICATE_DENY_CONFIG_ROUTE: &str = "/certificate-deny-config";
+const CERTIFICATE_DENY_ROUTE: &str = "/certificate-deny-config/deny";
+const CERTIFICATE_ALLOW_ROUTE: &str = "/certificate-deny-config/allow";
+const TRANSACTION_MANAGER_ROUTE: &str = "/transaction-manager";
+const CONGESTION_ROUTE: &str = "/congestion";
+const DEFERRED_TRANSACTIONS_ROUTE: &str = "/deferred-transactions";
+const OVERLOAD_INFO_ROUTE: &str = "/overload-info";
 
 struct AppState {
     node: Arc<SuiNode>,
@@ -119,6 +180,17 @@ pub async fn run_admin_server(node: Arc<SuiNode>, port: u16, tracing_handle: Tra
             RANDOMNESS_INJECT_FULL_SIG_ROUTE,
             post(randomness_inject_full_sig),
         )
+        .route(TRANSACTION_DENY_CONFIG_ROUTE, get(transaction_deny_config))
+        .route(TRANSACTION_DENY_ROUTE, post(transaction_deny))
+        .route(TRANSACTION_ALLOW_ROUTE, post(transaction_allow))
+        .route(TRANSACTION_DENY_SWITCH_ROUTE, post(transaction_deny_switch))
+        .route(CERTIFICATE_DENY_CONFIG_ROUTE, get(certificate_deny_config))
+        .route(CERTIFICATE_DENY_ROUTE, post(certificate_deny))
+        .route(CERTIFICATE_ALLOW_ROUTE, post(certificate_allow))
+        .route(TRANSACTION_MANAGER_ROUTE, get(transaction_manager))
+        .route(CONGESTION_ROUTE, get(congestion))
+        .route(DEFERRED_TRANSACTIONS_ROUTE, get(deferred_transactions))
+        .route(OVERLOAD_INFO_ROUTE, get(overload_info))
         .with_state(Arc::new(app_state));
 
     let socket_address = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), port);
@@ -443,3 +515,219 @@ async fn randomness_inject_full_sig(
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
     }
 }
+
+async fn transaction_deny_config(State(state): State<Arc<AppState>>) -> (StatusCode, String) {
+    let config = state.node.state().transaction_signing_filter().load();
+    match serde_yaml::to_string(&*config) {
+        Ok(config) => (StatusCode::OK, config),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()),
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum DenyList {
+    Object,
+    Package,
+    Address,
+    ZkloginProvider,
+}
+
+#[derive(Deserialize)]
+struct DenyListEntry {
+    list: DenyList,
+    entry: String,
+}
+
+async fn transaction_deny(
+    State(state): State<Arc<AppState>>,
+    args: Query<DenyListEntry>,
+) -> (StatusCode, String) {
+    let Query(entry) = args;
+    match deny_list_update(entry, /* deny */ true) {
+        Ok(update) => update_transaction_deny_config(&state, update).await,
+        Err(err) => (StatusCode::BAD_REQUEST, err),
+    }
+}
+
+async fn transaction_allow(
+    State(state): State<Arc<AppState>>,
+    args: Query<DenyListEntry>,
+) -> (StatusCode, String) {
+    let Query(entry) = args;
+    match deny_list_update(entry, /* deny */ false) {
+        Ok(update) => update_transaction_deny_config(&state, update).await,
+        Err(err) => (StatusCode::BAD_REQUEST, err),
+    }
+}
+
+#[derive(Deserialize)]
+struct DenySwitch {
+    switch: TransactionDenySwitch,
+    disabled: bool,
+}
+
+async fn transaction_deny_switch(
+    State(state): State<Arc<AppState>>,
+    args: Query<DenySwitch>,
+) -> (StatusCode, String) {
+    let Query(DenySwitch { switch, disabled }) = args;
+    update_transaction_deny_config(
+        &state,
+        TransactionDenyConfigUpdate::SetSwitch(switch, disabled),
+    )
+    .await
+}
+
+fn deny_list_update(
+    DenyListEntry { list, entry }: DenyListEntry,
+    deny: bool,
+) -> Result<TransactionDenyConfigUpdate, String> {
+    use TransactionDenyConfigUpdate as U;
+
+    let object_id = || ObjectID::from_str(&entry).map_err(|e| e.to_string());
+    let address = || SuiAddress::from_str(&entry).map_err(|e| e.to_string());
+
+    Ok(match (list, deny) {
+        (DenyList::Object, true) => U::DenyObject(object_id()?),
+        (DenyList::Object, false) => U::AllowObject(object_id()?),
+        (DenyList::Package, true) => U::DenyPackage(object_id()?),
+        (DenyList::Package, false) => U::AllowPackage(object_id()?),
+        (DenyList::Address, true) => U::DenyAddress(address()?),
+        (DenyList::Address, false) => U::AllowAddress(address()?),
+        (DenyList::ZkloginProvider, true) => U::DisableZkLoginProvider(entry.clone()),
+        (DenyList::ZkloginProvider, false) => U::EnableZkLoginProvider(entry.clone()),
+    })
+}
+
+async fn update_transaction_deny_config(
+    state: &AppState,
+    update: TransactionDenyConfigUpdate,
+) -> (StatusCode, String) {
+    // Updates are written to the audit log and synced to disk before they take effect.
+    let authority = state.node.state();
+    let updates = [update.clone()];
+    let result = tokio::task::spawn_blocking(move || {
+        authority.transaction_signing_filter().update(&updates)
+    })
+    .await;
+
+    match result {
+        Ok(Ok(true)) => (
+            StatusCode::OK,
+            format!("transaction deny config updated: {update:?}\n"),
+        ),
+        Ok(Ok(false)) => (
+            StatusCode::OK,
+            format!("transaction deny config unchanged, already in effect: {update:?}\n"),
+        ),
+        Ok(Err(err)) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()),
+    }
+}
+
+async fn certificate_deny_config(State(state): State<Arc<AppState>>) -> (StatusCode, String) {
+    let config = state.node.state().certificate_deny_filter().load();
+    match serde_yaml::to_string(&*config) {
+        Ok(config) => (StatusCode::OK, config),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()),
+    }
+}
+
+#[derive(Deserialize)]
+struct CertificateDenyEntry {
+    digest: String,
+    last_epoch: Option<EpochId>,
+}
+
+async fn certificate_deny(
+    State(state): State<Arc<AppState>>,
+    args: Query<CertificateDenyEntry>,
+) -> (StatusCode, String) {
+    let Query(CertificateDenyEntry { digest, last_epoch }) = args;
+    match TransactionDigest::from_str(&digest) {
+        Ok(digest) => {
+            update_certificate_deny_config(
+                &state,
+                CertificateDenyConfigUpdate::DenyCertificate { digest, last_epoch },
+            )
+            .await
+        }
+        Err(err) => (StatusCode::BAD_REQUEST, err.to_string()),
+    }
+}
+
+#[derive(Deserialize)]
+struct CertificateAllowEntry {
+    digest: String,
+}
+
+async fn certificate_allow(
+    State(state): State<Arc<AppState>>,
+    args: Query<CertificateAllowEntry>,
+) -> (StatusCode, String) {
+    let Query(CertificateAllowEntry { digest }) = args;
+    match TransactionDigest::from_str(&digest) {
+        Ok(digest) => {
+            update_certificate_deny_config(
+                &state,
+                CertificateDenyConfigUpdate::AllowCertificate(digest),
+            )
+            .await
+        }
+        Err(err) => (StatusCode::BAD_REQUEST, err.to_string()),
+    }
+}
+
+async fn update_certificate_deny_config(
+    state: &AppState,
+    update: CertificateDenyConfigUpdate,
+) -> (StatusCode, String) {
+    // Updates are written to the audit log and synced to disk before they take effect.
+    let authority = state.node.state();
+    let updates = [update.clone()];
+    let result =
+        tokio::task::spawn_blocking(move || authority.certificate_deny_filter().update(&updates))
+            .await;
+
+    match result {
+        Ok(Ok(true)) => (
+            StatusCode::OK,
+            format!("certificate deny config updated: {update:?}\n"),
+        ),
+        Ok(Ok(false)) => (
+            StatusCode::OK,
+            format!("certificate deny config unchanged, already in effect: {update:?}\n"),
+        ),
+        Ok(Err(err)) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()),
+    }
+}
+
+fn to_json_response<T: Serialize>(value: &T) -> (StatusCode, String) {
+    match serde_json::to_string_pretty(value) {
+        Ok(json) => (StatusCode::OK, json + "\n"),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()),
+    }
+}
+
+async fn transaction_manager(State(state): State<Arc<AppState>>) -> (StatusCode, String) {
+    to_json_response(&state.node.state().transaction_manager().snapshot())
+}
+
+async fn congestion(State(state): State<Arc<AppState>>) -> (StatusCode, String) {
+    let epoch_store = state.node.state().load_epoch_store_one_call_per_task();
+    to_json_response(&epoch_store.last_commit_congestion())
+}
+
+async fn deferred_transactions(State(state): State<Arc<AppState>>) -> (StatusCode, String) {
+    let epoch_store = state.node.state().load_epoch_store_one_call_per_task();
+    match epoch_store.deferred_transactions_summary() {
+        Ok(summary) => to_json_response(&summary),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()),
+    }
+}
+
+async fn overload_info(State(state): State<Arc<AppState>>) -> (StatusCode, String) {
+    to_json_response(&state.node.state().overload_info.snapshot())
+}
@@ -61,10 +61,12 @@ use narwhal_network::metrics::MetricsMakeCallbackHandler;
 use narwhal_network::metrics::{NetworkConnectionMetrics, NetworkMetrics};
 use sui_archival::reader::ArchiveReaderBalancer;
 use sui_archival::writer::ArchiveWriter;
-use sui_config::node::{DBCheckpointConfig, RunWithRange};
+use sui_archival::ArchiveContents;
+use sui_config::node::{DBCheckpointConfig, QuorumDriverLaneConfig, RunWithRange};
 use sui_config::node_config_metrics::NodeConfigMetrics;
 use sui_config::object_storage_config::{ObjectStoreConfig, ObjectStoreType};
 use sui_config::{ConsensusConfig, NodeConfig};
+use sui_core::archival_index::ArchivalIndexStore;
 use sui_core::authority::authority_per_epoch_store::AuthorityPerEpochStore;
 use sui_core::authority::authority_store_tables::AuthorityPerpetualTables;
 use sui_core::authority::epoch_start_configuration::EpochStartConfigTrait;
@@ -72,7 +74,7 @@ use sui_core::authority::epoch_start_configuration::EpochStartConfiguration;
 use sui_core::authority_aggregator::{AuthAggMetrics, AuthorityAggregator};
 use sui_core::authority_server::{ValidatorService, ValidatorServiceMetrics};
 use sui_core::checkpoints::checkpoint_executor::metrics::CheckpointExecutorMetrics;
-use sui_core::checkpoints::checkpoint_executor::{CheckpointExecutor, StopReason};
+use sui_core::checkpoints::checkpoint_executor::{CheckpointExecutor, FastSync, StopReason};
 use sui_core::checkpoints::{
     CheckpointMetrics, CheckpointService, CheckpointStore, SendCheckpointToStateSync,
     SubmitCheckpointToConsensus,
@@ -115,7 +117,7 @@ use sui_network::api::ValidatorServer;
 use sui_network::discovery;
 use sui_network::discovery::TrustedPeerChangeEvent;
 use sui_network::state_sync;
-use sui_protocol_config::{Chain, ProtocolConfig};
+use sui_protocol_config::{Chain, PerObjectCongestionControlMode, ProtocolConfig};
 use sui_snapshot::uploader::StateSnapshotUploader;
 use sui_storage::{
     http_key_value_store::HttpKVStore,
@@ -127,10 +129,13 @@ use sui_types::base_types::{AuthorityName, EpochId};
 use sui_types::committee::Committee;
 use sui_types::crypto::KeypairTraits;
 use sui_types::error::{SuiError, SuiResult};
+use sui_types::messages_checkpoint::CheckpointSequenceNumber;
 use sui_types::messages_consensus::{
-    check_total_jwk_size, AuthorityCapabilitiesV1, ConsensusTransaction,
+    check_total_jwk_size, AuthorityCapabilitiesV1, ConsensusTransaction, ExecutionCostObservation,
+};
+use sui_types::quorum_driver_types::{
+    QuorumDriverEffectsQueueResult, TransactionPriorityAllowlist,
 };
-use sui_types::quorum_driver_types::QuorumDriverEffectsQueueResult;
 use sui_types::sui_system_state::epoch_start_sui_system_state::EpochStartSystemState;
 use sui_types::sui_system_state::epoch_start_sui_system_state::EpochStartSystemStateTrait;
 use sui_types::sui_system_state::SuiSystemStateTrait;
@@ -236,6 +241,10 @@ pub struct SuiNode {
     accumulator: Mutex<Option<Arc<StateAccumulator>>>,
     connection_monitor_status: Arc<ConnectionMonitorStatus>,
 
+    /// Watch channel tracking the highest executed checkpoint, for services that follow
+    /// checkpoints as they are executed.
+    executed_checkpoints: Arc<watch::Sender<CheckpointSequenceNumber>>,
+
     /// Broadcast channel to send the starting system state for the next epoch.
     end_of_epoch_channel: broadcast::Sender<SuiSystemState>,
 
@@ -248,6 +257,7 @@ pub struct SuiNode {
     sim_state: SimState,
 
     _state_archive_handle: Option<broadcast::Sender<()>>,
+    _checkpoint_data_archive_handle: Option<broadcast::Sender<()>>,
 
     _state_snapshot_uploader_handle: Option<broadcast::Sender<()>>,
     // Channel to allow signaling upstream to shutdown sui-node
@@ -269,6 +279,7 @@ impl fmt::Debug for SuiNode {
 }
 
 static MAX_JWK_KEYS_PER_FETCH: usize = 100;
+static EXECUTION_COST_REPORT_INTERVAL: Duration = Duration::from_secs(5);
 
 impl SuiNode {
     pub async fn start(
@@ -414,6 +425,44 @@ impl SuiNode {
         }
     }
 
+    fn start_execution_cost_reporter(
+        authority: AuthorityName,
+        epoch_store: Arc<AuthorityPerEpochStore>,
+        consensus_adapter: Arc<ConsensusAdapter>,
+    ) {
+        let epoch = epoch_store.epoch();
+        info!(
+            ?EXECUTION_COST_REPORT_INTERVAL,
+            "Starting execution cost reporter task"
+        );
+
+        spawn_monitored_task!(epoch_store.clone().within_alive_epoch(
+            async move {
+                loop {
+                    tokio::time::sleep(EXECUTION_COST_REPORT_INTERVAL).await;
+                    let object_costs = epoch_store.take_unreported_execution_costs();
+                    if object_costs.is_empty() {
+                        continue;
+                    }
+                    debug!(
+                        "Submitting execution costs of {} objects to consensus",
+                        object_costs.len()
+                    );
+                    let txn = ConsensusTransaction::new_execution_cost_observation(
+                        ExecutionCostObservation::new(authority, object_costs),
+                    );
+                    consensus_adapter
+                        .submit(txn, None, &epoch_store)
+                        .tap_err(|e| {
+                            warn!("Error when submitting execution costs to consensus {:?}", e)
+                        })
+                        .ok();
+                }
+            }
+            .instrument(error_span!("execution_cost_reporter_task", epoch)),
+        ));
+    }
+
     pub async fn start_async(
         config: NodeConfig,
         registry_service: RegistryService,
@@ -433,6 +482,12 @@ impl SuiNode {
         let run_with_range = config.run_with_range;
         let is_validator = config.consensus_config().is_some();
         let is_full_node = !is_validator;
+        if is_validator && config.checkpoint_executor_config.fast_sync.is_some() {
+            return Err(anyhow!(
+                "Checkpoint fast sync applies checkpointed outputs without executing them, so it is only supported on fullnodes"
+            ));
+        }
+        QuorumDriverLaneConfig::validate_lanes(&config.quorum_driver_lanes)?;
         let prometheus_registry = registry_service.default_registry();
 
         info!(node =? config.protocol_public_key(),
@@ -482,8 +537,12 @@ impl SuiNode {
         let cache_metrics = Arc::new(ResolverMetrics::new(&prometheus_registry));
         let signature_verifier_metrics = SignatureVerifierMetrics::new(&prometheus_registry);
 
-        let cache_traits =
-            build_execution_cache(&epoch_start_configuration, &prometheus_registry, &store);
+        let cache_traits = build_execution_cache(
+            &config.execution_cache,
+            &epoch_start_configuration,
+            &prometheus_registry,
+            &store,
+        );
 
         let auth_agg = {
             let safe_client_metrics_base = SafeClientMetricsBase::new(&prometheus_registry);
@@ -592,6 +651,14 @@ impl SuiNode {
             None
         };
 
+        let archival_index = if is_full_node && config.archival_index.is_some() {
+            Some(Arc::new(ArchivalIndexStore::new(
+                config.db_path().join("archival_index"),
+            )))
+        } else {
+            None
+        };
+
         let chain_identifier = ChainIdentifier::from(*genesis.checkpoint().digest());
         // It's ok if the value is already set due to data races.
         let _ = CHAIN_IDENTIFIER.set(chain_identifier);
@@ -636,6 +703,12 @@ impl SuiNode {
         let state_archive_handle =
             Self::start_state_archival(&config, &prometheus_registry, state_sync_store.clone())
                 .await?;
+        let checkpoint_data_archive_handle = Self::start_checkpoint_data_archival(
+            &config,
+            &prometheus_registry,
+            state_sync_store.clone(),
+        )
+        .await?;
 
         info!("start snapshot upload");
         // Start uploading state snapshot to remote store
@@ -681,6 +754,7 @@ impl SuiNode {
             committee_store.clone(),
             index_store.clone(),
             rest_index,
+            archival_index,
             checkpoint_store.clone(),
             &prometheus_registry,
             genesis.objects(),
@@ -740,12 +814,20 @@ impl SuiNode {
                     end_of_epoch_receiver,
                     &config.db_path(),
                     &prometheus_registry,
+                    &config.quorum_driver_lanes,
                 ),
             ))
         } else {
             None
         };
 
+        let (executed_checkpoints, _) = watch::channel(
+            checkpoint_store
+                .get_highest_executed_checkpoint_seq_number()?
+                .unwrap_or_default(),
+        );
+        let executed_checkpoints = Arc::new(executed_checkpoints);
+
         let http_server = build_http_server(
             state.clone(),
             state_sync_store,
@@ -754,6 +836,7 @@ impl SuiNode {
             &prometheus_registry,
             custom_rpc_runtime,
             software_version,
+            executed_checkpoints.subscribe(),
         )
         .await?;
 
@@ -830,6 +913,7 @@ impl SuiNode {
             accumulator: Mutex::new(Some(accumulator)),
             end_of_epoch_channel,
             connection_monitor_status,
+            executed_checkpoints,
             trusted_peer_change_tx,
 
             _db_checkpoint_handle: db_checkpoint_handle,
@@ -838,6 +922,7 @@ impl SuiNode {
             sim_state: Default::default(),
 
             _state_archive_handle: state_archive_handle,
+            _checkpoint_data_archive_handle: checkpoint_data_archive_handle,
             _state_snapshot_uploader_handle: state_snapshot_handle,
             shutdown_channel_tx: shutdown_channel,
 
@@ -934,6 +1019,34 @@ impl SuiNode {
         }
     }
 
+    async fn start_checkpoint_data_archival(
+        config: &NodeConfig,
+        prometheus_registry: &Registry,
+        state_sync_store: RocksDbStore,
+    ) -> Result<Option<tokio::sync::broadcast::Sender<()>>> {
+        if let Some(remote_store_config) = &config.checkpoint_data_archive_write_config {
+            let local_store_config = ObjectStoreConfig {
+                object_store: Some(ObjectStoreType::File),
+                directory: Some(config.checkpoint_data_archive_path()),
+                ..Default::default()
+            };
+            let archive_writer = ArchiveWriter::new(
+                local_store_config,
+                remote_store_config.clone(),
+                FileCompression::Zstd,
+                StorageFormat::Blob,
+                Duration::from_secs(600),
+                256 * 1024 * 1024,
+                prometheus_registry,
+            )
+            .await?
+            .with_contents(ArchiveContents::CheckpointData);
+            Ok(Some(archive_writer.start(state_sync_store).await?))
+        } else {
+            Ok(None)
+        }
+    }
+
     fn start_state_snapshot(
         config: &NodeConfig,
         prometheus_registry: &Registry,
@@ -1341,6 +1454,18 @@ impl SuiNode {
             );
         }
 
+        if epoch_store
+            .protocol_config()
+            .per_object_congestion_control_mode()
+            == PerObjectCongestionControlMode::ObservedExecutionCost
+        {
+            Self::start_execution_cost_reporter(
+                state.name,
+                epoch_store.clone(),
+                consensus_adapter.clone(),
+            );
+        }
+
         Ok(ValidatorComponents {
             validator_server_handle,
             validator_overload_monitor_handle,
@@ -1513,6 +1638,23 @@ impl SuiNode {
         let checkpoint_executor_metrics =
             CheckpointExecutorMetrics::new(&self.registry_service.default_registry());
 
+        let fast_sync = self
+            .config
+            .checkpoint_executor_config
+            .fast_sync
+            .as_ref()
+            .and_then(|config| {
+                FastSync::new(
+                    config,
+                    &self.checkpoint_store,
+                    self.state.get_accumulator_store().as_ref(),
+                    checkpoint_executor_metrics.clone(),
+                )
+                .tap_err(|e| error!("Failed to set up checkpoint fast sync, disabling it: {e}"))
+                .ok()
+                .map(Arc::new)
+            });
+
         loop {
             let mut accumulator_guard = self.accumulator.lock().await;
             let accumulator = accumulator_guard.take().unwrap();
@@ -1523,7 +1665,11 @@ impl SuiNode {
                 accumulator.clone(),
                 self.config.checkpoint_executor_config.clone(),
                 checkpoint_executor_metrics.clone(),
-            );
+            )
+            .with_executed_checkpoint_notifications(self.executed_checkpoints.clone());
+            if let Some(fast_sync) = &fast_sync {
+                checkpoint_executor = checkpoint_executor.with_fast_sync(fast_sync.clone());
+            }
 
             let run_with_range = self.config.run_with_range;
 
@@ -1959,6 +2105,7 @@ pub async fn build_http_server(
     prometheus_registry: &Registry,
     _custom_runtime: Option<Handle>,
     software_version: &'static str,
+    executed_checkpoints: watch::Receiver<CheckpointSequenceNumber>,
 ) -> Result<Option<tokio::task::JoinHandle<()>>> {
     // Validators do not expose these APIs
     if config.consensus_config().is_some() {
@@ -1967,13 +2114,17 @@ pub async fn build_http_server(
 
     let mut router = axum::Router::new();
 
+    let transaction_priority_allowlist =
+        TransactionPriorityAllowlist::new(config.transaction_priority_allowlist.iter().copied());
+
     let json_rpc_router = {
         let mut server = JsonRpcServerBuilder::new(
             env!("CARGO_PKG_VERSION"),
             prometheus_registry,
             config.policy_config.clone(),
             config.firewall_config.clone(),
-        );
+        )
+        .with_transaction_priority_allowlist(transaction_priority_allowlist.clone());
 
         let kv_store = build_kv_store(&state, config, prometheus_registry)?;
 
@@ -2048,6 +2199,8 @@ pub async fn build_http_server(
         }
 
         rest_service.with_metrics(RestMetrics::new(prometheus_registry));
+        rest_service.with_executed_checkpoints(executed_checkpoints);
+        rest_service.with_transaction_priority_allowlist(transaction_priority_allowlist);
 
         if let Some(transaction_orchestrator) = transaction_orchestrator {
             rest_service.with_executor(transaction_orchestrator.clone())
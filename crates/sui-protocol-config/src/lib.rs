@@ -579,6 +579,9 @@ pub enum PerObjectCongestionControlMode {
     None, // No congestion control.
     TotalGasBudget, // Use txn gas budget as execution cost.
     TotalTxCount,   // Use total txn count as execution cost.
+    // Use the stake-weighted median of the computation cost on each shared object that validators
+    // observed and reported through consensus, capped by the txn gas budget, as execution cost.
+    ObservedExecutionCost,
 }
 
 impl PerObjectCongestionControlMode {
@@ -1265,6 +1268,12 @@ pub struct ProtocolConfig {
     /// Configures the garbage collection depth for consensus. When is unset or `0` then the garbage collection
     /// is disabled.
     consensus_gc_depth: Option<u32>,
+
+    /// The weight, in basis points, that each transaction executed by a validator is given when
+    /// it updates the execution cost of a shared object it reports under
+    /// `PerObjectCongestionControlMode::ObservedExecutionCost`. The remaining weight stays with
+    /// the previous cost.
+    observed_execution_cost_ewma_weight_bps: Option<u64>,
 }
 
 // feature flags
@@ -2135,6 +2144,8 @@ impl ProtocolConfig {
             max_accumulated_txn_cost_per_object_in_mysticeti_commit: None,
 
             consensus_gc_depth: None,
+
+            observed_execution_cost_ewma_weight_bps: None,
             // When adding a new constant, set it to None in the earliest version, like this:
             // new_constant: None,
         };
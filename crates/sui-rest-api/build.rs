@@ -0,0 +1,86 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{env, path::PathBuf};
+use tonic_build::manual::{Builder, Method, Service};
+
+type Result<T> = ::std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+fn main() -> Result<()> {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=proto/rest.proto");
+    println!("cargo:rerun-if-env-changed=BUILD_PROTOS");
+    println!("cargo:rerun-if-env-changed=DUMP_GENERATED_GRPC");
+
+    // The generated messages are checked in to `src/proto/generated` so that building this crate
+    // doesn't require `protoc`. Set this env var to regenerate them after editing the proto files.
+    if env::var("BUILD_PROTOS").is_ok() {
+        prost_build::Config::new()
+            .out_dir("src/proto/generated")
+            .enable_type_names()
+            .compile_protos(&["proto/rest.proto"], &["proto/"])?;
+    }
+
+    let out_dir = if env::var("DUMP_GENERATED_GRPC").is_ok() {
+        PathBuf::from("")
+    } else {
+        PathBuf::from(env::var("OUT_DIR")?)
+    };
+
+    let codec_path = "tonic::codec::ProstCodec";
+
+    let node_service = Service::builder()
+        .name("NodeService")
+        .package("sui.rest")
+        .comment("The Sui Node Api")
+        .method(
+            Method::builder()
+                .name("get_object")
+                .route_name("GetObject")
+                .input_type("crate::proto::GetObjectRequest")
+                .output_type("crate::proto::Object")
+                .codec_path(codec_path)
+                .build(),
+        )
+        .method(
+            Method::builder()
+                .name("get_checkpoint")
+                .route_name("GetCheckpoint")
+                .input_type("crate::proto::GetCheckpointRequest")
+                .output_type("crate::proto::Checkpoint")
+                .codec_path(codec_path)
+                .build(),
+        )
+        .method(
+            Method::builder()
+                .name("get_full_checkpoint")
+                .route_name("GetFullCheckpoint")
+                .input_type("crate::proto::GetFullCheckpointRequest")
+                .output_type("crate::proto::FullCheckpoint")
+                .codec_path(codec_path)
+                .build(),
+        )
+        .method(
+            Method::builder()
+                .name("get_transaction")
+                .route_name("GetTransaction")
+                .input_type("crate::proto::GetTransactionRequest")
+                .output_type("crate::proto::ExecutedTransaction")
+                .codec_path(codec_path)
+                .build(),
+        )
+        .method(
+            Method::builder()
+                .name("execute_transaction")
+                .route_name("ExecuteTransaction")
+                .input_type("crate::proto::ExecuteTransactionRequest")
+                .output_type("crate::proto::ExecuteTransactionResponse")
+                .codec_path(codec_path)
+                .build(),
+        )
+        .build();
+
+    Builder::new().out_dir(&out_dir).compile(&[node_service]);
+
+    Ok(())
+}
@@ -80,6 +80,43 @@ where
     }
 }
 
+/// Like [`AcceptFormat`] but for endpoints which can additionally respond with Protobuf.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AcceptJsonProtobufBcs {
+    Json,
+    Protobuf,
+    Bcs,
+}
+
+#[axum::async_trait]
+impl<S> axum::extract::FromRequestParts<S> for AcceptJsonProtobufBcs
+where
+    S: Send + Sync,
+{
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(
+        parts: &mut http::request::Parts,
+        s: &S,
+    ) -> Result<Self, Self::Rejection> {
+        let accept = Accept::from_request_parts(parts, s).await?;
+
+        for mime in accept.0 {
+            let essence = mime.essence_str();
+
+            if essence == mime::APPLICATION_JSON.essence_str() {
+                return Ok(Self::Json);
+            } else if essence == crate::APPLICATION_PROTOBUF {
+                return Ok(Self::Protobuf);
+            } else if essence == APPLICATION_BCS {
+                return Ok(Self::Bcs);
+            }
+        }
+
+        Ok(Self::Json)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -141,4 +178,38 @@ mod tests {
         let accept = AcceptFormat::from_request(req, &()).await.unwrap();
         assert_eq!(accept, AcceptFormat::Bcs);
     }
+
+    #[tokio::test]
+    async fn test_accept_json_protobuf_bcs() {
+        let req = Request::builder()
+            .header(header::ACCEPT, "*/*, application/x-protobuf")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let accept = AcceptJsonProtobufBcs::from_request(req, &()).await.unwrap();
+        assert_eq!(accept, AcceptJsonProtobufBcs::Protobuf);
+
+        let req = Request::builder()
+            .header(header::ACCEPT, "application/bcs, application/x-protobuf")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let accept = AcceptJsonProtobufBcs::from_request(req, &()).await.unwrap();
+        assert_eq!(accept, AcceptJsonProtobufBcs::Bcs);
+
+        let req = Request::builder()
+            .header(
+                header::ACCEPT,
+                "application/json;q=0.5, application/x-protobuf",
+            )
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let accept = AcceptJsonProtobufBcs::from_request(req, &()).await.unwrap();
+        assert_eq!(accept, AcceptJsonProtobufBcs::Protobuf);
+
+        let req = Request::builder()
+            .header(header::ACCEPT, "*/*")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let accept = AcceptJsonProtobufBcs::from_request(req, &()).await.unwrap();
+        assert_eq!(accept, AcceptJsonProtobufBcs::Json);
+    }
 }
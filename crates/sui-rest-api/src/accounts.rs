@@ -7,9 +7,10 @@ use crate::{response::ResponseContent, Result};
 use crate::{Page, RestService};
 use axum::extract::Query;
 use axum::extract::{Path, State};
+use axum::Json;
 use openapiv3::v3_1::Operation;
 use sui_sdk_types::types::{Address, ObjectId, StructTag, Version};
-use sui_types::sui_sdk_types_conversions::struct_tag_core_to_sdk;
+use sui_types::sui_sdk_types_conversions::{struct_tag_core_to_sdk, struct_tag_sdk_to_core};
 use tap::Pipe;
 
 pub struct ListAccountObjects;
@@ -81,7 +82,7 @@ async fn list_account_objects(
         .pipe(Ok)
 }
 
-#[derive(Debug, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
 pub struct ListAccountOwnedObjectsQueryParameters {
     pub limit: Option<u32>,
     pub start: Option<ObjectId>,
@@ -110,3 +111,162 @@ pub struct AccountOwnedObjectInfo {
     #[serde(rename = "type")]
     pub type_: StructTag,
 }
+
+pub struct ListAccountBalances;
+
+impl ApiEndpoint<RestService> for ListAccountBalances {
+    fn method(&self) -> axum::http::Method {
+        axum::http::Method::GET
+    }
+
+    fn path(&self) -> &'static str {
+        "/accounts/{account}/balances"
+    }
+
+    fn operation(&self, generator: &mut schemars::gen::SchemaGenerator) -> Operation {
+        OperationBuilder::new()
+            .tag("Account")
+            .operation_id("ListAccountBalances")
+            .path_parameter::<Address>("account", generator)
+            .query_parameters::<ListAccountBalancesQueryParameters>(generator)
+            .response(
+                200,
+                ResponseBuilder::new()
+                    .json_content::<Vec<Balance>>(generator)
+                    .header::<String>(crate::types::X_SUI_CURSOR, generator)
+                    .build(),
+            )
+            .build()
+    }
+
+    fn handler(&self) -> crate::openapi::RouteHandler<RestService> {
+        RouteHandler::new(self.method(), list_account_balances)
+    }
+}
+
+async fn list_account_balances(
+    Path(address): Path<Address>,
+    Query(parameters): Query<ListAccountBalancesQueryParameters>,
+    State(state): State<StateReader>,
+) -> Result<Page<Balance, StructTag>> {
+    let limit = parameters.limit();
+    let start = parameters.start()?;
+
+    let mut balances = state
+        .inner()
+        .account_balances_iter(address.into(), start)?
+        .take(limit + 1)
+        .map(Balance::try_from)
+        .collect::<Result<Vec<_>>>()?;
+
+    let cursor = if balances.len() > limit {
+        // SAFETY: We've already verified that balances is greater than limit, which is
+        // gaurenteed to be >= 1.
+        balances.pop().unwrap().coin_type.pipe(Some)
+    } else {
+        None
+    };
+
+    balances
+        .pipe(ResponseContent::Json)
+        .pipe(|entries| Page { entries, cursor })
+        .pipe(Ok)
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+pub struct ListAccountBalancesQueryParameters {
+    pub limit: Option<u32>,
+    pub start: Option<StructTag>,
+}
+
+impl ListAccountBalancesQueryParameters {
+    pub fn limit(&self) -> usize {
+        self.limit
+            .map(|l| (l as usize).clamp(1, crate::MAX_PAGE_SIZE))
+            .unwrap_or(crate::DEFAULT_PAGE_SIZE)
+    }
+
+    pub fn start(&self) -> Result<Option<move_core_types::language_storage::StructTag>> {
+        self.start
+            .clone()
+            .map(struct_tag_sdk_to_core)
+            .transpose()
+            .map_err(Into::into)
+    }
+}
+
+pub struct GetAccountBalance;
+
+impl ApiEndpoint<RestService> for GetAccountBalance {
+    fn method(&self) -> axum::http::Method {
+        axum::http::Method::GET
+    }
+
+    fn path(&self) -> &'static str {
+        "/accounts/{account}/balances/{coin_type}"
+    }
+
+    fn operation(&self, generator: &mut schemars::gen::SchemaGenerator) -> Operation {
+        OperationBuilder::new()
+            .tag("Account")
+            .operation_id("GetAccountBalance")
+            .path_parameter::<Address>("account", generator)
+            .path_parameter::<StructTag>("coin_type", generator)
+            .response(
+                200,
+                ResponseBuilder::new()
+                    .json_content::<Balance>(generator)
+                    .build(),
+            )
+            .build()
+    }
+
+    fn handler(&self) -> crate::openapi::RouteHandler<RestService> {
+        RouteHandler::new(self.method(), get_account_balance)
+    }
+}
+
+async fn get_account_balance(
+    Path((address, coin_type)): Path<(Address, StructTag)>,
+    State(state): State<StateReader>,
+) -> Result<Json<Balance>> {
+    let core_coin_type = struct_tag_sdk_to_core(coin_type.clone())?;
+
+    // An account that has never held a coin of this type simply has a zero balance.
+    let balance = match state.inner().get_balance(address.into(), &core_coin_type)? {
+        Some(info) => Balance::try_from(info)?,
+        None => Balance {
+            coin_type,
+            balance: 0,
+            coin_object_count: 0,
+        },
+    };
+
+    Ok(Json(balance))
+}
+
+/// The total balance of a coin type owned by an account.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+pub struct Balance {
+    pub coin_type: StructTag,
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    #[schemars(with = "crate::_schemars::U64")]
+    pub balance: u64,
+    /// The number of `Coin<T>` objects the balance is spread across.
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    #[schemars(with = "crate::_schemars::U64")]
+    pub coin_object_count: u64,
+}
+
+impl TryFrom<sui_types::storage::BalanceInfo> for Balance {
+    type Error = crate::RestError;
+
+    fn try_from(value: sui_types::storage::BalanceInfo) -> Result<Self> {
+        Ok(Self {
+            coin_type: struct_tag_core_to_sdk(value.coin_type)?,
+            balance: value.balance,
+            coin_object_count: value.coin_object_count,
+        })
+    }
+}
@@ -10,9 +10,11 @@ use sui_sdk_types::types::{
 use sui_types::storage::ReadStore;
 use tap::Pipe;
 
+use crate::accept::AcceptJsonProtobufBcs;
 use crate::openapi::{ApiEndpoint, OperationBuilder, ResponseBuilder, RouteHandler};
+use crate::proto;
 use crate::reader::StateReader;
-use crate::response::Bcs;
+use crate::response::JsonProtobufBcs;
 use crate::Page;
 use crate::{accept::AcceptFormat, response::ResponseContent, RestError, Result};
 use crate::{Direction, RestService};
@@ -61,6 +63,7 @@ impl ApiEndpoint<RestService> for GetCheckpoint {
                 200,
                 ResponseBuilder::new()
                     .json_content::<CheckpointResponse>(generator)
+                    .protobuf_content::<proto::Checkpoint>()
                     .bcs_content()
                     .build(),
             )
@@ -78,9 +81,24 @@ impl ApiEndpoint<RestService> for GetCheckpoint {
 async fn get_checkpoint(
     Path(checkpoint_id): Path<CheckpointId>,
     Query(parameters): Query<GetCheckpointQueryParameters>,
-    accept: AcceptFormat,
+    accept: AcceptJsonProtobufBcs,
     State(state): State<StateReader>,
-) -> Result<ResponseContent<CheckpointResponse>> {
+) -> Result<JsonProtobufBcs<CheckpointResponse, proto::Checkpoint>> {
+    let response = get_checkpoint_response(&state, checkpoint_id, parameters.contents)?;
+
+    match accept {
+        AcceptJsonProtobufBcs::Json => JsonProtobufBcs::Json(response),
+        AcceptJsonProtobufBcs::Protobuf => JsonProtobufBcs::Protobuf(response.try_into()?),
+        AcceptJsonProtobufBcs::Bcs => JsonProtobufBcs::Bcs(response),
+    }
+    .pipe(Ok)
+}
+
+pub(crate) fn get_checkpoint_response(
+    state: &StateReader,
+    checkpoint_id: CheckpointId,
+    contents: bool,
+) -> Result<CheckpointResponse> {
     let SignedCheckpointSummary {
         checkpoint,
         signature,
@@ -102,7 +120,7 @@ async fn get_checkpoint(
     .into_inner()
     .try_into()?;
 
-    let contents = if parameters.contents {
+    let contents = if contents {
         Some(
             state
                 .inner()
@@ -114,17 +132,11 @@ async fn get_checkpoint(
         None
     };
 
-    let response = CheckpointResponse {
+    Ok(CheckpointResponse {
         checkpoint,
         signature,
         contents,
-    };
-
-    match accept {
-        AcceptFormat::Json => ResponseContent::Json(response),
-        AcceptFormat::Bcs => ResponseContent::Bcs(response),
-    }
-    .pipe(Ok)
+    })
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, schemars::JsonSchema)]
@@ -324,7 +336,7 @@ async fn list_checkpoints(
     .pipe(Ok)
 }
 
-#[derive(Debug, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
 pub struct ListCheckpointsQueryParameters {
     /// Page size limit for the response.
     ///
@@ -397,7 +409,13 @@ impl ApiEndpoint<RestService> for GetFullCheckpoint {
             .operation_id("Get Full Checkpoint")
             .description(Self::DOCS)
             .path_parameter::<CheckpointId>("checkpoint", generator)
-            .response(200, ResponseBuilder::new().bcs_content().build())
+            .response(
+                200,
+                ResponseBuilder::new()
+                    .protobuf_content::<proto::FullCheckpoint>()
+                    .bcs_content()
+                    .build(),
+            )
             .response(404, ResponseBuilder::new().build())
             .response(410, ResponseBuilder::new().build())
             .response(500, ResponseBuilder::new().build())
@@ -411,19 +429,32 @@ impl ApiEndpoint<RestService> for GetFullCheckpoint {
 
 async fn get_full_checkpoint(
     Path(checkpoint_id): Path<CheckpointId>,
-    accept: AcceptFormat,
+    accept: AcceptJsonProtobufBcs,
     State(state): State<StateReader>,
-) -> Result<Bcs<sui_types::full_checkpoint_content::CheckpointData>> {
-    match accept {
-        AcceptFormat::Bcs => {}
-        _ => {
-            return Err(RestError::new(
-                axum::http::StatusCode::BAD_REQUEST,
-                "invalid accept type; only 'application/bcs' is supported",
-            ))
-        }
+) -> Result<
+    JsonProtobufBcs<(), proto::FullCheckpoint, sui_types::full_checkpoint_content::CheckpointData>,
+> {
+    if accept == AcceptJsonProtobufBcs::Json {
+        return Err(RestError::new(
+            axum::http::StatusCode::BAD_REQUEST,
+            "invalid accept type; only 'application/bcs' and 'application/x-protobuf' are supported",
+        ));
     }
 
+    let checkpoint_data = get_full_checkpoint_data(&state, checkpoint_id)?;
+
+    if accept == AcceptJsonProtobufBcs::Protobuf {
+        JsonProtobufBcs::Protobuf((&checkpoint_data).into())
+    } else {
+        JsonProtobufBcs::Bcs(checkpoint_data)
+    }
+    .pipe(Ok)
+}
+
+pub(crate) fn get_full_checkpoint_data(
+    state: &StateReader,
+    checkpoint_id: CheckpointId,
+) -> Result<sui_types::full_checkpoint_content::CheckpointData> {
     let verified_summary = match checkpoint_id {
         CheckpointId::SequenceNumber(s) => {
             // Since we need object contents we need to check for the lowest available checkpoint
@@ -447,11 +478,10 @@ async fn get_full_checkpoint(
         .get_checkpoint_contents_by_digest(&verified_summary.content_digest)?
         .ok_or(CheckpointNotFoundError(checkpoint_id))?;
 
-    let checkpoint_data = state
+    state
         .inner()
-        .get_checkpoint_data(verified_summary, checkpoint_contents)?;
-
-    Ok(Bcs(checkpoint_data))
+        .get_checkpoint_data(verified_summary, checkpoint_contents)
+        .map_err(Into::into)
 }
 
 /// List Full Checkpoints
@@ -576,7 +606,7 @@ async fn list_full_checkpoints(
         .pipe(Ok)
 }
 
-#[derive(Debug, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
 pub struct ListFullCheckpointsQueryParameters {
     /// Page size limit for the response.
     ///
@@ -605,3 +635,193 @@ impl ListFullCheckpointsQueryParameters {
         self.direction.unwrap_or(Direction::Descending)
     }
 }
+
+/// Stream Full Checkpoints
+///
+/// Stream checkpoints, and all data associated with them, in ascending order, starting from
+/// `start` and continuing with each new checkpoint as it is executed by the node. The response body
+/// never ends on its own; clients disconnect when they no longer need more checkpoints.
+///
+/// Each checkpoint is sent as a separate frame, made up of the length of the BCS-encoded
+/// `CheckpointData` in bytes, as a 4-byte big-endian unsigned integer, followed by the BCS-encoded
+/// `CheckpointData` itself.
+///
+/// If `start` is below the Node's `lowest_available_checkpoint_objects`, a 410 will be returned.
+#[derive(Documented)]
+pub struct StreamFullCheckpoints;
+
+impl ApiEndpoint<RestService> for StreamFullCheckpoints {
+    fn method(&self) -> axum::http::Method {
+        axum::http::Method::GET
+    }
+
+    fn path(&self) -> &'static str {
+        "/checkpoints/full/stream"
+    }
+
+    fn stable(&self) -> bool {
+        // Shares its format with the other full checkpoint APIs.
+        false
+    }
+
+    fn operation(
+        &self,
+        generator: &mut schemars::gen::SchemaGenerator,
+    ) -> openapiv3::v3_1::Operation {
+        OperationBuilder::new()
+            .tag("Checkpoint")
+            .operation_id("Stream Full Checkpoints")
+            .description(Self::DOCS)
+            .query_parameters::<StreamFullCheckpointsQueryParameters>(generator)
+            .response(200, ResponseBuilder::new().bcs_content().build())
+            .response(410, ResponseBuilder::new().build())
+            .response(500, ResponseBuilder::new().build())
+            .build()
+    }
+
+    fn handler(&self) -> RouteHandler<RestService> {
+        RouteHandler::new(self.method(), stream_full_checkpoints)
+    }
+}
+
+async fn stream_full_checkpoints(
+    Query(parameters): Query<StreamFullCheckpointsQueryParameters>,
+    accept: AcceptFormat,
+    State(state): State<StateReader>,
+    State(executed_checkpoints): State<
+        Option<
+            tokio::sync::watch::Receiver<sui_types::messages_checkpoint::CheckpointSequenceNumber>,
+        >,
+    >,
+) -> Result<axum::response::Response> {
+    use axum::response::IntoResponse;
+
+    let executed_checkpoints = executed_checkpoints
+        .ok_or_else(|| anyhow::anyhow!("No checkpoint execution notifications"))?;
+
+    match accept {
+        AcceptFormat::Bcs => {}
+        _ => {
+            return Err(RestError::new(
+                axum::http::StatusCode::BAD_REQUEST,
+                "invalid accept type; only 'application/bcs' is supported",
+            ))
+        }
+    }
+
+    let latest_checkpoint = state.inner().get_latest_checkpoint()?.sequence_number;
+    let oldest_checkpoint = state.inner().get_lowest_available_checkpoint_objects()?;
+    let start = parameters.start.unwrap_or(latest_checkpoint);
+
+    if start < oldest_checkpoint {
+        return Err(crate::RestError::new(
+            axum::http::StatusCode::GONE,
+            "Old checkpoints have been pruned",
+        ));
+    }
+
+    let frames = futures::stream::try_unfold(
+        (start, executed_checkpoints),
+        move |(sequence_number, mut executed_checkpoints)| {
+            let state = state.clone();
+            async move {
+                let checkpoint =
+                    next_full_checkpoint(&state, &mut executed_checkpoints, sequence_number)
+                        .await?;
+                let frame = encode_checkpoint_frame(&checkpoint)?;
+                anyhow::Ok(Some((frame, (sequence_number + 1, executed_checkpoints))))
+            }
+        },
+    );
+
+    Ok((
+        [(
+            axum::http::header::CONTENT_TYPE,
+            axum::http::HeaderValue::from_static(crate::APPLICATION_BCS),
+        )],
+        axum::body::Body::from_stream(frames),
+    )
+        .into_response())
+}
+
+/// Wait for checkpoint `sequence_number` to be executed, according to `executed_checkpoints`, and
+/// then load all of its data. Fails if the node stops publishing executed checkpoints.
+async fn next_full_checkpoint(
+    state: &StateReader,
+    executed_checkpoints: &mut tokio::sync::watch::Receiver<
+        sui_types::messages_checkpoint::CheckpointSequenceNumber,
+    >,
+    sequence_number: CheckpointSequenceNumber,
+) -> anyhow::Result<sui_types::full_checkpoint_content::CheckpointData> {
+    executed_checkpoints
+        .wait_for(|executed| *executed >= sequence_number)
+        .await
+        .map_err(|_| anyhow::anyhow!("Node stopped executing checkpoints"))?;
+
+    let summary = state
+        .inner()
+        .get_checkpoint_by_sequence_number(sequence_number)?
+        .ok_or_else(|| anyhow::anyhow!("Checkpoint {sequence_number} not found"))?;
+
+    let contents = state
+        .inner()
+        .get_checkpoint_contents_by_digest(&summary.content_digest)?
+        .ok_or_else(|| anyhow::anyhow!("Checkpoint {sequence_number} contents not found"))?;
+
+    state.inner().get_checkpoint_data(summary, contents)
+}
+
+/// Encode `value` as a frame in a checkpoint stream: The length of its BCS encoding as a 4-byte
+/// big-endian integer, followed by the encoding itself.
+fn encode_checkpoint_frame<T: serde::Serialize>(value: &T) -> anyhow::Result<Vec<u8>> {
+    let bytes = bcs::to_bytes(value)?;
+    let len = u32::try_from(bytes.len())?;
+
+    let mut frame = Vec::with_capacity(4 + bytes.len());
+    frame.extend_from_slice(&len.to_be_bytes());
+    frame.extend_from_slice(&bytes);
+    Ok(frame)
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+pub struct StreamFullCheckpointsQueryParameters {
+    /// The checkpoint to start streaming from.
+    ///
+    /// Defaults to the latest checkpoint if not provided.
+    pub start: Option<CheckpointSequenceNumber>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::next_checkpoint_frame;
+
+    #[test]
+    fn checkpoint_frame_roundtrip() {
+        let first = vec![1u8, 2, 3];
+        let second = u64::MAX;
+        let empty: Vec<u8> = vec![];
+
+        let mut stream = encode_checkpoint_frame(&first).unwrap();
+        assert_eq!(&stream[..4], &4u32.to_be_bytes());
+        stream.extend(encode_checkpoint_frame(&second).unwrap());
+        stream.extend(encode_checkpoint_frame(&empty).unwrap());
+
+        // Feed the stream to the decoder one byte at a time, to make sure frames split across
+        // chunks are reassembled.
+        let mut buffer = vec![];
+        let mut frames = vec![];
+        for byte in stream {
+            buffer.push(byte);
+            while let Some(frame) = next_checkpoint_frame(&mut buffer) {
+                frames.push(frame);
+            }
+        }
+
+        assert!(buffer.is_empty());
+        assert_eq!(frames.len(), 3);
+        assert_eq!(bcs::from_bytes::<Vec<u8>>(&frames[0]).unwrap(), first);
+        assert_eq!(bcs::from_bytes::<u64>(&frames[1]).unwrap(), second);
+        assert_eq!(bcs::from_bytes::<Vec<u8>>(&frames[2]).unwrap(), empty);
+    }
+}
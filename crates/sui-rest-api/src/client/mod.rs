@@ -46,20 +46,36 @@ impl Client {
         &self,
         checkpoint_sequence_number: CheckpointSequenceNumber,
     ) -> Result<CheckpointData> {
-        let url = self
-            .inner
-            .url()
-            .join(&format!("checkpoints/{checkpoint_sequence_number}/full"))?;
+        let request = self.inner.request(
+            &crate::checkpoints::GetFullCheckpoint,
+            &[("checkpoint", &checkpoint_sequence_number)],
+        )?;
+
+        self.inner.send_bcs(request).await.map(Response::into_inner)
+    }
 
-        let response = self
+    /// Stream full checkpoints in ascending order, starting from `start` (or the node's latest
+    /// checkpoint, if not provided), and continuing with each new checkpoint as it is executed by
+    /// the node.
+    pub async fn stream_full_checkpoints(
+        &self,
+        start: Option<CheckpointSequenceNumber>,
+    ) -> Result<CheckpointStream> {
+        let mut request = self
             .inner
-            .client()
-            .get(url)
-            .header(reqwest::header::ACCEPT, crate::APPLICATION_BCS)
-            .send()
-            .await?;
+            .request(&crate::checkpoints::StreamFullCheckpoints, &[])?
+            .header(reqwest::header::ACCEPT, crate::APPLICATION_BCS);
 
-        self.inner.bcs(response).await.map(Response::into_inner)
+        if let Some(start) = start {
+            request = request.query(&[("start", start)]);
+        }
+
+        let response = self.inner.send(request).await?;
+        let (response, _) = self.inner.check_response(response).await?;
+        Ok(CheckpointStream {
+            response,
+            buffer: Vec::new(),
+        })
     }
 
     pub async fn get_checkpoint_summary(
@@ -111,27 +127,63 @@ impl Client {
             signatures: &'a [sui_types::signature::GenericSignature],
         }
 
-        let url = self.inner.url().join("transactions")?;
         let body = bcs::to_bytes(&SignedTransaction {
             transaction: &transaction.inner().intent_message.value,
             signatures: &transaction.inner().tx_signatures,
         })?;
 
-        let response = self
+        let request = self
             .inner
-            .client()
-            .post(url)
+            .request(&crate::transactions::ExecuteTransaction, &[])?
             .query(parameters)
-            .header(reqwest::header::ACCEPT, crate::APPLICATION_BCS)
             .header(reqwest::header::CONTENT_TYPE, crate::APPLICATION_BCS)
             .body(body)
-            .send()
-            .await?;
+            .header(reqwest::header::ACCEPT, crate::APPLICATION_BCS);
 
+        // Executing a transaction is only retried if the request never reached the server.
+        let response = self.inner.send_unless_connected(request).await?;
         self.inner.bcs(response).await.map(Response::into_inner)
     }
 }
 
+/// A stream of full checkpoints, returned by [`Client::stream_full_checkpoints`].
+pub struct CheckpointStream {
+    response: reqwest::Response,
+    /// Bytes received from the server that have not yet been decoded into a checkpoint.
+    buffer: Vec<u8>,
+}
+
+impl CheckpointStream {
+    /// Wait for the next checkpoint in the stream. Returns `None` once the server has closed the
+    /// stream.
+    pub async fn next(&mut self) -> Option<Result<CheckpointData>> {
+        loop {
+            if let Some(frame) = next_checkpoint_frame(&mut self.buffer) {
+                return Some(bcs::from_bytes(&frame).map_err(Into::into));
+            }
+
+            match self.response.chunk().await {
+                Ok(Some(chunk)) => self.buffer.extend_from_slice(&chunk),
+                Ok(None) => return None,
+                Err(e) => return Some(Err(e.into())),
+            }
+        }
+    }
+}
+
+/// Split the next complete frame off the front of `buffer`, if there is one. Each frame is the
+/// length of its payload as a 4-byte big-endian integer, followed by the payload.
+pub(crate) fn next_checkpoint_frame(buffer: &mut Vec<u8>) -> Option<Vec<u8>> {
+    let len = u32::from_be_bytes(buffer.get(..4)?.try_into().ok()?) as usize;
+    if buffer.len() < 4 + len {
+        return None;
+    }
+
+    let frame = buffer[4..4 + len].to_vec();
+    buffer.drain(..4 + len);
+    Some(frame)
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct TransactionExecutionResponse {
     pub effects: TransactionEffects,
@@ -1,6 +1,10 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use std::time::Duration;
+
+use futures::Stream;
+use futures::TryStreamExt;
 use reqwest::header::HeaderValue;
 use reqwest::StatusCode;
 use reqwest::Url;
@@ -21,26 +25,37 @@ use sui_sdk_types::types::ValidatorCommittee;
 use sui_sdk_types::types::Version;
 use tap::Pipe;
 
-use crate::accounts::AccountOwnedObjectInfo;
-use crate::accounts::ListAccountOwnedObjectsQueryParameters;
-use crate::checkpoints::CheckpointResponse;
-use crate::checkpoints::ListCheckpointsQueryParameters;
-use crate::coins::CoinInfo;
+pub use crate::accounts::AccountOwnedObjectInfo;
+pub use crate::accounts::Balance;
+pub use crate::accounts::ListAccountBalancesQueryParameters;
+pub use crate::accounts::ListAccountOwnedObjectsQueryParameters;
+pub use crate::checkpoints::CheckpointResponse;
+pub use crate::checkpoints::ListCheckpointsQueryParameters;
+pub use crate::checkpoints::ListFullCheckpointsQueryParameters;
+pub use crate::coins::CoinInfo;
+pub use crate::events::EventResponse;
+pub use crate::events::ListEventsQueryParameters;
+pub use crate::info::NodeInfo;
+pub use crate::objects::DynamicFieldInfo;
+pub use crate::objects::ListDynamicFieldsQueryParameters;
+pub use crate::system::GasInfo;
+pub use crate::system::ProtocolConfigResponse;
+pub use crate::system::SystemStateSummary;
+pub use crate::transactions::ListTransactionsQueryParameters;
+pub use crate::transactions::PendingTransactionResponse;
+pub use crate::transactions::ResolveTransactionQueryParameters;
+pub use crate::transactions::ResolveTransactionResponse;
+pub use crate::transactions::SimulateTransactionQueryParameters;
+pub use crate::transactions::TransactionExecutionResponse;
+pub use crate::transactions::TransactionResponse;
+pub use crate::transactions::TransactionSimulationResponse;
+pub use crate::Direction;
+pub use crate::ExecuteTransactionQueryParameters;
+
 use crate::health::Threshold;
-use crate::info::NodeInfo;
-use crate::objects::DynamicFieldInfo;
-use crate::objects::ListDynamicFieldsQueryParameters;
-use crate::system::GasInfo;
-use crate::system::ProtocolConfigResponse;
-use crate::system::SystemStateSummary;
+use crate::openapi::ApiEndpoint;
 use crate::system::X_SUI_MAX_SUPPORTED_PROTOCOL_VERSION;
 use crate::system::X_SUI_MIN_SUPPORTED_PROTOCOL_VERSION;
-use crate::transactions::ListTransactionsQueryParameters;
-use crate::transactions::ResolveTransactionQueryParameters;
-use crate::transactions::ResolveTransactionResponse;
-use crate::transactions::TransactionExecutionResponse;
-use crate::transactions::TransactionResponse;
-use crate::transactions::TransactionSimulationResponse;
 use crate::types::X_SUI_CHAIN;
 use crate::types::X_SUI_CHAIN_ID;
 use crate::types::X_SUI_CHECKPOINT_HEIGHT;
@@ -49,14 +64,64 @@ use crate::types::X_SUI_EPOCH;
 use crate::types::X_SUI_LOWEST_AVAILABLE_CHECKPOINT;
 use crate::types::X_SUI_LOWEST_AVAILABLE_CHECKPOINT_OBJECTS;
 use crate::types::X_SUI_TIMESTAMP_MS;
-use crate::ExecuteTransactionQueryParameters;
+use crate::RestService;
 
 static USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"),);
 
+/// The encoding to request from endpoints which are able to respond with either JSON or BCS.
+///
+/// Endpoints which only support one of the two always use that encoding.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    #[default]
+    Bcs,
+}
+
+/// Policy for retrying requests which fail with a transient error, e.g. a failure to connect or a
+/// `503 Service Unavailable` response.
+///
+/// Retries back off exponentially, starting at `initial_backoff` and doubling with each attempt up
+/// to `max_backoff`.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// A policy which never retries a request.
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Default::default()
+        }
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_backoff)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Client {
     inner: reqwest::Client,
     url: Box<reqwest::Url>, // Boxed to save space
+    format: Format,
+    retry_policy: RetryPolicy,
 }
 
 impl Client {
@@ -79,51 +144,51 @@ impl Client {
         Self {
             inner,
             url: Box::new(url),
+            format: Format::default(),
+            retry_policy: RetryPolicy::default(),
         }
         .pipe(Ok)
     }
 
-    pub(super) fn client(&self) -> &reqwest::Client {
-        &self.inner
+    /// Set the encoding requested from endpoints which support both JSON and BCS.
+    pub fn with_format(mut self, format: Format) -> Self {
+        self.format = format;
+        self
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
     }
 
     pub fn url(&self) -> &Url {
         &self.url
     }
 
-    pub async fn node_info(&self) -> Result<Response<NodeInfo>> {
-        let url = self.url().join("")?;
+    pub fn format(&self) -> Format {
+        self.format
+    }
 
-        let response = self
-            .inner
-            .get(url)
-            .header(reqwest::header::ACCEPT, crate::APPLICATION_JSON)
-            .send()
-            .await?;
+    pub async fn node_info(&self) -> Result<Response<NodeInfo>> {
+        let request = self.request(&crate::info::GetNodeInfo, &[])?;
 
-        self.json(response).await
+        self.send_json(request).await
     }
 
     pub async fn health_check(&self, threshold_seconds: Option<u32>) -> Result<Response<()>> {
-        let url = self.url().join("health")?;
         let query = Threshold { threshold_seconds };
+        let request = self
+            .request(&crate::health::HealthCheck, &[])?
+            .query(&query);
 
-        let response = self.inner.get(url).query(&query).send().await?;
-
+        let response = self.send(request).await?;
         self.empty(response).await
     }
 
     pub async fn get_coin_info(&self, coin_type: &StructTag) -> Result<Response<CoinInfo>> {
-        let url = self.url().join(&format!("coins/{coin_type}"))?;
+        let request = self.request(&crate::coins::GetCoinInfo, &[("coin_type", coin_type)])?;
 
-        let response = self
-            .inner
-            .get(url)
-            .header(reqwest::header::ACCEPT, crate::APPLICATION_JSON)
-            .send()
-            .await?;
-
-        self.json(response).await
+        self.send_json(request).await
     }
 
     pub async fn list_account_objects(
@@ -131,30 +196,70 @@ impl Client {
         account: Address,
         parameters: &ListAccountOwnedObjectsQueryParameters,
     ) -> Result<Response<Vec<AccountOwnedObjectInfo>>> {
-        let url = self.url().join(&format!("account/{account}/objects"))?;
+        let request = self
+            .request(
+                &crate::accounts::ListAccountObjects,
+                &[("account", &account)],
+            )?
+            .query(parameters);
+
+        self.send_json(request).await
+    }
 
-        let response = self
-            .inner
-            .get(url)
-            .query(parameters)
-            .header(reqwest::header::ACCEPT, crate::APPLICATION_JSON)
-            .send()
-            .await?;
+    /// Stream all of the objects owned by `account`, starting from `parameters.start`.
+    pub fn account_objects_stream(
+        &self,
+        account: Address,
+        parameters: ListAccountOwnedObjectsQueryParameters,
+    ) -> impl Stream<Item = Result<AccountOwnedObjectInfo>> + '_ {
+        paginate(parameters, move |parameters| async move {
+            self.list_account_objects(account, &parameters).await
+        })
+    }
 
-        self.json(response).await
+    pub async fn list_account_balances(
+        &self,
+        account: Address,
+        parameters: &ListAccountBalancesQueryParameters,
+    ) -> Result<Response<Vec<Balance>>> {
+        let request = self
+            .request(
+                &crate::accounts::ListAccountBalances,
+                &[("account", &account)],
+            )?
+            .query(parameters);
+
+        self.send_json(request).await
     }
 
-    pub async fn get_object(&self, object_id: ObjectId) -> Result<Response<Object>> {
-        let url = self.url().join(&format!("objects/{object_id}"))?;
+    /// Stream all of the balances of `account`, starting from `parameters.start`.
+    pub fn account_balances_stream(
+        &self,
+        account: Address,
+        parameters: ListAccountBalancesQueryParameters,
+    ) -> impl Stream<Item = Result<Balance>> + '_ {
+        paginate(parameters, move |parameters| async move {
+            self.list_account_balances(account, &parameters).await
+        })
+    }
 
-        let response = self
-            .inner
-            .get(url)
-            .header(reqwest::header::ACCEPT, crate::APPLICATION_BCS)
-            .send()
-            .await?;
+    pub async fn get_account_balance(
+        &self,
+        account: Address,
+        coin_type: &StructTag,
+    ) -> Result<Response<Balance>> {
+        let request = self.request(
+            &crate::accounts::GetAccountBalance,
+            &[("account", &account), ("coin_type", coin_type)],
+        )?;
+
+        self.send_json(request).await
+    }
 
-        self.bcs(response).await
+    pub async fn get_object(&self, object_id: ObjectId) -> Result<Response<Object>> {
+        let request = self.request(&crate::objects::GetObject, &[("object_id", &object_id)])?;
+
+        self.send_with_format(request).await
     }
 
     pub async fn get_object_with_version(
@@ -162,18 +267,12 @@ impl Client {
         object_id: ObjectId,
         version: Version,
     ) -> Result<Response<Object>> {
-        let url = self
-            .url()
-            .join(&format!("objects/{object_id}/version/{version}"))?;
-
-        let response = self
-            .inner
-            .get(url)
-            .header(reqwest::header::ACCEPT, crate::APPLICATION_BCS)
-            .send()
-            .await?;
+        let request = self.request(
+            &crate::objects::GetObjectWithVersion,
+            &[("object_id", &object_id), ("version", &version)],
+        )?;
 
-        self.bcs(response).await
+        self.send_with_format(request).await
     }
 
     pub async fn list_dynamic_fields(
@@ -181,30 +280,31 @@ impl Client {
         object_id: ObjectId,
         parameters: &ListDynamicFieldsQueryParameters,
     ) -> Result<Response<Vec<DynamicFieldInfo>>> {
-        let url = self.url().join(&format!("objects/{object_id}"))?;
-
-        let response = self
-            .inner
-            .get(url)
-            .query(parameters)
-            .header(reqwest::header::ACCEPT, crate::APPLICATION_JSON)
-            .send()
-            .await?;
+        let request = self
+            .request(
+                &crate::objects::ListDynamicFields,
+                &[("object_id", &object_id)],
+            )?
+            .query(parameters);
+
+        self.send_json(request).await
+    }
 
-        self.json(response).await
+    /// Stream all of the dynamic fields of `object_id`, starting from `parameters.start`.
+    pub fn dynamic_fields_stream(
+        &self,
+        object_id: ObjectId,
+        parameters: ListDynamicFieldsQueryParameters,
+    ) -> impl Stream<Item = Result<DynamicFieldInfo>> + '_ {
+        paginate(parameters, move |parameters| async move {
+            self.list_dynamic_fields(object_id, &parameters).await
+        })
     }
 
     pub async fn get_gas_info(&self) -> Result<Response<GasInfo>> {
-        let url = self.url().join("system/gas")?;
-
-        let response = self
-            .inner
-            .get(url)
-            .header(reqwest::header::ACCEPT, crate::APPLICATION_JSON)
-            .send()
-            .await?;
+        let request = self.request(&crate::system::GetGasInfo, &[])?;
 
-        self.json(response).await
+        self.send_json(request).await
     }
 
     pub async fn get_reference_gas_price(&self) -> Result<u64> {
@@ -215,89 +315,48 @@ impl Client {
     }
 
     pub async fn get_current_protocol_config(&self) -> Result<Response<ProtocolConfigResponse>> {
-        let url = self.url().join("system/protocol")?;
+        let request = self.request(&crate::system::GetCurrentProtocolConfig, &[])?;
 
-        let response = self
-            .inner
-            .get(url)
-            .header(reqwest::header::ACCEPT, crate::APPLICATION_JSON)
-            .send()
-            .await?;
-
-        self.json(response).await
+        self.send_json(request).await
     }
 
     pub async fn get_protocol_config(
         &self,
         version: u64,
     ) -> Result<Response<ProtocolConfigResponse>> {
-        let url = self.url().join(&format!("system/protocol/{version}"))?;
-
-        let response = self
-            .inner
-            .get(url)
-            .header(reqwest::header::ACCEPT, crate::APPLICATION_JSON)
-            .send()
-            .await?;
+        let request = self.request(&crate::system::GetProtocolConfig, &[("version", &version)])?;
 
-        self.json(response).await
+        self.send_json(request).await
     }
 
     pub async fn get_system_state_summary(&self) -> Result<Response<SystemStateSummary>> {
-        let url = self.url().join("system")?;
-
-        let response = self
-            .inner
-            .get(url)
-            .header(reqwest::header::ACCEPT, crate::APPLICATION_JSON)
-            .send()
-            .await?;
+        let request = self.request(&crate::system::GetSystemStateSummary, &[])?;
 
-        self.json(response).await
+        self.send_json(request).await
     }
 
     pub async fn get_current_committee(&self) -> Result<Response<ValidatorCommittee>> {
-        let url = self.url().join("system/committee")?;
+        let request = self.request(&crate::committee::GetLatestCommittee, &[])?;
 
-        let response = self
-            .inner
-            .get(url)
-            .header(reqwest::header::ACCEPT, crate::APPLICATION_BCS)
-            .send()
-            .await?;
-
-        self.bcs(response).await
+        self.send_with_format(request).await
     }
 
     pub async fn get_committee(&self, epoch: EpochId) -> Result<Response<ValidatorCommittee>> {
-        let url = self.url().join(&format!("system/committee/{epoch}"))?;
+        let request = self.request(&crate::committee::GetCommittee, &[("epoch", &epoch)])?;
 
-        let response = self
-            .inner
-            .get(url)
-            .header(reqwest::header::ACCEPT, crate::APPLICATION_BCS)
-            .send()
-            .await?;
-
-        self.bcs(response).await
+        self.send_with_format(request).await
     }
 
     pub async fn get_checkpoint(
         &self,
         checkpoint_sequence_number: CheckpointSequenceNumber,
     ) -> Result<Response<CheckpointResponse>> {
-        let url = self
-            .url()
-            .join(&format!("checkpoints/{checkpoint_sequence_number}"))?;
-
-        let response = self
-            .inner
-            .get(url)
-            .header(reqwest::header::ACCEPT, crate::APPLICATION_BCS)
-            .send()
-            .await?;
+        let request = self.request(
+            &crate::checkpoints::GetCheckpoint,
+            &[("checkpoint", &checkpoint_sequence_number)],
+        )?;
 
-        self.bcs(response).await
+        self.send_with_format(request).await
     }
 
     pub async fn get_latest_checkpoint(&self) -> Result<Response<SignedCheckpointSummary>> {
@@ -325,127 +384,177 @@ impl Client {
         &self,
         parameters: &ListCheckpointsQueryParameters,
     ) -> Result<Response<Vec<CheckpointResponse>>> {
-        let url = self.url().join("checkpoints")?;
+        let request = self
+            .request(&crate::checkpoints::ListCheckpoints, &[])?
+            .query(parameters);
 
-        let response = self
-            .inner
-            .get(url)
-            .query(parameters)
-            .header(reqwest::header::ACCEPT, crate::APPLICATION_BCS)
-            .send()
-            .await?;
+        self.send_with_format(request).await
+    }
 
-        self.bcs(response).await
+    /// Stream checkpoints, starting from `parameters.start`, in the direction given by
+    /// `parameters.direction`.
+    pub fn checkpoints_stream(
+        &self,
+        parameters: ListCheckpointsQueryParameters,
+    ) -> impl Stream<Item = Result<CheckpointResponse>> + '_ {
+        paginate(parameters, move |parameters| async move {
+            self.list_checkpoints(&parameters).await
+        })
     }
 
     pub async fn get_full_checkpoint(
         &self,
         checkpoint_sequence_number: CheckpointSequenceNumber,
     ) -> Result<Response<CheckpointData>> {
-        let url = self
-            .url()
-            .join(&format!("checkpoints/{checkpoint_sequence_number}/full"))?;
+        let request = self.request(
+            &crate::checkpoints::GetFullCheckpoint,
+            &[("checkpoint", &checkpoint_sequence_number)],
+        )?;
 
-        let response = self
-            .inner
-            .get(url)
-            .header(reqwest::header::ACCEPT, crate::APPLICATION_BCS)
-            .send()
-            .await?;
+        self.send_bcs(request).await
+    }
 
-        self.bcs(response).await
+    pub async fn list_full_checkpoints(
+        &self,
+        parameters: &ListFullCheckpointsQueryParameters,
+    ) -> Result<Response<Vec<CheckpointData>>> {
+        let request = self
+            .request(&crate::checkpoints::ListFullCheckpoints, &[])?
+            .query(parameters);
+
+        self.send_bcs(request).await
     }
 
     pub async fn get_transaction(
         &self,
         transaction: &TransactionDigest,
     ) -> Result<Response<TransactionResponse>> {
-        let url = self.url().join(&format!("transactions/{transaction}"))?;
-
-        let response = self
-            .inner
-            .get(url)
-            .header(reqwest::header::ACCEPT, crate::APPLICATION_BCS)
-            .send()
-            .await?;
+        let request = self.request(
+            &crate::transactions::GetTransaction,
+            &[("transaction", transaction)],
+        )?;
 
-        self.bcs(response).await
+        self.send_with_format(request).await
     }
 
     pub async fn list_transactions(
         &self,
         parameters: &ListTransactionsQueryParameters,
     ) -> Result<Response<Vec<TransactionResponse>>> {
-        let url = self.url().join("transactions")?;
+        let request = self
+            .request(&crate::transactions::ListTransactions, &[])?
+            .query(parameters);
 
-        let response = self
-            .inner
-            .get(url)
-            .query(parameters)
-            .header(reqwest::header::ACCEPT, crate::APPLICATION_BCS)
-            .send()
-            .await?;
+        self.send_with_format(request).await
+    }
 
-        self.bcs(response).await
+    /// Stream transactions, starting from `parameters.start`, in the direction given by
+    /// `parameters.direction`.
+    pub fn transactions_stream(
+        &self,
+        parameters: ListTransactionsQueryParameters,
+    ) -> impl Stream<Item = Result<TransactionResponse>> + '_ {
+        paginate(parameters, move |parameters| async move {
+            self.list_transactions(&parameters).await
+        })
+    }
+
+    pub async fn list_events(
+        &self,
+        parameters: &ListEventsQueryParameters,
+    ) -> Result<Response<Vec<EventResponse>>> {
+        let request = self
+            .request(&crate::events::ListEvents, &[])?
+            .query(parameters);
+
+        self.send_json(request).await
     }
 
+    /// Stream the events selected by `parameters`, starting from `parameters.start`.
+    pub fn events_stream(
+        &self,
+        parameters: ListEventsQueryParameters,
+    ) -> impl Stream<Item = Result<EventResponse>> + '_ {
+        paginate(parameters, move |parameters| async move {
+            self.list_events(&parameters).await
+        })
+    }
+
+    /// Execute a signed transaction.
+    ///
+    /// Once the request may have reached the server, it is not retried: a retry after a timeout
+    /// could report a misleading error for a transaction that was already submitted. Only failures
+    /// to connect are retried, according to the client's [`RetryPolicy`].
     pub async fn execute_transaction(
         &self,
         parameters: &ExecuteTransactionQueryParameters,
         transaction: &SignedTransaction,
     ) -> Result<Response<TransactionExecutionResponse>> {
-        let url = self.url().join("transactions")?;
-
         let body = bcs::to_bytes(transaction)?;
 
-        let response = self
-            .inner
-            .post(url)
+        let request = self
+            .request(&crate::transactions::ExecuteTransaction, &[])?
             .query(parameters)
-            .header(reqwest::header::ACCEPT, crate::APPLICATION_BCS)
             .header(reqwest::header::CONTENT_TYPE, crate::APPLICATION_BCS)
-            .body(body)
-            .send()
-            .await?;
+            .body(body);
 
-        self.bcs(response).await
+        match self.format {
+            Format::Json => {
+                let request = request.header(reqwest::header::ACCEPT, crate::APPLICATION_JSON);
+                let response = self.send_unless_connected(request).await?;
+                self.json(response).await
+            }
+            Format::Bcs => {
+                let request = request.header(reqwest::header::ACCEPT, crate::APPLICATION_BCS);
+                let response = self.send_unless_connected(request).await?;
+                self.bcs(response).await
+            }
+        }
+    }
+
+    /// Report the progress of a transaction submitted for execution through the node.
+    pub async fn get_pending_transaction(
+        &self,
+        transaction: &TransactionDigest,
+    ) -> Result<Response<PendingTransactionResponse>> {
+        let request = self.request(
+            &crate::transactions::GetPendingTransaction,
+            &[("transaction", transaction)],
+        )?;
+
+        self.send_json(request).await
     }
 
     pub async fn simulate_transaction(
         &self,
         transaction: &Transaction,
     ) -> Result<Response<TransactionSimulationResponse>> {
-        let url = self.url().join("transactions/simulate")?;
+        self.simulate_transaction_with_parameters(transaction, &Default::default())
+            .await
+    }
 
+    pub async fn simulate_transaction_with_parameters(
+        &self,
+        transaction: &Transaction,
+        parameters: &SimulateTransactionQueryParameters,
+    ) -> Result<Response<TransactionSimulationResponse>> {
         let body = bcs::to_bytes(transaction)?;
 
-        let response = self
-            .inner
-            .post(url)
-            .header(reqwest::header::ACCEPT, crate::APPLICATION_BCS)
+        let request = self
+            .request(&crate::transactions::SimulateTransaction, &[])?
+            .query(parameters)
             .header(reqwest::header::CONTENT_TYPE, crate::APPLICATION_BCS)
-            .body(body)
-            .send()
-            .await?;
+            .body(body);
 
-        self.bcs(response).await
+        self.send_with_format(request).await
     }
 
     pub async fn resolve_transaction(
         &self,
         unresolved_transaction: &UnresolvedTransaction,
     ) -> Result<Response<ResolveTransactionResponse>> {
-        let url = self.url.join("transactions/resolve")?;
-
-        let response = self
-            .inner
-            .post(url)
-            .header(reqwest::header::ACCEPT, crate::APPLICATION_BCS)
-            .json(unresolved_transaction)
-            .send()
-            .await?;
-
-        self.bcs(response).await
+        self.resolve_transaction_with_parameters(unresolved_transaction, &Default::default())
+            .await
     }
 
     pub async fn resolve_transaction_with_parameters(
@@ -453,21 +562,111 @@ impl Client {
         unresolved_transaction: &UnresolvedTransaction,
         parameters: &ResolveTransactionQueryParameters,
     ) -> Result<Response<ResolveTransactionResponse>> {
-        let url = self.url.join("transactions/resolve")?;
+        let request = self
+            .request(&crate::transactions::ResolveTransaction, &[])?
+            .query(parameters)
+            .json(unresolved_transaction);
+
+        self.send_with_format(request).await
+    }
+
+    /// Build a request for `endpoint`, substituting each of the `{name}` segments in its path
+    /// with the matching value in `path_parameters`.
+    pub(super) fn request(
+        &self,
+        endpoint: &dyn ApiEndpoint<RestService>,
+        path_parameters: &[(&str, &dyn std::fmt::Display)],
+    ) -> Result<reqwest::RequestBuilder> {
+        let mut path = endpoint.path().trim_start_matches('/').to_owned();
+
+        for (name, value) in path_parameters {
+            path = path.replace(&format!("{{{name}}}"), &value.to_string());
+        }
+
+        if path.contains('{') {
+            return Err(Error::new_message(format!(
+                "missing path parameters for '{}'",
+                endpoint.path()
+            )));
+        }
+
+        let url = self.url().join(&path)?;
+
+        Ok(self.inner.request(endpoint.method(), url))
+    }
+
+    /// Send `request`, retrying transient failures according to the client's [`RetryPolicy`].
+    pub(super) async fn send(&self, request: reqwest::RequestBuilder) -> Result<reqwest::Response> {
+        self.send_with_retries(request, Retries::Transient).await
+    }
+
+    /// Send a request that must not be repeated once it may have reached the server, only
+    /// retrying failures to connect.
+    pub(super) async fn send_unless_connected(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response> {
+        self.send_with_retries(request, Retries::ConnectOnly).await
+    }
+
+    async fn send_with_retries(
+        &self,
+        request: reqwest::RequestBuilder,
+        retries: Retries,
+    ) -> Result<reqwest::Response> {
+        let mut attempt = 0;
+
+        loop {
+            // All request bodies sent by this client are buffered in memory, so requests can
+            // always be cloned.
+            let Some(current) = request.try_clone() else {
+                return request.send().await.map_err(Into::into);
+            };
+
+            let last_attempt = attempt >= self.retry_policy.max_retries;
+
+            match current.send().await {
+                Ok(response) if last_attempt || !retries.status(response.status()) => {
+                    return Ok(response)
+                }
+                Err(e) if last_attempt || !retries.error(&e) => return Err(e.into()),
+                _ => {}
+            }
 
-        let response = self
-            .inner
-            .post(url)
-            .query(&parameters)
-            .header(reqwest::header::ACCEPT, crate::APPLICATION_BCS)
-            .json(unresolved_transaction)
-            .send()
-            .await?;
+            tokio::time::sleep(self.retry_policy.backoff(attempt)).await;
+            attempt += 1;
+        }
+    }
 
+    async fn send_json<T: serde::de::DeserializeOwned>(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<Response<T>> {
+        let request = request.header(reqwest::header::ACCEPT, crate::APPLICATION_JSON);
+        let response = self.send(request).await?;
+        self.json(response).await
+    }
+
+    pub(super) async fn send_bcs<T: serde::de::DeserializeOwned>(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<Response<T>> {
+        let request = request.header(reqwest::header::ACCEPT, crate::APPLICATION_BCS);
+        let response = self.send(request).await?;
         self.bcs(response).await
     }
 
-    async fn check_response(
+    async fn send_with_format<T: serde::de::DeserializeOwned>(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<Response<T>> {
+        match self.format {
+            Format::Json => self.send_json(request).await,
+            Format::Bcs => self.send_bcs(request).await,
+        }
+    }
+
+    pub(super) async fn check_response(
         &self,
         response: reqwest::Response,
     ) -> Result<(reqwest::Response, ResponseParts)> {
@@ -515,6 +714,104 @@ impl Client {
     }
 }
 
+/// Which failures of a request are retried.
+#[derive(Clone, Copy, Debug)]
+enum Retries {
+    /// Failures to connect, timeouts, and transient error responses.
+    Transient,
+    /// Only failures to connect, where the request never reached the server.
+    ConnectOnly,
+}
+
+impl Retries {
+    fn status(self, status: StatusCode) -> bool {
+        match self {
+            Retries::Transient => matches!(
+                status,
+                StatusCode::TOO_MANY_REQUESTS
+                    | StatusCode::BAD_GATEWAY
+                    | StatusCode::SERVICE_UNAVAILABLE
+                    | StatusCode::GATEWAY_TIMEOUT
+            ),
+            Retries::ConnectOnly => false,
+        }
+    }
+
+    fn error(self, error: &reqwest::Error) -> bool {
+        match self {
+            Retries::Transient => error.is_connect() || error.is_timeout(),
+            Retries::ConnectOnly => error.is_connect(),
+        }
+    }
+}
+
+/// Query parameters of a paginated endpoint.
+pub trait PaginationParameters: Clone {
+    /// Update the parameters to request the page starting at `cursor`, as returned in the
+    /// `X_SUI_CURSOR` header of the previous page.
+    fn set_cursor(&mut self, cursor: &str) -> Result<()>;
+}
+
+macro_rules! impl_pagination_parameters {
+    ($($parameters:ty),* $(,)?) => {
+        $(
+            impl PaginationParameters for $parameters {
+                fn set_cursor(&mut self, cursor: &str) -> Result<()> {
+                    let cursor = cursor
+                        .parse()
+                        .map_err(|_| Error::new_message(format!("invalid cursor '{cursor}'")))?;
+                    self.start = Some(cursor);
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_pagination_parameters!(
+    ListAccountOwnedObjectsQueryParameters,
+    ListAccountBalancesQueryParameters,
+    ListDynamicFieldsQueryParameters,
+    ListCheckpointsQueryParameters,
+    ListFullCheckpointsQueryParameters,
+    ListTransactionsQueryParameters,
+    ListEventsQueryParameters,
+);
+
+/// Turn a paginated endpoint into a stream of its items, calling `fetch` to request each page
+/// until the server stops returning a cursor.
+pub fn paginate<'a, P, T, F, Fut>(parameters: P, mut fetch: F) -> impl Stream<Item = Result<T>> + 'a
+where
+    P: PaginationParameters + 'a,
+    T: 'a,
+    F: FnMut(P) -> Fut + 'a,
+    Fut: std::future::Future<Output = Result<Response<Vec<T>>>> + 'a,
+{
+    futures::stream::try_unfold(Some(parameters), move |parameters| {
+        let request = parameters.map(|parameters| (fetch(parameters.clone()), parameters));
+
+        async move {
+            let Some((request, mut parameters)) = request else {
+                return Ok(None);
+            };
+
+            let (page, parts) = request.await?.into_parts();
+
+            let next = match parts.cursor {
+                Some(cursor) => {
+                    parameters.set_cursor(&cursor)?;
+                    Some(parameters)
+                }
+                None => None,
+            };
+
+            Ok(Some((page, next)))
+        }
+    })
+    .map_ok(|page| futures::stream::iter(page.into_iter().map(Ok)))
+    .try_flatten()
+}
+
 #[derive(Debug)]
 pub struct ResponseParts {
     pub status: StatusCode,
@@ -739,3 +1036,72 @@ impl From<sui_types::sui_sdk_types_conversions::SdkTypeConversionError> for Erro
         Self::from_error(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn request_path_from_endpoint() {
+        let client = Client::new("http://localhost:9000").unwrap();
+        let object_id = ObjectId::new([0; 32]);
+
+        let request = client
+            .request(
+                &crate::objects::ListDynamicFields,
+                &[("object_id", &object_id)],
+            )
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(request.method(), reqwest::Method::GET);
+        assert_eq!(
+            request.url().as_str(),
+            format!("http://localhost:9000/v2/objects/{object_id}/dynamic-fields")
+        );
+
+        let request = client
+            .request(&crate::health::HealthCheck, &[])
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(request.url().as_str(), "http://localhost:9000/v2/-/health");
+
+        // Every path parameter must be provided
+        client
+            .request(
+                &crate::objects::GetObjectWithVersion,
+                &[("object_id", &object_id)],
+            )
+            .unwrap_err();
+    }
+
+    #[test]
+    fn execution_is_not_retried_after_reaching_the_server() {
+        for status in [
+            StatusCode::TOO_MANY_REQUESTS,
+            StatusCode::SERVICE_UNAVAILABLE,
+            StatusCode::GATEWAY_TIMEOUT,
+        ] {
+            assert!(Retries::Transient.status(status));
+            assert!(!Retries::ConnectOnly.status(status));
+        }
+
+        assert!(!Retries::Transient.status(StatusCode::BAD_REQUEST));
+    }
+
+    #[test]
+    fn retry_backoff() {
+        let policy = RetryPolicy {
+            max_retries: 10,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(1),
+        };
+
+        assert_eq!(policy.backoff(0), Duration::from_millis(100));
+        assert_eq!(policy.backoff(1), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(800));
+        assert_eq!(policy.backoff(4), Duration::from_secs(1));
+        assert_eq!(policy.backoff(40), Duration::from_secs(1));
+    }
+}
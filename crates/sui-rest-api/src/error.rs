@@ -166,3 +166,21 @@ impl From<sui_types::quorum_driver_types::QuorumDriverError> for RestError {
         }
     }
 }
+
+impl From<RestError> for tonic::Status {
+    fn from(value: RestError) -> Self {
+        let code = match value.status {
+            StatusCode::BAD_REQUEST | StatusCode::UNPROCESSABLE_ENTITY => {
+                tonic::Code::InvalidArgument
+            }
+            StatusCode::NOT_FOUND => tonic::Code::NotFound,
+            StatusCode::GONE => tonic::Code::OutOfRange,
+            StatusCode::CONFLICT => tonic::Code::Aborted,
+            StatusCode::SERVICE_UNAVAILABLE => tonic::Code::Unavailable,
+            StatusCode::NOT_IMPLEMENTED => tonic::Code::Unimplemented,
+            _ => tonic::Code::Internal,
+        };
+
+        tonic::Status::new(code, value.message.unwrap_or_default())
+    }
+}
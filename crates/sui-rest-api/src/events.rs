@@ -0,0 +1,362 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use axum::extract::{Query, State};
+use axum::http::StatusCode;
+use sui_sdk_types::types::{
+    CheckpointSequenceNumber, Event, StructTag, TransactionDigest, TransactionEvents,
+};
+use sui_types::effects::TransactionEffectsAPI;
+use sui_types::sui_sdk_types_conversions::struct_tag_sdk_to_core;
+use tap::Pipe;
+
+use crate::openapi::{ApiEndpoint, OperationBuilder, ResponseBuilder, RouteHandler};
+use crate::reader::StateReader;
+use crate::response::ResponseContent;
+use crate::transactions::TransactionNotFoundError;
+use crate::{Page, RestError, RestService, Result};
+
+pub struct ListEvents;
+
+impl ApiEndpoint<RestService> for ListEvents {
+    fn method(&self) -> axum::http::Method {
+        axum::http::Method::GET
+    }
+
+    fn path(&self) -> &'static str {
+        "/events"
+    }
+
+    fn operation(
+        &self,
+        generator: &mut schemars::gen::SchemaGenerator,
+    ) -> openapiv3::v3_1::Operation {
+        OperationBuilder::new()
+            .tag("Events")
+            .operation_id("ListEvents")
+            .query_parameters::<ListEventsQueryParameters>(generator)
+            .response(
+                200,
+                ResponseBuilder::new()
+                    .json_content::<Vec<EventResponse>>(generator)
+                    .header::<String>(crate::types::X_SUI_CURSOR, generator)
+                    .build(),
+            )
+            .response(400, ResponseBuilder::new().build())
+            .response(404, ResponseBuilder::new().build())
+            .build()
+    }
+
+    fn handler(&self) -> RouteHandler<RestService> {
+        RouteHandler::new(self.method(), list_events)
+    }
+}
+
+async fn list_events(
+    Query(parameters): Query<ListEventsQueryParameters>,
+    State(state): State<StateReader>,
+) -> Result<Page<EventResponse, EventCursor>> {
+    let limit = parameters.limit();
+
+    let mut events = match (parameters.transaction, &parameters.event_type) {
+        (Some(transaction), None) => {
+            list_transaction_events(&state, transaction, parameters.start, limit + 1)?
+        }
+        (None, Some(event_type)) => {
+            list_events_by_type(&state, event_type, parameters.start, limit + 1)?
+        }
+        _ => {
+            return Err(RestError::new(
+                StatusCode::BAD_REQUEST,
+                "exactly one of `transaction` or `event_type` must be provided",
+            ))
+        }
+    };
+
+    let cursor = if events.len() > limit {
+        // SAFETY: We've already verified that events is greater than limit, which is
+        // gaurenteed to be >= 1.
+        events.pop().unwrap().0.pipe(Some)
+    } else {
+        None
+    };
+
+    events
+        .into_iter()
+        .map(|(_, event)| event)
+        .collect::<Vec<_>>()
+        .pipe(ResponseContent::Json)
+        .pipe(|entries| Page { entries, cursor })
+        .pipe(Ok)
+}
+
+/// Up to `limit` of the events emitted by `transaction`, starting from `start`.
+fn list_transaction_events(
+    state: &StateReader,
+    transaction: TransactionDigest,
+    start: Option<EventCursor>,
+    limit: usize,
+) -> Result<Vec<(EventCursor, EventResponse)>> {
+    let checkpoint = state
+        .inner()
+        .get_transaction_checkpoint(&transaction.into())?
+        .ok_or(TransactionNotFoundError(transaction))?;
+
+    let digest: sui_types::digests::TransactionDigest = transaction.into();
+    let transaction_index = state
+        .inner()
+        .get_checkpoint_contents_by_sequence_number(checkpoint)?
+        .and_then(|contents| {
+            contents
+                .iter()
+                .position(|digests| digests.transaction == digest)
+        })
+        .ok_or(TransactionNotFoundError(transaction))? as u32;
+
+    get_transaction_events(state, transaction)?
+        .into_iter()
+        .enumerate()
+        .map(|(event_index, event)| {
+            let cursor = EventCursor {
+                checkpoint,
+                transaction_index,
+                event_index: event_index as u32,
+            };
+
+            let event = EventResponse {
+                checkpoint,
+                transaction_digest: transaction,
+                event_index: event_index as u32,
+                event,
+            };
+
+            (cursor, event)
+        })
+        .filter(|(cursor, _)| start.map_or(true, |start| *cursor >= start))
+        .take(limit)
+        .collect::<Vec<_>>()
+        .pipe(Ok)
+}
+
+/// Up to `limit` events of type `event_type`, in the order they were emitted, starting from
+/// `start`.
+fn list_events_by_type(
+    state: &StateReader,
+    event_type: &StructTag,
+    start: Option<EventCursor>,
+    limit: usize,
+) -> Result<Vec<(EventCursor, EventResponse)>> {
+    let event_type = struct_tag_sdk_to_core(event_type.clone())?;
+    let start = start.map(
+        |EventCursor {
+             checkpoint,
+             transaction_index,
+             event_index,
+         }| (checkpoint, transaction_index, event_index),
+    );
+
+    let mut events = Vec::new();
+
+    // Consecutive events of the same type are often emitted by the same transaction, so hold on
+    // to the last transaction's events to avoid reading them again.
+    let mut last: Option<(TransactionDigest, Vec<Event>)> = None;
+
+    for info in state
+        .inner()
+        .event_type_iter(&event_type, start)?
+        .take(limit)
+    {
+        let transaction_digest: TransactionDigest = info.transaction_digest.into();
+
+        if last
+            .as_ref()
+            .map_or(true, |(digest, _)| *digest != transaction_digest)
+        {
+            let events = get_transaction_events(state, transaction_digest)?;
+            last = Some((transaction_digest, events));
+        }
+
+        // SAFETY: `last` was populated with this transaction's events above.
+        let (_, transaction_events) = last.as_ref().unwrap();
+
+        let event = transaction_events
+            .get(info.event_index as usize)
+            .cloned()
+            .ok_or_else(|| {
+                RestError::new(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    format!(
+                        "Event {} of transaction {transaction_digest} is missing",
+                        info.event_index
+                    ),
+                )
+            })?;
+
+        let cursor = EventCursor {
+            checkpoint: info.checkpoint,
+            transaction_index: info.transaction_index,
+            event_index: info.event_index,
+        };
+
+        events.push((
+            cursor,
+            EventResponse {
+                checkpoint: info.checkpoint,
+                transaction_digest,
+                event_index: info.event_index,
+                event,
+            },
+        ));
+    }
+
+    Ok(events)
+}
+
+fn get_transaction_events(state: &StateReader, digest: TransactionDigest) -> Result<Vec<Event>> {
+    let effects = state
+        .inner()
+        .get_transaction_effects(&digest.into())?
+        .ok_or(TransactionNotFoundError(digest))?;
+
+    let Some(events_digest) = effects.events_digest() else {
+        return Ok(Vec::new());
+    };
+
+    let TransactionEvents(events) = state
+        .inner()
+        .get_events(events_digest)?
+        .ok_or(TransactionNotFoundError(digest))?
+        .try_into()?;
+
+    Ok(events)
+}
+
+/// An event, along with the transaction that emitted it.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+pub struct EventResponse {
+    #[serde_as(as = "sui_types::sui_serde::BigInt<u64>")]
+    #[schemars(with = "crate::_schemars::U64")]
+    pub checkpoint: CheckpointSequenceNumber,
+    pub transaction_digest: TransactionDigest,
+    /// Index of the event within the events emitted by its transaction.
+    pub event_index: u32,
+    pub event: Event,
+}
+
+/// A Cursor that points at a specific event in history.
+///
+/// Has the format of: `<checkpoint>.<transaction>.<event>`
+/// where `<checkpoint>` is the sequence number of a checkpoint, `<transaction>` is the index of a
+/// transaction in the particular checkpoint, and `<event>` is the index of an event emitted by
+/// that transaction.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EventCursor {
+    checkpoint: CheckpointSequenceNumber,
+    transaction_index: u32,
+    event_index: u32,
+}
+
+impl std::fmt::Display for EventCursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}.{}.{}",
+            self.checkpoint, self.transaction_index, self.event_index
+        )
+    }
+}
+
+impl std::str::FromStr for EventCursor {
+    type Err = InvalidEventCursor;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let mut parts = s.split('.');
+        let (Some(checkpoint), Some(transaction_index), Some(event_index), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(InvalidEventCursor);
+        };
+
+        Self {
+            checkpoint: checkpoint.parse().map_err(|_| InvalidEventCursor)?,
+            transaction_index: transaction_index.parse().map_err(|_| InvalidEventCursor)?,
+            event_index: event_index.parse().map_err(|_| InvalidEventCursor)?,
+        }
+        .pipe(Ok)
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidEventCursor;
+
+impl std::fmt::Display for InvalidEventCursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("invalid event cursor, expected `<checkpoint>.<transaction>.<event>`")
+    }
+}
+
+impl std::error::Error for InvalidEventCursor {}
+
+impl<'de> serde::Deserialize<'de> for EventCursor {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde_with::DeserializeAs;
+        serde_with::DisplayFromStr::deserialize_as(deserializer)
+    }
+}
+
+impl serde::Serialize for EventCursor {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde_with::SerializeAs;
+        serde_with::DisplayFromStr::serialize_as(self, serializer)
+    }
+}
+
+/// Exactly one of `transaction` or `event_type` must be provided.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+pub struct ListEventsQueryParameters {
+    /// List the events emitted by this transaction.
+    pub transaction: Option<TransactionDigest>,
+    /// List the events of this type, in the order they were emitted.
+    pub event_type: Option<StructTag>,
+    pub limit: Option<u32>,
+    #[schemars(with = "Option<String>")]
+    pub start: Option<EventCursor>,
+}
+
+impl ListEventsQueryParameters {
+    pub fn limit(&self) -> usize {
+        self.limit
+            .map(|l| (l as usize).clamp(1, crate::MAX_PAGE_SIZE))
+            .unwrap_or(crate::DEFAULT_PAGE_SIZE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_cursor_roundtrip() {
+        let cursor: EventCursor = "10.2.3".parse().unwrap();
+        assert_eq!(
+            cursor,
+            EventCursor {
+                checkpoint: 10,
+                transaction_index: 2,
+                event_index: 3,
+            }
+        );
+        assert_eq!(cursor.to_string(), "10.2.3");
+
+        assert!("10.2".parse::<EventCursor>().is_err());
+        assert!("10.2.3.4".parse::<EventCursor>().is_err());
+        assert!("a.b.c".parse::<EventCursor>().is_err());
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use axum::http::StatusCode;
+use fastcrypto::traits::ToFromBytes;
+use sui_sdk_types::types::{
+    CheckpointDigest, ObjectId, SignedTransaction, TransactionDigest, UserSignature,
+};
+use sui_types::signature::GenericSignature;
+use tonic::{Request, Response, Status};
+
+use crate::checkpoints::{get_checkpoint_response, get_full_checkpoint_data, CheckpointId};
+use crate::objects::ObjectNotFoundError;
+use crate::proto;
+use crate::{RestError, RestService, Result};
+
+mod node_service {
+    include!(concat!(env!("OUT_DIR"), "/sui.rest.NodeService.rs"));
+}
+pub use node_service::{
+    node_service_client::NodeServiceClient,
+    node_service_server::{NodeService, NodeServiceServer},
+};
+
+#[tonic::async_trait]
+impl NodeService for RestService {
+    async fn get_object(
+        &self,
+        request: Request<proto::GetObjectRequest>,
+    ) -> Result<Response<proto::Object>, Status> {
+        let request = request.into_inner();
+        let object_id = ObjectId::new(bytes_32("object_id", &request.object_id)?);
+
+        let object = match request.version {
+            Some(version) => self
+                .reader
+                .get_object_with_version(object_id, version)?
+                .ok_or_else(|| {
+                    RestError::from(ObjectNotFoundError::new_with_version(object_id, version))
+                })?,
+            None => self
+                .reader
+                .get_object(object_id)?
+                .ok_or_else(|| RestError::from(ObjectNotFoundError::new(object_id)))?,
+        };
+
+        proto::Object::try_from(&object)
+            .map(Response::new)
+            .map_err(|e| RestError::from(e).into())
+    }
+
+    async fn get_checkpoint(
+        &self,
+        request: Request<proto::GetCheckpointRequest>,
+    ) -> Result<Response<proto::Checkpoint>, Status> {
+        use proto::get_checkpoint_request::CheckpointId as Id;
+
+        let request = request.into_inner();
+        let checkpoint_id = match request.checkpoint_id {
+            Some(Id::SequenceNumber(sequence_number)) => {
+                CheckpointId::SequenceNumber(sequence_number)
+            }
+            Some(Id::Digest(digest)) => {
+                CheckpointId::Digest(CheckpointDigest::new(bytes_32("digest", &digest)?))
+            }
+            None => return Err(missing_field("checkpoint_id")),
+        };
+
+        let response = get_checkpoint_response(&self.reader, checkpoint_id, request.contents)?;
+
+        proto::Checkpoint::try_from(response)
+            .map(Response::new)
+            .map_err(|e| RestError::from(e).into())
+    }
+
+    async fn get_full_checkpoint(
+        &self,
+        request: Request<proto::GetFullCheckpointRequest>,
+    ) -> Result<Response<proto::FullCheckpoint>, Status> {
+        use proto::get_full_checkpoint_request::CheckpointId as Id;
+
+        let checkpoint_id = match request.into_inner().checkpoint_id {
+            Some(Id::SequenceNumber(sequence_number)) => {
+                CheckpointId::SequenceNumber(sequence_number)
+            }
+            Some(Id::Digest(digest)) => {
+                CheckpointId::Digest(CheckpointDigest::new(bytes_32("digest", &digest)?))
+            }
+            None => return Err(missing_field("checkpoint_id")),
+        };
+
+        let checkpoint_data = get_full_checkpoint_data(&self.reader, checkpoint_id)?;
+
+        Ok(Response::new(proto::FullCheckpoint::from(&checkpoint_data)))
+    }
+
+    async fn get_transaction(
+        &self,
+        request: Request<proto::GetTransactionRequest>,
+    ) -> Result<Response<proto::ExecutedTransaction>, Status> {
+        let digest = TransactionDigest::new(bytes_32("digest", &request.into_inner().digest)?);
+
+        let response = self.reader.get_transaction_response(digest)?;
+
+        proto::ExecutedTransaction::try_from(response)
+            .map(Response::new)
+            .map_err(|e| RestError::from(e).into())
+    }
+
+    async fn execute_transaction(
+        &self,
+        request: Request<proto::ExecuteTransactionRequest>,
+    ) -> Result<Response<proto::ExecuteTransactionResponse>, Status> {
+        let executor = self
+            .executor
+            .as_ref()
+            .ok_or_else(|| Status::unimplemented("No Transaction Executor"))?;
+        let client_address = request.remote_addr();
+        let priority = crate::transactions::parse_transaction_priority(
+            &self.transaction_priority_allowlist,
+            client_address.map(|a| a.ip()),
+            request
+                .metadata()
+                .get(crate::types::X_SUI_TRANSACTION_PRIORITY)
+                .map(|value| value.to_str().unwrap_or_default()),
+        )?;
+        let request = request.into_inner();
+
+        let transaction = SignedTransaction {
+            transaction: bcs::from_bytes(&request.transaction)
+                .map_err(|e| Status::invalid_argument(format!("invalid transaction: {e}")))?,
+            signatures: request
+                .signatures
+                .iter()
+                .map(|signature| user_signature_from_bytes(signature))
+                .collect::<Result<_, _>>()
+                .map_err(|e| Status::invalid_argument(format!("invalid signature: {e}")))?,
+        };
+
+        let response = crate::transactions::execute(
+            executor,
+            transaction,
+            &(&request).into(),
+            priority,
+            client_address,
+        )
+        .await?;
+
+        proto::ExecuteTransactionResponse::try_from(response)
+            .map(Response::new)
+            .map_err(|e| RestError::from(e).into())
+    }
+}
+
+/// Decodes a `UserSignature` from its flag-prefixed bytes.
+fn user_signature_from_bytes(bytes: &[u8]) -> anyhow::Result<UserSignature> {
+    let signature = GenericSignature::from_bytes(bytes)?;
+    Ok(signature.try_into()?)
+}
+
+fn bytes_32(field: &str, bytes: &[u8]) -> Result<[u8; 32]> {
+    bytes.try_into().map_err(|_| {
+        RestError::new(
+            StatusCode::BAD_REQUEST,
+            format!("invalid {field}: expected 32 bytes, found {}", bytes.len()),
+        )
+    })
+}
+
+fn missing_field(field: &str) -> Status {
+    Status::invalid_argument(format!("missing {field}"))
+}
@@ -6,9 +6,12 @@ use mysten_network::callback::CallbackLayer;
 use openapi::ApiEndpoint;
 use reader::StateReader;
 use std::sync::Arc;
+use sui_types::messages_checkpoint::CheckpointSequenceNumber;
+use sui_types::quorum_driver_types::TransactionPriorityAllowlist;
 use sui_types::storage::RestStateReader;
 use sui_types::transaction_executor::TransactionExecutor;
 use tap::Pipe;
+use tokio::sync::watch;
 
 pub mod accept;
 mod accounts;
@@ -18,11 +21,14 @@ mod coins;
 mod committee;
 pub mod content_type;
 mod error;
+mod events;
+pub mod grpc;
 mod health;
 mod info;
 mod metrics;
 mod objects;
 pub mod openapi;
+pub mod proto;
 mod reader;
 mod response;
 mod system;
@@ -38,6 +44,7 @@ pub use transactions::ExecuteTransactionQueryParameters;
 pub const TEXT_PLAIN_UTF_8: &str = "text/plain; charset=utf-8";
 pub const APPLICATION_BCS: &str = "application/bcs";
 pub const APPLICATION_JSON: &str = "application/json";
+pub const APPLICATION_PROTOBUF: &str = "application/x-protobuf";
 
 #[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
 #[serde(rename_all = "lowercase")]
@@ -79,11 +86,14 @@ const ENDPOINTS: &[&dyn ApiEndpoint<RestService>] = &[
     &checkpoints::GetCheckpoint,
     // unstable APIs
     &accounts::ListAccountObjects,
+    &accounts::ListAccountBalances,
+    &accounts::GetAccountBalance,
     &objects::GetObject,
     &objects::GetObjectWithVersion,
     &objects::ListDynamicFields,
     &checkpoints::GetFullCheckpoint,
     &checkpoints::ListFullCheckpoints,
+    &checkpoints::StreamFullCheckpoints,
     &transactions::GetTransaction,
     &transactions::ListTransactions,
     &committee::GetCommittee,
@@ -94,8 +104,10 @@ const ENDPOINTS: &[&dyn ApiEndpoint<RestService>] = &[
     &system::GetGasInfo,
     &transactions::ExecuteTransaction,
     &transactions::SimulateTransaction,
+    &transactions::GetPendingTransaction,
     &transactions::ResolveTransaction,
     &coins::GetCoinInfo,
+    &events::ListEvents,
 ];
 
 #[derive(Clone)]
@@ -106,6 +118,8 @@ pub struct RestService {
     software_version: &'static str,
     metrics: Option<Arc<RestMetrics>>,
     config: Config,
+    executed_checkpoints: Option<watch::Receiver<CheckpointSequenceNumber>>,
+    transaction_priority_allowlist: TransactionPriorityAllowlist,
 }
 
 impl axum::extract::FromRef<RestService> for StateReader {
@@ -120,6 +134,18 @@ impl axum::extract::FromRef<RestService> for Option<Arc<dyn TransactionExecutor>
     }
 }
 
+impl axum::extract::FromRef<RestService> for Option<watch::Receiver<CheckpointSequenceNumber>> {
+    fn from_ref(input: &RestService) -> Self {
+        input.executed_checkpoints.clone()
+    }
+}
+
+impl axum::extract::FromRef<RestService> for TransactionPriorityAllowlist {
+    fn from_ref(input: &RestService) -> Self {
+        input.transaction_priority_allowlist.clone()
+    }
+}
+
 impl RestService {
     pub fn new(reader: Arc<dyn RestStateReader>, software_version: &'static str) -> Self {
         let chain_id = reader.get_chain_identifier().unwrap();
@@ -130,6 +156,8 @@ impl RestService {
             software_version,
             metrics: None,
             config: Config::default(),
+            executed_checkpoints: None,
+            transaction_priority_allowlist: TransactionPriorityAllowlist::default(),
         }
     }
 
@@ -149,6 +177,21 @@ impl RestService {
         self.metrics = Some(Arc::new(metrics));
     }
 
+    /// Follow the highest executed checkpoint through `executed_checkpoints`, so that checkpoint
+    /// streams are notified as soon as each new checkpoint is executed.
+    pub fn with_executed_checkpoints(
+        &mut self,
+        executed_checkpoints: watch::Receiver<CheckpointSequenceNumber>,
+    ) {
+        self.executed_checkpoints = Some(executed_checkpoints);
+    }
+
+    /// Honour the `x-sui-transaction-priority` header of transactions submitted by the clients on
+    /// `allowlist`.
+    pub fn with_transaction_priority_allowlist(&mut self, allowlist: TransactionPriorityAllowlist) {
+        self.transaction_priority_allowlist = allowlist;
+    }
+
     pub fn chain_id(&self) -> sui_types::digests::ChainIdentifier {
         self.chain_id
     }
@@ -160,13 +203,14 @@ impl RestService {
     pub fn into_router(self) -> Router {
         let metrics = self.metrics.clone();
 
+        let enable_unstable_apis = self.config.enable_unstable_apis();
         let mut api = openapi::Api::new(info(self.software_version()));
 
         api.register_endpoints(
             ENDPOINTS
                 .iter()
                 .copied()
-                .filter(|endpoint| endpoint.stable() || self.config.enable_unstable_apis()),
+                .filter(|endpoint| endpoint.stable() || enable_unstable_apis),
         );
 
         Router::new()
@@ -177,6 +221,18 @@ impl RestService {
             .route("/rest/*path", axum::routing::method_routing::any(redirect))
             .route("/rest", get(|| async { Redirect::permanent("/v2/") }))
             .route("/rest/", get(|| async { Redirect::permanent("/v2/") }))
+            .pipe(|router| {
+                // The gRPC service exposes the same data as a number of unstable endpoints so it
+                // is only served when unstable APIs are enabled.
+                if enable_unstable_apis {
+                    router.route_service(
+                        "/sui.rest.NodeService/*rpc",
+                        grpc::NodeServiceServer::new(self.clone()),
+                    )
+                } else {
+                    router
+                }
+            })
             .layer(axum::middleware::map_response_with_state(
                 self,
                 response::append_info_headers,
@@ -2,10 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    accept::AcceptFormat,
+    accept::{AcceptFormat, AcceptJsonProtobufBcs},
     openapi::{ApiEndpoint, OperationBuilder, ResponseBuilder, RouteHandler},
+    proto,
     reader::StateReader,
-    response::ResponseContent,
+    response::{JsonProtobufBcs, ResponseContent},
     Page, RestError, RestService, Result,
 };
 use axum::extract::Query;
@@ -42,6 +43,7 @@ impl ApiEndpoint<RestService> for GetObject {
                 200,
                 ResponseBuilder::new()
                     .json_content::<Object>(generator)
+                    .protobuf_content::<proto::Object>()
                     .bcs_content()
                     .build(),
             )
@@ -56,16 +58,17 @@ impl ApiEndpoint<RestService> for GetObject {
 
 pub async fn get_object(
     Path(object_id): Path<ObjectId>,
-    accept: AcceptFormat,
+    accept: AcceptJsonProtobufBcs,
     State(state): State<StateReader>,
-) -> Result<ResponseContent<Object>> {
+) -> Result<JsonProtobufBcs<Object, proto::Object>> {
     let object = state
         .get_object(object_id)?
         .ok_or_else(|| ObjectNotFoundError::new(object_id))?;
 
     match accept {
-        AcceptFormat::Json => ResponseContent::Json(object),
-        AcceptFormat::Bcs => ResponseContent::Bcs(object),
+        AcceptJsonProtobufBcs::Json => JsonProtobufBcs::Json(object),
+        AcceptJsonProtobufBcs::Protobuf => JsonProtobufBcs::Protobuf((&object).try_into()?),
+        AcceptJsonProtobufBcs::Bcs => JsonProtobufBcs::Bcs(object),
     }
     .pipe(Ok)
 }
@@ -94,6 +97,7 @@ impl ApiEndpoint<RestService> for GetObjectWithVersion {
                 200,
                 ResponseBuilder::new()
                     .json_content::<Object>(generator)
+                    .protobuf_content::<proto::Object>()
                     .bcs_content()
                     .build(),
             )
@@ -108,16 +112,17 @@ impl ApiEndpoint<RestService> for GetObjectWithVersion {
 
 pub async fn get_object_with_version(
     Path((object_id, version)): Path<(ObjectId, Version)>,
-    accept: AcceptFormat,
+    accept: AcceptJsonProtobufBcs,
     State(state): State<StateReader>,
-) -> Result<ResponseContent<Object>> {
+) -> Result<JsonProtobufBcs<Object, proto::Object>> {
     let object = state
         .get_object_with_version(object_id, version)?
         .ok_or_else(|| ObjectNotFoundError::new_with_version(object_id, version))?;
 
     match accept {
-        AcceptFormat::Json => ResponseContent::Json(object),
-        AcceptFormat::Bcs => ResponseContent::Bcs(object),
+        AcceptJsonProtobufBcs::Json => JsonProtobufBcs::Json(object),
+        AcceptJsonProtobufBcs::Protobuf => JsonProtobufBcs::Protobuf((&object).try_into()?),
+        AcceptJsonProtobufBcs::Bcs => JsonProtobufBcs::Bcs(object),
     }
     .pipe(Ok)
 }
@@ -243,7 +248,7 @@ async fn list_dynamic_fields(
         .pipe(Ok)
 }
 
-#[derive(Debug, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
 pub struct ListDynamicFieldsQueryParameters {
     pub limit: Option<u32>,
     pub start: Option<ObjectId>,
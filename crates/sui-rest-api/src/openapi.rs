@@ -652,6 +652,33 @@ impl ResponseBuilder {
         self.content(crate::APPLICATION_BCS, MediaType::default())
     }
 
+    /// Protobuf encoded content, described by the fully qualified name of its message in
+    /// `proto/rest.proto`.
+    pub fn protobuf_content<T: prost::Name>(&mut self) -> &mut Self {
+        let json_schema = schemars::schema::SchemaObject {
+            metadata: Some(Box::new(schemars::schema::Metadata {
+                description: Some(format!(
+                    "A `{}` protobuf message, as defined in `proto/rest.proto`",
+                    T::full_name()
+                )),
+                ..Default::default()
+            })),
+            instance_type: Some(schemars::schema::InstanceType::String.into()),
+            format: Some("binary".to_owned()),
+            ..Default::default()
+        };
+        let media_type = MediaType {
+            schema: Some(SchemaObject {
+                json_schema: json_schema.into(),
+                external_docs: None,
+                example: None,
+            }),
+            ..Default::default()
+        };
+
+        self.content(crate::APPLICATION_PROTOBUF, media_type)
+    }
+
     pub fn text_content(&mut self) -> &mut Self {
         self.content(mime::TEXT_PLAIN_UTF_8.as_ref(), MediaType::default())
     }
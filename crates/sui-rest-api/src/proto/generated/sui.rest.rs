@@ -0,0 +1,1561 @@
+// This file is @generated by prost-build.
+/// A placeholder for variants of a `oneof` that carry no data.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct Empty {}
+impl ::prost::Name for Empty {
+    const NAME: &'static str = "Empty";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.Empty".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.Empty".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Owner {
+    #[prost(oneof = "owner::Kind", tags = "1, 2, 3, 4")]
+    pub kind: ::core::option::Option<owner::Kind>,
+}
+/// Nested message and enum types in `Owner`.
+pub mod owner {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Kind {
+        /// Owned by the 32-byte address.
+        #[prost(bytes, tag = "1")]
+        Address(::prost::alloc::vec::Vec<u8>),
+        /// Owned by the object with the 32-byte id, e.g. as a dynamic field.
+        #[prost(bytes, tag = "2")]
+        Object(::prost::alloc::vec::Vec<u8>),
+        /// Shared, starting from the given version.
+        #[prost(uint64, tag = "3")]
+        Shared(u64),
+        #[prost(message, tag = "4")]
+        Immutable(super::Empty),
+    }
+}
+impl ::prost::Name for Owner {
+    const NAME: &'static str = "Owner";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.Owner".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.Owner".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObjectReference {
+    /// The 32-byte id of the object.
+    #[prost(bytes = "vec", tag = "1")]
+    pub object_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag = "2")]
+    pub version: u64,
+    /// The 32-byte digest of the object.
+    #[prost(bytes = "vec", tag = "3")]
+    pub digest: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for ObjectReference {
+    const NAME: &'static str = "ObjectReference";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.ObjectReference".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.ObjectReference".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetObjectRequest {
+    /// The 32-byte id of the object.
+    #[prost(bytes = "vec", tag = "1")]
+    pub object_id: ::prost::alloc::vec::Vec<u8>,
+    /// The version of the object to fetch. Defaults to the latest version.
+    #[prost(uint64, optional, tag = "2")]
+    pub version: ::core::option::Option<u64>,
+}
+impl ::prost::Name for GetObjectRequest {
+    const NAME: &'static str = "GetObjectRequest";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.GetObjectRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.GetObjectRequest".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MoveStruct {
+    /// The type of the struct, e.g. `0x2::coin::Coin<0x2::sui::SUI>`.
+    #[prost(string, tag = "1")]
+    pub object_type: ::prost::alloc::string::String,
+    #[prost(bool, tag = "2")]
+    pub has_public_transfer: bool,
+    /// The BCS encoded value of the struct, laid out according to its type.
+    #[prost(bytes = "vec", tag = "3")]
+    pub contents: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for MoveStruct {
+    const NAME: &'static str = "MoveStruct";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.MoveStruct".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.MoveStruct".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MoveModule {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// The compiled bytecode of the module.
+    #[prost(bytes = "vec", tag = "2")]
+    pub contents: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for MoveModule {
+    const NAME: &'static str = "MoveModule";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.MoveModule".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.MoveModule".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TypeOrigin {
+    #[prost(string, tag = "1")]
+    pub module_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub datatype_name: ::prost::alloc::string::String,
+    /// The 32-byte id of the package that first defined the type.
+    #[prost(bytes = "vec", tag = "3")]
+    pub package_id: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for TypeOrigin {
+    const NAME: &'static str = "TypeOrigin";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.TypeOrigin".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.TypeOrigin".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Linkage {
+    /// The 32-byte id of the original version of the dependency.
+    #[prost(bytes = "vec", tag = "1")]
+    pub original_id: ::prost::alloc::vec::Vec<u8>,
+    /// The 32-byte id of the version of the dependency the package links against.
+    #[prost(bytes = "vec", tag = "2")]
+    pub upgraded_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag = "3")]
+    pub upgraded_version: u64,
+}
+impl ::prost::Name for Linkage {
+    const NAME: &'static str = "Linkage";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.Linkage".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.Linkage".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MovePackage {
+    #[prost(message, repeated, tag = "1")]
+    pub modules: ::prost::alloc::vec::Vec<MoveModule>,
+    #[prost(message, repeated, tag = "2")]
+    pub type_origin_table: ::prost::alloc::vec::Vec<TypeOrigin>,
+    #[prost(message, repeated, tag = "3")]
+    pub linkage_table: ::prost::alloc::vec::Vec<Linkage>,
+}
+impl ::prost::Name for MovePackage {
+    const NAME: &'static str = "MovePackage";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.MovePackage".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.MovePackage".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObjectData {
+    #[prost(oneof = "object_data::Kind", tags = "1, 2")]
+    pub kind: ::core::option::Option<object_data::Kind>,
+}
+/// Nested message and enum types in `ObjectData`.
+pub mod object_data {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Kind {
+        #[prost(message, tag = "1")]
+        MoveStruct(super::MoveStruct),
+        #[prost(message, tag = "2")]
+        Package(super::MovePackage),
+    }
+}
+impl ::prost::Name for ObjectData {
+    const NAME: &'static str = "ObjectData";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.ObjectData".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.ObjectData".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Object {
+    /// The 32-byte id of the object.
+    #[prost(bytes = "vec", tag = "1")]
+    pub object_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag = "2")]
+    pub version: u64,
+    /// The 32-byte digest of the object.
+    #[prost(bytes = "vec", tag = "3")]
+    pub digest: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "4")]
+    pub owner: ::core::option::Option<Owner>,
+    /// The 32-byte digest of the transaction that created or last mutated the object.
+    #[prost(bytes = "vec", tag = "5")]
+    pub previous_transaction: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag = "6")]
+    pub storage_rebate: u64,
+    #[prost(message, optional, tag = "7")]
+    pub data: ::core::option::Option<ObjectData>,
+}
+impl ::prost::Name for Object {
+    const NAME: &'static str = "Object";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.Object".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.Object".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetCheckpointRequest {
+    /// Request the checkpoint's contents be included in the response.
+    #[prost(bool, tag = "3")]
+    pub contents: bool,
+    #[prost(oneof = "get_checkpoint_request::CheckpointId", tags = "1, 2")]
+    pub checkpoint_id: ::core::option::Option<get_checkpoint_request::CheckpointId>,
+}
+/// Nested message and enum types in `GetCheckpointRequest`.
+pub mod get_checkpoint_request {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum CheckpointId {
+        #[prost(uint64, tag = "1")]
+        SequenceNumber(u64),
+        /// The 32-byte digest of the checkpoint.
+        #[prost(bytes, tag = "2")]
+        Digest(::prost::alloc::vec::Vec<u8>),
+    }
+}
+impl ::prost::Name for GetCheckpointRequest {
+    const NAME: &'static str = "GetCheckpointRequest";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.GetCheckpointRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.GetCheckpointRequest".into()
+    }
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GasCostSummary {
+    #[prost(uint64, tag = "1")]
+    pub computation_cost: u64,
+    #[prost(uint64, tag = "2")]
+    pub storage_cost: u64,
+    #[prost(uint64, tag = "3")]
+    pub storage_rebate: u64,
+    #[prost(uint64, tag = "4")]
+    pub non_refundable_storage_fee: u64,
+}
+impl ::prost::Name for GasCostSummary {
+    const NAME: &'static str = "GasCostSummary";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.GasCostSummary".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.GasCostSummary".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CheckpointCommitment {
+    #[prost(oneof = "checkpoint_commitment::Kind", tags = "1")]
+    pub kind: ::core::option::Option<checkpoint_commitment::Kind>,
+}
+/// Nested message and enum types in `CheckpointCommitment`.
+pub mod checkpoint_commitment {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Kind {
+        /// The 32-byte ECMH digest of the live object set.
+        #[prost(bytes, tag = "1")]
+        EcmhLiveObjectSet(::prost::alloc::vec::Vec<u8>),
+    }
+}
+impl ::prost::Name for CheckpointCommitment {
+    const NAME: &'static str = "CheckpointCommitment";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.CheckpointCommitment".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.CheckpointCommitment".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorCommitteeMember {
+    /// The 96-byte BLS12-381 public key of the validator.
+    #[prost(bytes = "vec", tag = "1")]
+    pub public_key: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag = "2")]
+    pub stake: u64,
+}
+impl ::prost::Name for ValidatorCommitteeMember {
+    const NAME: &'static str = "ValidatorCommitteeMember";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.ValidatorCommitteeMember".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.ValidatorCommitteeMember".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EndOfEpochData {
+    #[prost(message, repeated, tag = "1")]
+    pub next_epoch_committee: ::prost::alloc::vec::Vec<ValidatorCommitteeMember>,
+    #[prost(uint64, tag = "2")]
+    pub next_epoch_protocol_version: u64,
+    #[prost(message, repeated, tag = "3")]
+    pub epoch_commitments: ::prost::alloc::vec::Vec<CheckpointCommitment>,
+}
+impl ::prost::Name for EndOfEpochData {
+    const NAME: &'static str = "EndOfEpochData";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.EndOfEpochData".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.EndOfEpochData".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CheckpointSummary {
+    #[prost(uint64, tag = "1")]
+    pub epoch: u64,
+    #[prost(uint64, tag = "2")]
+    pub sequence_number: u64,
+    /// The 32-byte digest of the checkpoint.
+    #[prost(bytes = "vec", tag = "3")]
+    pub digest: ::prost::alloc::vec::Vec<u8>,
+    /// Total number of transactions committed since genesis, including those in this checkpoint.
+    #[prost(uint64, tag = "4")]
+    pub network_total_transactions: u64,
+    /// The 32-byte digest of the checkpoint's contents.
+    #[prost(bytes = "vec", tag = "5")]
+    pub content_digest: ::prost::alloc::vec::Vec<u8>,
+    /// The 32-byte digest of the previous checkpoint, unless this is the genesis checkpoint.
+    #[prost(bytes = "vec", optional, tag = "6")]
+    pub previous_digest: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    /// The running total gas costs of all transactions in the epoch, up to and including this
+    /// checkpoint.
+    #[prost(message, optional, tag = "7")]
+    pub epoch_rolling_gas_cost_summary: ::core::option::Option<GasCostSummary>,
+    #[prost(uint64, tag = "8")]
+    pub timestamp_ms: u64,
+    #[prost(message, repeated, tag = "9")]
+    pub checkpoint_commitments: ::prost::alloc::vec::Vec<CheckpointCommitment>,
+    /// Present only on the last checkpoint of an epoch.
+    #[prost(message, optional, tag = "10")]
+    pub end_of_epoch_data: ::core::option::Option<EndOfEpochData>,
+    /// Opaque data whose interpretation depends on the protocol version of the checkpoint's epoch.
+    #[prost(bytes = "vec", tag = "11")]
+    pub version_specific_data: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for CheckpointSummary {
+    const NAME: &'static str = "CheckpointSummary";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.CheckpointSummary".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.CheckpointSummary".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorAggregatedSignature {
+    #[prost(uint64, tag = "1")]
+    pub epoch: u64,
+    /// The 48-byte aggregated BLS12-381 signature.
+    #[prost(bytes = "vec", tag = "2")]
+    pub signature: ::prost::alloc::vec::Vec<u8>,
+    /// The indexes, in the committee, of the validators that contributed to the signature.
+    #[prost(uint32, repeated, tag = "3")]
+    pub bitmap: ::prost::alloc::vec::Vec<u32>,
+}
+impl ::prost::Name for ValidatorAggregatedSignature {
+    const NAME: &'static str = "ValidatorAggregatedSignature";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.ValidatorAggregatedSignature".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.ValidatorAggregatedSignature".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CheckpointedTransactionInfo {
+    /// The 32-byte digest of the transaction.
+    #[prost(bytes = "vec", tag = "1")]
+    pub transaction: ::prost::alloc::vec::Vec<u8>,
+    /// The 32-byte digest of the transaction's effects.
+    #[prost(bytes = "vec", tag = "2")]
+    pub effects: ::prost::alloc::vec::Vec<u8>,
+    /// The `UserSignature`s over the transaction, each as its flag-prefixed bytes.
+    #[prost(bytes = "vec", repeated, tag = "3")]
+    pub signatures: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+impl ::prost::Name for CheckpointedTransactionInfo {
+    const NAME: &'static str = "CheckpointedTransactionInfo";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.CheckpointedTransactionInfo".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.CheckpointedTransactionInfo".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CheckpointContents {
+    #[prost(message, repeated, tag = "1")]
+    pub transactions: ::prost::alloc::vec::Vec<CheckpointedTransactionInfo>,
+}
+impl ::prost::Name for CheckpointContents {
+    const NAME: &'static str = "CheckpointContents";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.CheckpointContents".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.CheckpointContents".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Checkpoint {
+    #[prost(message, optional, tag = "1")]
+    pub summary: ::core::option::Option<CheckpointSummary>,
+    #[prost(message, optional, tag = "2")]
+    pub signature: ::core::option::Option<ValidatorAggregatedSignature>,
+    /// The checkpoint's contents, if requested.
+    #[prost(message, optional, tag = "3")]
+    pub contents: ::core::option::Option<CheckpointContents>,
+}
+impl ::prost::Name for Checkpoint {
+    const NAME: &'static str = "Checkpoint";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.Checkpoint".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.Checkpoint".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetFullCheckpointRequest {
+    #[prost(oneof = "get_full_checkpoint_request::CheckpointId", tags = "1, 2")]
+    pub checkpoint_id: ::core::option::Option<get_full_checkpoint_request::CheckpointId>,
+}
+/// Nested message and enum types in `GetFullCheckpointRequest`.
+pub mod get_full_checkpoint_request {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum CheckpointId {
+        #[prost(uint64, tag = "1")]
+        SequenceNumber(u64),
+        /// The 32-byte digest of the checkpoint.
+        #[prost(bytes, tag = "2")]
+        Digest(::prost::alloc::vec::Vec<u8>),
+    }
+}
+impl ::prost::Name for GetFullCheckpointRequest {
+    const NAME: &'static str = "GetFullCheckpointRequest";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.GetFullCheckpointRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.GetFullCheckpointRequest".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GasPayment {
+    #[prost(message, repeated, tag = "1")]
+    pub objects: ::prost::alloc::vec::Vec<ObjectReference>,
+    /// The 32-byte address paying for gas.
+    #[prost(bytes = "vec", tag = "2")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag = "3")]
+    pub price: u64,
+    #[prost(uint64, tag = "4")]
+    pub budget: u64,
+}
+impl ::prost::Name for GasPayment {
+    const NAME: &'static str = "GasPayment";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.GasPayment".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.GasPayment".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SharedObjectInput {
+    /// The 32-byte id of the object.
+    #[prost(bytes = "vec", tag = "1")]
+    pub object_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag = "2")]
+    pub initial_shared_version: u64,
+    #[prost(bool, tag = "3")]
+    pub mutable: bool,
+}
+impl ::prost::Name for SharedObjectInput {
+    const NAME: &'static str = "SharedObjectInput";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.SharedObjectInput".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.SharedObjectInput".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Input {
+    #[prost(oneof = "input::Kind", tags = "1, 2, 3, 4")]
+    pub kind: ::core::option::Option<input::Kind>,
+}
+/// Nested message and enum types in `Input`.
+pub mod input {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Kind {
+        /// The BCS encoded value of a pure (non-object) input.
+        #[prost(bytes, tag = "1")]
+        Pure(::prost::alloc::vec::Vec<u8>),
+        #[prost(message, tag = "2")]
+        ImmutableOrOwned(super::ObjectReference),
+        #[prost(message, tag = "3")]
+        Shared(super::SharedObjectInput),
+        #[prost(message, tag = "4")]
+        Receiving(super::ObjectReference),
+    }
+}
+impl ::prost::Name for Input {
+    const NAME: &'static str = "Input";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.Input".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.Input".into()
+    }
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct NestedResult {
+    #[prost(uint32, tag = "1")]
+    pub result: u32,
+    #[prost(uint32, tag = "2")]
+    pub subresult: u32,
+}
+impl ::prost::Name for NestedResult {
+    const NAME: &'static str = "NestedResult";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.NestedResult".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.NestedResult".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Argument {
+    #[prost(oneof = "argument::Kind", tags = "1, 2, 3, 4")]
+    pub kind: ::core::option::Option<argument::Kind>,
+}
+/// Nested message and enum types in `Argument`.
+pub mod argument {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Kind {
+        #[prost(message, tag = "1")]
+        GasCoin(super::Empty),
+        #[prost(uint32, tag = "2")]
+        Input(u32),
+        #[prost(uint32, tag = "3")]
+        Result(u32),
+        #[prost(message, tag = "4")]
+        NestedResult(super::NestedResult),
+    }
+}
+impl ::prost::Name for Argument {
+    const NAME: &'static str = "Argument";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.Argument".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.Argument".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MoveCall {
+    /// The 32-byte id of the package.
+    #[prost(bytes = "vec", tag = "1")]
+    pub package: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag = "2")]
+    pub module: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub function: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag = "4")]
+    pub type_arguments: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, repeated, tag = "5")]
+    pub arguments: ::prost::alloc::vec::Vec<Argument>,
+}
+impl ::prost::Name for MoveCall {
+    const NAME: &'static str = "MoveCall";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.MoveCall".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.MoveCall".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransferObjects {
+    #[prost(message, repeated, tag = "1")]
+    pub objects: ::prost::alloc::vec::Vec<Argument>,
+    #[prost(message, optional, tag = "2")]
+    pub address: ::core::option::Option<Argument>,
+}
+impl ::prost::Name for TransferObjects {
+    const NAME: &'static str = "TransferObjects";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.TransferObjects".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.TransferObjects".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SplitCoins {
+    #[prost(message, optional, tag = "1")]
+    pub coin: ::core::option::Option<Argument>,
+    #[prost(message, repeated, tag = "2")]
+    pub amounts: ::prost::alloc::vec::Vec<Argument>,
+}
+impl ::prost::Name for SplitCoins {
+    const NAME: &'static str = "SplitCoins";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.SplitCoins".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.SplitCoins".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MergeCoins {
+    #[prost(message, optional, tag = "1")]
+    pub coin: ::core::option::Option<Argument>,
+    #[prost(message, repeated, tag = "2")]
+    pub coins_to_merge: ::prost::alloc::vec::Vec<Argument>,
+}
+impl ::prost::Name for MergeCoins {
+    const NAME: &'static str = "MergeCoins";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.MergeCoins".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.MergeCoins".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Publish {
+    /// The compiled bytecode of each of the package's modules.
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub modules: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// The 32-byte ids of the package's transitive dependencies.
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub dependencies: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+impl ::prost::Name for Publish {
+    const NAME: &'static str = "Publish";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.Publish".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.Publish".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MakeMoveVector {
+    /// The type of the vector's elements, required if it is empty or its elements are not objects.
+    #[prost(string, optional, tag = "1")]
+    pub element_type: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(message, repeated, tag = "2")]
+    pub elements: ::prost::alloc::vec::Vec<Argument>,
+}
+impl ::prost::Name for MakeMoveVector {
+    const NAME: &'static str = "MakeMoveVector";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.MakeMoveVector".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.MakeMoveVector".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Upgrade {
+    /// The compiled bytecode of each of the package's modules.
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub modules: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// The 32-byte ids of the package's transitive dependencies.
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub dependencies: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// The 32-byte id of the package being upgraded.
+    #[prost(bytes = "vec", tag = "3")]
+    pub package: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "4")]
+    pub ticket: ::core::option::Option<Argument>,
+}
+impl ::prost::Name for Upgrade {
+    const NAME: &'static str = "Upgrade";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.Upgrade".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.Upgrade".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Command {
+    #[prost(oneof = "command::Command", tags = "1, 2, 3, 4, 5, 6, 7")]
+    pub command: ::core::option::Option<command::Command>,
+}
+/// Nested message and enum types in `Command`.
+pub mod command {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Command {
+        #[prost(message, tag = "1")]
+        MoveCall(super::MoveCall),
+        #[prost(message, tag = "2")]
+        TransferObjects(super::TransferObjects),
+        #[prost(message, tag = "3")]
+        SplitCoins(super::SplitCoins),
+        #[prost(message, tag = "4")]
+        MergeCoins(super::MergeCoins),
+        #[prost(message, tag = "5")]
+        Publish(super::Publish),
+        #[prost(message, tag = "6")]
+        MakeMoveVector(super::MakeMoveVector),
+        #[prost(message, tag = "7")]
+        Upgrade(super::Upgrade),
+    }
+}
+impl ::prost::Name for Command {
+    const NAME: &'static str = "Command";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.Command".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.Command".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProgrammableTransaction {
+    #[prost(message, repeated, tag = "1")]
+    pub inputs: ::prost::alloc::vec::Vec<Input>,
+    #[prost(message, repeated, tag = "2")]
+    pub commands: ::prost::alloc::vec::Vec<Command>,
+}
+impl ::prost::Name for ProgrammableTransaction {
+    const NAME: &'static str = "ProgrammableTransaction";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.ProgrammableTransaction".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.ProgrammableTransaction".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SystemPackage {
+    #[prost(uint64, tag = "1")]
+    pub version: u64,
+    /// The compiled bytecode of each of the package's modules.
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub modules: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// The 32-byte ids of the package's transitive dependencies.
+    #[prost(bytes = "vec", repeated, tag = "3")]
+    pub dependencies: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+impl ::prost::Name for SystemPackage {
+    const NAME: &'static str = "SystemPackage";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.SystemPackage".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.SystemPackage".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChangeEpoch {
+    /// The epoch being started.
+    #[prost(uint64, tag = "1")]
+    pub epoch: u64,
+    #[prost(uint64, tag = "2")]
+    pub protocol_version: u64,
+    #[prost(uint64, tag = "3")]
+    pub storage_charge: u64,
+    #[prost(uint64, tag = "4")]
+    pub computation_charge: u64,
+    #[prost(uint64, tag = "5")]
+    pub storage_rebate: u64,
+    #[prost(uint64, tag = "6")]
+    pub non_refundable_storage_fee: u64,
+    #[prost(uint64, tag = "7")]
+    pub epoch_start_timestamp_ms: u64,
+    #[prost(message, repeated, tag = "8")]
+    pub system_packages: ::prost::alloc::vec::Vec<SystemPackage>,
+}
+impl ::prost::Name for ChangeEpoch {
+    const NAME: &'static str = "ChangeEpoch";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.ChangeEpoch".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.ChangeEpoch".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GenesisObject {
+    /// The 32-byte id of the object.
+    #[prost(bytes = "vec", tag = "1")]
+    pub object_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag = "2")]
+    pub version: u64,
+    #[prost(message, optional, tag = "3")]
+    pub owner: ::core::option::Option<Owner>,
+    #[prost(message, optional, tag = "4")]
+    pub data: ::core::option::Option<ObjectData>,
+}
+impl ::prost::Name for GenesisObject {
+    const NAME: &'static str = "GenesisObject";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.GenesisObject".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.GenesisObject".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GenesisTransaction {
+    #[prost(message, repeated, tag = "1")]
+    pub objects: ::prost::alloc::vec::Vec<GenesisObject>,
+}
+impl ::prost::Name for GenesisTransaction {
+    const NAME: &'static str = "GenesisTransaction";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.GenesisTransaction".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.GenesisTransaction".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VersionAssignment {
+    /// The 32-byte id of the object.
+    #[prost(bytes = "vec", tag = "1")]
+    pub object_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag = "2")]
+    pub version: u64,
+}
+impl ::prost::Name for VersionAssignment {
+    const NAME: &'static str = "VersionAssignment";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.VersionAssignment".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.VersionAssignment".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CancelledTransaction {
+    /// The 32-byte digest of the transaction.
+    #[prost(bytes = "vec", tag = "1")]
+    pub digest: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, repeated, tag = "2")]
+    pub version_assignments: ::prost::alloc::vec::Vec<VersionAssignment>,
+}
+impl ::prost::Name for CancelledTransaction {
+    const NAME: &'static str = "CancelledTransaction";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.CancelledTransaction".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.CancelledTransaction".into()
+    }
+}
+/// Covers every version of the consensus commit prologue. Fields that were introduced by later
+/// versions are left unset for earlier ones.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ConsensusCommitPrologue {
+    #[prost(uint64, tag = "1")]
+    pub epoch: u64,
+    #[prost(uint64, tag = "2")]
+    pub round: u64,
+    #[prost(uint64, tag = "3")]
+    pub commit_timestamp_ms: u64,
+    /// The 32-byte digest of the consensus output.
+    #[prost(bytes = "vec", optional, tag = "4")]
+    pub consensus_commit_digest: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(uint64, optional, tag = "5")]
+    pub sub_dag_index: ::core::option::Option<u64>,
+    #[prost(message, repeated, tag = "6")]
+    pub cancelled_transactions: ::prost::alloc::vec::Vec<CancelledTransaction>,
+}
+impl ::prost::Name for ConsensusCommitPrologue {
+    const NAME: &'static str = "ConsensusCommitPrologue";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.ConsensusCommitPrologue".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.ConsensusCommitPrologue".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ActiveJwk {
+    #[prost(string, tag = "1")]
+    pub iss: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub kid: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub kty: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub e: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub n: ::prost::alloc::string::String,
+    #[prost(string, tag = "6")]
+    pub alg: ::prost::alloc::string::String,
+    /// The most recent epoch in which the JWK was validated.
+    #[prost(uint64, tag = "7")]
+    pub epoch: u64,
+}
+impl ::prost::Name for ActiveJwk {
+    const NAME: &'static str = "ActiveJwk";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.ActiveJwk".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.ActiveJwk".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuthenticatorStateUpdate {
+    #[prost(uint64, tag = "1")]
+    pub epoch: u64,
+    #[prost(uint64, tag = "2")]
+    pub round: u64,
+    #[prost(message, repeated, tag = "3")]
+    pub new_active_jwks: ::prost::alloc::vec::Vec<ActiveJwk>,
+    #[prost(uint64, tag = "4")]
+    pub authenticator_obj_initial_shared_version: u64,
+}
+impl ::prost::Name for AuthenticatorStateUpdate {
+    const NAME: &'static str = "AuthenticatorStateUpdate";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.AuthenticatorStateUpdate".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.AuthenticatorStateUpdate".into()
+    }
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct AuthenticatorStateExpire {
+    #[prost(uint64, tag = "1")]
+    pub min_epoch: u64,
+    #[prost(uint64, tag = "2")]
+    pub authenticator_obj_initial_shared_version: u64,
+}
+impl ::prost::Name for AuthenticatorStateExpire {
+    const NAME: &'static str = "AuthenticatorStateExpire";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.AuthenticatorStateExpire".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.AuthenticatorStateExpire".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EndOfEpochTransactionKind {
+    #[prost(oneof = "end_of_epoch_transaction_kind::Kind", tags = "1, 2, 3, 4, 5, 6, 7")]
+    pub kind: ::core::option::Option<end_of_epoch_transaction_kind::Kind>,
+}
+/// Nested message and enum types in `EndOfEpochTransactionKind`.
+pub mod end_of_epoch_transaction_kind {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Kind {
+        #[prost(message, tag = "1")]
+        ChangeEpoch(super::ChangeEpoch),
+        #[prost(message, tag = "2")]
+        AuthenticatorStateCreate(super::Empty),
+        #[prost(message, tag = "3")]
+        AuthenticatorStateExpire(super::AuthenticatorStateExpire),
+        #[prost(message, tag = "4")]
+        RandomnessStateCreate(super::Empty),
+        #[prost(message, tag = "5")]
+        DenyListStateCreate(super::Empty),
+        /// The 32-byte identifier of the chain the bridge is created for.
+        #[prost(bytes, tag = "6")]
+        BridgeStateCreate(::prost::alloc::vec::Vec<u8>),
+        /// The version of the bridge object the committee is initialized at.
+        #[prost(uint64, tag = "7")]
+        BridgeCommitteeInit(u64),
+    }
+}
+impl ::prost::Name for EndOfEpochTransactionKind {
+    const NAME: &'static str = "EndOfEpochTransactionKind";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.EndOfEpochTransactionKind".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.EndOfEpochTransactionKind".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EndOfEpochTransaction {
+    #[prost(message, repeated, tag = "1")]
+    pub transactions: ::prost::alloc::vec::Vec<EndOfEpochTransactionKind>,
+}
+impl ::prost::Name for EndOfEpochTransaction {
+    const NAME: &'static str = "EndOfEpochTransaction";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.EndOfEpochTransaction".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.EndOfEpochTransaction".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RandomnessStateUpdate {
+    #[prost(uint64, tag = "1")]
+    pub epoch: u64,
+    #[prost(uint64, tag = "2")]
+    pub randomness_round: u64,
+    #[prost(bytes = "vec", tag = "3")]
+    pub random_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag = "4")]
+    pub randomness_obj_initial_shared_version: u64,
+}
+impl ::prost::Name for RandomnessStateUpdate {
+    const NAME: &'static str = "RandomnessStateUpdate";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.RandomnessStateUpdate".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.RandomnessStateUpdate".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionKind {
+    #[prost(oneof = "transaction_kind::Kind", tags = "1, 2, 3, 4, 5, 6, 7")]
+    pub kind: ::core::option::Option<transaction_kind::Kind>,
+}
+/// Nested message and enum types in `TransactionKind`.
+pub mod transaction_kind {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Kind {
+        #[prost(message, tag = "1")]
+        ProgrammableTransaction(super::ProgrammableTransaction),
+        #[prost(message, tag = "2")]
+        ChangeEpoch(super::ChangeEpoch),
+        #[prost(message, tag = "3")]
+        Genesis(super::GenesisTransaction),
+        #[prost(message, tag = "4")]
+        ConsensusCommitPrologue(super::ConsensusCommitPrologue),
+        #[prost(message, tag = "5")]
+        AuthenticatorStateUpdate(super::AuthenticatorStateUpdate),
+        #[prost(message, tag = "6")]
+        EndOfEpoch(super::EndOfEpochTransaction),
+        #[prost(message, tag = "7")]
+        RandomnessStateUpdate(super::RandomnessStateUpdate),
+    }
+}
+impl ::prost::Name for TransactionKind {
+    const NAME: &'static str = "TransactionKind";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.TransactionKind".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.TransactionKind".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Transaction {
+    /// The 32-byte digest of the transaction.
+    #[prost(bytes = "vec", tag = "1")]
+    pub digest: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "2")]
+    pub kind: ::core::option::Option<TransactionKind>,
+    /// The 32-byte address of the sender.
+    #[prost(bytes = "vec", tag = "3")]
+    pub sender: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "4")]
+    pub gas_payment: ::core::option::Option<GasPayment>,
+    /// The last epoch the transaction can be executed in, if it expires.
+    #[prost(uint64, optional, tag = "5")]
+    pub expiration: ::core::option::Option<u64>,
+}
+impl ::prost::Name for Transaction {
+    const NAME: &'static str = "Transaction";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.Transaction".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.Transaction".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExecutionStatus {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    /// A description of the failure, if the transaction failed.
+    #[prost(string, optional, tag = "2")]
+    pub error: ::core::option::Option<::prost::alloc::string::String>,
+    /// The index of the command that failed, if the failure can be attributed to one.
+    #[prost(uint64, optional, tag = "3")]
+    pub command: ::core::option::Option<u64>,
+}
+impl ::prost::Name for ExecutionStatus {
+    const NAME: &'static str = "ExecutionStatus";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.ExecutionStatus".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.ExecutionStatus".into()
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum IdOperation {
+    None = 0,
+    Created = 1,
+    Deleted = 2,
+}
+impl IdOperation {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::None => "ID_OPERATION_NONE",
+            Self::Created => "ID_OPERATION_CREATED",
+            Self::Deleted => "ID_OPERATION_DELETED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ID_OPERATION_NONE" => Some(Self::None),
+            "ID_OPERATION_CREATED" => Some(Self::Created),
+            "ID_OPERATION_DELETED" => Some(Self::Deleted),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChangedObject {
+    /// The 32-byte id of the object.
+    #[prost(bytes = "vec", tag = "1")]
+    pub object_id: ::prost::alloc::vec::Vec<u8>,
+    /// The version of the object before the transaction, unless it was created or unwrapped.
+    #[prost(uint64, optional, tag = "2")]
+    pub input_version: ::core::option::Option<u64>,
+    #[prost(bytes = "vec", optional, tag = "3")]
+    pub input_digest: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(message, optional, tag = "4")]
+    pub input_owner: ::core::option::Option<Owner>,
+    /// The version of the object after the transaction, unless it was deleted or wrapped.
+    #[prost(uint64, optional, tag = "5")]
+    pub output_version: ::core::option::Option<u64>,
+    #[prost(bytes = "vec", optional, tag = "6")]
+    pub output_digest: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(message, optional, tag = "7")]
+    pub output_owner: ::core::option::Option<Owner>,
+    #[prost(enumeration = "IdOperation", tag = "8")]
+    pub id_operation: i32,
+}
+impl ::prost::Name for ChangedObject {
+    const NAME: &'static str = "ChangedObject";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.ChangedObject".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.ChangedObject".into()
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum UnchangedSharedObjectKind {
+    ReadOnlyRoot = 0,
+    MutateDeleted = 1,
+    ReadDeleted = 2,
+    Cancelled = 3,
+    PerEpochConfig = 4,
+}
+impl UnchangedSharedObjectKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::ReadOnlyRoot => "UNCHANGED_SHARED_OBJECT_KIND_READ_ONLY_ROOT",
+            Self::MutateDeleted => "UNCHANGED_SHARED_OBJECT_KIND_MUTATE_DELETED",
+            Self::ReadDeleted => "UNCHANGED_SHARED_OBJECT_KIND_READ_DELETED",
+            Self::Cancelled => "UNCHANGED_SHARED_OBJECT_KIND_CANCELLED",
+            Self::PerEpochConfig => "UNCHANGED_SHARED_OBJECT_KIND_PER_EPOCH_CONFIG",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "UNCHANGED_SHARED_OBJECT_KIND_READ_ONLY_ROOT" => Some(Self::ReadOnlyRoot),
+            "UNCHANGED_SHARED_OBJECT_KIND_MUTATE_DELETED" => Some(Self::MutateDeleted),
+            "UNCHANGED_SHARED_OBJECT_KIND_READ_DELETED" => Some(Self::ReadDeleted),
+            "UNCHANGED_SHARED_OBJECT_KIND_CANCELLED" => Some(Self::Cancelled),
+            "UNCHANGED_SHARED_OBJECT_KIND_PER_EPOCH_CONFIG" => Some(Self::PerEpochConfig),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnchangedSharedObject {
+    /// The 32-byte id of the object.
+    #[prost(bytes = "vec", tag = "1")]
+    pub object_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration = "UnchangedSharedObjectKind", tag = "2")]
+    pub kind: i32,
+    #[prost(uint64, optional, tag = "3")]
+    pub version: ::core::option::Option<u64>,
+    /// The 32-byte digest of the object, for read only objects.
+    #[prost(bytes = "vec", optional, tag = "4")]
+    pub digest: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+}
+impl ::prost::Name for UnchangedSharedObject {
+    const NAME: &'static str = "UnchangedSharedObject";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.UnchangedSharedObject".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.UnchangedSharedObject".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionEffects {
+    /// The 32-byte digest of the transaction.
+    #[prost(bytes = "vec", tag = "1")]
+    pub transaction_digest: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "2")]
+    pub status: ::core::option::Option<ExecutionStatus>,
+    #[prost(uint64, tag = "3")]
+    pub executed_epoch: u64,
+    #[prost(message, optional, tag = "4")]
+    pub gas_used: ::core::option::Option<GasCostSummary>,
+    /// The gas object, after the transaction.
+    #[prost(message, optional, tag = "5")]
+    pub gas_object: ::core::option::Option<ObjectReference>,
+    /// The 32-byte digest of the transaction's events, if it emitted any.
+    #[prost(bytes = "vec", optional, tag = "6")]
+    pub events_digest: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    /// The 32-byte digests of the transactions this transaction depends on.
+    #[prost(bytes = "vec", repeated, tag = "7")]
+    pub dependencies: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(uint64, tag = "8")]
+    pub lamport_version: u64,
+    #[prost(message, repeated, tag = "9")]
+    pub changed_objects: ::prost::alloc::vec::Vec<ChangedObject>,
+    #[prost(message, repeated, tag = "10")]
+    pub unchanged_shared_objects: ::prost::alloc::vec::Vec<UnchangedSharedObject>,
+}
+impl ::prost::Name for TransactionEffects {
+    const NAME: &'static str = "TransactionEffects";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.TransactionEffects".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.TransactionEffects".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Event {
+    /// The 32-byte id of the package of the module that emitted the event.
+    #[prost(bytes = "vec", tag = "1")]
+    pub package_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag = "2")]
+    pub module: ::prost::alloc::string::String,
+    /// The 32-byte address of the sender of the transaction that emitted the event.
+    #[prost(bytes = "vec", tag = "3")]
+    pub sender: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag = "4")]
+    pub event_type: ::prost::alloc::string::String,
+    /// The BCS encoded value of the event, laid out according to its type.
+    #[prost(bytes = "vec", tag = "5")]
+    pub contents: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for Event {
+    const NAME: &'static str = "Event";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.Event".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.Event".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionEvents {
+    #[prost(message, repeated, tag = "1")]
+    pub events: ::prost::alloc::vec::Vec<Event>,
+}
+impl ::prost::Name for TransactionEvents {
+    const NAME: &'static str = "TransactionEvents";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.TransactionEvents".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.TransactionEvents".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CheckpointTransaction {
+    #[prost(message, optional, tag = "1")]
+    pub transaction: ::core::option::Option<Transaction>,
+    /// The `UserSignature`s over the transaction, each as its flag-prefixed bytes.
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub signatures: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(message, optional, tag = "3")]
+    pub effects: ::core::option::Option<TransactionEffects>,
+    /// The transaction's events, if it emitted any.
+    #[prost(message, optional, tag = "4")]
+    pub events: ::core::option::Option<TransactionEvents>,
+    #[prost(message, repeated, tag = "5")]
+    pub input_objects: ::prost::alloc::vec::Vec<Object>,
+    #[prost(message, repeated, tag = "6")]
+    pub output_objects: ::prost::alloc::vec::Vec<Object>,
+}
+impl ::prost::Name for CheckpointTransaction {
+    const NAME: &'static str = "CheckpointTransaction";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.CheckpointTransaction".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.CheckpointTransaction".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FullCheckpoint {
+    #[prost(message, optional, tag = "1")]
+    pub summary: ::core::option::Option<CheckpointSummary>,
+    #[prost(message, optional, tag = "2")]
+    pub signature: ::core::option::Option<ValidatorAggregatedSignature>,
+    #[prost(message, optional, tag = "3")]
+    pub contents: ::core::option::Option<CheckpointContents>,
+    #[prost(message, repeated, tag = "4")]
+    pub transactions: ::prost::alloc::vec::Vec<CheckpointTransaction>,
+}
+impl ::prost::Name for FullCheckpoint {
+    const NAME: &'static str = "FullCheckpoint";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.FullCheckpoint".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.FullCheckpoint".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTransactionRequest {
+    /// The 32-byte digest of the transaction.
+    #[prost(bytes = "vec", tag = "1")]
+    pub digest: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for GetTransactionRequest {
+    const NAME: &'static str = "GetTransactionRequest";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.GetTransactionRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.GetTransactionRequest".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExecutedTransaction {
+    /// The 32-byte digest of the transaction.
+    #[prost(bytes = "vec", tag = "1")]
+    pub digest: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "2")]
+    pub transaction: ::core::option::Option<Transaction>,
+    /// The `UserSignature`s over the transaction, each as its flag-prefixed bytes.
+    #[prost(bytes = "vec", repeated, tag = "3")]
+    pub signatures: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(message, optional, tag = "4")]
+    pub effects: ::core::option::Option<TransactionEffects>,
+    /// The transaction's events, if it emitted any.
+    #[prost(message, optional, tag = "5")]
+    pub events: ::core::option::Option<TransactionEvents>,
+    /// The checkpoint the transaction was included in, if it has been checkpointed.
+    #[prost(uint64, optional, tag = "6")]
+    pub checkpoint: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag = "7")]
+    pub timestamp_ms: ::core::option::Option<u64>,
+}
+impl ::prost::Name for ExecutedTransaction {
+    const NAME: &'static str = "ExecutedTransaction";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.ExecutedTransaction".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.ExecutedTransaction".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExecuteTransactionRequest {
+    /// The BCS encoded `TransactionData`. Signatures commit to these exact bytes, so they are
+    /// submitted as is rather than as a `Transaction` message.
+    #[prost(bytes = "vec", tag = "1")]
+    pub transaction: ::prost::alloc::vec::Vec<u8>,
+    /// The `UserSignature`s over the transaction, each as its flag-prefixed bytes.
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub signatures: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// Request `TransactionEvents` be included in the response.
+    #[prost(bool, tag = "3")]
+    pub events: bool,
+    /// Request `BalanceChange`s be included in the response.
+    #[prost(bool, tag = "4")]
+    pub balance_changes: bool,
+    /// Request input `Object`s be included in the response.
+    #[prost(bool, tag = "5")]
+    pub input_objects: bool,
+    /// Request output `Object`s be included in the response.
+    #[prost(bool, tag = "6")]
+    pub output_objects: bool,
+}
+impl ::prost::Name for ExecuteTransactionRequest {
+    const NAME: &'static str = "ExecuteTransactionRequest";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.ExecuteTransactionRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.ExecuteTransactionRequest".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BalanceChange {
+    /// The 32-byte address of the owner of the balance.
+    #[prost(bytes = "vec", tag = "1")]
+    pub address: ::prost::alloc::vec::Vec<u8>,
+    /// The type of the coin, e.g. `0x2::sui::SUI`.
+    #[prost(string, tag = "2")]
+    pub coin_type: ::prost::alloc::string::String,
+    /// The change in balance, as a signed decimal integer.
+    #[prost(string, tag = "3")]
+    pub amount: ::prost::alloc::string::String,
+}
+impl ::prost::Name for BalanceChange {
+    const NAME: &'static str = "BalanceChange";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.BalanceChange".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.BalanceChange".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExecuteTransactionResponse {
+    #[prost(message, optional, tag = "1")]
+    pub effects: ::core::option::Option<TransactionEffects>,
+    /// The transaction's events, if requested.
+    #[prost(message, optional, tag = "4")]
+    pub events: ::core::option::Option<TransactionEvents>,
+    #[prost(message, repeated, tag = "5")]
+    pub balance_changes: ::prost::alloc::vec::Vec<BalanceChange>,
+    #[prost(message, repeated, tag = "6")]
+    pub input_objects: ::prost::alloc::vec::Vec<Object>,
+    #[prost(message, repeated, tag = "7")]
+    pub output_objects: ::prost::alloc::vec::Vec<Object>,
+    #[prost(oneof = "execute_transaction_response::Finality", tags = "2, 3")]
+    pub finality: ::core::option::Option<execute_transaction_response::Finality>,
+}
+/// Nested message and enum types in `ExecuteTransactionResponse`.
+pub mod execute_transaction_response {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Finality {
+        /// The validators' signature certifying the effects.
+        #[prost(message, tag = "2")]
+        Certified(super::ValidatorAggregatedSignature),
+        /// The sequence number of the checkpoint that includes the transaction.
+        #[prost(uint64, tag = "3")]
+        Checkpointed(u64),
+    }
+}
+impl ::prost::Name for ExecuteTransactionResponse {
+    const NAME: &'static str = "ExecuteTransactionResponse";
+    const PACKAGE: &'static str = "sui.rest";
+    fn full_name() -> ::prost::alloc::string::String {
+        "sui.rest.ExecuteTransactionResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/sui.rest.ExecuteTransactionResponse".into()
+    }
+}
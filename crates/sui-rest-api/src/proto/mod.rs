@@ -0,0 +1,927 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Protobuf messages served as `application/x-protobuf` and by the `sui.rest.NodeService` gRPC
+//! service.
+//!
+//! The messages are generated from `proto/rest.proto` and checked in under `generated/`. To
+//! regenerate them, build this crate with the `BUILD_PROTOS` env var set.
+//!
+//! Responses are converted from the core `sui_types` representations, going through BCS to get
+//! there from the `sui_sdk_types` ones used by the JSON and BCS endpoints.
+
+#[rustfmt::skip]
+#[path = "generated/sui.rest.rs"]
+mod generated;
+pub use generated::*;
+
+use std::collections::BTreeMap;
+
+use sui_types::base_types::{ObjectID, ObjectRef};
+use sui_types::effects::TransactionEffectsAPI;
+use sui_types::transaction::TransactionDataAPI;
+
+use crate::checkpoints::CheckpointResponse;
+use crate::transactions::{EffectsFinality, TransactionExecutionResponse, TransactionResponse};
+use crate::Result;
+
+fn bytes<T: AsRef<[u8]>>(value: T) -> Vec<u8> {
+    value.as_ref().to_vec()
+}
+
+impl From<&ObjectRef> for ObjectReference {
+    fn from((object_id, version, digest): &ObjectRef) -> Self {
+        Self {
+            object_id: bytes(object_id),
+            version: version.value(),
+            digest: bytes(digest),
+        }
+    }
+}
+
+impl From<&sui_types::object::Owner> for Owner {
+    fn from(value: &sui_types::object::Owner) -> Self {
+        use sui_types::object::Owner as O;
+
+        let kind = match value {
+            O::AddressOwner(address) => owner::Kind::Address(bytes(address)),
+            O::ObjectOwner(object) => owner::Kind::Object(bytes(object)),
+            O::Shared {
+                initial_shared_version,
+            } => owner::Kind::Shared(initial_shared_version.value()),
+            O::Immutable => owner::Kind::Immutable(Empty {}),
+        };
+
+        Self { kind: Some(kind) }
+    }
+}
+
+impl From<&sui_types::object::Data> for ObjectData {
+    fn from(value: &sui_types::object::Data) -> Self {
+        use sui_types::object::Data;
+
+        let kind = match value {
+            Data::Move(object) => object_data::Kind::MoveStruct(MoveStruct {
+                object_type: move_core_types::language_storage::StructTag::from(
+                    object.type_().clone(),
+                )
+                .to_canonical_string(true),
+                has_public_transfer: object.has_public_transfer(),
+                contents: object.contents().to_vec(),
+            }),
+            Data::Package(package) => object_data::Kind::Package(MovePackage {
+                modules: package
+                    .serialized_module_map()
+                    .iter()
+                    .map(|(name, contents)| MoveModule {
+                        name: name.clone(),
+                        contents: contents.clone(),
+                    })
+                    .collect(),
+                type_origin_table: package
+                    .type_origin_table()
+                    .iter()
+                    .map(|origin| TypeOrigin {
+                        module_name: origin.module_name.clone(),
+                        datatype_name: origin.datatype_name.clone(),
+                        package_id: bytes(origin.package),
+                    })
+                    .collect(),
+                linkage_table: package
+                    .linkage_table()
+                    .iter()
+                    .map(|(original_id, upgrade)| Linkage {
+                        original_id: bytes(original_id),
+                        upgraded_id: bytes(upgrade.upgraded_id),
+                        upgraded_version: upgrade.upgraded_version.value(),
+                    })
+                    .collect(),
+            }),
+        };
+
+        Self { kind: Some(kind) }
+    }
+}
+
+impl From<&sui_types::object::Object> for Object {
+    fn from(value: &sui_types::object::Object) -> Self {
+        Self {
+            object_id: bytes(value.id()),
+            version: value.version().value(),
+            digest: bytes(value.digest()),
+            owner: Some((&value.owner).into()),
+            previous_transaction: bytes(value.previous_transaction),
+            storage_rebate: value.storage_rebate,
+            data: Some((&value.data).into()),
+        }
+    }
+}
+
+impl TryFrom<&sui_sdk_types::types::Object> for Object {
+    type Error = bcs::Error;
+
+    fn try_from(value: &sui_sdk_types::types::Object) -> Result<Self, Self::Error> {
+        let object = sui_types::object::Object::try_from(value.clone())?;
+        Ok((&object).into())
+    }
+}
+
+impl From<&sui_types::gas::GasCostSummary> for GasCostSummary {
+    fn from(value: &sui_types::gas::GasCostSummary) -> Self {
+        Self {
+            computation_cost: value.computation_cost,
+            storage_cost: value.storage_cost,
+            storage_rebate: value.storage_rebate,
+            non_refundable_storage_fee: value.non_refundable_storage_fee,
+        }
+    }
+}
+
+impl From<&sui_types::messages_checkpoint::CheckpointCommitment> for CheckpointCommitment {
+    fn from(value: &sui_types::messages_checkpoint::CheckpointCommitment) -> Self {
+        use sui_types::messages_checkpoint::CheckpointCommitment as C;
+
+        let kind = match value {
+            C::ECMHLiveObjectSetDigest(digest) => {
+                checkpoint_commitment::Kind::EcmhLiveObjectSet(bytes(digest.digest))
+            }
+        };
+
+        Self { kind: Some(kind) }
+    }
+}
+
+impl From<&sui_types::messages_checkpoint::CheckpointSummary> for CheckpointSummary {
+    fn from(value: &sui_types::messages_checkpoint::CheckpointSummary) -> Self {
+        Self {
+            epoch: value.epoch,
+            sequence_number: value.sequence_number,
+            digest: bytes(value.digest()),
+            network_total_transactions: value.network_total_transactions,
+            content_digest: bytes(value.content_digest),
+            previous_digest: value.previous_digest.map(bytes),
+            epoch_rolling_gas_cost_summary: Some((&value.epoch_rolling_gas_cost_summary).into()),
+            timestamp_ms: value.timestamp_ms,
+            checkpoint_commitments: value
+                .checkpoint_commitments
+                .iter()
+                .map(Into::into)
+                .collect(),
+            end_of_epoch_data: value.end_of_epoch_data.as_ref().map(|data| EndOfEpochData {
+                next_epoch_committee: data
+                    .next_epoch_committee
+                    .iter()
+                    .map(|(public_key, stake)| ValidatorCommitteeMember {
+                        public_key: bytes(public_key),
+                        stake: *stake,
+                    })
+                    .collect(),
+                next_epoch_protocol_version: data.next_epoch_protocol_version.as_u64(),
+                epoch_commitments: data.epoch_commitments.iter().map(Into::into).collect(),
+            }),
+            version_specific_data: value.version_specific_data.clone(),
+        }
+    }
+}
+
+impl<const T: bool> From<&sui_types::crypto::AuthorityQuorumSignInfo<T>>
+    for ValidatorAggregatedSignature
+{
+    fn from(value: &sui_types::crypto::AuthorityQuorumSignInfo<T>) -> Self {
+        Self {
+            epoch: value.epoch,
+            signature: bytes(&value.signature),
+            bitmap: value.signers_map.iter().collect(),
+        }
+    }
+}
+
+impl From<&sui_sdk_types::types::ValidatorAggregatedSignature> for ValidatorAggregatedSignature {
+    fn from(value: &sui_sdk_types::types::ValidatorAggregatedSignature) -> Self {
+        let signature: sui_types::crypto::AuthorityStrongQuorumSignInfo = value.clone().into();
+        (&signature).into()
+    }
+}
+
+impl From<&sui_types::messages_checkpoint::CheckpointContents> for CheckpointContents {
+    fn from(value: &sui_types::messages_checkpoint::CheckpointContents) -> Self {
+        Self {
+            transactions: value
+                .clone()
+                .into_iter_with_signatures()
+                .map(|(digests, signatures)| CheckpointedTransactionInfo {
+                    transaction: bytes(digests.transaction),
+                    effects: bytes(digests.effects),
+                    signatures: signatures.iter().map(bytes).collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<CheckpointResponse> for Checkpoint {
+    type Error = bcs::Error;
+
+    fn try_from(value: CheckpointResponse) -> Result<Self, Self::Error> {
+        let summary =
+            sui_types::messages_checkpoint::CheckpointSummary::try_from(value.checkpoint)?;
+        let contents = value
+            .contents
+            .map(sui_types::messages_checkpoint::CheckpointContents::try_from)
+            .transpose()?;
+
+        Ok(Self {
+            summary: Some((&summary).into()),
+            signature: Some((&value.signature).into()),
+            contents: contents.as_ref().map(Into::into),
+        })
+    }
+}
+
+impl From<&sui_types::transaction::Argument> for Argument {
+    fn from(value: &sui_types::transaction::Argument) -> Self {
+        use sui_types::transaction::Argument as A;
+
+        let kind = match *value {
+            A::GasCoin => argument::Kind::GasCoin(Empty {}),
+            A::Input(input) => argument::Kind::Input(input.into()),
+            A::Result(result) => argument::Kind::Result(result.into()),
+            A::NestedResult(result, subresult) => argument::Kind::NestedResult(NestedResult {
+                result: result.into(),
+                subresult: subresult.into(),
+            }),
+        };
+
+        Self { kind: Some(kind) }
+    }
+}
+
+fn arguments(arguments: &[sui_types::transaction::Argument]) -> Vec<Argument> {
+    arguments.iter().map(Into::into).collect()
+}
+
+fn object_ids(ids: &[ObjectID]) -> Vec<Vec<u8>> {
+    ids.iter().map(bytes).collect()
+}
+
+impl From<&sui_types::transaction::Command> for Command {
+    fn from(value: &sui_types::transaction::Command) -> Self {
+        use sui_types::transaction::Command as C;
+
+        let command = match value {
+            C::MoveCall(call) => command::Command::MoveCall(MoveCall {
+                package: bytes(call.package),
+                module: call.module.clone(),
+                function: call.function.clone(),
+                type_arguments: call
+                    .type_arguments
+                    .iter()
+                    .map(|type_| type_.to_canonical_string(true))
+                    .collect(),
+                arguments: arguments(&call.arguments),
+            }),
+            C::TransferObjects(objects, address) => {
+                command::Command::TransferObjects(TransferObjects {
+                    objects: arguments(objects),
+                    address: Some(address.into()),
+                })
+            }
+            C::SplitCoins(coin, amounts) => command::Command::SplitCoins(SplitCoins {
+                coin: Some(coin.into()),
+                amounts: arguments(amounts),
+            }),
+            C::MergeCoins(coin, coins_to_merge) => command::Command::MergeCoins(MergeCoins {
+                coin: Some(coin.into()),
+                coins_to_merge: arguments(coins_to_merge),
+            }),
+            C::Publish(modules, dependencies) => command::Command::Publish(Publish {
+                modules: modules.clone(),
+                dependencies: object_ids(dependencies),
+            }),
+            C::MakeMoveVec(element_type, elements) => {
+                command::Command::MakeMoveVector(MakeMoveVector {
+                    element_type: element_type
+                        .as_ref()
+                        .map(|type_| type_.to_canonical_string(true)),
+                    elements: arguments(elements),
+                })
+            }
+            C::Upgrade(modules, dependencies, package, ticket) => {
+                command::Command::Upgrade(Upgrade {
+                    modules: modules.clone(),
+                    dependencies: object_ids(dependencies),
+                    package: bytes(package),
+                    ticket: Some(ticket.into()),
+                })
+            }
+        };
+
+        Self {
+            command: Some(command),
+        }
+    }
+}
+
+impl From<&sui_types::transaction::CallArg> for Input {
+    fn from(value: &sui_types::transaction::CallArg) -> Self {
+        use sui_types::transaction::{CallArg, ObjectArg};
+
+        let kind = match value {
+            CallArg::Pure(value) => input::Kind::Pure(value.clone()),
+            CallArg::Object(ObjectArg::ImmOrOwnedObject(object)) => {
+                input::Kind::ImmutableOrOwned(object.into())
+            }
+            CallArg::Object(ObjectArg::SharedObject {
+                id,
+                initial_shared_version,
+                mutable,
+            }) => input::Kind::Shared(SharedObjectInput {
+                object_id: bytes(id),
+                initial_shared_version: initial_shared_version.value(),
+                mutable: *mutable,
+            }),
+            CallArg::Object(ObjectArg::Receiving(object)) => input::Kind::Receiving(object.into()),
+        };
+
+        Self { kind: Some(kind) }
+    }
+}
+
+impl From<&sui_types::transaction::ChangeEpoch> for ChangeEpoch {
+    fn from(value: &sui_types::transaction::ChangeEpoch) -> Self {
+        Self {
+            epoch: value.epoch,
+            protocol_version: value.protocol_version.as_u64(),
+            storage_charge: value.storage_charge,
+            computation_charge: value.computation_charge,
+            storage_rebate: value.storage_rebate,
+            non_refundable_storage_fee: value.non_refundable_storage_fee,
+            epoch_start_timestamp_ms: value.epoch_start_timestamp_ms,
+            system_packages: value
+                .system_packages
+                .iter()
+                .map(|(version, modules, dependencies)| SystemPackage {
+                    version: version.value(),
+                    modules: modules.clone(),
+                    dependencies: object_ids(dependencies),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<&sui_types::transaction::EndOfEpochTransactionKind> for EndOfEpochTransactionKind {
+    fn from(value: &sui_types::transaction::EndOfEpochTransactionKind) -> Self {
+        use end_of_epoch_transaction_kind::Kind;
+        use sui_types::transaction::EndOfEpochTransactionKind as K;
+
+        let kind = match value {
+            K::ChangeEpoch(change_epoch) => Kind::ChangeEpoch(change_epoch.into()),
+            K::AuthenticatorStateCreate => Kind::AuthenticatorStateCreate(Empty {}),
+            K::AuthenticatorStateExpire(expire) => {
+                Kind::AuthenticatorStateExpire(AuthenticatorStateExpire {
+                    min_epoch: expire.min_epoch,
+                    authenticator_obj_initial_shared_version: expire
+                        .authenticator_obj_initial_shared_version
+                        .value(),
+                })
+            }
+            K::RandomnessStateCreate => Kind::RandomnessStateCreate(Empty {}),
+            K::DenyListStateCreate => Kind::DenyListStateCreate(Empty {}),
+            K::BridgeStateCreate(chain_id) => Kind::BridgeStateCreate(bytes(chain_id.as_bytes())),
+            K::BridgeCommitteeInit(bridge_object_version) => {
+                Kind::BridgeCommitteeInit(bridge_object_version.value())
+            }
+        };
+
+        Self { kind: Some(kind) }
+    }
+}
+
+impl From<&sui_types::transaction::TransactionKind> for TransactionKind {
+    fn from(value: &sui_types::transaction::TransactionKind) -> Self {
+        use sui_types::messages_consensus::ConsensusDeterminedVersionAssignments;
+        use sui_types::transaction::{GenesisObject as G, TransactionKind as K};
+        use transaction_kind::Kind;
+
+        let kind = match value {
+            K::ProgrammableTransaction(transaction) => {
+                Kind::ProgrammableTransaction(ProgrammableTransaction {
+                    inputs: transaction.inputs.iter().map(Into::into).collect(),
+                    commands: transaction.commands.iter().map(Into::into).collect(),
+                })
+            }
+            K::ChangeEpoch(change_epoch) => Kind::ChangeEpoch(change_epoch.into()),
+            K::Genesis(genesis) => Kind::Genesis(GenesisTransaction {
+                objects: genesis
+                    .objects
+                    .iter()
+                    .map(|G::RawObject { data, owner }| GenesisObject {
+                        object_id: bytes(data.id()),
+                        version: match data {
+                            sui_types::object::Data::Move(object) => object.version().value(),
+                            sui_types::object::Data::Package(package) => package.version().value(),
+                        },
+                        owner: Some(owner.into()),
+                        data: Some(data.into()),
+                    })
+                    .collect(),
+            }),
+            K::ConsensusCommitPrologue(prologue) => {
+                Kind::ConsensusCommitPrologue(ConsensusCommitPrologue {
+                    epoch: prologue.epoch,
+                    round: prologue.round,
+                    commit_timestamp_ms: prologue.commit_timestamp_ms,
+                    consensus_commit_digest: None,
+                    sub_dag_index: None,
+                    cancelled_transactions: vec![],
+                })
+            }
+            K::ConsensusCommitPrologueV2(prologue) => {
+                Kind::ConsensusCommitPrologue(ConsensusCommitPrologue {
+                    epoch: prologue.epoch,
+                    round: prologue.round,
+                    commit_timestamp_ms: prologue.commit_timestamp_ms,
+                    consensus_commit_digest: Some(bytes(prologue.consensus_commit_digest.inner())),
+                    sub_dag_index: None,
+                    cancelled_transactions: vec![],
+                })
+            }
+            K::ConsensusCommitPrologueV3(prologue) => {
+                let ConsensusDeterminedVersionAssignments::CancelledTransactions(cancelled) =
+                    &prologue.consensus_determined_version_assignments;
+
+                Kind::ConsensusCommitPrologue(ConsensusCommitPrologue {
+                    epoch: prologue.epoch,
+                    round: prologue.round,
+                    commit_timestamp_ms: prologue.commit_timestamp_ms,
+                    consensus_commit_digest: Some(bytes(prologue.consensus_commit_digest.inner())),
+                    sub_dag_index: prologue.sub_dag_index,
+                    cancelled_transactions: cancelled
+                        .iter()
+                        .map(|(digest, assignments)| CancelledTransaction {
+                            digest: bytes(digest),
+                            version_assignments: assignments
+                                .iter()
+                                .map(|(object_id, version)| VersionAssignment {
+                                    object_id: bytes(object_id),
+                                    version: version.value(),
+                                })
+                                .collect(),
+                        })
+                        .collect(),
+                })
+            }
+            K::AuthenticatorStateUpdate(update) => {
+                Kind::AuthenticatorStateUpdate(AuthenticatorStateUpdate {
+                    epoch: update.epoch,
+                    round: update.round,
+                    new_active_jwks: update
+                        .new_active_jwks
+                        .iter()
+                        .map(|active| ActiveJwk {
+                            iss: active.jwk_id.iss.clone(),
+                            kid: active.jwk_id.kid.clone(),
+                            kty: active.jwk.kty.clone(),
+                            e: active.jwk.e.clone(),
+                            n: active.jwk.n.clone(),
+                            alg: active.jwk.alg.clone(),
+                            epoch: active.epoch,
+                        })
+                        .collect(),
+                    authenticator_obj_initial_shared_version: update
+                        .authenticator_obj_initial_shared_version
+                        .value(),
+                })
+            }
+            K::EndOfEpochTransaction(transactions) => Kind::EndOfEpoch(EndOfEpochTransaction {
+                transactions: transactions.iter().map(Into::into).collect(),
+            }),
+            K::RandomnessStateUpdate(update) => {
+                Kind::RandomnessStateUpdate(RandomnessStateUpdate {
+                    epoch: update.epoch,
+                    randomness_round: update.randomness_round.0,
+                    random_bytes: update.random_bytes.clone(),
+                    randomness_obj_initial_shared_version: update
+                        .randomness_obj_initial_shared_version
+                        .value(),
+                })
+            }
+        };
+
+        Self { kind: Some(kind) }
+    }
+}
+
+impl From<&sui_types::transaction::TransactionData> for Transaction {
+    fn from(value: &sui_types::transaction::TransactionData) -> Self {
+        use sui_types::transaction::TransactionExpiration;
+
+        let gas = value.gas_data();
+        Self {
+            digest: bytes(value.digest()),
+            kind: Some(value.kind().into()),
+            sender: bytes(value.sender()),
+            gas_payment: Some(GasPayment {
+                objects: gas.payment.iter().map(Into::into).collect(),
+                owner: bytes(gas.owner),
+                price: gas.price,
+                budget: gas.budget,
+            }),
+            expiration: match value.expiration() {
+                TransactionExpiration::None => None,
+                TransactionExpiration::Epoch(epoch) => Some(*epoch),
+            },
+        }
+    }
+}
+
+impl From<&sui_types::execution_status::ExecutionStatus> for ExecutionStatus {
+    fn from(value: &sui_types::execution_status::ExecutionStatus) -> Self {
+        use sui_types::execution_status::ExecutionStatus as S;
+
+        match value {
+            S::Success => Self {
+                success: true,
+                error: None,
+                command: None,
+            },
+            S::Failure { error, command } => Self {
+                success: false,
+                error: Some(error.to_string()),
+                command: command.map(|command| command as u64),
+            },
+        }
+    }
+}
+
+impl From<sui_types::effects::IDOperation> for IdOperation {
+    fn from(value: sui_types::effects::IDOperation) -> Self {
+        use sui_types::effects::IDOperation as O;
+
+        match value {
+            O::None => Self::None,
+            O::Created => Self::Created,
+            O::Deleted => Self::Deleted,
+        }
+    }
+}
+
+impl From<&(ObjectID, sui_types::effects::UnchangedSharedKind)> for UnchangedSharedObject {
+    fn from((object_id, kind): &(ObjectID, sui_types::effects::UnchangedSharedKind)) -> Self {
+        use sui_types::effects::UnchangedSharedKind as K;
+
+        let (kind, version, digest) = match kind {
+            K::ReadOnlyRoot((version, digest)) => (
+                UnchangedSharedObjectKind::ReadOnlyRoot,
+                Some(version.value()),
+                Some(bytes(digest)),
+            ),
+            K::MutateDeleted(version) => (
+                UnchangedSharedObjectKind::MutateDeleted,
+                Some(version.value()),
+                None,
+            ),
+            K::ReadDeleted(version) => (
+                UnchangedSharedObjectKind::ReadDeleted,
+                Some(version.value()),
+                None,
+            ),
+            K::Cancelled(version) => (
+                UnchangedSharedObjectKind::Cancelled,
+                Some(version.value()),
+                None,
+            ),
+            K::PerEpochConfig => (UnchangedSharedObjectKind::PerEpochConfig, None, None),
+        };
+
+        Self {
+            object_id: bytes(object_id),
+            kind: kind.into(),
+            version,
+            digest,
+        }
+    }
+}
+
+impl From<&sui_types::effects::TransactionEffects> for TransactionEffects {
+    fn from(value: &sui_types::effects::TransactionEffects) -> Self {
+        let input_owners: BTreeMap<_, _> = match value {
+            // V1 effects don't record the owners of their inputs.
+            sui_types::effects::TransactionEffects::V1(_) => BTreeMap::new(),
+            sui_types::effects::TransactionEffects::V2(_) => value
+                .old_object_metadata()
+                .into_iter()
+                .map(|((object_id, _, _), owner)| (object_id, owner))
+                .collect(),
+        };
+        let output_owners: BTreeMap<_, _> = value
+            .created()
+            .into_iter()
+            .chain(value.mutated())
+            .chain(value.unwrapped())
+            .map(|((object_id, _, _), owner)| (object_id, owner))
+            .collect();
+
+        Self {
+            transaction_digest: bytes(value.transaction_digest()),
+            status: Some(value.status().into()),
+            executed_epoch: value.executed_epoch(),
+            gas_used: Some(value.gas_cost_summary().into()),
+            gas_object: Some((&value.gas_object().0).into()),
+            events_digest: value.events_digest().map(bytes),
+            dependencies: value.dependencies().iter().map(bytes).collect(),
+            lamport_version: value.lamport_version().value(),
+            changed_objects: value
+                .object_changes()
+                .into_iter()
+                .map(|change| ChangedObject {
+                    object_id: bytes(change.id),
+                    input_version: change.input_version.map(|version| version.value()),
+                    input_digest: change.input_digest.map(bytes),
+                    input_owner: input_owners.get(&change.id).map(Into::into),
+                    output_version: change.output_version.map(|version| version.value()),
+                    output_digest: change.output_digest.map(bytes),
+                    output_owner: output_owners.get(&change.id).map(Into::into),
+                    id_operation: IdOperation::from(change.id_operation).into(),
+                })
+                .collect(),
+            unchanged_shared_objects: value
+                .unchanged_shared_objects()
+                .iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
+impl From<&sui_types::effects::TransactionEvents> for TransactionEvents {
+    fn from(value: &sui_types::effects::TransactionEvents) -> Self {
+        Self {
+            events: value
+                .data
+                .iter()
+                .map(|event| Event {
+                    package_id: bytes(event.package_id),
+                    module: event.transaction_module.to_string(),
+                    sender: bytes(event.sender),
+                    event_type: event.type_.to_canonical_string(true),
+                    contents: event.contents.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<&sui_types::full_checkpoint_content::CheckpointData> for FullCheckpoint {
+    fn from(value: &sui_types::full_checkpoint_content::CheckpointData) -> Self {
+        let transactions = value
+            .transactions
+            .iter()
+            .map(|transaction| {
+                let signed = transaction.transaction.data();
+                CheckpointTransaction {
+                    transaction: Some(signed.transaction_data().into()),
+                    signatures: signed.tx_signatures().iter().map(bytes).collect(),
+                    effects: Some((&transaction.effects).into()),
+                    events: transaction.events.as_ref().map(Into::into),
+                    input_objects: transaction.input_objects.iter().map(Into::into).collect(),
+                    output_objects: transaction.output_objects.iter().map(Into::into).collect(),
+                }
+            })
+            .collect();
+
+        Self {
+            summary: Some(value.checkpoint_summary.data().into()),
+            signature: Some(value.checkpoint_summary.auth_sig().into()),
+            contents: Some((&value.checkpoint_contents).into()),
+            transactions,
+        }
+    }
+}
+
+fn signatures(
+    signatures: Vec<sui_sdk_types::types::UserSignature>,
+) -> Result<Vec<Vec<u8>>, bcs::Error> {
+    signatures
+        .into_iter()
+        .map(|signature| sui_types::signature::GenericSignature::try_from(signature).map(bytes))
+        .collect()
+}
+
+impl TryFrom<TransactionResponse> for ExecutedTransaction {
+    type Error = bcs::Error;
+
+    fn try_from(value: TransactionResponse) -> Result<Self, Self::Error> {
+        let transaction = sui_types::transaction::TransactionData::try_from(value.transaction)?;
+        let effects = sui_types::effects::TransactionEffects::try_from(value.effects)?;
+        let events = value
+            .events
+            .map(sui_types::effects::TransactionEvents::try_from)
+            .transpose()?;
+
+        Ok(Self {
+            digest: value.digest.into_inner().to_vec(),
+            transaction: Some((&transaction).into()),
+            signatures: signatures(value.signatures)?,
+            effects: Some((&effects).into()),
+            events: events.as_ref().map(Into::into),
+            checkpoint: value.checkpoint,
+            timestamp_ms: value.timestamp_ms,
+        })
+    }
+}
+
+impl From<&sui_sdk_types::types::BalanceChange> for BalanceChange {
+    fn from(value: &sui_sdk_types::types::BalanceChange) -> Self {
+        Self {
+            address: value.address.into_inner().to_vec(),
+            coin_type: value.coin_type.to_string(),
+            amount: value.amount.to_string(),
+        }
+    }
+}
+
+impl TryFrom<TransactionExecutionResponse> for ExecuteTransactionResponse {
+    type Error = bcs::Error;
+
+    fn try_from(value: TransactionExecutionResponse) -> Result<Self, Self::Error> {
+        let finality = match &value.finality {
+            EffectsFinality::Certified { signature } => {
+                execute_transaction_response::Finality::Certified(signature.into())
+            }
+            EffectsFinality::Checkpointed { checkpoint } => {
+                execute_transaction_response::Finality::Checkpointed(*checkpoint)
+            }
+        };
+
+        let objects = |objects: &Option<Vec<sui_sdk_types::types::Object>>| {
+            objects
+                .iter()
+                .flatten()
+                .map(Object::try_from)
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        let effects = sui_types::effects::TransactionEffects::try_from(value.effects.clone())?;
+        let events = value
+            .events
+            .clone()
+            .map(sui_types::effects::TransactionEvents::try_from)
+            .transpose()?;
+
+        Ok(Self {
+            effects: Some((&effects).into()),
+            finality: Some(finality),
+            events: events.as_ref().map(Into::into),
+            balance_changes: value
+                .balance_changes
+                .iter()
+                .flatten()
+                .map(BalanceChange::from)
+                .collect(),
+            input_objects: objects(&value.input_objects)?,
+            output_objects: objects(&value.output_objects)?,
+        })
+    }
+}
+
+impl From<&ExecuteTransactionRequest> for crate::ExecuteTransactionQueryParameters {
+    fn from(value: &ExecuteTransactionRequest) -> Self {
+        Self {
+            events: value.events,
+            balance_changes: value.balance_changes,
+            input_objects: value.input_objects,
+            output_objects: value.output_objects,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost::Message;
+    use sui_types::base_types::{random_object_ref, SuiAddress};
+    use sui_types::programmable_transaction_builder::ProgrammableTransactionBuilder;
+
+    #[test]
+    fn checkpoint_id_oneof_roundtrip() {
+        let request = GetCheckpointRequest {
+            contents: true,
+            checkpoint_id: Some(get_checkpoint_request::CheckpointId::SequenceNumber(42)),
+        };
+
+        let bytes = request.encode_to_vec();
+        assert_eq!(
+            GetCheckpointRequest::decode(bytes.as_slice()).unwrap(),
+            request
+        );
+
+        let request = GetCheckpointRequest {
+            contents: false,
+            checkpoint_id: Some(get_checkpoint_request::CheckpointId::Digest(vec![7; 32])),
+        };
+
+        let bytes = request.encode_to_vec();
+        assert_eq!(
+            GetCheckpointRequest::decode(bytes.as_slice()).unwrap(),
+            request
+        );
+    }
+
+    #[test]
+    fn object_fields() {
+        let owner = SuiAddress::random_for_testing_only();
+        let object =
+            sui_types::object::Object::new_gas_with_balance_and_owner_for_testing(100, owner);
+        let message = Object::from(&object);
+
+        assert_eq!(message.object_id, object.id().to_vec());
+        assert_eq!(message.version, object.version().value());
+        assert_eq!(message.digest, bytes(object.digest()));
+        assert_eq!(
+            message.owner.unwrap().kind,
+            Some(owner::Kind::Address(owner.to_vec()))
+        );
+
+        let Some(object_data::Kind::MoveStruct(contents)) = message.data.unwrap().kind else {
+            panic!("expected a Move struct");
+        };
+        assert_eq!(
+            contents.object_type,
+            "0x0000000000000000000000000000000000000000000000000000000000000002::coin::Coin<0x0000000000000000000000000000000000000000000000000000000000000002::sui::SUI>",
+        );
+        assert!(contents.has_public_transfer);
+        assert_eq!(
+            contents.contents,
+            object.data.try_as_move().unwrap().contents()
+        );
+    }
+
+    #[test]
+    fn programmable_transaction_roundtrip() {
+        let sender = SuiAddress::random_for_testing_only();
+        let recipient = SuiAddress::random_for_testing_only();
+        let gas = random_object_ref();
+
+        let mut builder = ProgrammableTransactionBuilder::new();
+        builder.transfer_sui(recipient, Some(10));
+        let data = sui_types::transaction::TransactionData::new_programmable(
+            sender,
+            vec![gas],
+            builder.finish(),
+            1_000,
+            1,
+        );
+
+        let transaction = Transaction::from(&data);
+        assert_eq!(transaction.digest, bytes(data.digest()));
+        assert_eq!(transaction.sender, sender.to_vec());
+        assert_eq!(transaction.expiration, None);
+        assert_eq!(
+            transaction.gas_payment.as_ref().unwrap().objects,
+            vec![ObjectReference::from(&gas)]
+        );
+
+        let Some(transaction_kind::Kind::ProgrammableTransaction(ptb)) =
+            transaction.kind.as_ref().unwrap().kind.clone()
+        else {
+            panic!("expected a programmable transaction");
+        };
+
+        // The recipient and the amount are pure inputs, in the order they were added.
+        assert_eq!(
+            ptb.inputs,
+            vec![
+                Input {
+                    kind: Some(input::Kind::Pure(bcs::to_bytes(&recipient).unwrap())),
+                },
+                Input {
+                    kind: Some(input::Kind::Pure(bcs::to_bytes(&10u64).unwrap())),
+                },
+            ]
+        );
+
+        let argument = |kind| Argument { kind: Some(kind) };
+        assert_eq!(
+            ptb.commands,
+            vec![
+                Command {
+                    command: Some(command::Command::SplitCoins(SplitCoins {
+                        coin: Some(argument(argument::Kind::GasCoin(Empty {}))),
+                        amounts: vec![argument(argument::Kind::Input(1))],
+                    })),
+                },
+                Command {
+                    command: Some(command::Command::TransferObjects(TransferObjects {
+                        objects: vec![argument(argument::Kind::Result(0))],
+                        address: Some(argument(argument::Kind::Input(0))),
+                    })),
+                },
+            ]
+        );
+
+        let bytes = transaction.encode_to_vec();
+        assert_eq!(Transaction::decode(bytes.as_slice()).unwrap(), transaction);
+    }
+}
@@ -14,11 +14,13 @@ use crate::{
         X_SUI_LOWEST_AVAILABLE_CHECKPOINT, X_SUI_LOWEST_AVAILABLE_CHECKPOINT_OBJECTS,
         X_SUI_TIMESTAMP_MS,
     },
-    RestService, APPLICATION_BCS, TEXT_PLAIN_UTF_8,
+    RestService, APPLICATION_BCS, APPLICATION_PROTOBUF, TEXT_PLAIN_UTF_8,
 };
 
 pub struct Bcs<T>(pub T);
 
+pub struct Protobuf<T>(pub T);
+
 #[derive(Debug)]
 pub enum ResponseContent<T, J = T> {
     Bcs(T),
@@ -52,6 +54,22 @@ where
     }
 }
 
+impl<T> axum::response::IntoResponse for Protobuf<T>
+where
+    T: prost::Message,
+{
+    fn into_response(self) -> axum::response::Response {
+        (
+            [(
+                axum::http::header::CONTENT_TYPE,
+                axum::http::HeaderValue::from_static(APPLICATION_PROTOBUF),
+            )],
+            self.0.encode_to_vec(),
+        )
+            .into_response()
+    }
+}
+
 #[axum::async_trait]
 impl<T, S> axum::extract::FromRequest<S> for Bcs<T>
 where
@@ -125,6 +143,30 @@ where
     }
 }
 
+/// Response content for endpoints which can additionally respond with Protobuf, see
+/// [`AcceptJsonProtobufBcs`](crate::accept::AcceptJsonProtobufBcs).
+#[derive(Debug)]
+pub enum JsonProtobufBcs<J, P, T = J> {
+    Json(J),
+    Protobuf(P),
+    Bcs(T),
+}
+
+impl<J, P, T> axum::response::IntoResponse for JsonProtobufBcs<J, P, T>
+where
+    J: serde::Serialize,
+    P: prost::Message,
+    T: serde::Serialize,
+{
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            JsonProtobufBcs::Json(inner) => axum::Json(inner).into_response(),
+            JsonProtobufBcs::Protobuf(inner) => Protobuf(inner).into_response(),
+            JsonProtobufBcs::Bcs(inner) => Bcs(inner).into_response(),
+        }
+    }
+}
+
 pub async fn append_info_headers(
     State(state): State<RestService>,
     response: Response,
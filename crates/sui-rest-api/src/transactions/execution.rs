@@ -1,20 +1,28 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::accept::AcceptJsonProtobufBcs;
 use crate::openapi::{
     ApiEndpoint, OperationBuilder, RequestBodyBuilder, ResponseBuilder, RouteHandler,
 };
-use crate::response::Bcs;
+use crate::response::{Bcs, JsonProtobufBcs};
+use crate::transactions::TransactionNotFoundError;
 use crate::{accept::AcceptFormat, response::ResponseContent};
-use crate::{RestError, RestService, Result};
-use axum::extract::{Query, State};
+use crate::{proto, RestError, RestService, Result};
+use axum::extract::{Path, Query, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::Json;
 use schemars::JsonSchema;
-use std::net::SocketAddr;
+use std::net::{IpAddr, SocketAddr};
 use std::sync::Arc;
 use sui_sdk_types::types::framework::Coin;
 use sui_sdk_types::types::{
-    Address, BalanceChange, CheckpointSequenceNumber, Object, Owner, SignedTransaction,
-    Transaction, TransactionEffects, TransactionEvents, ValidatorAggregatedSignature,
+    Address, BalanceChange, Bls12381PublicKey, CheckpointSequenceNumber, Object, Owner,
+    SignedTransaction, Transaction, TransactionDigest, TransactionEffects, TransactionEvents,
+    ValidatorAggregatedSignature,
+};
+use sui_types::quorum_driver_types::{
+    PendingTransactionInfo, TransactionPriority, TransactionPriorityAllowlist,
 };
 use sui_types::transaction_executor::{SimulateTransactionResult, TransactionExecutor};
 use tap::Pipe;
@@ -43,6 +51,7 @@ impl ApiEndpoint<RestService> for ExecuteTransaction {
                 200,
                 ResponseBuilder::new()
                     .json_content::<TransactionExecutionResponse>(generator)
+                    .protobuf_content::<proto::ExecuteTransactionResponse>()
                     .bcs_content()
                     .build(),
             )
@@ -60,21 +69,77 @@ impl ApiEndpoint<RestService> for ExecuteTransaction {
 /// an internal QuorumDriver which drives execution of the transaction with the current validator
 /// set.
 ///
-/// A client can signal, using the `Accept` header, the response format as either JSON or BCS.
+/// A client can signal, using the `Accept` header, the response format as either JSON, Protobuf or
+/// BCS, and using the `x-sui-transaction-priority` header, the priority class the transaction is
+/// submitted under, if the client is on the node's allowlist.
 async fn execute_transaction(
     State(state): State<Option<Arc<dyn TransactionExecutor>>>,
+    State(allowlist): State<TransactionPriorityAllowlist>,
     Query(parameters): Query<ExecuteTransactionQueryParameters>,
     client_address: Option<axum::extract::ConnectInfo<SocketAddr>>,
-    accept: AcceptFormat,
+    accept: AcceptJsonProtobufBcs,
+    headers: HeaderMap,
     Bcs(transaction): Bcs<SignedTransaction>,
-) -> Result<ResponseContent<TransactionExecutionResponse>> {
+) -> Result<JsonProtobufBcs<TransactionExecutionResponse, proto::ExecuteTransactionResponse>> {
     let executor = state.ok_or_else(|| anyhow::anyhow!("No Transaction Executor"))?;
+    let priority = parse_transaction_priority(
+        &allowlist,
+        client_address.as_ref().map(|a| a.0.ip()),
+        headers
+            .get(crate::types::X_SUI_TRANSACTION_PRIORITY)
+            .map(|value| value.to_str().unwrap_or_default()),
+    )?;
+    let response = execute(
+        &executor,
+        transaction,
+        &parameters,
+        priority,
+        client_address.map(|a| a.0),
+    )
+    .await?;
+
+    match accept {
+        AcceptJsonProtobufBcs::Json => JsonProtobufBcs::Json(response),
+        AcceptJsonProtobufBcs::Protobuf => JsonProtobufBcs::Protobuf(response.try_into()?),
+        AcceptJsonProtobufBcs::Bcs => JsonProtobufBcs::Bcs(response),
+    }
+    .pipe(Ok)
+}
+
+/// Parse the value of the `x-sui-transaction-priority` header, defaulting to normal priority when
+/// the header is absent or `client` is not on `allowlist`, and rejecting unrecognized values.
+pub(crate) fn parse_transaction_priority(
+    allowlist: &TransactionPriorityAllowlist,
+    client: Option<IpAddr>,
+    value: Option<&str>,
+) -> Result<TransactionPriority> {
+    allowlist.priority(client, value).map_err(|_| {
+        RestError::new(
+            StatusCode::BAD_REQUEST,
+            format!(
+                "invalid transaction priority: {}",
+                value.unwrap_or_default()
+            ),
+        )
+    })
+}
+
+/// Submit `transaction` to `executor` for execution, shaping the response according to
+/// `parameters`.
+pub(crate) async fn execute(
+    executor: &Arc<dyn TransactionExecutor>,
+    transaction: SignedTransaction,
+    parameters: &ExecuteTransactionQueryParameters,
+    priority: TransactionPriority,
+    client_address: Option<SocketAddr>,
+) -> Result<TransactionExecutionResponse> {
     let request = sui_types::quorum_driver_types::ExecuteTransactionRequestV3 {
         transaction: transaction.try_into()?,
         include_events: parameters.events,
         include_input_objects: parameters.input_objects || parameters.balance_changes,
         include_output_objects: parameters.output_objects || parameters.balance_changes,
         include_auxiliary_data: false,
+        priority,
     };
 
     let sui_types::quorum_driver_types::ExecuteTransactionResponseV3 {
@@ -84,7 +149,7 @@ async fn execute_transaction(
         output_objects,
         auxiliary_data: _,
     } = executor
-        .execute_transaction(request, client_address.map(|a| a.0))
+        .execute_transaction(request, client_address)
         .await?;
 
     let (effects, finality) = {
@@ -151,20 +216,14 @@ async fn execute_transaction(
         None
     };
 
-    let response = TransactionExecutionResponse {
+    Ok(TransactionExecutionResponse {
         effects,
         finality,
         events,
         balance_changes,
         input_objects,
         output_objects,
-    };
-
-    match accept {
-        AcceptFormat::Json => ResponseContent::Json(response),
-        AcceptFormat::Bcs => ResponseContent::Bcs(response),
-    }
-    .pipe(Ok)
+    })
 }
 
 /// Query parameters for the execute transaction endpoint
@@ -191,13 +250,13 @@ pub struct ExecuteTransactionQueryParameters {
 /// Response type for the execute transaction endpoint
 #[derive(Debug, serde::Serialize, serde::Deserialize, JsonSchema)]
 pub struct TransactionExecutionResponse {
-    effects: TransactionEffects,
+    pub effects: TransactionEffects,
 
-    finality: EffectsFinality,
-    events: Option<TransactionEvents>,
-    balance_changes: Option<Vec<BalanceChange>>,
-    input_objects: Option<Vec<Object>>,
-    output_objects: Option<Vec<Object>>,
+    pub finality: EffectsFinality,
+    pub events: Option<TransactionEvents>,
+    pub balance_changes: Option<Vec<BalanceChange>>,
+    pub input_objects: Option<Vec<Object>>,
+    pub output_objects: Option<Vec<Object>>,
 }
 
 #[derive(Clone, Debug)]
@@ -353,6 +412,142 @@ fn derive_balance_changes(
         .collect()
 }
 
+pub struct GetPendingTransaction;
+
+impl ApiEndpoint<RestService> for GetPendingTransaction {
+    fn method(&self) -> axum::http::Method {
+        axum::http::Method::GET
+    }
+
+    fn path(&self) -> &'static str {
+        "/transactions/{transaction}/pending"
+    }
+
+    fn operation(
+        &self,
+        generator: &mut schemars::gen::SchemaGenerator,
+    ) -> openapiv3::v3_1::Operation {
+        OperationBuilder::new()
+            .tag("Transactions")
+            .operation_id("GetPendingTransaction")
+            .path_parameter::<TransactionDigest>("transaction", generator)
+            .response(
+                200,
+                ResponseBuilder::new()
+                    .json_content::<PendingTransactionResponse>(generator)
+                    .build(),
+            )
+            .response(404, ResponseBuilder::new().build())
+            .build()
+    }
+
+    fn handler(&self) -> RouteHandler<RestService> {
+        RouteHandler::new(self.method(), get_pending_transaction)
+    }
+}
+
+/// Get Pending Transaction REST endpoint.
+///
+/// Reports the progress of a transaction submitted for execution through this node: the stage it
+/// has reached, how many submission attempts have been made, which validators have responded and
+/// the errors returned so far. Transactions are only known while in flight and for a bounded while
+/// after they finish.
+async fn get_pending_transaction(
+    State(state): State<Option<Arc<dyn TransactionExecutor>>>,
+    Path(transaction_digest): Path<TransactionDigest>,
+) -> Result<Json<PendingTransactionResponse>> {
+    let executor = state.ok_or_else(|| anyhow::anyhow!("No Transaction Executor"))?;
+
+    executor
+        .get_pending_transaction(&transaction_digest.into())
+        .ok_or(TransactionNotFoundError(transaction_digest))?
+        .pipe(PendingTransactionResponse::from)
+        .pipe(Json)
+        .pipe(Ok)
+}
+
+/// Progress of a transaction submitted for execution through this node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PendingTransactionState {
+    Signing,
+    Certified,
+    Finalized,
+    ExecutedLocally,
+    Failed,
+}
+
+impl From<sui_types::quorum_driver_types::PendingTransactionState> for PendingTransactionState {
+    fn from(value: sui_types::quorum_driver_types::PendingTransactionState) -> Self {
+        use sui_types::quorum_driver_types::PendingTransactionState as Inner;
+
+        match value {
+            Inner::Signing => Self::Signing,
+            Inner::Certified => Self::Certified,
+            Inner::Finalized => Self::Finalized,
+            Inner::ExecutedLocally => Self::ExecutedLocally,
+            Inner::Failed => Self::Failed,
+        }
+    }
+}
+
+/// An error returned by one or more validators while submitting a transaction.
+#[derive(Debug, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct PendingTransactionErrorResponse {
+    /// The submission attempt the error was observed on.
+    pub attempt: u32,
+    pub error: String,
+    /// Validators which returned the error.
+    pub validators: Vec<Bls12381PublicKey>,
+}
+
+/// Response type for the get pending transaction endpoint
+#[serde_with::serde_as]
+#[derive(Debug, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct PendingTransactionResponse {
+    pub digest: TransactionDigest,
+    pub priority: TransactionPriority,
+    pub state: PendingTransactionState,
+    /// Number of submission attempts made so far.
+    pub attempts: u32,
+    #[serde_as(as = "sui_types::sui_serde::Readable<sui_types::sui_serde::BigInt<u64>, _>")]
+    #[schemars(with = "crate::_schemars::U64")]
+    pub submitted_at_ms: u64,
+    #[serde_as(as = "sui_types::sui_serde::Readable<sui_types::sui_serde::BigInt<u64>, _>")]
+    #[schemars(with = "crate::_schemars::U64")]
+    pub last_updated_ms: u64,
+    /// Validators which have signed the transaction or its effects.
+    pub responded_validators: Vec<Bls12381PublicKey>,
+    pub errors: Vec<PendingTransactionErrorResponse>,
+}
+
+impl From<PendingTransactionInfo> for PendingTransactionResponse {
+    fn from(value: PendingTransactionInfo) -> Self {
+        Self {
+            digest: value.digest.into(),
+            priority: value.priority,
+            state: value.state.into(),
+            attempts: value.attempts,
+            submitted_at_ms: value.submitted_at_ms,
+            last_updated_ms: value.last_updated_ms,
+            responded_validators: value
+                .responded_validators
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            errors: value
+                .errors
+                .into_iter()
+                .map(|error| PendingTransactionErrorResponse {
+                    attempt: error.attempt,
+                    error: error.error,
+                    validators: error.validators.into_iter().map(Into::into).collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
 pub struct SimulateTransaction;
 
 impl ApiEndpoint<RestService> for SimulateTransaction {
@@ -5,10 +5,15 @@ mod execution;
 pub use execution::EffectsFinality;
 pub use execution::ExecuteTransaction;
 pub use execution::ExecuteTransactionQueryParameters;
+pub use execution::GetPendingTransaction;
+pub use execution::PendingTransactionErrorResponse;
+pub use execution::PendingTransactionResponse;
+pub use execution::PendingTransactionState;
 pub use execution::SimulateTransaction;
 pub use execution::SimulateTransactionQueryParameters;
 pub use execution::TransactionExecutionResponse;
 pub use execution::TransactionSimulationResponse;
+pub(crate) use execution::{execute, parse_transaction_priority};
 
 mod resolve;
 pub use resolve::ResolveTransaction;
@@ -24,11 +29,14 @@ use sui_sdk_types::types::{
 };
 use tap::Pipe;
 
+use crate::accept::AcceptJsonProtobufBcs;
 use crate::openapi::ApiEndpoint;
 use crate::openapi::OperationBuilder;
 use crate::openapi::ResponseBuilder;
 use crate::openapi::RouteHandler;
+use crate::proto;
 use crate::reader::StateReader;
+use crate::response::JsonProtobufBcs;
 use crate::Direction;
 use crate::Page;
 use crate::RestError;
@@ -59,6 +67,7 @@ impl ApiEndpoint<RestService> for GetTransaction {
                 200,
                 ResponseBuilder::new()
                     .json_content::<TransactionResponse>(generator)
+                    .protobuf_content::<proto::ExecutedTransaction>()
                     .bcs_content()
                     .build(),
             )
@@ -73,14 +82,15 @@ impl ApiEndpoint<RestService> for GetTransaction {
 
 async fn get_transaction(
     Path(transaction_digest): Path<TransactionDigest>,
-    accept: AcceptFormat,
+    accept: AcceptJsonProtobufBcs,
     State(state): State<StateReader>,
-) -> Result<ResponseContent<TransactionResponse>> {
+) -> Result<JsonProtobufBcs<TransactionResponse, proto::ExecutedTransaction>> {
     let response = state.get_transaction_response(transaction_digest)?;
 
     match accept {
-        AcceptFormat::Json => ResponseContent::Json(response),
-        AcceptFormat::Bcs => ResponseContent::Bcs(response),
+        AcceptJsonProtobufBcs::Json => JsonProtobufBcs::Json(response),
+        AcceptJsonProtobufBcs::Protobuf => JsonProtobufBcs::Protobuf(response.try_into()?),
+        AcceptJsonProtobufBcs::Bcs => JsonProtobufBcs::Bcs(response),
     }
     .pipe(Ok)
 }
@@ -278,7 +288,7 @@ impl serde::Serialize for TransactionCursor {
     }
 }
 
-#[derive(Debug, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
 pub struct ListTransactionsQueryParameters {
     pub limit: Option<u32>,
     #[schemars(with = "Option<String>")]
@@ -34,3 +34,8 @@ pub const X_SUI_CURSOR: &str = "x-sui-cursor";
 
 /// Current timestamp of the chain - represented as number of milliseconds from the Unix epoch
 pub const X_SUI_TIMESTAMP_MS: &str = "x-sui-timestamp-ms";
+
+/// Priority class (`high`, `normal` or `low`) under which a transaction is submitted for
+/// execution. Defaults to `normal` when absent. Shared with the JSON-RPC API.
+pub const X_SUI_TRANSACTION_PRIORITY: &str =
+    sui_types::quorum_driver_types::TRANSACTION_PRIORITY_HEADER;
@@ -1,14 +1,17 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::{BTreeSet, HashMap};
+
 use fastcrypto_zkp::bn254::zk_login::OIDCProvider;
-use sui_config::transaction_deny_config::TransactionDenyConfig;
+use sui_config::transaction_deny_config::{PackageDenyRule, TransactionDenyConfig};
 use sui_types::{
-    base_types::ObjectRef,
+    base_types::{ObjectID, ObjectRef},
     error::{SuiError, SuiResult, UserInputError},
     signature::GenericSignature,
     storage::BackingPackageStore,
     transaction::{Command, InputObjectKind, TransactionData, TransactionDataAPI},
+    type_input::TypeInput,
 };
 macro_rules! deny_if_true {
     ($cond:expr, $msg:expr) => {
@@ -40,6 +43,8 @@ pub fn check_transaction_for_signing(
 
     check_package_dependencies(filter_config, tx_data, package_store)?;
 
+    check_package_deny_rules(filter_config, tx_data, package_store)?;
+
     check_receiving_objects(filter_config, receiving_objects)?;
 
     Ok(())
@@ -209,3 +214,176 @@ fn check_package_dependencies(
     }
     Ok(())
 }
+
+/// Check the transaction against the config's package deny rules. Unlike the plain package deny
+/// list, rules can match packages by their original ID, so every package the transaction touches
+/// is loaded to find out which upgrade family it belongs to.
+fn check_package_deny_rules(
+    filter_config: &TransactionDenyConfig,
+    tx_data: &TransactionData,
+    package_store: &dyn BackingPackageStore,
+) -> SuiResult {
+    let sender = tx_data.sender();
+    let rules: Vec<_> = filter_config
+        .package_deny_rules()
+        .iter()
+        .filter(|rule| rule.applies_to(&sender))
+        .collect();
+    if rules.is_empty() {
+        return Ok(());
+    }
+
+    let mut original_ids = OriginalIds::new(package_store);
+    for command in tx_data.kind().iter_commands() {
+        match command {
+            Command::Publish(_, deps) => {
+                for dep in deps {
+                    // As with the package deny list, the deps list may be inaccurate, in which
+                    // case the transaction will fail to execute anyway.
+                    if let Some(original_id) = original_ids.get(dep)? {
+                        check_package_use(&rules, dep, &original_id)?;
+                    }
+                }
+            }
+            Command::Upgrade(_, deps, package_id, _) => {
+                for dep in deps.iter().chain(Some(package_id)) {
+                    if let Some(original_id) = original_ids.get(dep)? {
+                        check_package_use(&rules, dep, &original_id)?;
+                    }
+                }
+            }
+            Command::MoveCall(call) => {
+                let package = package_store.get_package_object(&call.package)?.ok_or(
+                    SuiError::UserInputError {
+                        error: UserInputError::ObjectNotFound {
+                            object_id: call.package,
+                            version: None,
+                        },
+                    },
+                )?;
+                let package = package.move_package();
+                let (id, original_id) = (package.id(), package.original_package_id());
+
+                for rule in &rules {
+                    deny_if_true!(
+                        rule.matches_package(&id, &original_id)
+                            && rule.matches_function(&call.module, &call.function),
+                        format!(
+                            "Calling {id}::{}::{} is temporarily disabled",
+                            call.module, call.function
+                        )
+                    );
+                }
+
+                // The linkage table is keyed by the original ID of each dependency, so this does
+                // not need to load the dependencies themselves.
+                for (original_id, upgrade_info) in package.linkage_table() {
+                    check_package_use(&rules, &upgrade_info.upgraded_id, original_id)?;
+                }
+
+                check_type_arguments(&rules, &mut original_ids, &call.type_arguments)?;
+            }
+            Command::MakeMoveVec(Some(ty), _) => {
+                check_type_arguments(&rules, &mut original_ids, std::slice::from_ref(ty))?;
+            }
+            Command::MakeMoveVec(None, _)
+            | Command::TransferObjects(..)
+            | Command::SplitCoins(..)
+            | Command::MergeCoins(..) => {}
+        }
+    }
+    Ok(())
+}
+
+/// Deny any use of the package `id`, whose original ID is `original_id`, that is matched by one
+/// of `rules`.
+fn check_package_use(
+    rules: &[&PackageDenyRule],
+    id: &ObjectID,
+    original_id: &ObjectID,
+) -> SuiResult {
+    for rule in rules {
+        deny_if_true!(
+            rule.denies_all_uses() && rule.matches_package(id, original_id),
+            format!("Access to package {:?} is temporarily disabled", id)
+        );
+    }
+    Ok(())
+}
+
+/// Deny the use of types from packages matched by `rules` that deny type arguments, anywhere in
+/// `type_arguments`, including nested inside other types.
+fn check_type_arguments(
+    rules: &[&PackageDenyRule],
+    original_ids: &mut OriginalIds<'_>,
+    type_arguments: &[TypeInput],
+) -> SuiResult {
+    if !rules.iter().any(|rule| rule.type_arguments) {
+        return Ok(());
+    }
+
+    let mut packages = BTreeSet::new();
+    let mut stack: Vec<_> = type_arguments.iter().collect();
+    while let Some(ty) = stack.pop() {
+        match ty {
+            TypeInput::Vector(inner) => stack.push(inner),
+            TypeInput::Struct(struct_input) => {
+                packages.insert(ObjectID::from(struct_input.address));
+                stack.extend(struct_input.type_params.iter());
+            }
+            TypeInput::Bool
+            | TypeInput::U8
+            | TypeInput::U16
+            | TypeInput::U32
+            | TypeInput::U64
+            | TypeInput::U128
+            | TypeInput::U256
+            | TypeInput::Address
+            | TypeInput::Signer => {}
+        }
+    }
+
+    for id in packages {
+        // A type argument from a package that doesn't exist will fail to execute anyway.
+        let Some(original_id) = original_ids.get(&id)? else {
+            continue;
+        };
+
+        for rule in rules {
+            deny_if_true!(
+                rule.type_arguments && rule.matches_package(&id, &original_id),
+                format!("Using types from package {:?} is temporarily disabled", id)
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Looks up the original IDs of packages, loading each package at most once per transaction.
+struct OriginalIds<'s> {
+    package_store: &'s dyn BackingPackageStore,
+    cache: HashMap<ObjectID, Option<ObjectID>>,
+}
+
+impl<'s> OriginalIds<'s> {
+    fn new(package_store: &'s dyn BackingPackageStore) -> Self {
+        Self {
+            package_store,
+            cache: HashMap::new(),
+        }
+    }
+
+    /// The original ID of package `id`, or `None` if there is no such package.
+    fn get(&mut self, id: &ObjectID) -> SuiResult<Option<ObjectID>> {
+        if let Some(original_id) = self.cache.get(id) {
+            return Ok(*original_id);
+        }
+
+        let original_id = self
+            .package_store
+            .get_package_object(id)?
+            .map(|p| p.move_package().original_package_id());
+        self.cache.insert(*id, original_id);
+        Ok(original_id)
+    }
+}
@@ -466,6 +466,12 @@ impl Display for ConciseAuthorityPublicKeyBytes {
     }
 }
 
+impl From<ConciseAuthorityPublicKeyBytes> for AuthorityPublicKeyBytes {
+    fn from(concise: ConciseAuthorityPublicKeyBytes) -> Self {
+        concise.0
+    }
+}
+
 impl TryFrom<AuthorityPublicKeyBytes> for AuthorityPublicKey {
     type Error = FastCryptoError;
 
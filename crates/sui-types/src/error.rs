@@ -290,6 +290,16 @@ pub enum UserInputError {
 
     #[error("Invalid identifier found in the transaction: {error}")]
     InvalidIdentifier { error: String },
+
+    #[error(
+        "Checkpoint {checkpoint} is not available for historical reads, which are served for \
+         checkpoints {lowest_available} to {highest_available}"
+    )]
+    CheckpointNotAvailableForHistoricalReads {
+        checkpoint: CheckpointSequenceNumber,
+        lowest_available: CheckpointSequenceNumber,
+        highest_available: CheckpointSequenceNumber,
+    },
 }
 
 #[derive(
@@ -112,6 +112,7 @@ pub enum ConsensusTransactionKey {
     RandomnessDkgMessage(AuthorityName),
     RandomnessDkgConfirmation(AuthorityName),
     UserTransaction(TransactionDigest),
+    ExecutionCostObservation(AuthorityName, u64 /* generation */),
 }
 
 impl Debug for ConsensusTransactionKey {
@@ -145,6 +146,12 @@ impl Debug for ConsensusTransactionKey {
                 write!(f, "RandomnessDkgConfirmation({:?})", name.concise())
             }
             Self::UserTransaction(digest) => write!(f, "UserTransaction({:?})", digest),
+            Self::ExecutionCostObservation(name, generation) => write!(
+                f,
+                "ExecutionCostObservation({:?}, {:?})",
+                name.concise(),
+                generation
+            ),
         }
     }
 }
@@ -266,6 +273,54 @@ impl AuthorityCapabilitiesV2 {
     }
 }
 
+/// The maximum number of objects whose cost a single ExecutionCostObservation can carry.
+pub const MAX_EXECUTION_COST_OBSERVATION_OBJECTS: usize = 1000;
+
+/// Used by each authority to report, via consensus, the execution cost it measured for
+/// transactions on shared objects. Every validator derives the cost estimates used for congestion
+/// control from these reports, in consensus order, rather than from its own execution.
+#[derive(Serialize, Deserialize, Clone, Hash)]
+pub struct ExecutionCostObservation {
+    /// Originating authority - must match transaction source authority from consensus.
+    pub authority: AuthorityName,
+    /// Generation number set by sending authority. Only observations with a higher generation
+    /// than the last one sequenced from the same authority are used.
+    ///
+    /// (Currently, we just set this to the current time in milliseconds since the epoch, but this
+    /// should not be interpreted as a timestamp.)
+    pub generation: u64,
+
+    /// The computation cost the authority measured for a transaction on each object. Objects
+    /// that are not listed keep the cost the authority last reported for them.
+    pub object_costs: Vec<(ObjectID, u64)>,
+}
+
+impl Debug for ExecutionCostObservation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExecutionCostObservation")
+            .field("authority", &self.authority.concise())
+            .field("generation", &self.generation)
+            .field("object_costs", &self.object_costs)
+            .finish()
+    }
+}
+
+impl ExecutionCostObservation {
+    pub fn new(authority: AuthorityName, object_costs: Vec<(ObjectID, u64)>) -> Self {
+        let generation = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Sui did not exist prior to 1970")
+            .as_millis()
+            .try_into()
+            .expect("This build of sui is not supported in the year 500,000,000");
+        Self {
+            authority,
+            generation,
+            object_costs,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub enum ConsensusTransactionKind {
     CertifiedTransaction(Box<CertifiedTransaction>),
@@ -288,6 +343,8 @@ pub enum ConsensusTransactionKind {
     CapabilityNotificationV2(AuthorityCapabilitiesV2),
 
     UserTransaction(Box<Transaction>),
+
+    ExecutionCostObservation(Box<ExecutionCostObservation>),
 }
 
 impl ConsensusTransactionKind {
@@ -473,6 +530,16 @@ impl ConsensusTransaction {
         }
     }
 
+    pub fn new_execution_cost_observation(observation: ExecutionCostObservation) -> Self {
+        let mut hasher = DefaultHasher::new();
+        observation.hash(&mut hasher);
+        let tracking_id = hasher.finish().to_le_bytes();
+        Self {
+            tracking_id,
+            kind: ConsensusTransactionKind::ExecutionCostObservation(Box::new(observation)),
+        }
+    }
+
     pub fn new_jwk_fetched(authority: AuthorityName, id: JwkId, jwk: JWK) -> Self {
         let mut hasher = DefaultHasher::new();
         id.hash(&mut hasher);
@@ -557,6 +624,12 @@ impl ConsensusTransaction {
             ConsensusTransactionKind::UserTransaction(tx) => {
                 ConsensusTransactionKey::UserTransaction(*tx.digest())
             }
+            ConsensusTransactionKind::ExecutionCostObservation(observation) => {
+                ConsensusTransactionKey::ExecutionCostObservation(
+                    observation.authority,
+                    observation.generation,
+                )
+            }
         }
     }
 
@@ -2,7 +2,9 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::net::IpAddr;
+use std::sync::Arc;
 
 use crate::base_types::{AuthorityName, EpochId, ObjectRef, TransactionDigest};
 use crate::committee::StakeUnit;
@@ -16,7 +18,7 @@ use crate::messages_checkpoint::CheckpointSequenceNumber;
 use crate::object::Object;
 use crate::transaction::{Transaction, VerifiedTransaction};
 use serde::{Deserialize, Serialize};
-use strum::AsRefStr;
+use strum::{AsRefStr, EnumIter, EnumString};
 use thiserror::Error;
 
 pub type QuorumDriverResult = Result<QuorumDriverResponse, QuorumDriverError>;
@@ -136,6 +138,113 @@ impl ExecuteTransactionRequest {
     }
 }
 
+/// Submission class of a transaction. Each class is driven through its own lane in the
+/// QuorumDriver, with a separate queue, concurrency limit and retry budget, so that bulk
+/// traffic cannot delay latency-sensitive submissions.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    AsRefStr,
+    EnumString,
+    EnumIter,
+    PartialOrd,
+    Ord,
+    schemars::JsonSchema,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum TransactionPriority {
+    High,
+    #[default]
+    Normal,
+    Low,
+}
+
+/// Header under which clients of both the JSON-RPC and the REST APIs set the priority of a
+/// transaction execution request.
+pub const TRANSACTION_PRIORITY_HEADER: &str = "x-sui-transaction-priority";
+
+impl TransactionPriority {
+    /// Parse the value of the `x-sui-transaction-priority` header. A missing header means normal
+    /// priority, while an unrecognized value is an error, so that a misspelled priority is not
+    /// silently submitted as normal.
+    pub fn from_header(value: Option<&str>) -> Result<Self, strum::ParseError> {
+        value.map_or(Ok(Self::default()), str::parse)
+    }
+}
+
+/// Clients trusted to set the priority of their transaction execution requests. The priority
+/// header of requests from other clients is ignored, and their transactions are executed at
+/// normal priority.
+#[derive(Clone, Debug, Default)]
+pub struct TransactionPriorityAllowlist(Arc<HashSet<IpAddr>>);
+
+impl TransactionPriorityAllowlist {
+    pub fn new(clients: impl IntoIterator<Item = IpAddr>) -> Self {
+        Self(Arc::new(clients.into_iter().collect()))
+    }
+
+    /// The priority that `client` requested through the `x-sui-transaction-priority` header,
+    /// which is only parsed if the client is on the allowlist.
+    pub fn priority(
+        &self,
+        client: Option<IpAddr>,
+        header: Option<&str>,
+    ) -> Result<TransactionPriority, strum::ParseError> {
+        match client {
+            Some(client) if self.0.contains(&client) => TransactionPriority::from_header(header),
+            _ => Ok(TransactionPriority::default()),
+        }
+    }
+}
+
+/// Progress of a transaction submitted for execution through this node.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PendingTransactionState {
+    /// Collecting validator signatures to form a transaction certificate.
+    Signing,
+    /// A certificate was formed and is being submitted to validators for execution.
+    Certified,
+    /// A quorum of validators signed the effects of the transaction.
+    Finalized,
+    /// The transaction is finalized and was also executed by this node.
+    ExecutedLocally,
+    /// Submission stopped with a non-retryable error, or ran out of retries.
+    Failed,
+}
+
+/// An error returned by a group of validators while submitting a transaction.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PendingTransactionError {
+    /// The submission attempt, starting from 1, during which the error was seen.
+    pub attempt: u32,
+    pub error: String,
+    pub validators: Vec<AuthorityName>,
+}
+
+/// Status of a transaction submitted through the QuorumDriver of this node.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PendingTransactionInfo {
+    pub digest: TransactionDigest,
+    pub priority: TransactionPriority,
+    pub state: PendingTransactionState,
+    /// Number of submission attempts started so far.
+    pub attempts: u32,
+    pub submitted_at_ms: u64,
+    pub last_updated_ms: u64,
+    /// Validators whose signatures were collected on the transaction or its effects.
+    pub responded_validators: BTreeSet<AuthorityName>,
+    /// Most recent validator errors, oldest first.
+    pub errors: Vec<PendingTransactionError>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct ExecuteTransactionRequestV3 {
     pub transaction: Transaction,
@@ -144,6 +253,9 @@ pub struct ExecuteTransactionRequestV3 {
     pub include_input_objects: bool,
     pub include_output_objects: bool,
     pub include_auxiliary_data: bool,
+
+    #[serde(default)]
+    pub priority: TransactionPriority,
 }
 
 #[derive(Clone, Debug)]
@@ -165,8 +277,14 @@ impl ExecuteTransactionRequestV3 {
             include_input_objects: false,
             include_output_objects: false,
             include_auxiliary_data: false,
+            priority: TransactionPriority::default(),
         }
     }
+
+    pub fn with_priority(mut self, priority: TransactionPriority) -> Self {
+        self.priority = priority;
+        self
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -23,9 +23,11 @@ use move_binary_format::CompiledModule;
 use move_core_types::language_storage::ModuleId;
 pub use object_store_trait::ObjectStore;
 pub use read_store::AccountOwnedObjectInfo;
+pub use read_store::BalanceInfo;
 pub use read_store::CoinInfo;
 pub use read_store::DynamicFieldIndexInfo;
 pub use read_store::DynamicFieldKey;
+pub use read_store::EventInfo;
 pub use read_store::ReadStore;
 pub use read_store::RestStateReader;
 use serde::{Deserialize, Serialize};
@@ -38,7 +40,7 @@ use std::sync::Arc;
 pub use write_store::WriteStore;
 
 /// A potential input to a transaction.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
 pub enum InputKey {
     VersionedObject {
         id: ObjectID,
@@ -673,6 +673,24 @@ pub trait RestStateReader: ObjectStore + ReadStore + Send + Sync {
     ) -> Result<Box<dyn Iterator<Item = (DynamicFieldKey, DynamicFieldIndexInfo)> + '_>>;
 
     fn get_coin_info(&self, coin_type: &StructTag) -> Result<Option<CoinInfo>>;
+
+    /// Iterate over the balances of each coin type owned by `owner`, starting from the balance of
+    /// `cursor`, if provided.
+    fn account_balances_iter(
+        &self,
+        owner: SuiAddress,
+        cursor: Option<StructTag>,
+    ) -> Result<Box<dyn Iterator<Item = BalanceInfo> + '_>>;
+
+    fn get_balance(&self, owner: SuiAddress, coin_type: &StructTag) -> Result<Option<BalanceInfo>>;
+
+    /// Iterate over the events of type `event_type`, in the order they were emitted, starting from
+    /// the event at `cursor` (a checkpoint, transaction index and event index), if provided.
+    fn event_type_iter(
+        &self,
+        event_type: &StructTag,
+        cursor: Option<(CheckpointSequenceNumber, u32, u32)>,
+    ) -> Result<Box<dyn Iterator<Item = EventInfo> + '_>>;
 }
 
 pub struct AccountOwnedObjectInfo {
@@ -718,3 +736,20 @@ pub struct CoinInfo {
     pub coin_metadata_object_id: Option<ObjectID>,
     pub treasury_object_id: Option<ObjectID>,
 }
+
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Debug)]
+pub struct BalanceInfo {
+    pub coin_type: StructTag,
+    pub balance: u64,
+    pub coin_object_count: u64,
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Debug)]
+pub struct EventInfo {
+    pub checkpoint: CheckpointSequenceNumber,
+    /// Index of the emitting transaction within its checkpoint.
+    pub transaction_index: u32,
+    pub transaction_digest: TransactionDigest,
+    /// Index of the event within the events emitted by its transaction.
+    pub event_index: u32,
+}
@@ -4,12 +4,14 @@
 use std::collections::BTreeMap;
 
 use crate::base_types::ObjectID;
+use crate::digests::TransactionDigest;
 use crate::effects::TransactionEffects;
 use crate::effects::TransactionEvents;
 use crate::error::SuiError;
 use crate::object::Object;
 use crate::quorum_driver_types::ExecuteTransactionRequestV3;
 use crate::quorum_driver_types::ExecuteTransactionResponseV3;
+use crate::quorum_driver_types::PendingTransactionInfo;
 use crate::quorum_driver_types::QuorumDriverError;
 use crate::transaction::TransactionData;
 
@@ -27,6 +29,10 @@ pub trait TransactionExecutor: Send + Sync {
         &self,
         transaction: TransactionData,
     ) -> Result<SimulateTransactionResult, SuiError>;
+
+    /// Status of a transaction recently submitted through this executor, if it is still known.
+    fn get_pending_transaction(&self, digest: &TransactionDigest)
+        -> Option<PendingTransactionInfo>;
 }
 
 pub struct SimulateTransactionResult {